@@ -0,0 +1,63 @@
+//! Exhaustive and Monte Carlo equity on the standard scenarios.
+//!
+//! ```text
+//! cargo bench --bench equity
+//! ```
+
+use std::hint::black_box;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use riverrun::bench_utils::EquityScenario;
+use riverrun::core::domain::services::{
+    CactusKevEvaluator, ExhaustiveEquityCalculator, MonteCarloEquityCalculator,
+};
+use riverrun::core::ports::inbound::EquityCalculator;
+
+/// Monte Carlo samples drawn per iteration.
+const SAMPLES: u32 = 10_000;
+
+fn exhaustive(c: &mut Criterion) {
+    let calculator = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+
+    let mut group = c.benchmark_group("exhaustive_equity");
+    group.sample_size(20);
+    for scenario in [EquityScenario::river(), EquityScenario::turn()] {
+        group.bench_function(scenario.name, |b| {
+            b.iter(|| {
+                calculator
+                    .calculate(
+                        black_box(&scenario.hole_cards),
+                        black_box(&scenario.board),
+                        scenario.num_opponents,
+                    )
+                    .unwrap()
+            });
+        });
+    }
+    group.finish();
+}
+
+fn monte_carlo(c: &mut Criterion) {
+    let calculator = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+
+    let mut group = c.benchmark_group("monte_carlo_equity");
+    group.throughput(Throughput::Elements(u64::from(SAMPLES)));
+    for scenario in [EquityScenario::flop(), EquityScenario::preflop_multiway()] {
+        group.bench_function(scenario.name, |b| {
+            b.iter(|| {
+                calculator
+                    .calculate_sampled(
+                        black_box(&scenario.hole_cards),
+                        black_box(&scenario.board),
+                        scenario.num_opponents,
+                        SAMPLES,
+                    )
+                    .unwrap()
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, exhaustive, monte_carlo);
+criterion_main!(benches);
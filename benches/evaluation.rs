@@ -0,0 +1,58 @@
+//! Throughput of five- and seven-card hand evaluation.
+//!
+//! ```text
+//! cargo bench --bench evaluation
+//! ```
+
+use std::hint::black_box;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use riverrun::bench_utils::{DEFAULT_BATCH, DEFAULT_SEED, five_card_hands, seven_card_hands};
+use riverrun::core::domain::services::{CactusKevEvaluator, PerfectHashEvaluator};
+use riverrun::core::ports::inbound::HandEvaluator;
+
+fn five_card(c: &mut Criterion) {
+    let hands = five_card_hands(DEFAULT_BATCH, DEFAULT_SEED);
+    let evaluator = CactusKevEvaluator::new();
+
+    let mut group = c.benchmark_group("evaluate_5cards");
+    group.throughput(Throughput::Elements(hands.len() as u64));
+    group.bench_function("cactus_kev", |b| {
+        b.iter(|| {
+            for hand in &hands {
+                black_box(evaluator.evaluate_5cards_fast(black_box(hand)));
+            }
+        });
+    });
+    group.finish();
+}
+
+fn seven_card(c: &mut Criterion) {
+    let hands = seven_card_hands(DEFAULT_BATCH, DEFAULT_SEED);
+    let cactus_kev = CactusKevEvaluator::new();
+    let perfect_hash = PerfectHashEvaluator::new();
+
+    let mut group = c.benchmark_group("evaluate_7cards");
+    group.throughput(Throughput::Elements(hands.len() as u64));
+    group.bench_function("cactus_kev", |b| {
+        b.iter(|| {
+            for hand in &hands {
+                black_box(cactus_kev.evaluate_7cards_fast(black_box(hand)));
+            }
+        });
+    });
+    group.bench_function("perfect_hash", |b| {
+        b.iter(|| {
+            for hand in &hands {
+                black_box(perfect_hash.evaluate_7cards_fast(black_box(hand)));
+            }
+        });
+    });
+    group.bench_function("cactus_kev_batch", |b| {
+        b.iter(|| black_box(cactus_kev.evaluate_batch(black_box(&hands))));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, five_card, seven_card);
+criterion_main!(benches);
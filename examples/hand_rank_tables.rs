@@ -0,0 +1,18 @@
+//! Regenerates the hand rank tables embedded by the `embedded-tables` feature.
+//!
+//! ```text
+//! cargo run --release --example hand_rank_tables -- [output path]
+//! ```
+
+use std::{env, io};
+
+use riverrun::core::domain::services::evaluation::HandRankTables;
+
+const DEFAULT_OUTPUT: &str = "src/core/domain/services/evaluation/hand_rank_tables.bin";
+
+fn main() -> io::Result<()> {
+    let output = env::args().nth(1).unwrap_or_else(|| DEFAULT_OUTPUT.to_string());
+    HandRankTables::new().save_to(&output)?;
+    println!("wrote {output}");
+    Ok(())
+}
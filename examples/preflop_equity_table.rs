@@ -0,0 +1,20 @@
+//! Regenerates the preflop equity table shipped with the crate.
+//!
+//! ```text
+//! cargo run --release --example preflop_equity_table -- [output path]
+//! ```
+
+use std::{env, fs, io};
+
+use riverrun::core::domain::services::equity::{PreflopEquityTable, SHIPPED_SAMPLES};
+use riverrun::core::domain::services::PerfectHashEvaluator;
+
+const DEFAULT_OUTPUT: &str = "src/core/domain/services/equity/preflop_equity.bin";
+
+fn main() -> io::Result<()> {
+    let output = env::args().nth(1).unwrap_or_else(|| DEFAULT_OUTPUT.to_string());
+    let table = PreflopEquityTable::generate(&PerfectHashEvaluator::new(), SHIPPED_SAMPLES);
+    fs::write(&output, table.to_bytes())?;
+    println!("wrote {output}");
+    Ok(())
+}
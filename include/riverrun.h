@@ -0,0 +1,156 @@
+#ifndef RIVERRUN_H
+#define RIVERRUN_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Most hands `riverrun_showdown` compares.
+ */
+#define RIVERRUN_MAX_PLAYERS 10
+
+/**
+ * Outcome of an FFI call.
+ */
+typedef enum RiverrunStatus {
+  /**
+   * The call succeeded and its output was written.
+   */
+  RIVERRUN_STATUS_OK = 0,
+  /**
+   * A required pointer was null.
+   */
+  RIVERRUN_STATUS_NULL_POINTER = 1,
+  /**
+   * A card index was 52 or more.
+   */
+  RIVERRUN_STATUS_INVALID_CARD = 2,
+  /**
+   * The same card was passed twice.
+   */
+  RIVERRUN_STATUS_DUPLICATE_CARD = 3,
+  /**
+   * Too few or too many cards or players were passed.
+   */
+  RIVERRUN_STATUS_INVALID_COUNT = 4,
+  /**
+   * The equity calculation failed, e.g. too many opponents for the cards left.
+   */
+  RIVERRUN_STATUS_EQUITY_FAILED = 5,
+} RiverrunStatus;
+
+/**
+ * Best five-card hand, written by `riverrun_evaluate`.
+ */
+typedef struct RiverrunHand {
+  /**
+   * Strength from 1 (royal flush) to 7462 (seven high); lower is stronger.
+   */
+  uint16_t strength;
+  /**
+   * Hand category from 0 (high card) to 8 (straight flush); see
+   * `riverrun_hand_rank_name`.
+   */
+  uint8_t rank;
+  /**
+   * Indices of the five cards making the hand.
+   */
+  uint8_t best_five[5];
+} RiverrunHand;
+
+/**
+ * Equity of a hand, written by `riverrun_equity`.
+ */
+typedef struct RiverrunEquity {
+  /**
+   * Share of the pot won on average, from 0 to 1, counting ties as split pots.
+   */
+  double equity;
+  /**
+   * Fraction of runouts won outright.
+   */
+  double win;
+  /**
+   * Fraction of runouts tied.
+   */
+  double tie;
+  /**
+   * Fraction of runouts lost.
+   */
+  double loss;
+  /**
+   * Runouts evaluated, enumerated or sampled.
+   */
+  uint64_t samples;
+} RiverrunEquity;
+
+/**
+ * Winners of a showdown, written by `riverrun_showdown`.
+ */
+typedef struct RiverrunShowdown {
+  /**
+   * Indices of the winning players; only the first `num_winners` are set.
+   */
+  uint8_t winners[RIVERRUN_MAX_PLAYERS];
+  /**
+   * Number of winners; more than one for a split pot.
+   */
+  uint8_t num_winners;
+  /**
+   * Strength of each player's best hand; only the first `num_players` are set.
+   */
+  uint16_t strengths[RIVERRUN_MAX_PLAYERS];
+} RiverrunShowdown;
+
+/**
+ * Evaluates the best five-card hand among `len` cards, five to seven.
+ *
+ * # Safety
+ *
+ * `cards` must point to `len` readable bytes and `out` to a writable `RiverrunHand`.
+ */
+enum RiverrunStatus riverrun_evaluate(const uint8_t *cards, size_t len, struct RiverrunHand *out);
+
+/**
+ * Equity of the two `hole_cards` on a board of `board_len` cards (0, 3, 4 or 5)
+ * against `opponents` random hands.
+ *
+ * Small calculations are enumerated exactly; larger ones draw `samples` Monte Carlo
+ * samples, or the default number when `samples` is 0.
+ *
+ * # Safety
+ *
+ * `hole_cards` must point to 2 readable bytes, `board` to `board_len` readable bytes
+ * (or be null when `board_len` is 0), and `out` to a writable `RiverrunEquity`.
+ */
+enum RiverrunStatus riverrun_equity(const uint8_t *hole_cards,
+                                    const uint8_t *board,
+                                    size_t board_len,
+                                    size_t opponents,
+                                    uint32_t samples,
+                                    struct RiverrunEquity *out);
+
+/**
+ * Winner(s) among `num_players` hands on a complete board.
+ *
+ * `hole_cards` holds two cards per player, the first player's two first.
+ *
+ * # Safety
+ *
+ * `hole_cards` must point to `2 * num_players` readable bytes, `board` to 5 readable
+ * bytes, and `out` to a writable `RiverrunShowdown`.
+ */
+enum RiverrunStatus riverrun_showdown(const uint8_t *hole_cards,
+                                      size_t num_players,
+                                      const uint8_t *board,
+                                      struct RiverrunShowdown *out);
+
+/**
+ * Name of hand category `rank` as a static NUL-terminated string, e.g.
+ * `"Full House"`, or null if `rank` is above 8.
+ */
+const char *riverrun_hand_rank_name(uint8_t rank);
+
+#endif  /* RIVERRUN_H */
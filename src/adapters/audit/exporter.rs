@@ -0,0 +1,363 @@
+//! Hash-chained audit records and the exporter that writes them.
+
+use std::error::Error;
+use std::fmt::{self, Write as _};
+use std::io::{self, BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::adapters::fair_shuffle::sha256;
+use crate::core::ports::outbound::{
+    EventStore, EventStoreError, GameId, StoredEvent, Timestamp, Version,
+};
+
+/// `prev_hash` of the first record in a trail.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Error returned when a trail cannot be exported or fails verification.
+#[derive(Debug)]
+pub enum AuditError {
+    /// The event stream could not be loaded.
+    Store(EventStoreError),
+    /// An event could not be serialized, or a record could not be parsed.
+    Serialization(String),
+    /// The trail could not be written or read.
+    Io(io::Error),
+    /// The record at `version` does not follow from the records before it.
+    Tampered {
+        /// Version of the first record that fails verification.
+        version: Version,
+        /// What is wrong with it.
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Store(err) => write!(f, "{err}"),
+            Self::Serialization(msg) => write!(f, "serialization error: {msg}"),
+            Self::Io(err) => write!(f, "i/o error: {err}"),
+            Self::Tampered { version, reason } => {
+                write!(f, "audit record {version} fails verification: {reason}")
+            }
+        }
+    }
+}
+
+impl Error for AuditError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Store(err) => Some(err),
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<EventStoreError> for AuditError {
+    fn from(err: EventStoreError) -> Self {
+        Self::Store(err)
+    }
+}
+
+impl From<serde_json::Error> for AuditError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Serialization(err.to_string())
+    }
+}
+
+impl From<io::Error> for AuditError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// One event of an audit trail, chained to the record before it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// The game the event belongs to.
+    pub game_id: GameId,
+    /// The event's version in its stream, starting at 1.
+    pub version: Version,
+    /// When the event was stored, in milliseconds since the epoch.
+    pub timestamp: Timestamp,
+    /// The event as JSON.
+    pub event: Value,
+    /// `hash` of the previous record, or `GENESIS_HASH` for the first.
+    pub prev_hash: String,
+    /// Hex SHA-256 of `prev_hash` followed by the JSON of the fields above it.
+    pub hash: String,
+}
+
+/// The hashed fields of an `AuditRecord`, in the order they are hashed.
+#[derive(Serialize)]
+struct Body<'a> {
+    game_id: &'a str,
+    version: Version,
+    timestamp: Timestamp,
+    event: &'a Value,
+}
+
+/// `AuditRecord` - Constructors
+impl AuditRecord {
+    /// Record for `stored`, chained after the record hashed `prev_hash`.
+    ///
+    /// # Errors
+    /// Returns `AuditError::Serialization` if the event cannot be converted to JSON.
+    pub fn new<E: Serialize>(stored: &StoredEvent<E>, prev_hash: &str) -> Result<Self, AuditError> {
+        let event = serde_json::to_value(&stored.event)?;
+        let mut record = Self {
+            game_id: stored.game_id.clone(),
+            version: stored.version,
+            timestamp: stored.timestamp,
+            event,
+            prev_hash: prev_hash.to_string(),
+            hash: String::new(),
+        };
+        record.hash = record.compute_hash()?;
+        Ok(record)
+    }
+}
+
+/// `AuditRecord` - Operations
+impl AuditRecord {
+    /// Recomputes the hash over this record's contents.
+    ///
+    /// # Errors
+    /// Returns `AuditError::Serialization` if the record cannot be converted to JSON.
+    pub fn compute_hash(&self) -> Result<String, AuditError> {
+        let body = serde_json::to_vec(&Body {
+            game_id: self.game_id.as_str(),
+            version: self.version,
+            timestamp: self.timestamp,
+            event: &self.event,
+        })?;
+        let mut input = Vec::with_capacity(self.prev_hash.len() + body.len());
+        input.extend_from_slice(self.prev_hash.as_bytes());
+        input.extend_from_slice(&body);
+        Ok(sha256(&input)
+            .iter()
+            .fold(String::with_capacity(64), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            }))
+    }
+}
+
+/// Exports a game's event stream as a hash-chained audit trail.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemoryEventStore;
+/// use riverrun::adapters::audit::{AuditExporter, verify_trail};
+/// use riverrun::core::ports::outbound::{EventStore, GameId};
+///
+/// let store = InMemoryEventStore::new();
+/// let game = GameId::from("game-1");
+/// store.append(&game, vec!["dealt As Kd", "paid seat 2"], 0).unwrap();
+///
+/// let exporter = AuditExporter::new(store);
+/// let mut trail = Vec::new();
+/// let head = exporter.export::<&str>(&game, &mut trail).unwrap();
+/// assert_eq!(verify_trail(trail.as_slice()).unwrap(), Some(head));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AuditExporter<S> {
+    store: S,
+}
+
+/// `AuditExporter` - Constructors
+impl<S> AuditExporter<S> {
+    /// Exporter reading events from `store`.
+    pub const fn new(store: S) -> Self {
+        Self { store }
+    }
+}
+
+/// `AuditExporter` - Operations
+impl<S> AuditExporter<S> {
+    /// Every event of `game_id`, in version order, as chained audit records.
+    ///
+    /// # Errors
+    /// Returns an error if the stream cannot be loaded or an event cannot be serialized.
+    pub fn records<E>(&self, game_id: &GameId) -> Result<Vec<AuditRecord>, AuditError>
+    where
+        S: EventStore<E>,
+        E: Serialize,
+    {
+        let mut prev_hash = GENESIS_HASH.to_string();
+        self.store
+            .load(game_id)?
+            .iter()
+            .map(|stored| {
+                let record = AuditRecord::new(stored, &prev_hash)?;
+                prev_hash.clone_from(&record.hash);
+                Ok(record)
+            })
+            .collect()
+    }
+
+    /// Writes the trail of `game_id` to `writer` as JSON lines and returns the chain
+    /// head: the hash of the last record.
+    ///
+    /// # Errors
+    /// Returns an error if the stream cannot be loaded, serialized or written.
+    pub fn export<E>(&self, game_id: &GameId, mut writer: impl Write) -> Result<String, AuditError>
+    where
+        S: EventStore<E>,
+        E: Serialize,
+    {
+        let mut head = GENESIS_HASH.to_string();
+        for record in self.records(game_id)? {
+            serde_json::to_writer(&mut writer, &record)?;
+            writer.write_all(b"\n")?;
+            head = record.hash;
+        }
+        writer.flush()?;
+        Ok(head)
+    }
+}
+
+/// Checks a trail written by `AuditExporter::export` and returns its chain head, or
+/// `None` for an empty trail.
+///
+/// Compare the head against one obtained independently, e.g. published when the game
+/// ended; a trail rewritten from scratch is consistent with itself but not with the
+/// original head.
+///
+/// # Errors
+/// Returns `AuditError::Tampered` for the first record that does not follow from the
+/// ones before it, or another error if the trail cannot be read or parsed.
+pub fn verify_trail(reader: impl BufRead) -> Result<Option<String>, AuditError> {
+    let mut prev: Option<AuditRecord> = None;
+    for line in reader.lines() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+        let record: AuditRecord = serde_json::from_str(&line)?;
+        let tampered = |reason| AuditError::Tampered {
+            version: record.version,
+            reason,
+        };
+        let (expected_version, expected_prev) = prev.as_ref().map_or((1, GENESIS_HASH), |prev| {
+            (prev.version + 1, prev.hash.as_str())
+        });
+        if record.version != expected_version {
+            return Err(tampered("version out of sequence"));
+        }
+        if prev
+            .as_ref()
+            .is_some_and(|prev| prev.game_id != record.game_id)
+        {
+            return Err(tampered("game id differs from the previous record"));
+        }
+        if record.prev_hash != expected_prev {
+            return Err(tampered("prev_hash does not match the previous record"));
+        }
+        if record.compute_hash()? != record.hash {
+            return Err(tampered("hash does not match the record's contents"));
+        }
+        prev = Some(record);
+    }
+    Ok(prev.map(|record| record.hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryEventStore;
+
+    fn trail() -> (Vec<String>, String) {
+        let store = InMemoryEventStore::new();
+        let game = GameId::from("game-1");
+        store
+            .append(
+                &game,
+                vec![
+                    "dealt As Kd".to_string(),
+                    "dealt Qh Qc".to_string(),
+                    "paid seat 2: 300".to_string(),
+                ],
+                0,
+            )
+            .unwrap();
+        let mut bytes = Vec::new();
+        let head = AuditExporter::new(store)
+            .export::<String>(&game, &mut bytes)
+            .unwrap();
+        let lines = String::from_utf8(bytes)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        (lines, head)
+    }
+
+    fn verify(lines: &[String]) -> Result<Option<String>, AuditError> {
+        verify_trail(lines.join("\n").as_bytes())
+    }
+
+    #[test]
+    fn test_export_chains_records() {
+        let (lines, head) = trail();
+        assert_eq!(lines.len(), 3);
+        let records: Vec<AuditRecord> = lines
+            .iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records[0].prev_hash, GENESIS_HASH);
+        assert_eq!(records[1].prev_hash, records[0].hash);
+        assert_eq!(records[2].version, 3);
+        assert_eq!(records[2].event, "paid seat 2: 300");
+        assert_eq!(verify(&lines).unwrap(), Some(head));
+    }
+
+    #[test]
+    fn test_verify_detects_tampering() {
+        let (lines, _) = trail();
+
+        let edited: Vec<String> = lines
+            .iter()
+            .map(|line| line.replace("paid seat 2: 300", "paid seat 2: 900"))
+            .collect();
+        assert!(matches!(
+            verify(&edited),
+            Err(AuditError::Tampered { version: 3, .. })
+        ));
+
+        let dropped = vec![lines[0].clone(), lines[2].clone()];
+        assert!(matches!(
+            verify(&dropped),
+            Err(AuditError::Tampered { version: 3, .. })
+        ));
+
+        // Rehashing an edited record still breaks the link to the next one
+        let mut record: AuditRecord = serde_json::from_str(&lines[0]).unwrap();
+        record.event = Value::from("dealt Ac Ad");
+        record.hash = record.compute_hash().unwrap();
+        let rehashed = vec![
+            serde_json::to_string(&record).unwrap(),
+            lines[1].clone(),
+            lines[2].clone(),
+        ];
+        assert!(matches!(
+            verify(&rehashed),
+            Err(AuditError::Tampered { version: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_missing_stream_and_empty_trail() {
+        let store: InMemoryEventStore<String> = InMemoryEventStore::new();
+        let game = GameId::from("missing");
+        assert!(matches!(
+            AuditExporter::new(store).export::<String>(&game, io::sink()),
+            Err(AuditError::Store(EventStoreError::NotFound(_)))
+        ));
+        assert_eq!(verify_trail(&b""[..]).unwrap(), None);
+    }
+}
@@ -0,0 +1,11 @@
+//! Tamper-evident audit trails exported from the event store.
+//!
+//! Each event of a game becomes an `AuditRecord`: a JSON line holding the event with
+//! its version and timestamp, the hash of the previous record and its own SHA-256
+//! hash over both. Changing, dropping or reordering any record breaks every hash after
+//! it, so a reviewer holding the final hash (the chain head) can confirm that the
+//! dealt cards and payouts in the trail are exactly what was recorded.
+
+mod exporter;
+
+pub use exporter::{AuditError, AuditExporter, AuditRecord, GENESIS_HASH, verify_trail};
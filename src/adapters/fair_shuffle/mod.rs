@@ -0,0 +1,14 @@
+//! Commit-reveal shuffling whose fairness clients can check after the hand.
+//!
+//! Before dealing, the server publishes a `SeedCommitment`: the SHA-256 hash of the
+//! seed it will shuffle with. After the hand it reveals the seed, and anyone can
+//! confirm it matches the commitment and recompute the shuffle from it. The seed
+//! drives a fully specified SHA-256 counter stream rather than a library RNG, so
+//! the shuffle can be recomputed by any implementation of the same steps.
+
+mod sha256;
+mod shuffler;
+
+#[cfg(feature = "serde")]
+pub(crate) use sha256::sha256;
+pub use shuffler::{SEED_LEN, SeedCommitment, VerifiableShuffler};
@@ -0,0 +1,297 @@
+//! Seed commitments and the shuffler that honours them.
+
+use std::fmt;
+
+use rand::Rng;
+
+use super::sha256::sha256;
+use crate::core::domain::entities::card::Card;
+use crate::core::ports::outbound::RandomSource;
+
+/// Length in bytes of a shuffle seed.
+pub const SEED_LEN: usize = 32;
+
+/// SHA-256 hash of a shuffle seed, published before dealing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SeedCommitment([u8; 32]);
+
+/// `SeedCommitment` - Constructors
+impl SeedCommitment {
+    /// Commitment to `seed`.
+    #[must_use]
+    pub fn for_seed(seed: &[u8; SEED_LEN]) -> Self {
+        Self(sha256(seed))
+    }
+
+    /// Parses a commitment from 64 hex digits, as written by `Display`.
+    #[must_use]
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        if hex.len() != 64 || !hex.is_ascii() {
+            return None;
+        }
+        let mut digest = [0; 32];
+        for (byte, pair) in digest.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+            let pair = std::str::from_utf8(pair).ok()?;
+            *byte = u8::from_str_radix(pair, 16).ok()?;
+        }
+        Some(Self(digest))
+    }
+}
+
+/// `SeedCommitment` - Accessors
+impl SeedCommitment {
+    /// Raw digest bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Whether `seed` is the seed this commits to.
+    #[must_use]
+    pub fn matches(&self, seed: &[u8; SEED_LEN]) -> bool {
+        *self == Self::for_seed(seed)
+    }
+}
+
+impl fmt::Display for SeedCommitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+/// A `RandomSource` whose shuffles can be verified once its seed is revealed.
+///
+/// Publish `commitment()` before the hand, deal with the shuffler, then `reveal()`
+/// the seed. Clients check the deal with [`VerifiableShuffler::verify`]. Use a
+/// fresh shuffler per hand: verification recomputes the first shuffle drawn from
+/// the seed.
+///
+/// Random numbers come from SHA-256 over the seed followed by a little-endian
+/// 64-bit block counter, read as little-endian 64-bit words. Cards are shuffled by
+/// Fisher-Yates from the back of the slice, each index drawn by rejection sampling
+/// so every permutation is equally likely.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::fair_shuffle::VerifiableShuffler;
+/// use riverrun::core::domain::entities::deck::Deck;
+///
+/// let mut shuffler = VerifiableShuffler::new([7; 32]);
+/// let commitment = shuffler.commitment();
+///
+/// let mut deck = Deck::new();
+/// deck.shuffle_with(&mut shuffler);
+///
+/// let seed = shuffler.reveal();
+/// assert!(VerifiableShuffler::verify(&commitment, &seed, Deck::new().cards(), deck.cards()));
+/// ```
+pub struct VerifiableShuffler {
+    seed: [u8; SEED_LEN],
+    commitment: SeedCommitment,
+    counter: u64,
+    block: [u8; 32],
+    used: usize,
+}
+
+/// `VerifiableShuffler` - Constructors
+impl VerifiableShuffler {
+    /// Shuffler drawing from `seed`.
+    #[must_use]
+    pub fn new(seed: [u8; SEED_LEN]) -> Self {
+        Self {
+            seed,
+            commitment: SeedCommitment::for_seed(&seed),
+            counter: 0,
+            block: [0; 32],
+            used: 32,
+        }
+    }
+
+    /// Shuffler drawing from a seed taken from `rng`.
+    #[must_use]
+    pub fn from_rng<R: rand::CryptoRng>(rng: &mut R) -> Self {
+        let mut seed = [0; SEED_LEN];
+        rng.fill(&mut seed);
+        Self::new(seed)
+    }
+
+    /// Shuffler drawing from a seed taken from the thread's cryptographic generator.
+    #[must_use]
+    pub fn generate() -> Self {
+        Self::from_rng(&mut rand::rng())
+    }
+}
+
+/// `VerifiableShuffler` - Accessors
+impl VerifiableShuffler {
+    /// Commitment to publish before dealing.
+    #[must_use]
+    pub const fn commitment(&self) -> SeedCommitment {
+        self.commitment
+    }
+}
+
+/// `VerifiableShuffler` - Operations
+impl VerifiableShuffler {
+    /// Ends the hand, giving up the seed for clients to verify with.
+    #[must_use]
+    pub const fn reveal(self) -> [u8; SEED_LEN] {
+        self.seed
+    }
+
+    /// `cards` as shuffled by the first shuffle drawn from `seed`.
+    #[must_use]
+    pub fn shuffled(seed: &[u8; SEED_LEN], cards: &[Card]) -> Vec<Card> {
+        let mut cards = cards.to_vec();
+        Self::new(*seed).shuffle_cards(&mut cards);
+        cards
+    }
+
+    /// Whether `seed` is the one `commitment` was made to and shuffles `original`
+    /// into `shuffled`.
+    #[must_use]
+    pub fn verify(
+        commitment: &SeedCommitment,
+        seed: &[u8; SEED_LEN],
+        original: &[Card],
+        shuffled: &[Card],
+    ) -> bool {
+        commitment.matches(seed) && Self::shuffled(seed, original) == shuffled
+    }
+}
+
+/// `VerifiableShuffler` - Helpers
+impl VerifiableShuffler {
+    /// Next word of the seed's SHA-256 counter stream.
+    fn next_u64(&mut self) -> u64 {
+        if self.used + 8 > self.block.len() {
+            let mut input = [0; SEED_LEN + 8];
+            input[..SEED_LEN].copy_from_slice(&self.seed);
+            input[SEED_LEN..].copy_from_slice(&self.counter.to_le_bytes());
+            self.block = sha256(&input);
+            self.counter += 1;
+            self.used = 0;
+        }
+        let mut word = [0; 8];
+        word.copy_from_slice(&self.block[self.used..self.used + 8]);
+        self.used += 8;
+        u64::from_le_bytes(word)
+    }
+
+    /// Uniform draw from `0..bound`, rejecting words from the incomplete top range.
+    fn below(&mut self, bound: u64) -> u64 {
+        let limit = u64::MAX - u64::MAX % bound;
+        loop {
+            let word = self.next_u64();
+            if word < limit {
+                return word % bound;
+            }
+        }
+    }
+}
+
+impl RandomSource for VerifiableShuffler {
+    fn shuffle_cards(&mut self, cards: &mut [Card]) {
+        for i in (1..cards.len()).rev() {
+            let j = self.below(i as u64 + 1);
+            cards.swap(i, usize::try_from(j).unwrap_or(i));
+        }
+    }
+
+    fn random_index(&mut self, max: usize) -> usize {
+        if max == 0 {
+            return 0;
+        }
+        usize::try_from(self.below(max as u64)).unwrap_or(0)
+    }
+}
+
+impl fmt::Debug for VerifiableShuffler {
+    // The seed stays hidden until revealed
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerifiableShuffler")
+            .field("commitment", &self.commitment)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::deck::Deck;
+    use crate::core::domain::entities::game::Game;
+
+    #[test]
+    fn test_commitment_round_trips_through_hex() {
+        let commitment = VerifiableShuffler::new([1; SEED_LEN]).commitment();
+        let hex = commitment.to_string();
+        assert_eq!(hex.len(), 64);
+        assert_eq!(SeedCommitment::from_hex(&hex), Some(commitment));
+        assert_eq!(SeedCommitment::from_hex("not hex"), None);
+        assert!(commitment.matches(&[1; SEED_LEN]));
+        assert!(!commitment.matches(&[2; SEED_LEN]));
+    }
+
+    #[test]
+    fn test_verify_accepts_only_the_committed_deal() {
+        let mut shuffler = VerifiableShuffler::new([42; SEED_LEN]);
+        let commitment = shuffler.commitment();
+        let mut deck = Deck::new();
+        deck.shuffle_with(&mut shuffler);
+        let seed = shuffler.reveal();
+
+        let original = Deck::new();
+        assert_ne!(deck.cards(), original.cards());
+        assert!(VerifiableShuffler::verify(
+            &commitment,
+            &seed,
+            original.cards(),
+            deck.cards()
+        ));
+
+        // Any other seed or deal fails
+        let other = [43; SEED_LEN];
+        assert!(!VerifiableShuffler::verify(
+            &commitment,
+            &other,
+            original.cards(),
+            deck.cards()
+        ));
+        let mut tampered = deck.to_vec();
+        tampered.swap(0, 1);
+        assert!(!VerifiableShuffler::verify(
+            &commitment,
+            &seed,
+            original.cards(),
+            &tampered
+        ));
+    }
+
+    #[test]
+    fn test_shuffle_is_a_permutation_that_depends_on_the_seed() {
+        let original = Deck::new();
+        let first = VerifiableShuffler::shuffled(&[3; SEED_LEN], original.cards());
+        let again = VerifiableShuffler::shuffled(&[3; SEED_LEN], original.cards());
+        let other = VerifiableShuffler::shuffled(&[4; SEED_LEN], original.cards());
+        assert_eq!(first, again);
+        assert_ne!(first, other);
+
+        let mut sorted = first;
+        sorted.sort_by_key(ToString::to_string);
+        let mut expected = original.to_vec();
+        expected.sort_by_key(ToString::to_string);
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn test_game_deals_from_shuffler() {
+        let mut shuffler = VerifiableShuffler::new([9; SEED_LEN]);
+        let mut game = Game::new(2, &mut shuffler).unwrap();
+        game.deal_hole_cards();
+        let expected = VerifiableShuffler::shuffled(&shuffler.reveal(), Deck::new().cards());
+        let mut replay = Game::with_deck(2, Deck::from_cards(expected)).unwrap();
+        replay.deal_hole_cards();
+        assert_eq!(game.all_hole_cards(), replay.all_hole_cards());
+    }
+}
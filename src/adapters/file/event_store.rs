@@ -0,0 +1,447 @@
+//! File-backed event store adapter writing one JSON-lines file per game.
+
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::core::ports::outbound::{
+    Clock, EventStore, EventStoreError, GameId, StoredEvent, SystemClock, Timestamp, Version,
+};
+use crate::telemetry::{self, Timer};
+
+/// File extension used for per-game event logs.
+const EXTENSION: &str = "jsonl";
+
+/// One line of an event log. The game id is implied by the file name.
+#[derive(Serialize, Deserialize)]
+struct Record<E> {
+    version: Version,
+    timestamp: Timestamp,
+    event: E,
+}
+
+/// Byte offsets of the committed lines in a game's log.
+#[derive(Debug, Default)]
+struct StreamIndex {
+    /// Start offset of the line holding version `i + 1`.
+    offsets: Vec<u64>,
+    /// Offset just past the last complete line.
+    end: u64,
+}
+
+impl StreamIndex {
+    const fn version(&self) -> Version {
+        self.offsets.len() as Version
+    }
+}
+
+/// Event store persisting each game's events as JSON lines under a directory.
+///
+/// Every append is written in a single `write_all` and flushed to disk with
+/// `sync_data` before the new version is returned. A line without a trailing
+/// newline is the remnant of an interrupted append; it is ignored when reading
+/// and truncated away by the next append, so a crash never exposes a partial
+/// batch.
+///
+/// Byte offsets of each event are indexed on first access to a game, which lets
+/// `load_from` seek straight to the requested version.
+///
+/// The store assumes it is the only writer of its directory.
+#[derive(Debug)]
+pub struct FileEventStore<E, C: Clock = SystemClock> {
+    root: PathBuf,
+    indexes: Mutex<HashMap<GameId, StreamIndex>>,
+    clock: C,
+    _events: PhantomData<fn() -> E>,
+}
+
+/// `FileEventStore` - Constructors
+impl<E> FileEventStore<E> {
+    /// Open (creating if needed) a store rooted at `root`, timestamping events with the system clock.
+    ///
+    /// # Errors
+    /// Returns `EventStoreError::StorageError` if the directory cannot be created.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self, EventStoreError> {
+        Self::open_with_clock(root, SystemClock)
+    }
+}
+
+/// `FileEventStore` - Constructors
+impl<E, C: Clock> FileEventStore<E, C> {
+    /// Open (creating if needed) a store rooted at `root`, timestamping events with `clock`.
+    ///
+    /// # Errors
+    /// Returns `EventStoreError::StorageError` if the directory cannot be created.
+    pub fn open_with_clock(root: impl Into<PathBuf>, clock: C) -> Result<Self, EventStoreError> {
+        let root = root.into();
+        fs::create_dir_all(&root).map_err(storage)?;
+        Ok(Self {
+            root,
+            indexes: Mutex::new(HashMap::new()),
+            clock,
+            _events: PhantomData,
+        })
+    }
+}
+
+/// `FileEventStore` - Accessors
+impl<E, C: Clock> FileEventStore<E, C> {
+    /// Directory holding the event logs.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Path of the event log for `game_id`.
+    ///
+    /// # Errors
+    /// Returns `EventStoreError::StorageError` if the id cannot be used as a file name.
+    pub fn path_for(&self, game_id: &GameId) -> Result<PathBuf, EventStoreError> {
+        let game_id = game_id.as_str();
+        let valid = !game_id.is_empty()
+            && !game_id.starts_with('.')
+            && game_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        if !valid {
+            return Err(EventStoreError::StorageError(format!(
+                "invalid game id for file storage: {game_id:?}"
+            )));
+        }
+        Ok(self.root.join(format!("{game_id}.{EXTENSION}")))
+    }
+}
+
+/// `FileEventStore` - Operations
+impl<E, C: Clock> FileEventStore<E, C> {
+    /// Return the cached index for `game_id`, scanning its log on first access.
+    fn index<'a>(
+        &self,
+        indexes: &'a mut HashMap<GameId, StreamIndex>,
+        game_id: &GameId,
+    ) -> Result<&'a mut StreamIndex, EventStoreError> {
+        let cached = indexes.contains_key(game_id);
+        telemetry::cache_lookup("file_event_store_index", cached);
+        if !cached {
+            let index = Self::scan(&self.path_for(game_id)?)?;
+            indexes.insert(game_id.clone(), index);
+        }
+        Ok(indexes.get_mut(game_id).expect("index inserted above"))
+    }
+
+    /// Build an index of the complete lines in the log at `path`.
+    fn scan(path: &Path) -> Result<StreamIndex, EventStoreError> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(StreamIndex::default()),
+            Err(err) => return Err(storage(err)),
+        };
+
+        let mut reader = BufReader::new(file);
+        let mut index = StreamIndex::default();
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            let read = reader.read_until(b'\n', &mut line).map_err(storage)?;
+            if read == 0 || line.last() != Some(&b'\n') {
+                break;
+            }
+            index.offsets.push(index.end);
+            index.end += read as u64;
+        }
+        Ok(index)
+    }
+
+    /// Read the records between `start` and `end` bytes of the log at `path`.
+    fn read_range(
+        path: &Path,
+        game_id: &GameId,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<StoredEvent<E>>, EventStoreError>
+    where
+        E: DeserializeOwned,
+    {
+        let mut file = File::open(path).map_err(storage)?;
+        file.seek(SeekFrom::Start(start)).map_err(storage)?;
+
+        BufReader::new(file.take(end - start))
+            .lines()
+            .map(|line| {
+                let line = line.map_err(storage)?;
+                let record: Record<E> = serde_json::from_str(&line)
+                    .map_err(|err| EventStoreError::SerializationError(err.to_string()))?;
+                Ok(StoredEvent::new(record.event, record.version, record.timestamp, game_id.clone()))
+            })
+            .collect()
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn storage(err: io::Error) -> EventStoreError {
+    EventStoreError::StorageError(err.to_string())
+}
+
+fn poisoned<T>(_: PoisonError<T>) -> EventStoreError {
+    EventStoreError::StorageError("event store lock poisoned".to_string())
+}
+
+impl<E, C> EventStore<E> for FileEventStore<E, C>
+where
+    E: Serialize + DeserializeOwned,
+    C: Clock,
+{
+    fn append(
+        &self,
+        game_id: &GameId,
+        events: Vec<E>,
+        expected_version: Version,
+    ) -> Result<Version, EventStoreError> {
+        let timer = Timer::start();
+        let path = self.path_for(game_id)?;
+        let mut indexes = self.indexes.lock().map_err(poisoned)?;
+        let index = self.index(&mut indexes, game_id)?;
+
+        let actual = index.version();
+        if actual != expected_version {
+            return Err(EventStoreError::ConcurrencyConflict {
+                expected: expected_version,
+                actual,
+            });
+        }
+        if events.is_empty() {
+            return Ok(actual);
+        }
+
+        let timestamp = self.clock.now();
+        let mut buffer = Vec::new();
+        let mut line_lengths = Vec::with_capacity(events.len());
+        for (version, event) in (actual + 1..).zip(events) {
+            let before = buffer.len();
+            serde_json::to_writer(&mut buffer, &Record { version, timestamp, event })
+                .map_err(|err| EventStoreError::SerializationError(err.to_string()))?;
+            buffer.push(b'\n');
+            line_lengths.push((buffer.len() - before) as u64);
+        }
+
+        let created = !path.exists();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(&path)
+            .map_err(storage)?;
+        // Drop any torn line left behind by an interrupted append.
+        file.set_len(index.end).map_err(storage)?;
+        file.seek(SeekFrom::Start(index.end)).map_err(storage)?;
+        file.write_all(&buffer).map_err(storage)?;
+        file.sync_data().map_err(storage)?;
+        if created {
+            sync_dir(&self.root)?;
+        }
+
+        let appended = line_lengths.len();
+        for length in line_lengths {
+            index.offsets.push(index.end);
+            index.end += length;
+        }
+        let version = index.version();
+        drop(indexes);
+        telemetry::event_store_append("file", appended, timer);
+        Ok(version)
+    }
+
+    fn load(&self, game_id: &GameId) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        let events = self.load_from(game_id, 0)?;
+        if events.is_empty() {
+            return Err(EventStoreError::NotFound(game_id.clone()));
+        }
+        Ok(events)
+    }
+
+    fn load_from(
+        &self,
+        game_id: &GameId,
+        from_version: Version,
+    ) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        let path = self.path_for(game_id)?;
+        let mut indexes = self.indexes.lock().map_err(poisoned)?;
+        let index = self.index(&mut indexes, game_id)?;
+        let range = usize::try_from(from_version)
+            .ok()
+            .and_then(|skip| index.offsets.get(skip))
+            .map(|&start| (start, index.end));
+        drop(indexes);
+
+        match range {
+            Some((start, end)) => Self::read_range(&path, game_id, start, end),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn version(&self, game_id: &GameId) -> Result<Version, EventStoreError> {
+        let mut indexes = self.indexes.lock().map_err(poisoned)?;
+        Ok(self.index(&mut indexes, game_id)?.version())
+    }
+
+    fn exists(&self, game_id: &GameId) -> bool {
+        self.version(game_id).is_ok_and(|version| version > 0)
+    }
+}
+
+/// Flush a directory entry so a newly created log survives a crash.
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> Result<(), EventStoreError> {
+    File::open(dir).and_then(|dir| dir.sync_all()).map_err(storage)
+}
+
+#[cfg(not(unix))]
+#[allow(clippy::unnecessary_wraps)]
+const fn sync_dir(_dir: &Path) -> Result<(), EventStoreError> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::core::ports::outbound::FixedClock;
+
+    /// Scratch directory removed when dropped.
+    struct ScratchDir(PathBuf);
+
+    impl ScratchDir {
+        fn new() -> Self {
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let path = std::env::temp_dir().join(format!(
+                "riverrun-file-store-{}-{}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            Self(path)
+        }
+    }
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn game(id: &str) -> GameId {
+        GameId::new(id)
+    }
+
+    #[test]
+    fn test_append_and_load_round_trip() {
+        let dir = ScratchDir::new();
+        let store = FileEventStore::open_with_clock(&dir.0, FixedClock::new(7)).unwrap();
+        let id = game("g1");
+
+        assert_eq!(store.append(&id, vec!["a".to_string(), "b".to_string()], 0), Ok(2));
+        assert_eq!(store.append(&id, vec!["c".to_string()], 2), Ok(3));
+
+        let events = store.load(&id).unwrap();
+        let payloads: Vec<&str> = events.iter().map(|e| e.event.as_str()).collect();
+        assert_eq!(payloads, vec!["a", "b", "c"]);
+        assert!(events.iter().all(|e| e.timestamp == 7 && e.game_id == id));
+        assert_eq!(events[2].version, 3);
+    }
+
+    #[test]
+    fn test_reopen_recovers_versions() {
+        let dir = ScratchDir::new();
+        let id = game("g1");
+        {
+            let store = FileEventStore::open(&dir.0).unwrap();
+            store.append(&id, vec![1u32, 2, 3], 0).unwrap();
+        }
+
+        let store: FileEventStore<u32> = FileEventStore::open(&dir.0).unwrap();
+        assert_eq!(store.version(&id), Ok(3));
+        assert!(store.exists(&id));
+        assert_eq!(store.append(&id, vec![4], 3), Ok(4));
+    }
+
+    #[test]
+    fn test_append_rejects_stale_version() {
+        let dir = ScratchDir::new();
+        let store = FileEventStore::open(&dir.0).unwrap();
+        let id = game("g1");
+        store.append(&id, vec![1u8], 0).unwrap();
+
+        assert_eq!(
+            store.append(&id, vec![2], 0),
+            Err(EventStoreError::ConcurrencyConflict {
+                expected: 0,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_from_seeks_past_earlier_events() {
+        let dir = ScratchDir::new();
+        let store = FileEventStore::open(&dir.0).unwrap();
+        let id = game("g1");
+        store.append(&id, vec![10u16, 20, 30, 40], 0).unwrap();
+
+        let tail: Vec<u16> = store.load_from(&id, 2).unwrap().into_iter().map(|e| e.event).collect();
+        assert_eq!(tail, vec![30, 40]);
+        assert!(store.load_from(&id, 4).unwrap().is_empty());
+        assert!(store.load_from(&id, 100).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_missing_game() {
+        let dir = ScratchDir::new();
+        let store: FileEventStore<u8> = FileEventStore::open(&dir.0).unwrap();
+        let id = game("missing");
+
+        assert_eq!(store.load(&id).unwrap_err(), EventStoreError::NotFound(id.clone()));
+        assert_eq!(store.version(&id), Ok(0));
+        assert!(!store.exists(&id));
+    }
+
+    #[test]
+    fn test_torn_line_is_ignored_and_overwritten() {
+        let dir = ScratchDir::new();
+        let id = game("g1");
+        {
+            let store = FileEventStore::open(&dir.0).unwrap();
+            store.append(&id, vec![1u8, 2], 0).unwrap();
+            let mut file = OpenOptions::new()
+                .append(true)
+                .open(store.path_for(&id).unwrap())
+                .unwrap();
+            file.write_all(br#"{"version":3,"timest"#).unwrap();
+        }
+
+        let store: FileEventStore<u8> = FileEventStore::open(&dir.0).unwrap();
+        assert_eq!(store.version(&id), Ok(2));
+        assert_eq!(store.append(&id, vec![3], 2), Ok(3));
+
+        let events: Vec<u8> = store.load(&id).unwrap().into_iter().map(|e| e.event).collect();
+        assert_eq!(events, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_rejects_unsafe_game_ids() {
+        let dir = ScratchDir::new();
+        let store: FileEventStore<u8> = FileEventStore::open(&dir.0).unwrap();
+
+        for id in ["", "../escape", "a/b", ".hidden"] {
+            assert!(matches!(
+                store.append(&game(id), vec![1], 0),
+                Err(EventStoreError::StorageError(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! File-backed adapters for single-node persistence.
+
+mod event_store;
+mod result_cache;
+
+pub use event_store::FileEventStore;
+pub use result_cache::FileResultCache;
@@ -0,0 +1,278 @@
+//! File-backed result cache adapter writing one file per entry.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::core::ports::outbound::{CacheKey, ResultCache, ResultCacheError, CACHE_FORMAT_VERSION};
+use crate::telemetry;
+
+/// File extension used for cache entries.
+const EXTENSION: &str = "bin";
+
+/// Bytes every entry starts with, before the format version and the key.
+const MAGIC: &[u8; 4] = b"RRC\0";
+
+/// Result cache persisting each entry as a file under a directory, so results
+/// survive between sessions.
+///
+/// Entries are written to a temporary file and renamed into place, so a crash never
+/// leaves a partial entry behind. Each entry records the cache format version and its
+/// key's encoding, and one written by another version, or for another request whose
+/// key shares the same hash, is treated as a miss. Reading an entry marks it as used;
+/// once more than `capacity` entries are stored, the least recently used are deleted.
+///
+/// The cache assumes it is the only writer of its directory.
+#[derive(Debug)]
+pub struct FileResultCache {
+    root: PathBuf,
+    capacity: usize,
+}
+
+/// `FileResultCache` - Constructors
+impl FileResultCache {
+    /// Open (creating if needed) a cache rooted at `root` holding at most `capacity`
+    /// entries, and at least one.
+    ///
+    /// # Errors
+    /// Returns `ResultCacheError::StorageError` if the directory cannot be created.
+    pub fn open(root: impl Into<PathBuf>, capacity: usize) -> Result<Self, ResultCacheError> {
+        let root = root.into();
+        fs::create_dir_all(&root).map_err(storage)?;
+        Ok(Self {
+            root,
+            capacity: capacity.max(1),
+        })
+    }
+}
+
+/// `FileResultCache` - Accessors
+impl FileResultCache {
+    /// Directory holding the entries.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Most entries kept on disk.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Path of the entry for `key`.
+    #[must_use]
+    pub fn path_for(&self, key: &CacheKey) -> PathBuf {
+        self.root.join(format!("{key}.{EXTENSION}"))
+    }
+
+    /// Number of entries stored.
+    ///
+    /// # Errors
+    /// Returns `ResultCacheError::StorageError` if the directory cannot be read.
+    pub fn len(&self) -> Result<usize, ResultCacheError> {
+        Ok(self.entries()?.len())
+    }
+
+    /// Whether nothing is stored.
+    ///
+    /// # Errors
+    /// Returns `ResultCacheError::StorageError` if the directory cannot be read.
+    pub fn is_empty(&self) -> Result<bool, ResultCacheError> {
+        Ok(self.len()? == 0)
+    }
+}
+
+/// `FileResultCache` - Operations
+impl FileResultCache {
+    /// Every entry's path with the time it was last used.
+    fn entries(&self) -> Result<Vec<(SystemTime, PathBuf)>, ResultCacheError> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&self.root).map_err(storage)? {
+            let path = entry.map_err(storage)?.path();
+            if path.extension().is_some_and(|extension| extension == EXTENSION) {
+                // An entry deleted since the listing is simply skipped
+                if let Ok(used) = fs::metadata(&path).and_then(|metadata| metadata.modified()) {
+                    entries.push((used, path));
+                }
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Delete the least recently used entries beyond `capacity`.
+    fn evict(&self) -> Result<(), ResultCacheError> {
+        let mut entries = self.entries()?;
+        if entries.len() <= self.capacity {
+            return Ok(());
+        }
+        entries.sort();
+        let excess = entries.len() - self.capacity;
+        for (_, path) in entries.into_iter().take(excess) {
+            remove(&path)?;
+        }
+        Ok(())
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn storage(err: io::Error) -> ResultCacheError {
+    ResultCacheError::StorageError(err.to_string())
+}
+
+/// Delete the file at `path`, if it is still there.
+fn remove(path: &Path) -> Result<(), ResultCacheError> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(storage(err)),
+        _ => Ok(()),
+    }
+}
+
+/// The header written before the value of the entry for `key`.
+fn header(key: &CacheKey) -> Vec<u8> {
+    let encoding = key.encoding();
+    let mut header = Vec::with_capacity(MAGIC.len() + 4 + 8 + encoding.len());
+    header.extend_from_slice(MAGIC);
+    header.extend_from_slice(&CACHE_FORMAT_VERSION.to_le_bytes());
+    header.extend_from_slice(&(encoding.len() as u64).to_le_bytes());
+    header.extend_from_slice(encoding);
+    header
+}
+
+impl ResultCache for FileResultCache {
+    fn get(&self, key: &CacheKey) -> Result<Option<Vec<u8>>, ResultCacheError> {
+        let path = self.path_for(key);
+        let mut bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                telemetry::cache_lookup("file_result_cache", false);
+                return Ok(None);
+            }
+            Err(err) => return Err(storage(err)),
+        };
+
+        let header = header(key);
+        let hit = bytes.starts_with(&header);
+        telemetry::cache_lookup("file_result_cache", hit);
+        if !hit {
+            return Ok(None);
+        }
+        // Recording the use is best effort: a stale time only makes eviction less exact
+        if let Ok(file) = File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Ok(Some(bytes.split_off(header.len())))
+    }
+
+    fn put(&self, key: &CacheKey, value: &[u8]) -> Result<(), ResultCacheError> {
+        let path = self.path_for(key);
+        let partial = path.with_extension("tmp");
+        let mut file = File::create(&partial).map_err(storage)?;
+        file.write_all(&header(key)).map_err(storage)?;
+        file.write_all(value).map_err(storage)?;
+        file.sync_data().map_err(storage)?;
+        fs::rename(&partial, &path).map_err(storage)?;
+        self.evict()
+    }
+
+    fn clear(&self) -> Result<(), ResultCacheError> {
+        for (_, path) in self.entries()? {
+            remove(&path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Scratch directory removed when dropped.
+    struct ScratchDir(PathBuf);
+
+    impl ScratchDir {
+        fn new() -> Self {
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let path = std::env::temp_dir().join(format!(
+                "riverrun-result-cache-{}-{}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            Self(path)
+        }
+    }
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn key(name: &str) -> CacheKey {
+        CacheKey::builder(name).finish()
+    }
+
+    /// Backdate the entry for `key` by `seconds`, since file times may be coarse.
+    fn age(cache: &FileResultCache, key: &CacheKey, seconds: u64) {
+        let file = File::options().write(true).open(cache.path_for(key)).unwrap();
+        file.set_modified(SystemTime::now() - Duration::from_secs(seconds)).unwrap();
+    }
+
+    #[test]
+    fn test_entries_survive_reopening() {
+        let dir = ScratchDir::new();
+        let cache = FileResultCache::open(&dir.0, 10).unwrap();
+        assert_eq!(cache.get(&key("a")).unwrap(), None);
+        cache.put(&key("a"), b"result").unwrap();
+        drop(cache);
+
+        let reopened = FileResultCache::open(&dir.0, 10).unwrap();
+        assert_eq!(reopened.get(&key("a")).unwrap(), Some(b"result".to_vec()));
+        assert_eq!(reopened.len().unwrap(), 1);
+        reopened.clear().unwrap();
+        assert!(reopened.is_empty().unwrap());
+    }
+
+    #[test]
+    fn test_least_recently_used_entries_are_evicted() {
+        let dir = ScratchDir::new();
+        let cache = FileResultCache::open(&dir.0, 2).unwrap();
+        cache.put(&key("a"), b"1").unwrap();
+        cache.put(&key("b"), b"2").unwrap();
+        age(&cache, &key("a"), 20);
+        age(&cache, &key("b"), 10);
+
+        // Reading "a" makes "b" the least recently used
+        assert!(cache.get(&key("a")).unwrap().is_some());
+        cache.put(&key("c"), b"3").unwrap();
+        assert_eq!(cache.get(&key("b")).unwrap(), None);
+        assert!(cache.get(&key("a")).unwrap().is_some());
+        assert_eq!(cache.len().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_entries_from_other_versions_are_missed() {
+        let dir = ScratchDir::new();
+        let cache = FileResultCache::open(&dir.0, 10).unwrap();
+        let mut stale = header(&key("a"));
+        stale[4..8].copy_from_slice(&(CACHE_FORMAT_VERSION + 1).to_le_bytes());
+        stale.extend_from_slice(b"old");
+        fs::write(cache.path_for(&key("a")), stale).unwrap();
+        assert_eq!(cache.get(&key("a")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_entries_for_colliding_keys_are_missed() {
+        let dir = ScratchDir::new();
+        let cache = FileResultCache::open(&dir.0, 10).unwrap();
+        // An entry for another request stored where "a" would be, as on a hash collision
+        let mut other = header(&key("b"));
+        other.extend_from_slice(b"other");
+        fs::write(cache.path_for(&key("a")), other).unwrap();
+        assert_eq!(cache.get(&key("a")).unwrap(), None);
+    }
+}
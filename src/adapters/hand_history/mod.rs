@@ -0,0 +1,12 @@
+//! PokerStars-style hand history text.
+//!
+//! The PokerStars-style layout is the de facto interchange format for hand histories
+//! and is understood by most hand trackers. Hands can be written out for trackers and
+//! read back in, including GG Poker exports, to replay and analyze imported play.
+
+mod parser;
+mod timestamp;
+mod writer;
+
+pub use parser::{Collection, HandHistoryParser, ParseError, ParsedHand};
+pub use writer::{HandHistoryError, HandHistoryWriter};
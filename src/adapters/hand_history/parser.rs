@@ -0,0 +1,877 @@
+//! Reads PokerStars-style hand history text back into domain events.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::core::domain::aggregates::{GameAggregate, GameCommand, GameEvent};
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::deck::{Deck, ScriptedDeck};
+use crate::core::domain::errors::{CardError, GameError};
+use crate::core::domain::primitives::{Action, Chips, HandNumber, PlayerId};
+use crate::core::ports::outbound::{GameId, HandSummary, Timestamp};
+
+use super::timestamp::parse_timestamp;
+
+/// Suffix marking an action that put the player all-in.
+const ALL_IN_SUFFIX: &str = " and is all-in";
+
+/// Error returned when hand history text cannot be imported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The text contains no hand.
+    Empty,
+    /// The first line is not a recognizable hand header.
+    InvalidHeader(String),
+    /// The hand uses a game or rule that is not modelled, such as antes or Omaha.
+    Unsupported(String),
+    /// A line could not be understood.
+    InvalidLine { line: usize, text: String },
+    /// The same card appears twice among the revealed cards.
+    DuplicateCard(Card),
+    /// An action broke the rules of the hand as replayed.
+    IllegalAction { line: usize, source: GameError },
+    /// The history ends before the hand is over.
+    Incomplete,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no hand found"),
+            Self::InvalidHeader(header) => write!(f, "invalid hand header: {header}"),
+            Self::Unsupported(what) => write!(f, "unsupported: {what}"),
+            Self::InvalidLine { line, text } => write!(f, "line {line}: cannot parse '{text}'"),
+            Self::DuplicateCard(card) => write!(f, "card {card} appears twice"),
+            Self::IllegalAction { line, source } => write!(f, "line {line}: {source}"),
+            Self::Incomplete => write!(f, "hand history ends before the hand is over"),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IllegalAction { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Chips a player collected from one pot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collection {
+    /// Player who collected.
+    pub player: PlayerId,
+    /// Chips collected.
+    pub amount: Chips,
+    /// Pot as named in the history, e.g. "pot", "main pot", or "side pot-1".
+    pub pot: String,
+}
+
+/// A hand imported from hand history text.
+#[derive(Debug, Clone)]
+pub struct ParsedHand {
+    /// Hand number as written by the site, e.g. "123456" or "RC123456".
+    pub hand_id: String,
+    /// Name of the table.
+    pub table_name: String,
+    /// Start time of the hand, read as UTC.
+    pub started_at: Timestamp,
+    /// Player names, indexed by `PlayerId`.
+    pub player_names: Vec<String>,
+    /// Seat number each player sat in at the original table, indexed by `PlayerId`.
+    pub seat_numbers: Vec<u8>,
+    /// Hole cards revealed by the history (dealt to the hero or shown), by `PlayerId`.
+    pub known_hole_cards: Vec<Option<[Card; 2]>>,
+    /// Whether currency amounts were converted to cents.
+    pub amounts_in_cents: bool,
+    /// Events that replay the hand through `GameAggregate`.
+    ///
+    /// Cards the history does not reveal are filled in with unused cards.
+    pub events: Vec<GameEvent>,
+    /// Chips collected from each pot, in the order the history lists them.
+    pub collections: Vec<Collection>,
+    /// Description of the winning hand, when the history gives one.
+    pub winning_hand: Option<String>,
+}
+
+/// `ParsedHand` - Accessors
+impl ParsedHand {
+    /// Players who collected chips, without duplicates.
+    #[must_use]
+    pub fn winners(&self) -> Vec<PlayerId> {
+        let mut winners: Vec<PlayerId> = Vec::new();
+        for collection in &self.collections {
+            if !winners.contains(&collection.player) {
+                winners.push(collection.player);
+            }
+        }
+        winners
+    }
+
+    /// Returns whether some pot was split between several players.
+    #[must_use]
+    pub fn is_tie(&self) -> bool {
+        self.collections.iter().enumerate().any(|(index, collection)| {
+            self.collections[index + 1..]
+                .iter()
+                .any(|other| other.pot == collection.pot && other.player != collection.player)
+        })
+    }
+
+    /// Builds the `HandSummary` read model for this hand within `game_id`.
+    ///
+    /// Histories carry no end time, so the hand is recorded as ending when it started.
+    #[must_use]
+    pub fn summary(&self, game_id: GameId) -> HandSummary {
+        let name = |player: PlayerId| self.player_names[player.as_index()].clone();
+        HandSummary {
+            hand_id: self.hand_id.clone(),
+            game_id,
+            started_at: self.started_at,
+            ended_at: self.started_at,
+            num_players: self.player_names.len(),
+            player_ids: self.player_names.clone(),
+            winner_ids: self.winners().into_iter().map(name).collect(),
+            is_tie: self.is_tie(),
+            winning_hand_rank: self.winning_hand.clone(),
+            pot: self
+                .collections
+                .iter()
+                .map(|collection| collection.amount.value())
+                .sum(),
+            all_in_ev: Vec::new(),
+        }
+    }
+}
+
+/// Parses PokerStars-style No Limit Hold'em hand histories, including GG Poker exports.
+///
+/// Each hand is replayed through a `GameAggregate`, so an imported hand yields the same
+/// events as one played live and is checked against the betting rules on the way in.
+/// Cash-game amounts written with decimals or a currency symbol are converted to cents.
+///
+/// Site chatter such as chat messages or players joining the table is skipped, unless
+/// the parser is strict, in which case any unrecognized line is an error.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::hand_history::HandHistoryParser;
+/// use riverrun::core::domain::primitives::PlayerId;
+///
+/// let text = "\
+/// PokerStars Hand #1001: Hold'em No Limit (5/10) - 2024/03/01 18:30:00 ET
+/// Table 'Alpha' 3-max Seat #1 is the button
+/// Seat 1: ann (1000 in chips)
+/// Seat 2: ben (1000 in chips)
+/// Seat 3: cat (1000 in chips)
+/// ben: posts small blind 5
+/// cat: posts big blind 10
+/// *** HOLE CARDS ***
+/// Dealt to ann [Ah Kd]
+/// ann: raises 20 to 30
+/// ben: folds
+/// cat: folds
+/// Uncalled bet (20) returned to ann
+/// ann collected 25 from pot
+/// *** SUMMARY ***
+/// Total pot 25 | Rake 0
+/// ";
+///
+/// let hand = HandHistoryParser::new().parse(text).unwrap();
+/// assert_eq!(hand.hand_id, "1001");
+/// assert_eq!(hand.player_names, ["ann", "ben", "cat"]);
+/// assert_eq!(hand.winners(), [PlayerId::new(0)]);
+/// assert_eq!(hand.summary("alpha".into()).winner_ids, ["ann"]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HandHistoryParser {
+    strict: bool,
+}
+
+/// `HandHistoryParser` - Constructors
+impl HandHistoryParser {
+    /// Creates a parser that skips lines it does not recognize.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { strict: false }
+    }
+
+    /// Creates a parser that rejects lines it does not recognize.
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self { strict: true }
+    }
+}
+
+/// `HandHistoryParser` - Accessors
+impl HandHistoryParser {
+    /// Returns whether unrecognized lines are rejected.
+    #[must_use]
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+}
+
+/// `HandHistoryParser` - Operations
+impl HandHistoryParser {
+    /// Parses every hand in `text`, as found in a hand history file.
+    ///
+    /// A hand starts at each header line; anything before the first header is ignored.
+    #[must_use]
+    pub fn parse_all(&self, text: &str) -> Vec<Result<ParsedHand, ParseError>> {
+        let mut starts: Vec<usize> = text
+            .lines()
+            .scan(0, |offset, line| {
+                let start = *offset;
+                *offset += line.len() + 1;
+                Some((start, line))
+            })
+            .filter(|(_, line)| is_header(line))
+            .map(|(start, _)| start)
+            .collect();
+        starts.push(text.len());
+        starts
+            .windows(2)
+            .map(|bounds| self.parse(&text[bounds[0]..bounds[1].min(text.len())]))
+            .collect()
+    }
+
+    /// Parses a single hand.
+    ///
+    /// # Errors
+    /// Returns a `ParseError` if the text is not a complete No Limit Hold'em hand, uses
+    /// unsupported rules such as antes, or contains an action the rules do not allow.
+    pub fn parse(&self, text: &str) -> Result<ParsedHand, ParseError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+        let (_, header) = lines.next().ok_or(ParseError::Empty)?;
+        let header = parse_header(header)?;
+        let (number, table) = lines.next().ok_or(ParseError::Incomplete)?;
+        let (table_name, button_seat) = parse_table(table).ok_or_else(|| invalid_line(number, table))?;
+
+        let mut seats = Vec::new();
+        let mut body = Vec::new();
+        for (number, line) in lines.by_ref() {
+            if !line.starts_with("Seat ") {
+                body.push((number, line));
+                break;
+            }
+            let seat = parse_seat(line, header.cents).ok_or_else(|| invalid_line(number, line))?;
+            if !seat.sitting_out {
+                seats.push(seat);
+            }
+        }
+        body.extend(lines);
+
+        let button = seats
+            .iter()
+            .position(|seat| seat.number == button_seat)
+            .map(PlayerId::from)
+            .ok_or_else(|| ParseError::Unsupported("button on an empty seat".to_string()))?;
+        let names: Vec<String> = seats.iter().map(|seat| seat.name.clone()).collect();
+        let cards = RevealedCards::collect(&body, &names)?;
+
+        let mut replay = Replay {
+            parser: *self,
+            names: &names,
+            cents: header.cents,
+            aggregate: GameAggregate::new(),
+            events: Vec::new(),
+            collections: Vec::new(),
+        };
+        replay.start(
+            &header,
+            seats.iter().map(|seat| seat.stack).collect(),
+            button,
+            &cards,
+            &body,
+        )?;
+        replay.play(&body)?;
+
+        Ok(ParsedHand {
+            hand_id: header.hand_id,
+            table_name,
+            started_at: header.started_at,
+            player_names: names.clone(),
+            seat_numbers: seats.iter().map(|seat| seat.number).collect(),
+            known_hole_cards: cards.hole_cards,
+            amounts_in_cents: header.cents,
+            events: replay.events,
+            collections: replay.collections,
+            winning_hand: cards.winning_hand,
+        })
+    }
+}
+
+/// Fields read from the first line of a hand.
+struct Header {
+    hand_id: String,
+    small_blind: Chips,
+    big_blind: Chips,
+    started_at: Timestamp,
+    cents: bool,
+}
+
+/// A player listed in the seat section.
+struct Seat {
+    number: u8,
+    name: String,
+    stack: Chips,
+    sitting_out: bool,
+}
+
+/// Cards revealed anywhere in the history.
+struct RevealedCards {
+    hole_cards: Vec<Option<[Card; 2]>>,
+    board: Vec<Card>,
+    winning_hand: Option<String>,
+}
+
+impl RevealedCards {
+    /// Gathers hole cards, the board, and the winning hand description from `body`.
+    fn collect(body: &[(usize, &str)], names: &[String]) -> Result<Self, ParseError> {
+        let mut revealed = Self {
+            hole_cards: vec![None; names.len()],
+            board: Vec::new(),
+            winning_hand: None,
+        };
+
+        for &(number, line) in body {
+            let invalid = || invalid_line(number, line);
+            if line.starts_with("*** FLOP ***")
+                || line.starts_with("*** TURN ***")
+                || line.starts_with("*** RIVER ***")
+                || line.starts_with("Board ")
+            {
+                revealed.board = bracketed_cards(line).ok_or_else(invalid)?;
+                continue;
+            }
+
+            let (player, rest) = if let Some(dealt) = line.strip_prefix("Dealt to ") {
+                match split_player(dealt, names) {
+                    Some((player, rest)) => (player, rest),
+                    None => continue,
+                }
+            } else if let Some(seat_line) = line.strip_prefix("Seat ") {
+                // Summary lines: "Seat 1: name (button) showed [Ah Kd] and won (100) with ..."
+                let Some((_, rest)) = seat_line.split_once(": ") else {
+                    continue;
+                };
+                if let Some((_, description)) = rest.split_once(") with ")
+                    && rest.contains(" and won (")
+                    && revealed.winning_hand.is_none()
+                {
+                    revealed.winning_hand = Some(description.to_string());
+                }
+                match split_player(rest, names) {
+                    Some((player, rest)) => (player, rest),
+                    None => continue,
+                }
+            } else {
+                match split_player(line, names) {
+                    Some((player, rest)) if rest.starts_with("shows [") => (player, rest),
+                    _ => continue,
+                }
+            };
+
+            let Some(cards) = bracketed_cards(rest) else {
+                continue;
+            };
+            let [first, second] = cards[..] else {
+                return Err(invalid());
+            };
+            revealed.hole_cards[player.as_index()] = Some([first, second]);
+        }
+        Ok(revealed)
+    }
+
+    /// Builds a deck that deals the revealed cards where the history shows them.
+    ///
+    /// Cards the history does not reveal are filled with cards nobody was seen holding.
+    fn deck(&self) -> Result<Deck, ParseError> {
+        let mut scripted = ScriptedDeck::new(self.hole_cards.len()).with_board(&self.board);
+        for (seat, cards) in self.hole_cards.iter().enumerate() {
+            if let Some(cards) = cards {
+                scripted = scripted.with_hole_cards(seat, *cards);
+            }
+        }
+        scripted.build().map_err(|err| match err {
+            CardError::DuplicateCard(card) => ParseError::DuplicateCard(card),
+            CardError::InvalidBoardSize(count) => ParseError::Unsupported(format!("{count} board cards")),
+        })
+    }
+}
+
+/// Replays the action lines of a hand through a `GameAggregate`.
+struct Replay<'a> {
+    parser: HandHistoryParser,
+    names: &'a [String],
+    cents: bool,
+    aggregate: GameAggregate,
+    events: Vec<GameEvent>,
+    collections: Vec<Collection>,
+}
+
+impl Replay<'_> {
+    /// Starts the hand and checks that the blinds match the history.
+    fn start(
+        &mut self,
+        header: &Header,
+        stacks: Vec<Chips>,
+        button: PlayerId,
+        cards: &RevealedCards,
+        body: &[(usize, &str)],
+    ) -> Result<(), ParseError> {
+        let mut posted = Vec::new();
+        for &(number, line) in body {
+            let Some((player, rest)) = split_player(line, self.names) else {
+                continue;
+            };
+            let Some(post) = rest.strip_prefix("posts ") else {
+                continue;
+            };
+            let amount = |text: &str| {
+                parse_amount(text.trim_end_matches(ALL_IN_SUFFIX), self.cents)
+                    .ok_or_else(|| invalid_line(number, line))
+            };
+            if let Some(text) = post.strip_prefix("the ante ") {
+                posted.push(GameEvent::AntePosted {
+                    player,
+                    amount: amount(text)?,
+                });
+            } else if let Some(text) = post.strip_prefix("small blind ") {
+                posted.push(GameEvent::BlindPosted {
+                    player,
+                    amount: amount(text)?,
+                });
+            } else if let Some(text) = post.strip_prefix("big blind ") {
+                posted.push(GameEvent::BlindPosted {
+                    player,
+                    amount: amount(text)?,
+                });
+            } else {
+                return Err(ParseError::Unsupported(format!("posting {post}")));
+            }
+        }
+
+        // A short-stacked player may post less than the full ante
+        let ante = posted
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::AntePosted { amount, .. } => Some(*amount),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default();
+        let hand_number = header.hand_id.parse().map_or(HandNumber::new(0), HandNumber::new);
+        let events = self
+            .aggregate
+            .execute(&GameCommand::StartHand {
+                hand_number,
+                stacks,
+                button,
+                small_blind: header.small_blind,
+                big_blind: header.big_blind,
+                ante,
+                deck: cards.deck()?,
+            })
+            .map_err(|source| ParseError::IllegalAction { line: 1, source })?;
+
+        let blinds: Vec<&GameEvent> = events
+            .iter()
+            .filter(|event| {
+                matches!(event, GameEvent::AntePosted { .. } | GameEvent::BlindPosted { .. })
+            })
+            .collect();
+        if blinds != posted.iter().collect::<Vec<_>>() {
+            return Err(ParseError::Unsupported(
+                "blinds that do not follow the button".to_string(),
+            ));
+        }
+        self.events = events;
+        Ok(())
+    }
+
+    /// Applies every action line and records the pots collected.
+    fn play(&mut self, body: &[(usize, &str)]) -> Result<(), ParseError> {
+        for &(number, line) in body {
+            if line.starts_with("*** SUMMARY ***") {
+                break;
+            }
+            let Some((player, rest)) = split_player(line, self.names) else {
+                if self.parser.strict && !is_known_line(line) {
+                    return Err(invalid_line(number, line));
+                }
+                continue;
+            };
+
+            if let Some(collected) = rest.strip_prefix("collected ") {
+                let (amount, pot) = collected.split_once(" from ").ok_or_else(|| invalid_line(number, line))?;
+                self.collections.push(Collection {
+                    player,
+                    amount: parse_amount(amount, self.cents).ok_or_else(|| invalid_line(number, line))?,
+                    pot: pot.to_string(),
+                });
+                continue;
+            }
+
+            match self.action(player, rest) {
+                Some(Ok(action)) => {
+                    let events = self
+                        .aggregate
+                        .execute(&GameCommand::Act { player, action })
+                        .map_err(|source| ParseError::IllegalAction { line: number, source })?;
+                    self.events.extend(events);
+                }
+                Some(Err(())) => return Err(invalid_line(number, line)),
+                None if self.parser.strict && !is_known_player_line(rest) => {
+                    return Err(invalid_line(number, line));
+                }
+                None => {}
+            }
+        }
+
+        if self.aggregate.is_hand_in_progress() {
+            return Err(ParseError::Incomplete);
+        }
+        Ok(())
+    }
+
+    /// Converts the text after a player's name into an action.
+    ///
+    /// # Returns
+    /// `None` if the text is not an action, `Some(Err(()))` if it is a malformed one.
+    fn action(&self, player: PlayerId, text: &str) -> Option<Result<Action, ()>> {
+        let (text, all_in) = text
+            .strip_suffix(ALL_IN_SUFFIX)
+            .map_or((text, false), |text| (text, true));
+        let amount = |amount: &str| parse_amount(amount, self.cents).ok_or(());
+        let contributed = self
+            .aggregate
+            .betting()
+            .and_then(|betting| betting.current_round())
+            .map_or(Chips::ZERO, |round| round.player_contribution(player));
+
+        // Folding may reveal the cards, e.g. "folds [Ah Kd]"
+        let action = if text == "folds" || text.starts_with("folds [") {
+            Ok(Action::Fold)
+        } else if text == "checks" {
+            Ok(Action::Check)
+        } else if let Some(called) = text.strip_prefix("calls ") {
+            amount(called).map(|called| if all_in { Action::AllIn(called) } else { Action::Call(called) })
+        } else if let Some(bet) = text.strip_prefix("bets ") {
+            amount(bet).map(|bet| if all_in { Action::AllIn(bet) } else { Action::Bet(bet) })
+        } else if let Some(raise) = text.strip_prefix("raises ") {
+            raise
+                .split_once(" to ")
+                .ok_or(())
+                .and_then(|(_, total)| amount(total))
+                .map(|total| {
+                    if all_in {
+                        Action::AllIn(total.saturating_sub(contributed))
+                    } else {
+                        Action::Raise(total)
+                    }
+                })
+        } else {
+            return None;
+        };
+        Some(action)
+    }
+}
+
+/// Returns whether `line` starts a new hand.
+fn is_header(line: &str) -> bool {
+    let line = line.trim_start_matches('\u{feff}').trim_start();
+    (line.starts_with("PokerStars ") || line.starts_with("Poker Hand #")) && line.contains("Hand #")
+}
+
+/// Returns whether `line` is a non-player line the parser knows to skip.
+fn is_known_line(line: &str) -> bool {
+    ["***", "Dealt to ", "Uncalled bet ", "Board ", "Total pot ", "Seat "]
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+}
+
+/// Returns whether the text after a player's name is a known non-action statement.
+fn is_known_player_line(rest: &str) -> bool {
+    ["posts ", "shows ", "mucks", "doesn't show", "is sitting out", "sits out"]
+        .iter()
+        .any(|prefix| rest.starts_with(prefix))
+}
+
+fn invalid_line(line: usize, text: &str) -> ParseError {
+    ParseError::InvalidLine {
+        line,
+        text: text.to_string(),
+    }
+}
+
+/// Parses `PokerStars Hand #1: Hold'em No Limit ($0.01/$0.02 USD) - 2024/01/01 12:00:00 ET`
+/// and the GG Poker equivalent starting with `Poker Hand #`.
+fn parse_header(line: &str) -> Result<Header, ParseError> {
+    let invalid = || ParseError::InvalidHeader(line.to_string());
+    let (_, after) = line.split_once("Hand #").ok_or_else(invalid)?;
+    let (hand_id, rest) = after.split_once(':').ok_or_else(invalid)?;
+    let Some((_, game)) = rest.split_once("Hold'em No Limit") else {
+        return Err(ParseError::Unsupported(format!("game '{}'", rest.trim())));
+    };
+
+    // Stakes are the first parenthesized pair after the game name
+    let open = game.find('(').ok_or_else(invalid)?;
+    let close = open + game[open..].find(')').ok_or_else(invalid)?;
+    let stakes = &game[open + 1..close];
+    let (small_blind, big_blind) = stakes.split_once('/').ok_or_else(invalid)?;
+    let big_blind = big_blind.split_whitespace().next().ok_or_else(invalid)?;
+    let cents = stakes.contains(['.', '$', '€', '£']);
+
+    // The date follows the last " - ", possibly with a time zone and a second time in brackets
+    let (_, date_text) = game[close..].rsplit_once(" - ").ok_or_else(invalid)?;
+    let mut tokens = date_text
+        .split_whitespace()
+        .map(|token| token.trim_matches(['[', ']']));
+    let date = tokens.find(|token| token.contains('/')).ok_or_else(invalid)?;
+    let time = tokens.next().ok_or_else(invalid)?;
+
+    Ok(Header {
+        hand_id: hand_id.trim().to_string(),
+        small_blind: parse_amount(small_blind, cents).ok_or_else(invalid)?,
+        big_blind: parse_amount(big_blind, cents).ok_or_else(invalid)?,
+        started_at: parse_timestamp(date, time).ok_or_else(invalid)?,
+        cents,
+    })
+}
+
+/// Parses `Table 'Name' 6-max Seat #3 is the button` into the name and button seat.
+fn parse_table(line: &str) -> Option<(String, u8)> {
+    let rest = line.strip_prefix("Table '")?;
+    let (name, rest) = rest.rsplit_once('\'')?;
+    let (_, button) = rest.split_once("Seat #")?;
+    let button = button.split_whitespace().next()?.parse().ok()?;
+    Some((name.to_string(), button))
+}
+
+/// Parses `Seat 3: name (1000 in chips)`, possibly followed by `is sitting out`.
+fn parse_seat(line: &str, cents: bool) -> Option<Seat> {
+    let (number, rest) = line.strip_prefix("Seat ")?.split_once(": ")?;
+    let chips_at = rest.find(" in chips")?;
+    let open = rest[..chips_at].rfind(" (")?;
+    Some(Seat {
+        number: number.parse().ok()?,
+        name: rest[..open].to_string(),
+        stack: parse_amount(&rest[open + 2..chips_at], cents)?,
+        sitting_out: rest[chips_at..].contains("sitting out"),
+    })
+}
+
+/// Splits `line` into the seated player it starts with and the text after the name.
+///
+/// The longest matching name wins, so "Bob" and "Bob Jr" can sit at the same table.
+fn split_player<'a>(line: &'a str, names: &[String]) -> Option<(PlayerId, &'a str)> {
+    names
+        .iter()
+        .enumerate()
+        .filter_map(|(index, name)| {
+            let rest = line.strip_prefix(name.as_str())?;
+            let rest = rest.strip_prefix(": ").or_else(|| rest.strip_prefix(' '))?;
+            Some((name.len(), PlayerId::from(index), rest))
+        })
+        .max_by_key(|&(length, _, _)| length)
+        .map(|(_, player, rest)| (player, rest))
+}
+
+/// Parses a chip amount such as `1,000`, `$0.25`, or `€2`.
+///
+/// With `cents`, amounts are currency and are returned in cents.
+fn parse_amount(text: &str, cents: bool) -> Option<Chips> {
+    let text = text.trim().trim_start_matches(['$', '€', '£']).replace(',', "");
+    let (whole, fraction) = text.split_once('.').unwrap_or((&text, ""));
+    let whole: u64 = whole.parse().ok()?;
+    if !cents {
+        return fraction.is_empty().then_some(Chips::new(whole));
+    }
+    if fraction.len() > 2 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let fraction: u64 = format!("{fraction:0<2}").parse().ok()?;
+    Some(Chips::new(whole * 100 + fraction))
+}
+
+/// Parses every card inside the square brackets of `text`, e.g. `[Ah Kd] [2c]`.
+fn bracketed_cards(text: &str) -> Option<Vec<Card>> {
+    let mut cards = Vec::new();
+    let mut rest = text;
+    while let Some(open) = rest.find('[') {
+        let close = open + rest[open..].find(']')?;
+        for token in rest[open + 1..close].split_whitespace() {
+            cards.push(Card::from_str(token).ok()?);
+        }
+        rest = &rest[close + 1..];
+    }
+    (!cards.is_empty()).then_some(cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::hand_history::HandHistoryWriter;
+    use crate::core::application::{HandEngine, HandSetup};
+    use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+
+    const GG_HAND: &str = "\
+Poker Hand #RC1234567: Hold'em No Limit ($0.05/$0.10) - 2024/02/03 04:05:06
+Table 'RushAndCash42' 6-max Seat #4 is the button
+Seat 2: hero ($10.00 in chips)
+Seat 4: villain ($7.50 in chips)
+villain: posts small blind $0.05
+hero: posts big blind $0.10
+*** HOLE CARDS ***
+Dealt to hero [As Ad]
+Dealt to villain
+villain: raises $0.20 to $0.30
+villain said, \"gl\"
+hero: raises $7.20 to $7.50
+villain: calls $7.20 and is all-in
+*** FLOP *** [2c 7d 9h]
+*** TURN *** [2c 7d 9h] [Js]
+*** RIVER *** [2c 7d 9h Js] [3c]
+*** SHOWDOWN ***
+villain: shows [Kc Kh] (a pair of Kings)
+hero: shows [As Ad] (a pair of Aces)
+hero collected $15.00 from pot
+*** SUMMARY ***
+Total pot $15.00 | Rake $0
+Board [2c 7d 9h Js 3c]
+Seat 2: hero (big blind) showed [As Ad] and won ($15.00) with a pair of Aces
+Seat 4: villain (button) (small blind) showed [Kc Kh] and lost with a pair of Kings
+";
+
+    fn card(text: &str) -> Card {
+        Card::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount("1,500", false), Some(Chips::new(1500)));
+        assert_eq!(parse_amount("$0.05", true), Some(Chips::new(5)));
+        assert_eq!(parse_amount("€2.5", true), Some(Chips::new(250)));
+        assert_eq!(parse_amount("$7", true), Some(Chips::new(700)));
+        assert_eq!(parse_amount("0.5", false), None);
+        assert_eq!(parse_amount("1.234", true), None);
+    }
+
+    #[test]
+    fn test_parse_gg_cash_hand() {
+        let hand = HandHistoryParser::new().parse(GG_HAND).unwrap();
+        assert_eq!(hand.hand_id, "RC1234567");
+        assert_eq!(hand.table_name, "RushAndCash42");
+        assert!(hand.amounts_in_cents);
+        assert_eq!(hand.player_names, ["hero", "villain"]);
+        assert_eq!(hand.seat_numbers, [2, 4]);
+        assert_eq!(hand.known_hole_cards[1], Some([card("Kc"), card("Kh")]));
+        assert_eq!(hand.winning_hand.as_deref(), Some("a pair of Aces"));
+        assert_eq!(
+            hand.collections,
+            [Collection {
+                player: PlayerId::new(0),
+                amount: Chips::new(1500),
+                pot: "pot".to_string()
+            }]
+        );
+
+        let replayed = GameAggregate::from_events(&hand.events);
+        let game = replayed.game().unwrap();
+        assert_eq!(game.player_hole_cards(0), Some(&[card("As"), card("Ad")]));
+        assert_eq!(
+            game.board().cards(),
+            ["2c", "7d", "9h", "Js", "3c"].map(card).as_slice()
+        );
+        assert_eq!(replayed.betting().unwrap().total_pot(), Chips::new(1500));
+        assert!(!replayed.is_hand_in_progress());
+
+        let summary = hand.summary("session-1".into());
+        assert_eq!((summary.started_at, summary.pot), (1_706_933_106_000, 1500));
+        assert_eq!(summary.winner_ids, ["hero"]);
+        assert!(!summary.is_tie);
+    }
+
+    #[test]
+    fn test_round_trips_writer_output() {
+        let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+        let setup = HandSetup {
+            hand_number: HandNumber::new(77),
+            stacks: vec![Chips::new(200), Chips::new(1000), Chips::new(1000)],
+            button: PlayerId::new(0),
+            small_blind: Chips::new(5),
+            big_blind: Chips::new(10),
+            ante: Chips::ZERO,
+        };
+        engine.start_hand(setup, Deck::new()).unwrap();
+        engine.act(PlayerId::new(0), Action::AllIn(Chips::new(200))).unwrap();
+        engine.act(PlayerId::new(1), Action::Raise(Chips::new(600))).unwrap();
+        engine.act(PlayerId::new(2), Action::Call(Chips::new(590))).unwrap();
+        engine.act(PlayerId::new(1), Action::Bet(Chips::new(100))).unwrap();
+        engine.act(PlayerId::new(2), Action::Fold).unwrap();
+
+        let text = HandHistoryWriter::new("Round Trip")
+            .write(engine.history(), engine.outcome().unwrap(), 1_700_000_000_000)
+            .unwrap();
+        let hand = HandHistoryParser::strict().parse(&text).unwrap();
+
+        let actions = |events: &[GameEvent]| -> Vec<GameEvent> {
+            events
+                .iter()
+                .filter(|event| !matches!(event, GameEvent::HandStarted { .. }))
+                .filter(|event| !matches!(event, GameEvent::HoleCardsDealt { .. }))
+                .cloned()
+                .collect()
+        };
+        assert_eq!(hand.started_at, 1_700_000_000_000);
+        assert_eq!(actions(&hand.events), actions(engine.history()));
+        assert_eq!(hand.collections.iter().map(|c| c.amount.value()).sum::<u64>(), 1400);
+    }
+
+    #[test]
+    fn test_rejects_unsupported_and_illegal_hands() {
+        let parser = HandHistoryParser::new();
+        assert_eq!(parser.parse("").unwrap_err(), ParseError::Empty);
+        assert!(matches!(
+            parser.parse("PokerStars Hand #1: Omaha Pot Limit (5/10) - 2024/01/01 00:00:00"),
+            Err(ParseError::Unsupported(_))
+        ));
+
+        let with_ante = GG_HAND.replace(
+            "hero: posts big blind $0.10\n",
+            "hero: posts big blind $0.10\nhero: posts the ante $0.02\n",
+        );
+        assert!(matches!(parser.parse(&with_ante), Err(ParseError::Unsupported(_))));
+
+        let undersized = GG_HAND.replace("raises $0.20 to $0.30", "raises $0.05 to $0.15");
+        assert!(matches!(
+            parser.parse(&undersized),
+            Err(ParseError::IllegalAction { line: 10, .. })
+        ));
+
+        let truncated = GG_HAND.lines().take(10).collect::<Vec<_>>().join("\n");
+        assert_eq!(parser.parse(&truncated).unwrap_err(), ParseError::Incomplete);
+
+        assert!(matches!(
+            HandHistoryParser::strict().parse(GG_HAND),
+            Err(ParseError::InvalidLine { line: 11, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_all_splits_hands() {
+        let text = format!("\u{feff}{GG_HAND}\n\n{}\n\n", GG_HAND.replace("RC1234567", "RC1234568"));
+        let hands = HandHistoryParser::new().parse_all(&text);
+        assert_eq!(hands.len(), 2);
+        assert_eq!(hands[1].as_ref().unwrap().hand_id, "RC1234568");
+    }
+}
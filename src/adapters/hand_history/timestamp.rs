@@ -0,0 +1,92 @@
+//! Conversion between Unix timestamps and hand history dates (`YYYY/MM/DD HH:MM:SS`).
+
+use crate::core::ports::outbound::Timestamp;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Formats a Unix timestamp in milliseconds as `YYYY/MM/DD HH:MM:SS`.
+pub(super) fn format_timestamp(timestamp: Timestamp) -> String {
+    let seconds = timestamp / 1000;
+    let (year, month, day) = civil_from_days(seconds / SECONDS_PER_DAY);
+    let time = seconds % SECONDS_PER_DAY;
+    format!(
+        "{year:04}/{month:02}/{day:02} {:02}:{:02}:{:02}",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// Parses a `YYYY/MM/DD` date and an `H:MM:SS` time into a Unix timestamp in milliseconds.
+///
+/// The time zone is not known, so the date is read as UTC.
+pub(super) fn parse_timestamp(date: &str, time: &str) -> Option<Timestamp> {
+    let mut date_parts = date.split('/').map(str::parse::<u64>);
+    let (year, month, day) = (date_parts.next()?.ok()?, date_parts.next()?.ok()?, date_parts.next()?.ok()?);
+    let mut time_parts = time.split(':').map(str::parse::<u64>);
+    let (hours, minutes, seconds) = (time_parts.next()?.ok()?, time_parts.next()?.ok()?, time_parts.next()?.ok()?);
+    if date_parts.next().is_some()
+        || time_parts.next().is_some()
+        || year < 1970
+        || !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hours > 23
+        || minutes > 59
+        || seconds > 59
+    {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day);
+    Some((days * SECONDS_PER_DAY + hours * 3600 + minutes * 60 + seconds) * 1000)
+}
+
+// Howard Hinnant's civil calendar algorithms, with eras starting on March 1st so the
+// leap day falls at the end of the year. Only dates from 1970 onwards are supported.
+
+/// Converts days since the Unix epoch to a proleptic Gregorian `(year, month, day)`.
+const fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let shifted = days + 719_468;
+    let era = shifted / 146_097;
+    let day_of_era = shifted % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Converts a proleptic Gregorian date to days since the Unix epoch.
+const fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970/01/01 00:00:00");
+        assert_eq!(format_timestamp(1_700_000_000_000), "2023/11/14 22:13:20");
+        assert_eq!(format_timestamp(951_782_400_000), "2000/02/29 00:00:00");
+    }
+
+    #[test]
+    fn test_parse_timestamp_round_trips() {
+        assert_eq!(parse_timestamp("2023/11/14", "22:13:20"), Some(1_700_000_000_000));
+        assert_eq!(parse_timestamp("2000/02/29", "0:00:00"), Some(951_782_400_000));
+        assert_eq!(parse_timestamp("1970/01/01", "00:00:00"), Some(0));
+        assert_eq!(parse_timestamp("2023/13/01", "00:00:00"), None);
+        assert_eq!(parse_timestamp("2023/11/14", "22:13"), None);
+    }
+}
@@ -0,0 +1,661 @@
+//! Writes completed hands as PokerStars-style hand history text.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::core::application::HandOutcome;
+use crate::core::domain::aggregates::{GameAggregate, GameEvent};
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::primitives::{Action, BettingRound, BettingState, Chips, PlayerId, Pot, Street};
+use crate::core::domain::services::solving::PotAward;
+use crate::core::ports::outbound::Timestamp;
+
+use super::timestamp::format_timestamp;
+
+/// Error returned when events do not describe a complete hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandHistoryError {
+    /// The events do not begin with `GameEvent::HandStarted`.
+    MissingHandStart,
+    /// The events end before `GameEvent::HandFinished`.
+    HandNotFinished,
+}
+
+impl fmt::Display for HandHistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHandStart => write!(f, "events do not start with HandStarted"),
+            Self::HandNotFinished => write!(f, "hand has not finished"),
+        }
+    }
+}
+
+impl Error for HandHistoryError {}
+
+/// Converts a completed hand into PokerStars-style hand history text.
+///
+/// The hand is read from its `GameEvent`s (as kept by `HandEngine::history`) together
+/// with the `HandOutcome` that awarded the pots. The output contains the header, seats,
+/// blinds, per-street action lines, board, showdown, and a pot summary, and can be
+/// imported into trackers that read PokerStars-style histories.
+///
+/// Seats are numbered from 1 as in PokerStars-style histories, so `PlayerId(0)` sits
+/// in seat 1. Hole cards are only listed for the hero and for players who reach the
+/// showdown.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::HandHistoryWriter;
+/// use riverrun::core::application::{HandEngine, HandSetup};
+/// use riverrun::core::domain::entities::deck::Deck;
+/// use riverrun::core::domain::primitives::{Action, Chips, HandNumber, PlayerId};
+/// use riverrun::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+///
+/// let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+/// let setup = HandSetup {
+///     hand_number: HandNumber::new(42),
+///     stacks: vec![Chips::new(1000); 2],
+///     button: PlayerId::new(0),
+///     small_blind: Chips::new(5),
+///     big_blind: Chips::new(10),
+///     ante: Chips::ZERO,
+/// };
+/// engine.start_hand(setup, Deck::new()).unwrap();
+/// engine.act(PlayerId::new(0), Action::Fold).unwrap();
+///
+/// let writer = HandHistoryWriter::new("Riverrun I")
+///     .with_player_names(vec!["alice".into(), "bob".into()]);
+/// let text = writer
+///     .write(engine.history(), engine.outcome().unwrap(), 0)
+///     .unwrap();
+/// assert!(text.starts_with("PokerStars Hand #42: Hold'em No Limit (5/10)"));
+/// assert!(text.contains("bob collected 10 from pot"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HandHistoryWriter {
+    table_name: String,
+    player_names: Vec<String>,
+    hero: Option<PlayerId>,
+}
+
+/// `HandHistoryWriter` - Constructors
+impl HandHistoryWriter {
+    /// Creates a writer for the table named `table_name`.
+    ///
+    /// Players are named after their `PlayerId` (e.g. "Player 0") until names are set.
+    #[must_use]
+    pub fn new(table_name: impl Into<String>) -> Self {
+        Self {
+            table_name: table_name.into(),
+            player_names: Vec::new(),
+            hero: None,
+        }
+    }
+
+    /// Names players by seat: `names[i]` is the name of `PlayerId(i)`.
+    #[must_use]
+    pub fn with_player_names(mut self, names: Vec<String>) -> Self {
+        self.player_names = names;
+        self
+    }
+
+    /// Lists `hero`'s hole cards even when they do not reach the showdown.
+    #[must_use]
+    pub const fn with_hero(mut self, hero: PlayerId) -> Self {
+        self.hero = Some(hero);
+        self
+    }
+}
+
+/// `HandHistoryWriter` - Accessors
+impl HandHistoryWriter {
+    /// Name of the table written in the header.
+    #[must_use]
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    /// Name written for `player`.
+    #[must_use]
+    pub fn player_name(&self, player: PlayerId) -> String {
+        self.player_names
+            .get(player.as_index())
+            .cloned()
+            .unwrap_or_else(|| player.to_string())
+    }
+}
+
+/// `HandHistoryWriter` - Operations
+impl HandHistoryWriter {
+    /// Writes the hand described by `events` and `outcome`, played at `timestamp`.
+    ///
+    /// # Errors
+    /// Returns a `HandHistoryError` if `events` does not run from
+    /// `GameEvent::HandStarted` to `GameEvent::HandFinished`.
+    pub fn write(
+        &self,
+        events: &[GameEvent],
+        outcome: &HandOutcome,
+        timestamp: Timestamp,
+    ) -> Result<String, HandHistoryError> {
+        let Some(GameEvent::HandStarted {
+            hand_number,
+            stacks,
+            button,
+            small_blind,
+            big_blind,
+            ..
+        }) = events.first()
+        else {
+            return Err(HandHistoryError::MissingHandStart);
+        };
+        if !events
+            .iter()
+            .any(|event| matches!(event, GameEvent::HandFinished { .. }))
+        {
+            return Err(HandHistoryError::HandNotFinished);
+        }
+
+        let mut lines = vec![
+            format!(
+                "PokerStars Hand #{}: Hold'em No Limit ({small_blind}/{big_blind}) - {} UTC",
+                hand_number.value(),
+                format_timestamp(timestamp)
+            ),
+            format!(
+                "Table '{}' {}-max Seat #{} is the button",
+                self.table_name,
+                stacks.len(),
+                seat_number(*button)
+            ),
+        ];
+        for (seat, stack) in stacks.iter().enumerate() {
+            let player = PlayerId::from(seat);
+            lines.push(format!(
+                "Seat {}: {} ({stack} in chips)",
+                seat_number(player),
+                self.player_name(player)
+            ));
+        }
+
+        let replay = self.replay(events, &mut lines)?;
+        let settlement = Settlement::new(&replay.betting, outcome);
+        self.write_results(&replay, &settlement, outcome, &mut lines);
+        self.write_summary(&replay, &settlement, outcome, *button, &mut lines);
+
+        let mut text = lines.join("\n");
+        text.push('\n');
+        Ok(text)
+    }
+
+    /// Replays `events`, writing the blind, street, and action lines.
+    fn replay(&self, events: &[GameEvent], lines: &mut Vec<String>) -> Result<Replay, HandHistoryError> {
+        let mut aggregate = GameAggregate::new();
+        let mut blinds = Vec::new();
+        let mut folded_on = Vec::new();
+        let mut street = Street::Preflop;
+        let mut board: Vec<Card> = Vec::new();
+        let mut hole_cards: Vec<[Card; 2]> = Vec::new();
+        let mut runouts = 1;
+
+        for event in events {
+            let mut action_line = None;
+            match event {
+                GameEvent::HandStarted { stacks, .. } => folded_on = vec![None; stacks.len()],
+                GameEvent::HandFinished { .. } => {}
+                GameEvent::AntePosted { player, amount } => {
+                    lines.push(format!("{}: posts the ante {amount}", self.player_name(*player)));
+                }
+                GameEvent::BlindPosted { player, amount } => {
+                    let kind = if blinds.is_empty() { "small" } else { "big" };
+                    blinds.push(*player);
+                    lines.push(format!("{}: posts {kind} blind {amount}", self.player_name(*player)));
+                }
+                GameEvent::HoleCardsDealt { hole_cards: dealt } => {
+                    hole_cards.clone_from(dealt);
+                    lines.push("*** HOLE CARDS ***".to_string());
+                    if let Some(hero) = self.hero
+                        && let Some(cards) = dealt.get(hero.as_index())
+                    {
+                        lines.push(format!("Dealt to {} {}", self.player_name(hero), card_list(cards)));
+                    }
+                }
+                GameEvent::ActionTaken { player, action } => {
+                    // Describe the action against the state it faced, before applying it
+                    let betting = aggregate
+                        .betting()
+                        .ok_or(HandHistoryError::MissingHandStart)?;
+                    action_line = Some((*player, describe_action(betting, *player, *action)));
+                    if *action == Action::Fold
+                        && let Some(folded) = folded_on.get_mut(player.as_index())
+                    {
+                        *folded = Some(street);
+                    }
+                }
+                GameEvent::StreetDealt {
+                    street: next,
+                    cards,
+                } => {
+                    let name = next.to_string().to_uppercase();
+                    lines.push(if board.is_empty() {
+                        format!("*** {name} *** {}", card_list(cards))
+                    } else {
+                        format!("*** {name} *** {} {}", card_list(&board), card_list(cards))
+                    });
+                    street = *next;
+                    board.extend_from_slice(cards);
+                }
+                GameEvent::RunoutDealt { board: runout, .. } => {
+                    runouts += 1;
+                    lines.push(format!("*** BOARD {runouts} *** {}", card_list(runout)));
+                }
+            }
+
+            aggregate.apply(event);
+            if let Some((player, text)) = action_line {
+                let all_in = aggregate
+                    .betting()
+                    .is_some_and(|betting| betting.is_all_in(player) && action_puts_chips_in(event));
+                let suffix = if all_in { " and is all-in" } else { "" };
+                lines.push(format!("{}: {text}{suffix}", self.player_name(player)));
+            }
+        }
+
+        let betting = aggregate
+            .betting()
+            .cloned()
+            .ok_or(HandHistoryError::MissingHandStart)?;
+        Ok(Replay {
+            blinds,
+            folded_on,
+            board,
+            hole_cards,
+            betting,
+        })
+    }
+
+    /// Writes the uncalled bet, showdown, and collection lines.
+    fn write_results(
+        &self,
+        replay: &Replay,
+        settlement: &Settlement,
+        outcome: &HandOutcome,
+        lines: &mut Vec<String>,
+    ) {
+        if !settlement.uncalled.is_zero() {
+            lines.push(format!(
+                "Uncalled bet ({}) returned to {}",
+                settlement.uncalled,
+                self.player_name(settlement.uncalled_to)
+            ));
+        }
+
+        if let Some(showdown) = outcome.showdown() {
+            lines.push("*** SHOW DOWN ***".to_string());
+            for seat in 0..replay.betting.num_players() {
+                let player = PlayerId::from(seat);
+                if replay.betting.has_folded(player) {
+                    continue;
+                }
+                if let (Some(cards), Some(hand)) = (replay.hole_cards.get(seat), showdown.hand(seat)) {
+                    lines.push(format!(
+                        "{}: shows {} ({})",
+                        self.player_name(player),
+                        card_list(cards),
+                        hand.rank()
+                    ));
+                }
+            }
+        }
+
+        for award in &settlement.collected {
+            lines.push(format!(
+                "{} collected {} from {}",
+                self.player_name(award.player),
+                award.amount,
+                pot_label(award.pot_index, settlement.pots.len())
+            ));
+        }
+    }
+
+    /// Writes the summary: pot breakdown, board, and how each seat finished.
+    fn write_summary(
+        &self,
+        replay: &Replay,
+        settlement: &Settlement,
+        outcome: &HandOutcome,
+        button: PlayerId,
+        lines: &mut Vec<String>,
+    ) {
+        lines.push("*** SUMMARY ***".to_string());
+        let pots = &settlement.pots;
+        let total = pots.iter().fold(Chips::ZERO, |acc, &pot| acc + pot);
+        if pots.len() > 1 {
+            let breakdown: Vec<String> = pots
+                .iter()
+                .enumerate()
+                .map(|(index, pot)| format!("{} {pot}.", capitalize(&pot_label(index, pots.len()))))
+                .collect();
+            lines.push(format!("Total pot {total} {} | Rake 0", breakdown.join(" ")));
+        } else {
+            lines.push(format!("Total pot {total} | Rake 0"));
+        }
+        if !replay.board.is_empty() {
+            lines.push(format!("Board {}", card_list(&replay.board)));
+        }
+
+        for (seat, folded_on) in replay.folded_on.iter().enumerate() {
+            let player = PlayerId::from(seat);
+            let mut roles = String::new();
+            if player == button {
+                roles.push_str(" (button)");
+            }
+            if replay.blinds.first() == Some(&player) {
+                roles.push_str(" (small blind)");
+            } else if replay.blinds.get(1) == Some(&player) {
+                roles.push_str(" (big blind)");
+            }
+
+            let status = match folded_on {
+                Some(Street::Preflop) if replay.betting.total_invested(player).is_zero() => {
+                    "folded before Flop (didn't bet)".to_string()
+                }
+                Some(Street::Preflop) => "folded before Flop".to_string(),
+                Some(street) => format!("folded on the {street}"),
+                None => {
+                    let amount = settlement.collected_by(player);
+                    let shown = outcome
+                        .showdown()
+                        .and_then(|result| Some((replay.hole_cards.get(seat)?, result.hand(seat)?)));
+                    match shown {
+                        Some((cards, hand)) if amount.is_zero() => {
+                            format!("showed {} and lost with {}", card_list(cards), hand.rank())
+                        }
+                        Some((cards, hand)) => {
+                            format!("showed {} and won ({amount}) with {}", card_list(cards), hand.rank())
+                        }
+                        None => format!("collected ({amount})"),
+                    }
+                }
+            };
+            lines.push(format!(
+                "Seat {}: {}{roles} {status}",
+                seat_number(player),
+                self.player_name(player)
+            ));
+        }
+    }
+}
+
+/// Facts gathered by replaying a hand's events.
+struct Replay {
+    /// Small blind then big blind seat.
+    blinds: Vec<PlayerId>,
+    /// Street on which each seat folded, if it did.
+    folded_on: Vec<Option<Street>>,
+    board: Vec<Card>,
+    hole_cards: Vec<[Card; 2]>,
+    /// Betting state once the hand finished.
+    betting: BettingState,
+}
+
+/// Pots and collections as the history reports them, with the uncalled bet set apart.
+struct Settlement {
+    uncalled_to: PlayerId,
+    uncalled: Chips,
+    /// Pot amounts after returning the uncalled bet, main pot first.
+    pots: Vec<Chips>,
+    /// Chips won from each pot, excluding the returned uncalled bet.
+    collected: Vec<PotAward>,
+}
+
+impl Settlement {
+    fn new(betting: &BettingState, outcome: &HandOutcome) -> Self {
+        let (uncalled_to, uncalled) = uncalled_bet(betting);
+
+        // The uncalled chips sit in the last pot; they are returned rather than won
+        let mut pots: Vec<Chips> = betting.build_pots().iter().map(Pot::amount).collect();
+        let last_pot = pots.len().saturating_sub(1);
+        if let Some(last) = pots.last_mut() {
+            *last = last.saturating_sub(uncalled);
+        }
+        while pots.len() > 1 && pots.last().is_some_and(|pot| pot.is_zero()) {
+            pots.pop();
+        }
+
+        let collected = outcome
+            .payouts()
+            .awards()
+            .iter()
+            .map(|award| {
+                let returned = if award.pot_index == last_pot && award.player == uncalled_to {
+                    uncalled
+                } else {
+                    Chips::ZERO
+                };
+                PotAward {
+                    amount: award.amount.saturating_sub(returned),
+                    ..*award
+                }
+            })
+            .filter(|award| !award.amount.is_zero())
+            .collect();
+
+        Self {
+            uncalled_to,
+            uncalled,
+            pots,
+            collected,
+        }
+    }
+
+    /// Total chips `player` collected across all pots.
+    fn collected_by(&self, player: PlayerId) -> Chips {
+        self.collected
+            .iter()
+            .filter(|award| award.player == player)
+            .fold(Chips::ZERO, |acc, award| acc + award.amount)
+    }
+}
+
+/// Describes `action` relative to the betting state it was taken in.
+fn describe_action(betting: &BettingState, player: PlayerId, action: Action) -> String {
+    let round = betting.current_round();
+    let current_bet = round.map_or(Chips::ZERO, BettingRound::current_bet);
+    let contributed = round.map_or(Chips::ZERO, |round| round.player_contribution(player));
+
+    match action {
+        Action::Fold => "folds".to_string(),
+        Action::Check => "checks".to_string(),
+        Action::Call(amount) => format!("calls {amount}"),
+        Action::Bet(amount) => format!("bets {amount}"),
+        Action::Raise(total) => format!("raises {} to {total}", total.saturating_sub(current_bet)),
+        Action::AllIn(amount) => {
+            let total = contributed + amount;
+            if current_bet.is_zero() {
+                format!("bets {amount}")
+            } else if total > current_bet {
+                format!("raises {} to {total}", total.saturating_sub(current_bet))
+            } else {
+                format!("calls {amount}")
+            }
+        }
+    }
+}
+
+/// Returns whether `event` is an action that moved chips into the pot.
+const fn action_puts_chips_in(event: &GameEvent) -> bool {
+    matches!(event, GameEvent::ActionTaken { action, .. } if action.puts_chips_in())
+}
+
+/// The part of the largest investment that nobody else matched, and who made it.
+fn uncalled_bet(betting: &BettingState) -> (PlayerId, Chips) {
+    let mut invested: Vec<(PlayerId, Chips)> = (0..betting.num_players())
+        .map(PlayerId::from)
+        .map(|player| (player, betting.total_invested(player)))
+        .collect();
+    invested.sort_by_key(|&(_, amount)| std::cmp::Reverse(amount));
+    match invested.as_slice() {
+        [(top, most), (_, next), ..] => (*top, most.saturating_sub(*next)),
+        _ => (PlayerId::new(0), Chips::ZERO),
+    }
+}
+
+/// Seat number (1-based) of `player` as written in the history.
+const fn seat_number(player: PlayerId) -> usize {
+    player.as_index() + 1
+}
+
+/// Name of pot `index` among `count` pots.
+fn pot_label(index: usize, count: usize) -> String {
+    match (index, count) {
+        (_, 0 | 1) => "pot".to_string(),
+        (0, _) => "main pot".to_string(),
+        (index, _) => format!("side pot-{index}"),
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Formats cards as `[Ah Kd]`.
+fn card_list(cards: &[Card]) -> String {
+    let cards: Vec<String> = cards.iter().map(ToString::to_string).collect();
+    format!("[{}]", cards.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::application::{HandEngine, HandSetup};
+    use crate::core::domain::entities::deck::Deck;
+    use crate::core::domain::primitives::HandNumber;
+    use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+
+    fn p(seat: u8) -> PlayerId {
+        PlayerId::new(seat)
+    }
+
+    fn engine(stacks: &[u64], button: u8) -> HandEngine<ShowdownSolver<CactusKevEvaluator>> {
+        let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+        let setup = HandSetup {
+            hand_number: HandNumber::new(7),
+            stacks: stacks.iter().copied().map(Chips::new).collect(),
+            button: p(button),
+            small_blind: Chips::new(5),
+            big_blind: Chips::new(10),
+            ante: Chips::ZERO,
+        };
+        engine.start_hand(setup, Deck::new()).unwrap();
+        engine
+    }
+
+    fn write(engine: &HandEngine<ShowdownSolver<CactusKevEvaluator>>, writer: &HandHistoryWriter) -> String {
+        writer
+            .write(engine.history(), engine.outcome().unwrap(), 1_700_000_000_000)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_folded_hand_returns_uncalled_bet() {
+        let mut engine = engine(&[1000, 1000, 1000], 0);
+        engine.act(p(0), Action::Fold).unwrap();
+        engine.act(p(1), Action::Fold).unwrap();
+
+        let text = write(&engine, &HandHistoryWriter::new("Test"));
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines[..6],
+            [
+                "PokerStars Hand #7: Hold'em No Limit (5/10) - 2023/11/14 22:13:20 UTC",
+                "Table 'Test' 3-max Seat #1 is the button",
+                "Seat 1: Player 0 (1000 in chips)",
+                "Seat 2: Player 1 (1000 in chips)",
+                "Seat 3: Player 2 (1000 in chips)",
+                "Player 1: posts small blind 5",
+            ]
+        );
+        assert!(text.contains("Player 0: folds\n"));
+        assert!(text.contains("Uncalled bet (5) returned to Player 2\n"));
+        assert!(text.contains("Player 2 collected 10 from pot\n"));
+        assert!(text.contains("Total pot 10 | Rake 0\n"));
+        assert!(text.contains("Seat 1: Player 0 (button) folded before Flop (didn't bet)\n"));
+        assert!(text.contains("Seat 2: Player 1 (small blind) folded before Flop\n"));
+        assert!(text.contains("Seat 3: Player 2 (big blind) collected (10)\n"));
+        assert!(!text.contains("*** SHOW DOWN ***"));
+    }
+
+    #[test]
+    fn test_showdown_hand_lists_streets_and_hands() {
+        let mut engine = engine(&[1000, 1000], 1);
+        engine.act(p(1), Action::Raise(Chips::new(30))).unwrap();
+        engine.act(p(0), Action::Call(Chips::new(20))).unwrap();
+        engine.act(p(0), Action::Bet(Chips::new(40))).unwrap();
+        engine.act(p(1), Action::Call(Chips::new(40))).unwrap();
+        for _ in 0..2 {
+            engine.act(p(0), Action::Check).unwrap();
+            engine.act(p(1), Action::Check).unwrap();
+        }
+
+        let writer = HandHistoryWriter::new("Test").with_hero(p(0));
+        let text = write(&engine, &writer);
+        let game = engine.aggregate().game().unwrap();
+        let hero_cards = card_list(game.player_hole_cards(0).unwrap());
+        let board = card_list(game.board().cards());
+
+        assert!(text.contains(&format!("Dealt to Player 0 {hero_cards}\n")));
+        assert!(text.contains("Seat #2 is the button"));
+        assert!(text.contains("Player 1: raises 20 to 30\n"));
+        assert!(text.contains("Player 0: bets 40\n"));
+        assert!(text.contains("*** FLOP *** ["));
+        assert!(text.contains(&format!("*** RIVER *** {}", card_list(&game.board().cards()[..4]))));
+        assert!(text.contains("*** SHOW DOWN ***\n"));
+        assert_eq!(text.matches(": shows [").count(), 2);
+        assert!(text.contains("Total pot 140 | Rake 0\n"));
+        assert!(text.contains(&format!("Board {board}\n")));
+        assert!(text.contains("Seat 2: Player 1 (button) (small blind) showed ["));
+        assert!(!text.contains("Uncalled bet"));
+    }
+
+    #[test]
+    fn test_side_pots_and_all_in() {
+        let mut engine = engine(&[200, 1000, 1000], 0);
+        engine.act(p(0), Action::AllIn(Chips::new(200))).unwrap();
+        engine.act(p(1), Action::Raise(Chips::new(600))).unwrap();
+        engine.act(p(2), Action::Call(Chips::new(590))).unwrap();
+        engine.act(p(1), Action::Bet(Chips::new(100))).unwrap();
+        engine.act(p(2), Action::Fold).unwrap();
+
+        let names = vec!["ann".to_string(), "ben".to_string(), "cat".to_string()];
+        let text = write(&engine, &HandHistoryWriter::new("Test").with_player_names(names));
+        assert!(text.contains("ann: raises 190 to 200 and is all-in\n"));
+        assert!(text.contains("Uncalled bet (100) returned to ben\n"));
+        assert!(text.contains("Total pot 1400 Main pot 600. Side pot-1 800. | Rake 0\n"));
+        assert!(text.contains("ben collected 800 from side pot-1\n"));
+        assert!(text.contains("Seat 3: cat (big blind) folded on the Flop\n"));
+    }
+
+    #[test]
+    fn test_rejects_incomplete_events() {
+        let unfinished = engine(&[1000, 1000], 0);
+        let mut finished = engine(&[1000, 1000], 0);
+        finished.act(p(0), Action::Fold).unwrap();
+        let outcome = finished.outcome().unwrap();
+
+        let writer = HandHistoryWriter::new("Test");
+        assert_eq!(
+            writer.write(&[], outcome, 0),
+            Err(HandHistoryError::MissingHandStart)
+        );
+        assert_eq!(
+            writer.write(unfinished.history(), outcome, 0),
+            Err(HandHistoryError::HandNotFinished)
+        );
+    }
+}
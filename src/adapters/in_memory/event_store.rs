@@ -0,0 +1,248 @@
+//! In-memory event store adapter.
+
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+
+use crate::core::ports::outbound::{
+    Clock, EventStore, EventStoreError, GameId, StoredEvent, SystemClock, Version,
+};
+use crate::telemetry::{self, Timer};
+
+/// Event store keeping every aggregate's event stream in memory.
+///
+/// Streams are guarded by a single `RwLock`, so concurrent readers never block
+/// each other and appends are serialized. Optimistic concurrency is enforced by
+/// comparing `expected_version` with the stream length under the write lock.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemoryEventStore;
+/// use riverrun::core::ports::outbound::{EventStore, GameId};
+///
+/// let store = InMemoryEventStore::new();
+/// let game = GameId::from("game-1");
+/// assert_eq!(store.append(&game, vec!["started"], 0), Ok(1));
+/// assert_eq!(store.version(&game), Ok(1));
+/// ```
+#[derive(Debug)]
+pub struct InMemoryEventStore<E, C: Clock = SystemClock> {
+    streams: RwLock<HashMap<GameId, Vec<StoredEvent<E>>>>,
+    clock: C,
+}
+
+/// `InMemoryEventStore` - Constructors
+impl<E> InMemoryEventStore<E> {
+    /// Create an empty store timestamping events with the system clock.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<E> Default for InMemoryEventStore<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `InMemoryEventStore` - Constructors
+impl<E, C: Clock> InMemoryEventStore<E, C> {
+    /// Create an empty store timestamping events with the given clock.
+    #[must_use]
+    pub fn with_clock(clock: C) -> Self {
+        Self {
+            streams: RwLock::new(HashMap::new()),
+            clock,
+        }
+    }
+}
+
+/// `InMemoryEventStore` - Accessors
+impl<E, C: Clock> InMemoryEventStore<E, C> {
+    /// Number of aggregates with at least one stored event.
+    ///
+    /// # Errors
+    /// Returns `EventStoreError::StorageError` if the lock was poisoned.
+    pub fn stream_count(&self) -> Result<usize, EventStoreError> {
+        Ok(self.streams.read().map_err(poisoned)?.len())
+    }
+}
+
+fn poisoned<T>(_: PoisonError<T>) -> EventStoreError {
+    EventStoreError::StorageError("event store lock poisoned".to_string())
+}
+
+impl<E, C> EventStore<E> for InMemoryEventStore<E, C>
+where
+    E: Clone + Send + Sync,
+    C: Clock,
+{
+    fn append(
+        &self,
+        game_id: &GameId,
+        events: Vec<E>,
+        expected_version: Version,
+    ) -> Result<Version, EventStoreError> {
+        let timer = Timer::start();
+        let mut streams = self.streams.write().map_err(poisoned)?;
+        let actual = streams.get(game_id).map_or(0, |stream| stream.len() as Version);
+        if actual != expected_version {
+            return Err(EventStoreError::ConcurrencyConflict {
+                expected: expected_version,
+                actual,
+            });
+        }
+        if events.is_empty() {
+            return Ok(actual);
+        }
+
+        let timestamp = self.clock.now();
+        let appended = events.len();
+        let new_version = actual + appended as Version;
+        streams.entry(game_id.clone()).or_default().extend(
+            (actual + 1..)
+                .zip(events)
+                .map(|(version, event)| StoredEvent::new(event, version, timestamp, game_id.clone())),
+        );
+        drop(streams);
+        telemetry::event_store_append("in_memory", appended, timer);
+        Ok(new_version)
+    }
+
+    fn load(&self, game_id: &GameId) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        self.streams
+            .read()
+            .map_err(poisoned)?
+            .get(game_id)
+            .cloned()
+            .ok_or_else(|| EventStoreError::NotFound(game_id.clone()))
+    }
+
+    fn load_from(
+        &self,
+        game_id: &GameId,
+        from_version: Version,
+    ) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        let streams = self.streams.read().map_err(poisoned)?;
+        Ok(streams.get(game_id).map_or_else(Vec::new, |stream| {
+            stream
+                .iter()
+                .filter(|stored| stored.version > from_version)
+                .cloned()
+                .collect()
+        }))
+    }
+
+    fn version(&self, game_id: &GameId) -> Result<Version, EventStoreError> {
+        let streams = self.streams.read().map_err(poisoned)?;
+        Ok(streams.get(game_id).map_or(0, |stream| stream.len() as Version))
+    }
+
+    fn exists(&self, game_id: &GameId) -> bool {
+        self.streams
+            .read()
+            .is_ok_and(|streams| streams.contains_key(game_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::*;
+    use crate::core::ports::outbound::FixedClock;
+
+    fn game(id: &str) -> GameId {
+        GameId::new(id)
+    }
+
+    #[test]
+    fn test_append_assigns_sequential_versions() {
+        let store = InMemoryEventStore::with_clock(FixedClock::new(42));
+        let id = game("g1");
+
+        assert_eq!(store.append(&id, vec!["a", "b"], 0), Ok(2));
+        assert_eq!(store.append(&id, vec!["c"], 2), Ok(3));
+
+        let events = store.load(&id).unwrap();
+        let versions: Vec<Version> = events.iter().map(|e| e.version).collect();
+        assert_eq!(versions, vec![1, 2, 3]);
+        assert!(events.iter().all(|e| e.timestamp == 42 && e.game_id == id));
+        assert_eq!(events[2].event, "c");
+    }
+
+    #[test]
+    fn test_append_rejects_stale_version() {
+        let store = InMemoryEventStore::new();
+        let id = game("g1");
+        store.append(&id, vec![1, 2], 0).unwrap();
+
+        assert_eq!(
+            store.append(&id, vec![3], 1),
+            Err(EventStoreError::ConcurrencyConflict {
+                expected: 1,
+                actual: 2
+            })
+        );
+        assert_eq!(store.version(&id), Ok(2));
+    }
+
+    #[test]
+    fn test_load_missing_stream() {
+        let store: InMemoryEventStore<u8> = InMemoryEventStore::new();
+        let id = game("missing");
+
+        assert_eq!(store.load(&id).unwrap_err(), EventStoreError::NotFound(id.clone()));
+        assert!(store.load_from(&id, 0).unwrap().is_empty());
+        assert_eq!(store.version(&id), Ok(0));
+        assert!(!store.exists(&id));
+    }
+
+    #[test]
+    fn test_load_from_filters_by_version() {
+        let store = InMemoryEventStore::new();
+        let id = game("g1");
+        store.append(&id, vec!['a', 'b', 'c', 'd'], 0).unwrap();
+
+        let events: Vec<char> = store.load_from(&id, 2).unwrap().into_iter().map(|e| e.event).collect();
+        assert_eq!(events, vec!['c', 'd']);
+    }
+
+    #[test]
+    fn test_empty_append_does_not_create_stream() {
+        let store: InMemoryEventStore<u8> = InMemoryEventStore::new();
+        let id = game("g1");
+
+        assert_eq!(store.append(&id, Vec::new(), 0), Ok(0));
+        assert!(!store.exists(&id));
+        assert_eq!(store.stream_count(), Ok(0));
+    }
+
+    #[test]
+    fn test_concurrent_appends_serialize() {
+        let store = Arc::new(InMemoryEventStore::new());
+        let id = game("g1");
+
+        let handles: Vec<_> = (0..8)
+            .map(|n| {
+                let store = Arc::clone(&store);
+                let id = id.clone();
+                thread::spawn(move || loop {
+                    let version = store.version(&id).unwrap();
+                    if store.append(&id, vec![n], version).is_ok() {
+                        break;
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(store.version(&id), Ok(8));
+        let versions: Vec<Version> = store.load(&id).unwrap().iter().map(|e| e.version).collect();
+        assert_eq!(versions, (1..=8).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,15 @@
+//! In-memory adapters for running the application without external infrastructure.
+//!
+//! State lives only for the lifetime of the adapter, which makes these adapters
+//! suitable for tests, simulations, and single-process deployments.
+
+mod event_store;
+mod read_model;
+mod result_cache;
+
+pub use event_store::InMemoryEventStore;
+pub use read_model::{
+    InMemoryActiveGameStore, InMemoryHandSummaryStore, InMemoryPlayerStatsStore,
+    InMemorySessionStatsStore,
+};
+pub use result_cache::InMemoryResultCache;
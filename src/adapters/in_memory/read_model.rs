@@ -0,0 +1,716 @@
+//! In-memory read model store adapters.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+
+use crate::core::ports::outbound::{
+    ActiveGameState, ActiveGameStore, GameId, HandCursor, HandId, HandPage, HandQuery,
+    HandSummary, HandSummaryStore, PeriodStats, PlayerId, PlayerStats, PlayerStatsStore, ReadModelError, SessionId,
+    SessionStats, SessionStatsStore, Timestamp, period_start,
+};
+
+fn poisoned<T>(_: PoisonError<T>) -> ReadModelError {
+    ReadModelError::StorageError("read model lock poisoned".to_string())
+}
+
+/// Hand summary store keeping every summary in memory.
+///
+/// Player queries and searches return the most recent hands first; time range and game queries
+/// return hands in the order they started. Saving a summary with an existing
+/// `hand_id` replaces it.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemoryHandSummaryStore;
+/// use riverrun::core::ports::outbound::{HandSummary, HandSummaryStore};
+///
+/// let store = InMemoryHandSummaryStore::new();
+/// store
+///     .save(&HandSummary {
+///         hand_id: "hand-1".to_string(),
+///         game_id: "game-1".into(),
+///         started_at: 1_000,
+///         ended_at: 2_000,
+///         num_players: 2,
+///         player_ids: vec!["alice".to_string(), "bob".to_string()],
+///         winner_ids: vec!["alice".to_string()],
+///         is_tie: false,
+///         winning_hand_rank: Some("Flush".to_string()),
+///         pot: 200,
+///         all_in_ev: Vec::new(),
+///     })
+///     .unwrap();
+///
+/// assert_eq!(store.count_by_player(&"bob".to_string()), Ok(1));
+/// assert_eq!(store.find_by_time_range(0, 1_500, 10).unwrap().len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryHandSummaryStore {
+    hands: RwLock<HashMap<HandId, HandSummary>>,
+}
+
+/// `InMemoryHandSummaryStore` - Constructors
+impl InMemoryHandSummaryStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// `InMemoryHandSummaryStore` - Accessors
+impl InMemoryHandSummaryStore {
+    /// Number of stored hands.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` if the lock was poisoned.
+    pub fn hand_count(&self) -> Result<usize, ReadModelError> {
+        Ok(self.hands.read().map_err(poisoned)?.len())
+    }
+
+    /// Stored hands matching `filter`, ordered by start time then hand ID.
+    fn matching(
+        &self,
+        filter: impl Fn(&HandSummary) -> bool,
+    ) -> Result<Vec<HandSummary>, ReadModelError> {
+        let mut hands: Vec<HandSummary> = self
+            .hands
+            .read()
+            .map_err(poisoned)?
+            .values()
+            .filter(|hand| filter(hand))
+            .cloned()
+            .collect();
+        hands.sort_by(|a, b| {
+            a.started_at
+                .cmp(&b.started_at)
+                .then_with(|| a.hand_id.cmp(&b.hand_id))
+        });
+        Ok(hands)
+    }
+}
+
+impl HandSummaryStore for InMemoryHandSummaryStore {
+    fn save(&self, summary: &HandSummary) -> Result<(), ReadModelError> {
+        self.hands
+            .write()
+            .map_err(poisoned)?
+            .insert(summary.hand_id.clone(), summary.clone());
+        Ok(())
+    }
+
+    fn get(&self, hand_id: &HandId) -> Result<HandSummary, ReadModelError> {
+        self.hands
+            .read()
+            .map_err(poisoned)?
+            .get(hand_id)
+            .cloned()
+            .ok_or_else(|| ReadModelError::NotFound(hand_id.clone()))
+    }
+
+    fn find_by_player(
+        &self,
+        player_id: &PlayerId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<HandSummary>, ReadModelError> {
+        let hands = self.matching(|hand| hand.player_ids.contains(player_id))?;
+        Ok(hands.into_iter().rev().skip(offset).take(limit).collect())
+    }
+
+    fn find_by_time_range(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+        limit: usize,
+    ) -> Result<Vec<HandSummary>, ReadModelError> {
+        if from > to {
+            return Err(ReadModelError::InvalidQuery(format!(
+                "time range starts at {from} after it ends at {to}"
+            )));
+        }
+        let mut hands = self.matching(|hand| (from..=to).contains(&hand.started_at))?;
+        hands.truncate(limit);
+        Ok(hands)
+    }
+
+    fn find_by_game(&self, game_id: &GameId) -> Result<Vec<HandSummary>, ReadModelError> {
+        self.matching(|hand| &hand.game_id == game_id)
+    }
+
+    fn count_by_player(&self, player_id: &PlayerId) -> Result<u64, ReadModelError> {
+        let hands = self.hands.read().map_err(poisoned)?;
+        Ok(hands
+            .values()
+            .filter(|hand| hand.player_ids.contains(player_id))
+            .count() as u64)
+    }
+
+    fn search(
+        &self,
+        query: &HandQuery,
+        cursor: Option<&HandCursor>,
+        limit: usize,
+    ) -> Result<HandPage, ReadModelError> {
+        query.validate()?;
+        let mut hands = self.matching(|hand| {
+            query.matches(hand) && cursor.is_none_or(|cursor| cursor.precedes(hand))
+        })?;
+        hands.reverse();
+        hands.truncate(limit.saturating_add(1));
+        Ok(HandPage::from_lookahead(hands, limit))
+    }
+}
+
+/// Player statistics store keeping every player's stats in memory.
+///
+/// Per-period stats are kept per player and period start. Leaderboards break ties by
+/// the player with more hands played, then by player ID, so their order is
+/// deterministic.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemoryPlayerStatsStore;
+/// use riverrun::core::ports::outbound::{PlayerStats, PlayerStatsStore};
+///
+/// let store = InMemoryPlayerStatsStore::new();
+/// for (player, played, won) in [("alice", 10, 6), ("bob", 40, 12)] {
+///     store
+///         .save(&PlayerStats {
+///             player_id: player.to_string(),
+///             hands_played: played,
+///             hands_won: won,
+///             last_played_at: None,
+///             ..PlayerStats::default()
+///         })
+///         .unwrap();
+/// }
+///
+/// assert_eq!(store.top_by_wins(1).unwrap()[0].player_id, "bob");
+/// assert_eq!(store.top_by_win_rate(5, 1).unwrap()[0].player_id, "alice");
+/// assert_eq!(store.get(&"carol".to_string()).unwrap().hands_played, 0);
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryPlayerStatsStore {
+    players: RwLock<HashMap<PlayerId, PlayerStats>>,
+    periods: RwLock<HashMap<(PlayerId, Timestamp), PeriodStats>>,
+}
+
+/// `InMemoryPlayerStatsStore` - Constructors
+impl InMemoryPlayerStatsStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// `InMemoryPlayerStatsStore` - Accessors
+impl InMemoryPlayerStatsStore {
+    /// Number of players with saved stats.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` if the lock was poisoned.
+    pub fn player_count(&self) -> Result<usize, ReadModelError> {
+        Ok(self.players.read().map_err(poisoned)?.len())
+    }
+
+    /// Stored stats matching `filter`.
+    fn matching(
+        &self,
+        filter: impl Fn(&PlayerStats) -> bool,
+    ) -> Result<Vec<PlayerStats>, ReadModelError> {
+        Ok(self
+            .players
+            .read()
+            .map_err(poisoned)?
+            .values()
+            .filter(|stats| filter(stats))
+            .cloned()
+            .collect())
+    }
+}
+
+impl PlayerStatsStore for InMemoryPlayerStatsStore {
+    fn get(&self, player_id: &PlayerId) -> Result<PlayerStats, ReadModelError> {
+        let players = self.players.read().map_err(poisoned)?;
+        Ok(players.get(player_id).cloned().unwrap_or_else(|| PlayerStats {
+            player_id: player_id.clone(),
+            ..PlayerStats::default()
+        }))
+    }
+
+    fn save(&self, stats: &PlayerStats) -> Result<(), ReadModelError> {
+        self.players
+            .write()
+            .map_err(poisoned)?
+            .insert(stats.player_id.clone(), stats.clone());
+        Ok(())
+    }
+
+    fn top_by_wins(&self, limit: usize) -> Result<Vec<PlayerStats>, ReadModelError> {
+        let mut players = self.matching(|_| true)?;
+        players.sort_by(|a, b| {
+            b.hands_won
+                .cmp(&a.hands_won)
+                .then_with(|| b.hands_played.cmp(&a.hands_played))
+                .then_with(|| a.player_id.cmp(&b.player_id))
+        });
+        players.truncate(limit);
+        Ok(players)
+    }
+
+    fn top_by_win_rate(
+        &self,
+        min_hands: u64,
+        limit: usize,
+    ) -> Result<Vec<PlayerStats>, ReadModelError> {
+        let mut players = self.matching(|stats| stats.hands_played >= min_hands)?;
+        players.sort_by(|a, b| {
+            b.win_rate()
+                .total_cmp(&a.win_rate())
+                .then_with(|| b.hands_played.cmp(&a.hands_played))
+                .then_with(|| a.player_id.cmp(&b.player_id))
+        });
+        players.truncate(limit);
+        Ok(players)
+    }
+
+    fn get_period(
+        &self,
+        player_id: &PlayerId,
+        at: Timestamp,
+    ) -> Result<PeriodStats, ReadModelError> {
+        let periods = self.periods.read().map_err(poisoned)?;
+        Ok(periods
+            .get(&(player_id.clone(), period_start(at)))
+            .cloned()
+            .unwrap_or_else(|| PeriodStats::new(player_id.clone(), at)))
+    }
+
+    fn save_period(&self, stats: &PeriodStats) -> Result<(), ReadModelError> {
+        self.periods.write().map_err(poisoned)?.insert(
+            (stats.player_id.clone(), stats.period_start),
+            stats.clone(),
+        );
+        Ok(())
+    }
+
+    fn top_by_wins_since(
+        &self,
+        from: Timestamp,
+        limit: usize,
+    ) -> Result<Vec<PeriodStats>, ReadModelError> {
+        let from = period_start(from);
+        let mut totals: HashMap<PlayerId, PeriodStats> = HashMap::new();
+        for stats in self
+            .periods
+            .read()
+            .map_err(poisoned)?
+            .values()
+            .filter(|stats| stats.period_start >= from)
+        {
+            let total = totals
+                .entry(stats.player_id.clone())
+                .or_insert_with(|| PeriodStats::new(stats.player_id.clone(), from));
+            total.hands_played += stats.hands_played;
+            total.hands_won += stats.hands_won;
+        }
+        let mut players: Vec<PeriodStats> = totals.into_values().collect();
+        players.sort_by(|a, b| {
+            b.hands_won
+                .cmp(&a.hands_won)
+                .then_with(|| b.hands_played.cmp(&a.hands_played))
+                .then_with(|| a.player_id.cmp(&b.player_id))
+        });
+        players.truncate(limit);
+        Ok(players)
+    }
+}
+
+/// Session statistics store keeping every session in memory.
+///
+/// A player's sessions are listed in the order they started, then by session ID.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemorySessionStatsStore;
+/// use riverrun::core::ports::outbound::{SessionStats, SessionStatsStore};
+///
+/// let store = InMemorySessionStatsStore::new();
+/// let mut stats = SessionStats::new("session-1".into(), "alice".to_string(), 1_000);
+/// stats.record_buy_in(500, 1_000);
+/// stats.record_hand(100, 0, 2_000);
+/// store.save(&stats).unwrap();
+///
+/// let history = store.bankroll_history(&"alice".to_string()).unwrap();
+/// assert_eq!(history[0].bankroll, -100);
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemorySessionStatsStore {
+    sessions: RwLock<HashMap<SessionId, SessionStats>>,
+}
+
+/// `InMemorySessionStatsStore` - Constructors
+impl InMemorySessionStatsStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SessionStatsStore for InMemorySessionStatsStore {
+    fn save(&self, stats: &SessionStats) -> Result<(), ReadModelError> {
+        self.sessions
+            .write()
+            .map_err(poisoned)?
+            .insert(stats.session_id.clone(), stats.clone());
+        Ok(())
+    }
+
+    fn get(&self, session_id: &SessionId) -> Result<SessionStats, ReadModelError> {
+        self.sessions
+            .read()
+            .map_err(poisoned)?
+            .get(session_id)
+            .cloned()
+            .ok_or_else(|| ReadModelError::NotFound(session_id.to_string()))
+    }
+
+    fn find_by_player(&self, player_id: &PlayerId) -> Result<Vec<SessionStats>, ReadModelError> {
+        let mut sessions: Vec<SessionStats> = self
+            .sessions
+            .read()
+            .map_err(poisoned)?
+            .values()
+            .filter(|stats| &stats.player_id == player_id)
+            .cloned()
+            .collect();
+        sessions.sort_by(|a, b| {
+            a.started_at
+                .cmp(&b.started_at)
+                .then_with(|| a.session_id.cmp(&b.session_id))
+        });
+        Ok(sessions)
+    }
+}
+
+/// Active game store keeping every running game's state in memory.
+///
+/// Games are listed most recently updated first.
+#[derive(Debug, Default)]
+pub struct InMemoryActiveGameStore {
+    games: RwLock<HashMap<GameId, ActiveGameState>>,
+}
+
+/// `InMemoryActiveGameStore` - Constructors
+impl InMemoryActiveGameStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ActiveGameStore for InMemoryActiveGameStore {
+    fn save(&self, state: &ActiveGameState) -> Result<(), ReadModelError> {
+        self.games
+            .write()
+            .map_err(poisoned)?
+            .insert(state.game_id.clone(), state.clone());
+        Ok(())
+    }
+
+    fn get(&self, game_id: &GameId) -> Result<ActiveGameState, ReadModelError> {
+        self.games
+            .read()
+            .map_err(poisoned)?
+            .get(game_id)
+            .cloned()
+            .ok_or_else(|| ReadModelError::NotFound(game_id.to_string()))
+    }
+
+    fn remove(&self, game_id: &GameId) -> Result<(), ReadModelError> {
+        self.games.write().map_err(poisoned)?.remove(game_id);
+        Ok(())
+    }
+
+    fn list_active(&self) -> Result<Vec<ActiveGameState>, ReadModelError> {
+        let mut games: Vec<ActiveGameState> =
+            self.games.read().map_err(poisoned)?.values().cloned().collect();
+        games.sort_by_key(|game| (Reverse(game.updated_at), game.game_id.clone()));
+        Ok(games)
+    }
+
+    fn count_active(&self) -> Result<u64, ReadModelError> {
+        Ok(self.games.read().map_err(poisoned)?.len() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ports::outbound::{LeaderboardWindow, STATS_PERIOD_MILLIS};
+
+    fn hand(id: &str, game: &str, started_at: Timestamp, players: &[&str]) -> HandSummary {
+        HandSummary {
+            hand_id: id.to_string(),
+            game_id: game.into(),
+            started_at,
+            ended_at: started_at + 100,
+            num_players: players.len(),
+            player_ids: players.iter().map(ToString::to_string).collect(),
+            winner_ids: players.iter().take(1).map(ToString::to_string).collect(),
+            is_tie: false,
+            winning_hand_rank: None,
+            pot: 0,
+            all_in_ev: Vec::new(),
+        }
+    }
+
+    fn ids(hands: &[HandSummary]) -> Vec<&str> {
+        hands.iter().map(|hand| hand.hand_id.as_str()).collect()
+    }
+
+    fn stats(player: &str, hands_played: u64, hands_won: u64) -> PlayerStats {
+        PlayerStats {
+            player_id: player.to_string(),
+            hands_played,
+            hands_won,
+            last_played_at: None,
+            ..PlayerStats::default()
+        }
+    }
+
+    fn session(id: &str, player: &str, started_at: Timestamp, results: &[(u64, u64)]) -> SessionStats {
+        let mut stats = SessionStats::new(id.into(), player.to_string(), started_at);
+        stats.record_buy_in(1000, started_at);
+        for (offset, &(contributed, awarded)) in (1..).zip(results) {
+            stats.record_hand(contributed, awarded, started_at + offset);
+        }
+        stats
+    }
+
+    fn game(id: &str, updated_at: Timestamp) -> ActiveGameState {
+        ActiveGameState {
+            game_id: id.into(),
+            num_players: 2,
+            player_ids: vec!["alice".to_string(), "bob".to_string()],
+            current_street: "preflop".to_string(),
+            is_showdown: false,
+            updated_at,
+        }
+    }
+
+    #[test]
+    fn test_hand_summary_save_and_get() {
+        let store = InMemoryHandSummaryStore::new();
+        store.save(&hand("h1", "g1", 10, &["alice"])).unwrap();
+        store.save(&hand("h1", "g2", 20, &["bob"])).unwrap();
+
+        assert_eq!(store.hand_count(), Ok(1));
+        assert_eq!(store.get(&"h1".to_string()).unwrap().game_id, GameId::from("g2"));
+        assert_eq!(
+            store.get(&"h2".to_string()).unwrap_err(),
+            ReadModelError::NotFound("h2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_find_by_player_paginates_newest_first() {
+        let store = InMemoryHandSummaryStore::new();
+        for (index, started_at) in [30, 10, 50, 20, 40].into_iter().enumerate() {
+            store
+                .save(&hand(&format!("h{index}"), "g1", started_at, &["alice", "bob"]))
+                .unwrap();
+        }
+        store.save(&hand("other", "g1", 60, &["carol"])).unwrap();
+        let alice = "alice".to_string();
+
+        assert_eq!(ids(&store.find_by_player(&alice, 2, 0).unwrap()), ["h2", "h4"]);
+        assert_eq!(ids(&store.find_by_player(&alice, 2, 2).unwrap()), ["h0", "h3"]);
+        assert_eq!(ids(&store.find_by_player(&alice, 2, 4).unwrap()), ["h1"]);
+        assert!(store.find_by_player(&alice, 2, 6).unwrap().is_empty());
+        assert_eq!(store.count_by_player(&alice), Ok(5));
+        assert_eq!(store.count_by_player(&"dave".to_string()), Ok(0));
+    }
+
+    #[test]
+    fn test_find_by_time_range_and_game() {
+        let store = InMemoryHandSummaryStore::new();
+        store.save(&hand("h1", "g1", 100, &["alice"])).unwrap();
+        store.save(&hand("h2", "g2", 200, &["alice"])).unwrap();
+        store.save(&hand("h3", "g1", 300, &["alice"])).unwrap();
+
+        assert_eq!(ids(&store.find_by_time_range(100, 200, 10).unwrap()), ["h1", "h2"]);
+        assert_eq!(ids(&store.find_by_time_range(0, 1000, 1).unwrap()), ["h1"]);
+        assert!(matches!(
+            store.find_by_time_range(300, 100, 10),
+            Err(ReadModelError::InvalidQuery(_))
+        ));
+        assert_eq!(ids(&store.find_by_game(&"g1".into()).unwrap()), ["h1", "h3"]);
+    }
+
+    #[test]
+    fn test_search_filters_and_pages_by_cursor() {
+        let store = InMemoryHandSummaryStore::new();
+        for (id, started_at, rank, pot, is_tie) in [
+            ("h1", 100, "Flush", 500, false),
+            ("h2", 200, "Full House", 50, false),
+            ("h3", 200, "flush", 900, true),
+            ("h4", 300, "Flush", 700, false),
+            ("h5", 400, "Flush", 800, false),
+        ] {
+            store
+                .save(&HandSummary {
+                    winning_hand_rank: Some(rank.to_string()),
+                    pot,
+                    is_tie,
+                    ..hand(id, "g1", started_at, &["alice", "bob"])
+                })
+                .unwrap();
+        }
+        store.save(&hand("other", "g1", 250, &["carol"])).unwrap();
+
+        let query = HandQuery::new()
+            .player("alice".to_string())
+            .between(100, 300)
+            .winning_hand_rank("Flush")
+            .min_pot(500);
+        let first = store.search(&query, None, 2).unwrap();
+        assert_eq!(ids(&first.hands), ["h4", "h3"]);
+        // A hand saved after the first page does not shift the second
+        store.save(&hand("h0", "g1", 250, &["alice"])).unwrap();
+        let second = store.search(&query, first.next.as_ref(), 2).unwrap();
+        assert_eq!(ids(&second.hands), ["h1"]);
+        assert!(second.next.is_none());
+
+        let ties = store.search(&HandQuery::new().ties_only(), None, 10).unwrap();
+        assert_eq!(ids(&ties.hands), ["h3"]);
+        assert!(matches!(
+            store.search(&HandQuery::new().between(300, 100), None, 10),
+            Err(ReadModelError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_player_stats_leaderboards() {
+        let store = InMemoryPlayerStatsStore::new();
+        store.save(&stats("alice", 10, 5)).unwrap();
+        store.save(&stats("bob", 100, 30)).unwrap();
+        store.save(&stats("carol", 20, 10)).unwrap();
+        store.save(&stats("dave", 2, 2)).unwrap();
+
+        let names = |players: Vec<PlayerStats>| -> Vec<String> {
+            players.into_iter().map(|stats| stats.player_id).collect()
+        };
+        assert_eq!(names(store.top_by_wins(3).unwrap()), ["bob", "carol", "alice"]);
+        assert_eq!(
+            names(store.top_by_win_rate(10, 10).unwrap()),
+            ["carol", "alice", "bob"]
+        );
+        assert_eq!(names(store.top_by_win_rate(0, 1).unwrap()), ["dave"]);
+        assert_eq!(store.player_count(), Ok(4));
+    }
+
+    #[test]
+    fn test_player_stats_windowed_leaderboards() {
+        const DAY: Timestamp = STATS_PERIOD_MILLIS;
+        let store = InMemoryPlayerStatsStore::new();
+        let now = 40 * DAY + 5;
+        for (player, days_ago, played, won) in [
+            ("alice", 0, 10, 4),
+            ("alice", 6, 10, 4),
+            ("bob", 1, 20, 5),
+            ("bob", 20, 50, 40),
+            ("carol", 7, 30, 25),
+        ] {
+            let mut period = store.get_period(&player.to_string(), now - days_ago * DAY).unwrap();
+            period.hands_played += played;
+            period.hands_won += won;
+            store.save_period(&period).unwrap();
+        }
+
+        let names = |players: Vec<PeriodStats>| -> Vec<(String, u64)> {
+            players.into_iter().map(|stats| (stats.player_id, stats.hands_won)).collect()
+        };
+        let weekly = store.leaderboard(LeaderboardWindow::Weekly, now, 10).unwrap();
+        assert_eq!(weekly[0].period_start, 34 * DAY);
+        assert_eq!(names(weekly), [("alice".to_string(), 8), ("bob".to_string(), 5)]);
+        assert_eq!(
+            names(store.leaderboard(LeaderboardWindow::Monthly, now, 2).unwrap()),
+            [("bob".to_string(), 45), ("carol".to_string(), 25)]
+        );
+        assert!(store.top_by_wins_since(now + DAY, 10).unwrap().is_empty());
+        // Period stats are kept apart from the all-time totals
+        assert_eq!(store.player_count(), Ok(0));
+    }
+
+    #[test]
+    fn test_player_stats_get_defaults_missing_player() {
+        let store = InMemoryPlayerStatsStore::new();
+        let stats = store.get(&"eve".to_string()).unwrap();
+        assert_eq!(stats.player_id, "eve");
+        assert_eq!(stats.hands_played, 0);
+        assert_eq!(store.player_count(), Ok(0));
+    }
+
+    #[test]
+    fn test_active_games() {
+        let store = InMemoryActiveGameStore::new();
+        store.save(&game("g1", 10)).unwrap();
+        store.save(&game("g2", 30)).unwrap();
+        store.save(&game("g3", 20)).unwrap();
+
+        let listed: Vec<GameId> = store
+            .list_active()
+            .unwrap()
+            .into_iter()
+            .map(|state| state.game_id)
+            .collect();
+        assert_eq!(listed, ["g2", "g3", "g1"].map(GameId::from));
+
+        store.remove(&"g2".into()).unwrap();
+        assert_eq!(store.count_active(), Ok(2));
+        assert!(matches!(
+            store.get(&"g2".into()),
+            Err(ReadModelError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_session_stats_and_bankroll_history() {
+        let store = InMemorySessionStatsStore::new();
+        // The second session starts while the first is still being played
+        store.save(&session("s1", "alice", 100, &[(50, 0), (100, 300)])).unwrap();
+        store.save(&session("s2", "alice", 101, &[(200, 0)])).unwrap();
+        store.save(&session("s3", "bob", 50, &[(10, 0)])).unwrap();
+
+        let alice = "alice".to_string();
+        let sessions: Vec<SessionId> = store
+            .find_by_player(&alice)
+            .unwrap()
+            .into_iter()
+            .map(|stats| stats.session_id)
+            .collect();
+        assert_eq!(sessions, ["s1", "s2"].map(SessionId::from));
+        assert_eq!(store.get(&"s1".into()).unwrap().net_result(), 150);
+        assert!(matches!(
+            store.get(&"s9".into()),
+            Err(ReadModelError::NotFound(_))
+        ));
+
+        let history: Vec<(Timestamp, i64)> = store
+            .bankroll_history(&alice)
+            .unwrap()
+            .into_iter()
+            .map(|point| (point.timestamp, point.bankroll))
+            .collect();
+        assert_eq!(history, [(101, -50), (102, 150), (102, -50)]);
+    }
+}
@@ -0,0 +1,146 @@
+//! In-memory result cache adapter.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+use crate::core::ports::outbound::{CacheKey, ResultCache, ResultCacheError};
+use crate::telemetry;
+
+/// Result cache keeping up to a fixed number of entries in memory.
+///
+/// Once full, storing a new entry evicts the least recently used one.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemoryResultCache;
+/// use riverrun::core::ports::outbound::{CacheKey, ResultCache};
+///
+/// let cache = InMemoryResultCache::new(2);
+/// let key = |name: &str| CacheKey::builder(name).finish();
+/// cache.put(&key("a"), b"first").unwrap();
+/// cache.put(&key("b"), b"second").unwrap();
+/// assert_eq!(cache.get(&key("a")).unwrap(), Some(b"first".to_vec()));
+/// // "b" is now the least recently used entry
+/// cache.put(&key("c"), b"third").unwrap();
+/// assert_eq!(cache.get(&key("b")).unwrap(), None);
+/// ```
+#[derive(Debug)]
+pub struct InMemoryResultCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+/// Cached values with the tick of their last use.
+#[derive(Debug, Default)]
+struct Entries {
+    values: HashMap<CacheKey, (u64, Vec<u8>)>,
+    tick: u64,
+}
+
+/// `InMemoryResultCache` - Constructors
+impl InMemoryResultCache {
+    /// Create an empty cache holding at most `capacity` entries, and at least one.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+}
+
+/// `InMemoryResultCache` - Accessors
+impl InMemoryResultCache {
+    /// Most entries held at once.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of entries cached.
+    ///
+    /// # Errors
+    /// Returns `ResultCacheError::StorageError` if the lock was poisoned.
+    pub fn len(&self) -> Result<usize, ResultCacheError> {
+        Ok(self.entries.lock().map_err(poisoned)?.values.len())
+    }
+
+    /// Whether nothing is cached.
+    ///
+    /// # Errors
+    /// Returns `ResultCacheError::StorageError` if the lock was poisoned.
+    pub fn is_empty(&self) -> Result<bool, ResultCacheError> {
+        Ok(self.len()? == 0)
+    }
+}
+
+fn poisoned<T>(_: PoisonError<T>) -> ResultCacheError {
+    ResultCacheError::StorageError("result cache lock poisoned".to_string())
+}
+
+impl ResultCache for InMemoryResultCache {
+    fn get(&self, key: &CacheKey) -> Result<Option<Vec<u8>>, ResultCacheError> {
+        let mut entries = self.entries.lock().map_err(poisoned)?;
+        entries.tick += 1;
+        let tick = entries.tick;
+        let value = entries.values.get_mut(key).map(|(used, value)| {
+            *used = tick;
+            value.clone()
+        });
+        drop(entries);
+        telemetry::cache_lookup("in_memory_result_cache", value.is_some());
+        Ok(value)
+    }
+
+    fn put(&self, key: &CacheKey, value: &[u8]) -> Result<(), ResultCacheError> {
+        let mut entries = self.entries.lock().map_err(poisoned)?;
+        entries.tick += 1;
+        let tick = entries.tick;
+        if !entries.values.contains_key(key) && entries.values.len() >= self.capacity {
+            let oldest = entries.values.iter().min_by_key(|(_, (used, _))| *used).map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.values.remove(&oldest);
+            }
+        }
+        entries.values.insert(key.clone(), (tick, value.to_vec()));
+        drop(entries);
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(), ResultCacheError> {
+        self.entries.lock().map_err(poisoned)?.values.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(name: &str) -> CacheKey {
+        CacheKey::builder(name).finish()
+    }
+
+    #[test]
+    fn test_replacing_an_entry_evicts_nothing() {
+        let cache = InMemoryResultCache::new(2);
+        cache.put(&key("a"), b"1").unwrap();
+        cache.put(&key("b"), b"2").unwrap();
+        cache.put(&key("a"), b"3").unwrap();
+        assert_eq!(cache.len().unwrap(), 2);
+        assert_eq!(cache.get(&key("a")).unwrap(), Some(b"3".to_vec()));
+        assert_eq!(cache.get(&key("b")).unwrap(), Some(b"2".to_vec()));
+    }
+
+    #[test]
+    fn test_clear_and_minimum_capacity() {
+        let cache = InMemoryResultCache::new(0);
+        assert_eq!(cache.capacity(), 1);
+        cache.put(&key("a"), b"1").unwrap();
+        cache.put(&key("b"), b"2").unwrap();
+        assert_eq!(cache.get(&key("a")).unwrap(), None);
+        cache.clear().unwrap();
+        assert!(cache.is_empty().unwrap());
+    }
+}
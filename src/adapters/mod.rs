@@ -0,0 +1,33 @@
+//! Adapters (driven side) implementing the outbound ports.
+//!
+//! Each adapter connects a port trait from `core::ports::outbound` to a concrete
+//! storage or transport mechanism, or translates domain data to an external format.
+
+#[cfg(feature = "serde")]
+pub mod audit;
+pub mod fair_shuffle;
+#[cfg(feature = "serde")]
+pub mod file;
+pub mod hand_history;
+pub mod in_memory;
+#[cfg(feature = "redis")]
+pub mod redis;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+#[cfg(feature = "ws")]
+pub mod ws;
+
+pub use fair_shuffle::{SeedCommitment, VerifiableShuffler};
+#[cfg(feature = "serde")]
+pub use file::{FileEventStore, FileResultCache};
+pub use hand_history::{HandHistoryParser, HandHistoryWriter};
+pub use in_memory::{
+    InMemoryActiveGameStore, InMemoryEventStore, InMemoryHandSummaryStore, InMemoryPlayerStatsStore,
+    InMemoryResultCache, InMemorySessionStatsStore,
+};
+#[cfg(feature = "redis")]
+pub use redis::{RedisActiveGameStore, RedisPublisher};
+#[cfg(feature = "sqlite")]
+pub use sqlite::{SqliteEventStore, SqliteHandSummaryStore, SqlitePlayerStatsStore};
+#[cfg(feature = "ws")]
+pub use ws::WebSocketPublisher;
@@ -0,0 +1,245 @@
+//! Active game store shared between server instances through Redis.
+
+use std::cmp::Reverse;
+use std::io;
+use std::net::ToSocketAddrs;
+use std::time::Duration;
+
+use super::KEY_PREFIX;
+use super::resp::{Client, Reply};
+use crate::core::ports::outbound::{ActiveGameState, ActiveGameStore, GameId, ReadModelError};
+
+/// How long a game stays listed without being saved again, unless configured otherwise.
+pub const DEFAULT_GAME_TTL: Duration = Duration::from_hours(1);
+
+#[allow(clippy::needless_pass_by_value)]
+fn storage(err: io::Error) -> ReadModelError {
+    ReadModelError::StorageError(err.to_string())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn serialization(err: serde_json::Error) -> ReadModelError {
+    ReadModelError::StorageError(format!("invalid active game state: {err}"))
+}
+
+/// Active game store keeping each game's state in Redis, so every server instance
+/// sees the same games.
+///
+/// Each game is stored as JSON under `riverrun:game:{game_id}` with a time to live
+/// that every `save` renews, so games whose server stopped updating them expire on
+/// their own. A sorted set, `riverrun:games`, indexes the games by `updated_at`;
+/// expired games are pruned from it whenever the games are listed or counted.
+///
+/// Games are listed most recently updated first, like `InMemoryActiveGameStore`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use riverrun::adapters::RedisActiveGameStore;
+/// use riverrun::core::ports::outbound::ActiveGameStore;
+///
+/// let store = RedisActiveGameStore::connect_with_ttl("127.0.0.1:6379", Duration::from_secs(600))
+///     .unwrap();
+/// println!("{} games running", store.count_active().unwrap());
+/// ```
+#[derive(Debug)]
+pub struct RedisActiveGameStore {
+    client: Client,
+    ttl: Duration,
+}
+
+/// `RedisActiveGameStore` - Constructors
+impl RedisActiveGameStore {
+    /// Connect to the Redis server at `addr`, expiring games after `DEFAULT_GAME_TTL`.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` if the server cannot be reached.
+    pub fn connect(addr: impl ToSocketAddrs) -> Result<Self, ReadModelError> {
+        Self::connect_with_ttl(addr, DEFAULT_GAME_TTL)
+    }
+
+    /// Connect to the Redis server at `addr`, expiring games not saved for `ttl`.
+    ///
+    /// The TTL is rounded down to whole seconds, with a minimum of one second.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` if the server cannot be reached.
+    pub fn connect_with_ttl(
+        addr: impl ToSocketAddrs,
+        ttl: Duration,
+    ) -> Result<Self, ReadModelError> {
+        Ok(Self {
+            client: Client::connect(addr).map_err(storage)?,
+            ttl,
+        })
+    }
+}
+
+/// `RedisActiveGameStore` - Accessors
+impl RedisActiveGameStore {
+    /// How long a game stays listed without being saved again.
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Key holding `game_id`'s state.
+    fn game_key(game_id: &str) -> String {
+        format!("{KEY_PREFIX}game:{game_id}")
+    }
+
+    /// Key of the sorted set indexing games by `updated_at`.
+    fn index_key() -> String {
+        format!("{KEY_PREFIX}games")
+    }
+}
+
+/// `RedisActiveGameStore` - Helpers
+impl RedisActiveGameStore {
+    fn command(&self, args: &[&[u8]]) -> Result<Reply, ReadModelError> {
+        self.client
+            .command(args)
+            .and_then(Reply::check)
+            .map_err(storage)
+    }
+}
+
+impl ActiveGameStore for RedisActiveGameStore {
+    #[allow(clippy::cast_precision_loss)]
+    fn save(&self, state: &ActiveGameState) -> Result<(), ReadModelError> {
+        let json = serde_json::to_vec(state).map_err(serialization)?;
+        let ttl = self.ttl.as_secs().max(1).to_string();
+        let game_key = Self::game_key(state.game_id.as_str());
+        let score = (state.updated_at as f64).to_string();
+
+        self.command(&[b"SET", game_key.as_bytes(), &json, b"EX", ttl.as_bytes()])?;
+        self.command(&[
+            b"ZADD",
+            Self::index_key().as_bytes(),
+            score.as_bytes(),
+            state.game_id.as_str().as_bytes(),
+        ])?;
+        Ok(())
+    }
+
+    fn get(&self, game_id: &GameId) -> Result<ActiveGameState, ReadModelError> {
+        match self.command(&[b"GET", Self::game_key(game_id.as_str()).as_bytes()])? {
+            Reply::Bulk(Some(json)) => serde_json::from_slice(&json).map_err(serialization),
+            Reply::Bulk(None) => Err(ReadModelError::NotFound(game_id.to_string())),
+            other => Err(ReadModelError::StorageError(format!(
+                "unexpected reply {other:?}"
+            ))),
+        }
+    }
+
+    fn remove(&self, game_id: &GameId) -> Result<(), ReadModelError> {
+        self.command(&[b"DEL", Self::game_key(game_id.as_str()).as_bytes()])?;
+        self.command(&[b"ZREM", Self::index_key().as_bytes(), game_id.as_str().as_bytes()])?;
+        Ok(())
+    }
+
+    fn list_active(&self) -> Result<Vec<ActiveGameState>, ReadModelError> {
+        let index_key = Self::index_key();
+        let game_ids: Vec<Vec<u8>> = self
+            .command(&[b"ZREVRANGE", index_key.as_bytes(), b"0", b"-1"])?
+            .into_bulks()
+            .map_err(storage)?
+            .into_iter()
+            .flatten()
+            .collect();
+        if game_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let game_keys: Vec<Vec<u8>> = game_ids
+            .iter()
+            .map(|game_id| Self::game_key(&String::from_utf8_lossy(game_id)).into_bytes())
+            .collect();
+        let mut mget: Vec<&[u8]> = vec![b"MGET"];
+        mget.extend(game_keys.iter().map(Vec::as_slice));
+        let values = self.command(&mget)?.into_bulks().map_err(storage)?;
+
+        let mut games = Vec::with_capacity(values.len());
+        let mut expired: Vec<&[u8]> = vec![b"ZREM", index_key.as_bytes()];
+        for (game_id, value) in game_ids.iter().zip(values) {
+            match value {
+                Some(json) => games.push(serde_json::from_slice(&json).map_err(serialization)?),
+                None => expired.push(game_id),
+            }
+        }
+        if expired.len() > 2 {
+            self.command(&expired)?;
+        }
+
+        games
+            .sort_by_key(|game: &ActiveGameState| (Reverse(game.updated_at), game.game_id.clone()));
+        Ok(games)
+    }
+
+    fn count_active(&self) -> Result<u64, ReadModelError> {
+        Ok(self.list_active()?.len() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_server::TestServer;
+    use super::*;
+
+    fn game(id: &str, updated_at: u64) -> ActiveGameState {
+        ActiveGameState {
+            game_id: id.into(),
+            num_players: 2,
+            player_ids: vec!["alice".to_string(), "bob".to_string()],
+            current_street: "flop".to_string(),
+            is_showdown: false,
+            updated_at,
+        }
+    }
+
+    fn ids(games: &[ActiveGameState]) -> Vec<&str> {
+        games.iter().map(|game| game.game_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_save_get_and_list() {
+        let server = TestServer::start();
+        let store =
+            RedisActiveGameStore::connect_with_ttl(server.addr, Duration::from_secs(30)).unwrap();
+
+        store.save(&game("g1", 100)).unwrap();
+        store.save(&game("g2", 300)).unwrap();
+        store.save(&game("g3", 200)).unwrap();
+
+        assert_eq!(store.get(&"g2".into()).unwrap().updated_at, 300);
+        assert_eq!(ids(&store.list_active().unwrap()), ["g2", "g3", "g1"]);
+        assert_eq!(
+            server.state.lock().unwrap().strings[b"riverrun:game:g1".as_slice()].1,
+            Some(30)
+        );
+
+        store.remove(&"g3".into()).unwrap();
+        assert_eq!(
+            store.get(&"g3".into()).unwrap_err(),
+            ReadModelError::NotFound("g3".to_string())
+        );
+        assert_eq!(store.count_active().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_expired_games_are_pruned() {
+        let server = TestServer::start();
+        let store = RedisActiveGameStore::connect(server.addr).unwrap();
+        store.save(&game("g1", 100)).unwrap();
+        store.save(&game("g2", 200)).unwrap();
+
+        server.expire("riverrun:game:g2");
+        assert_eq!(ids(&store.list_active().unwrap()), ["g1"]);
+        assert_eq!(
+            server.state.lock().unwrap().sorted_sets[b"riverrun:games".as_slice()].len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Redis adapters for sharing live state between server instances.
+//!
+//! Speaks just enough of the RESP2 protocol to send commands and read replies, so no
+//! Redis client dependency is needed.
+
+mod active_game_store;
+mod publisher;
+mod resp;
+#[cfg(test)]
+mod test_server;
+
+pub use active_game_store::{DEFAULT_GAME_TTL, RedisActiveGameStore};
+pub use publisher::RedisPublisher;
+
+/// Prefix of every key and channel the adapters use.
+const KEY_PREFIX: &str = "riverrun:";
@@ -0,0 +1,98 @@
+//! Notification publisher fanning out through Redis pub/sub.
+
+use std::io;
+use std::net::ToSocketAddrs;
+
+use super::KEY_PREFIX;
+use super::resp::Client;
+use crate::core::ports::outbound::{
+    GameNotification, NotificationPublisher, VersionedNotification,
+};
+
+/// Publisher sending each notification to a Redis pub/sub channel for its game.
+///
+/// Notifications for `game_id` go to the channel returned by `channel`,
+/// `riverrun:games:{game_id}`, as the JSON form of its `VersionedNotification`. Server
+/// instances subscribe to the games their clients watch, or to the pattern
+/// `riverrun:games:*` for every game, so a notification raised on one instance reaches
+/// clients connected to any other.
+///
+/// Publishing is best effort: a notification that cannot be sent is dropped, and the
+/// connection is reopened for the next one.
+///
+/// # Examples
+///
+/// ```no_run
+/// use riverrun::adapters::RedisPublisher;
+/// use riverrun::core::ports::outbound::{GameNotification, NotificationPublisher};
+///
+/// let publisher = RedisPublisher::connect("127.0.0.1:6379").unwrap();
+/// publisher.publish(GameNotification::GameEnded {
+///     game_id: "game-1".into(),
+///     timestamp: 0,
+/// });
+/// ```
+#[derive(Debug)]
+pub struct RedisPublisher {
+    client: Client,
+}
+
+/// `RedisPublisher` - Constructors
+impl RedisPublisher {
+    /// Connect to the Redis server at `addr`.
+    ///
+    /// # Errors
+    /// Returns an error if the server cannot be reached.
+    pub fn connect(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        Ok(Self {
+            client: Client::connect(addr)?,
+        })
+    }
+}
+
+/// `RedisPublisher` - Accessors
+impl RedisPublisher {
+    /// Channel that notifications for `game_id` are published to.
+    #[must_use]
+    pub fn channel(game_id: &str) -> String {
+        format!("{KEY_PREFIX}games:{game_id}")
+    }
+}
+
+impl NotificationPublisher for RedisPublisher {
+    fn publish(&self, notification: GameNotification) {
+        let channel = Self::channel(notification.game_id().as_str());
+        let Ok(json) = serde_json::to_string(&VersionedNotification::new(notification)) else {
+            return;
+        };
+        // Delivery is best effort; the client reconnects on the next publish
+        let _ = self
+            .client
+            .command(&[b"PUBLISH", channel.as_bytes(), json.as_bytes()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_server::TestServer;
+    use super::*;
+
+    #[test]
+    fn test_publishes_to_game_channel() {
+        let server = TestServer::start();
+        let publisher = RedisPublisher::connect(server.addr).unwrap();
+
+        publisher.publish(GameNotification::GameEnded {
+            game_id: "g1".into(),
+            timestamp: 7,
+        });
+
+        assert_eq!(
+            server.state.lock().unwrap().published,
+            [(
+                "riverrun:games:g1".to_string(),
+                r#"{"version":3,"type":"game_ended","game_id":"g1","timestamp":7}"#.to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! Minimal RESP2 client: sends commands and reads their replies.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+/// How long to wait for the server to connect, accept a command, or reply.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A reply to a Redis command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    /// A status such as `OK`.
+    Status(String),
+    /// An error reported by the server.
+    Error(String),
+    /// An integer.
+    Integer(i64),
+    /// A binary-safe string, or `None` for a missing value.
+    Bulk(Option<Vec<u8>>),
+    /// Nested replies, or `None` for a missing array.
+    Array(Option<Vec<Self>>),
+}
+
+/// `Reply` - Accessors
+impl Reply {
+    /// Fails with the server's message if this is an error reply.
+    pub fn check(self) -> io::Result<Self> {
+        match self {
+            Self::Error(message) => Err(io::Error::other(message)),
+            reply => Ok(reply),
+        }
+    }
+
+    /// The values of an array of bulk strings, with `None` for missing values.
+    pub fn into_bulks(self) -> io::Result<Vec<Option<Vec<u8>>>> {
+        match self.check()? {
+            Self::Array(items) => items
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| match item {
+                    Self::Bulk(value) => Ok(value),
+                    other => Err(unexpected(&other)),
+                })
+                .collect(),
+            other => Err(unexpected(&other)),
+        }
+    }
+}
+
+fn unexpected(reply: &Reply) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unexpected reply {reply:?}"),
+    )
+}
+
+/// Encodes a command as a RESP array of bulk strings.
+pub fn encode(args: &[&[u8]]) -> Vec<u8> {
+    let mut bytes = format!("*{}\r\n", args.len()).into_bytes();
+    for arg in args {
+        bytes.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+        bytes.extend_from_slice(arg);
+        bytes.extend_from_slice(b"\r\n");
+    }
+    bytes
+}
+
+/// Reads one reply from `reader`.
+pub fn read_reply(reader: &mut impl BufRead) -> io::Result<Reply> {
+    let mut line = Vec::new();
+    reader.read_until(b'\n', &mut line)?;
+    let Some(body) = line.strip_suffix(b"\r\n") else {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "connection closed mid-reply",
+        ));
+    };
+    let (&kind, rest) = body
+        .split_first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "empty reply"))?;
+    let text = String::from_utf8_lossy(rest).into_owned();
+
+    match kind {
+        b'+' => Ok(Reply::Status(text)),
+        b'-' => Ok(Reply::Error(text)),
+        b':' => parse_int(&text).map(Reply::Integer),
+        b'$' => {
+            let Ok(len) = usize::try_from(parse_int(&text)?) else {
+                return Ok(Reply::Bulk(None));
+            };
+            let mut value = vec![0; len + 2];
+            reader.read_exact(&mut value)?;
+            value.truncate(len);
+            Ok(Reply::Bulk(Some(value)))
+        }
+        b'*' => {
+            let Ok(len) = usize::try_from(parse_int(&text)?) else {
+                return Ok(Reply::Array(None));
+            };
+            let items = (0..len)
+                .map(|_| read_reply(reader))
+                .collect::<io::Result<_>>()?;
+            Ok(Reply::Array(Some(items)))
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unknown reply type {:?}", char::from(kind)),
+        )),
+    }
+}
+
+fn parse_int(text: &str) -> io::Result<i64> {
+    text.parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("bad integer {text:?}")))
+}
+
+/// A lazily opened connection that is reopened after any failure.
+#[derive(Debug)]
+pub struct Client {
+    addrs: Vec<SocketAddr>,
+    conn: Mutex<Option<BufReader<TcpStream>>>,
+}
+
+/// `Client` - Constructors
+impl Client {
+    /// Connects to the server at `addr`.
+    pub fn connect(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let addrs: Vec<SocketAddr> = addr.to_socket_addrs()?.collect();
+        let conn = open(&addrs)?;
+        Ok(Self {
+            addrs,
+            conn: Mutex::new(Some(conn)),
+        })
+    }
+}
+
+/// `Client` - Operations
+impl Client {
+    /// Sends a command and reads its reply, reconnecting first if an earlier command
+    /// failed.
+    pub fn command(&self, args: &[&[u8]]) -> io::Result<Reply> {
+        let mut conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut reader = match conn.take() {
+            Some(reader) => reader,
+            None => open(&self.addrs)?,
+        };
+        let result = round_trip(&mut reader, args);
+        // After a failure the stream may hold a partial reply, so it is not reused
+        if result.is_ok() {
+            *conn = Some(reader);
+        }
+        drop(conn);
+        result
+    }
+}
+
+fn open(addrs: &[SocketAddr]) -> io::Result<BufReader<TcpStream>> {
+    let mut last_err = io::Error::new(io::ErrorKind::InvalidInput, "no address to connect to");
+    for addr in addrs {
+        match TcpStream::connect_timeout(addr, TIMEOUT) {
+            Ok(stream) => {
+                stream.set_read_timeout(Some(TIMEOUT))?;
+                stream.set_write_timeout(Some(TIMEOUT))?;
+                stream.set_nodelay(true)?;
+                return Ok(BufReader::new(stream));
+            }
+            Err(err) => last_err = err,
+        }
+    }
+    Err(last_err)
+}
+
+fn round_trip(reader: &mut BufReader<TcpStream>, args: &[&[u8]]) -> io::Result<Reply> {
+    reader.get_mut().write_all(&encode(args))?;
+    read_reply(reader)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(
+            encode(&[b"SET", b"key", b"a b"]),
+            b"*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$3\r\na b\r\n"
+        );
+    }
+
+    #[test]
+    fn test_read_reply() {
+        let mut input: &[u8] =
+            b"+OK\r\n-ERR wrong\r\n:42\r\n$-1\r\n*2\r\n$5\r\nhe\r\no\r\n$-1\r\n*-1\r\n";
+        assert_eq!(
+            read_reply(&mut input).unwrap(),
+            Reply::Status("OK".to_string())
+        );
+        assert!(read_reply(&mut input).unwrap().check().is_err());
+        assert_eq!(read_reply(&mut input).unwrap(), Reply::Integer(42));
+        assert_eq!(read_reply(&mut input).unwrap(), Reply::Bulk(None));
+        assert_eq!(
+            read_reply(&mut input).unwrap().into_bulks().unwrap(),
+            [Some(b"he\r\no".to_vec()), None]
+        );
+        assert_eq!(read_reply(&mut input).unwrap(), Reply::Array(None));
+        assert_eq!(
+            read_reply(&mut input).unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+}
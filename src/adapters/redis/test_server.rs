@@ -0,0 +1,139 @@
+//! In-process stand-in for a Redis server, supporting the commands the adapters use.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use super::resp::{read_reply, Reply};
+
+#[derive(Debug, Default)]
+pub struct State {
+    /// String values with their `EX` expiry in seconds, if any.
+    pub strings: HashMap<Vec<u8>, (Vec<u8>, Option<u64>)>,
+    pub sorted_sets: HashMap<Vec<u8>, BTreeMap<Vec<u8>, f64>>,
+    /// Every `PUBLISH` as (channel, message).
+    pub published: Vec<(String, String)>,
+}
+
+pub struct TestServer {
+    pub addr: SocketAddr,
+    pub state: Arc<Mutex<State>>,
+}
+
+impl TestServer {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let state = Arc::new(Mutex::new(State::default()));
+        let shared = Arc::clone(&state);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let state = Arc::clone(&shared);
+                thread::spawn(move || serve(stream, &state));
+            }
+        });
+        Self { addr, state }
+    }
+
+    /// Expires a string key, as if its TTL had run out.
+    pub fn expire(&self, key: &str) {
+        self.state.lock().unwrap().strings.remove(key.as_bytes());
+    }
+}
+
+fn serve(stream: TcpStream, state: &Mutex<State>) {
+    let mut writer = stream.try_clone().unwrap();
+    let mut reader = BufReader::new(stream);
+    while let Ok(Reply::Array(Some(args))) = read_reply(&mut reader) {
+        let args: Vec<Vec<u8>> = args
+            .into_iter()
+            .map(|arg| match arg {
+                Reply::Bulk(Some(arg)) => arg,
+                other => panic!("unexpected argument {other:?}"),
+            })
+            .collect();
+        let reply = execute(&args, &mut state.lock().unwrap());
+        if writer.write_all(&encode_reply(&reply)).is_err() {
+            return;
+        }
+    }
+}
+
+fn execute(args: &[Vec<u8>], state: &mut State) -> Reply {
+    let text = |i: usize| String::from_utf8(args[i].clone()).unwrap();
+    match text(0).to_uppercase().as_str() {
+        "SET" => {
+            let expiry = (args.len() == 5).then(|| text(4).parse().unwrap());
+            state
+                .strings
+                .insert(args[1].clone(), (args[2].clone(), expiry));
+            Reply::Status("OK".to_string())
+        }
+        "GET" => Reply::Bulk(state.strings.get(&args[1]).map(|(value, _)| value.clone())),
+        "MGET" => Reply::Array(Some(
+            args[1..]
+                .iter()
+                .map(|key| Reply::Bulk(state.strings.get(key).map(|(value, _)| value.clone())))
+                .collect(),
+        )),
+        "DEL" => Reply::Integer(i64::from(state.strings.remove(&args[1]).is_some())),
+        "ZADD" => {
+            let set = state.sorted_sets.entry(args[1].clone()).or_default();
+            Reply::Integer(i64::from(
+                set.insert(args[3].clone(), text(2).parse().unwrap())
+                    .is_none(),
+            ))
+        }
+        "ZREM" => {
+            let set = state.sorted_sets.entry(args[1].clone()).or_default();
+            let removed = args[2..]
+                .iter()
+                .filter(|member| set.remove(*member).is_some());
+            Reply::Integer(i64::try_from(removed.count()).unwrap())
+        }
+        "ZREVRANGE" => {
+            let mut members: Vec<_> = state
+                .sorted_sets
+                .get(&args[1])
+                .map(|set| set.iter().collect())
+                .unwrap_or_default();
+            members.sort_by(|a, b| b.1.total_cmp(a.1).then_with(|| b.0.cmp(a.0)));
+            Reply::Array(Some(
+                members
+                    .into_iter()
+                    .map(|(member, _)| Reply::Bulk(Some(member.clone())))
+                    .collect(),
+            ))
+        }
+        "PUBLISH" => {
+            state.published.push((text(1), text(2)));
+            Reply::Integer(0)
+        }
+        command => Reply::Error(format!("ERR unknown command '{command}'")),
+    }
+}
+
+fn encode_reply(reply: &Reply) -> Vec<u8> {
+    match reply {
+        Reply::Status(status) => format!("+{status}\r\n").into_bytes(),
+        Reply::Error(message) => format!("-{message}\r\n").into_bytes(),
+        Reply::Integer(value) => format!(":{value}\r\n").into_bytes(),
+        Reply::Bulk(None) => b"$-1\r\n".to_vec(),
+        Reply::Bulk(Some(value)) => {
+            let mut bytes = format!("${}\r\n", value.len()).into_bytes();
+            bytes.extend_from_slice(value);
+            bytes.extend_from_slice(b"\r\n");
+            bytes
+        }
+        Reply::Array(None) => b"*-1\r\n".to_vec(),
+        Reply::Array(Some(items)) => {
+            let mut bytes = format!("*{}\r\n", items.len()).into_bytes();
+            for item in items {
+                bytes.extend(encode_reply(item));
+            }
+            bytes
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! SQLite-backed event store adapter.
+
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::{Mutex, PoisonError};
+
+use rusqlite::{params, Connection, TransactionBehavior};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::core::ports::outbound::{
+    Clock, EventStore, EventStoreError, GameId, StoredEvent, SystemClock, Timestamp, Version,
+};
+use crate::telemetry::{self, Timer};
+
+use super::{from_sql_int, to_sql_int};
+
+/// Event store persisting events as JSON rows in a `SQLite` database.
+///
+/// Each append runs in an immediate transaction that checks the stream version and
+/// inserts the new rows, so concurrent writers, including other processes sharing the
+/// database file, are serialized and a stale `expected_version` is rejected. The
+/// `(game_id, version)` primary key makes a lost race fail rather than fork the stream.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::SqliteEventStore;
+/// use riverrun::core::ports::outbound::{EventStore, GameId};
+///
+/// let store = SqliteEventStore::open_in_memory().unwrap();
+/// let game = GameId::from("game-1");
+/// assert_eq!(store.append(&game, vec!["started".to_string()], 0), Ok(1));
+/// assert_eq!(store.load(&game).unwrap()[0].event, "started");
+/// ```
+#[derive(Debug)]
+pub struct SqliteEventStore<E, C: Clock = SystemClock> {
+    conn: Mutex<Connection>,
+    clock: C,
+    _events: PhantomData<fn() -> E>,
+}
+
+/// `SqliteEventStore` - Constructors
+impl<E> SqliteEventStore<E> {
+    /// Open (creating and migrating if needed) the database at `path`, timestamping
+    /// events with the system clock.
+    ///
+    /// # Errors
+    /// Returns `EventStoreError::StorageError` if the database cannot be opened or migrated.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, EventStoreError> {
+        Self::open_with_clock(path, SystemClock)
+    }
+
+    /// Create a store over a private in-memory database.
+    ///
+    /// # Errors
+    /// Returns `EventStoreError::StorageError` if the schema cannot be created.
+    pub fn open_in_memory() -> Result<Self, EventStoreError> {
+        Ok(Self::with_connection(
+            super::open_in_memory().map_err(storage)?,
+            SystemClock,
+        ))
+    }
+}
+
+/// `SqliteEventStore` - Constructors
+impl<E, C: Clock> SqliteEventStore<E, C> {
+    /// Open (creating and migrating if needed) the database at `path`, timestamping
+    /// events with `clock`.
+    ///
+    /// # Errors
+    /// Returns `EventStoreError::StorageError` if the database cannot be opened or migrated.
+    pub fn open_with_clock(path: impl AsRef<Path>, clock: C) -> Result<Self, EventStoreError> {
+        Ok(Self::with_connection(
+            super::open(path.as_ref()).map_err(storage)?,
+            clock,
+        ))
+    }
+
+    fn with_connection(conn: Connection, clock: C) -> Self {
+        Self {
+            conn: Mutex::new(conn),
+            clock,
+            _events: PhantomData,
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn storage(err: rusqlite::Error) -> EventStoreError {
+    EventStoreError::StorageError(err.to_string())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn serialization(err: serde_json::Error) -> EventStoreError {
+    EventStoreError::SerializationError(err.to_string())
+}
+
+fn poisoned<T>(_: PoisonError<T>) -> EventStoreError {
+    EventStoreError::StorageError("event store lock poisoned".to_string())
+}
+
+/// Current version of `game_id`'s stream.
+fn stream_version(conn: &Connection, game_id: &GameId) -> Result<Version, EventStoreError> {
+    let version: i64 = conn
+        .query_row(
+            "SELECT COALESCE(MAX(version), 0) FROM events WHERE game_id = ?1",
+            params![game_id.as_str()],
+            |row| row.get(0),
+        )
+        .map_err(storage)?;
+    from_sql_int(version, 0).map_err(storage)
+}
+
+impl<E, C> EventStore<E> for SqliteEventStore<E, C>
+where
+    E: Serialize + DeserializeOwned,
+    C: Clock,
+{
+    fn append(
+        &self,
+        game_id: &GameId,
+        events: Vec<E>,
+        expected_version: Version,
+    ) -> Result<Version, EventStoreError> {
+        let timer = Timer::start();
+        let payloads = events
+            .iter()
+            .map(serde_json::to_string)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(serialization)?;
+
+        let mut conn = self.conn.lock().map_err(poisoned)?;
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(storage)?;
+
+        let actual = stream_version(&tx, game_id)?;
+        if actual != expected_version {
+            return Err(EventStoreError::ConcurrencyConflict {
+                expected: expected_version,
+                actual,
+            });
+        }
+        if payloads.is_empty() {
+            return Ok(actual);
+        }
+
+        let timestamp = to_sql_int(self.clock.now()).map_err(storage)?;
+        {
+            let mut insert = tx
+                .prepare_cached(
+                    "INSERT INTO events (game_id, version, timestamp, payload) VALUES (?1, ?2, ?3, ?4)",
+                )
+                .map_err(storage)?;
+            for (version, payload) in (actual + 1..).zip(&payloads) {
+                let version = to_sql_int(version).map_err(storage)?;
+                insert
+                    .execute(params![game_id.as_str(), version, timestamp, payload])
+                    .map_err(storage)?;
+            }
+        }
+        tx.commit().map_err(storage)?;
+        drop(conn);
+
+        telemetry::event_store_append("sqlite", payloads.len(), timer);
+        Ok(actual + payloads.len() as Version)
+    }
+
+    fn load(&self, game_id: &GameId) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        let events = self.load_from(game_id, 0)?;
+        if events.is_empty() {
+            return Err(EventStoreError::NotFound(game_id.clone()));
+        }
+        Ok(events)
+    }
+
+    fn load_from(
+        &self,
+        game_id: &GameId,
+        from_version: Version,
+    ) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        let from_version = i64::try_from(from_version).unwrap_or(i64::MAX);
+        let rows: Vec<(Version, Timestamp, String)> = self
+            .conn
+            .lock()
+            .map_err(poisoned)?
+            .prepare_cached(
+                "SELECT version, timestamp, payload FROM events
+                 WHERE game_id = ?1 AND version > ?2 ORDER BY version",
+            )
+            .and_then(|mut select| {
+                select
+                    .query_map(params![game_id.as_str(), from_version], |row| {
+                        Ok((
+                            from_sql_int(row.get(0)?, 0)?,
+                            from_sql_int(row.get(1)?, 1)?,
+                            row.get(2)?,
+                        ))
+                    })?
+                    .collect()
+            })
+            .map_err(storage)?;
+
+        rows.into_iter()
+            .map(|(version, timestamp, payload)| {
+                let event = serde_json::from_str(&payload).map_err(serialization)?;
+                Ok(StoredEvent::new(event, version, timestamp, game_id.clone()))
+            })
+            .collect()
+    }
+
+    fn version(&self, game_id: &GameId) -> Result<Version, EventStoreError> {
+        let conn = self.conn.lock().map_err(poisoned)?;
+        stream_version(&conn, game_id)
+    }
+
+    fn exists(&self, game_id: &GameId) -> bool {
+        self.version(game_id).is_ok_and(|version| version > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ports::outbound::FixedClock;
+
+    fn store() -> SqliteEventStore<String, FixedClock> {
+        let conn = super::super::open_in_memory().unwrap();
+        SqliteEventStore::with_connection(conn, FixedClock::new(42))
+    }
+
+    fn events(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| (*name).to_string()).collect()
+    }
+
+    #[test]
+    fn test_append_and_load() {
+        let store = store();
+        let game = GameId::from("game-1");
+
+        assert_eq!(store.append(&game, events(&["a", "b"]), 0), Ok(2));
+        assert_eq!(store.append(&game, events(&["c"]), 2), Ok(3));
+
+        let loaded = store.load(&game).unwrap();
+        let versions: Vec<Version> = loaded.iter().map(|e| e.version).collect();
+        assert_eq!(versions, [1, 2, 3]);
+        assert!(
+            loaded
+                .iter()
+                .all(|e| e.timestamp == 42 && e.game_id == game)
+        );
+        assert_eq!(store.load_from(&game, 2).unwrap()[0].event, "c");
+        assert!(store.exists(&game));
+    }
+
+    #[test]
+    fn test_append_rejects_stale_version() {
+        let store = store();
+        let game = GameId::from("game-1");
+        store.append(&game, events(&["a", "b"]), 0).unwrap();
+
+        assert_eq!(
+            store.append(&game, events(&["c"]), 1),
+            Err(EventStoreError::ConcurrencyConflict {
+                expected: 1,
+                actual: 2
+            })
+        );
+        assert_eq!(store.version(&game), Ok(2));
+    }
+
+    #[test]
+    fn test_missing_stream() {
+        let store = store();
+        let game = GameId::from("missing");
+
+        assert_eq!(
+            store.load(&game).unwrap_err(),
+            EventStoreError::NotFound(game.clone())
+        );
+        assert!(store.load_from(&game, 0).unwrap().is_empty());
+        assert_eq!(store.version(&game), Ok(0));
+        assert!(!store.exists(&game));
+    }
+}
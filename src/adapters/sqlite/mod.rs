@@ -0,0 +1,149 @@
+//! SQLite-backed adapters for durable, queryable persistence.
+//!
+//! The event store and read models can share one database file: every store runs the
+//! same migrations when it opens, so whichever opens first creates the full schema.
+
+mod event_store;
+mod read_model;
+
+use std::path::Path;
+use std::time::Duration;
+
+use rusqlite::{Connection, TransactionBehavior};
+
+pub use event_store::SqliteEventStore;
+pub use read_model::{SqliteHandSummaryStore, SqlitePlayerStatsStore};
+
+/// How long a connection waits for another writer's lock before failing.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Schema changes, applied in order. `PRAGMA user_version` records how many have run.
+const MIGRATIONS: &[&str] = &["CREATE TABLE events (
+        game_id   TEXT    NOT NULL,
+        version   INTEGER NOT NULL,
+        timestamp INTEGER NOT NULL,
+        payload   TEXT    NOT NULL,
+        PRIMARY KEY (game_id, version)
+    ) WITHOUT ROWID;
+
+    CREATE TABLE hand_summaries (
+        hand_id           TEXT    PRIMARY KEY,
+        game_id           TEXT    NOT NULL,
+        started_at        INTEGER NOT NULL,
+        ended_at          INTEGER NOT NULL,
+        num_players       INTEGER NOT NULL,
+        winner_ids        TEXT    NOT NULL,
+        is_tie            INTEGER NOT NULL,
+        winning_hand_rank TEXT
+    );
+    CREATE INDEX hand_summaries_by_start ON hand_summaries (started_at, hand_id);
+    CREATE INDEX hand_summaries_by_game ON hand_summaries (game_id, started_at, hand_id);
+
+    CREATE TABLE hand_players (
+        hand_id   TEXT    NOT NULL,
+        seat      INTEGER NOT NULL,
+        player_id TEXT    NOT NULL,
+        PRIMARY KEY (hand_id, seat)
+    ) WITHOUT ROWID;
+    CREATE INDEX hand_players_by_player ON hand_players (player_id, hand_id);
+
+    CREATE TABLE player_stats (
+        player_id      TEXT    PRIMARY KEY,
+        hands_played   INTEGER NOT NULL,
+        hands_won      INTEGER NOT NULL,
+        last_played_at INTEGER
+    );
+    CREATE INDEX player_stats_by_wins ON player_stats (hands_won DESC, hands_played DESC);",
+    "ALTER TABLE player_stats ADD COLUMN vpip_hands INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN pfr_hands INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN three_bet_opportunities INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN three_bets INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN cbet_opportunities INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN cbets INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN flops_seen INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN showdowns INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN showdowns_won INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN postflop_aggressive_actions INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN postflop_calls INTEGER NOT NULL DEFAULT 0;",
+    "CREATE TABLE player_period_stats (
+        player_id    TEXT    NOT NULL,
+        period_start INTEGER NOT NULL,
+        hands_played INTEGER NOT NULL,
+        hands_won    INTEGER NOT NULL,
+        PRIMARY KEY (player_id, period_start)
+    ) WITHOUT ROWID;
+    CREATE INDEX player_period_stats_by_start ON player_period_stats (period_start, player_id);",
+    "ALTER TABLE hand_summaries ADD COLUMN pot INTEGER NOT NULL DEFAULT 0;
+    CREATE INDEX hand_summaries_by_rank
+        ON hand_summaries (winning_hand_rank COLLATE NOCASE, started_at, hand_id);",
+    "ALTER TABLE hand_players ADD COLUMN all_in_equity REAL;
+    ALTER TABLE hand_players ADD COLUMN all_in_expected INTEGER;
+    ALTER TABLE hand_players ADD COLUMN all_in_actual INTEGER;
+    ALTER TABLE player_stats ADD COLUMN all_in_hands INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN all_in_expected_winnings INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE player_stats ADD COLUMN all_in_winnings INTEGER NOT NULL DEFAULT 0;"];
+
+/// Opens the database at `path`, creating it and applying pending migrations.
+fn open(path: &Path) -> rusqlite::Result<Connection> {
+    prepare(Connection::open(path)?)
+}
+
+/// Opens a private in-memory database with the schema applied.
+fn open_in_memory() -> rusqlite::Result<Connection> {
+    prepare(Connection::open_in_memory()?)
+}
+
+fn prepare(mut conn: Connection) -> rusqlite::Result<Connection> {
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+    migrate(&mut conn)?;
+    Ok(conn)
+}
+
+/// Applies every migration newer than the database's `user_version`.
+fn migrate(conn: &mut Connection) -> rusqlite::Result<()> {
+    let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+    let applied: i64 = tx.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    let applied = usize::try_from(applied).unwrap_or(0);
+    for (version, migration) in (1i64..).zip(MIGRATIONS).skip(applied) {
+        tx.execute_batch(migration)?;
+        tx.pragma_update(None, "user_version", version)?;
+    }
+    tx.commit()
+}
+
+/// Converts an unsigned count or timestamp to `SQLite`'s signed integer.
+fn to_sql_int(value: u64) -> rusqlite::Result<i64> {
+    i64::try_from(value).map_err(|err| rusqlite::Error::ToSqlConversionFailure(Box::new(err)))
+}
+
+/// Converts a count, `LIMIT`, or `OFFSET`, saturating so a huge limit stays unbounded.
+fn to_sql_count(value: usize) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+/// Converts a column read back from `SQLite` to an unsigned value.
+fn from_sql_int(value: i64, column: usize) -> rusqlite::Result<u64> {
+    u64::try_from(value).map_err(|err| {
+        rusqlite::Error::FromSqlConversionFailure(
+            column,
+            rusqlite::types::Type::Integer,
+            Box::new(err),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrations_run_once() {
+        let mut conn = open_in_memory().unwrap();
+        migrate(&mut conn).unwrap();
+
+        let version: i64 = conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .unwrap();
+        assert_eq!(usize::try_from(version).unwrap(), MIGRATIONS.len());
+    }
+}
@@ -0,0 +1,897 @@
+//! SQLite-backed read model adapters.
+
+use std::path::Path;
+use std::sync::{Mutex, PoisonError};
+
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Params, Row};
+
+use crate::core::ports::outbound::{
+    AllInEv, GameId, HandCursor, HandId, HandPage, HandQuery, HandSummary, HandSummaryStore, PeriodStats,
+    PlayerId, PlayerStats, PlayerStatsStore, ReadModelError, Timestamp, period_start,
+};
+
+use super::{from_sql_int, to_sql_count, to_sql_int};
+
+/// Columns selected by every player stats query, in the order `stats_from_row` reads them.
+const STATS_COLUMNS: &str = "player_id, hands_played, hands_won, last_played_at, vpip_hands, \
+                             pfr_hands, three_bet_opportunities, three_bets, cbet_opportunities, \
+                             cbets, flops_seen, showdowns, showdowns_won, \
+                             postflop_aggressive_actions, postflop_calls, all_in_hands, \
+                             all_in_expected_winnings, all_in_winnings";
+
+/// Columns selected by every hand summary query, in the order `summary_from_row` reads them.
+const SUMMARY_COLUMNS: &str = "hand_id, game_id, started_at, ended_at, num_players, \
+                               winner_ids, is_tie, winning_hand_rank, pot";
+
+#[allow(clippy::needless_pass_by_value)]
+fn storage(err: rusqlite::Error) -> ReadModelError {
+    ReadModelError::StorageError(err.to_string())
+}
+
+fn poisoned<T>(_: PoisonError<T>) -> ReadModelError {
+    ReadModelError::StorageError("read model lock poisoned".to_string())
+}
+
+/// Hand summary store backed by a `SQLite` database.
+///
+/// Participants are kept in an indexed table of their own, so `find_by_player` and
+/// `count_by_player` do not scan every hand. Queries order hands like
+/// `InMemoryHandSummaryStore`: by start time, then hand ID.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::SqliteHandSummaryStore;
+/// use riverrun::core::ports::outbound::{HandSummary, HandSummaryStore};
+///
+/// let store = SqliteHandSummaryStore::open_in_memory().unwrap();
+/// store
+///     .save(&HandSummary {
+///         hand_id: "hand-1".to_string(),
+///         game_id: "game-1".into(),
+///         started_at: 1_000,
+///         ended_at: 4_000,
+///         num_players: 2,
+///         player_ids: vec!["alice".to_string(), "bob".to_string()],
+///         winner_ids: vec!["bob".to_string()],
+///         is_tie: false,
+///         winning_hand_rank: Some("Flush".to_string()),
+///         pot: 200,
+///         all_in_ev: Vec::new(),
+///     })
+///     .unwrap();
+///
+/// let hands = store.find_by_player(&"alice".to_string(), 10, 0).unwrap();
+/// assert_eq!(hands[0].winner_ids, ["bob"]);
+/// ```
+#[derive(Debug)]
+pub struct SqliteHandSummaryStore {
+    conn: Mutex<Connection>,
+}
+
+/// `SqliteHandSummaryStore` - Constructors
+impl SqliteHandSummaryStore {
+    /// Open (creating and migrating if needed) the database at `path`.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` if the database cannot be opened or migrated.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ReadModelError> {
+        let conn = super::open(path.as_ref()).map_err(storage)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Create a store over a private in-memory database.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` if the schema cannot be created.
+    pub fn open_in_memory() -> Result<Self, ReadModelError> {
+        let conn = super::open_in_memory().map_err(storage)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+}
+
+/// `SqliteHandSummaryStore` - Helpers
+impl SqliteHandSummaryStore {
+    /// Runs a query selecting `SUMMARY_COLUMNS` and attaches each hand's players.
+    fn query(&self, sql: &str, params: impl Params) -> Result<Vec<HandSummary>, ReadModelError> {
+        let conn = self.conn.lock().map_err(poisoned)?;
+        let mut hands: Vec<HandSummary> = conn
+            .prepare_cached(sql)
+            .and_then(|mut select| select.query_map(params, summary_from_row)?.collect())
+            .map_err(storage)?;
+
+        for hand in &mut hands {
+            let seats: Vec<(PlayerId, Option<AllInEv>)> = conn
+                .prepare_cached(
+                    "SELECT player_id, all_in_equity, all_in_expected, all_in_actual
+                     FROM hand_players WHERE hand_id = ?1 ORDER BY seat",
+                )
+                .and_then(|mut players| {
+                    players.query_map(params![hand.hand_id], seat_from_row)?.collect()
+                })
+                .map_err(storage)?;
+            for (player_id, all_in_ev) in seats {
+                hand.player_ids.push(player_id);
+                hand.all_in_ev.extend(all_in_ev);
+            }
+        }
+        drop(conn);
+        Ok(hands)
+    }
+}
+
+/// Reads `SUMMARY_COLUMNS`; players and their all-in EV are filled in separately.
+fn summary_from_row(row: &Row<'_>) -> rusqlite::Result<HandSummary> {
+    let winner_ids: String = row.get(5)?;
+    Ok(HandSummary {
+        hand_id: row.get(0)?,
+        game_id: row.get::<_, String>(1)?.into(),
+        started_at: from_sql_int(row.get(2)?, 2)?,
+        ended_at: from_sql_int(row.get(3)?, 3)?,
+        num_players: usize::try_from(from_sql_int(row.get(4)?, 4)?).unwrap_or(usize::MAX),
+        player_ids: Vec::new(),
+        winner_ids: serde_json::from_str(&winner_ids).map_err(|err| {
+            rusqlite::Error::FromSqlConversionFailure(5, rusqlite::types::Type::Text, Box::new(err))
+        })?,
+        is_tie: row.get(6)?,
+        winning_hand_rank: row.get(7)?,
+        pot: from_sql_int(row.get(8)?, 8)?,
+        all_in_ev: Vec::new(),
+    })
+}
+
+/// Reads a `hand_players` row: the player, and their all-in EV if they were all-in.
+fn seat_from_row(row: &Row<'_>) -> rusqlite::Result<(PlayerId, Option<AllInEv>)> {
+    let player_id: PlayerId = row.get(0)?;
+    let equity: Option<f64> = row.get(1)?;
+    let expected: Option<i64> = row.get(2)?;
+    let actual: Option<i64> = row.get(3)?;
+    let all_in_ev = match (equity, expected, actual) {
+        (Some(equity), Some(expected), Some(actual)) => Some(AllInEv {
+            player_id: player_id.clone(),
+            equity,
+            expected: from_sql_int(expected, 2)?,
+            actual: from_sql_int(actual, 3)?,
+        }),
+        _ => None,
+    };
+    Ok((player_id, all_in_ev))
+}
+
+impl HandSummaryStore for SqliteHandSummaryStore {
+    fn save(&self, summary: &HandSummary) -> Result<(), ReadModelError> {
+        let winner_ids = serde_json::to_string(&summary.winner_ids)
+            .map_err(|err| ReadModelError::StorageError(err.to_string()))?;
+        let mut conn = self.conn.lock().map_err(poisoned)?;
+        let tx = conn.transaction().map_err(storage)?;
+        tx.execute(
+            "INSERT INTO hand_summaries
+                 (hand_id, game_id, started_at, ended_at, num_players, winner_ids, is_tie,
+                  winning_hand_rank, pot)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+             ON CONFLICT (hand_id) DO UPDATE SET
+                 game_id = excluded.game_id,
+                 started_at = excluded.started_at,
+                 ended_at = excluded.ended_at,
+                 num_players = excluded.num_players,
+                 winner_ids = excluded.winner_ids,
+                 is_tie = excluded.is_tie,
+                 winning_hand_rank = excluded.winning_hand_rank,
+                 pot = excluded.pot",
+            params![
+                summary.hand_id,
+                summary.game_id.as_str(),
+                to_sql_int(summary.started_at).map_err(storage)?,
+                to_sql_int(summary.ended_at).map_err(storage)?,
+                to_sql_count(summary.num_players),
+                winner_ids,
+                summary.is_tie,
+                summary.winning_hand_rank,
+                to_sql_int(summary.pot).map_err(storage)?,
+            ],
+        )
+        .map_err(storage)?;
+        tx.execute(
+            "DELETE FROM hand_players WHERE hand_id = ?1",
+            params![summary.hand_id],
+        )
+        .map_err(storage)?;
+        {
+            let mut insert = tx
+                .prepare_cached(
+                    "INSERT INTO hand_players
+                         (hand_id, seat, player_id, all_in_equity, all_in_expected, all_in_actual)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                )
+                .map_err(storage)?;
+            for (seat, player_id) in (0i64..).zip(&summary.player_ids) {
+                let all_in_ev = summary
+                    .all_in_ev
+                    .iter()
+                    .find(|ev| &ev.player_id == player_id);
+                let expected = all_in_ev
+                    .map(|ev| to_sql_int(ev.expected))
+                    .transpose()
+                    .map_err(storage)?;
+                let actual = all_in_ev
+                    .map(|ev| to_sql_int(ev.actual))
+                    .transpose()
+                    .map_err(storage)?;
+                insert
+                    .execute(params![
+                        summary.hand_id,
+                        seat,
+                        player_id,
+                        all_in_ev.map(|ev| ev.equity),
+                        expected,
+                        actual,
+                    ])
+                    .map_err(storage)?;
+            }
+        }
+        tx.commit().map_err(storage)?;
+        drop(conn);
+        Ok(())
+    }
+
+    fn get(&self, hand_id: &HandId) -> Result<HandSummary, ReadModelError> {
+        self.query(
+            &format!("SELECT {SUMMARY_COLUMNS} FROM hand_summaries WHERE hand_id = ?1"),
+            params![hand_id],
+        )?
+        .pop()
+        .ok_or_else(|| ReadModelError::NotFound(hand_id.clone()))
+    }
+
+    fn find_by_player(
+        &self,
+        player_id: &PlayerId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<HandSummary>, ReadModelError> {
+        self.query(
+            &format!(
+                "SELECT {SUMMARY_COLUMNS} FROM hand_summaries
+                 WHERE hand_id IN (SELECT hand_id FROM hand_players WHERE player_id = ?1)
+                 ORDER BY started_at DESC, hand_id DESC LIMIT ?2 OFFSET ?3"
+            ),
+            params![player_id, to_sql_count(limit), to_sql_count(offset)],
+        )
+    }
+
+    fn find_by_time_range(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+        limit: usize,
+    ) -> Result<Vec<HandSummary>, ReadModelError> {
+        if from > to {
+            return Err(ReadModelError::InvalidQuery(format!(
+                "time range starts at {from} after it ends at {to}"
+            )));
+        }
+        // Bounds past the largest storable timestamp cannot exclude any hand
+        let from = i64::try_from(from).unwrap_or(i64::MAX);
+        let to = i64::try_from(to).unwrap_or(i64::MAX);
+        self.query(
+            &format!(
+                "SELECT {SUMMARY_COLUMNS} FROM hand_summaries
+                 WHERE started_at BETWEEN ?1 AND ?2
+                 ORDER BY started_at, hand_id LIMIT ?3"
+            ),
+            params![from, to, to_sql_count(limit)],
+        )
+    }
+
+    fn find_by_game(&self, game_id: &GameId) -> Result<Vec<HandSummary>, ReadModelError> {
+        self.query(
+            &format!(
+                "SELECT {SUMMARY_COLUMNS} FROM hand_summaries
+                 WHERE game_id = ?1 ORDER BY started_at, hand_id"
+            ),
+            params![game_id.as_str()],
+        )
+    }
+
+    fn count_by_player(&self, player_id: &PlayerId) -> Result<u64, ReadModelError> {
+        let count: i64 = self
+            .conn
+            .lock()
+            .map_err(poisoned)?
+            .query_row(
+                "SELECT COUNT(DISTINCT hand_id) FROM hand_players WHERE player_id = ?1",
+                params![player_id],
+                |row| row.get(0),
+            )
+            .map_err(storage)?;
+        from_sql_int(count, 0).map_err(storage)
+    }
+
+    fn search(
+        &self,
+        query: &HandQuery,
+        cursor: Option<&HandCursor>,
+        limit: usize,
+    ) -> Result<HandPage, ReadModelError> {
+        query.validate()?;
+        // Bounds past the largest storable value cannot exclude any hand
+        let bound = |value: u64| Value::Integer(i64::try_from(value).unwrap_or(i64::MAX));
+        let mut conditions = vec!["1"];
+        let mut values = Vec::new();
+        if let Some(player_id) = &query.player_id {
+            conditions.push("hand_id IN (SELECT hand_id FROM hand_players WHERE player_id = ?)");
+            values.push(Value::Text(player_id.clone()));
+        }
+        if let Some(from) = query.from {
+            conditions.push("started_at >= ?");
+            values.push(bound(from));
+        }
+        if let Some(to) = query.to {
+            conditions.push("started_at <= ?");
+            values.push(bound(to));
+        }
+        if let Some(rank) = &query.winning_hand_rank {
+            conditions.push("winning_hand_rank = ? COLLATE NOCASE");
+            values.push(Value::Text(rank.clone()));
+        }
+        if let Some(min_pot) = query.min_pot {
+            conditions.push("pot >= ?");
+            values.push(bound(min_pot));
+        }
+        if query.ties_only {
+            conditions.push("is_tie");
+        }
+        if let Some(cursor) = cursor {
+            conditions.push("(started_at, hand_id) < (?, ?)");
+            values.push(bound(cursor.started_at));
+            values.push(Value::Text(cursor.hand_id.clone()));
+        }
+        values.push(Value::Integer(to_sql_count(limit.saturating_add(1))));
+
+        let hands = self.query(
+            &format!(
+                "SELECT {SUMMARY_COLUMNS} FROM hand_summaries
+                 WHERE {}
+                 ORDER BY started_at DESC, hand_id DESC LIMIT ?",
+                conditions.join(" AND ")
+            ),
+            params_from_iter(values),
+        )?;
+        Ok(HandPage::from_lookahead(hands, limit))
+    }
+}
+
+/// Player statistics store backed by a `SQLite` database.
+///
+/// Leaderboards break ties like `InMemoryPlayerStatsStore`: by more hands played, then
+/// by player ID.
+#[derive(Debug)]
+pub struct SqlitePlayerStatsStore {
+    conn: Mutex<Connection>,
+}
+
+/// `SqlitePlayerStatsStore` - Constructors
+impl SqlitePlayerStatsStore {
+    /// Open (creating and migrating if needed) the database at `path`.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` if the database cannot be opened or migrated.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ReadModelError> {
+        let conn = super::open(path.as_ref()).map_err(storage)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Create a store over a private in-memory database.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` if the schema cannot be created.
+    pub fn open_in_memory() -> Result<Self, ReadModelError> {
+        let conn = super::open_in_memory().map_err(storage)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+}
+
+/// `SqlitePlayerStatsStore` - Helpers
+impl SqlitePlayerStatsStore {
+    /// Runs a query selecting `STATS_COLUMNS`.
+    fn query(&self, sql: &str, params: impl Params) -> Result<Vec<PlayerStats>, ReadModelError> {
+        self.conn
+            .lock()
+            .map_err(poisoned)?
+            .prepare_cached(sql)
+            .and_then(|mut select| select.query_map(params, stats_from_row)?.collect())
+            .map_err(storage)
+    }
+}
+
+fn stats_from_row(row: &Row<'_>) -> rusqlite::Result<PlayerStats> {
+    let count = |column: usize| from_sql_int(row.get(column)?, column);
+    Ok(PlayerStats {
+        player_id: row.get(0)?,
+        hands_played: count(1)?,
+        hands_won: count(2)?,
+        last_played_at: row
+            .get::<_, Option<i64>>(3)?
+            .map(|at| from_sql_int(at, 3))
+            .transpose()?,
+        vpip_hands: count(4)?,
+        pfr_hands: count(5)?,
+        three_bet_opportunities: count(6)?,
+        three_bets: count(7)?,
+        cbet_opportunities: count(8)?,
+        cbets: count(9)?,
+        flops_seen: count(10)?,
+        showdowns: count(11)?,
+        showdowns_won: count(12)?,
+        postflop_aggressive_actions: count(13)?,
+        postflop_calls: count(14)?,
+        all_in_hands: count(15)?,
+        all_in_expected_winnings: count(16)?,
+        all_in_winnings: count(17)?,
+    })
+}
+
+fn period_from_row(row: &Row<'_>) -> rusqlite::Result<PeriodStats> {
+    let count = |column: usize| from_sql_int(row.get(column)?, column);
+    Ok(PeriodStats {
+        player_id: row.get(0)?,
+        period_start: count(1)?,
+        hands_played: count(2)?,
+        hands_won: count(3)?,
+    })
+}
+
+impl PlayerStatsStore for SqlitePlayerStatsStore {
+    fn get(&self, player_id: &PlayerId) -> Result<PlayerStats, ReadModelError> {
+        let stats = self
+            .conn
+            .lock()
+            .map_err(poisoned)?
+            .query_row(
+                &format!("SELECT {STATS_COLUMNS} FROM player_stats WHERE player_id = ?1"),
+                params![player_id],
+                stats_from_row,
+            )
+            .optional()
+            .map_err(storage)?;
+        Ok(stats.unwrap_or_else(|| PlayerStats {
+            player_id: player_id.clone(),
+            ..PlayerStats::default()
+        }))
+    }
+
+    fn save(&self, stats: &PlayerStats) -> Result<(), ReadModelError> {
+        let last_played_at = stats
+            .last_played_at
+            .map(to_sql_int)
+            .transpose()
+            .map_err(storage)?;
+        let counts = [
+            stats.hands_played,
+            stats.hands_won,
+            stats.vpip_hands,
+            stats.pfr_hands,
+            stats.three_bet_opportunities,
+            stats.three_bets,
+            stats.cbet_opportunities,
+            stats.cbets,
+            stats.flops_seen,
+            stats.showdowns,
+            stats.showdowns_won,
+            stats.postflop_aggressive_actions,
+            stats.postflop_calls,
+            stats.all_in_hands,
+            stats.all_in_expected_winnings,
+            stats.all_in_winnings,
+        ]
+        .map(to_sql_int)
+        .into_iter()
+        .collect::<rusqlite::Result<Vec<i64>>>()
+        .map_err(storage)?;
+        self.conn.lock().map_err(poisoned)?.execute(
+            "INSERT INTO player_stats (player_id, last_played_at, hands_played, hands_won,
+                 vpip_hands, pfr_hands, three_bet_opportunities, three_bets,
+                 cbet_opportunities, cbets, flops_seen, showdowns, showdowns_won,
+                 postflop_aggressive_actions, postflop_calls, all_in_hands,
+                 all_in_expected_winnings, all_in_winnings)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17,
+                     ?18)
+             ON CONFLICT (player_id) DO UPDATE SET
+                 last_played_at = excluded.last_played_at,
+                 hands_played = excluded.hands_played,
+                 hands_won = excluded.hands_won,
+                 vpip_hands = excluded.vpip_hands,
+                 pfr_hands = excluded.pfr_hands,
+                 three_bet_opportunities = excluded.three_bet_opportunities,
+                 three_bets = excluded.three_bets,
+                 cbet_opportunities = excluded.cbet_opportunities,
+                 cbets = excluded.cbets,
+                 flops_seen = excluded.flops_seen,
+                 showdowns = excluded.showdowns,
+                 showdowns_won = excluded.showdowns_won,
+                 postflop_aggressive_actions = excluded.postflop_aggressive_actions,
+                 postflop_calls = excluded.postflop_calls,
+                 all_in_hands = excluded.all_in_hands,
+                 all_in_expected_winnings = excluded.all_in_expected_winnings,
+                 all_in_winnings = excluded.all_in_winnings",
+            params![
+                stats.player_id,
+                last_played_at,
+                counts[0],
+                counts[1],
+                counts[2],
+                counts[3],
+                counts[4],
+                counts[5],
+                counts[6],
+                counts[7],
+                counts[8],
+                counts[9],
+                counts[10],
+                counts[11],
+                counts[12],
+                counts[13],
+                counts[14],
+                counts[15],
+            ],
+        )
+        .map_err(storage)?;
+        Ok(())
+    }
+
+    fn top_by_wins(&self, limit: usize) -> Result<Vec<PlayerStats>, ReadModelError> {
+        self.query(
+            &format!(
+                "SELECT {STATS_COLUMNS} FROM player_stats
+                 ORDER BY hands_won DESC, hands_played DESC, player_id LIMIT ?1"
+            ),
+            params![to_sql_count(limit)],
+        )
+    }
+
+    fn top_by_win_rate(
+        &self,
+        min_hands: u64,
+        limit: usize,
+    ) -> Result<Vec<PlayerStats>, ReadModelError> {
+        self.query(
+            &format!(
+                "SELECT {STATS_COLUMNS} FROM player_stats
+                 WHERE hands_played >= ?1
+                 ORDER BY CAST(hands_won AS REAL) / MAX(hands_played, 1) DESC,
+                          hands_played DESC, player_id
+                 LIMIT ?2"
+            ),
+            params![
+                i64::try_from(min_hands).unwrap_or(i64::MAX),
+                to_sql_count(limit)
+            ],
+        )
+    }
+
+    fn get_period(
+        &self,
+        player_id: &PlayerId,
+        at: Timestamp,
+    ) -> Result<PeriodStats, ReadModelError> {
+        let start = to_sql_int(period_start(at)).map_err(storage)?;
+        let stats = self
+            .conn
+            .lock()
+            .map_err(poisoned)?
+            .query_row(
+                "SELECT player_id, period_start, hands_played, hands_won
+                 FROM player_period_stats WHERE player_id = ?1 AND period_start = ?2",
+                params![player_id, start],
+                period_from_row,
+            )
+            .optional()
+            .map_err(storage)?;
+        Ok(stats.unwrap_or_else(|| PeriodStats::new(player_id.clone(), at)))
+    }
+
+    fn save_period(&self, stats: &PeriodStats) -> Result<(), ReadModelError> {
+        let [start, hands_played, hands_won] =
+            [stats.period_start, stats.hands_played, stats.hands_won].map(to_sql_int);
+        self.conn.lock().map_err(poisoned)?.execute(
+            "INSERT INTO player_period_stats (player_id, period_start, hands_played, hands_won)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (player_id, period_start) DO UPDATE SET
+                 hands_played = excluded.hands_played,
+                 hands_won = excluded.hands_won",
+            params![
+                stats.player_id,
+                start.map_err(storage)?,
+                hands_played.map_err(storage)?,
+                hands_won.map_err(storage)?,
+            ],
+        )
+        .map_err(storage)?;
+        Ok(())
+    }
+
+    fn top_by_wins_since(
+        &self,
+        from: Timestamp,
+        limit: usize,
+    ) -> Result<Vec<PeriodStats>, ReadModelError> {
+        let from = to_sql_int(period_start(from)).map_err(storage)?;
+        self.conn
+            .lock()
+            .map_err(poisoned)?
+            .prepare_cached(
+                "SELECT player_id, ?1, SUM(hands_played) AS played, SUM(hands_won) AS won
+                 FROM player_period_stats WHERE period_start >= ?1
+                 GROUP BY player_id
+                 ORDER BY won DESC, played DESC, player_id
+                 LIMIT ?2",
+            )
+            .and_then(|mut select| {
+                select.query_map(params![from, to_sql_count(limit)], period_from_row)?.collect()
+            })
+            .map_err(storage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ports::outbound::{LeaderboardWindow, STATS_PERIOD_MILLIS};
+
+    fn hand(id: &str, game: &str, started_at: Timestamp, players: &[&str]) -> HandSummary {
+        HandSummary {
+            hand_id: id.to_string(),
+            game_id: game.into(),
+            started_at,
+            ended_at: started_at + 100,
+            num_players: players.len(),
+            player_ids: players.iter().map(|p| (*p).to_string()).collect(),
+            winner_ids: vec![players[0].to_string()],
+            is_tie: false,
+            winning_hand_rank: None,
+            pot: 0,
+            all_in_ev: Vec::new(),
+        }
+    }
+
+    fn ids(hands: &[HandSummary]) -> Vec<&str> {
+        hands.iter().map(|hand| hand.hand_id.as_str()).collect()
+    }
+
+    fn stats(player: &str, hands_played: u64, hands_won: u64) -> PlayerStats {
+        PlayerStats {
+            player_id: player.to_string(),
+            hands_played,
+            hands_won,
+            last_played_at: Some(7),
+            ..PlayerStats::default()
+        }
+    }
+
+    #[test]
+    fn test_hand_summary_queries() {
+        let store = SqliteHandSummaryStore::open_in_memory().unwrap();
+        store
+            .save(&hand("h1", "g1", 100, &["alice", "bob"]))
+            .unwrap();
+        store
+            .save(&hand("h2", "g1", 200, &["bob", "carol"]))
+            .unwrap();
+        store
+            .save(&hand("h3", "g2", 300, &["alice", "carol"]))
+            .unwrap();
+        // Saving again replaces the hand, including its players
+        store
+            .save(&hand("h2", "g1", 200, &["bob", "alice"]))
+            .unwrap();
+
+        let h2 = store.get(&"h2".to_string()).unwrap();
+        assert_eq!(h2.player_ids, ["bob", "alice"]);
+        assert_eq!(h2.winner_ids, ["bob"]);
+
+        let alice = "alice".to_string();
+        assert_eq!(
+            ids(&store.find_by_player(&alice, 10, 0).unwrap()),
+            ["h3", "h2", "h1"]
+        );
+        assert_eq!(ids(&store.find_by_player(&alice, 1, 1).unwrap()), ["h2"]);
+        assert_eq!(store.count_by_player(&alice).unwrap(), 3);
+        assert_eq!(
+            ids(&store.find_by_time_range(150, 300, 10).unwrap()),
+            ["h2", "h3"]
+        );
+        assert_eq!(
+            ids(&store.find_by_game(&"g1".into()).unwrap()),
+            ["h1", "h2"]
+        );
+        assert!(matches!(
+            store.find_by_time_range(300, 100, 10),
+            Err(ReadModelError::InvalidQuery(_))
+        ));
+        assert_eq!(
+            store.get(&"missing".to_string()).unwrap_err(),
+            ReadModelError::NotFound("missing".to_string())
+        );
+    }
+
+    #[test]
+    fn test_search_filters_and_pages_by_cursor() {
+        let store = SqliteHandSummaryStore::open_in_memory().unwrap();
+        for (id, started_at, rank, pot, is_tie) in [
+            ("h1", 100, "Flush", 500, false),
+            ("h2", 200, "Full House", 50, false),
+            ("h3", 200, "flush", 900, true),
+            ("h4", 300, "Flush", 700, false),
+            ("h5", 400, "Flush", 800, false),
+        ] {
+            store
+                .save(&HandSummary {
+                    winning_hand_rank: Some(rank.to_string()),
+                    pot,
+                    is_tie,
+                    ..hand(id, "g1", started_at, &["alice", "bob"])
+                })
+                .unwrap();
+        }
+        store.save(&hand("other", "g1", 250, &["carol"])).unwrap();
+
+        let query = HandQuery::new()
+            .player("alice".to_string())
+            .between(100, 300)
+            .winning_hand_rank("Flush")
+            .min_pot(500);
+        let first = store.search(&query, None, 2).unwrap();
+        assert_eq!(ids(&first.hands), ["h4", "h3"]);
+        assert_eq!(first.hands[1].pot, 900);
+        store.save(&hand("h0", "g1", 250, &["alice"])).unwrap();
+        let second = store.search(&query, first.next.as_ref(), 2).unwrap();
+        assert_eq!(ids(&second.hands), ["h1"]);
+        assert!(second.next.is_none());
+
+        let ties = store.search(&HandQuery::new().ties_only(), None, 10).unwrap();
+        assert_eq!(ids(&ties.hands), ["h3"]);
+        assert!(matches!(
+            store.search(&HandQuery::new().between(300, 100), None, 10),
+            Err(ReadModelError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_player_stats_leaderboards() {
+        let store = SqlitePlayerStatsStore::open_in_memory().unwrap();
+        store.save(&stats("alice", 10, 6)).unwrap();
+        store.save(&stats("bob", 40, 12)).unwrap();
+        store.save(&stats("carol", 2, 2)).unwrap();
+
+        let top = store.top_by_wins(2).unwrap();
+        assert_eq!(top[0].player_id, "bob");
+        assert_eq!(
+            (
+                top[1].player_id.as_str(),
+                top[1].hands_won,
+                top[1].last_played_at
+            ),
+            ("alice", 6, Some(7))
+        );
+        assert_eq!(store.top_by_win_rate(5, 1).unwrap()[0].player_id, "alice");
+        assert_eq!(store.top_by_win_rate(0, 1).unwrap()[0].player_id, "carol");
+
+        let dave = store.get(&"dave".to_string()).unwrap();
+        assert_eq!((dave.player_id.as_str(), dave.hands_played), ("dave", 0));
+    }
+
+    #[test]
+    fn test_player_stats_windowed_leaderboards() {
+        const DAY: Timestamp = STATS_PERIOD_MILLIS;
+        let store = SqlitePlayerStatsStore::open_in_memory().unwrap();
+        let now = 40 * DAY + 5;
+        for (player, days_ago, played, won) in [
+            ("alice", 0, 10, 4),
+            ("alice", 6, 10, 4),
+            ("bob", 1, 20, 5),
+            ("bob", 20, 50, 40),
+        ] {
+            let mut period = store
+                .get_period(&player.to_string(), now - days_ago * DAY)
+                .unwrap();
+            period.hands_played += played;
+            period.hands_won += won;
+            store.save_period(&period).unwrap();
+        }
+
+        let weekly = store.leaderboard(LeaderboardWindow::Weekly, now, 10).unwrap();
+        assert_eq!(
+            weekly[0],
+            PeriodStats {
+                player_id: "alice".to_string(),
+                period_start: 34 * DAY,
+                hands_played: 20,
+                hands_won: 8,
+            }
+        );
+        assert_eq!((weekly[1].player_id.as_str(), weekly[1].hands_won), ("bob", 5));
+        let monthly = store.leaderboard(LeaderboardWindow::Monthly, now, 1).unwrap();
+        assert_eq!((monthly[0].player_id.as_str(), monthly[0].hands_played), ("bob", 70));
+        assert_eq!(store.get_period(&"bob".to_string(), 20 * DAY).unwrap().hands_won, 40);
+    }
+
+    #[test]
+    fn test_player_stats_round_trip_hud_counts() {
+        let store = SqlitePlayerStatsStore::open_in_memory().unwrap();
+        let saved = PlayerStats {
+            vpip_hands: 4,
+            pfr_hands: 3,
+            three_bet_opportunities: 2,
+            three_bets: 1,
+            cbet_opportunities: 5,
+            cbets: 6,
+            flops_seen: 7,
+            showdowns: 8,
+            showdowns_won: 9,
+            postflop_aggressive_actions: 10,
+            postflop_calls: 11,
+            all_in_hands: 3,
+            all_in_expected_winnings: 450,
+            all_in_winnings: 300,
+            ..stats("alice", 20, 5)
+        };
+        store.save(&saved).unwrap();
+        store.save(&saved).unwrap();
+
+        let loaded = store.get(&"alice".to_string()).unwrap();
+        assert_eq!(
+            [
+                loaded.hands_played,
+                loaded.vpip_hands,
+                loaded.three_bets,
+                loaded.cbets,
+                loaded.showdowns_won,
+                loaded.postflop_calls,
+                loaded.all_in_hands,
+                loaded.all_in_expected_winnings,
+                loaded.all_in_winnings
+            ],
+            [20, 4, 1, 6, 9, 11, 3, 450, 300]
+        );
+        assert_eq!(loaded.all_in_luck(), -150);
+    }
+
+    #[test]
+    fn test_hand_summary_round_trips_all_in_ev() {
+        let store = SqliteHandSummaryStore::open_in_memory().unwrap();
+        let mut saved = hand("h1", "g1", 100, &["alice", "bob", "carol"]);
+        saved.all_in_ev = vec![
+            AllInEv {
+                player_id: "alice".to_string(),
+                equity: 0.25,
+                expected: 50,
+                actual: 200,
+            },
+            AllInEv {
+                player_id: "carol".to_string(),
+                equity: 0.75,
+                expected: 150,
+                actual: 0,
+            },
+        ];
+        store.save(&saved).unwrap();
+
+        let loaded = store.get(&"h1".to_string()).unwrap();
+        assert_eq!(loaded.player_ids, ["alice", "bob", "carol"]);
+        assert_eq!(loaded.all_in_ev, saved.all_in_ev);
+        assert_eq!(loaded.all_in_ev[0].luck(), 150);
+
+        let folded = hand("h2", "g1", 200, &["alice", "bob"]);
+        store.save(&folded).unwrap();
+        assert!(store.get(&"h2".to_string()).unwrap().all_in_ev.is_empty());
+    }
+}
@@ -0,0 +1,218 @@
+//! The server side of the WebSocket opening handshake and text frames (RFC 6455).
+
+use std::io::{self, BufRead, BufReader, Read};
+
+/// GUID appended to the client's key to derive `Sec-WebSocket-Accept`.
+const ACCEPT_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Upper bound on the size of an opening handshake request.
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// A client's request to upgrade to a WebSocket connection.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Upgrade {
+    /// Request path, without any query string.
+    pub path: String,
+    /// Value of the `Sec-WebSocket-Key` header.
+    pub key: String,
+}
+
+/// Reads an HTTP upgrade request from `stream`.
+///
+/// # Errors
+/// Returns `io::ErrorKind::InvalidData` if the request is not a WebSocket upgrade.
+pub(super) fn read_upgrade(stream: impl Read) -> io::Result<Upgrade> {
+    let invalid = |reason: &str| io::Error::new(io::ErrorKind::InvalidData, reason.to_string());
+    let mut reader = BufReader::new(stream.take(MAX_REQUEST_BYTES as u64));
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    if parts.next() != Some("GET") {
+        return Err(invalid("upgrade request must use GET"));
+    }
+    let target = parts.next().ok_or_else(|| invalid("missing request target"))?;
+    let path = target.split('?').next().unwrap_or(target).to_string();
+
+    let mut key = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(invalid("request ended before its headers"));
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("sec-websocket-key")
+        {
+            key = Some(value.trim().to_string());
+        }
+    }
+
+    let key = key.ok_or_else(|| invalid("missing Sec-WebSocket-Key header"))?;
+    Ok(Upgrade { path, key })
+}
+
+/// Response accepting the upgrade identified by `key`.
+pub(super) fn accept_response(key: &str) -> String {
+    format!(
+        "HTTP/1.1 101 Switching Protocols\r\n\
+         Upgrade: websocket\r\n\
+         Connection: Upgrade\r\n\
+         Sec-WebSocket-Accept: {}\r\n\r\n",
+        accept_key(key)
+    )
+}
+
+/// Response refusing an upgrade with the given HTTP status, e.g. `404 Not Found`.
+pub(super) fn reject_response(status: &str) -> String {
+    format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+}
+
+/// Encodes `payload` as a single unmasked text frame.
+pub(super) fn text_frame(payload: &str) -> Vec<u8> {
+    let bytes = payload.as_bytes();
+    let mut frame = Vec::with_capacity(bytes.len() + 10);
+    // FIN with the text opcode
+    frame.push(0x81);
+    match (u8::try_from(bytes.len()), u16::try_from(bytes.len())) {
+        (Ok(len), _) if len <= 125 => frame.push(len),
+        (_, Ok(len)) => {
+            frame.push(126);
+            frame.extend_from_slice(&len.to_be_bytes());
+        }
+        _ => {
+            frame.push(127);
+            frame.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+        }
+    }
+    frame.extend_from_slice(bytes);
+    frame
+}
+
+/// Derives `Sec-WebSocket-Accept` from the client's `Sec-WebSocket-Key`.
+fn accept_key(key: &str) -> String {
+    base64(&sha1(format!("{key}{ACCEPT_GUID}").as_bytes()))
+}
+
+/// SHA-1 digest, needed only for the handshake.
+///
+/// Variable names follow FIPS 180-4.
+#[allow(clippy::many_single_char_names)]
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x6745_2301, 0xEFCD_AB89, 0x98BA_DCFE, 0x1032_5476, 0xC3D2_E1F0];
+
+    let mut message = data.to_vec();
+    let bit_len = (data.len() as u64).wrapping_mul(8);
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&bit_len.to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut words = [0u32; 80];
+        for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        for i in 16..80 {
+            words[i] = (words[i - 3] ^ words[i - 8] ^ words[i - 14] ^ words[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (i, word) in words.into_iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ED9_EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1B_BCDC),
+                _ => (b ^ c ^ d, 0xCA62_C1D6),
+            };
+            let next = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = next;
+        }
+        for (value, add) in state.iter_mut().zip([a, b, c, d, e]) {
+            *value = value.wrapping_add(add);
+        }
+    }
+
+    let mut digest = [0; 20];
+    for (bytes, value) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&value.to_be_bytes());
+    }
+    digest
+}
+
+/// Standard padded base64.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .chain([0, 0].iter())
+            .take(3)
+            .fold(0u32, |group, &byte| (group << 8) | u32::from(byte));
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (group >> (18 - 6 * index)) & 0x3F;
+                encoded.push(char::from(ALPHABET[sextet as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_key_matches_rfc_example() {
+        assert_eq!(accept_key("dGhlIHNhbXBsZSBub25jZQ=="), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_read_upgrade() {
+        let request = "GET /games/g1?token=x HTTP/1.1\r\nHost: localhost\r\n\
+                       sec-websocket-key: abc==\r\n\r\n";
+        assert_eq!(
+            read_upgrade(request.as_bytes()).unwrap(),
+            Upgrade {
+                path: "/games/g1".to_string(),
+                key: "abc==".to_string()
+            }
+        );
+
+        let missing_key = "GET / HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        assert!(read_upgrade(missing_key.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_text_frame_lengths() {
+        assert_eq!(text_frame("hi"), [0x81, 2, b'h', b'i']);
+
+        let medium = "x".repeat(300);
+        assert_eq!(text_frame(&medium)[..4], [0x81, 126, 0x01, 0x2C]);
+
+        let large = "x".repeat(70_000);
+        let frame = text_frame(&large);
+        assert_eq!(frame[1], 127);
+        assert_eq!(frame[2..10], 70_000u64.to_be_bytes());
+    }
+}
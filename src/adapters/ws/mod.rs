@@ -0,0 +1,9 @@
+//! WebSocket adapter for pushing live game notifications to front-ends.
+//!
+//! Implements just enough of RFC 6455 to accept connections and send text frames,
+//! so no WebSocket dependency is needed.
+
+mod handshake;
+mod publisher;
+
+pub use publisher::WebSocketPublisher;
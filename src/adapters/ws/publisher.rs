@@ -0,0 +1,292 @@
+//! Notification publisher pushing JSON to WebSocket clients.
+
+use std::io::{self, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+use super::handshake::{accept_response, read_upgrade, reject_response, text_frame};
+use crate::core::ports::outbound::{
+    GameId, GameNotification, NotificationPublisher, VersionedNotification,
+};
+
+/// How long a client may take to send its handshake or accept a frame.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Notifications a client subscribed to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Topic {
+    /// Every game.
+    All,
+    /// A single game.
+    Game(GameId),
+}
+
+impl Topic {
+    /// Topic for a request path: `/` or `/games` for every game, `/games/{game_id}` for one.
+    fn from_path(path: &str) -> Option<Self> {
+        match path.trim_end_matches('/') {
+            "" | "/games" => Some(Self::All),
+            path => path
+                .strip_prefix("/games/")
+                .filter(|game_id| !game_id.is_empty() && !game_id.contains('/'))
+                .map(|game_id| Self::Game(game_id.into())),
+        }
+    }
+
+    fn matches(&self, game_id: &GameId) -> bool {
+        match self {
+            Self::All => true,
+            Self::Game(topic) => topic == game_id,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Client {
+    stream: TcpStream,
+    topic: Topic,
+}
+
+/// State shared between the publisher and its accept thread.
+#[derive(Debug, Default)]
+struct Shared {
+    clients: Mutex<Vec<Client>>,
+    shutdown: AtomicBool,
+}
+
+/// Publisher pushing each notification as a JSON text frame to WebSocket clients.
+///
+/// Clients choose their topic with the path they connect to: `/games/{game_id}`
+/// receives one game's notifications, and `/` or `/games` receives every game's.
+/// Notifications use the JSON form of their `VersionedNotification`.
+///
+/// Connections are accepted on a background thread that stops when the publisher is
+/// dropped. Delivery is push-only: frames sent by clients are never read, and a
+/// client whose connection fails or stalls for longer than five seconds is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use riverrun::adapters::WebSocketPublisher;
+/// use riverrun::core::ports::outbound::{GameNotification, NotificationPublisher};
+///
+/// let publisher = WebSocketPublisher::bind("127.0.0.1:9001").unwrap();
+/// // Front-ends connect to ws://127.0.0.1:9001/games/game-1
+/// publisher.publish(GameNotification::GameEnded {
+///     game_id: "game-1".into(),
+///     timestamp: 0,
+/// });
+/// ```
+#[derive(Debug)]
+pub struct WebSocketPublisher {
+    shared: Arc<Shared>,
+    local_addr: SocketAddr,
+}
+
+/// `WebSocketPublisher` - Constructors
+impl WebSocketPublisher {
+    /// Listen for WebSocket clients on `addr`.
+    ///
+    /// # Errors
+    /// Returns an error if the address cannot be bound.
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let local_addr = listener.local_addr()?;
+        let shared = Arc::new(Shared::default());
+
+        let accepting = Arc::clone(&shared);
+        thread::Builder::new()
+            .name("ws-accept".to_string())
+            .spawn(move || accept_loop(&listener, &accepting))?;
+
+        Ok(Self { shared, local_addr })
+    }
+}
+
+/// `WebSocketPublisher` - Accessors
+impl WebSocketPublisher {
+    /// Address clients connect to.
+    #[must_use]
+    pub const fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Number of connected clients.
+    #[must_use]
+    pub fn client_count(&self) -> usize {
+        self.shared
+            .clients
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+}
+
+impl NotificationPublisher for WebSocketPublisher {
+    fn publish(&self, notification: GameNotification) {
+        let versioned = VersionedNotification::new(notification);
+        let Ok(json) = serde_json::to_string(&versioned) else {
+            return;
+        };
+        let frame = text_frame(&json);
+        let game_id = versioned.notification.game_id();
+        self.shared
+            .clients
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain_mut(|client| {
+                !client.topic.matches(game_id) || client.stream.write_all(&frame).is_ok()
+            });
+    }
+}
+
+impl Drop for WebSocketPublisher {
+    fn drop(&mut self) {
+        self.shared.shutdown.store(true, Ordering::SeqCst);
+        // Wake the accept thread so it sees the shutdown flag
+        let _ = TcpStream::connect(self.local_addr);
+    }
+}
+
+fn accept_loop(listener: &TcpListener, shared: &Shared) {
+    for stream in listener.incoming() {
+        if shared.shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+        if let Ok(stream) = stream {
+            // A failed handshake only affects that client
+            let _ = accept_client(stream, shared);
+        }
+    }
+}
+
+/// Completes the handshake with `stream` and registers it as a client.
+fn accept_client(mut stream: TcpStream, shared: &Shared) -> io::Result<()> {
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+
+    let upgrade = match read_upgrade(&stream) {
+        Ok(upgrade) => upgrade,
+        Err(err) => {
+            stream.write_all(reject_response("400 Bad Request").as_bytes())?;
+            return Err(err);
+        }
+    };
+    let Some(topic) = Topic::from_path(&upgrade.path) else {
+        return stream.write_all(reject_response("404 Not Found").as_bytes());
+    };
+
+    // Hold the lock while responding so no frame can precede the handshake response
+    let mut clients = shared.clients.lock().unwrap_or_else(PoisonError::into_inner);
+    stream.write_all(accept_response(&upgrade.key).as_bytes())?;
+    clients.push(Client { stream, topic });
+    drop(clients);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read};
+
+    use super::*;
+    use crate::core::ports::outbound::Street;
+
+    fn connect(publisher: &WebSocketPublisher, path: &str) -> BufReader<TcpStream> {
+        let mut stream = TcpStream::connect(publisher.local_addr()).unwrap();
+        stream.set_read_timeout(Some(CLIENT_TIMEOUT)).unwrap();
+        write!(
+            stream,
+            "GET {path} HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\n\
+             Connection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+             Sec-WebSocket-Version: 13\r\n\r\n"
+        )
+        .unwrap();
+
+        let mut reader = BufReader::new(stream);
+        let mut status = String::new();
+        reader.read_line(&mut status).unwrap();
+        assert!(status.starts_with("HTTP/1.1 101"), "{status}");
+        let mut line = String::new();
+        while line != "\r\n" {
+            line.clear();
+            reader.read_line(&mut line).unwrap();
+        }
+        reader
+    }
+
+    fn read_text(reader: &mut BufReader<TcpStream>) -> String {
+        let mut header = [0; 2];
+        reader.read_exact(&mut header).unwrap();
+        assert_eq!(header[0], 0x81);
+        let mut payload = vec![0; usize::from(header[1])];
+        reader.read_exact(&mut payload).unwrap();
+        String::from_utf8(payload).unwrap()
+    }
+
+    fn ended(game_id: &str) -> GameNotification {
+        GameNotification::GameEnded {
+            game_id: game_id.into(),
+            timestamp: 7,
+        }
+    }
+
+    #[test]
+    fn test_topic_from_path() {
+        assert_eq!(Topic::from_path("/"), Some(Topic::All));
+        assert_eq!(Topic::from_path("/games/"), Some(Topic::All));
+        assert_eq!(Topic::from_path("/games/g1"), Some(Topic::Game("g1".into())));
+        assert_eq!(Topic::from_path("/games/g1/x"), None);
+        assert_eq!(Topic::from_path("/other"), None);
+    }
+
+    #[test]
+    fn test_publishes_to_subscribed_clients() {
+        let publisher = WebSocketPublisher::bind("127.0.0.1:0").unwrap();
+        let mut all = connect(&publisher, "/");
+        let mut g2 = connect(&publisher, "/games/g2");
+        assert_eq!(publisher.client_count(), 2);
+
+        publisher.publish(ended("g1"));
+        publisher.publish(GameNotification::StreetDealt {
+            game_id: "g2".into(),
+            timestamp: 8,
+            street: Street::Flop,
+            cards: vec!["Ah".to_string(), "Kd".to_string(), "2c".to_string()],
+            pot: 30,
+        });
+
+        assert_eq!(
+            read_text(&mut all),
+            r#"{"version":3,"type":"game_ended","game_id":"g1","timestamp":7}"#
+        );
+        let street = r#"{"version":3,"type":"street_dealt","game_id":"g2","timestamp":8,"street":"flop","cards":["Ah","Kd","2c"],"pot":30}"#;
+        assert_eq!(read_text(&mut all), street);
+        assert_eq!(read_text(&mut g2), street);
+    }
+
+    #[test]
+    fn test_rejects_unknown_path_and_drops_closed_clients() {
+        let publisher = WebSocketPublisher::bind("127.0.0.1:0").unwrap();
+
+        let mut stream = TcpStream::connect(publisher.local_addr()).unwrap();
+        write!(stream, "GET /lobby HTTP/1.1\r\nSec-WebSocket-Key: a\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 404"));
+
+        let client = connect(&publisher, "/games/g1");
+        drop(client);
+        // The first write to a closed socket may still succeed, so publish until it fails
+        for _ in 0..50 {
+            publisher.publish(ended("g1"));
+            if publisher.client_count() == 0 {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(publisher.client_count(), 0);
+    }
+}
@@ -0,0 +1,147 @@
+//! Standard scenarios for benchmarking evaluators and equity calculators.
+//!
+//! Every generator is seeded, so a benchmark sees the same hands on every run and
+//! timings stay comparable across evaluator changes. The `benches/` suite is built
+//! on these; run it with `cargo bench`.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::deck::Deck;
+use crate::core::domain::entities::hole_cards::HoleCards;
+
+/// Seed used by the standard scenarios.
+pub const DEFAULT_SEED: u64 = 0x7269_7665_7272_756e;
+
+/// Number of hands in the standard evaluation batches.
+pub const DEFAULT_BATCH: usize = 10_000;
+
+/// `count` random five-card hands, each dealt from a fresh deck shuffled with `seed`.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::bench_utils::five_card_hands;
+///
+/// let hands = five_card_hands(100, 7);
+/// assert_eq!(hands.len(), 100);
+/// assert_eq!(hands, five_card_hands(100, 7));
+/// ```
+#[must_use]
+pub fn five_card_hands(count: usize, seed: u64) -> Vec<[Card; 5]> {
+    random_hands(count, seed)
+}
+
+/// `count` random seven-card hands, each dealt from a fresh deck shuffled with `seed`.
+#[must_use]
+pub fn seven_card_hands(count: usize, seed: u64) -> Vec<[Card; 7]> {
+    random_hands(count, seed)
+}
+
+/// Deals `count` hands of `N` distinct cards.
+fn random_hands<const N: usize>(count: usize, seed: u64) -> Vec<[Card; N]> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count)
+        .map(|_| {
+            let mut deck = Deck::new();
+            deck.shuffle(&mut rng);
+            let cards = deck.deal_exact(N).expect("a deck holds more than seven cards");
+            cards.try_into().expect("dealt exactly N cards")
+        })
+        .collect()
+}
+
+/// One hero hand on a board against random opponents.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EquityScenario {
+    /// Short label for reports, e.g. `"river"`.
+    pub name: &'static str,
+    /// Hero's hole cards.
+    pub hole_cards: HoleCards,
+    /// Cards already on the board.
+    pub board: Board,
+    /// Number of random opponents.
+    pub num_opponents: usize,
+}
+
+/// `EquityScenario` - Constructors
+impl EquityScenario {
+    /// Builds a scenario from card strings such as `"AhKh"` and `"Qh 7h 2c"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cards do not parse; scenarios are written by hand.
+    #[must_use]
+    pub fn new(name: &'static str, hole_cards: &str, board: &str, num_opponents: usize) -> Self {
+        Self {
+            name,
+            hole_cards: hole_cards.parse().expect("valid hole cards"),
+            board: board.parse().expect("valid board"),
+            num_opponents,
+        }
+    }
+
+    /// A flush draw on the river, enumerated against 990 opponent hands.
+    #[must_use]
+    pub fn river() -> Self {
+        Self::new("river", "AhKh", "Qh 7h 2c 9s 3d", 1)
+    }
+
+    /// A flush draw on the turn, enumerated over 46 rivers.
+    #[must_use]
+    pub fn turn() -> Self {
+        Self::new("turn", "AhKh", "Qh 7h 2c 9s", 1)
+    }
+
+    /// A flush draw on the flop, about a million showdowns to enumerate heads-up.
+    #[must_use]
+    pub fn flop() -> Self {
+        Self::new("flop", "AhKh", "Qh 7h 2c", 1)
+    }
+
+    /// Pocket queens preflop against three opponents, a Monte Carlo workload.
+    #[must_use]
+    pub fn preflop_multiway() -> Self {
+        Self::new("preflop_multiway", "QsQd", "", 3)
+    }
+}
+
+/// The standard equity scenarios, from the cheapest to enumerate to the most expensive.
+#[must_use]
+pub fn equity_scenarios() -> Vec<EquityScenario> {
+    vec![
+        EquityScenario::river(),
+        EquityScenario::turn(),
+        EquityScenario::flop(),
+        EquityScenario::preflop_multiway(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::card_set::CardSet;
+
+    #[test]
+    fn test_hands_are_distinct_and_seeded() {
+        let hands = seven_card_hands(50, DEFAULT_SEED);
+        for hand in &hands {
+            let mut seen = CardSet::empty();
+            assert!(hand.iter().all(|&card| seen.insert(card)));
+        }
+        assert_eq!(hands, seven_card_hands(50, DEFAULT_SEED));
+        assert_ne!(hands, seven_card_hands(50, DEFAULT_SEED + 1));
+    }
+
+    #[test]
+    fn test_scenarios_are_valid() {
+        for scenario in equity_scenarios() {
+            let mut cards = scenario.board.cards().to_vec();
+            cards.extend_from_slice(scenario.hole_cards.cards());
+            let mut seen = CardSet::empty();
+            assert!(cards.iter().all(|&card| seen.insert(card)), "{}", scenario.name);
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! Command line argument parsing.
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::{self, Card};
+use crate::core::domain::entities::card_display::DisplayStyle;
+use crate::core::domain::entities::hole_cards::HoleCards;
+
+use super::CliError;
+
+/// How results are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Aligned, human-readable text.
+    #[default]
+    Text,
+    /// A single JSON object.
+    Json,
+}
+
+/// A parsed subcommand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    /// Hero's equity against random opponent hands.
+    Equity {
+        hole_cards: HoleCards,
+        board: Board,
+        opponents: usize,
+        /// Monte Carlo samples, or `None` for the default.
+        samples: Option<u32>,
+        /// Enumerate every runout instead of sampling.
+        exact: bool,
+    },
+    /// The best five-card hand from five to seven cards.
+    Evaluate { cards: Vec<Card> },
+    /// Winner(s) of a hand between known hole cards on a complete board.
+    Showdown {
+        players: Vec<HoleCards>,
+        board: Board,
+    },
+    /// Print usage.
+    Help,
+}
+
+/// A command with its output options.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Invocation {
+    /// The subcommand to run.
+    pub command: Command,
+    /// How to print the result.
+    pub format: OutputFormat,
+    /// How cards are rendered in text output.
+    pub style: DisplayStyle,
+}
+
+/// `Invocation` - Constructors
+impl Invocation {
+    /// Parses arguments, excluding the program name.
+    ///
+    /// # Errors
+    /// Returns `CliError` for an unknown subcommand or option, a missing or malformed
+    /// value, or cards that are invalid or repeated.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, CliError> {
+        let mut args = args.into_iter();
+        let Some(name) = args.next() else {
+            return Ok(Self {
+                command: Command::Help,
+                format: OutputFormat::Text,
+                style: DisplayStyle::Ascii,
+            });
+        };
+
+        let mut positional = Vec::new();
+        let mut board = None;
+        let mut opponents = None;
+        let mut samples = None;
+        let mut exact = false;
+        let mut format = OutputFormat::Text;
+        let mut style = DisplayStyle::Ascii;
+        while let Some(arg) = args.next() {
+            let mut value = |option: &str| {
+                args.next()
+                    .ok_or_else(|| CliError::MissingValue(option.to_string()))
+            };
+            match arg.as_str() {
+                "--board" | "-b" => board = Some(value(&arg)?),
+                "--opponents" | "-o" => opponents = Some(parse_number(&arg, &value(&arg)?)?),
+                "--samples" | "-n" => samples = Some(parse_number(&arg, &value(&arg)?)?),
+                "--exact" => exact = true,
+                "--json" => format = OutputFormat::Json,
+                "--style" => style = parse_style(&arg, &value(&arg)?)?,
+                "--help" | "-h" => {
+                    return Ok(Self {
+                        command: Command::Help,
+                        format,
+                        style,
+                    });
+                }
+                option if option.starts_with('-') => {
+                    return Err(CliError::UnknownOption(option.to_string()));
+                }
+                _ => positional.push(arg),
+            }
+        }
+
+        let board = board.map_or_else(|| Ok(Board::new()), parse_board)?;
+
+        let command = match name.as_str() {
+            "equity" => {
+                let [hole_cards] = positional.as_slice() else {
+                    return Err(CliError::Usage("equity takes one hand, e.g. AsKs"));
+                };
+                let hole_cards = parse_hole_cards(hole_cards)?;
+                let opponents = opponents.unwrap_or(1);
+                if opponents == 0 {
+                    return Err(CliError::InvalidValue {
+                        option: "--opponents".to_string(),
+                        value: "0".to_string(),
+                    });
+                }
+                check_distinct(hole_cards.cards().iter().chain(board.cards()))?;
+                Command::Equity {
+                    hole_cards,
+                    board,
+                    opponents,
+                    samples,
+                    exact,
+                }
+            }
+            "evaluate" => {
+                let cards = parse_cards(&positional.join(" "))?;
+                if !(5..=7).contains(&cards.len()) {
+                    return Err(CliError::Usage("evaluate takes five to seven cards"));
+                }
+                check_distinct(&cards)?;
+                Command::Evaluate { cards }
+            }
+            "showdown" => {
+                if positional.len() < 2 || !board.is_complete() {
+                    return Err(CliError::Usage(
+                        "showdown takes two or more hands and a five-card --board",
+                    ));
+                }
+                let players = positional
+                    .iter()
+                    .map(|text| parse_hole_cards(text))
+                    .collect::<Result<Vec<_>, _>>()?;
+                check_distinct(
+                    players
+                        .iter()
+                        .flat_map(HoleCards::cards)
+                        .chain(board.cards()),
+                )?;
+                Command::Showdown { players, board }
+            }
+            "help" => Command::Help,
+            other => return Err(CliError::UnknownCommand(other.to_string())),
+        };
+
+        Ok(Self {
+            command,
+            format,
+            style,
+        })
+    }
+}
+
+/// Parses cards written with or without spaces or commas, e.g. `AsKs` or `As, Ks`.
+fn parse_cards(text: &str) -> Result<Vec<Card>, CliError> {
+    card::parse_cards(text).map_err(|_| CliError::InvalidCards(text.to_string()))
+}
+
+/// Parses a board of 0, 3, 4, or 5 cards.
+fn parse_board(text: String) -> Result<Board, CliError> {
+    let cards = parse_cards(&text)?;
+    Board::with_cards(cards).ok_or(CliError::InvalidBoard(text))
+}
+
+/// Parses exactly two hole cards.
+fn parse_hole_cards(text: &str) -> Result<HoleCards, CliError> {
+    match parse_cards(text)?.as_slice() {
+        &[first, second] => Ok(HoleCards::new(first, second)),
+        _ => Err(CliError::InvalidCards(text.to_string())),
+    }
+}
+
+/// Parses a non-negative integer option value.
+fn parse_number<T: FromStr>(option: &str, value: &str) -> Result<T, CliError> {
+    value.parse().map_err(|_| CliError::InvalidValue {
+        option: option.to_string(),
+        value: value.to_string(),
+    })
+}
+
+/// Parses a card style name such as `unicode`.
+fn parse_style(option: &str, value: &str) -> Result<DisplayStyle, CliError> {
+    DisplayStyle::from_name(value).ok_or_else(|| CliError::InvalidValue {
+        option: option.to_string(),
+        value: value.to_string(),
+    })
+}
+
+/// Fails on the first card that appears more than once.
+fn check_distinct<'a>(cards: impl IntoIterator<Item = &'a Card>) -> Result<(), CliError> {
+    let mut seen = HashSet::new();
+    for &card in cards {
+        if !seen.insert(card) {
+            return Err(CliError::DuplicateCard(card));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Invocation, CliError> {
+        Invocation::parse(shell_words(args))
+    }
+
+    /// Splits on spaces, keeping double-quoted groups together.
+    fn shell_words(args: &str) -> Vec<String> {
+        args.split('"')
+            .enumerate()
+            .flat_map(|(i, part)| {
+                if i % 2 == 1 {
+                    vec![part.to_string()]
+                } else {
+                    part.split_whitespace().map(str::to_string).collect()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_equity() {
+        let invocation =
+            parse(r#"equity AsKs --board "Qs Js 2h" --opponents 2 --samples 5000 --json"#).unwrap();
+        assert_eq!(invocation.format, OutputFormat::Json);
+        assert_eq!(invocation.style, DisplayStyle::Ascii);
+        let Command::Equity {
+            hole_cards,
+            board,
+            opponents,
+            samples,
+            exact,
+        } = invocation.command
+        else {
+            panic!("expected equity");
+        };
+        assert_eq!(hole_cards.to_string(), "AsKs");
+        assert_eq!(board.len(), 3);
+        assert_eq!((opponents, samples, exact), (2, Some(5000), false));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("").unwrap().command, Command::Help);
+        assert_eq!(
+            parse("fold"),
+            Err(CliError::UnknownCommand("fold".to_string()))
+        );
+        assert_eq!(
+            parse("equity AsKs --board"),
+            Err(CliError::MissingValue("--board".to_string()))
+        );
+        assert_eq!(
+            parse("equity AsKs --board \"Qs Js\""),
+            Err(CliError::InvalidBoard("Qs Js".to_string()))
+        );
+        assert_eq!(
+            parse("equity AsAs"),
+            Err(CliError::DuplicateCard(Card::from_string("As").unwrap()))
+        );
+        assert_eq!(
+            parse("evaluate As Ks Qs"),
+            Err(CliError::Usage("evaluate takes five to seven cards"))
+        );
+        assert_eq!(
+            parse("equity AsKs -n lots"),
+            Err(CliError::InvalidValue {
+                option: "-n".to_string(),
+                value: "lots".to_string()
+            })
+        );
+        assert_eq!(
+            parse("equity AsKs --style fancy"),
+            Err(CliError::InvalidValue {
+                option: "--style".to_string(),
+                value: "fancy".to_string()
+            })
+        );
+        assert!(matches!(
+            parse("showdown AsKs QhQd --board \"2c 3d 4h\""),
+            Err(CliError::Usage(_))
+        ));
+    }
+}
@@ -0,0 +1,260 @@
+//! Command line interface for equity, evaluation, and showdown queries.
+//!
+//! The `riverrun` binary is a thin wrapper around `run`, which parses the arguments,
+//! runs the command against the domain services, and returns the text to print:
+//!
+//! ```text
+//! riverrun equity AsKs --board "Qs Js 2h" --opponents 2 --samples 100000
+//! riverrun evaluate "As Ks Qs Js Ts 2h 3d"
+//! riverrun showdown AsKs QhQd --board "Qs Js 2h 7c 3d" --json
+//! ```
+
+mod command;
+mod render;
+
+use std::error::Error;
+use std::fmt;
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hand::Hand;
+use crate::core::domain::services::{
+    CactusKevEvaluator, ExhaustiveEquityCalculator, MonteCarloEquityCalculator, ShowdownSolver,
+};
+use crate::core::ports::inbound::{EquityCalculator, EquityError, HandEvaluator, HandSolver};
+
+pub use command::{Command, Invocation, OutputFormat};
+
+/// Usage text printed by `help` and alongside errors.
+pub const USAGE: &str = "\
+Usage: riverrun <command> [options]
+
+Commands:
+  equity <hand>            Equity against random hands, e.g. equity AsKs
+  evaluate <cards>         Best hand from five to seven cards
+  showdown <hand> <hand>.. Winner(s) on a complete board
+  help                     Show this message
+
+Options:
+  -b, --board <cards>      Board cards, e.g. \"Qs Js 2h\"
+  -o, --opponents <n>      Number of opponents for equity (default 1)
+  -n, --samples <n>        Monte Carlo samples for equity
+      --exact              Enumerate every runout instead of sampling
+      --style <style>      Card style for text: ascii, unicode, or color
+      --json               Print JSON instead of text";
+
+/// Error returned when a command line cannot be run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// The subcommand is not recognized.
+    UnknownCommand(String),
+    /// An option is not recognized.
+    UnknownOption(String),
+    /// An option is missing its value.
+    MissingValue(String),
+    /// An option value could not be parsed.
+    InvalidValue { option: String, value: String },
+    /// Cards could not be parsed.
+    InvalidCards(String),
+    /// The board does not hold 0, 3, 4, or 5 cards.
+    InvalidBoard(String),
+    /// The same card was given twice.
+    DuplicateCard(Card),
+    /// The arguments do not fit the subcommand.
+    Usage(&'static str),
+    /// The calculation cannot be run exhaustively.
+    Unsupported(&'static str),
+    /// The equity calculation could not be run.
+    Equity(EquityError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCommand(command) => write!(f, "unknown command '{command}'"),
+            Self::UnknownOption(option) => write!(f, "unknown option '{option}'"),
+            Self::MissingValue(option) => write!(f, "{option} needs a value"),
+            Self::InvalidValue { option, value } => write!(f, "invalid {option} value '{value}'"),
+            Self::InvalidCards(text) => write!(f, "invalid cards '{text}'"),
+            Self::InvalidBoard(text) => write!(f, "board '{text}' must have 0, 3, 4, or 5 cards"),
+            Self::DuplicateCard(card) => write!(f, "card {card} appears twice"),
+            Self::Usage(message) | Self::Unsupported(message) => write!(f, "{message}"),
+            Self::Equity(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for CliError {}
+
+impl From<EquityError> for CliError {
+    fn from(error: EquityError) -> Self {
+        Self::Equity(error)
+    }
+}
+
+/// Parses `args` (excluding the program name), runs the command, and returns its output.
+///
+/// # Errors
+/// Returns `CliError` if the arguments cannot be parsed or the command cannot be run.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::cli;
+///
+/// let args = ["evaluate", "As Ks Qs Js Ts 2h 3d"].map(String::from);
+/// let output = cli::run(args).unwrap();
+/// assert!(output.contains("Straight Flush"));
+/// ```
+pub fn run(args: impl IntoIterator<Item = String>) -> Result<String, CliError> {
+    let Invocation {
+        command,
+        format,
+        style,
+    } = Invocation::parse(args)?;
+    let evaluator = CactusKevEvaluator::new();
+
+    match command {
+        Command::Equity {
+            hole_cards,
+            board,
+            opponents,
+            samples,
+            exact,
+        } => {
+            let result = if exact {
+                // Enumerating every runout is only quick once most of the board is known
+                let supported = match board.len() {
+                    3 => opponents == 1,
+                    4 | 5 => opponents <= 2,
+                    _ => false,
+                };
+                if !supported {
+                    return Err(CliError::Unsupported(
+                        "--exact needs a flop for one opponent, or a turn for two",
+                    ));
+                }
+                ExhaustiveEquityCalculator::new(evaluator).calculate(&hole_cards, &board, opponents)?
+            } else {
+                let calculator = MonteCarloEquityCalculator::new(evaluator);
+                samples.map_or_else(
+                    || calculator.calculate(&hole_cards, &board, opponents),
+                    |samples| calculator.calculate_sampled(&hole_cards, &board, opponents, samples),
+                )?
+            };
+            Ok(render::equity(
+                format, style, hole_cards, &board, opponents, &result, exact,
+            ))
+        }
+        Command::Evaluate { cards } => {
+            let hand = best_hand(&evaluator, &cards);
+            Ok(render::evaluation(format, style, &cards, &hand))
+        }
+        Command::Showdown { players, board } => {
+            let result = ShowdownSolver::new(evaluator).solve_with_hands(&players, &board);
+            Ok(render::showdown(format, style, &players, &board, &result))
+        }
+        Command::Help => Ok(USAGE.to_string()),
+    }
+}
+
+/// Best five-card hand among five to seven cards.
+fn best_hand(evaluator: &impl HandEvaluator, cards: &[Card]) -> Hand {
+    (0u32..1 << cards.len())
+        .filter(|mask| mask.count_ones() == 5)
+        .filter_map(|mask| {
+            let five: Vec<Card> = cards
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| mask & (1 << i) != 0)
+                .map(|(_, &card)| card)
+                .collect();
+            <[Card; 5]>::try_from(five).ok()
+        })
+        .map(|five| evaluator.evaluate_5cards(five))
+        .min_by_key(Hand::strength)
+        .expect("five to seven cards make at least one hand")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_args(args: &[&str]) -> Result<String, CliError> {
+        run(args.iter().map(|arg| (*arg).to_string()))
+    }
+
+    #[test]
+    fn test_evaluate_picks_best_five() {
+        let output = run_args(&["evaluate", "2h 2d 2c Kd Ks 7s 3c"]).unwrap();
+        assert!(output.starts_with("Hand:       Full House"), "{output}");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&run_args(&["evaluate", "AsKsQsJsTs", "--json"]).unwrap())
+                .unwrap();
+        assert_eq!(json["rank"], "Straight Flush");
+        assert_eq!(json["strength"], 1);
+    }
+
+    #[test]
+    fn test_equity_exact_on_river() {
+        let json: serde_json::Value = serde_json::from_str(
+            &run_args(&[
+                "equity",
+                "AsAh",
+                "-b",
+                "Kd Qc Js 2h 7c",
+                "--exact",
+                "--json",
+            ])
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["samples"], 990);
+        assert!(json["equity"].as_f64().unwrap() > 0.85);
+        assert!(json["margin_of_error"].is_null());
+
+        assert!(matches!(
+            run_args(&["equity", "AsAh", "--exact"]),
+            Err(CliError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_showdown() {
+        let output = run_args(&["showdown", "AsKs", "QhQd", "-b", "Qs Js 2h 7c 3d"]).unwrap();
+        assert!(
+            output.contains("Player 2: QhQd  Three of a Kind"),
+            "{output}"
+        );
+        assert!(output.ends_with("Winner: player 2"), "{output}");
+
+        let json: serde_json::Value = serde_json::from_str(
+            &run_args(&["showdown", "2c3c", "2d3d", "-b", "As Ks Qs Js Ts", "--json"]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["winners"], serde_json::json!([0, 1]));
+    }
+
+    #[test]
+    fn test_unicode_style() {
+        let output = run_args(&[
+            "showdown",
+            "AsKs",
+            "QhQd",
+            "-b",
+            "Qs Js 2h 7c 3d",
+            "--style",
+            "unicode",
+        ])
+        .unwrap();
+        assert!(output.starts_with("Board: [Q♠ J♠ 2♥ 7♣ 3♦]"), "{output}");
+        assert!(output.contains("Player 1: A♠K♠  "), "{output}");
+
+        // JSON keeps the plain card codes
+        let json: serde_json::Value = serde_json::from_str(
+            &run_args(&["evaluate", "AhKhQhJhTh", "--style", "color", "--json"]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["best_five"][0], "Ah");
+    }
+}
@@ -0,0 +1,146 @@
+//! Formats command results as text or JSON.
+
+use serde_json::json;
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_display::DisplayStyle;
+use crate::core::domain::entities::hand::Hand;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::ports::inbound::{EquityResult, ShowdownResultWithHands};
+
+use super::command::OutputFormat;
+
+/// Confidence level of the reported Monte Carlo margin of error.
+const CONFIDENCE: f64 = 0.95;
+
+/// Renders an equity result.
+pub fn equity(
+    format: OutputFormat,
+    style: DisplayStyle,
+    hole_cards: HoleCards,
+    board: &Board,
+    opponents: usize,
+    result: &EquityResult,
+    exact: bool,
+) -> String {
+    let margin = (!exact).then(|| result.margin_of_error(CONFIDENCE));
+    match format {
+        OutputFormat::Json => json!({
+            "hand": hole_cards.to_string(),
+            "board": cards_json(board.cards()),
+            "opponents": opponents,
+            "exact": exact,
+            "equity": result.equity(),
+            "win": result.win_rate(),
+            "tie": result.tie_rate(),
+            "loss": result.lose_rate(),
+            "margin_of_error": margin,
+            "samples": result.samples(),
+        })
+        .to_string(),
+        OutputFormat::Text => {
+            let equity = margin.map_or_else(
+                || format!("{:.2}%", result.equity_percent()),
+                |margin| format!("{:.2}% ± {:.2}%", result.equity_percent(), margin * 100.0),
+            );
+            let count = if exact { "Runouts:" } else { "Samples:" };
+            [
+                format!("Hand:      {}", hole_cards.display(style)),
+                format!("Board:     {}", board.display(style)),
+                format!("Opponents: {opponents}"),
+                format!("Equity:    {equity}"),
+                format!("Win:       {:.2}%", result.win_percent()),
+                format!("Tie:       {:.2}%", result.tie_percent()),
+                format!("Loss:      {:.2}%", result.lose_percent()),
+                format!("{count:<10} {}", result.samples()),
+            ]
+            .join("\n")
+        }
+    }
+}
+
+/// Renders the best hand made from `cards`.
+pub fn evaluation(format: OutputFormat, style: DisplayStyle, cards: &[Card], hand: &Hand) -> String {
+    match format {
+        OutputFormat::Json => json!({
+            "cards": cards_json(cards),
+            "rank": hand.rank().name(),
+            "best_five": cards_json(hand.cards()),
+            "strength": hand.strength(),
+            "percentile": hand.percentile(),
+        })
+        .to_string(),
+        OutputFormat::Text => {
+            format!(
+                "Hand:       {}\nStrength:   {}\nPercentile: {:.1}%",
+                hand.display(style),
+                hand.strength(),
+                hand.percentile() * 100.0
+            )
+        }
+    }
+}
+
+/// Renders a showdown between `players`.
+pub fn showdown(
+    format: OutputFormat,
+    style: DisplayStyle,
+    players: &[HoleCards],
+    board: &Board,
+    result: &ShowdownResultWithHands,
+) -> String {
+    match format {
+        OutputFormat::Json => {
+            let players: Vec<_> = players
+                .iter()
+                .zip(&result.hands)
+                .map(|(hole_cards, hand)| {
+                    json!({
+                        "hand": hole_cards.to_string(),
+                        "rank": hand.rank().name(),
+                        "best_five": cards_json(hand.cards()),
+                    })
+                })
+                .collect();
+            json!({
+                "board": cards_json(board.cards()),
+                "players": players,
+                "winners": result.winner_indices(),
+            })
+            .to_string()
+        }
+        OutputFormat::Text => {
+            let mut lines = vec![format!("Board: {}", board.display(style))];
+            for (i, (hole_cards, hand)) in players.iter().zip(&result.hands).enumerate() {
+                let marker = if result.winner_indices().contains(&i) {
+                    " *"
+                } else {
+                    ""
+                };
+                lines.push(format!(
+                    "Player {}: {}  {}{marker}",
+                    i + 1,
+                    hole_cards.display(style),
+                    hand.display(style)
+                ));
+            }
+            let seats: Vec<String> = result
+                .winner_indices()
+                .iter()
+                .map(|i| (i + 1).to_string())
+                .collect();
+            lines.push(if result.is_tie() {
+                format!("Split pot: players {}", seats.join(", "))
+            } else {
+                format!("Winner: player {}", seats.join(""))
+            });
+            lines.join("\n")
+        }
+    }
+}
+
+/// Cards as a JSON array of strings like `"As"`.
+fn cards_json(cards: &[Card]) -> serde_json::Value {
+    cards.iter().map(ToString::to_string).collect()
+}
@@ -0,0 +1,406 @@
+//! Expected value ("EV cashout") of hands decided by an all-in runout.
+
+use crate::core::domain::aggregates::{GameAggregate, GameEvent};
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::primitives::{Chips, PlayerId, Street};
+use crate::core::domain::services::ExhaustiveEquityCalculator;
+use crate::core::ports::inbound::{EquityResult, HandEvaluator};
+use crate::core::ports::outbound::{self, AllInEv, PlayerStatsStore, ReadModelError};
+
+use super::hand_engine::HandOutcome;
+
+/// One player's equity when the chips went in, and what it was worth.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeatEquity {
+    /// The player.
+    pub player: PlayerId,
+    /// Share of the main pot the player was expected to win, from 0 to 1.
+    pub equity: f64,
+    /// Chips the player was expected to win from every pot they could win.
+    pub expected: f64,
+    /// Chips the player was actually awarded.
+    pub actual: Chips,
+}
+
+/// `SeatEquity` - Accessors
+impl SeatEquity {
+    /// Chips won above (positive) or below (negative) expectation.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn luck(&self) -> f64 {
+        self.actual.value() as f64 - self.expected
+    }
+}
+
+/// The moment a hand went all-in before the river, and each player's share of it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllInEquity {
+    /// Street the last action was taken on.
+    pub street: Street,
+    /// Community cards dealt when the last action was taken.
+    pub board: Vec<Card>,
+    /// Every player still in the hand, in seat order.
+    pub seats: Vec<SeatEquity>,
+}
+
+/// `AllInEquity` - Accessors
+impl AllInEquity {
+    /// Equity of `player`, if they were in the all-in.
+    #[must_use]
+    pub fn seat(&self, player: PlayerId) -> Option<&SeatEquity> {
+        self.seats.iter().find(|seat| seat.player == player)
+    }
+
+    /// The read model form, naming seats by `players`, indexed by `PlayerId`.
+    ///
+    /// Expected winnings are rounded to the nearest chip.
+    #[must_use]
+    pub fn to_read_model(&self, players: &[outbound::PlayerId]) -> Vec<AllInEv> {
+        self.seats
+            .iter()
+            .filter_map(|seat| {
+                Some(AllInEv {
+                    player_id: players.get(seat.player.as_index())?.clone(),
+                    equity: seat.equity,
+                    expected: rounded(seat.expected),
+                    actual: seat.actual.value(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Works out what players all-in before the river were expected to win.
+///
+/// A hand counts when betting ended before the river with more than one player left,
+/// so the rest of the board was run out with no further decisions. Each player's
+/// equity is computed exactly, over every runout from the board as it was at the last
+/// action. Every pot, main and side, is split by the equities of the players eligible
+/// for it, so a short stack's expectation only covers the chips they could win.
+///
+/// Results can be written to a `PlayerStatsStore` with `record_hand`, and attached to a
+/// `HandSummary` with `AllInEquity::to_read_model`; the gap between expected and actual
+/// winnings is the luck that trackers subtract to give "all-in adjusted" results.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::application::{AllInEvAnalyzer, HandEngine, HandSetup};
+/// use riverrun::core::domain::entities::card::Card;
+/// use riverrun::core::domain::entities::deck::Deck;
+/// use riverrun::core::domain::primitives::{Action, Blinds, Chips, HandNumber, PlayerId, Street};
+/// use riverrun::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+///
+/// // Cards in the order they are dealt: hole cards one at a time, then burns and streets
+/// let dealt: Vec<Card> = "As Ks Ah Kh 3c 2c 7d 9s 4c Jd 5c Kd"
+///     .split_whitespace()
+///     .map(|card| card.parse().unwrap())
+///     .collect();
+/// let mut cards = Deck::excluding(&dealt).to_vec();
+/// cards.extend(dealt.iter().rev());
+///
+/// let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+/// let blinds = Blinds::new(Chips::new(5), Chips::new(10));
+/// let setup = HandSetup::new(HandNumber::new(1), vec![Chips::new(100); 2], PlayerId::new(0), blinds);
+/// engine.start_hand(setup, Deck::from_cards(cards)).unwrap();
+/// engine.act(PlayerId::new(0), Action::Call(Chips::new(5))).unwrap();
+/// engine.act(PlayerId::new(1), Action::Check).unwrap();
+/// // Kings shove the flop into aces, and hit a king on the river
+/// engine.act(PlayerId::new(1), Action::AllIn(Chips::new(90))).unwrap();
+/// engine.act(PlayerId::new(0), Action::Call(Chips::new(90))).unwrap();
+///
+/// let analyzer = AllInEvAnalyzer::new(CactusKevEvaluator::new());
+/// let all_in = analyzer.analyze(engine.history(), engine.outcome().unwrap()).unwrap();
+/// assert_eq!(all_in.street, Street::Flop);
+/// let aces = all_in.seat(PlayerId::new(0)).unwrap();
+/// assert!(aces.equity > 0.9);
+/// assert_eq!(aces.actual, Chips::ZERO);
+/// assert!(aces.luck() < -150.0);
+/// ```
+pub struct AllInEvAnalyzer<E: HandEvaluator> {
+    calculator: ExhaustiveEquityCalculator<E>,
+}
+
+/// `AllInEvAnalyzer` - Constructors
+impl<E: HandEvaluator> AllInEvAnalyzer<E> {
+    /// Creates an analyzer evaluating runouts with `evaluator`.
+    #[must_use]
+    pub const fn new(evaluator: E) -> Self {
+        Self {
+            calculator: ExhaustiveEquityCalculator::new(evaluator),
+        }
+    }
+}
+
+/// `AllInEvAnalyzer` - Operations
+impl<E: HandEvaluator> AllInEvAnalyzer<E> {
+    /// Analyzes a completed hand.
+    ///
+    /// `events` is the hand's full history, from `GameEvent::HandStarted` on. Returns
+    /// `None` unless the hand was decided by an all-in before the river.
+    #[must_use]
+    pub fn analyze(&self, events: &[GameEvent], outcome: &HandOutcome) -> Option<AllInEquity> {
+        if !outcome.went_to_showdown() {
+            return None;
+        }
+
+        let mut aggregate = GameAggregate::new();
+        let mut last_decision = None;
+        for event in events {
+            aggregate.apply(event);
+            if matches!(
+                event,
+                GameEvent::HoleCardsDealt { .. } | GameEvent::ActionTaken { .. }
+            ) {
+                let game = aggregate.game()?;
+                last_decision = Some((game.street(), game.board().cards().to_vec()));
+            }
+        }
+        let (street, board) = last_decision?;
+        if street == Street::River {
+            return None;
+        }
+
+        let game = aggregate.game()?;
+        let betting = aggregate.betting()?;
+        let hole_cards = |player: PlayerId| {
+            let [first, second] = *game.player_hole_cards(player.as_index())?;
+            Some(HoleCards::new(first, second))
+        };
+        let runout_from = Board::with_cards(board.clone())?;
+
+        let mut expected = vec![0.0; betting.num_players()];
+        let mut main_pot_equity = Vec::new();
+        let pots = std::iter::once(betting.main_pot()).chain(betting.side_pots());
+        for (index, pot) in pots.enumerate() {
+            let eligible = pot.eligible_players();
+            let hands = eligible
+                .iter()
+                .map(|&player| hole_cards(player))
+                .collect::<Option<Vec<HoleCards>>>()?;
+            let equities = self.split_equities(&hands, &runout_from);
+            #[allow(clippy::cast_precision_loss)]
+            let amount = pot.amount().value() as f64;
+            for (&player, &equity) in eligible.iter().zip(&equities) {
+                expected[player.as_index()] += equity * amount;
+            }
+            if index == 0 {
+                main_pot_equity = eligible.iter().copied().zip(equities).collect();
+            }
+        }
+
+        let seats = (0..betting.num_players())
+            .map(PlayerId::from)
+            .filter(|&player| !betting.has_folded(player))
+            .map(|player| SeatEquity {
+                player,
+                equity: main_pot_equity
+                    .iter()
+                    .find(|(eligible, _)| *eligible == player)
+                    .map_or(0.0, |&(_, equity)| equity),
+                expected: expected[player.as_index()],
+                actual: outcome.payouts().amount_for(player),
+            })
+            .collect();
+        Some(AllInEquity {
+            street,
+            board,
+            seats,
+        })
+    }
+
+    /// Analyzes a completed hand and adds the all-in to the stats of every player in
+    /// it.
+    ///
+    /// `players` is indexed by `PlayerId`. Returns the analysis, if the hand was
+    /// decided by an all-in before the river; otherwise nothing is recorded.
+    ///
+    /// # Errors
+    /// Returns the store's error if stats cannot be loaded or saved. Players before
+    /// the failing one have already been recorded.
+    pub fn record_hand<S: PlayerStatsStore>(
+        &self,
+        store: &S,
+        players: &[outbound::PlayerId],
+        events: &[GameEvent],
+        outcome: &HandOutcome,
+    ) -> Result<Option<AllInEquity>, ReadModelError> {
+        let Some(all_in) = self.analyze(events, outcome) else {
+            return Ok(None);
+        };
+        for ev in all_in.to_read_model(players) {
+            let mut stats = store.get(&ev.player_id)?;
+            stats.all_in_hands += 1;
+            stats.all_in_expected_winnings += ev.expected;
+            stats.all_in_winnings += ev.actual;
+            store.save(&stats)?;
+        }
+        Ok(Some(all_in))
+    }
+}
+
+/// `AllInEvAnalyzer` - Helpers
+impl<E: HandEvaluator> AllInEvAnalyzer<E> {
+    /// Share of a pot each of `hands` is expected to win on `board`, summing to one.
+    fn split_equities(&self, hands: &[HoleCards], board: &Board) -> Vec<f64> {
+        if hands.is_empty() {
+            return Vec::new();
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let even = 1.0 / hands.len() as f64;
+        // Hands that clash with each other or the board cannot be ranked, so split evenly
+        self.calculator.calculate_all(hands, board, &[]).map_or_else(
+            |_| vec![even; hands.len()],
+            |results| results.iter().map(EquityResult::equity).collect(),
+        )
+    }
+}
+
+/// `chips` rounded to the nearest whole chip.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn rounded(chips: f64) -> u64 {
+    chips.round().max(0.0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryPlayerStatsStore;
+    use crate::core::application::{HandEngine, HandSetup};
+    use crate::core::domain::entities::deck::Deck;
+    use crate::core::domain::primitives::{Action, Blinds, HandNumber};
+    use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+
+    fn p(seat: u8) -> PlayerId {
+        PlayerId::new(seat)
+    }
+
+    /// Engine with a hand started from a deck dealing `dealt` in order.
+    fn start(stacks: &[u64], dealt: &str) -> HandEngine<ShowdownSolver<CactusKevEvaluator>> {
+        let dealt: Vec<Card> = dealt
+            .split_whitespace()
+            .map(|card| card.parse().unwrap())
+            .collect();
+        let mut cards = Deck::excluding(&dealt).to_vec();
+        cards.extend(dealt.iter().rev());
+
+        let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+        let setup = HandSetup::new(
+            HandNumber::new(1),
+            stacks.iter().copied().map(Chips::new).collect(),
+            p(0),
+            Blinds::new(Chips::new(5), Chips::new(10)),
+        );
+        engine.start_hand(setup, Deck::from_cards(cards)).unwrap();
+        engine
+    }
+
+    fn analyzer() -> AllInEvAnalyzer<CactusKevEvaluator> {
+        AllInEvAnalyzer::new(CactusKevEvaluator::new())
+    }
+
+    #[test]
+    fn test_side_pot_expectation() {
+        // Button, small and big blind hold aces, kings and queens; flop 7d 9s 4c
+        let mut engine = start(
+            &[200, 100, 300],
+            "As Ks Qs Ah Kh Qh 3c 7d 9s 4c 2c Jd 5c 8h",
+        );
+        engine.act(p(0), Action::Call(Chips::new(10))).unwrap();
+        engine.act(p(1), Action::Call(Chips::new(5))).unwrap();
+        engine.act(p(2), Action::Check).unwrap();
+        engine.act(p(1), Action::AllIn(Chips::new(90))).unwrap();
+        engine.act(p(2), Action::AllIn(Chips::new(290))).unwrap();
+        engine.act(p(0), Action::AllIn(Chips::new(190))).unwrap();
+
+        let all_in = analyzer()
+            .analyze(engine.history(), engine.outcome().unwrap())
+            .unwrap();
+        assert_eq!(all_in.street, Street::Flop);
+        assert_eq!(all_in.board.len(), 3);
+        assert_eq!(all_in.seats.len(), 3);
+
+        // A 300 main pot, a 200 side pot without the kings, and 100 uncalled
+        let expected: f64 = all_in.seats.iter().map(|seat| seat.expected).sum();
+        assert!((expected - 600.0).abs() < 1e-6);
+        let equity: f64 = all_in.seats.iter().map(|seat| seat.equity).sum();
+        assert!((equity - 1.0).abs() < 1e-9);
+        let aces = all_in.seat(p(0)).unwrap();
+        let kings = all_in.seat(p(1)).unwrap();
+        assert!(aces.equity > kings.equity);
+        // The kings can only win the main pot
+        let kings_share = kings.expected / 300.0;
+        assert!((kings_share - kings.equity).abs() < 1e-9);
+        let queens = all_in.seat(p(2)).unwrap();
+        assert!(queens.expected > 100.0);
+        // Aces held up
+        assert_eq!(aces.actual, Chips::new(500));
+        assert!(aces.luck() > 0.0);
+    }
+
+    #[test]
+    fn test_record_hand_updates_stats() {
+        let mut engine = start(&[100, 100], "As Ks Ah Kh 3c 2c 7d 9s 4c Jd 5c Kd");
+        engine.act(p(0), Action::Call(Chips::new(5))).unwrap();
+        engine.act(p(1), Action::Check).unwrap();
+        engine.act(p(1), Action::AllIn(Chips::new(90))).unwrap();
+        engine.act(p(0), Action::Call(Chips::new(90))).unwrap();
+
+        let store = InMemoryPlayerStatsStore::new();
+        let players = ["aces".to_string(), "kings".to_string()];
+        let all_in = analyzer()
+            .record_hand(
+                &store,
+                &players,
+                engine.history(),
+                engine.outcome().unwrap(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let summary = all_in.to_read_model(&players);
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].expected + summary[1].expected, 200);
+        assert_eq!((summary[0].actual, summary[1].actual), (0, 200));
+
+        let aces = store.get(&players[0]).unwrap();
+        assert_eq!(aces.all_in_hands, 1);
+        assert_eq!(aces.all_in_expected_winnings, summary[0].expected);
+        assert_eq!(aces.all_in_winnings, 0);
+        assert!(aces.all_in_luck() < -150);
+        assert!(store.get(&players[1]).unwrap().all_in_luck() > 150);
+    }
+
+    #[test]
+    fn test_ignores_hands_without_an_all_in_runout() {
+        // Checked down to the river
+        let mut engine = start(&[100, 100], "As Ks Ah Kh");
+        engine.act(p(0), Action::Call(Chips::new(5))).unwrap();
+        engine.act(p(1), Action::Check).unwrap();
+        for _ in 0..3 {
+            engine.act(p(1), Action::Check).unwrap();
+            engine.act(p(0), Action::Check).unwrap();
+        }
+        let outcome = engine.outcome().unwrap();
+        assert!(outcome.went_to_showdown());
+        assert!(analyzer().analyze(engine.history(), outcome).is_none());
+
+        // Won without a showdown
+        let mut engine = start(&[100, 100], "As Ks Ah Kh");
+        engine.act(p(0), Action::Fold).unwrap();
+        let store = InMemoryPlayerStatsStore::new();
+        let players = ["a".to_string(), "b".to_string()];
+        let recorded = analyzer()
+            .record_hand(
+                &store,
+                &players,
+                engine.history(),
+                engine.outcome().unwrap(),
+            )
+            .unwrap();
+        assert!(recorded.is_none());
+        assert_eq!(store.get(&players[0]).unwrap().all_in_hands, 0);
+    }
+}
@@ -0,0 +1,147 @@
+//! Equity runs served from a result cache when they have been run before.
+
+use crate::core::domain::services::equity::EquityRequestBuilder;
+use crate::core::domain::services::MonteCarloEquityCalculator;
+use crate::core::ports::inbound::{EquityError, EquityResult, HandEvaluator};
+use crate::core::ports::outbound::ResultCache;
+
+/// Runs equity requests through a `ResultCache`, so repeating a request, such as by
+/// reopening a hand review, returns its earlier result instead of sampling again.
+///
+/// Results are stored under `EquityRequestBuilder::cache_key`, which changes with the
+/// evaluator's version, so entries from an older evaluator are never served. The cache
+/// only saves time: an entry that cannot be read or stored is computed afresh rather
+/// than failing the request.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemoryResultCache;
+/// use riverrun::core::application::EquityCache;
+/// use riverrun::core::domain::services::equity::EquityRequestBuilder;
+/// use riverrun::core::domain::services::{CactusKevEvaluator, MonteCarloEquityCalculator};
+///
+/// let calculator = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+/// let equity = EquityCache::new(InMemoryResultCache::new(100));
+/// let request = EquityRequestBuilder::new("AsKs".parse().unwrap()).with_opponents(1);
+///
+/// let first = equity.run(&request, &calculator).unwrap();
+/// let again = equity.run(&request, &calculator).unwrap();
+/// assert_eq!(first.equity(), again.equity());
+/// assert_eq!(equity.cache().len().unwrap(), 1);
+/// ```
+#[derive(Debug)]
+pub struct EquityCache<C: ResultCache> {
+    cache: C,
+}
+
+/// `EquityCache` - Constructors
+impl<C: ResultCache> EquityCache<C> {
+    /// Serve requests from `cache`.
+    pub const fn new(cache: C) -> Self {
+        Self { cache }
+    }
+}
+
+/// `EquityCache` - Accessors
+impl<C: ResultCache> EquityCache<C> {
+    /// The cache results are stored in.
+    pub const fn cache(&self) -> &C {
+        &self.cache
+    }
+}
+
+/// `EquityCache` - Operations
+impl<C: ResultCache> EquityCache<C> {
+    /// The result of `request` run with `calculator`, from the cache when it was run
+    /// before and otherwise computed and stored.
+    ///
+    /// # Errors
+    /// Returns the `EquityError` of `EquityRequestBuilder::run` for an impossible
+    /// request. Failed requests are not cached.
+    pub fn run<E: HandEvaluator>(
+        &self,
+        request: &EquityRequestBuilder,
+        calculator: &MonteCarloEquityCalculator<E>,
+    ) -> Result<EquityResult, EquityError> {
+        let Some(key) = request.cache_key(calculator) else {
+            return request.run(calculator);
+        };
+        if let Some(result) = self
+            .cache
+            .get(&key)
+            .ok()
+            .flatten()
+            .and_then(|bytes| EquityResult::from_bytes(&bytes))
+        {
+            return Ok(result);
+        }
+
+        let result = request.run(calculator)?;
+        // A result that cannot be stored is simply computed again next time
+        let _ = self.cache.put(&key, &result.to_bytes());
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryResultCache;
+    use crate::core::domain::entities::hole_cards::HoleCards;
+    use crate::core::domain::services::analysis::RunoutConstraint;
+    use crate::core::domain::services::CactusKevEvaluator;
+    use crate::core::ports::outbound::CacheKey;
+
+    fn calculator() -> MonteCarloEquityCalculator<CactusKevEvaluator> {
+        MonteCarloEquityCalculator::with_samples(CactusKevEvaluator::new(), 2_000)
+    }
+
+    #[test]
+    fn test_equivalent_requests_share_an_entry() {
+        let calculator = calculator();
+        let equity = EquityCache::new(InMemoryResultCache::new(10));
+        let request = |hero: &str| {
+            EquityRequestBuilder::new(hero.parse().unwrap())
+                .with_board("Qh 7h 2c".parse().unwrap())
+                .with_opponents(1)
+        };
+
+        let first = equity.run(&request("AhKh"), &calculator).unwrap();
+        let swapped = equity.run(&request("KhAh"), &calculator).unwrap();
+        assert_eq!(first.equity().to_bits(), swapped.equity().to_bits());
+        assert_eq!(first.samples(), swapped.samples());
+        assert_eq!(equity.cache().len().unwrap(), 1);
+
+        // Spelling out the default sample count is the same request
+        equity.run(&request("AhKh").with_samples(2_000), &calculator).unwrap();
+        assert_eq!(equity.cache().len().unwrap(), 1);
+        equity.run(&request("AhKh").with_samples(3_000), &calculator).unwrap();
+        equity.run(&request("AhKh").with_seed(7), &calculator).unwrap();
+        assert_eq!(equity.cache().len().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_unusable_entries_are_recomputed() {
+        let calculator = calculator();
+        let equity = EquityCache::new(InMemoryResultCache::new(10));
+        let hero: HoleCards = "AsAd".parse().unwrap();
+        let request = EquityRequestBuilder::new(hero).with_opponents(1);
+        let key = request.cache_key(&calculator).unwrap();
+        equity.cache().put(&key, b"corrupt").unwrap();
+
+        let result = equity.run(&request, &calculator).unwrap();
+        assert_eq!(result.samples(), 2_000);
+        assert_eq!(
+            EquityResult::from_bytes(&equity.cache().get(&key).unwrap().unwrap())
+                .map(|cached| cached.equity().to_bits()),
+            Some(result.equity().to_bits())
+        );
+
+        // Custom constraints cannot be keyed, so their requests bypass the cache
+        let custom = request.with_emphasis(RunoutConstraint::Custom(|_, _| true), 2.0);
+        assert_eq!(custom.cache_key(&calculator), None::<CacheKey>);
+        equity.run(&custom, &calculator).unwrap();
+        assert_eq!(equity.cache().len().unwrap(), 1);
+    }
+}
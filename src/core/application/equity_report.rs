@@ -0,0 +1,284 @@
+//! Batches of equity queries exported as CSV or JSON for plotting.
+
+use std::io::{self, Write};
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_display::{CardsDisplay, DisplayStyle};
+use crate::core::domain::entities::card_set::CardSet;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::ports::inbound::{EquityCalculator, EquityError, EquityResult};
+
+/// Header row written by `EquityReport::to_csv`.
+const CSV_HEADER: &str = "label,board,equity,win,tie,loss,samples,std_error";
+
+/// Equity of the report's hand on one board.
+#[derive(Clone, Debug)]
+pub struct EquityPoint {
+    /// What varies between points, e.g. the turn card dealt.
+    pub label: String,
+    /// The board the equity was calculated on.
+    pub board: Board,
+    /// The calculated equity.
+    pub result: EquityResult,
+}
+
+/// Equity of one hand across a batch of boards, ready to plot.
+///
+/// Build one point per board with `run`, or per possible next card with
+/// `across_next_card`, then write the points with `to_csv` or `to_json`.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::application::EquityReport;
+/// use riverrun::core::domain::services::{CactusKevEvaluator, SmartEquityCalculator};
+///
+/// let calculator = SmartEquityCalculator::new(CactusKevEvaluator::new());
+/// let report = EquityReport::across_next_card(
+///     &calculator,
+///     "AsKs".parse().unwrap(),
+///     &"Qs Js 2h 7c".parse().unwrap(),
+///     1,
+/// )
+/// .unwrap();
+/// assert_eq!(report.points().len(), 46);
+///
+/// let mut csv = Vec::new();
+/// report.to_csv(&mut csv).unwrap();
+/// let csv = String::from_utf8(csv).unwrap();
+/// assert!(csv.starts_with("label,board,equity,win,tie,loss,samples,std_error\n2c,"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct EquityReport {
+    hole_cards: HoleCards,
+    opponents: usize,
+    points: Vec<EquityPoint>,
+}
+
+/// `EquityReport` - Constructors
+impl EquityReport {
+    /// An empty report for `hole_cards` against `opponents` random hands.
+    #[must_use]
+    pub const fn new(hole_cards: HoleCards, opponents: usize) -> Self {
+        Self {
+            hole_cards,
+            opponents,
+            points: Vec::new(),
+        }
+    }
+
+    /// Equity of `hole_cards` on each labelled board.
+    ///
+    /// # Errors
+    /// Returns the first `EquityError` raised by `calculator`.
+    pub fn run<C: EquityCalculator>(
+        calculator: &C,
+        hole_cards: HoleCards,
+        boards: impl IntoIterator<Item = (String, Board)>,
+        opponents: usize,
+    ) -> Result<Self, EquityError> {
+        let mut report = Self::new(hole_cards, opponents);
+        for (label, board) in boards {
+            let result = calculator.calculate(&hole_cards, &board, opponents)?;
+            report.push(label, board, result);
+        }
+        Ok(report)
+    }
+
+    /// Equity of `hole_cards` after each card that can come next on `board`, labelled
+    /// with that card: every turn for a flop, every river for a turn.
+    ///
+    /// # Errors
+    /// Returns `EquityError::UnsupportedConfiguration` unless `board` is a flop or turn,
+    /// or the first error raised by `calculator`.
+    pub fn across_next_card<C: EquityCalculator>(
+        calculator: &C,
+        hole_cards: HoleCards,
+        board: &Board,
+        opponents: usize,
+    ) -> Result<Self, EquityError> {
+        if !matches!(board.len(), 3 | 4) {
+            return Err(EquityError::UnsupportedConfiguration(
+                "the next card needs a flop or turn",
+            ));
+        }
+        let dead =
+            CardSet::from_cards(hole_cards.cards()).union(CardSet::from_cards(board.cards()));
+        let boards = CardSet::full().difference(dead).iter().filter_map(|card| {
+            let cards: Vec<Card> = board.cards().iter().copied().chain([card]).collect();
+            Board::partial(cards).map(|next| (card.to_string(), next))
+        });
+        Self::run(calculator, hole_cards, boards, opponents)
+    }
+}
+
+/// `EquityReport` - Accessors
+impl EquityReport {
+    /// The hand whose equity is reported.
+    #[must_use]
+    pub const fn hole_cards(&self) -> HoleCards {
+        self.hole_cards
+    }
+
+    /// Number of random opponents.
+    #[must_use]
+    pub const fn opponents(&self) -> usize {
+        self.opponents
+    }
+
+    /// The points, in the order they were added.
+    #[must_use]
+    pub fn points(&self) -> &[EquityPoint] {
+        &self.points
+    }
+}
+
+/// `EquityReport` - Operations
+impl EquityReport {
+    /// Adds the equity calculated on `board`.
+    pub fn push(&mut self, label: impl Into<String>, board: Board, result: EquityResult) {
+        self.points.push(EquityPoint {
+            label: label.into(),
+            board,
+            result,
+        });
+    }
+
+    /// Writes one CSV row per point under a header row.
+    ///
+    /// Boards are written compactly, e.g. `QsJs2h`, and rates as fractions from 0 to 1.
+    ///
+    /// # Errors
+    /// Returns any error from `writer`.
+    pub fn to_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{CSV_HEADER}")?;
+        for point in &self.points {
+            let result = &point.result;
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{}",
+                csv_field(&point.label),
+                compact(point.board.cards()),
+                result.equity(),
+                result.win_rate(),
+                result.tie_rate(),
+                result.lose_rate(),
+                result.samples(),
+                result.std_error(),
+            )?;
+        }
+        writer.flush()
+    }
+
+    /// Writes the report as one JSON object with the hand, opponents and points.
+    ///
+    /// # Errors
+    /// Returns any error from `writer`.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self, mut writer: impl Write) -> io::Result<()> {
+        let points: Vec<serde_json::Value> = self
+            .points
+            .iter()
+            .map(|point| {
+                let result = &point.result;
+                serde_json::json!({
+                    "label": point.label,
+                    "board": compact(point.board.cards()),
+                    "equity": result.equity(),
+                    "win": result.win_rate(),
+                    "tie": result.tie_rate(),
+                    "loss": result.lose_rate(),
+                    "samples": result.samples(),
+                    "std_error": result.std_error(),
+                })
+            })
+            .collect();
+        let report = serde_json::json!({
+            "hole_cards": compact(self.hole_cards.cards()),
+            "opponents": self.opponents,
+            "points": points,
+        });
+        serde_json::to_writer(&mut writer, &report)?;
+        writer.flush()
+    }
+}
+
+/// `cards` back to back, e.g. `QsJs2h`.
+fn compact(cards: &[Card]) -> String {
+    CardsDisplay::new(cards, DisplayStyle::Ascii).to_string()
+}
+
+/// Quotes `field` if it holds a comma, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::services::{CactusKevEvaluator, ExhaustiveEquityCalculator};
+
+    fn calculator() -> ExhaustiveEquityCalculator<CactusKevEvaluator> {
+        ExhaustiveEquityCalculator::new(CactusKevEvaluator::new())
+    }
+
+    #[test]
+    fn test_across_next_card() {
+        let hole: HoleCards = "AsKs".parse().unwrap();
+        let turn: Board = "Qs Js 2h 7c".parse().unwrap();
+        let report = EquityReport::across_next_card(&calculator(), hole, &turn, 1).unwrap();
+        assert_eq!(report.points().len(), 46);
+        let royal = report
+            .points()
+            .iter()
+            .find(|point| point.label == "Ts")
+            .unwrap();
+        assert!((royal.result.equity() - 1.0).abs() < 1e-12);
+        assert_eq!(royal.board.len(), 5);
+
+        let river: Board = "Qs Js 2h 7c 3d".parse().unwrap();
+        assert!(matches!(
+            EquityReport::across_next_card(&calculator(), hole, &river, 1),
+            Err(EquityError::UnsupportedConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let hole: HoleCards = "AsKs".parse().unwrap();
+        let boards = [
+            (
+                "dry, rainbow".to_string(),
+                "2c 7d 9h 4s 3c".parse().unwrap(),
+            ),
+            ("royal".to_string(), "Qs Js Ts 4d 3c".parse().unwrap()),
+        ];
+        let report = EquityReport::run(&calculator(), hole, boards, 1).unwrap();
+        let mut csv = Vec::new();
+        report.to_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert!(lines[1].starts_with("\"dry, rainbow\",2c7d9h4s3c,"));
+        assert!(lines[2].starts_with("royal,QsJsTs4d3c,1,1,0,0,990,"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_json() {
+        let hole: HoleCards = "AsKs".parse().unwrap();
+        let boards = [("royal".to_string(), "Qs Js Ts 4d 3c".parse().unwrap())];
+        let report = EquityReport::run(&calculator(), hole, boards, 1).unwrap();
+        let mut json = Vec::new();
+        report.to_json(&mut json).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json["hole_cards"], "AsKs");
+        assert_eq!(json["points"][0]["board"], "QsJsTs4d3c");
+        assert_eq!(json["points"][0]["equity"], 1.0);
+    }
+}
@@ -0,0 +1,495 @@
+//! Hand orchestration: runs a complete hand from the blinds to the payouts.
+
+use crate::core::domain::aggregates::{GameAggregate, GameCommand, GameEvent};
+use crate::core::domain::entities::deck::Deck;
+use crate::core::domain::entities::game::Game;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::errors::GameError;
+use crate::core::domain::primitives::{
+    Action, Blinds, Chips, HandNumber, LegalActions, PlayerId, Position, Street, TableSeating,
+};
+use crate::core::domain::services::solving::{Payouts, PotDistributor};
+use crate::core::ports::inbound::{HandSolver, ShowdownResultWithHands, MAX_PLAYERS};
+use crate::telemetry;
+
+use super::hand_notifier::HandNotifier;
+
+/// Seating, stacks, and blinds for a single hand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HandSetup {
+    /// Number of the hand within the game.
+    pub hand_number: HandNumber,
+    /// Starting stack of each seat, indexed by `PlayerId`.
+    pub stacks: Vec<Chips>,
+    /// Seat holding the dealer button.
+    pub button: PlayerId,
+    /// Small blind amount.
+    pub small_blind: Chips,
+    /// Big blind amount.
+    pub big_blind: Chips,
+    /// Ante posted by every seat; zero for none.
+    pub ante: Chips,
+}
+
+/// `HandSetup` - Constructors
+impl HandSetup {
+    /// Setup for hand `hand_number` played at `blinds`.
+    #[must_use]
+    pub const fn new(
+        hand_number: HandNumber,
+        stacks: Vec<Chips>,
+        button: PlayerId,
+        blinds: Blinds,
+    ) -> Self {
+        Self {
+            hand_number,
+            stacks,
+            button,
+            small_blind: blinds.small_blind,
+            big_blind: blinds.big_blind,
+            ante: blinds.ante,
+        }
+    }
+}
+
+/// `HandSetup` - Accessors
+impl HandSetup {
+    /// Blinds and ante of the hand.
+    #[must_use]
+    pub const fn blinds(&self) -> Blinds {
+        Blinds::new(self.small_blind, self.big_blind).with_ante(self.ante)
+    }
+}
+
+/// Result of a completed hand.
+#[derive(Clone, Debug)]
+pub struct HandOutcome {
+    showdowns: Vec<ShowdownResultWithHands>,
+    payouts: Payouts,
+    final_stacks: Vec<Chips>,
+}
+
+/// `HandOutcome` - Accessors
+impl HandOutcome {
+    /// Evaluated hands of every seat, if the hand reached a showdown. When the board
+    /// was run more than once, this is the showdown on the first board.
+    #[must_use]
+    pub fn showdown(&self) -> Option<&ShowdownResultWithHands> {
+        self.showdowns.first()
+    }
+
+    /// Evaluated hands of every seat on each board, or none without a showdown.
+    #[must_use]
+    pub fn showdowns(&self) -> &[ShowdownResultWithHands] {
+        &self.showdowns
+    }
+
+    /// Returns whether more than one player was left when betting ended.
+    #[must_use]
+    pub const fn went_to_showdown(&self) -> bool {
+        !self.showdowns.is_empty()
+    }
+
+    /// Chips awarded from each pot.
+    #[must_use]
+    pub const fn payouts(&self) -> &Payouts {
+        &self.payouts
+    }
+
+    /// Stack of each seat after the pots have been awarded.
+    #[must_use]
+    pub fn final_stacks(&self) -> &[Chips] {
+        &self.final_stacks
+    }
+
+    /// Net chips won (positive) or lost (negative) by `player` over the hand.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn net_result(&self, player: PlayerId, starting_stack: Chips) -> i64 {
+        let end = self.final_stacks.get(player.as_index()).copied().unwrap_or_default();
+        end.value() as i64 - starting_stack.value() as i64
+    }
+}
+
+/// Application service that runs complete hands.
+///
+/// The engine drives a `GameAggregate` through the hand lifecycle: it posts the blinds
+/// and deals when a hand starts, enforces turn order and bet sizes on every action,
+/// deals each street when its betting round closes, and once betting is over resolves
+/// the showdown with the configured `HandSolver` and awards the pots with a
+/// `PotDistributor`.
+///
+/// Every event produced is kept in the hand history, so the hand can be persisted or
+/// replayed with `GameAggregate::from_events`. An engine given a `HandNotifier` also
+/// publishes each action, street, and showdown, and prompts the player to act next.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::application::{HandEngine, HandSetup};
+/// use riverrun::core::domain::entities::deck::Deck;
+/// use riverrun::core::domain::primitives::{Action, Chips, HandNumber, PlayerId};
+/// use riverrun::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+///
+/// let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+/// engine
+///     .start_hand(
+///         HandSetup {
+///             hand_number: HandNumber::new(1),
+///             stacks: vec![Chips::new(1000); 3],
+///             button: PlayerId::new(0),
+///             small_blind: Chips::new(5),
+///             big_blind: Chips::new(10),
+///             ante: Chips::ZERO,
+///         },
+///         Deck::new(),
+///     )
+///     .unwrap();
+///
+/// engine.act(PlayerId::new(0), Action::Fold).unwrap();
+/// engine.act(PlayerId::new(1), Action::Fold).unwrap();
+///
+/// let outcome = engine.outcome().unwrap();
+/// assert!(!outcome.went_to_showdown());
+/// assert_eq!(outcome.final_stacks()[2], Chips::new(1005));
+/// ```
+#[derive(Debug)]
+pub struct HandEngine<S: HandSolver> {
+    solver: S,
+    distributor: PotDistributor,
+    aggregate: GameAggregate,
+    history: Vec<GameEvent>,
+    outcome: Option<HandOutcome>,
+    notifier: Option<HandNotifier>,
+}
+
+/// `HandEngine` - Constructors
+impl<S: HandSolver> HandEngine<S> {
+    /// Creates an engine that resolves showdowns with `solver` and splits pots using
+    /// the default odd-chip rule.
+    #[must_use]
+    pub fn new(solver: S) -> Self {
+        Self::with_distributor(solver, PotDistributor::default())
+    }
+
+    /// Creates an engine that awards pots with `distributor`.
+    #[must_use]
+    pub fn with_distributor(solver: S, distributor: PotDistributor) -> Self {
+        Self {
+            solver,
+            distributor,
+            aggregate: GameAggregate::new(),
+            history: Vec::new(),
+            outcome: None,
+            notifier: None,
+        }
+    }
+
+    /// Publishes the engine's hands through `notifier`.
+    #[must_use]
+    pub fn with_notifier(mut self, notifier: HandNotifier) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+}
+
+/// `HandEngine` - Accessors
+impl<S: HandSolver> HandEngine<S> {
+    /// The underlying aggregate, for inspecting cards, stacks, and pots.
+    #[must_use]
+    pub const fn aggregate(&self) -> &GameAggregate {
+        &self.aggregate
+    }
+
+    /// Events of the current (or last) hand, in order.
+    #[must_use]
+    pub fn history(&self) -> &[GameEvent] {
+        &self.history
+    }
+
+    /// Returns whether a hand is being played.
+    #[must_use]
+    pub fn is_hand_in_progress(&self) -> bool {
+        self.aggregate.is_hand_in_progress()
+    }
+
+    /// Street of the current (or last) hand.
+    #[must_use]
+    pub fn street(&self) -> Option<Street> {
+        self.aggregate.game().map(Game::street)
+    }
+
+    /// Player whose turn it is.
+    #[must_use]
+    pub fn to_act(&self) -> Option<PlayerId> {
+        self.aggregate.to_act()
+    }
+
+    /// Actions available to the player whose turn it is.
+    #[must_use]
+    pub fn legal_actions(&self) -> LegalActions {
+        self.aggregate.legal_actions()
+    }
+
+    /// Table position of `player` relative to the button.
+    #[must_use]
+    pub fn position(&self, player: PlayerId) -> Option<Position> {
+        let num_players = self.aggregate.betting()?.num_players();
+        TableSeating::with_button(num_players, self.aggregate.button()?)?.position(player)
+    }
+
+    /// Result of the last hand, once betting is over.
+    #[must_use]
+    pub const fn outcome(&self) -> Option<&HandOutcome> {
+        self.outcome.as_ref()
+    }
+
+    /// The notifier publishing the engine's hands, e.g. to reseat it between hands.
+    #[must_use]
+    pub const fn notifier_mut(&mut self) -> Option<&mut HandNotifier> {
+        self.notifier.as_mut()
+    }
+}
+
+/// `HandEngine` - Operations
+impl<S: HandSolver> HandEngine<S> {
+    /// Starts a hand: posts the antes and blinds and deals hole cards from `deck`.
+    ///
+    /// If nobody can act (e.g. the blinds put everyone all-in), the board is run out
+    /// and the hand is resolved immediately.
+    ///
+    /// # Errors
+    /// Returns a `GameError` if a hand is already in progress or `setup` is invalid.
+    pub fn start_hand(&mut self, setup: HandSetup, deck: Deck) -> Result<Vec<GameEvent>, GameError> {
+        let _span = telemetry::hand_engine_span("start_hand");
+        let events = self.aggregate.execute(&GameCommand::StartHand {
+            hand_number: setup.hand_number,
+            stacks: setup.stacks,
+            button: setup.button,
+            small_blind: setup.small_blind,
+            big_blind: setup.big_blind,
+            ante: setup.ante,
+            deck,
+        })?;
+        self.history.clear();
+        self.outcome = None;
+        telemetry::hand_started();
+        self.record(&events);
+        Ok(events)
+    }
+
+    /// Takes `action` for `player`, dealing streets and resolving the hand as needed.
+    ///
+    /// # Errors
+    /// Returns a `GameError` if no hand is in progress or the action is not legal.
+    pub fn act(&mut self, player: PlayerId, action: Action) -> Result<Vec<GameEvent>, GameError> {
+        let _span = telemetry::hand_engine_span("act");
+        let events = self
+            .aggregate
+            .execute(&GameCommand::Act { player, action })?;
+        self.record(&events);
+        Ok(events)
+    }
+
+    /// Runs the rest of the board `times` times if betting ends with players all-in
+    /// before the river (see `GameAggregate::run_it_n_times`); each pot is then split
+    /// evenly between the boards.
+    ///
+    /// # Errors
+    /// Returns `GameError::NoHandInProgress` if no hand is being played.
+    pub fn run_it_n_times(&mut self, times: u8) -> Result<(), GameError> {
+        self.aggregate.run_it_n_times(times)
+    }
+
+    /// Appends `events` to the history, resolving the hand if it finished, and
+    /// publishes them.
+    fn record(&mut self, events: &[GameEvent]) {
+        self.history.extend_from_slice(events);
+        if events
+            .iter()
+            .any(|event| matches!(event, GameEvent::HandFinished { .. }))
+        {
+            self.outcome = self.resolve();
+            telemetry::hand_completed();
+        }
+        if let Some(notifier) = &self.notifier {
+            notifier.publish(events, &self.aggregate, self.outcome.as_ref());
+        }
+    }
+
+    /// Evaluates the showdown on every board, if any, and awards every pot.
+    fn resolve(&self) -> Option<HandOutcome> {
+        let game = self.aggregate.game()?;
+        let betting = self.aggregate.betting()?;
+
+        let showdowns: Vec<ShowdownResultWithHands> = if betting.players_in_hand() > 1 {
+            let players: Vec<HoleCards> = game
+                .all_hole_cards()
+                .iter()
+                .map(|&[first, second]| HoleCards::new(first, second))
+                .collect();
+            self.aggregate
+                .boards()
+                .into_iter()
+                .map(|board| self.solver.solve_with_hands(&players, board))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let payouts = if showdowns.is_empty() {
+            // Uncontested: every pot has a single eligible player, so no hands are needed
+            let uncontested = ShowdownResultWithHands {
+                winners: [0; MAX_PLAYERS],
+                winner_count: 0,
+                hands: Vec::new(),
+                hole_cards: Vec::new(),
+            };
+            self.distributor.distribute(betting, &uncontested)
+        } else {
+            self.distributor
+                .distribute_runouts(&betting.build_pots(), &showdowns)
+        };
+        let final_stacks = (0..betting.num_players())
+            .map(PlayerId::from)
+            .map(|player| betting.stack(player) + payouts.amount_for(player))
+            .collect();
+
+        Some(HandOutcome {
+            showdowns,
+            payouts,
+            final_stacks,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+
+    fn p(seat: u8) -> PlayerId {
+        PlayerId::new(seat)
+    }
+
+    fn engine() -> HandEngine<ShowdownSolver<CactusKevEvaluator>> {
+        HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()))
+    }
+
+    fn setup(stacks: &[u64], button: u8) -> HandSetup {
+        HandSetup {
+            hand_number: HandNumber::new(1),
+            stacks: stacks.iter().copied().map(Chips::new).collect(),
+            button: p(button),
+            small_blind: Chips::new(5),
+            big_blind: Chips::new(10),
+            ante: Chips::ZERO,
+        }
+    }
+
+    fn total(stacks: &[Chips]) -> Chips {
+        stacks.iter().fold(Chips::ZERO, |acc, &stack| acc + stack)
+    }
+
+    #[test]
+    fn test_act_without_hand_is_rejected() {
+        let mut engine = engine();
+        assert_eq!(
+            engine.act(p(0), Action::Check).unwrap_err(),
+            GameError::NoHandInProgress
+        );
+        assert!(engine.outcome().is_none());
+    }
+
+    #[test]
+    fn test_positions_follow_button() {
+        let mut engine = engine();
+        engine.start_hand(setup(&[1000; 4], 2), Deck::new()).unwrap();
+        assert_eq!(engine.position(p(2)), Some(Position::Button));
+        assert_eq!(engine.position(p(3)), Some(Position::SmallBlind));
+        assert_eq!(engine.position(p(0)), Some(Position::BigBlind));
+        assert_eq!(engine.position(p(1)), Some(Position::UTG));
+        assert_eq!(engine.to_act(), Some(p(1)));
+    }
+
+    #[test]
+    fn test_checked_down_hand_reaches_showdown() {
+        let mut engine = engine();
+        engine.start_hand(setup(&[1000; 3], 0), Deck::new()).unwrap();
+        engine.act(p(0), Action::Call(Chips::new(10))).unwrap();
+        engine.act(p(1), Action::Call(Chips::new(5))).unwrap();
+        engine.act(p(2), Action::Check).unwrap();
+        for street in Street::Preflop.after() {
+            assert_eq!(engine.street(), Some(street));
+            for seat in [1, 2, 0] {
+                engine.act(p(seat), Action::Check).unwrap();
+            }
+        }
+
+        assert!(!engine.is_hand_in_progress());
+        let outcome = engine.outcome().unwrap();
+        let showdown = outcome.showdown().unwrap();
+        assert_eq!(showdown.hands.len(), 3);
+        assert_eq!(outcome.payouts().total(), Chips::new(30));
+        assert_eq!(total(outcome.final_stacks()), Chips::new(3000));
+
+        let winner = p(u8::try_from(showdown.winner_indices()[0]).unwrap());
+        assert!(outcome.net_result(winner, Chips::new(1000)) > 0);
+    }
+
+    #[test]
+    fn test_all_in_side_pot_conserves_chips() {
+        let mut engine = engine();
+        engine.start_hand(setup(&[200, 1000, 1000], 0), Deck::new()).unwrap();
+        engine.act(p(0), Action::AllIn(Chips::new(200))).unwrap();
+        engine.act(p(1), Action::Raise(Chips::new(600))).unwrap();
+        engine.act(p(2), Action::Call(Chips::new(590))).unwrap();
+        assert_eq!(engine.street(), Some(Street::Flop));
+
+        engine.act(p(1), Action::Bet(Chips::new(100))).unwrap();
+        engine.act(p(2), Action::Fold).unwrap();
+
+        let outcome = engine.outcome().unwrap();
+        assert!(outcome.went_to_showdown());
+        assert_eq!(outcome.payouts().total(), Chips::new(1500));
+        assert_eq!(total(outcome.final_stacks()), Chips::new(2200));
+        assert_eq!(outcome.final_stacks()[2], Chips::new(400));
+    }
+
+    #[test]
+    fn test_running_it_twice_splits_the_pot() {
+        let mut engine = engine();
+        engine.start_hand(setup(&[500, 500], 0), Deck::new()).unwrap();
+        engine.run_it_n_times(2).unwrap();
+        engine.act(p(0), Action::AllIn(Chips::new(495))).unwrap();
+        engine.act(p(1), Action::Call(Chips::new(490))).unwrap();
+
+        let outcome = engine.outcome().unwrap();
+        assert_eq!(outcome.showdowns().len(), 2);
+        assert_eq!(engine.aggregate().boards().len(), 2);
+        assert_eq!(outcome.payouts().total(), Chips::new(1000));
+        assert_eq!(total(outcome.final_stacks()), Chips::new(1000));
+        assert!(outcome.payouts().awards().iter().any(|award| award.run == 1));
+    }
+
+    #[test]
+    fn test_history_replays_to_same_state() {
+        let mut engine = engine();
+        engine.start_hand(setup(&[1000; 2], 1), Deck::new()).unwrap();
+        engine.act(p(1), Action::Raise(Chips::new(30))).unwrap();
+        engine.act(p(0), Action::Call(Chips::new(20))).unwrap();
+
+        let replayed = GameAggregate::from_events(engine.history());
+        assert_eq!(replayed.version(), engine.aggregate().version());
+        assert_eq!(replayed.to_act(), engine.to_act());
+
+        // A new hand starts a fresh history
+        engine.act(p(0), Action::Bet(Chips::new(10))).unwrap();
+        engine.act(p(1), Action::Fold).unwrap();
+        assert!(engine.outcome().is_some());
+        let mut next = setup(&[1000; 2], 0);
+        next.hand_number = HandNumber::new(2);
+        let events = engine.start_hand(next, Deck::new()).unwrap();
+        assert_eq!(engine.history(), events.as_slice());
+        assert!(engine.outcome().is_none());
+    }
+}
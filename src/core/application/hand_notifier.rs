@@ -0,0 +1,357 @@
+//! Real-time notifications for the hands an engine plays.
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::core::domain::aggregates::{GameAggregate, GameEvent};
+use crate::core::domain::primitives::{Action, Chips, PlayerId};
+use crate::core::ports::outbound::{
+    self, Clock, GameNotification, NotificationPublisher, PlayerAction, Winnings,
+};
+
+use super::hand_engine::HandOutcome;
+
+/// Time a player is given to act when no time bank is configured.
+pub const DEFAULT_TIME_BANK: Duration = Duration::from_secs(30);
+
+/// Publishes the notifications for a `HandEngine`'s hands.
+///
+/// Every action is published as `GameNotification::ActionTaken`, every street dealt as
+/// `StreetDealt`, and a hand decided at showdown as `Showdown`. After each batch of
+/// events, the player whose turn it is gets an `ActionRequired` prompt with the
+/// configured time bank.
+///
+/// Seats are named by `player_ids`, indexed by `PlayerId`; a seat without an entry is
+/// named by its number.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::application::{HandEngine, HandNotifier, HandSetup};
+/// use riverrun::core::domain::entities::deck::Deck;
+/// use riverrun::core::domain::primitives::{Blinds, Chips, HandNumber, PlayerId};
+/// use riverrun::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+/// use riverrun::core::ports::outbound::{FixedClock, NoOpPublisher};
+///
+/// let notifier = HandNotifier::new(
+///     NoOpPublisher,
+///     FixedClock::new(0),
+///     "game-1".into(),
+///     vec!["alice".to_string(), "bob".to_string()],
+/// );
+/// let mut engine =
+///     HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new())).with_notifier(notifier);
+/// let blinds = Blinds::new(Chips::new(5), Chips::new(10));
+/// let setup = HandSetup::new(HandNumber::new(1), vec![Chips::new(1000); 2], PlayerId::new(0), blinds);
+/// // Publishes the prompt for the small blind
+/// engine.start_hand(setup, Deck::new()).unwrap();
+/// ```
+pub struct HandNotifier {
+    publisher: Box<dyn NotificationPublisher>,
+    clock: Box<dyn Clock>,
+    game_id: outbound::GameId,
+    player_ids: Vec<outbound::PlayerId>,
+    time_bank: Duration,
+}
+
+/// `HandNotifier` - Constructors
+impl HandNotifier {
+    /// Creates a notifier publishing `game_id`'s hands to `publisher`.
+    #[must_use]
+    pub fn new(
+        publisher: impl NotificationPublisher + 'static,
+        clock: impl Clock + 'static,
+        game_id: outbound::GameId,
+        player_ids: Vec<outbound::PlayerId>,
+    ) -> Self {
+        Self {
+            publisher: Box::new(publisher),
+            clock: Box::new(clock),
+            game_id,
+            player_ids,
+            time_bank: DEFAULT_TIME_BANK,
+        }
+    }
+
+    /// Gives players `time_bank` to act.
+    #[must_use]
+    pub const fn with_time_bank(mut self, time_bank: Duration) -> Self {
+        self.time_bank = time_bank;
+        self
+    }
+}
+
+/// `HandNotifier` - Accessors
+impl HandNotifier {
+    /// The game notifications are published for.
+    #[must_use]
+    pub const fn game_id(&self) -> &outbound::GameId {
+        &self.game_id
+    }
+
+    /// Player ID of each seat, indexed by `PlayerId`.
+    #[must_use]
+    pub fn player_ids(&self) -> &[outbound::PlayerId] {
+        &self.player_ids
+    }
+
+    /// Time players are given to act.
+    #[must_use]
+    pub const fn time_bank(&self) -> Duration {
+        self.time_bank
+    }
+}
+
+/// `HandNotifier` - Operations
+impl HandNotifier {
+    /// Reseats the table, e.g. between hands.
+    pub fn set_player_ids(&mut self, player_ids: Vec<outbound::PlayerId>) {
+        self.player_ids = player_ids;
+    }
+
+    /// Sets the time players are given to act from the next prompt on.
+    pub const fn set_time_bank(&mut self, time_bank: Duration) {
+        self.time_bank = time_bank;
+    }
+
+    /// Publishes `events`, just applied to `aggregate`, then prompts the next player.
+    pub(crate) fn publish(
+        &self,
+        events: &[GameEvent],
+        aggregate: &GameAggregate,
+        outcome: Option<&HandOutcome>,
+    ) {
+        let timestamp = self.clock.now();
+        let pot = aggregate
+            .betting()
+            .map_or(0, |betting| betting.total_pot().value());
+
+        let mut notifications: Vec<GameNotification> = events
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::ActionTaken { player, action } => Some(GameNotification::ActionTaken {
+                    game_id: self.game_id.clone(),
+                    timestamp,
+                    player_id: self.player_id(*player),
+                    action: player_action(*action),
+                    pot_after: pot,
+                }),
+                GameEvent::StreetDealt { street, cards } => Some(GameNotification::StreetDealt {
+                    game_id: self.game_id.clone(),
+                    timestamp,
+                    street: *street,
+                    cards: cards.iter().map(ToString::to_string).collect(),
+                    pot,
+                }),
+                GameEvent::HandFinished { .. } => outcome
+                    .filter(|outcome| outcome.went_to_showdown())
+                    .map(|outcome| self.showdown(outcome, timestamp)),
+                _ => None,
+            })
+            .collect();
+
+        // The prompt only carries what the player to act may see
+        if let Some(view) = aggregate.to_act().and_then(|player| aggregate.player_view(player)) {
+            let legal = view.legal_actions;
+            notifications.push(GameNotification::ActionRequired {
+                game_id: self.game_id.clone(),
+                timestamp,
+                player_id: self.player_id(view.player),
+                to_call: legal.call.map_or(0, Chips::value),
+                min_raise: legal
+                    .raise
+                    .or(legal.bet)
+                    .map_or(0, |range| range.min.value()),
+                time_bank: u64::try_from(self.time_bank.as_millis()).unwrap_or(u64::MAX),
+            });
+        }
+        self.publisher.publish_batch(&notifications);
+    }
+}
+
+/// `HandNotifier` - Helpers
+impl HandNotifier {
+    /// Player ID of `seat`, or its number if it has none.
+    fn player_id(&self, seat: PlayerId) -> outbound::PlayerId {
+        self.player_ids
+            .get(seat.as_index())
+            .cloned()
+            .unwrap_or_else(|| seat.as_index().to_string())
+    }
+
+    /// Showdown notification for a hand decided by comparing hands.
+    fn showdown(&self, outcome: &HandOutcome, timestamp: outbound::Timestamp) -> GameNotification {
+        let mut winnings: Vec<Winnings> = Vec::new();
+        for award in outcome.payouts().awards() {
+            let player_id = self.player_id(award.player);
+            match winnings.iter_mut().find(|won| won.player_id == player_id) {
+                Some(won) => won.amount += award.amount.value(),
+                None => winnings.push(Winnings {
+                    player_id,
+                    amount: award.amount.value(),
+                }),
+            }
+        }
+        let winning_hand_rank = outcome.showdown().and_then(|showdown| {
+            let winner = *showdown.winner_indices().first()?;
+            Some(showdown.hands.get(winner)?.rank().to_string())
+        });
+
+        GameNotification::Showdown {
+            game_id: self.game_id.clone(),
+            timestamp,
+            winner_ids: winnings.iter().map(|won| won.player_id.clone()).collect(),
+            pot: outcome.payouts().total().value(),
+            winning_hand_rank,
+            winnings,
+        }
+    }
+}
+
+impl fmt::Debug for HandNotifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HandNotifier")
+            .field("game_id", &self.game_id)
+            .field("player_ids", &self.player_ids)
+            .field("time_bank", &self.time_bank)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The notification form of `action`.
+const fn player_action(action: Action) -> PlayerAction {
+    match action {
+        Action::Fold => PlayerAction::Fold,
+        Action::Check => PlayerAction::Check,
+        Action::Call(amount) => PlayerAction::Call(amount.value()),
+        Action::Bet(amount) => PlayerAction::Bet(amount.value()),
+        Action::Raise(amount) => PlayerAction::Raise(amount.value()),
+        Action::AllIn(amount) => PlayerAction::AllIn(amount.value()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::core::application::{HandEngine, HandSetup};
+    use crate::core::domain::entities::deck::Deck;
+    use crate::core::domain::primitives::{Blinds, HandNumber};
+    use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+    use crate::core::ports::outbound::FixedClock;
+
+    /// Publisher keeping every notification it is sent.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<GameNotification>>>);
+
+    impl NotificationPublisher for Recorder {
+        fn publish(&self, notification: GameNotification) {
+            self.0.lock().unwrap().push(notification);
+        }
+    }
+
+    impl Recorder {
+        fn take(&self) -> Vec<GameNotification> {
+            std::mem::take(&mut *self.0.lock().unwrap())
+        }
+    }
+
+    fn engine(recorder: &Recorder) -> HandEngine<ShowdownSolver<CactusKevEvaluator>> {
+        let notifier = HandNotifier::new(
+            recorder.clone(),
+            FixedClock::new(9),
+            "g1".into(),
+            vec!["alice".to_string(), "bob".to_string()],
+        )
+        .with_time_bank(Duration::from_secs(15));
+        let mut engine =
+            HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new())).with_notifier(notifier);
+        let setup = HandSetup::new(
+            HandNumber::new(1),
+            vec![Chips::new(1000); 2],
+            PlayerId::new(0),
+            Blinds::new(Chips::new(5), Chips::new(10)),
+        );
+        engine.start_hand(setup, Deck::new()).unwrap();
+        engine
+    }
+
+    fn prompt(player: &str, to_call: u64, min_raise: u64) -> GameNotification {
+        GameNotification::ActionRequired {
+            game_id: "g1".into(),
+            timestamp: 9,
+            player_id: player.to_string(),
+            to_call,
+            min_raise,
+            time_bank: 15_000,
+        }
+    }
+
+    #[test]
+    fn test_actions_and_prompts() {
+        let recorder = Recorder::default();
+        let mut engine = engine(&recorder);
+        assert_eq!(recorder.take(), [prompt("alice", 5, 20)]);
+
+        engine
+            .act(PlayerId::new(0), Action::Raise(Chips::new(30)))
+            .unwrap();
+        assert_eq!(
+            recorder.take(),
+            [
+                GameNotification::ActionTaken {
+                    game_id: "g1".into(),
+                    timestamp: 9,
+                    player_id: "alice".to_string(),
+                    action: PlayerAction::Raise(30),
+                    pot_after: 40,
+                },
+                prompt("bob", 20, 50),
+            ]
+        );
+
+        engine
+            .act(PlayerId::new(1), Action::Call(Chips::new(20)))
+            .unwrap();
+        let notifications = recorder.take();
+        assert_eq!(notifications.len(), 3);
+        assert!(matches!(
+            &notifications[1],
+            GameNotification::StreetDealt { street: outbound::Street::Flop, cards, pot: 60, .. }
+                if cards.len() == 3
+        ));
+        // Bob is out of position on the flop
+        assert_eq!(notifications[2], prompt("bob", 0, 10));
+    }
+
+    #[test]
+    fn test_showdown_notification() {
+        let recorder = Recorder::default();
+        let mut engine = engine(&recorder);
+        engine
+            .act(PlayerId::new(0), Action::Call(Chips::new(5)))
+            .unwrap();
+        engine.act(PlayerId::new(1), Action::Check).unwrap();
+        for _ in 0..3 {
+            engine.act(PlayerId::new(1), Action::Check).unwrap();
+            engine.act(PlayerId::new(0), Action::Check).unwrap();
+        }
+
+        let notifications = recorder.take();
+        let Some(GameNotification::Showdown {
+            winner_ids,
+            pot,
+            winning_hand_rank,
+            winnings,
+            ..
+        }) = notifications.last()
+        else {
+            panic!("expected a showdown, got {notifications:?}");
+        };
+        assert_eq!(*pot, 20);
+        assert!(winning_hand_rank.is_some());
+        assert_eq!(winnings.iter().map(|won| won.amount).sum::<u64>(), 20);
+        assert_eq!(winner_ids.len(), winnings.len());
+    }
+}
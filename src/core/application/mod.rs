@@ -0,0 +1,39 @@
+//! Application services.
+//!
+//! Application services orchestrate the domain model into complete use cases, such as
+//! playing a hand from the blinds to the payouts. They hold no poker rules of their own:
+//! rules live in the domain aggregates and services they coordinate.
+
+mod all_in_ev;
+mod equity_cache;
+mod equity_report;
+mod hand_engine;
+mod hand_notifier;
+mod player_stats;
+mod replayer;
+mod retry;
+mod service_registry;
+mod session_stats;
+mod simulation;
+mod solver_cache;
+mod tournament_clock;
+mod turn_timer;
+
+pub use all_in_ev::{AllInEquity, AllInEvAnalyzer, SeatEquity};
+pub use equity_cache::EquityCache;
+pub use equity_report::{EquityPoint, EquityReport};
+pub use hand_engine::{HandEngine, HandOutcome, HandSetup};
+pub use hand_notifier::{HandNotifier, DEFAULT_TIME_BANK};
+pub use player_stats::PlayerStatsProjector;
+pub use replayer::{GameReplayer, ReplayStep};
+pub use retry::{
+    retry_on_conflict, Backoff, ExponentialBackoff, NoBackoff, RetryError, RetryPolicy,
+};
+pub use service_registry::{
+    CalculatorKind, EvaluatorKind, ParseServiceConfigError, ServiceConfig, ServiceRegistry,
+};
+pub use session_stats::SessionStatsProjector;
+pub use simulation::{SeatResult, SimulationError, SimulationReport, SimulationRunner};
+pub use solver_cache::SolverCache;
+pub use tournament_clock::TournamentClock;
+pub use turn_timer::{TurnTimeout, TurnTimer};
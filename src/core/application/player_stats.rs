@@ -0,0 +1,346 @@
+//! Projection of completed hands into player statistics.
+
+use crate::core::domain::aggregates::{GameAggregate, GameEvent};
+use crate::core::domain::entities::game::Game;
+use crate::core::domain::primitives::{Action, BettingRound, BettingState, PlayerId, Street};
+use crate::core::ports::outbound::{self, Clock, PlayerStatsStore, ReadModelError};
+use crate::telemetry;
+
+use super::hand_engine::HandOutcome;
+
+/// What one seat did in one hand, as counted by the HUD statistics.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct SeatTally {
+    vpip: bool,
+    pfr: bool,
+    three_bet_opportunity: bool,
+    three_bet: bool,
+    cbet_opportunity: bool,
+    cbet: bool,
+    saw_flop: bool,
+    showdown: bool,
+    postflop_aggressive_actions: u64,
+    postflop_calls: u64,
+}
+
+/// Keeps a `PlayerStatsStore` up to date as hands complete.
+///
+/// Each hand is projected from its events: they are replayed through a
+/// `GameAggregate` so every action is judged against the betting round it was taken
+/// in. An action counts as a bet or raise when it lifts the amount to call, so a
+/// short all-in that only calls is counted as a call. A preflop three-bet is a
+/// re-raise of exactly one raise, and a continuation bet is a flop bet by the last
+/// preflop raiser before anyone else has bet the flop. Hands and showdowns won come
+/// from the hand's pot awards. Hands played and won are also added to the current
+/// `PeriodStats`, which time-windowed leaderboards are built from.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemoryPlayerStatsStore;
+/// use riverrun::core::application::{HandEngine, HandSetup, PlayerStatsProjector};
+/// use riverrun::core::domain::entities::deck::Deck;
+/// use riverrun::core::domain::primitives::{Action, Blinds, Chips, HandNumber, PlayerId};
+/// use riverrun::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+/// use riverrun::core::ports::outbound::{FixedClock, PlayerStatsStore};
+///
+/// let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+/// let blinds = Blinds::new(Chips::new(5), Chips::new(10));
+/// let setup = HandSetup::new(HandNumber::new(1), vec![Chips::new(1000); 2], PlayerId::new(0), blinds);
+/// engine.start_hand(setup, Deck::new()).unwrap();
+/// engine.act(PlayerId::new(0), Action::Raise(Chips::new(30))).unwrap();
+/// engine.act(PlayerId::new(1), Action::Fold).unwrap();
+///
+/// let projector = PlayerStatsProjector::new(InMemoryPlayerStatsStore::new(), FixedClock::new(0));
+/// let players = ["alice".to_string(), "bob".to_string()];
+/// projector
+///     .record_hand(&players, engine.history(), engine.outcome().unwrap())
+///     .unwrap();
+///
+/// let alice = projector.store().get(&players[0]).unwrap();
+/// assert_eq!((alice.vpip(), alice.pfr(), alice.hands_won), (100.0, 100.0, 1));
+/// ```
+#[derive(Debug)]
+pub struct PlayerStatsProjector<S: PlayerStatsStore, C: Clock> {
+    store: S,
+    clock: C,
+}
+
+/// `PlayerStatsProjector` - Constructors
+impl<S: PlayerStatsStore, C: Clock> PlayerStatsProjector<S, C> {
+    /// Creates a projector writing to `store`, stamping hands with `clock`.
+    #[must_use]
+    pub const fn new(store: S, clock: C) -> Self {
+        Self { store, clock }
+    }
+}
+
+/// `PlayerStatsProjector` - Accessors
+impl<S: PlayerStatsStore, C: Clock> PlayerStatsProjector<S, C> {
+    /// The store being projected into.
+    #[must_use]
+    pub const fn store(&self) -> &S {
+        &self.store
+    }
+}
+
+/// `PlayerStatsProjector` - Operations
+impl<S: PlayerStatsStore, C: Clock> PlayerStatsProjector<S, C> {
+    /// Adds a completed hand to the all-time and current-period stats of every seated
+    /// player.
+    ///
+    /// `players` is indexed by `PlayerId`; `events` is the hand's full history, from
+    /// `GameEvent::HandStarted` on.
+    ///
+    /// # Errors
+    /// Returns the store's error if stats cannot be loaded or saved. Seats before the
+    /// failing one have already been recorded.
+    pub fn record_hand(
+        &self,
+        players: &[outbound::PlayerId],
+        events: &[GameEvent],
+        outcome: &HandOutcome,
+    ) -> Result<(), ReadModelError> {
+        let now = self.clock.now();
+        let tallies = tally_hand(events);
+        for (seat, (player_id, tally)) in players.iter().zip(tallies).enumerate() {
+            let won = !outcome.payouts().amount_for(PlayerId::from(seat)).is_zero();
+
+            let mut stats = self.store.get(player_id)?;
+            stats.hands_played += 1;
+            stats.hands_won += u64::from(won);
+            stats.last_played_at = Some(now);
+            stats.vpip_hands += u64::from(tally.vpip);
+            stats.pfr_hands += u64::from(tally.pfr);
+            stats.three_bet_opportunities += u64::from(tally.three_bet_opportunity);
+            stats.three_bets += u64::from(tally.three_bet);
+            stats.cbet_opportunities += u64::from(tally.cbet_opportunity);
+            stats.cbets += u64::from(tally.cbet);
+            stats.flops_seen += u64::from(tally.saw_flop);
+            stats.showdowns += u64::from(tally.showdown);
+            stats.showdowns_won += u64::from(tally.showdown && won);
+            stats.postflop_aggressive_actions += tally.postflop_aggressive_actions;
+            stats.postflop_calls += tally.postflop_calls;
+            self.store.save(&stats)?;
+
+            let mut period = self.store.get_period(player_id, now)?;
+            period.hands_played += 1;
+            period.hands_won += u64::from(won);
+            self.store.save_period(&period)?;
+        }
+        telemetry::projection_update("player_stats");
+        Ok(())
+    }
+}
+
+/// Replays `events` and tallies what every seat did.
+fn tally_hand(events: &[GameEvent]) -> Vec<SeatTally> {
+    let mut game = GameAggregate::new();
+    let mut tallies = Vec::new();
+    let mut preflop_aggressor = None;
+    let mut flop_bet = false;
+
+    for event in events {
+        let street = game.game().map(Game::street);
+        let raises_before = raise_count(&game);
+        game.apply(event);
+
+        match event {
+            GameEvent::HandStarted { stacks, .. } => {
+                tallies = vec![SeatTally::default(); stacks.len()];
+                preflop_aggressor = None;
+                flop_bet = false;
+            }
+            GameEvent::ActionTaken { player, action } => {
+                let Some(tally) = tallies.get_mut(player.as_index()) else {
+                    continue;
+                };
+                let aggressive = raise_count(&game) > raises_before;
+                match street {
+                    Some(Street::Preflop) => {
+                        tally.vpip |= action.puts_chips_in();
+                        tally.pfr |= aggressive;
+                        if raises_before == 1 {
+                            tally.three_bet_opportunity = true;
+                            tally.three_bet |= aggressive;
+                        }
+                        if aggressive {
+                            preflop_aggressor = Some(*player);
+                        }
+                    }
+                    Some(street) => {
+                        if street == Street::Flop && !flop_bet && preflop_aggressor == Some(*player)
+                        {
+                            tally.cbet_opportunity = true;
+                            tally.cbet |= aggressive;
+                        }
+                        if aggressive {
+                            flop_bet |= street == Street::Flop;
+                            tally.postflop_aggressive_actions += 1;
+                        } else if matches!(action, Action::Call(_) | Action::AllIn(_)) {
+                            tally.postflop_calls += 1;
+                        }
+                    }
+                    None => {}
+                }
+            }
+            GameEvent::StreetDealt {
+                street: Street::Flop,
+                ..
+            } => {
+                let betting = game.betting();
+                for (seat, tally) in tallies.iter_mut().enumerate() {
+                    tally.saw_flop = betting.is_some_and(|b| !b.has_folded(PlayerId::from(seat)));
+                }
+            }
+            GameEvent::HandFinished {
+                players_in_hand, ..
+            } if players_in_hand.len() > 1 => {
+                for player in players_in_hand {
+                    if let Some(tally) = tallies.get_mut(player.as_index()) {
+                        tally.showdown = true;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    tallies
+}
+
+/// Raises made so far in the betting round in progress.
+fn raise_count(game: &GameAggregate) -> u8 {
+    game.betting()
+        .and_then(BettingState::current_round)
+        .map_or(0, BettingRound::raise_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryPlayerStatsStore;
+    use crate::core::application::{HandEngine, HandSetup};
+    use crate::core::domain::entities::deck::Deck;
+    use crate::core::domain::primitives::{Blinds, Chips, HandNumber};
+    use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+    use crate::core::ports::outbound::{FixedClock, LeaderboardWindow, PlayerStats};
+
+    fn p(seat: u8) -> PlayerId {
+        PlayerId::new(seat)
+    }
+
+    /// Three-handed hand: seat 0 opens, the small blind three-bets, and both see a
+    /// showdown after betting the flop and turn.
+    fn played_hand() -> HandEngine<ShowdownSolver<CactusKevEvaluator>> {
+        let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+        let setup = HandSetup::new(
+            HandNumber::new(1),
+            vec![Chips::new(1000); 3],
+            p(0),
+            Blinds::new(Chips::new(5), Chips::new(10)),
+        );
+        engine.start_hand(setup, Deck::new()).unwrap();
+        for (seat, action) in [
+            (0, Action::Raise(Chips::new(30))),
+            (1, Action::Raise(Chips::new(90))),
+            (2, Action::Fold),
+            (0, Action::Call(Chips::new(60))),
+            // Flop
+            (1, Action::Bet(Chips::new(20))),
+            (0, Action::Call(Chips::new(20))),
+            // Turn
+            (1, Action::Check),
+            (0, Action::Bet(Chips::new(50))),
+            (1, Action::Call(Chips::new(50))),
+            // River
+            (1, Action::Check),
+            (0, Action::Check),
+        ] {
+            engine.act(p(seat), action).unwrap();
+        }
+        engine
+    }
+
+    fn record(engine: &HandEngine<ShowdownSolver<CactusKevEvaluator>>) -> Vec<PlayerStats> {
+        let projector =
+            PlayerStatsProjector::new(InMemoryPlayerStatsStore::new(), FixedClock::new(42));
+        let players: Vec<String> = ["alice", "bob", "carol"].map(String::from).to_vec();
+        projector
+            .record_hand(&players, engine.history(), engine.outcome().unwrap())
+            .unwrap();
+        players
+            .iter()
+            .map(|player| projector.store().get(player).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_preflop_stats() {
+        let stats = record(&played_hand());
+        let [opener, three_bettor, folder] = [&stats[0], &stats[1], &stats[2]];
+
+        assert_eq!((opener.vpip_hands, opener.pfr_hands), (1, 1));
+        assert_eq!(opener.three_bet_opportunities, 0);
+        assert_eq!(
+            (
+                three_bettor.three_bet_opportunities,
+                three_bettor.three_bets
+            ),
+            (1, 1)
+        );
+        // Facing two raises is not a three-bet opportunity
+        assert_eq!(
+            (
+                folder.vpip_hands,
+                folder.three_bet_opportunities,
+                folder.flops_seen
+            ),
+            (0, 0, 0)
+        );
+        assert_eq!(folder.last_played_at, Some(42));
+    }
+
+    #[test]
+    fn test_postflop_stats() {
+        let stats = record(&played_hand());
+        let [opener, three_bettor] = [&stats[0], &stats[1]];
+
+        assert_eq!(
+            (three_bettor.cbet_opportunities, three_bettor.cbets),
+            (1, 1)
+        );
+        assert_eq!(opener.cbet_opportunities, 0);
+        for player in [opener, three_bettor] {
+            assert_eq!((player.flops_seen, player.showdowns), (1, 1));
+            assert!((player.aggression_factor() - 1.0).abs() < f64::EPSILON);
+        }
+        let winners = stats
+            .iter()
+            .filter(|player| player.showdowns_won == 1)
+            .count();
+        assert!((1..=2).contains(&winners));
+        assert_eq!(stats[2].showdowns, 0);
+    }
+
+    #[test]
+    fn test_hands_recorded_in_current_period() {
+        let engine = played_hand();
+        let projector =
+            PlayerStatsProjector::new(InMemoryPlayerStatsStore::new(), FixedClock::new(42));
+        let players: Vec<String> = ["alice", "bob", "carol"].map(String::from).to_vec();
+        for _ in 0..2 {
+            projector
+                .record_hand(&players, engine.history(), engine.outcome().unwrap())
+                .unwrap();
+        }
+
+        let weekly = projector
+            .store()
+            .leaderboard(LeaderboardWindow::Weekly, 42, 10)
+            .unwrap();
+        assert_eq!(weekly.len(), 3);
+        assert!(weekly.iter().all(|stats| stats.hands_played == 2));
+        assert_eq!(weekly[2].player_id, "carol");
+    }
+}
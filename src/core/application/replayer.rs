@@ -0,0 +1,324 @@
+//! Step-by-step replay of a stored game.
+
+use crate::core::domain::aggregates::{GameAggregate, GameEvent};
+use crate::core::domain::entities::game::Game;
+use crate::core::domain::primitives::BettingState;
+use crate::core::ports::outbound::{EventStore, EventStoreError, GameId, StoredEvent};
+
+/// The state of a replay right after one event was applied.
+#[derive(Clone, Copy, Debug)]
+pub struct ReplayStep<'a> {
+    /// Number of events applied so far, counting this one.
+    pub position: usize,
+    /// The event just applied.
+    pub event: &'a StoredEvent<GameEvent>,
+    /// The aggregate with every event up to and including `event` applied.
+    pub aggregate: &'a GameAggregate,
+}
+
+/// `ReplayStep` - Accessors
+impl ReplayStep<'_> {
+    /// Cards dealt so far in the hand being replayed.
+    #[must_use]
+    pub fn game(&self) -> Option<&Game> {
+        self.aggregate.game()
+    }
+
+    /// Stacks, pots, and betting round of the hand being replayed.
+    #[must_use]
+    pub fn betting(&self) -> Option<&BettingState> {
+        self.aggregate.betting()
+    }
+}
+
+/// Rebuilds a game from its stored events one event at a time.
+///
+/// Every step applies the next event to a `GameAggregate`, exactly as loading the game
+/// would, and hands back a `ReplayStep` with the event and the state it produced. Pass
+/// a hook to `run` to assert on or render every intermediate state, or drive the
+/// replay with `step` and `seek` to move through a hand interactively. Events are
+/// applied in the order given, so a replay of the same stream always passes through
+/// the same states.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::application::GameReplayer;
+/// use riverrun::core::domain::aggregates::{GameAggregate, GameCommand};
+/// use riverrun::core::domain::entities::deck::Deck;
+/// use riverrun::core::domain::primitives::{Action, Chips, HandNumber, PlayerId};
+/// use riverrun::core::ports::outbound::StoredEvent;
+///
+/// let mut game = GameAggregate::new();
+/// let mut events = game
+///     .execute(&GameCommand::StartHand {
+///         hand_number: HandNumber::new(1),
+///         stacks: vec![Chips::new(1000); 2],
+///         button: PlayerId::new(0),
+///         small_blind: Chips::new(5),
+///         big_blind: Chips::new(10),
+///         ante: Chips::ZERO,
+///         deck: Deck::new(),
+///     })
+///     .unwrap();
+/// events.extend(
+///     game.execute(&GameCommand::Act { player: PlayerId::new(0), action: Action::Fold })
+///         .unwrap(),
+/// );
+/// let stored = events
+///     .into_iter()
+///     .zip(1..)
+///     .map(|(event, version)| StoredEvent::new(event, version, 0, "game-1".into()));
+///
+/// let mut pots = Vec::new();
+/// GameReplayer::new(stored).run(|step| pots.push(step.betting().unwrap().total_pot()));
+/// assert_eq!(pots.last(), Some(&Chips::new(15)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GameReplayer {
+    events: Vec<StoredEvent<GameEvent>>,
+    aggregate: GameAggregate,
+    position: usize,
+}
+
+/// `GameReplayer` - Constructors
+impl GameReplayer {
+    /// Creates a replay of `events`, positioned before the first one.
+    #[must_use]
+    pub fn new(events: impl IntoIterator<Item = StoredEvent<GameEvent>>) -> Self {
+        Self {
+            events: events.into_iter().collect(),
+            aggregate: GameAggregate::new(),
+            position: 0,
+        }
+    }
+
+    /// Loads every event of `game_id` from `store` and creates a replay of them.
+    ///
+    /// # Errors
+    /// Returns the store's error if the events cannot be loaded, including
+    /// `EventStoreError::NotFound` for an unknown game.
+    pub fn from_store(
+        store: &impl EventStore<GameEvent>,
+        game_id: &GameId,
+    ) -> Result<Self, EventStoreError> {
+        Ok(Self::new(store.load(game_id)?))
+    }
+}
+
+/// `GameReplayer` - Accessors
+impl GameReplayer {
+    /// Every event of the replay, applied or not.
+    #[must_use]
+    pub fn events(&self) -> &[StoredEvent<GameEvent>] {
+        &self.events
+    }
+
+    /// Number of events applied so far.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns whether every event has been applied.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.position == self.events.len()
+    }
+
+    /// The aggregate with the first `position` events applied.
+    #[must_use]
+    pub const fn aggregate(&self) -> &GameAggregate {
+        &self.aggregate
+    }
+
+    /// The most recently applied event, if any.
+    #[must_use]
+    pub fn current_event(&self) -> Option<&StoredEvent<GameEvent>> {
+        self.position
+            .checked_sub(1)
+            .map(|index| &self.events[index])
+    }
+
+    /// The event the next `step` will apply, if any.
+    #[must_use]
+    pub fn next_event(&self) -> Option<&StoredEvent<GameEvent>> {
+        self.events.get(self.position)
+    }
+}
+
+/// `GameReplayer` - Operations
+impl GameReplayer {
+    /// Applies the next event, returning the resulting step, or `None` once every
+    /// event has been applied.
+    pub fn step(&mut self) -> Option<ReplayStep<'_>> {
+        let event = self.events.get(self.position)?;
+        self.aggregate.apply(&event.event);
+        self.position += 1;
+        Some(ReplayStep {
+            position: self.position,
+            event,
+            aggregate: &self.aggregate,
+        })
+    }
+
+    /// Applies every remaining event, calling `hook` after each one.
+    pub fn run(&mut self, mut hook: impl FnMut(&ReplayStep<'_>)) {
+        while let Some(step) = self.step() {
+            hook(&step);
+        }
+    }
+
+    /// Applies events until `stop` returns `true` for the step just taken.
+    ///
+    /// # Returns
+    /// Whether `stop` matched; `false` means every event was applied without a match.
+    pub fn run_until(&mut self, mut stop: impl FnMut(&ReplayStep<'_>) -> bool) -> bool {
+        while let Some(step) = self.step() {
+            if stop(&step) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Moves the replay so that exactly `position` events are applied.
+    ///
+    /// Moving backwards rebuilds the aggregate from the first event, since applied
+    /// events cannot be undone. Positions past the end are clamped to the end.
+    pub fn seek(&mut self, position: usize) {
+        let position = position.min(self.events.len());
+        if position < self.position {
+            self.rewind();
+        }
+        while self.position < position {
+            self.step();
+        }
+    }
+
+    /// Moves the replay back before the first event.
+    pub fn rewind(&mut self) {
+        self.aggregate = GameAggregate::new();
+        self.position = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryEventStore;
+    use crate::core::domain::aggregates::GameCommand;
+    use crate::core::domain::entities::deck::Deck;
+    use crate::core::domain::primitives::{Action, Chips, HandNumber, PlayerId, Street};
+
+    fn p(seat: u8) -> PlayerId {
+        PlayerId::new(seat)
+    }
+
+    fn act(game: &mut GameAggregate, seat: u8, action: Action) -> Vec<GameEvent> {
+        game.execute(&GameCommand::Act {
+            player: p(seat),
+            action,
+        })
+        .unwrap()
+    }
+
+    /// Heads-up hand that limps preflop and sees the flop.
+    fn history() -> (GameAggregate, Vec<GameEvent>) {
+        let mut game = GameAggregate::new();
+        let mut events = game
+            .execute(&GameCommand::StartHand {
+                hand_number: HandNumber::new(1),
+                stacks: vec![Chips::new(1000); 2],
+                button: p(0),
+                small_blind: Chips::new(5),
+                big_blind: Chips::new(10),
+                ante: Chips::ZERO,
+                deck: Deck::new(),
+            })
+            .unwrap();
+        events.extend(act(&mut game, 0, Action::Call(Chips::new(5))));
+        events.extend(act(&mut game, 1, Action::Check));
+        (game, events)
+    }
+
+    fn replayer(events: Vec<GameEvent>) -> GameReplayer {
+        GameReplayer::new(
+            events
+                .into_iter()
+                .zip(1..)
+                .map(|(event, version)| StoredEvent::new(event, version, 0, "g1".into())),
+        )
+    }
+
+    #[test]
+    fn test_run_visits_every_state() {
+        let (game, events) = history();
+        let mut replayer = replayer(events.clone());
+
+        let mut seen = Vec::new();
+        replayer.run(|step| {
+            assert_eq!(step.aggregate.version(), step.position as u64);
+            seen.push((
+                step.event.event.clone(),
+                step.betting().unwrap().total_pot(),
+            ));
+        });
+
+        let seen_events: Vec<GameEvent> = seen.iter().map(|(event, _)| event.clone()).collect();
+        assert_eq!(seen_events, events);
+        assert_eq!(seen[1].1, Chips::new(5));
+        assert_eq!(seen[2].1, Chips::new(15));
+        assert!(replayer.is_finished());
+        assert_eq!(replayer.aggregate().to_act(), game.to_act());
+        assert_eq!(
+            replayer.aggregate().game().unwrap().board(),
+            game.game().unwrap().board()
+        );
+    }
+
+    #[test]
+    fn test_step_and_seek() {
+        let (_, events) = history();
+        let mut replayer = replayer(events);
+        assert!(replayer.current_event().is_none());
+        assert_eq!(replayer.next_event().unwrap().version, 1);
+
+        let found =
+            replayer.run_until(|step| matches!(step.event.event, GameEvent::StreetDealt { .. }));
+        assert!(found);
+        assert_eq!(replayer.aggregate().game().unwrap().street(), Street::Flop);
+        let flop_position = replayer.position();
+
+        replayer.seek(2);
+        assert_eq!(replayer.position(), 2);
+        assert_eq!(replayer.aggregate().version(), 2);
+        assert_eq!(replayer.current_event().unwrap().version, 2);
+        assert!(replayer.aggregate().game().unwrap().board().is_empty());
+
+        replayer.seek(usize::MAX);
+        assert_eq!(replayer.position(), flop_position);
+        assert!(replayer.step().is_none());
+
+        replayer.rewind();
+        assert_eq!(replayer.position(), 0);
+        assert_eq!(replayer.aggregate().hand_number(), None);
+    }
+
+    #[test]
+    fn test_from_store() {
+        let (_, events) = history();
+        let store = InMemoryEventStore::new();
+        let game_id = GameId::from("g1");
+        store.append(&game_id, events.clone(), 0).unwrap();
+
+        let mut replayer = GameReplayer::from_store(&store, &game_id).unwrap();
+        assert_eq!(replayer.events().len(), events.len());
+        replayer.seek(events.len());
+        assert!(replayer.is_finished());
+        assert!(matches!(
+            GameReplayer::from_store(&store, &GameId::from("missing")),
+            Err(EventStoreError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,276 @@
+//! Optimistic-concurrency retries for appending events.
+
+use std::error::Error;
+use std::fmt;
+use std::thread;
+use std::time::Duration;
+
+use crate::core::ports::outbound::{EventStore, EventStoreError, GameId, StoredEvent, Version};
+
+/// Delay to wait before retrying after a concurrency conflict.
+pub trait Backoff {
+    /// Delay before retry number `retry`, counting from 1.
+    fn delay(&self, retry: u32) -> Duration;
+}
+
+impl<F: Fn(u32) -> Duration> Backoff for F {
+    fn delay(&self, retry: u32) -> Duration {
+        self(retry)
+    }
+}
+
+/// Retry immediately.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoBackoff;
+
+impl Backoff for NoBackoff {
+    fn delay(&self, _retry: u32) -> Duration {
+        Duration::ZERO
+    }
+}
+
+/// Doubles the delay after every conflict, starting at `initial` and capped at `max`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExponentialBackoff {
+    /// Delay before the first retry.
+    pub initial: Duration,
+    /// Longest delay between retries.
+    pub max: Duration,
+}
+
+impl Backoff for ExponentialBackoff {
+    fn delay(&self, retry: u32) -> Duration {
+        let factor = 1u32.checked_shl(retry.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+}
+
+/// How many times to attempt an append, and how long to wait between attempts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy<B: Backoff = NoBackoff> {
+    max_attempts: u32,
+    backoff: B,
+}
+
+/// `RetryPolicy` - Constructors
+impl RetryPolicy {
+    /// Attempts the append up to `max_attempts` times without waiting in between.
+    ///
+    /// # Panics
+    /// Panics if `max_attempts` is zero.
+    #[must_use]
+    pub const fn new(max_attempts: u32) -> Self {
+        Self::with_backoff(max_attempts, NoBackoff)
+    }
+}
+
+/// `RetryPolicy` - Constructors
+impl<B: Backoff> RetryPolicy<B> {
+    /// Attempts the append up to `max_attempts` times, waiting `backoff` between them.
+    ///
+    /// # Panics
+    /// Panics if `max_attempts` is zero.
+    #[must_use]
+    pub const fn with_backoff(max_attempts: u32, backoff: B) -> Self {
+        assert!(max_attempts > 0, "max_attempts must be at least 1");
+        Self {
+            max_attempts,
+            backoff,
+        }
+    }
+}
+
+/// `RetryPolicy` - Accessors
+impl<B: Backoff> RetryPolicy<B> {
+    /// Maximum number of attempts, including the first.
+    #[must_use]
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+}
+
+/// Error returned by `retry_on_conflict`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetryError<E> {
+    /// Every attempt lost the race to another writer.
+    ConflictsExhausted {
+        /// Attempts made.
+        attempts: u32,
+    },
+    /// The command was rejected against the current state.
+    Rejected(E),
+    /// Loading or appending failed for a reason other than a conflict.
+    Store(EventStoreError),
+}
+
+impl<E: fmt::Display> fmt::Display for RetryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConflictsExhausted { attempts } => {
+                write!(f, "append still conflicted after {attempts} attempts")
+            }
+            Self::Rejected(err) => write!(f, "command rejected: {err}"),
+            Self::Store(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for RetryError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ConflictsExhausted { .. } => None,
+            Self::Rejected(err) => Some(err),
+            Self::Store(err) => Some(err),
+        }
+    }
+}
+
+/// Loads `game_id`, decides the events to append, and appends them, starting over
+/// when another writer appends first.
+///
+/// `decide` receives every stored event (none for a new aggregate) and returns the
+/// events to append, typically by rebuilding the aggregate and handling a command.
+/// It is called again with the fresh stream after each
+/// `EventStoreError::ConcurrencyConflict`, so it must not have side effects.
+///
+/// # Returns
+/// The stream version after the successful append.
+///
+/// # Errors
+/// Returns `RetryError::Rejected` if `decide` fails, `RetryError::Store` if the store
+/// fails for any other reason, and `RetryError::ConflictsExhausted` if every attempt
+/// allowed by `policy` conflicted.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemoryEventStore;
+/// use riverrun::core::application::{retry_on_conflict, RetryError, RetryPolicy};
+/// use riverrun::core::ports::outbound::{EventStore, GameId};
+///
+/// let store = InMemoryEventStore::new();
+/// let game = GameId::from("game-1");
+///
+/// let version = retry_on_conflict(&store, &game, &RetryPolicy::new(3), |events| {
+///     if events.is_empty() {
+///         Ok(vec!["started"])
+///     } else {
+///         Err("already started")
+///     }
+/// });
+/// assert_eq!(version, Ok(1));
+///
+/// let again = retry_on_conflict(&store, &game, &RetryPolicy::new(3), |_| Err("already started"));
+/// assert_eq!(again, Err(RetryError::Rejected("already started")));
+/// ```
+pub fn retry_on_conflict<E, S, B, Err>(
+    store: &S,
+    game_id: &GameId,
+    policy: &RetryPolicy<B>,
+    mut decide: impl FnMut(&[StoredEvent<E>]) -> Result<Vec<E>, Err>,
+) -> Result<Version, RetryError<Err>>
+where
+    S: EventStore<E> + ?Sized,
+    B: Backoff,
+{
+    for attempt in 1..=policy.max_attempts {
+        if attempt > 1 {
+            let delay = policy.backoff.delay(attempt - 1);
+            if !delay.is_zero() {
+                thread::sleep(delay);
+            }
+        }
+
+        let stored = match store.load(game_id) {
+            Ok(stored) => stored,
+            Err(EventStoreError::NotFound(_)) => Vec::new(),
+            Err(err) => return Err(RetryError::Store(err)),
+        };
+        let expected_version = stored.last().map_or(0, |event| event.version);
+        let events = decide(&stored).map_err(RetryError::Rejected)?;
+
+        match store.append(game_id, events, expected_version) {
+            Ok(version) => return Ok(version),
+            Err(EventStoreError::ConcurrencyConflict { .. }) => {}
+            Err(err) => return Err(RetryError::Store(err)),
+        }
+    }
+
+    Err(RetryError::ConflictsExhausted {
+        attempts: policy.max_attempts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::adapters::InMemoryEventStore;
+
+    /// Appends a competing event before each of the first `conflicts` attempts.
+    fn decide_with_rival<'a>(
+        store: &'a InMemoryEventStore<&'static str>,
+        game: &'a GameId,
+        conflicts: u32,
+        calls: &'a Cell<u32>,
+    ) -> impl FnMut(&[StoredEvent<&'static str>]) -> Result<Vec<&'static str>, &'static str> + 'a {
+        move |stored| {
+            calls.set(calls.get() + 1);
+            if calls.get() <= conflicts {
+                store.append(game, vec!["rival"], stored.len() as u64).unwrap();
+            }
+            Ok(vec!["mine"])
+        }
+    }
+
+    #[test]
+    fn test_retries_until_append_succeeds() {
+        let store = InMemoryEventStore::new();
+        let game = GameId::from("game-1");
+        let calls = Cell::new(0);
+
+        let version = retry_on_conflict(
+            &store,
+            &game,
+            &RetryPolicy::new(3),
+            decide_with_rival(&store, &game, 2, &calls),
+        );
+        assert_eq!(version, Ok(3));
+        assert_eq!(calls.get(), 3);
+        let events: Vec<_> = store.load(&game).unwrap().into_iter().map(|e| e.event).collect();
+        assert_eq!(events, ["rival", "rival", "mine"]);
+    }
+
+    #[test]
+    fn test_gives_up_after_max_attempts() {
+        let store = InMemoryEventStore::new();
+        let game = GameId::from("game-1");
+        let calls = Cell::new(0);
+        let delays = std::cell::RefCell::new(Vec::new());
+        let backoff = |retry: u32| {
+            delays.borrow_mut().push(retry);
+            Duration::ZERO
+        };
+
+        let result = retry_on_conflict(
+            &store,
+            &game,
+            &RetryPolicy::with_backoff(2, backoff),
+            decide_with_rival(&store, &game, 5, &calls),
+        );
+        assert_eq!(result, Err(RetryError::ConflictsExhausted { attempts: 2 }));
+        assert_eq!(*delays.borrow(), [1]);
+    }
+
+    #[test]
+    fn test_exponential_backoff() {
+        let backoff = ExponentialBackoff {
+            initial: Duration::from_millis(10),
+            max: Duration::from_millis(50),
+        };
+        let delays: Vec<_> = (1..=5).map(|retry| backoff.delay(retry).as_millis()).collect();
+        assert_eq!(delays, [10, 20, 40, 50, 50]);
+        assert_eq!(backoff.delay(100), Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,378 @@
+//! Choosing port implementations at runtime, from configuration.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::core::domain::services::equity::DEFAULT_SAMPLES;
+use crate::core::domain::services::{
+    CactusKevEvaluator, ExhaustiveEquityCalculator, MonteCarloEquityCalculator,
+    PerfectHashEvaluator, ReferenceEvaluator, ShowdownSolver, SmartEquityCalculator,
+};
+use crate::core::ports::inbound::{EquityCalculator, HandEvaluator, HandSolver};
+
+/// Error returned when a configuration setting names no known implementation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseServiceConfigError {
+    /// The setting that could not be parsed.
+    pub setting: String,
+}
+
+impl fmt::Display for ParseServiceConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid service setting '{}'", self.setting)
+    }
+}
+
+impl std::error::Error for ParseServiceConfigError {}
+
+impl ParseServiceConfigError {
+    fn new(setting: &str) -> Self {
+        Self {
+            setting: setting.to_string(),
+        }
+    }
+}
+
+/// Hand evaluator implementations that can be chosen by name.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EvaluatorKind {
+    /// `CactusKevEvaluator`, named `cactus-kev`.
+    #[default]
+    CactusKev,
+    /// `PerfectHashEvaluator`, named `perfect-hash`.
+    PerfectHash,
+    /// `ReferenceEvaluator`, named `reference`.
+    Reference,
+}
+
+/// `EvaluatorKind` - Operations
+impl EvaluatorKind {
+    /// A new evaluator of this kind.
+    #[must_use]
+    pub fn build(self) -> Arc<dyn HandEvaluator> {
+        match self {
+            Self::CactusKev => Arc::new(CactusKevEvaluator::new()),
+            Self::PerfectHash => Arc::new(PerfectHashEvaluator::new()),
+            Self::Reference => Arc::new(ReferenceEvaluator::new()),
+        }
+    }
+}
+
+impl fmt::Display for EvaluatorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::CactusKev => "cactus-kev",
+            Self::PerfectHash => "perfect-hash",
+            Self::Reference => "reference",
+        })
+    }
+}
+
+impl FromStr for EvaluatorKind {
+    type Err = ParseServiceConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "cactus-kev" => Ok(Self::CactusKev),
+            "perfect-hash" => Ok(Self::PerfectHash),
+            "reference" => Ok(Self::Reference),
+            other => Err(ParseServiceConfigError::new(other)),
+        }
+    }
+}
+
+/// Equity calculator implementations that can be chosen by name.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CalculatorKind {
+    /// `SmartEquityCalculator`, named `smart`.
+    #[default]
+    Smart,
+    /// `ExhaustiveEquityCalculator`, named `exhaustive`.
+    Exhaustive,
+    /// `MonteCarloEquityCalculator` drawing `samples` samples, named `monte-carlo` or
+    /// `monte-carlo:<samples>`.
+    MonteCarlo {
+        /// Samples drawn when no sample count is given.
+        samples: u32,
+    },
+}
+
+/// `CalculatorKind` - Operations
+impl CalculatorKind {
+    /// A new calculator of this kind, evaluating hands with `evaluator`.
+    #[must_use]
+    pub fn build(self, evaluator: Arc<dyn HandEvaluator>) -> Arc<dyn EquityCalculator> {
+        match self {
+            Self::Smart => Arc::new(SmartEquityCalculator::new(evaluator)),
+            Self::Exhaustive => Arc::new(ExhaustiveEquityCalculator::new(evaluator)),
+            Self::MonteCarlo { samples } => {
+                Arc::new(MonteCarloEquityCalculator::with_samples(evaluator, samples))
+            }
+        }
+    }
+}
+
+impl fmt::Display for CalculatorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Smart => f.write_str("smart"),
+            Self::Exhaustive => f.write_str("exhaustive"),
+            Self::MonteCarlo { samples } => write!(f, "monte-carlo:{samples}"),
+        }
+    }
+}
+
+impl FromStr for CalculatorKind {
+    type Err = ParseServiceConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.split_once(':') {
+            None => match s {
+                "smart" => Ok(Self::Smart),
+                "exhaustive" => Ok(Self::Exhaustive),
+                "monte-carlo" => Ok(Self::MonteCarlo {
+                    samples: DEFAULT_SAMPLES,
+                }),
+                _ => Err(ParseServiceConfigError::new(s)),
+            },
+            Some(("monte-carlo", samples)) => samples
+                .parse()
+                .ok()
+                .filter(|&samples| samples > 0)
+                .map(|samples| Self::MonteCarlo { samples })
+                .ok_or_else(|| ParseServiceConfigError::new(s)),
+            Some(_) => Err(ParseServiceConfigError::new(s)),
+        }
+    }
+}
+
+/// Which implementation to use for each port.
+///
+/// Parses from comma-separated `key=value` settings, e.g.
+/// `evaluator=perfect-hash, equity=monte-carlo:50000`. Settings left out keep their
+/// defaults: `cactus-kev` and `smart`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ServiceConfig {
+    evaluator: EvaluatorKind,
+    equity: CalculatorKind,
+}
+
+/// `ServiceConfig` - Constructors
+impl ServiceConfig {
+    /// This configuration with `evaluator` for hand evaluation.
+    #[must_use]
+    pub const fn with_evaluator(mut self, evaluator: EvaluatorKind) -> Self {
+        self.evaluator = evaluator;
+        self
+    }
+
+    /// This configuration with `equity` for equity calculation.
+    #[must_use]
+    pub const fn with_equity(mut self, equity: CalculatorKind) -> Self {
+        self.equity = equity;
+        self
+    }
+}
+
+/// `ServiceConfig` - Accessors
+impl ServiceConfig {
+    /// The hand evaluator implementation.
+    #[must_use]
+    pub const fn evaluator(&self) -> EvaluatorKind {
+        self.evaluator
+    }
+
+    /// The equity calculator implementation.
+    #[must_use]
+    pub const fn equity(&self) -> CalculatorKind {
+        self.equity
+    }
+}
+
+impl fmt::Display for ServiceConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "evaluator={}, equity={}", self.evaluator, self.equity)
+    }
+}
+
+impl FromStr for ServiceConfig {
+    type Err = ParseServiceConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut config = Self::default();
+        for setting in s.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            match setting.split_once('=') {
+                Some((key, value)) if key.trim() == "evaluator" => {
+                    config.evaluator = value.parse()?;
+                }
+                Some((key, value)) if key.trim() == "equity" => {
+                    config.equity = value.parse()?;
+                }
+                _ => return Err(ParseServiceConfigError::new(setting)),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Hand evaluator, equity calculator, and showdown solver chosen at runtime, shared
+/// behind `Arc<dyn _>` so callers need not name the concrete types.
+///
+/// Every service built by `from_config` shares one evaluator, so table-backed
+/// evaluators are built once.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::application::{ServiceConfig, ServiceRegistry};
+/// use riverrun::core::domain::entities::board::Board;
+///
+/// let config: ServiceConfig = "evaluator=perfect-hash, equity=exhaustive".parse().unwrap();
+/// let services = ServiceRegistry::from_config(&config);
+///
+/// let hole = "AsAh".parse().unwrap();
+/// let river: Board = "Ad Kc 7h 2s 9d".parse().unwrap();
+/// let equity = services.equity().calculate(&hole, &river, 1).unwrap();
+/// assert!(equity.equity() > 0.99);
+/// ```
+#[derive(Clone)]
+pub struct ServiceRegistry {
+    evaluator: Arc<dyn HandEvaluator>,
+    equity: Arc<dyn EquityCalculator>,
+    solver: Arc<dyn HandSolver>,
+}
+
+/// `ServiceRegistry` - Constructors
+impl ServiceRegistry {
+    /// A registry of the given implementations.
+    #[must_use]
+    pub fn new(
+        evaluator: Arc<dyn HandEvaluator>,
+        equity: Arc<dyn EquityCalculator>,
+        solver: Arc<dyn HandSolver>,
+    ) -> Self {
+        Self {
+            evaluator,
+            equity,
+            solver,
+        }
+    }
+
+    /// Builds the implementations named by `config`, all sharing one evaluator.
+    #[must_use]
+    pub fn from_config(config: &ServiceConfig) -> Self {
+        let evaluator = config.evaluator.build();
+        Self::new(
+            Arc::clone(&evaluator),
+            config.equity.build(Arc::clone(&evaluator)),
+            Arc::new(ShowdownSolver::new(evaluator)),
+        )
+    }
+}
+
+/// `ServiceRegistry` - Accessors
+impl ServiceRegistry {
+    /// The hand evaluator.
+    #[must_use]
+    pub fn evaluator(&self) -> &dyn HandEvaluator {
+        &*self.evaluator
+    }
+
+    /// The equity calculator.
+    #[must_use]
+    pub fn equity(&self) -> &dyn EquityCalculator {
+        &*self.equity
+    }
+
+    /// The showdown solver.
+    #[must_use]
+    pub fn solver(&self) -> &dyn HandSolver {
+        &*self.solver
+    }
+}
+
+impl Default for ServiceRegistry {
+    fn default() -> Self {
+        Self::from_config(&ServiceConfig::default())
+    }
+}
+
+impl fmt::Debug for ServiceRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ServiceRegistry").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::board::Board;
+    use crate::core::domain::entities::hole_cards::HoleCards;
+
+    #[test]
+    fn test_config_parses_settings() {
+        let config: ServiceConfig = "evaluator = reference, equity=monte-carlo:500"
+            .parse()
+            .unwrap();
+        assert_eq!(config.evaluator(), EvaluatorKind::Reference);
+        assert_eq!(config.equity(), CalculatorKind::MonteCarlo { samples: 500 });
+        assert_eq!(config.to_string().parse::<ServiceConfig>().unwrap(), config);
+
+        assert_eq!(
+            "".parse::<ServiceConfig>().unwrap(),
+            ServiceConfig::default()
+        );
+        assert_eq!(
+            "equity=monte-carlo"
+                .parse::<ServiceConfig>()
+                .unwrap()
+                .equity(),
+            CalculatorKind::MonteCarlo {
+                samples: DEFAULT_SAMPLES
+            }
+        );
+    }
+
+    #[test]
+    fn test_config_rejects_unknown_settings() {
+        assert_eq!(
+            "evaluator=fast".parse::<ServiceConfig>(),
+            Err(ParseServiceConfigError::new("fast"))
+        );
+        assert_eq!(
+            "solver=showdown".parse::<ServiceConfig>(),
+            Err(ParseServiceConfigError::new("solver=showdown"))
+        );
+        assert!("equity=monte-carlo:0".parse::<ServiceConfig>().is_err());
+    }
+
+    #[test]
+    fn test_every_configuration_agrees() {
+        let players: Vec<HoleCards> = ["AsKs", "QhQd"]
+            .iter()
+            .map(|hand| hand.parse().unwrap())
+            .collect();
+        let river: Board = "Qs Js 2h 7c 3s".parse().unwrap();
+
+        for evaluator in [
+            EvaluatorKind::CactusKev,
+            EvaluatorKind::PerfectHash,
+            EvaluatorKind::Reference,
+        ] {
+            for equity in [CalculatorKind::Smart, CalculatorKind::Exhaustive] {
+                let config = ServiceConfig::default()
+                    .with_evaluator(evaluator)
+                    .with_equity(equity);
+                let services = ServiceRegistry::from_config(&config);
+                assert_eq!(
+                    services.solver().solve(&players, &river).winner_indices(),
+                    &[0]
+                );
+                let equity = services.equity().calculate(&players[0], &river, 1).unwrap();
+                assert!(equity.equity() > 0.9);
+            }
+        }
+    }
+}
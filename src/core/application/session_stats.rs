@@ -0,0 +1,203 @@
+//! Projection of buy-ins, cash-outs, and hand results into session statistics.
+
+use crate::core::domain::primitives::{Chips, PlayerId};
+use crate::core::ports::outbound::{
+    self, Clock, ReadModelError, SessionId, SessionStats, SessionStatsStore,
+};
+use crate::telemetry;
+
+use super::hand_engine::HandOutcome;
+
+/// Keeps a `SessionStatsStore` up to date as players buy in, play hands, and leave.
+///
+/// Every update loads the session's stats, records the change stamped with the
+/// `Clock`'s current time, and saves them back. Hands are projected from their pot
+/// awards: each seat's contribution is what it started with less what it kept, and
+/// its winnings are what the `PotDistributor` awarded it.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemorySessionStatsStore;
+/// use riverrun::core::application::SessionStatsProjector;
+/// use riverrun::core::domain::primitives::Chips;
+/// use riverrun::core::ports::outbound::{FixedClock, SessionId, SessionStatsStore};
+///
+/// let projector = SessionStatsProjector::new(InMemorySessionStatsStore::new(), FixedClock::new(0));
+/// let session = SessionId::from("session-1");
+/// projector.buy_in(&session, &"alice".to_string(), Chips::new(1000)).unwrap();
+/// projector.cash_out(&session, Chips::new(1200)).unwrap();
+///
+/// // Only the chips on the table can be cashed out
+/// let stats = projector.store().get(&session).unwrap();
+/// assert_eq!((stats.cash_outs, stats.net_result()), (1000, 0));
+/// ```
+#[derive(Debug)]
+pub struct SessionStatsProjector<S: SessionStatsStore, C: Clock> {
+    store: S,
+    clock: C,
+}
+
+/// `SessionStatsProjector` - Constructors
+impl<S: SessionStatsStore, C: Clock> SessionStatsProjector<S, C> {
+    /// Creates a projector writing to `store`, stamping updates with `clock`.
+    #[must_use]
+    pub const fn new(store: S, clock: C) -> Self {
+        Self { store, clock }
+    }
+}
+
+/// `SessionStatsProjector` - Accessors
+impl<S: SessionStatsStore, C: Clock> SessionStatsProjector<S, C> {
+    /// The store being projected into.
+    #[must_use]
+    pub const fn store(&self) -> &S {
+        &self.store
+    }
+}
+
+/// `SessionStatsProjector` - Operations
+impl<S: SessionStatsStore, C: Clock> SessionStatsProjector<S, C> {
+    /// Records `player_id` buying `amount` chips, starting the session if it is new.
+    ///
+    /// # Errors
+    /// Returns the store's error if the session cannot be loaded or saved.
+    pub fn buy_in(
+        &self,
+        session_id: &SessionId,
+        player_id: &outbound::PlayerId,
+        amount: Chips,
+    ) -> Result<SessionStats, ReadModelError> {
+        let now = self.clock.now();
+        let mut stats = match self.store.get(session_id) {
+            Ok(stats) => stats,
+            Err(ReadModelError::NotFound(_)) => {
+                SessionStats::new(session_id.clone(), player_id.clone(), now)
+            }
+            Err(err) => return Err(err),
+        };
+        stats.record_buy_in(amount.value(), now);
+        self.store.save(&stats)?;
+        telemetry::projection_update("session_stats");
+        Ok(stats)
+    }
+
+    /// Records the player taking up to `amount` chips off the table.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::NotFound` for an unknown session, or the store's error
+    /// if it cannot be saved.
+    pub fn cash_out(
+        &self,
+        session_id: &SessionId,
+        amount: Chips,
+    ) -> Result<SessionStats, ReadModelError> {
+        let mut stats = self.store.get(session_id)?;
+        stats.record_cash_out(amount.value(), self.clock.now());
+        self.store.save(&stats)?;
+        telemetry::projection_update("session_stats");
+        Ok(stats)
+    }
+
+    /// Records a completed hand for every seat.
+    ///
+    /// `sessions` and `starting_stacks` are indexed by `PlayerId`, like the
+    /// `HandSetup` the hand was started with.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::NotFound` if a seat's session has not bought in, or the
+    /// store's error if stats cannot be saved. Seats before the failing one have
+    /// already been recorded.
+    pub fn record_hand(
+        &self,
+        sessions: &[SessionId],
+        starting_stacks: &[Chips],
+        outcome: &HandOutcome,
+    ) -> Result<(), ReadModelError> {
+        let now = self.clock.now();
+        for (seat, (session_id, &start)) in sessions.iter().zip(starting_stacks).enumerate() {
+            let player = PlayerId::from(seat);
+            let awarded = outcome.payouts().amount_for(player);
+            let end = outcome
+                .final_stacks()
+                .get(seat)
+                .copied()
+                .unwrap_or_default();
+            let contributed = (start + awarded).saturating_sub(end);
+
+            let mut stats = self.store.get(session_id)?;
+            stats.record_hand(contributed.value(), awarded.value(), now);
+            self.store.save(&stats)?;
+        }
+        telemetry::projection_update("session_stats");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemorySessionStatsStore;
+    use crate::core::application::{HandEngine, HandSetup};
+    use crate::core::domain::entities::deck::Deck;
+    use crate::core::domain::primitives::{Action, Blinds, HandNumber};
+    use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+    use crate::core::ports::outbound::FixedClock;
+
+    fn projector() -> SessionStatsProjector<InMemorySessionStatsStore, FixedClock> {
+        SessionStatsProjector::new(InMemorySessionStatsStore::new(), FixedClock::new(500))
+    }
+
+    #[test]
+    fn test_record_hand_from_pot_awards() {
+        let projector = projector();
+        let sessions = [SessionId::from("s0"), SessionId::from("s1")];
+        for (session, player) in sessions.iter().zip(["alice", "bob"]) {
+            projector
+                .buy_in(session, &player.to_string(), Chips::new(1000))
+                .unwrap();
+        }
+
+        // The small blind folds to the big blind
+        let stacks = vec![Chips::new(1000); 2];
+        let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+        let setup = HandSetup::new(
+            HandNumber::new(1),
+            stacks.clone(),
+            PlayerId::new(0),
+            Blinds::new(Chips::new(5), Chips::new(10)),
+        );
+        engine.start_hand(setup, Deck::new()).unwrap();
+        engine.act(PlayerId::new(0), Action::Fold).unwrap();
+        let outcome = engine.outcome().unwrap();
+        projector.record_hand(&sessions, &stacks, outcome).unwrap();
+
+        let folded = projector.store().get(&sessions[0]).unwrap();
+        assert_eq!((folded.stack, folded.net_result()), (995, -5));
+        assert_eq!((folded.hands_played, folded.hands_won), (1, 0));
+        let won = projector.store().get(&sessions[1]).unwrap();
+        assert_eq!((won.stack, won.net_result()), (1005, 5));
+        assert_eq!(won.hands_won, 1);
+        assert_eq!(
+            won.history,
+            [outbound::BankrollPoint {
+                timestamp: 500,
+                bankroll: 5
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unknown_session() {
+        let projector = projector();
+        assert!(matches!(
+            projector.cash_out(&"missing".into(), Chips::new(10)),
+            Err(ReadModelError::NotFound(_))
+        ));
+
+        let stats = projector
+            .buy_in(&"s1".into(), &"alice".to_string(), Chips::new(300))
+            .unwrap();
+        assert_eq!((stats.started_at, stats.buy_ins), (500, 300));
+    }
+}
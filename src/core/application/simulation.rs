@@ -0,0 +1,415 @@
+//! Bot-versus-bot simulation: plays many hands between strategies.
+
+use std::error::Error;
+use std::fmt;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::core::domain::entities::deck::Deck;
+use crate::core::domain::errors::GameError;
+use crate::core::domain::primitives::{Action, Blinds, Chips, HandNumber, PlayerId};
+use crate::core::ports::inbound::{HandSolver, Strategy};
+use crate::core::ports::outbound::{self, Clock, PlayerStatsStore, ReadModelError};
+
+use super::hand_engine::{HandEngine, HandSetup};
+use super::player_stats::PlayerStatsProjector;
+
+/// Error returned by `SimulationRunner::run`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulationError {
+    /// The engine rejected a hand, e.g. because fewer than two players are seated.
+    Game(GameError),
+    /// Statistics could not be recorded.
+    ReadModel(ReadModelError),
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Game(err) => write!(f, "{err}"),
+            Self::ReadModel(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for SimulationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Game(err) => Some(err),
+            Self::ReadModel(err) => Some(err),
+        }
+    }
+}
+
+impl From<GameError> for SimulationError {
+    fn from(err: GameError) -> Self {
+        Self::Game(err)
+    }
+}
+
+impl From<ReadModelError> for SimulationError {
+    fn from(err: ReadModelError) -> Self {
+        Self::ReadModel(err)
+    }
+}
+
+/// How one seat fared over a simulation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeatResult {
+    /// Player in the seat.
+    pub player_id: outbound::PlayerId,
+    /// Hands in which the seat was awarded chips.
+    pub hands_won: u64,
+    /// Chips won (positive) or lost (negative) over every hand.
+    pub net_chips: i64,
+}
+
+/// Results of `SimulationRunner::run`, one entry per seat.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulationReport {
+    /// Hands played.
+    pub hands: u64,
+    /// Big blind the hands were played at.
+    pub big_blind: Chips,
+    /// Result of each seat, indexed by `PlayerId`.
+    pub seats: Vec<SeatResult>,
+}
+
+/// `SimulationReport` - Accessors
+impl SimulationReport {
+    /// Result of the seat held by `player_id`.
+    #[must_use]
+    pub fn seat(&self, player_id: &str) -> Option<&SeatResult> {
+        self.seats.iter().find(|seat| seat.player_id == player_id)
+    }
+
+    /// Win rate of `player_id` in big blinds per 100 hands.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn big_blinds_per_100(&self, player_id: &str) -> Option<f64> {
+        let seat = self.seat(player_id)?;
+        if self.hands == 0 || self.big_blind.is_zero() {
+            return Some(0.0);
+        }
+        let big_blinds = seat.net_chips as f64 / self.big_blind.value() as f64;
+        Some(big_blinds * 100.0 / self.hands as f64)
+    }
+}
+
+/// A strategy and the player it plays for.
+struct Seat {
+    player_id: outbound::PlayerId,
+    strategy: Box<dyn Strategy>,
+}
+
+/// Application service that plays hands between bots to benchmark them.
+///
+/// Every hand is played through a `HandEngine`, asking each seat's `Strategy` to act
+/// from its `PlayerView` in turn; a strategy that returns an illegal action checks if
+/// it can and folds otherwise. Each hand starts with every seat at the same stack, as
+/// in a cash game where players top up, and the button moves one seat per hand.
+/// Decks are shuffled from a seed, so a run between deterministic strategies can be
+/// reproduced.
+///
+/// Completed hands are recorded through a `PlayerStatsProjector`, so the bots' HUD
+/// statistics and leaderboards can be read back from its store.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemoryPlayerStatsStore;
+/// use riverrun::core::application::{PlayerStatsProjector, SimulationRunner};
+/// use riverrun::core::domain::primitives::{Blinds, Chips};
+/// use riverrun::core::domain::services::strategy::{AlwaysCallStrategy, PushFoldStrategy};
+/// use riverrun::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+/// use riverrun::core::ports::outbound::{FixedClock, PlayerStatsStore};
+///
+/// let stats = PlayerStatsProjector::new(InMemoryPlayerStatsStore::new(), FixedClock::new(0));
+/// let mut runner = SimulationRunner::new(
+///     ShowdownSolver::new(CactusKevEvaluator::new()),
+///     stats,
+///     Blinds::new(Chips::new(50), Chips::new(100)),
+///     Chips::new(1000),
+/// )
+/// .seat("station", AlwaysCallStrategy)
+/// .seat("jammer", PushFoldStrategy::new());
+///
+/// let report = runner.run(50).unwrap();
+/// let net: i64 = report.seats.iter().map(|seat| seat.net_chips).sum();
+/// assert_eq!(net, 0);
+/// assert_eq!(runner.stats().store().get(&"jammer".to_string()).unwrap().hands_played, 50);
+/// ```
+pub struct SimulationRunner<S: HandSolver, P: PlayerStatsStore, C: Clock> {
+    engine: HandEngine<S>,
+    stats: PlayerStatsProjector<P, C>,
+    seats: Vec<Seat>,
+    blinds: Blinds,
+    starting_stack: Chips,
+    rng: StdRng,
+    hands_played: u64,
+}
+
+impl<S: HandSolver, P: PlayerStatsStore, C: Clock> fmt::Debug for SimulationRunner<S, P, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let players: Vec<&str> = self
+            .seats
+            .iter()
+            .map(|seat| seat.player_id.as_str())
+            .collect();
+        f.debug_struct("SimulationRunner")
+            .field("players", &players)
+            .field("blinds", &self.blinds)
+            .field("starting_stack", &self.starting_stack)
+            .field("hands_played", &self.hands_played)
+            .finish_non_exhaustive()
+    }
+}
+
+/// `SimulationRunner` - Constructors
+impl<S: HandSolver, P: PlayerStatsStore, C: Clock> SimulationRunner<S, P, C> {
+    /// Creates a runner with no seats, resolving showdowns with `solver` and recording
+    /// hands through `stats`. Every hand is played at `blinds` with `starting_stack`
+    /// in front of each seat.
+    #[must_use]
+    pub fn new(
+        solver: S,
+        stats: PlayerStatsProjector<P, C>,
+        blinds: Blinds,
+        starting_stack: Chips,
+    ) -> Self {
+        Self {
+            engine: HandEngine::new(solver),
+            stats,
+            seats: Vec::new(),
+            blinds,
+            starting_stack,
+            rng: StdRng::seed_from_u64(0),
+            hands_played: 0,
+        }
+    }
+
+    /// Seats `strategy` in the next seat, playing as `player_id`.
+    #[must_use]
+    pub fn seat(
+        mut self,
+        player_id: impl Into<outbound::PlayerId>,
+        strategy: impl Strategy + 'static,
+    ) -> Self {
+        self.seats.push(Seat {
+            player_id: player_id.into(),
+            strategy: Box::new(strategy),
+        });
+        self
+    }
+
+    /// Shuffles decks from `seed` instead of the default seed.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+}
+
+/// `SimulationRunner` - Accessors
+impl<S: HandSolver, P: PlayerStatsStore, C: Clock> SimulationRunner<S, P, C> {
+    /// The projector hands are recorded through.
+    #[must_use]
+    pub const fn stats(&self) -> &PlayerStatsProjector<P, C> {
+        &self.stats
+    }
+
+    /// Players seated, indexed by `PlayerId`.
+    #[must_use]
+    pub fn player_ids(&self) -> Vec<outbound::PlayerId> {
+        self.seats
+            .iter()
+            .map(|seat| seat.player_id.clone())
+            .collect()
+    }
+
+    /// Hands played over every run so far.
+    #[must_use]
+    pub const fn hands_played(&self) -> u64 {
+        self.hands_played
+    }
+}
+
+/// `SimulationRunner` - Operations
+impl<S: HandSolver, P: PlayerStatsStore, C: Clock> SimulationRunner<S, P, C> {
+    /// Plays `hands` hands and reports how each seat fared over them.
+    ///
+    /// Hand numbers and the button carry on from earlier runs.
+    ///
+    /// # Errors
+    /// Returns `SimulationError::Game` if the seating is invalid (fewer than two or
+    /// more than ten seats, or blinds the engine rejects), and
+    /// `SimulationError::ReadModel` if a hand cannot be recorded. Hands before the
+    /// failing one have already been recorded.
+    ///
+    /// # Panics
+    /// Panics if the engine ends betting without resolving the hand, which it never
+    /// does.
+    pub fn run(&mut self, hands: u64) -> Result<SimulationReport, SimulationError> {
+        let player_ids = self.player_ids();
+        let mut seats: Vec<SeatResult> = player_ids
+            .iter()
+            .map(|player_id| SeatResult {
+                player_id: player_id.clone(),
+                hands_won: 0,
+                net_chips: 0,
+            })
+            .collect();
+
+        for _ in 0..hands {
+            self.play_hand()?;
+            let outcome = self
+                .engine
+                .outcome()
+                .expect("betting is over, so the hand has been resolved");
+            self.stats
+                .record_hand(&player_ids, self.engine.history(), outcome)?;
+            for (index, seat) in seats.iter_mut().enumerate() {
+                let player = PlayerId::from(index);
+                seat.hands_won += u64::from(!outcome.payouts().amount_for(player).is_zero());
+                seat.net_chips += outcome.net_result(player, self.starting_stack);
+            }
+        }
+
+        Ok(SimulationReport {
+            hands,
+            big_blind: self.blinds.big_blind,
+            seats,
+        })
+    }
+}
+
+/// `SimulationRunner` - Helpers
+impl<S: HandSolver, P: PlayerStatsStore, C: Clock> SimulationRunner<S, P, C> {
+    /// Deals the next hand and asks each strategy to act until betting is over.
+    #[allow(clippy::cast_possible_truncation)]
+    fn play_hand(&mut self) -> Result<(), SimulationError> {
+        let num_seats = self.seats.len().max(1);
+        let button = PlayerId::from((self.hands_played % num_seats as u64) as usize);
+        self.hands_played += 1;
+        let setup = HandSetup::new(
+            HandNumber::new(self.hands_played),
+            vec![self.starting_stack; self.seats.len()],
+            button,
+            self.blinds,
+        );
+        let mut deck = Deck::new();
+        deck.shuffle(&mut self.rng);
+        self.engine.start_hand(setup, deck)?;
+
+        while let Some(player) = self.engine.to_act() {
+            let view = self
+                .engine
+                .aggregate()
+                .player_view(player)
+                .expect("the player to act is seated");
+            let action = self.seats[player.as_index()].strategy.act(&view);
+            let legal = view.legal_actions;
+            let action = if legal.allows(action) {
+                action
+            } else if legal.check {
+                Action::Check
+            } else {
+                Action::Fold
+            };
+            self.engine.act(player, action)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryPlayerStatsStore;
+    use crate::core::domain::services::strategy::{
+        AlwaysCallStrategy, PushFoldStrategy, RandomStrategy,
+    };
+    use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+    use crate::core::ports::outbound::FixedClock;
+
+    type Runner =
+        SimulationRunner<ShowdownSolver<CactusKevEvaluator>, InMemoryPlayerStatsStore, FixedClock>;
+
+    fn runner(seed: u64) -> Runner {
+        SimulationRunner::new(
+            ShowdownSolver::new(CactusKevEvaluator::new()),
+            PlayerStatsProjector::new(InMemoryPlayerStatsStore::new(), FixedClock::new(0)),
+            Blinds::new(Chips::new(5), Chips::new(10)),
+            Chips::new(500),
+        )
+        .with_seed(seed)
+    }
+
+    /// Strategy that only ever asks for something it cannot do.
+    struct Illegal;
+
+    impl Strategy for Illegal {
+        fn act(&self, _view: &crate::core::domain::aggregates::PlayerView) -> Action {
+            Action::Bet(Chips::new(1))
+        }
+    }
+
+    #[test]
+    fn test_run_records_every_hand() {
+        let mut runner = runner(7)
+            .seat("random", RandomStrategy::new(1))
+            .seat("station", AlwaysCallStrategy)
+            .seat("jammer", PushFoldStrategy::new());
+        let report = runner.run(200).unwrap();
+
+        assert_eq!(report.hands, 200);
+        assert_eq!(
+            report.seats.iter().map(|seat| seat.net_chips).sum::<i64>(),
+            0
+        );
+        for player_id in runner.player_ids() {
+            let stats = runner.stats().store().get(&player_id).unwrap();
+            assert_eq!(stats.hands_played, 200);
+            assert_eq!(stats.hands_won, report.seat(&player_id).unwrap().hands_won);
+        }
+        let station = runner.stats().store().get(&"station".to_string()).unwrap();
+        assert_eq!(station.pfr_hands, 0);
+
+        // Runs carry on where the last one stopped
+        runner.run(10).unwrap();
+        assert_eq!(runner.hands_played(), 210);
+    }
+
+    #[test]
+    fn test_same_seed_same_results() {
+        let play = |seed| {
+            runner(seed)
+                .seat("a", RandomStrategy::new(5))
+                .seat("b", AlwaysCallStrategy)
+                .run(50)
+                .unwrap()
+        };
+        assert_eq!(play(3), play(3));
+    }
+
+    #[test]
+    fn test_illegal_actions_check_or_fold() {
+        let mut runner = runner(1)
+            .seat("illegal", Illegal)
+            .seat("station", AlwaysCallStrategy);
+        let report = runner.run(1).unwrap();
+
+        // The illegal bot, on the button, folds its small blind
+        let illegal = report.seat("illegal").unwrap();
+        assert_eq!((illegal.hands_won, illegal.net_chips), (0, -5));
+        assert_eq!(report.big_blinds_per_100("illegal"), Some(-50.0));
+        assert_eq!(report.big_blinds_per_100("nobody"), None);
+    }
+
+    #[test]
+    fn test_run_needs_two_seats() {
+        let mut runner = runner(1).seat("alone", AlwaysCallStrategy);
+        assert!(matches!(runner.run(1), Err(SimulationError::Game(_))));
+    }
+}
@@ -0,0 +1,197 @@
+//! Solver runs served from a result cache when they have been run before.
+
+use crate::core::domain::services::solver::{
+    MccfrSolver, RiverSolver, SolverError, SolverGame, StrategyProfile,
+};
+use crate::core::ports::inbound::HandEvaluator;
+use crate::core::ports::outbound::{CacheKey, ResultCache};
+
+/// Runs the solvers through a `ResultCache`, so solving a spot again, such as by
+/// reopening a hand review, returns the earlier profile instead of iterating again.
+///
+/// Profiles are stored under `SolverGame::cache_key` with the solver's settings added,
+/// which changes with the evaluator's version, so entries from an older evaluator are
+/// never served. The cache only saves time: an entry that cannot be read or stored is
+/// solved afresh rather than failing the run.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::adapters::InMemoryResultCache;
+/// use riverrun::core::application::SolverCache;
+/// use riverrun::core::domain::entities::board::Board;
+/// use riverrun::core::domain::entities::hand_range::HandRange;
+/// use riverrun::core::domain::primitives::Chips;
+/// use riverrun::core::domain::services::CactusKevEvaluator;
+/// use riverrun::core::domain::services::solver::SolverGame;
+///
+/// let board = Board::with_cards(
+///     "Kh 9d 5c 2s 2h".split_whitespace().map(|c| c.parse().unwrap()).collect(),
+/// )
+/// .unwrap();
+/// let ranges: [HandRange; 2] = ["QQ".parse().unwrap(), "KK, 76s".parse().unwrap()];
+/// let game = SolverGame::new(board, ranges, Chips::new(100), Chips::new(100)).unwrap();
+/// let solver = SolverCache::new(InMemoryResultCache::new(100));
+///
+/// let evaluator = CactusKevEvaluator::new();
+/// let first = solver.river(&game, &evaluator, 100).unwrap();
+/// let again = solver.river(&game, &evaluator, 100).unwrap();
+/// assert_eq!(first.len(), again.len());
+/// assert_eq!(solver.cache().len().unwrap(), 1);
+/// ```
+#[derive(Debug)]
+pub struct SolverCache<C: ResultCache> {
+    cache: C,
+}
+
+/// `SolverCache` - Constructors
+impl<C: ResultCache> SolverCache<C> {
+    /// Serve runs from `cache`.
+    pub const fn new(cache: C) -> Self {
+        Self { cache }
+    }
+}
+
+/// `SolverCache` - Accessors
+impl<C: ResultCache> SolverCache<C> {
+    /// The cache profiles are stored in.
+    pub const fn cache(&self) -> &C {
+        &self.cache
+    }
+}
+
+/// `SolverCache` - Operations
+impl<C: ResultCache> SolverCache<C> {
+    /// The profile of `game` after `iterations` iterations of `RiverSolver` ranking
+    /// showdowns with `evaluator`, from the cache when it was solved before and
+    /// otherwise solved and stored.
+    ///
+    /// # Errors
+    /// Returns the `SolverError` of `RiverSolver::new` for a game starting before the
+    /// river. Failed runs are not cached.
+    pub fn river<E: HandEvaluator>(
+        &self,
+        game: &SolverGame,
+        evaluator: &E,
+        iterations: u64,
+    ) -> Result<StrategyProfile, SolverError> {
+        let key = game.cache_key("river", evaluator.version()).u64(iterations).finish();
+        if let Some(profile) = self.lookup(&key) {
+            return Ok(profile);
+        }
+
+        let mut solver = RiverSolver::new(game.clone(), evaluator)?;
+        solver.run(iterations);
+        let profile = solver.profile();
+        // A profile that cannot be stored is simply solved again next time
+        let _ = self.cache.put(&key, &profile.to_bytes());
+        Ok(profile)
+    }
+
+    /// The profile of `game` after `iterations` iterations of `MccfrSolver` dealing
+    /// from `seed` and ranking showdowns with `evaluator`, from the cache when it was
+    /// solved before and otherwise solved and stored.
+    pub fn mccfr<E: HandEvaluator>(
+        &self,
+        game: &SolverGame,
+        evaluator: E,
+        seed: u64,
+        iterations: u64,
+    ) -> StrategyProfile {
+        let key = game
+            .cache_key("mccfr", evaluator.version())
+            .u64(seed)
+            .u64(iterations)
+            .finish();
+        if let Some(profile) = self.lookup(&key) {
+            return profile;
+        }
+
+        let mut solver = MccfrSolver::new(game.clone(), evaluator).with_seed(seed);
+        solver.run(iterations);
+        let profile = solver.profile();
+        let _ = self.cache.put(&key, &profile.to_bytes());
+        profile
+    }
+
+    /// The profile cached under `key`, if there is a readable one.
+    fn lookup(&self, key: &CacheKey) -> Option<StrategyProfile> {
+        self.cache
+            .get(key)
+            .ok()
+            .flatten()
+            .and_then(|bytes| StrategyProfile::from_bytes(&bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryResultCache;
+    use crate::core::domain::entities::board::Board;
+    use crate::core::domain::entities::card::Card;
+    use crate::core::domain::entities::hand_range::HandRange;
+    use crate::core::domain::primitives::Chips;
+    use crate::core::domain::services::CactusKevEvaluator;
+
+    fn game(board: &str) -> SolverGame {
+        let board = Board::with_cards(
+            board
+                .split_whitespace()
+                .map(|card| card.parse::<Card>().unwrap())
+                .collect(),
+        )
+        .unwrap();
+        let ranges: [HandRange; 2] = ["QQ, 98s".parse().unwrap(), "KK, 76s".parse().unwrap()];
+        SolverGame::new(board, ranges, Chips::new(100), Chips::new(200))
+            .unwrap()
+            .with_bet_sizes(vec![1.0])
+    }
+
+    #[test]
+    fn test_cached_profiles_match_solved_ones() {
+        let evaluator = CactusKevEvaluator::new();
+        let solver = SolverCache::new(InMemoryResultCache::new(10));
+        let river = game("Kh 9d 5c 2s 2h");
+
+        let fresh = solver.river(&river, &evaluator, 50).unwrap();
+        let cached = solver.river(&river, &evaluator, 50).unwrap();
+        assert_eq!(cached.iterations(), 50);
+        assert_eq!(cached.len(), fresh.len());
+        for (info_set, strategy) in fresh.iter() {
+            assert_eq!(cached.strategy(info_set), Some(strategy));
+        }
+        assert_eq!(solver.cache().len().unwrap(), 1);
+
+        // Other iterations, seeds or solvers are other runs
+        solver.river(&river, &evaluator, 60).unwrap();
+        let turn = game("Kh 9d 5c 2s");
+        let first = solver.mccfr(&turn, evaluator.clone(), 1, 200);
+        let again = solver.mccfr(&turn, evaluator.clone(), 1, 200);
+        assert_eq!(first.len(), again.len());
+        solver.mccfr(&turn, evaluator, 2, 200);
+        assert_eq!(solver.cache().len().unwrap(), 4);
+    }
+
+    #[test]
+    fn test_unusable_entries_are_resolved() {
+        let evaluator = CactusKevEvaluator::new();
+        let solver = SolverCache::new(InMemoryResultCache::new(10));
+        let river = game("Kh 9d 5c 2s 2h");
+        let key = river.cache_key("river", evaluator.version()).u64(20).finish();
+        solver.cache().put(&key, b"corrupt").unwrap();
+
+        let profile = solver.river(&river, &evaluator, 20).unwrap();
+        assert_eq!(profile.iterations(), 20);
+        let stored = solver.cache().get(&key).unwrap().unwrap();
+        assert_eq!(
+            StrategyProfile::from_bytes(&stored).map(|profile| profile.len()),
+            Some(profile.len())
+        );
+
+        // Turn games cannot be solved exactly and leave nothing behind
+        let turn = game("Kh 9d 5c 2s");
+        assert!(solver.river(&turn, &evaluator, 20).is_err());
+        assert_eq!(solver.cache().len().unwrap(), 1);
+    }
+}
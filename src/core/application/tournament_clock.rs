@@ -0,0 +1,232 @@
+//! Tournament clock advancing the blind levels as time passes.
+
+use std::time::Duration;
+
+use crate::core::domain::primitives::{
+    BlindLevel, BlindSchedule, Blinds, Chips, HandNumber, PlayerId,
+};
+use crate::core::ports::outbound::Clock;
+
+use super::hand_engine::HandSetup;
+
+/// Clock that tracks how long a tournament has been played and which blind level is up.
+///
+/// Time is read from a `Clock`, so a `FixedClock` or `TestClock` makes level
+/// changes deterministic. The clock can be paused for breaks, and the tournament
+/// director can jump straight to a level. Each hand picks up the blinds and ante of
+/// the level current when it starts, through `hand_setup`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use riverrun::core::application::{HandEngine, TournamentClock};
+/// use riverrun::core::domain::entities::deck::Deck;
+/// use riverrun::core::domain::primitives::{BlindSchedule, Blinds, Chips, HandNumber, PlayerId};
+/// use riverrun::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+/// use riverrun::core::ports::outbound::FixedClock;
+///
+/// let schedule = BlindSchedule::uniform(
+///     [
+///         Blinds::new(Chips::new(10), Chips::new(20)),
+///         Blinds::new(Chips::new(20), Chips::new(40)).with_ante(Chips::new(5)),
+///     ],
+///     Duration::from_mins(15),
+/// )
+/// .unwrap();
+/// let mut clock = TournamentClock::new(schedule, FixedClock::new(0));
+/// clock.skip_to_level(1);
+///
+/// let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+/// let setup = clock.hand_setup(HandNumber::new(1), vec![Chips::new(1500); 3], PlayerId::new(0));
+/// engine.start_hand(setup, Deck::new()).unwrap();
+/// // Three antes, the small blind, and the big blind
+/// assert_eq!(engine.aggregate().betting().unwrap().total_pot(), Chips::new(75));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TournamentClock<C: Clock> {
+    schedule: BlindSchedule,
+    clock: C,
+    /// Play time banked before the clock last started running.
+    banked: Duration,
+    /// `Clock::now_instant` when the clock last started running, or `None` while paused.
+    running_since: Option<Duration>,
+}
+
+/// `TournamentClock` - Constructors
+impl<C: Clock> TournamentClock<C> {
+    /// Starts the clock at the first level of `schedule`, reading time from `clock`.
+    #[must_use]
+    pub fn new(schedule: BlindSchedule, clock: C) -> Self {
+        let running_since = Some(clock.now_instant());
+        Self {
+            schedule,
+            clock,
+            banked: Duration::ZERO,
+            running_since,
+        }
+    }
+}
+
+/// `TournamentClock` - Accessors
+impl<C: Clock> TournamentClock<C> {
+    /// The blind structure being played.
+    #[must_use]
+    pub const fn schedule(&self) -> &BlindSchedule {
+        &self.schedule
+    }
+
+    /// Returns whether the clock is paused.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.running_since.is_none()
+    }
+
+    /// Play time so far, not counting pauses.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        let running = self.running_since.map_or(Duration::ZERO, |since| {
+            self.clock.now_instant().saturating_sub(since)
+        });
+        self.banked + running
+    }
+
+    /// Index of the current level, counting from zero.
+    #[must_use]
+    pub fn level_index(&self) -> usize {
+        self.schedule.level_index_at(self.elapsed())
+    }
+
+    /// The current level.
+    #[must_use]
+    pub fn level(&self) -> &BlindLevel {
+        self.schedule.level_at(self.elapsed())
+    }
+
+    /// Blinds and ante of the current level.
+    #[must_use]
+    pub fn blinds(&self) -> Blinds {
+        self.level().blinds
+    }
+
+    /// Play time left before the next level, or `None` during the final level.
+    #[must_use]
+    pub fn time_until_next_level(&self) -> Option<Duration> {
+        self.schedule.time_until_next_level(self.elapsed())
+    }
+
+    /// Setup for a hand played at the current level's blinds and ante.
+    #[must_use]
+    pub fn hand_setup(
+        &self,
+        hand_number: HandNumber,
+        stacks: Vec<Chips>,
+        button: PlayerId,
+    ) -> HandSetup {
+        HandSetup::new(hand_number, stacks, button, self.blinds())
+    }
+}
+
+/// `TournamentClock` - Operations
+impl<C: Clock> TournamentClock<C> {
+    /// Stops the clock, for example for a break. Does nothing if already paused.
+    pub fn pause(&mut self) {
+        self.banked = self.elapsed();
+        self.running_since = None;
+    }
+
+    /// Restarts a paused clock. Does nothing if already running.
+    pub fn resume(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(self.clock.now_instant());
+        }
+    }
+
+    /// Jumps to the start of level `index`, keeping the clock paused or running.
+    ///
+    /// Indexes past the final level jump to the final level.
+    pub fn skip_to_level(&mut self, index: usize) {
+        let index = index.min(self.schedule.num_levels() - 1);
+        self.banked = self.schedule.level_start(index).unwrap_or_default();
+        if self.running_since.is_some() {
+            self.running_since = Some(self.clock.now_instant());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ports::outbound::TestClock;
+
+    fn schedule() -> BlindSchedule {
+        BlindSchedule::uniform(
+            [
+                Blinds::new(Chips::new(10), Chips::new(20)),
+                Blinds::new(Chips::new(20), Chips::new(40)),
+                Blinds::new(Chips::new(50), Chips::new(100)).with_ante(Chips::new(10)),
+            ],
+            Duration::from_mins(10),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_levels_advance_with_the_clock() {
+        let time = TestClock::default();
+        let clock = TournamentClock::new(schedule(), time.clone());
+        assert_eq!(clock.level_index(), 0);
+
+        time.advance(Duration::from_mins(12));
+        assert_eq!(clock.level_index(), 1);
+        assert_eq!(clock.blinds().big_blind, Chips::new(40));
+        assert_eq!(clock.time_until_next_level(), Some(Duration::from_mins(8)));
+
+        time.advance(Duration::from_hours(2));
+        assert_eq!(clock.level_index(), 2);
+        assert_eq!(clock.time_until_next_level(), None);
+    }
+
+    #[test]
+    fn test_pause_stops_the_levels() {
+        let time = TestClock::default();
+        let mut clock = TournamentClock::new(schedule(), time.clone());
+
+        time.advance(Duration::from_mins(5));
+        clock.pause();
+        assert!(clock.is_paused());
+        time.advance(Duration::from_mins(30));
+        assert_eq!(clock.elapsed(), Duration::from_mins(5));
+        assert_eq!(clock.level_index(), 0);
+
+        clock.resume();
+        time.advance(Duration::from_mins(6));
+        assert_eq!(clock.elapsed(), Duration::from_mins(11));
+        assert_eq!(clock.level_index(), 1);
+    }
+
+    #[test]
+    fn test_skip_to_level() {
+        let time = TestClock::default();
+        let mut clock = TournamentClock::new(schedule(), time.clone());
+
+        clock.skip_to_level(2);
+        assert_eq!(clock.elapsed(), Duration::from_mins(20));
+        time.advance(Duration::from_mins(1));
+        assert_eq!(clock.elapsed(), Duration::from_mins(21));
+
+        clock.pause();
+        clock.skip_to_level(7);
+        assert_eq!(clock.level_index(), 2);
+        assert!(clock.is_paused());
+
+        let setup = clock.hand_setup(
+            HandNumber::new(3),
+            vec![Chips::new(500); 2],
+            PlayerId::new(1),
+        );
+        assert_eq!(setup.blinds(), clock.blinds());
+        assert_eq!(setup.ante, Chips::new(10));
+    }
+}
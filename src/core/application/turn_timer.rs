@@ -0,0 +1,320 @@
+//! Turn timer limiting how long each player may take to act.
+
+use std::time::Duration;
+
+use crate::core::domain::aggregates::GameEvent;
+use crate::core::domain::errors::GameError;
+use crate::core::domain::primitives::{Action, PlayerId};
+use crate::core::ports::inbound::HandSolver;
+use crate::core::ports::outbound::Clock;
+
+use super::hand_engine::HandEngine;
+
+/// A player who ran out of time, and the action taken for them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TurnTimeout {
+    /// The player who timed out.
+    pub player: PlayerId,
+    /// Check if it was free, fold otherwise.
+    pub action: Action,
+    /// Events produced by the action.
+    pub events: Vec<GameEvent>,
+}
+
+/// The turn being timed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Turn {
+    player: PlayerId,
+    /// `Clock::now_instant` when the turn started.
+    started_at: Duration,
+}
+
+/// Timer giving each decision a time limit, backed by a per-player time bank.
+///
+/// A player has `decision_time` for every decision; once it runs out, their time bank
+/// starts draining, and whatever they use of it is gone for later decisions. A player
+/// who runs out of both is timed out: they check if they can, and fold otherwise.
+///
+/// Time is read from a `Clock`. Turns are timed by hand with `start_turn` and
+/// `end_turn`, or against a `HandEngine` with `poll`, which follows the engine's turn
+/// order and acts for players who time out.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use riverrun::core::application::{HandEngine, HandSetup, TurnTimer};
+/// use riverrun::core::domain::entities::deck::Deck;
+/// use riverrun::core::domain::primitives::{Action, Blinds, Chips, HandNumber, PlayerId};
+/// use riverrun::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+/// use riverrun::core::ports::outbound::FixedClock;
+///
+/// let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+/// let blinds = Blinds::new(Chips::new(5), Chips::new(10));
+/// let setup = HandSetup::new(HandNumber::new(1), vec![Chips::new(1000); 2], PlayerId::new(0), blinds);
+/// engine.start_hand(setup, Deck::new()).unwrap();
+///
+/// // No time at all: the small blind is folded as soon as their turn is polled
+/// let mut timer = TurnTimer::new(FixedClock::new(0), Duration::ZERO, Duration::ZERO);
+/// assert!(timer.poll(&mut engine).unwrap().is_none());
+/// let timeout = timer.poll(&mut engine).unwrap().unwrap();
+/// assert_eq!((timeout.player, timeout.action), (PlayerId::new(0), Action::Fold));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TurnTimer<C: Clock> {
+    clock: C,
+    decision_time: Duration,
+    initial_time_bank: Duration,
+    /// Time bank left to each player, indexed by `PlayerId`; missing seats are full.
+    time_banks: Vec<Duration>,
+    turn: Option<Turn>,
+}
+
+/// `TurnTimer` - Constructors
+impl<C: Clock> TurnTimer<C> {
+    /// Creates a timer giving `decision_time` per decision and a `time_bank` per
+    /// player, reading time from `clock`.
+    #[must_use]
+    pub const fn new(clock: C, decision_time: Duration, time_bank: Duration) -> Self {
+        Self {
+            clock,
+            decision_time,
+            initial_time_bank: time_bank,
+            time_banks: Vec::new(),
+            turn: None,
+        }
+    }
+}
+
+/// `TurnTimer` - Accessors
+impl<C: Clock> TurnTimer<C> {
+    /// Time given for every decision before the time bank is used.
+    #[must_use]
+    pub const fn decision_time(&self) -> Duration {
+        self.decision_time
+    }
+
+    /// Player whose turn is being timed.
+    #[must_use]
+    pub fn current_player(&self) -> Option<PlayerId> {
+        self.turn.map(|turn| turn.player)
+    }
+
+    /// Time `player` has left in their time bank, not counting the turn in progress.
+    #[must_use]
+    pub fn time_bank(&self, player: PlayerId) -> Duration {
+        self.time_banks
+            .get(player.as_index())
+            .copied()
+            .unwrap_or(self.initial_time_bank)
+    }
+
+    /// Time spent on the current turn.
+    #[must_use]
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.turn.map(|turn| self.since(turn.started_at))
+    }
+
+    /// Time left on the current turn, counting the time bank.
+    #[must_use]
+    pub fn remaining(&self) -> Option<Duration> {
+        let turn = self.turn?;
+        let allowed = self.decision_time + self.time_bank(turn.player);
+        Some(allowed.saturating_sub(self.since(turn.started_at)))
+    }
+
+    /// Time left before the current player starts using their time bank.
+    #[must_use]
+    pub fn remaining_decision_time(&self) -> Option<Duration> {
+        let turn = self.turn?;
+        Some(
+            self.decision_time
+                .saturating_sub(self.since(turn.started_at)),
+        )
+    }
+
+    /// Returns whether the current player has run out of time.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.remaining()
+            .is_some_and(|remaining| remaining.is_zero())
+    }
+}
+
+/// `TurnTimer` - Operations
+impl<C: Clock> TurnTimer<C> {
+    /// Starts timing `player`'s turn, ending any turn in progress.
+    pub fn start_turn(&mut self, player: PlayerId) {
+        self.end_turn();
+        self.turn = Some(Turn {
+            player,
+            started_at: self.clock.now_instant(),
+        });
+    }
+
+    /// Ends the current turn, charging any time past the decision time to the player's
+    /// time bank. Returns the time taken.
+    pub fn end_turn(&mut self) -> Option<Duration> {
+        let turn = self.turn.take()?;
+        let elapsed = self.since(turn.started_at);
+        let overtime = elapsed.saturating_sub(self.decision_time);
+        let bank = self.time_bank(turn.player).saturating_sub(overtime);
+        self.set_time_bank(turn.player, bank);
+        Some(elapsed)
+    }
+
+    /// Sets the time `player` has left in their time bank, e.g. to top it up between
+    /// levels.
+    pub fn set_time_bank(&mut self, player: PlayerId, time_bank: Duration) {
+        let index = player.as_index();
+        if index >= self.time_banks.len() {
+            self.time_banks.resize(index + 1, self.initial_time_bank);
+        }
+        self.time_banks[index] = time_bank;
+    }
+
+    /// Follows `engine`'s turn order, acting for a player who has run out of time.
+    ///
+    /// When the player to act has changed since the last poll, the previous turn is
+    /// ended and the new one started. Otherwise, if the current player has run out of
+    /// time, they check or fold and the next turn starts. Poll after every action and
+    /// regularly in between; the time taken on a turn is counted up to the poll that
+    /// notices it has ended.
+    ///
+    /// # Errors
+    /// Returns the engine's `GameError` if the timeout action is rejected.
+    pub fn poll<S: HandSolver>(
+        &mut self,
+        engine: &mut HandEngine<S>,
+    ) -> Result<Option<TurnTimeout>, GameError> {
+        let to_act = engine.to_act();
+        if to_act != self.current_player() {
+            match to_act {
+                Some(player) => self.start_turn(player),
+                None => {
+                    self.end_turn();
+                }
+            }
+            return Ok(None);
+        }
+        let Some(player) = to_act.filter(|_| self.is_expired()) else {
+            return Ok(None);
+        };
+
+        let action = if engine.legal_actions().check {
+            Action::Check
+        } else {
+            Action::Fold
+        };
+        let events = engine.act(player, action)?;
+        self.end_turn();
+        if let Some(next) = engine.to_act() {
+            self.start_turn(next);
+        }
+        Ok(Some(TurnTimeout {
+            player,
+            action,
+            events,
+        }))
+    }
+}
+
+/// `TurnTimer` - Helpers
+impl<C: Clock> TurnTimer<C> {
+    /// Time since `started_at`.
+    fn since(&self, started_at: Duration) -> Duration {
+        self.clock.now_instant().saturating_sub(started_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ports::outbound::TestClock;
+
+    use crate::core::application::HandSetup;
+    use crate::core::domain::entities::deck::Deck;
+    use crate::core::domain::primitives::{Blinds, Chips, HandNumber};
+    use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver};
+
+    fn p(seat: u8) -> PlayerId {
+        PlayerId::new(seat)
+    }
+
+    fn timer(clock: &TestClock) -> TurnTimer<TestClock> {
+        TurnTimer::new(
+            clock.clone(),
+            Duration::from_secs(10),
+            Duration::from_secs(30),
+        )
+    }
+
+    #[test]
+    fn test_time_bank_drains_after_decision_time() {
+        let clock = TestClock::default();
+        let mut timer = timer(&clock);
+        assert_eq!(timer.remaining(), None);
+
+        timer.start_turn(p(1));
+        clock.advance(Duration::from_secs(4));
+        assert_eq!(timer.remaining(), Some(Duration::from_secs(36)));
+        assert_eq!(
+            timer.remaining_decision_time(),
+            Some(Duration::from_secs(6))
+        );
+
+        // Twelve seconds over the decision time
+        clock.advance(Duration::from_secs(18));
+        assert_eq!(timer.end_turn(), Some(Duration::from_secs(22)));
+        assert_eq!(timer.time_bank(p(1)), Duration::from_secs(18));
+        assert_eq!(timer.time_bank(p(0)), Duration::from_secs(30));
+
+        timer.start_turn(p(1));
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(timer.end_turn(), Some(Duration::from_secs(5)));
+        assert_eq!(timer.time_bank(p(1)), Duration::from_secs(18));
+
+        timer.start_turn(p(1));
+        clock.advance(Duration::from_secs(28));
+        assert!(timer.is_expired());
+        assert_eq!(timer.remaining(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_poll_times_out_players() {
+        let clock = TestClock::default();
+        let mut timer = timer(&clock);
+        let mut engine = HandEngine::new(ShowdownSolver::new(CactusKevEvaluator::new()));
+        let setup = HandSetup::new(
+            HandNumber::new(1),
+            vec![Chips::new(1000); 3],
+            p(0),
+            Blinds::new(Chips::new(5), Chips::new(10)),
+        );
+        engine.start_hand(setup, Deck::new()).unwrap();
+
+        assert_eq!(timer.poll(&mut engine), Ok(None));
+        assert_eq!(timer.current_player(), Some(p(0)));
+        clock.advance(Duration::from_secs(39));
+        assert_eq!(timer.poll(&mut engine), Ok(None));
+
+        // The button faces the big blind, so is folded
+        clock.advance(Duration::from_secs(1));
+        let timeout = timer.poll(&mut engine).unwrap().unwrap();
+        assert_eq!((timeout.player, timeout.action), (p(0), Action::Fold));
+        assert_eq!(timer.time_bank(p(0)), Duration::ZERO);
+        assert_eq!(timer.current_player(), Some(p(1)));
+
+        // Acting in time hands the turn on at the next poll
+        engine.act(p(1), Action::Call(Chips::new(5))).unwrap();
+        assert_eq!(timer.poll(&mut engine), Ok(None));
+        assert_eq!(timer.current_player(), Some(p(2)));
+
+        // The big blind can check
+        clock.advance(Duration::from_secs(40));
+        let timeout = timer.poll(&mut engine).unwrap().unwrap();
+        assert_eq!((timeout.player, timeout.action), (p(2), Action::Check));
+        assert_eq!(timer.current_player(), Some(p(1)));
+    }
+}
@@ -0,0 +1,48 @@
+//! Commands accepted by the game and tournament aggregates.
+
+use crate::core::domain::entities::deck::Deck;
+use crate::core::domain::primitives::{Action, Chips, GameId, HandNumber, PlayerId, SessionId};
+
+use super::TournamentConfig;
+
+/// A request to change the state of a game.
+///
+/// Commands are validated by `GameAggregate::handle`; they are never persisted.
+#[derive(Clone, Debug)]
+pub enum GameCommand {
+    /// Start a new hand, posting antes and blinds and dealing hole cards.
+    StartHand {
+        hand_number: HandNumber,
+        /// Starting stack for each seat, indexed by `PlayerId`.
+        stacks: Vec<Chips>,
+        button: PlayerId,
+        small_blind: Chips,
+        big_blind: Chips,
+        /// Ante posted by every player; zero for none.
+        ante: Chips,
+        /// Shuffled deck the hand is dealt from.
+        deck: Deck,
+    },
+    /// A player takes an action.
+    Act { player: PlayerId, action: Action },
+}
+
+/// A request to change the state of a tournament.
+///
+/// Commands are validated by `TournamentAggregate::handle`; they are never persisted.
+#[derive(Clone, Debug)]
+pub enum TournamentCommand {
+    /// Set up the tournament and open registration.
+    Create { config: TournamentConfig },
+    /// Buy a player in.
+    Register { player: SessionId },
+    /// Withdraw a player before the start.
+    Unregister { player: SessionId },
+    /// Close registration and seat every entrant.
+    ///
+    /// Only as many tables as the entrants need are opened, taken in order from
+    /// `tables`.
+    Start { tables: Vec<GameId> },
+    /// Record that a player busted out, then rebalance or break tables as needed.
+    Eliminate { player: SessionId },
+}
@@ -0,0 +1,124 @@
+//! Domain events emitted by the game and tournament aggregates.
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::primitives::{
+    Action, Chips, GameId, HandNumber, PlayerId, SessionId, Street,
+};
+
+use super::TournamentConfig;
+
+/// Something that happened in a game.
+///
+/// Events carry everything needed to rebuild the aggregate, including the deck
+/// order, so replaying a stream is deterministic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GameEvent {
+    /// A new hand began.
+    HandStarted {
+        hand_number: HandNumber,
+        stacks: Vec<Chips>,
+        button: PlayerId,
+        small_blind: Chips,
+        big_blind: Chips,
+        /// Ante posted by every player; zero without antes.
+        ante: Chips,
+        /// Deck order the hand is dealt from.
+        deck: Vec<Card>,
+    },
+    /// A player posted an ante.
+    AntePosted { player: PlayerId, amount: Chips },
+    /// A player posted a forced blind.
+    BlindPosted { player: PlayerId, amount: Chips },
+    /// Hole cards were dealt to every seat, indexed by `PlayerId`.
+    HoleCardsDealt { hole_cards: Vec<[Card; 2]> },
+    /// A player acted.
+    ActionTaken { player: PlayerId, action: Action },
+    /// Community cards for a street were dealt.
+    StreetDealt { street: Street, cards: Vec<Card> },
+    /// Another board was run out after the players agreed to run it more than once.
+    ///
+    /// It shares the cards dealt up to `from`, where betting ended, with the first
+    /// board; the rest were dealt afresh from the deck.
+    RunoutDealt {
+        from: Street,
+        /// The whole five-card board of this runout.
+        board: Vec<Card>,
+    },
+    /// Betting is over: either one player is left or the river betting completed.
+    HandFinished {
+        /// Players who have not folded.
+        players_in_hand: Vec<PlayerId>,
+        pot: Chips,
+    },
+}
+
+impl GameEvent {
+    /// Returns the event name as a string.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::HandStarted { .. } => "HandStarted",
+            Self::AntePosted { .. } => "AntePosted",
+            Self::BlindPosted { .. } => "BlindPosted",
+            Self::HoleCardsDealt { .. } => "HoleCardsDealt",
+            Self::ActionTaken { .. } => "ActionTaken",
+            Self::StreetDealt { .. } => "StreetDealt",
+            Self::RunoutDealt { .. } => "RunoutDealt",
+            Self::HandFinished { .. } => "HandFinished",
+        }
+    }
+}
+
+/// Something that happened in a tournament.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TournamentEvent {
+    /// The tournament was set up and opened for registration.
+    TournamentCreated { config: TournamentConfig },
+    /// A player bought in.
+    PlayerRegistered { player: SessionId },
+    /// A player withdrew before the start and was refunded.
+    PlayerUnregistered { player: SessionId },
+    /// Registration closed and every entrant was seated.
+    TournamentStarted {
+        /// Players at each table, in seating order.
+        tables: Vec<(GameId, Vec<SessionId>)>,
+    },
+    /// A player busted out.
+    PlayerEliminated {
+        player: SessionId,
+        /// Finishing place, counting from 1 for the winner.
+        place: usize,
+        /// Prize awarded for the place; zero outside the money.
+        prize: Chips,
+    },
+    /// A player was moved to another table to keep the tables balanced.
+    PlayerMoved {
+        player: SessionId,
+        from: GameId,
+        to: GameId,
+    },
+    /// An empty table was broken.
+    TableClosed { table: GameId },
+    /// Every remaining player is seated at one table.
+    FinalTableFormed { table: GameId },
+    /// One player is left holding every chip.
+    TournamentFinished { winner: SessionId, prize: Chips },
+}
+
+impl TournamentEvent {
+    /// Returns the event name as a string.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::TournamentCreated { .. } => "TournamentCreated",
+            Self::PlayerRegistered { .. } => "PlayerRegistered",
+            Self::PlayerUnregistered { .. } => "PlayerUnregistered",
+            Self::TournamentStarted { .. } => "TournamentStarted",
+            Self::PlayerEliminated { .. } => "PlayerEliminated",
+            Self::PlayerMoved { .. } => "PlayerMoved",
+            Self::TableClosed { .. } => "TableClosed",
+            Self::FinalTableFormed { .. } => "FinalTableFormed",
+            Self::TournamentFinished { .. } => "TournamentFinished",
+        }
+    }
+}
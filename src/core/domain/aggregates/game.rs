@@ -0,0 +1,947 @@
+//! Event-sourced game aggregate wrapping `Game` and `BettingState`.
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::deck::Deck;
+use crate::core::domain::entities::game::Game;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::errors::GameError;
+use crate::core::domain::primitives::{
+    Action, BettingState, Blinds, Chips, HandNumber, LegalActions, PlayerId, RuleSet, Street,
+    TableSeating,
+};
+
+use super::{ActionRecord, GameCommand, GameEvent, PlayerView};
+
+/// Cards needed beyond the hole cards: three burns and a five-card board.
+const BOARD_AND_BURNS: usize = 8;
+
+/// State of the hand currently being played.
+#[derive(Clone, Debug)]
+struct HandState {
+    hand_number: HandNumber,
+    game: Game,
+    betting: BettingState,
+    button: PlayerId,
+    big_blind: Chips,
+    actions: Vec<ActionRecord>,
+    /// Times the board is to be run out if betting ends with players all-in.
+    runs: u8,
+    /// Boards run out after the first.
+    extra_boards: Vec<Board>,
+    finished: bool,
+}
+
+/// Event-sourced aggregate for a poker game.
+///
+/// `handle` validates a command against the current state and returns the events it
+/// produces without changing anything; `apply` folds a single event into the state.
+/// Rebuilding an aggregate from its stored events therefore yields exactly the state
+/// that produced them.
+///
+/// The aggregate enforces turn order and legal bet sizes, posts blinds, and deals each
+/// street once its betting round is complete. When betting is over it emits
+/// `GameEvent::HandFinished`; resolving the showdown is left to the caller.
+///
+/// Players all-in before the river may agree to run the rest of the board several
+/// times with `run_it_n_times`; each board after the first is a `GameEvent::RunoutDealt`.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::aggregates::{GameAggregate, GameCommand};
+/// use riverrun::core::domain::entities::deck::Deck;
+/// use riverrun::core::domain::primitives::{Action, Chips, HandNumber, PlayerId};
+///
+/// let mut game = GameAggregate::new();
+/// game.execute(&GameCommand::StartHand {
+///     hand_number: HandNumber::new(1),
+///     stacks: vec![Chips::new(1000); 3],
+///     button: PlayerId::new(0),
+///     small_blind: Chips::new(5),
+///     big_blind: Chips::new(10),
+///     ante: Chips::ZERO,
+///     deck: Deck::new(),
+/// })
+/// .unwrap();
+///
+/// // Three-handed, the button is first to act preflop
+/// assert_eq!(game.to_act(), Some(PlayerId::new(0)));
+/// let events = game
+///     .execute(&GameCommand::Act { player: PlayerId::new(0), action: Action::Fold })
+///     .unwrap();
+/// assert_eq!(events.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GameAggregate {
+    hand: Option<HandState>,
+    rules: RuleSet,
+    version: u64,
+}
+
+/// `GameAggregate` - Constructors
+impl GameAggregate {
+    /// Creates an aggregate with no hand in progress.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rebuilds an aggregate by applying `events` in order.
+    #[must_use]
+    pub fn from_events<'a>(events: impl IntoIterator<Item = &'a GameEvent>) -> Self {
+        let mut aggregate = Self::new();
+        for event in events {
+            aggregate.apply(event);
+        }
+        aggregate
+    }
+
+    /// This aggregate enforcing `rules` in every hand instead of uncapped no-limit
+    /// betting.
+    ///
+    /// Rules are table configuration rather than events, so pass the same rules before
+    /// replaying a stored stream.
+    #[must_use]
+    pub const fn with_rules(mut self, rules: RuleSet) -> Self {
+        self.rules = rules;
+        self
+    }
+}
+
+/// `GameAggregate` - Accessors
+impl GameAggregate {
+    /// Rules the table is played under.
+    #[must_use]
+    pub const fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    /// Number of events applied so far.
+    #[must_use]
+    pub const fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns whether a hand has started and betting is not yet over.
+    #[must_use]
+    pub fn is_hand_in_progress(&self) -> bool {
+        self.hand.as_ref().is_some_and(|hand| !hand.finished)
+    }
+
+    /// Number of the current (or last) hand.
+    #[must_use]
+    pub fn hand_number(&self) -> Option<HandNumber> {
+        self.hand.as_ref().map(|hand| hand.hand_number)
+    }
+
+    /// Seat holding the dealer button.
+    #[must_use]
+    pub fn button(&self) -> Option<PlayerId> {
+        self.hand.as_ref().map(|hand| hand.button)
+    }
+
+    /// Every board of the current (or last) hand: the board dealt street by street,
+    /// then any further runouts.
+    #[must_use]
+    pub fn boards(&self) -> Vec<&Board> {
+        self.hand.as_ref().map_or_else(Vec::new, |hand| {
+            std::iter::once(hand.game.board())
+                .chain(&hand.extra_boards)
+                .collect()
+        })
+    }
+
+    /// Cards dealt so far in the current (or last) hand.
+    #[must_use]
+    pub fn game(&self) -> Option<&Game> {
+        self.hand.as_ref().map(|hand| &hand.game)
+    }
+
+    /// Stacks, pots, and betting round of the current (or last) hand.
+    #[must_use]
+    pub fn betting(&self) -> Option<&BettingState> {
+        self.hand.as_ref().map(|hand| &hand.betting)
+    }
+
+    /// Player whose turn it is, if betting is in progress.
+    #[must_use]
+    pub fn to_act(&self) -> Option<PlayerId> {
+        self.hand.as_ref().and_then(|hand| hand.betting.to_act())
+    }
+
+    /// Actions available to the player whose turn it is.
+    ///
+    /// Empty when nobody is due to act.
+    #[must_use]
+    pub fn legal_actions(&self) -> LegalActions {
+        self.hand
+            .as_ref()
+            .and_then(|hand| Some(hand.betting.legal_actions(hand.betting.to_act()?)))
+            .unwrap_or_default()
+    }
+
+    /// What `player` can see of the current (or last) hand: everything public plus
+    /// their own hole cards.
+    ///
+    /// Returns `None` if no hand has started or `player` is not seated.
+    #[must_use]
+    pub fn player_view(&self, player: PlayerId) -> Option<PlayerView> {
+        let hand = self.hand.as_ref()?;
+        let [first, second] = *hand.game.player_hole_cards(player.as_index())?;
+        let betting = &hand.betting;
+        let legal_actions = if betting.to_act() == Some(player) {
+            betting.legal_actions(player)
+        } else {
+            LegalActions::default()
+        };
+        Some(PlayerView {
+            player,
+            hand_number: hand.hand_number,
+            button: hand.button,
+            street: hand.game.street(),
+            hole_cards: HoleCards::new(first, second),
+            board: hand.game.board().cards().to_vec(),
+            stacks: (0..betting.num_players())
+                .map(|seat| betting.stack(PlayerId::from(seat)))
+                .collect(),
+            pot: betting.total_pot(),
+            to_call: betting
+                .current_round()
+                .map_or(Chips::ZERO, |round| round.amount_to_call(player)),
+            big_blind: hand.big_blind,
+            legal_actions,
+            actions: hand.actions.clone(),
+        })
+    }
+}
+
+/// `GameAggregate` - Operations
+impl GameAggregate {
+    /// Validates `command` and returns the events it produces.
+    ///
+    /// The aggregate itself is left unchanged; apply the returned events (or use
+    /// `execute`) to advance it.
+    ///
+    /// # Errors
+    /// Returns a `GameError` describing the violated invariant if the command is
+    /// rejected, e.g. acting out of turn or betting less than the minimum.
+    pub fn handle(&self, command: &GameCommand) -> Result<Vec<GameEvent>, GameError> {
+        match command {
+            GameCommand::StartHand {
+                hand_number,
+                stacks,
+                button,
+                small_blind,
+                big_blind,
+                ante,
+                deck,
+            } => self.handle_start_hand(
+                *hand_number,
+                stacks,
+                *button,
+                Blinds::new(*small_blind, *big_blind).with_ante(*ante),
+                deck,
+            ),
+            GameCommand::Act { player, action } => self.handle_act(*player, *action),
+        }
+    }
+
+    /// Applies a single event to the aggregate state.
+    ///
+    /// Events are facts: they are applied without validation.
+    pub fn apply(&mut self, event: &GameEvent) {
+        self.version += 1;
+
+        if let GameEvent::HandStarted {
+            hand_number,
+            stacks,
+            button,
+            big_blind,
+            deck,
+            ..
+        } = event
+        {
+            let Some(game) = Game::with_deck(stacks.len(), Deck::from_cards(deck.clone())) else {
+                return;
+            };
+            let mut betting = BettingState::new(stacks.clone()).with_rules(self.rules);
+            betting.start_round(*big_blind, true);
+            self.hand = Some(HandState {
+                hand_number: *hand_number,
+                game,
+                betting,
+                button: *button,
+                big_blind: *big_blind,
+                actions: Vec::new(),
+                runs: 1,
+                extra_boards: Vec::new(),
+                finished: false,
+            });
+            return;
+        }
+
+        if let Some(hand) = self.hand.as_mut() {
+            hand.apply(event);
+        }
+    }
+
+    /// Runs the rest of the board `times` times if betting in the hand in progress ends
+    /// with players all-in before the river, e.g. twice to run it twice.
+    ///
+    /// Call it before the action that ends betting. Each extra runout needs fresh cards,
+    /// so fewer are dealt if the deck runs out. The agreement lasts for this hand only,
+    /// and replaying the events does not need it: they hold every runout dealt.
+    ///
+    /// # Errors
+    /// Returns `GameError::NoHandInProgress` if no hand is being played.
+    pub fn run_it_n_times(&mut self, times: u8) -> Result<(), GameError> {
+        let hand = self
+            .hand
+            .as_mut()
+            .filter(|hand| !hand.finished)
+            .ok_or(GameError::NoHandInProgress)?;
+        hand.runs = times.max(1);
+        Ok(())
+    }
+
+    /// Handles `command` and applies the resulting events.
+    ///
+    /// # Errors
+    /// Returns a `GameError` if the command is rejected; the aggregate is unchanged.
+    pub fn execute(&mut self, command: &GameCommand) -> Result<Vec<GameEvent>, GameError> {
+        let events = self.handle(command)?;
+        for event in &events {
+            self.apply(event);
+        }
+        Ok(events)
+    }
+}
+
+/// `GameAggregate` - Command Handlers
+impl GameAggregate {
+    fn handle_start_hand(
+        &self,
+        hand_number: HandNumber,
+        stacks: &[Chips],
+        button: PlayerId,
+        blinds: Blinds,
+        deck: &Deck,
+    ) -> Result<Vec<GameEvent>, GameError> {
+        let Blinds {
+            small_blind,
+            big_blind,
+            ante,
+        } = blinds;
+        if self.is_hand_in_progress() {
+            return Err(GameError::HandInProgress);
+        }
+        let num_players = stacks.len();
+        if !(2..=10).contains(&num_players) {
+            return Err(GameError::InvalidPlayerCount(num_players));
+        }
+        if big_blind.is_zero() || small_blind > big_blind {
+            return Err(GameError::InvalidBlinds {
+                small_blind,
+                big_blind,
+            });
+        }
+        if button.as_index() >= num_players {
+            return Err(GameError::InvalidButton(button));
+        }
+        if let Some(seat) = stacks.iter().position(|stack| stack.is_zero()) {
+            return Err(GameError::EmptyStack(PlayerId::from(seat)));
+        }
+        let required = num_players * 2 + BOARD_AND_BURNS;
+        if deck.remaining() < required {
+            return Err(GameError::InsufficientDeck {
+                required,
+                available: deck.remaining(),
+            });
+        }
+
+        let (small, big) = blind_seats(num_players, button);
+        let mut scratch = self.clone();
+        let mut events = Vec::new();
+        scratch.record(
+            &mut events,
+            GameEvent::HandStarted {
+                hand_number,
+                stacks: stacks.to_vec(),
+                button,
+                small_blind,
+                big_blind,
+                ante,
+                deck: deck.to_vec(),
+            },
+        );
+        if !ante.is_zero() {
+            for (seat, &stack) in stacks.iter().enumerate() {
+                let player = PlayerId::from(seat);
+                scratch.record(
+                    &mut events,
+                    GameEvent::AntePosted {
+                        player,
+                        amount: ante.min(stack),
+                    },
+                );
+            }
+        }
+        for (player, blind) in [(small, small_blind), (big, big_blind)] {
+            let stack = scratch.hand_state().betting.stack(player);
+            if !stack.is_zero() {
+                scratch.record(
+                    &mut events,
+                    GameEvent::BlindPosted {
+                        player,
+                        amount: blind.min(stack),
+                    },
+                );
+            }
+        }
+
+        let mut preview = scratch.hand_state().game.clone();
+        preview.deal_hole_cards();
+        scratch.record(
+            &mut events,
+            GameEvent::HoleCardsDealt {
+                hole_cards: preview.all_hole_cards().to_vec(),
+            },
+        );
+        scratch.settle(&mut events);
+        Ok(events)
+    }
+
+    fn handle_act(&self, player: PlayerId, action: Action) -> Result<Vec<GameEvent>, GameError> {
+        let hand = self
+            .hand
+            .as_ref()
+            .filter(|hand| !hand.finished)
+            .ok_or(GameError::NoHandInProgress)?;
+        hand.betting.validate_action(player, action)?;
+
+        let mut scratch = self.clone();
+        let mut events = Vec::new();
+        scratch.record(&mut events, GameEvent::ActionTaken { player, action });
+        scratch.settle(&mut events);
+        Ok(events)
+    }
+
+    /// Applies `event` and appends it to `events`.
+    fn record(&mut self, events: &mut Vec<GameEvent>, event: GameEvent) {
+        self.apply(&event);
+        events.push(event);
+    }
+
+    /// Deals streets while nobody can act, finishing the hand when betting is over.
+    fn settle(&mut self, events: &mut Vec<GameEvent>) {
+        loop {
+            let hand = self.hand_state();
+            if hand.finished || hand.betting.to_act().is_some() {
+                return;
+            }
+
+            let street = hand.game.street();
+            let next = street.next().filter(|_| hand.betting.players_in_hand() > 1);
+            let Some(next) = next else {
+                if let Some(runout) = hand.next_runout() {
+                    self.record(events, runout);
+                    continue;
+                }
+                let finished = GameEvent::HandFinished {
+                    players_in_hand: hand.players_in_hand(),
+                    pot: hand.betting.total_pot(),
+                };
+                self.record(events, finished);
+                return;
+            };
+
+            let mut preview = hand.game.clone();
+            deal_next_street(&mut preview);
+            let cards = preview.board().cards()[street.card_count()..].to_vec();
+            self.record(events, GameEvent::StreetDealt { street: next, cards });
+        }
+    }
+
+    const fn hand_state(&self) -> &HandState {
+        self.hand.as_ref().expect("hand started before dealing")
+    }
+}
+
+/// `HandState` - Operations
+impl HandState {
+    fn num_players(&self) -> usize {
+        self.betting.num_players()
+    }
+
+    fn players_in_hand(&self) -> Vec<PlayerId> {
+        (0..self.num_players())
+            .map(PlayerId::from)
+            .filter(|&player| !self.betting.has_folded(player))
+            .collect()
+    }
+
+    /// The next extra runout to deal, if the players agreed to run it again and betting
+    /// ended before the river.
+    fn next_runout(&self) -> Option<GameEvent> {
+        if self.players_in_hand().len() < 2 || self.extra_boards.len() + 1 >= usize::from(self.runs)
+        {
+            return None;
+        }
+        let from = self
+            .actions
+            .last()
+            .map_or(Street::Preflop, |action| action.street);
+        if from == Street::River {
+            return None;
+        }
+        let board = self.game.clone().deal_runout(from)?;
+        Some(GameEvent::RunoutDealt {
+            from,
+            board: board.cards().to_vec(),
+        })
+    }
+
+    fn apply(&mut self, event: &GameEvent) {
+        let num_players = self.num_players();
+        match event {
+            GameEvent::HandStarted { .. } => {}
+            GameEvent::AntePosted { player, amount } => self.betting.post_ante(*player, *amount),
+            GameEvent::BlindPosted { player, amount } => self.betting.post_blind(*player, *amount),
+            GameEvent::HoleCardsDealt { .. } => {
+                self.game.deal_hole_cards();
+                let (_, big) = blind_seats(num_players, self.button);
+                self.betting.set_first_to_act(seat_after(big, num_players));
+            }
+            GameEvent::ActionTaken { player, action } => {
+                // Actions were validated when handled; a rejected one leaves the state as is
+                if self.betting.apply_action(*player, *action).is_ok() {
+                    self.actions.push(ActionRecord {
+                        street: self.game.street(),
+                        player: *player,
+                        action: *action,
+                    });
+                }
+            }
+            GameEvent::StreetDealt { street, .. } => {
+                deal_next_street(&mut self.game);
+                self.betting.end_round();
+                self.betting.start_street(*street, self.big_blind);
+                self.betting.set_first_to_act(seat_after(self.button, num_players));
+            }
+            GameEvent::RunoutDealt { from, .. } => {
+                self.extra_boards.extend(self.game.deal_runout(*from));
+            }
+            GameEvent::HandFinished { .. } => {
+                self.betting.end_round();
+                self.finished = true;
+            }
+        }
+    }
+}
+
+/// Returns the small and big blind seats; heads-up, the button posts the small blind.
+fn blind_seats(num_players: usize, button: PlayerId) -> (PlayerId, PlayerId) {
+    let mut seating = TableSeating::full(num_players);
+    seating.set_button(button);
+    seating.blinds().expect("a started hand seats at least two players")
+}
+
+/// Seat immediately clockwise of `player`.
+fn seat_after(player: PlayerId, num_players: usize) -> PlayerId {
+    PlayerId::from((player.as_index() + 1) % num_players)
+}
+
+/// Deals the next street of `game`.
+fn deal_next_street(game: &mut Game) -> bool {
+    match game.street() {
+        Street::Preflop => game.deal_flop(),
+        Street::Flop => game.deal_turn(),
+        Street::Turn => game.deal_river(),
+        Street::River => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::errors::BettingError;
+
+    fn p(seat: u8) -> PlayerId {
+        PlayerId::new(seat)
+    }
+
+    fn start(stacks: &[u64], button: u8) -> GameCommand {
+        GameCommand::StartHand {
+            hand_number: HandNumber::new(1),
+            stacks: stacks.iter().copied().map(Chips::new).collect(),
+            button: p(button),
+            small_blind: Chips::new(5),
+            big_blind: Chips::new(10),
+            ante: Chips::ZERO,
+            deck: Deck::new(),
+        }
+    }
+
+    fn act(game: &mut GameAggregate, seat: u8, action: Action) -> Vec<GameEvent> {
+        game.execute(&GameCommand::Act {
+            player: p(seat),
+            action,
+        })
+        .unwrap()
+    }
+
+    fn started(stacks: &[u64], button: u8) -> GameAggregate {
+        let mut game = GameAggregate::new();
+        game.execute(&start(stacks, button)).unwrap();
+        game
+    }
+
+    #[test]
+    fn test_start_hand_posts_blinds_and_deals() {
+        let mut game = GameAggregate::new();
+        let events = game.execute(&start(&[1000, 1000, 1000, 1000], 0)).unwrap();
+
+        let names: Vec<&str> = events.iter().map(GameEvent::name).collect();
+        assert_eq!(
+            names,
+            vec!["HandStarted", "BlindPosted", "BlindPosted", "HoleCardsDealt"]
+        );
+        assert_eq!(
+            events[1],
+            GameEvent::BlindPosted {
+                player: p(1),
+                amount: Chips::new(5)
+            }
+        );
+        assert_eq!(game.version(), 4);
+        assert_eq!(game.to_act(), Some(p(3)));
+        assert_eq!(game.legal_actions().call, Some(Chips::new(10)));
+        assert_eq!(game.game().unwrap().all_hole_cards().len(), 4);
+        assert_eq!(game.betting().unwrap().total_pot(), Chips::new(15));
+    }
+
+    #[test]
+    fn test_heads_up_button_posts_small_blind_and_acts_first() {
+        let game = started(&[1000, 1000], 1);
+        let betting = game.betting().unwrap();
+        assert_eq!(betting.stack(p(1)), Chips::new(995));
+        assert_eq!(betting.stack(p(0)), Chips::new(990));
+        assert_eq!(game.to_act(), Some(p(1)));
+    }
+
+    #[test]
+    fn test_antes_are_posted_before_blinds() {
+        let mut game = GameAggregate::new();
+        let mut command = start(&[1000, 1000, 3], 0);
+        if let GameCommand::StartHand { ante, .. } = &mut command {
+            *ante = Chips::new(3);
+        }
+        let events = game.execute(&command).unwrap();
+
+        let names: Vec<&str> = events.iter().map(GameEvent::name).collect();
+        assert_eq!(
+            names,
+            vec![
+                "HandStarted",
+                "AntePosted",
+                "AntePosted",
+                "AntePosted",
+                "BlindPosted",
+                "HoleCardsDealt"
+            ]
+        );
+        // Seat 2 went all in with the ante and cannot post the big blind
+        let betting = game.betting().unwrap();
+        assert_eq!(betting.stack(p(2)), Chips::ZERO);
+        assert_eq!(betting.stack(p(1)), Chips::new(992));
+        assert_eq!(betting.total_pot(), Chips::new(14));
+    }
+
+    #[test]
+    fn test_start_hand_validation() {
+        let game = GameAggregate::new();
+        assert_eq!(
+            game.handle(&start(&[1000], 0)).unwrap_err(),
+            GameError::InvalidPlayerCount(1)
+        );
+        assert_eq!(
+            game.handle(&start(&[1000, 1000], 2)).unwrap_err(),
+            GameError::InvalidButton(p(2))
+        );
+        assert_eq!(
+            game.handle(&start(&[1000, 0, 1000], 0)).unwrap_err(),
+            GameError::EmptyStack(p(1))
+        );
+
+        let running = started(&[1000, 1000], 0);
+        assert_eq!(
+            running.handle(&start(&[1000, 1000], 0)).unwrap_err(),
+            GameError::HandInProgress
+        );
+    }
+
+    #[test]
+    fn test_rejects_out_of_turn_action() {
+        let game = started(&[1000, 1000, 1000], 0);
+        let err = game
+            .handle(&GameCommand::Act {
+                player: p(2),
+                action: Action::Fold,
+            })
+            .unwrap_err();
+        assert_eq!(
+            err,
+            GameError::InvalidAction(BettingError::OutOfTurn {
+                expected: p(0),
+                actual: p(2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejects_illegal_bet_sizes() {
+        let game = started(&[1000, 1000, 1000], 0);
+        let attempt = |action| {
+            game.handle(&GameCommand::Act {
+                player: p(0),
+                action,
+            })
+            .unwrap_err()
+        };
+
+        let invalid = GameError::InvalidAction;
+        assert_eq!(
+            attempt(Action::Check),
+            invalid(BettingError::CannotCheck {
+                to_call: Chips::new(10)
+            })
+        );
+        assert_eq!(
+            attempt(Action::Bet(Chips::new(50))),
+            invalid(BettingError::BetFacingBet {
+                current_bet: Chips::new(10)
+            })
+        );
+        assert_eq!(
+            attempt(Action::Raise(Chips::new(15))),
+            invalid(BettingError::BelowMinimum {
+                action: Action::Raise(Chips::new(15)),
+                min: Chips::new(20)
+            })
+        );
+        assert!(matches!(
+            attempt(Action::Call(Chips::new(5))),
+            GameError::InvalidAction(BettingError::WrongAmount { .. })
+        ));
+        assert!(matches!(
+            attempt(Action::AllIn(Chips::new(500))),
+            GameError::InvalidAction(BettingError::WrongAmount { .. })
+        ));
+    }
+
+    #[test]
+    fn test_big_blind_gets_option_then_flop_is_dealt() {
+        let mut game = started(&[1000, 1000, 1000], 0);
+        act(&mut game, 0, Action::Call(Chips::new(10)));
+        act(&mut game, 1, Action::Call(Chips::new(5)));
+        assert_eq!(game.to_act(), Some(p(2)));
+
+        let events = act(&mut game, 2, Action::Check);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            &events[1],
+            GameEvent::StreetDealt { street: Street::Flop, cards } if cards.len() == 3
+        ));
+        assert_eq!(game.game().unwrap().street(), Street::Flop);
+        assert_eq!(game.to_act(), Some(p(1)));
+    }
+
+    #[test]
+    fn test_raise_reopens_action() {
+        let mut game = started(&[1000, 1000, 1000], 0);
+        act(&mut game, 0, Action::Call(Chips::new(10)));
+        act(&mut game, 1, Action::Call(Chips::new(5)));
+        act(&mut game, 2, Action::Raise(Chips::new(40)));
+
+        assert_eq!(game.to_act(), Some(p(0)));
+        act(&mut game, 0, Action::Call(Chips::new(30)));
+        act(&mut game, 1, Action::Fold);
+        assert_eq!(game.game().unwrap().street(), Street::Flop);
+        assert_eq!(game.betting().unwrap().total_pot(), Chips::new(90));
+    }
+
+    #[test]
+    fn test_rules_cap_raises() {
+        let rules = RuleSet::fixed_limit(Blinds::new(Chips::new(5), Chips::new(10)))
+            .with_max_raises(Some(3));
+        let mut game = GameAggregate::new().with_rules(rules);
+        game.execute(&start(&[1000, 1000, 1000], 0)).unwrap();
+        act(&mut game, 0, Action::Raise(Chips::new(20)));
+        act(&mut game, 1, Action::Raise(Chips::new(30)));
+
+        assert_eq!(game.legal_actions().raise, None);
+        let err = game.execute(&GameCommand::Act {
+            player: p(2),
+            action: Action::Raise(Chips::new(40)),
+        });
+        assert_eq!(
+            err,
+            Err(GameError::InvalidAction(BettingError::RaiseCapReached { cap: 3 }))
+        );
+        assert_eq!(game.rules().max_raises(), Some(3));
+    }
+
+    #[test]
+    fn test_everyone_folds_to_big_blind() {
+        let mut game = started(&[1000, 1000, 1000], 0);
+        act(&mut game, 0, Action::Fold);
+        let events = act(&mut game, 1, Action::Fold);
+
+        assert_eq!(
+            events.last(),
+            Some(&GameEvent::HandFinished {
+                players_in_hand: vec![p(2)],
+                pot: Chips::new(15)
+            })
+        );
+        assert!(!game.is_hand_in_progress());
+        assert_eq!(game.to_act(), None);
+        assert!(game.legal_actions().is_empty());
+    }
+
+    #[test]
+    fn test_all_in_runs_out_the_board() {
+        let mut game = started(&[500, 1000], 0);
+        act(&mut game, 0, Action::AllIn(Chips::new(495)));
+        let events = act(&mut game, 1, Action::Call(Chips::new(490)));
+
+        let names: Vec<&str> = events.iter().map(GameEvent::name).collect();
+        assert_eq!(
+            names,
+            vec!["ActionTaken", "StreetDealt", "StreetDealt", "StreetDealt", "HandFinished"]
+        );
+        assert!(game.game().unwrap().is_showdown());
+        assert_eq!(game.betting().unwrap().total_pot(), Chips::new(1000));
+    }
+
+    #[test]
+    fn test_run_it_twice_deals_a_second_board() {
+        let mut game = GameAggregate::new();
+        let mut history = game.execute(&start(&[500, 1000], 0)).unwrap();
+        game.run_it_n_times(2).unwrap();
+        history.extend(act(&mut game, 0, Action::AllIn(Chips::new(495))));
+        let events = act(&mut game, 1, Action::Call(Chips::new(490)));
+        history.extend(events.iter().cloned());
+
+        let names: Vec<&str> = events.iter().map(GameEvent::name).collect();
+        assert_eq!(names[names.len() - 2..], ["RunoutDealt", "HandFinished"]);
+        let boards = game.boards();
+        assert_eq!(boards.len(), 2);
+        assert_eq!(boards[1].len(), 5);
+        assert!(boards[0].cards().iter().all(|card| !boards[1].cards().contains(card)));
+
+        let replayed = GameAggregate::from_events(&history);
+        assert_eq!(replayed.boards(), boards);
+        assert_eq!(
+            game.run_it_n_times(2).unwrap_err(),
+            GameError::NoHandInProgress
+        );
+    }
+
+    #[test]
+    fn test_checked_down_hand_finishes_after_river() {
+        let mut game = started(&[1000, 1000], 0);
+        act(&mut game, 0, Action::Call(Chips::new(5)));
+        act(&mut game, 1, Action::Check);
+        for _ in 0..2 {
+            act(&mut game, 1, Action::Check);
+            act(&mut game, 0, Action::Check);
+        }
+        act(&mut game, 1, Action::Bet(Chips::new(10)));
+        let events = act(&mut game, 0, Action::Call(Chips::new(10)));
+
+        assert_eq!(
+            events.last(),
+            Some(&GameEvent::HandFinished {
+                players_in_hand: vec![p(0), p(1)],
+                pot: Chips::new(40)
+            })
+        );
+    }
+
+    #[test]
+    fn test_fixed_limit_doubles_bets_on_the_turn() {
+        let rules = RuleSet::fixed_limit(Blinds::new(Chips::new(5), Chips::new(10)));
+        let mut game = GameAggregate::new().with_rules(rules);
+        game.execute(&start(&[1000, 1000], 0)).unwrap();
+        act(&mut game, 0, Action::Call(Chips::new(5)));
+        act(&mut game, 1, Action::Check);
+        assert_eq!(game.legal_actions().bet.map(|range| range.max), Some(Chips::new(10)));
+        act(&mut game, 1, Action::Check);
+        act(&mut game, 0, Action::Check);
+
+        assert_eq!(game.game().unwrap().street(), Street::Turn);
+        assert_eq!(game.legal_actions().bet.map(|range| range.min), Some(Chips::new(20)));
+        let err = game.execute(&GameCommand::Act {
+            player: p(1),
+            action: Action::Bet(Chips::new(10)),
+        });
+        assert!(matches!(
+            err,
+            Err(GameError::InvalidAction(BettingError::BelowMinimum { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_replay_rebuilds_state() {
+        let mut game = GameAggregate::new();
+        let mut history = game.execute(&start(&[1000, 1000, 1000], 2)).unwrap();
+        history.extend(act(&mut game, 2, Action::Raise(Chips::new(30))));
+        history.extend(act(&mut game, 0, Action::Call(Chips::new(25))));
+        history.extend(act(&mut game, 1, Action::Call(Chips::new(20))));
+
+        let replayed = GameAggregate::from_events(&history);
+        assert_eq!(replayed.version(), game.version());
+        assert_eq!(replayed.to_act(), game.to_act());
+        assert_eq!(replayed.game().unwrap().board(), game.game().unwrap().board());
+        assert_eq!(
+            replayed.betting().unwrap().total_pot(),
+            game.betting().unwrap().total_pot()
+        );
+    }
+
+    #[test]
+    fn test_player_view_hides_other_hole_cards() {
+        let mut game = started(&[1000, 1000, 1000], 0);
+        act(&mut game, 0, Action::Raise(Chips::new(30)));
+
+        let view = game.player_view(p(1)).unwrap();
+        let hole_cards = game.game().unwrap().player_hole_cards(1).unwrap();
+        assert_eq!(view.hole_cards.cards(), hole_cards);
+        assert_eq!(view.street, Street::Preflop);
+        assert_eq!((view.pot, view.to_call), (Chips::new(45), Chips::new(25)));
+        assert_eq!(view.stack(), Chips::new(995));
+        assert!(view.is_to_act());
+        assert_eq!(view.legal_actions, game.legal_actions());
+        assert_eq!(
+            view.actions,
+            [ActionRecord {
+                street: Street::Preflop,
+                player: p(0),
+                action: Action::Raise(Chips::new(30)),
+            }]
+        );
+
+        // Out of turn there is nothing to do
+        let view = game.player_view(p(2)).unwrap();
+        assert!(!view.is_to_act());
+        assert_eq!(view.to_call, Chips::new(20));
+        assert!(game.player_view(p(3)).is_none());
+
+        act(&mut game, 1, Action::Fold);
+        let view = game.player_view(p(2)).unwrap();
+        assert!(view.has_folded(p(1)) && !view.has_folded(p(0)));
+        assert_eq!(view.actions_on(Street::Preflop).count(), 2);
+        assert_eq!(view.actions_on(Street::Flop).count(), 0);
+    }
+}
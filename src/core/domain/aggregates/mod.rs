@@ -0,0 +1,17 @@
+//! Event-sourced aggregates.
+//!
+//! An aggregate validates commands against its current state, emits the resulting
+//! domain events, and rebuilds its state by applying those events in order. The
+//! events are what gets persisted through the `EventStore` port.
+
+mod commands;
+mod events;
+mod game;
+mod player_view;
+mod tournament;
+
+pub use commands::{GameCommand, TournamentCommand};
+pub use events::{GameEvent, TournamentEvent};
+pub use game::GameAggregate;
+pub use player_view::{ActionRecord, PlayerView};
+pub use tournament::{TournamentAggregate, TournamentConfig, TournamentPhase, TournamentResult};
@@ -0,0 +1,83 @@
+//! What a single player can see of a hand in progress.
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::primitives::{Action, Chips, HandNumber, LegalActions, PlayerId, Street};
+
+/// An action taken during a hand, as every player at the table saw it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ActionRecord {
+    /// Street the action was taken on.
+    pub street: Street,
+    /// Player who acted.
+    pub player: PlayerId,
+    /// What they did.
+    pub action: Action,
+}
+
+/// One player's view of a hand: the public table state plus their own hole cards.
+///
+/// Built by `GameAggregate::player_view`. Other players' hole cards are never
+/// included, so a view can be handed to a bot or sent to a client without leaking
+/// hidden information.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlayerView {
+    /// The player the view belongs to.
+    pub player: PlayerId,
+    /// Number of the hand.
+    pub hand_number: HandNumber,
+    /// Seat holding the dealer button.
+    pub button: PlayerId,
+    /// Current street.
+    pub street: Street,
+    /// The player's own hole cards.
+    pub hole_cards: HoleCards,
+    /// Community cards dealt so far.
+    pub board: Vec<Card>,
+    /// Remaining stack of each seat, indexed by `PlayerId`.
+    pub stacks: Vec<Chips>,
+    /// Chips in all pots, including bets of the current round.
+    pub pot: Chips,
+    /// Amount the player must add to call.
+    pub to_call: Chips,
+    /// Big blind of the hand.
+    pub big_blind: Chips,
+    /// Actions available to the player; empty unless it is their turn.
+    pub legal_actions: LegalActions,
+    /// Every action taken so far in the hand, in order. Blinds and antes are not
+    /// included.
+    pub actions: Vec<ActionRecord>,
+}
+
+/// `PlayerView` - Accessors
+impl PlayerView {
+    /// The player's own remaining stack.
+    #[must_use]
+    pub fn stack(&self) -> Chips {
+        self.stacks
+            .get(self.player.as_index())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the player is due to act.
+    #[must_use]
+    pub const fn is_to_act(&self) -> bool {
+        !self.legal_actions.is_empty()
+    }
+
+    /// Actions taken on `street`, in order.
+    pub fn actions_on(&self, street: Street) -> impl Iterator<Item = &ActionRecord> {
+        self.actions
+            .iter()
+            .filter(move |record| record.street == street)
+    }
+
+    /// Returns whether `player` has folded.
+    #[must_use]
+    pub fn has_folded(&self, player: PlayerId) -> bool {
+        self.actions
+            .iter()
+            .any(|record| record.player == player && record.action == Action::Fold)
+    }
+}
@@ -0,0 +1,849 @@
+//! Event-sourced tournament aggregate: registration, seating, balancing, and payouts.
+
+use crate::core::domain::errors::TournamentError;
+use crate::core::domain::primitives::{Chips, GameId, RoundingMode, SessionId};
+
+use super::{TournamentCommand, TournamentEvent};
+
+/// Basis points making up the whole prize pool.
+const TOTAL_SHARE: u64 = 10_000;
+
+/// Players at one table, in seating order.
+type Table = (GameId, Vec<SessionId>);
+
+/// Rules a tournament is run under.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TournamentConfig {
+    /// Chips each entrant pays into the prize pool.
+    pub buy_in: Chips,
+    /// Chips each entrant starts with.
+    pub starting_stack: Chips,
+    /// Most players seated at one table.
+    pub table_size: usize,
+    /// Share of the prize pool paid to each place, winner first, in basis points.
+    pub payouts: Vec<u32>,
+}
+
+/// `TournamentConfig` - Constructors
+impl TournamentConfig {
+    /// Creates a configuration; see the fields for what each argument means.
+    #[must_use]
+    pub const fn new(
+        buy_in: Chips,
+        starting_stack: Chips,
+        table_size: usize,
+        payouts: Vec<u32>,
+    ) -> Self {
+        Self {
+            buy_in,
+            starting_stack,
+            table_size,
+            payouts,
+        }
+    }
+}
+
+/// `TournamentConfig` - Helpers
+impl TournamentConfig {
+    fn validate(&self) -> Result<(), TournamentError> {
+        if !(2..=10).contains(&self.table_size) {
+            return Err(TournamentError::InvalidTableSize(self.table_size));
+        }
+        let total: u64 = self.payouts.iter().map(|&share| u64::from(share)).sum();
+        if self.payouts.is_empty() || total != TOTAL_SHARE {
+            return Err(TournamentError::InvalidPayouts);
+        }
+        Ok(())
+    }
+}
+
+/// Stage a tournament is in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TournamentPhase {
+    /// No `TournamentCreated` event has been applied.
+    #[default]
+    NotCreated,
+    /// Players can register and unregister.
+    Registering,
+    /// Entrants are seated and being eliminated.
+    Running,
+    /// A winner has been decided.
+    Finished,
+}
+
+/// Finishing place decided for one player.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TournamentResult {
+    pub player: SessionId,
+    /// Place, counting from 1 for the winner.
+    pub place: usize,
+    /// Prize awarded; zero outside the money.
+    pub prize: Chips,
+}
+
+/// Event-sourced aggregate for a multi-table tournament.
+///
+/// Like `GameAggregate`, `handle` validates a command and returns the events it
+/// produces, and `apply` folds an event into the state, so the events can be stored
+/// through the `EventStore` port and the aggregate rebuilt from them. The hands
+/// themselves are played by a `GameAggregate` per table, keyed by the table's
+/// `GameId`; the tournament only hears about the players they knock out.
+///
+/// Entrants are seated round-robin across as few tables as will hold them. After
+/// every elimination the aggregate breaks a table once the rest can absorb its
+/// players, then moves players from the fullest to the shortest table until no two
+/// tables differ by more than one player. The player moved is the one seated most
+/// recently at the fuller table. Once one table is left, `FinalTableFormed` is
+/// emitted, and the last player standing wins.
+///
+/// The prize pool is every entrant's buy-in, split by the configured payout shares.
+/// Shares are rounded down; the winner takes the rounding remainder, along with the
+/// shares of any paid places that had no entrant to fill them.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::aggregates::{
+///     TournamentAggregate, TournamentCommand, TournamentConfig, TournamentEvent,
+/// };
+/// use riverrun::core::domain::primitives::{Chips, GameId, SessionId};
+///
+/// let mut tournament = TournamentAggregate::new();
+/// let config = TournamentConfig::new(Chips::new(100), Chips::new(1500), 2, vec![7000, 3000]);
+/// tournament.execute(&TournamentCommand::Create { config }).unwrap();
+/// for name in ["alice", "bob", "carol"] {
+///     tournament
+///         .execute(&TournamentCommand::Register { player: SessionId::new(name) })
+///         .unwrap();
+/// }
+/// tournament
+///     .execute(&TournamentCommand::Start { tables: vec![GameId::new("t1"), GameId::new("t2")] })
+///     .unwrap();
+/// assert_eq!(tournament.tables().len(), 2);
+///
+/// // Two players fit at one table, so the other table breaks
+/// let events = tournament
+///     .execute(&TournamentCommand::Eliminate { player: SessionId::new("carol") })
+///     .unwrap();
+/// assert!(events.iter().any(|event| matches!(event, TournamentEvent::FinalTableFormed { .. })));
+/// assert_eq!(tournament.payouts(), [Chips::new(210), Chips::new(90)]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TournamentAggregate {
+    config: Option<TournamentConfig>,
+    phase: TournamentPhase,
+    entrants: Vec<SessionId>,
+    tables: Vec<Table>,
+    results: Vec<TournamentResult>,
+    final_table: Option<GameId>,
+    version: u64,
+}
+
+/// `TournamentAggregate` - Constructors
+impl TournamentAggregate {
+    /// Creates an aggregate for a tournament that has not been set up yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rebuilds an aggregate by applying `events` in order.
+    #[must_use]
+    pub fn from_events<'a>(events: impl IntoIterator<Item = &'a TournamentEvent>) -> Self {
+        let mut aggregate = Self::new();
+        for event in events {
+            aggregate.apply(event);
+        }
+        aggregate
+    }
+}
+
+/// `TournamentAggregate` - Accessors
+impl TournamentAggregate {
+    /// Number of events applied so far.
+    #[must_use]
+    pub const fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Stage the tournament is in.
+    #[must_use]
+    pub const fn phase(&self) -> TournamentPhase {
+        self.phase
+    }
+
+    /// Rules the tournament is run under, once created.
+    #[must_use]
+    pub const fn config(&self) -> Option<&TournamentConfig> {
+        self.config.as_ref()
+    }
+
+    /// Every registered player, in registration order.
+    #[must_use]
+    pub fn entrants(&self) -> &[SessionId] {
+        &self.entrants
+    }
+
+    /// Open tables with the players seated at each, in seating order.
+    #[must_use]
+    pub fn tables(&self) -> &[(GameId, Vec<SessionId>)] {
+        &self.tables
+    }
+
+    /// Table `player` is seated at, if they are still in the tournament.
+    #[must_use]
+    pub fn table_of(&self, player: &SessionId) -> Option<&GameId> {
+        self.tables
+            .iter()
+            .find(|(_, players)| players.contains(player))
+            .map(|(table, _)| table)
+    }
+
+    /// Number of players still in the tournament.
+    #[must_use]
+    pub fn players_remaining(&self) -> usize {
+        match self.phase {
+            TournamentPhase::NotCreated => 0,
+            TournamentPhase::Registering => self.entrants.len(),
+            TournamentPhase::Running => self.tables.iter().map(|(_, players)| players.len()).sum(),
+            TournamentPhase::Finished => 1,
+        }
+    }
+
+    /// The last table standing, once every remaining player fits at it.
+    #[must_use]
+    pub const fn final_table(&self) -> Option<&GameId> {
+        self.final_table.as_ref()
+    }
+
+    /// Places decided so far, in the order they were decided; the winner is last.
+    #[must_use]
+    pub fn results(&self) -> &[TournamentResult] {
+        &self.results
+    }
+
+    /// Every entrant's buy-in.
+    #[must_use]
+    pub fn prize_pool(&self) -> Chips {
+        self.config.as_ref().map_or(Chips::ZERO, |config| {
+            Chips::new(
+                config
+                    .buy_in
+                    .value()
+                    .saturating_mul(self.entrants.len() as u64),
+            )
+        })
+    }
+
+    /// Prize for each paid place, winner first.
+    ///
+    /// Only places that entrants can fill are paid; their prizes always add up to the
+    /// whole prize pool.
+    #[must_use]
+    pub fn payouts(&self) -> Vec<Chips> {
+        let Some(config) = self.config.as_ref() else {
+            return Vec::new();
+        };
+        let pool = self.prize_pool();
+        let places = config.payouts.len().min(self.entrants.len().max(1));
+        let mut prizes: Vec<Chips> = config.payouts[..places]
+            .iter()
+            .map(|&share| pool.mul_ratio(u64::from(share), TOTAL_SHARE, RoundingMode::Down))
+            .collect();
+        let paid = prizes
+            .iter()
+            .fold(Chips::ZERO, |total, &prize| total + prize);
+        prizes[0] += pool.saturating_sub(paid);
+        prizes
+    }
+
+    /// Prize for finishing in `place`, counting from 1; zero outside the money.
+    #[must_use]
+    pub fn prize_for(&self, place: usize) -> Chips {
+        place
+            .checked_sub(1)
+            .and_then(|index| self.payouts().get(index).copied())
+            .unwrap_or(Chips::ZERO)
+    }
+}
+
+/// `TournamentAggregate` - Operations
+impl TournamentAggregate {
+    /// Validates `command` and returns the events it produces.
+    ///
+    /// The aggregate itself is left unchanged; apply the returned events (or use
+    /// `execute`) to advance it.
+    ///
+    /// # Errors
+    /// Returns a `TournamentError` describing the violated invariant if the command is
+    /// rejected, e.g. registering after the start or eliminating an unknown player.
+    pub fn handle(
+        &self,
+        command: &TournamentCommand,
+    ) -> Result<Vec<TournamentEvent>, TournamentError> {
+        match command {
+            TournamentCommand::Create { config } => self.handle_create(config),
+            TournamentCommand::Register { player } => self.handle_register(player),
+            TournamentCommand::Unregister { player } => self.handle_unregister(player),
+            TournamentCommand::Start { tables } => self.handle_start(tables),
+            TournamentCommand::Eliminate { player } => self.handle_eliminate(player),
+        }
+    }
+
+    /// Applies a single event to the aggregate state.
+    ///
+    /// Events are facts: they are applied without validation.
+    pub fn apply(&mut self, event: &TournamentEvent) {
+        self.version += 1;
+        match event {
+            TournamentEvent::TournamentCreated { config } => {
+                self.config = Some(config.clone());
+                self.phase = TournamentPhase::Registering;
+            }
+            TournamentEvent::PlayerRegistered { player } => self.entrants.push(player.clone()),
+            TournamentEvent::PlayerUnregistered { player } => {
+                self.entrants.retain(|entrant| entrant != player);
+            }
+            TournamentEvent::TournamentStarted { tables } => {
+                self.tables.clone_from(tables);
+                self.phase = TournamentPhase::Running;
+            }
+            TournamentEvent::PlayerEliminated {
+                player,
+                place,
+                prize,
+            } => {
+                remove_player(&mut self.tables, player);
+                self.results.push(TournamentResult {
+                    player: player.clone(),
+                    place: *place,
+                    prize: *prize,
+                });
+            }
+            TournamentEvent::PlayerMoved { player, from, to } => {
+                move_player(&mut self.tables, player, from, to);
+            }
+            TournamentEvent::TableClosed { table } => {
+                self.tables.retain(|(id, _)| id != table);
+            }
+            TournamentEvent::FinalTableFormed { table } => self.final_table = Some(table.clone()),
+            TournamentEvent::TournamentFinished { winner, prize } => {
+                self.results.push(TournamentResult {
+                    player: winner.clone(),
+                    place: 1,
+                    prize: *prize,
+                });
+                self.phase = TournamentPhase::Finished;
+            }
+        }
+    }
+
+    /// Handles `command` and applies the resulting events.
+    ///
+    /// # Errors
+    /// Returns a `TournamentError` if the command is rejected; the aggregate is
+    /// unchanged.
+    pub fn execute(
+        &mut self,
+        command: &TournamentCommand,
+    ) -> Result<Vec<TournamentEvent>, TournamentError> {
+        let events = self.handle(command)?;
+        for event in &events {
+            self.apply(event);
+        }
+        Ok(events)
+    }
+}
+
+/// `TournamentAggregate` - Command Handlers
+impl TournamentAggregate {
+    fn handle_create(
+        &self,
+        config: &TournamentConfig,
+    ) -> Result<Vec<TournamentEvent>, TournamentError> {
+        if self.phase != TournamentPhase::NotCreated {
+            return Err(TournamentError::AlreadyCreated);
+        }
+        config.validate()?;
+        Ok(vec![TournamentEvent::TournamentCreated {
+            config: config.clone(),
+        }])
+    }
+
+    fn handle_register(&self, player: &SessionId) -> Result<Vec<TournamentEvent>, TournamentError> {
+        self.require_registration()?;
+        if self.entrants.contains(player) {
+            return Err(TournamentError::AlreadyRegistered(player.clone()));
+        }
+        Ok(vec![TournamentEvent::PlayerRegistered {
+            player: player.clone(),
+        }])
+    }
+
+    fn handle_unregister(
+        &self,
+        player: &SessionId,
+    ) -> Result<Vec<TournamentEvent>, TournamentError> {
+        self.require_registration()?;
+        if !self.entrants.contains(player) {
+            return Err(TournamentError::NotRegistered(player.clone()));
+        }
+        Ok(vec![TournamentEvent::PlayerUnregistered {
+            player: player.clone(),
+        }])
+    }
+
+    fn handle_start(&self, tables: &[GameId]) -> Result<Vec<TournamentEvent>, TournamentError> {
+        self.require_registration()?;
+        let config = self.config.as_ref().ok_or(TournamentError::NotCreated)?;
+        let entrants = self.entrants.len();
+        if entrants < 2 {
+            return Err(TournamentError::NotEnoughEntrants(entrants));
+        }
+        let required = entrants.div_ceil(config.table_size);
+        if tables.len() < required {
+            return Err(TournamentError::NotEnoughTables {
+                required,
+                available: tables.len(),
+            });
+        }
+        if let Some(duplicate) = tables
+            .iter()
+            .enumerate()
+            .find_map(|(i, table)| tables[..i].contains(table).then_some(table))
+        {
+            return Err(TournamentError::DuplicateTable(duplicate.clone()));
+        }
+
+        let mut seating: Vec<Table> = tables[..required]
+            .iter()
+            .map(|table| (table.clone(), Vec::new()))
+            .collect();
+        for (i, player) in self.entrants.iter().enumerate() {
+            seating[i % required].1.push(player.clone());
+        }
+        let mut events = vec![TournamentEvent::TournamentStarted { tables: seating }];
+        if required == 1 {
+            events.push(TournamentEvent::FinalTableFormed {
+                table: tables[0].clone(),
+            });
+        }
+        Ok(events)
+    }
+
+    fn handle_eliminate(
+        &self,
+        player: &SessionId,
+    ) -> Result<Vec<TournamentEvent>, TournamentError> {
+        match self.phase {
+            TournamentPhase::NotCreated => return Err(TournamentError::NotCreated),
+            TournamentPhase::Registering | TournamentPhase::Finished => {
+                return Err(TournamentError::NotRunning);
+            }
+            TournamentPhase::Running => {}
+        }
+        if self.table_of(player).is_none() {
+            return Err(TournamentError::NotRegistered(player.clone()));
+        }
+
+        let place = self.players_remaining();
+        let mut events = vec![TournamentEvent::PlayerEliminated {
+            player: player.clone(),
+            place,
+            prize: self.prize_for(place),
+        }];
+        let mut tables = self.tables.clone();
+        remove_player(&mut tables, player);
+
+        if place == 2 {
+            let winner = tables.iter().flat_map(|(_, players)| players).next();
+            if let Some(winner) = winner {
+                events.push(TournamentEvent::TournamentFinished {
+                    winner: winner.clone(),
+                    prize: self.prize_for(1),
+                });
+            }
+        } else if let Some(config) = self.config.as_ref() {
+            rebalance(&mut tables, config.table_size, &mut events);
+        }
+        Ok(events)
+    }
+
+    const fn require_registration(&self) -> Result<(), TournamentError> {
+        match self.phase {
+            TournamentPhase::NotCreated => Err(TournamentError::NotCreated),
+            TournamentPhase::Registering => Ok(()),
+            TournamentPhase::Running | TournamentPhase::Finished => {
+                Err(TournamentError::RegistrationClosed)
+            }
+        }
+    }
+}
+
+/// Breaks and balances `tables` after an elimination, recording every move in `events`.
+fn rebalance(tables: &mut Vec<Table>, table_size: usize, events: &mut Vec<TournamentEvent>) {
+    loop {
+        let remaining: usize = tables.iter().map(|(_, players)| players.len()).sum();
+        if tables.len() > 1 && remaining <= (tables.len() - 1) * table_size {
+            // Break the shortest table, the latest opened among equals
+            let closing = tables
+                .iter()
+                .enumerate()
+                .rev()
+                .min_by_key(|(_, (_, players))| players.len())
+                .map_or(0, |(i, _)| i);
+            let (table, players) = tables.remove(closing);
+            for player in players {
+                let to = shortest_table(tables);
+                tables[to].1.push(player.clone());
+                events.push(TournamentEvent::PlayerMoved {
+                    player,
+                    from: table.clone(),
+                    to: tables[to].0.clone(),
+                });
+            }
+            events.push(TournamentEvent::TableClosed { table });
+            if let [(table, _)] = tables.as_slice() {
+                events.push(TournamentEvent::FinalTableFormed {
+                    table: table.clone(),
+                });
+            }
+            continue;
+        }
+
+        let fullest = tables
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, (_, players))| players.len())
+            .map_or(0, |(i, _)| i);
+        let shortest = shortest_table(tables);
+        if tables[fullest].1.len() <= tables[shortest].1.len() + 1 {
+            return;
+        }
+        let Some(player) = tables[fullest].1.pop() else {
+            return;
+        };
+        tables[shortest].1.push(player.clone());
+        events.push(TournamentEvent::PlayerMoved {
+            player,
+            from: tables[fullest].0.clone(),
+            to: tables[shortest].0.clone(),
+        });
+    }
+}
+
+/// Index of the table with the fewest players, the earliest opened among equals.
+fn shortest_table(tables: &[Table]) -> usize {
+    tables
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, (_, players))| players.len())
+        .map_or(0, |(i, _)| i)
+}
+
+fn remove_player(tables: &mut [Table], player: &SessionId) {
+    for (_, players) in tables {
+        players.retain(|seated| seated != player);
+    }
+}
+
+fn move_player(tables: &mut [Table], player: &SessionId, from: &GameId, to: &GameId) {
+    if let Some((_, players)) = tables.iter_mut().find(|(table, _)| table == from) {
+        players.retain(|seated| seated != player);
+    }
+    if let Some((_, players)) = tables.iter_mut().find(|(table, _)| table == to) {
+        players.push(player.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryEventStore;
+    use crate::core::ports::outbound::EventStore;
+
+    fn player(i: usize) -> SessionId {
+        SessionId::new(format!("p{i}"))
+    }
+
+    fn table(i: usize) -> GameId {
+        GameId::new(format!("t{i}"))
+    }
+
+    fn config(table_size: usize) -> TournamentConfig {
+        TournamentConfig::new(
+            Chips::new(100),
+            Chips::new(1500),
+            table_size,
+            vec![5000, 3000, 2000],
+        )
+    }
+
+    fn registered(entrants: usize, table_size: usize) -> TournamentAggregate {
+        let mut tournament = TournamentAggregate::new();
+        tournament
+            .execute(&TournamentCommand::Create {
+                config: config(table_size),
+            })
+            .unwrap();
+        for i in 0..entrants {
+            tournament
+                .execute(&TournamentCommand::Register { player: player(i) })
+                .unwrap();
+        }
+        tournament
+    }
+
+    fn started(entrants: usize, table_size: usize) -> TournamentAggregate {
+        let mut tournament = registered(entrants, table_size);
+        tournament
+            .execute(&TournamentCommand::Start {
+                tables: (0..10).map(table).collect(),
+            })
+            .unwrap();
+        tournament
+    }
+
+    fn eliminate(tournament: &mut TournamentAggregate, i: usize) -> Vec<TournamentEvent> {
+        tournament
+            .execute(&TournamentCommand::Eliminate { player: player(i) })
+            .unwrap()
+    }
+
+    fn table_sizes(tournament: &TournamentAggregate) -> Vec<usize> {
+        tournament
+            .tables()
+            .iter()
+            .map(|(_, players)| players.len())
+            .collect()
+    }
+
+    #[test]
+    fn test_create_validation() {
+        let tournament = TournamentAggregate::new();
+        let create = |table_size, payouts| TournamentCommand::Create {
+            config: TournamentConfig::new(Chips::new(100), Chips::new(1500), table_size, payouts),
+        };
+        assert_eq!(
+            tournament.handle(&create(11, vec![10_000])).unwrap_err(),
+            TournamentError::InvalidTableSize(11)
+        );
+        assert_eq!(
+            tournament.handle(&create(9, vec![6000, 3000])).unwrap_err(),
+            TournamentError::InvalidPayouts
+        );
+        assert_eq!(
+            tournament.handle(&create(9, Vec::new())).unwrap_err(),
+            TournamentError::InvalidPayouts
+        );
+        assert_eq!(
+            tournament
+                .handle(&TournamentCommand::Register { player: player(0) })
+                .unwrap_err(),
+            TournamentError::NotCreated
+        );
+        assert_eq!(
+            registered(0, 9)
+                .handle(&create(9, vec![10_000]))
+                .unwrap_err(),
+            TournamentError::AlreadyCreated
+        );
+    }
+
+    #[test]
+    fn test_registration() {
+        let mut tournament = registered(3, 9);
+        assert_eq!(tournament.phase(), TournamentPhase::Registering);
+        assert_eq!(
+            tournament
+                .handle(&TournamentCommand::Register { player: player(1) })
+                .unwrap_err(),
+            TournamentError::AlreadyRegistered(player(1))
+        );
+
+        tournament
+            .execute(&TournamentCommand::Unregister { player: player(1) })
+            .unwrap();
+        assert_eq!(tournament.entrants(), [player(0), player(2)]);
+        assert_eq!(tournament.prize_pool(), Chips::new(200));
+        assert_eq!(
+            tournament
+                .handle(&TournamentCommand::Unregister { player: player(1) })
+                .unwrap_err(),
+            TournamentError::NotRegistered(player(1))
+        );
+
+        let started = started(2, 9);
+        assert_eq!(
+            started
+                .handle(&TournamentCommand::Register { player: player(5) })
+                .unwrap_err(),
+            TournamentError::RegistrationClosed
+        );
+    }
+
+    #[test]
+    fn test_start_seats_entrants_evenly() {
+        let tournament = started(20, 9);
+        assert_eq!(tournament.phase(), TournamentPhase::Running);
+        assert_eq!(table_sizes(&tournament), [7, 7, 6]);
+        assert_eq!(tournament.table_of(&player(4)), Some(&table(1)));
+        assert_eq!(tournament.final_table(), None);
+
+        let single = started(5, 9);
+        assert_eq!(single.final_table(), Some(&table(0)));
+
+        let short = registered(20, 9);
+        assert_eq!(
+            short
+                .handle(&TournamentCommand::Start {
+                    tables: vec![table(0), table(1)]
+                })
+                .unwrap_err(),
+            TournamentError::NotEnoughTables {
+                required: 3,
+                available: 2
+            }
+        );
+        assert_eq!(
+            short
+                .handle(&TournamentCommand::Start {
+                    tables: vec![table(0), table(1), table(0)]
+                })
+                .unwrap_err(),
+            TournamentError::DuplicateTable(table(0))
+        );
+        assert_eq!(
+            registered(1, 9)
+                .handle(&TournamentCommand::Start {
+                    tables: vec![table(0)]
+                })
+                .unwrap_err(),
+            TournamentError::NotEnoughEntrants(1)
+        );
+    }
+
+    #[test]
+    fn test_eliminations_balance_and_break_tables() {
+        // Round-robin seating: t0 gets p0, p3, p6, p9; t1 p1, p4, p7; t2 p2, p5, p8
+        let mut tournament = started(10, 4);
+        assert_eq!(table_sizes(&tournament), [4, 3, 3]);
+
+        // Losing p1 leaves 4, 2, 3, so the last player seated at t0 moves over
+        let events = eliminate(&mut tournament, 1);
+        assert_eq!(
+            events[1],
+            TournamentEvent::PlayerMoved {
+                player: player(9),
+                from: table(0),
+                to: table(1)
+            }
+        );
+        assert_eq!(table_sizes(&tournament), [3, 3, 3]);
+
+        // Eight players fit at two tables of four, so the shortest table breaks
+        let events = eliminate(&mut tournament, 4);
+        let names: Vec<&str> = events.iter().map(TournamentEvent::name).collect();
+        assert_eq!(
+            names,
+            ["PlayerEliminated", "PlayerMoved", "PlayerMoved", "TableClosed"]
+        );
+        assert_eq!(table_sizes(&tournament), [4, 4]);
+        assert_eq!(tournament.table_of(&player(7)), Some(&table(0)));
+        assert_eq!(tournament.table_of(&player(9)), Some(&table(2)));
+
+        // Down to four, everyone fits at the final table
+        for i in [0, 2, 3] {
+            eliminate(&mut tournament, i);
+            assert_eq!(tournament.final_table(), None);
+        }
+        let events = eliminate(&mut tournament, 5);
+        assert_eq!(
+            events.last(),
+            Some(&TournamentEvent::FinalTableFormed { table: table(0) })
+        );
+        assert_eq!(tournament.tables().len(), 1);
+        assert_eq!(tournament.final_table(), Some(&table(0)));
+        assert_eq!(tournament.players_remaining(), 4);
+    }
+
+    #[test]
+    fn test_payouts_and_finish() {
+        let mut tournament = started(3, 9);
+        assert_eq!(tournament.prize_pool(), Chips::new(300));
+        assert_eq!(
+            tournament.payouts(),
+            [Chips::new(150), Chips::new(90), Chips::new(60)]
+        );
+
+        eliminate(&mut tournament, 2);
+        let events = eliminate(&mut tournament, 0);
+        assert_eq!(
+            events[1],
+            TournamentEvent::TournamentFinished {
+                winner: player(1),
+                prize: Chips::new(150)
+            }
+        );
+        assert_eq!(tournament.phase(), TournamentPhase::Finished);
+        let results: Vec<(usize, Chips)> = tournament
+            .results()
+            .iter()
+            .map(|result| (result.place, result.prize))
+            .collect();
+        assert_eq!(
+            results,
+            [
+                (3, Chips::new(60)),
+                (2, Chips::new(90)),
+                (1, Chips::new(150))
+            ]
+        );
+        assert_eq!(
+            tournament
+                .handle(&TournamentCommand::Eliminate { player: player(1) })
+                .unwrap_err(),
+            TournamentError::NotRunning
+        );
+    }
+
+    #[test]
+    fn test_unfilled_places_go_to_the_winner() {
+        let tournament = started(2, 9);
+        assert_eq!(tournament.payouts(), [Chips::new(140), Chips::new(60)]);
+        assert_eq!(tournament.prize_for(3), Chips::ZERO);
+    }
+
+    #[test]
+    fn test_events_round_trip_through_store() {
+        let store = InMemoryEventStore::new();
+        let id = GameId::from("tournament-1");
+        let mut source = TournamentAggregate::new();
+        let mut commands = vec![TournamentCommand::Create { config: config(6) }];
+        commands.extend((0..12).map(|i| TournamentCommand::Register { player: player(i) }));
+        commands.push(TournamentCommand::Start {
+            tables: vec![table(0), table(1)],
+        });
+        commands.extend([3, 7, 9].map(|i| TournamentCommand::Eliminate { player: player(i) }));
+
+        for command in &commands {
+            let expected_version = source.version();
+            let events = source.execute(command).unwrap();
+            store.append(&id, events, expected_version).unwrap();
+        }
+
+        let events: Vec<TournamentEvent> = store
+            .load(&id)
+            .unwrap()
+            .into_iter()
+            .map(|stored| stored.event)
+            .collect();
+        let loaded = TournamentAggregate::from_events(&events);
+        assert_eq!(loaded.version(), source.version());
+        assert_eq!(loaded.phase(), TournamentPhase::Running);
+        assert_eq!(loaded.tables(), source.tables());
+        assert_eq!(loaded.results(), source.results());
+        assert_eq!(table_sizes(&loaded), [5, 4]);
+    }
+}
@@ -1,6 +1,11 @@
 //! Community board cards for Texas Hold'em.
 
-use super::card::Card;
+use std::str::FromStr;
+
+use super::card::{Card, ParseCardError, parse_cards};
+use super::card_display::{CardsDisplay, DisplayStyle};
+use super::card_set::CardSet;
+use crate::core::domain::errors::CardError;
 use crate::core::domain::primitives::Street;
 
 /// The community board cards (flop, turn, river).
@@ -32,8 +37,35 @@ impl Board {
     /// count is valid, `None` otherwise.
     #[must_use]
     pub fn with_cards(cards: Vec<Card>) -> Option<Self> {
+        let street = Street::from_card_count(cards.len())?;
+        Some(Self { cards, street })
+    }
+
+    /// Constructs a Board from `cards` like `with_cards`, reporting why they are invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidBoardSize` if there are not 0, 3, 4, or 5 cards, and
+    /// `CardError::DuplicateCard` if a card appears twice.
+    pub fn try_with_cards(cards: Vec<Card>) -> Result<Self, CardError> {
+        let street =
+            Street::from_card_count(cards.len()).ok_or(CardError::InvalidBoardSize(cards.len()))?;
+        let mut seen = CardSet::empty();
+        if let Some(&card) = cards.iter().find(|&&card| !seen.insert(card)) {
+            return Err(CardError::DuplicateCard(card));
+        }
+        Ok(Self { cards, street })
+    }
+
+    /// Constructs a partial Board of up to five cards, such as the one or two exposed
+    /// cards of stud-like games.
+    ///
+    /// The street is the latest one whose cards are all present, so one or two cards
+    /// leave the board at Preflop. Returns `None` for more than five cards.
+    #[must_use]
+    pub fn partial(cards: Vec<Card>) -> Option<Self> {
         let street = match cards.len() {
-            0 => Street::Preflop,
+            0..=2 => Street::Preflop,
             3 => Street::Flop,
             4 => Street::Turn,
             5 => Street::River,
@@ -45,6 +77,12 @@ impl Board {
 
 /// Accessors
 impl Board {
+    /// Renders the board like its `Display` output in `style`, e.g. `[Q♠ J♠ 2♥]`.
+    #[must_use]
+    pub fn display(&self, style: DisplayStyle) -> CardsDisplay<'_> {
+        CardsDisplay::bracketed(&self.cards, style)
+    }
+
     /// Accesses all cards on the board.
     ///
     /// Returns a slice of cards currently on the board.
@@ -123,9 +161,9 @@ impl Board {
 /// Operations
 impl Board {
     /// Deal the flop cards.
-    /// Returns false if not at preflop stage.
+    /// Returns false if not at preflop stage or if the board is partial.
     pub fn deal_flop(&mut self, c1: Card, c2: Card, c3: Card) -> bool {
-        if self.street != Street::Preflop {
+        if self.street != Street::Preflop || !self.cards.is_empty() {
             return false;
         }
         self.cards.push(c1);
@@ -185,6 +223,18 @@ impl std::fmt::Display for Board {
     }
 }
 
+impl FromStr for Board {
+    type Err = ParseCardError;
+
+    /// Parses a board such as `"Qs Js 2h"`, `"Qs,Js,2h"`, or `"QsJs2h"`.
+    ///
+    /// Returns `ParseCardError::InvalidCount` unless there are 0, 3, 4, or 5 cards, and
+    /// `ParseCardError::DuplicateCard` if a card appears twice.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::try_with_cards(parse_cards(s)?)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -327,6 +377,22 @@ mod tests {
         .is_none());
     }
 
+    #[test]
+    fn test_try_with_cards() {
+        let ace = card(Rank::Ace, Suit::Spades);
+        let king = card(Rank::King, Suit::Hearts);
+        assert_eq!(
+            Board::try_with_cards(vec![ace, king]),
+            Err(CardError::InvalidBoardSize(2))
+        );
+        assert_eq!(
+            Board::try_with_cards(vec![ace, king, ace]),
+            Err(CardError::DuplicateCard(ace))
+        );
+        let queen = card(Rank::Queen, Suit::Diamonds);
+        assert_eq!(Board::try_with_cards(vec![ace, king, queen]), Ok(make_flop()));
+    }
+
     #[test]
     fn test_cards_accessor() {
         let board = make_flop();
@@ -356,4 +422,37 @@ mod tests {
         assert!(board.is_empty());
         assert_eq!(board.street(), Street::Preflop);
     }
+
+    #[test]
+    fn test_partial_board() {
+        let mut board = Board::partial(vec![
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+        ])
+        .unwrap();
+        assert_eq!(board.len(), 2);
+        assert_eq!(board.street(), Street::Preflop);
+        // A flop cannot be dealt on top of partial cards
+        assert!(!board.deal_flop(
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Three, Suit::Clubs),
+            card(Rank::Four, Suit::Clubs),
+        ));
+        assert_eq!(Board::partial(make_flop().cards().to_vec()), Some(make_flop()));
+        assert!(Board::partial(vec![card(Rank::Two, Suit::Clubs); 6]).is_none());
+    }
+    #[test]
+    fn test_from_str() {
+        let queen = card(Rank::Queen, Suit::Spades);
+        let jack = card(Rank::Jack, Suit::Spades);
+        let two = card(Rank::Two, Suit::Hearts);
+        let flop = Board::with_cards(vec![queen, jack, two]).unwrap();
+        for text in ["Qs Js 2h", "Qs,Js,2h", "QsJs2h"] {
+            assert_eq!(text.parse(), Ok(flop.clone()));
+        }
+        assert_eq!("".parse(), Ok(Board::new()));
+        assert_eq!("Qs Js".parse::<Board>(), Err(ParseCardError::InvalidCount(2)));
+        assert_eq!("Qs Js Qs".parse::<Board>(), Err(ParseCardError::DuplicateCard(queen)));
+        assert_eq!("Qs Js 2".parse::<Board>(), Err(ParseCardError::InvalidLength));
+    }
 }
@@ -16,6 +16,8 @@
 use std::fmt;
 use std::str::FromStr;
 
+use super::card_display::{CardDisplay, DisplayStyle};
+
 /// 13 prime numbers mapped to card ranks (2-A).
 pub const PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
 
@@ -60,6 +62,23 @@ impl Suit {
         SUIT_CHARS[self as usize]
     }
 
+    /// Unicode symbol of the suit: `♣`, `♦`, `♥`, or `♠`.
+    #[must_use]
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::Clubs => '♣',
+            Self::Diamonds => '♦',
+            Self::Hearts => '♥',
+            Self::Spades => '♠',
+        }
+    }
+
+    /// Whether the suit is printed in red: hearts and diamonds.
+    #[must_use]
+    pub const fn is_red(self) -> bool {
+        matches!(self, Self::Hearts | Self::Diamonds)
+    }
+
     /// Get the bit mask for this suit in the Cactus Kev card encoding.
     ///
     /// The mask has a single bit set at position (`suit_index` + 12).
@@ -158,6 +177,46 @@ impl Rank {
         RANK_CHARS[self as usize]
     }
 
+    /// English name of the rank, for example `"Ace"` or `"Six"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Two => "Two",
+            Self::Three => "Three",
+            Self::Four => "Four",
+            Self::Five => "Five",
+            Self::Six => "Six",
+            Self::Seven => "Seven",
+            Self::Eight => "Eight",
+            Self::Nine => "Nine",
+            Self::Ten => "Ten",
+            Self::Jack => "Jack",
+            Self::Queen => "Queen",
+            Self::King => "King",
+            Self::Ace => "Ace",
+        }
+    }
+
+    /// Plural English name of the rank, for example `"Aces"` or `"Sixes"`.
+    #[must_use]
+    pub const fn plural(self) -> &'static str {
+        match self {
+            Self::Two => "Twos",
+            Self::Three => "Threes",
+            Self::Four => "Fours",
+            Self::Five => "Fives",
+            Self::Six => "Sixes",
+            Self::Seven => "Sevens",
+            Self::Eight => "Eights",
+            Self::Nine => "Nines",
+            Self::Ten => "Tens",
+            Self::Jack => "Jacks",
+            Self::Queen => "Queens",
+            Self::King => "Kings",
+            Self::Ace => "Aces",
+        }
+    }
+
     /// Returns the prime number associated with this rank.
     ///
     /// The prime numbers are the canonical values used by the Cactus Kev encoding for hand evaluation.
@@ -228,6 +287,10 @@ pub enum ParseCardError {
     InvalidLength,
     InvalidRank,
     InvalidSuit,
+    /// The wrong number of cards was given, e.g. three for hole cards.
+    InvalidCount(usize),
+    /// The card was given more than once.
+    DuplicateCard(Card),
 }
 
 impl fmt::Display for ParseCardError {
@@ -237,6 +300,8 @@ impl fmt::Display for ParseCardError {
             Self::InvalidLength => write!(f, "card string must be exactly 2 characters"),
             Self::InvalidRank => write!(f, "invalid rank character"),
             Self::InvalidSuit => write!(f, "invalid suit character"),
+            Self::InvalidCount(count) => write!(f, "wrong number of cards: {count}"),
+            Self::DuplicateCard(card) => write!(f, "duplicate card: {card}"),
         }
     }
 }
@@ -318,6 +383,12 @@ impl Card {
 
 /// Card - Accessors
 impl Card {
+    /// Renders the card in `style`, e.g. `A♠` for `DisplayStyle::Unicode`.
+    #[must_use]
+    pub const fn display(self, style: DisplayStyle) -> CardDisplay {
+        CardDisplay::new(self, style)
+    }
+
     /// Accesses the card's underlying 32-bit Cactus Kev encoding.
     #[must_use]
     pub const fn raw(&self) -> u32 {
@@ -472,6 +543,45 @@ impl FromStr for Card {
     }
 }
 
+/// Parses a list of cards such as `"As Kd 7h"`, `"As,Kd,7h"`, or `"AsKd7h"`.
+///
+/// Cards may be separated by whitespace or commas, or written back to back; the
+/// formats can be mixed. An empty string parses to no cards. Duplicates are not
+/// rejected here.
+///
+/// # Errors
+///
+/// Returns `ParseCardError::InvalidLength` if a run of concatenated cards has an odd
+/// number of characters, or the rank or suit error of the first invalid card.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::card::{parse_cards, Card};
+///
+/// let expected: Vec<Card> = vec!["Qs".parse().unwrap(), "Js".parse().unwrap(), "2h".parse().unwrap()];
+/// assert_eq!(parse_cards("Qs Js 2h").unwrap(), expected);
+/// assert_eq!(parse_cards("Qs, Js, 2h").unwrap(), expected);
+/// assert_eq!(parse_cards("QsJs2h").unwrap(), expected);
+/// assert!(parse_cards("QsJ").is_err());
+/// ```
+pub fn parse_cards(text: &str) -> Result<Vec<Card>, ParseCardError> {
+    let mut cards = Vec::new();
+    for token in text
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+    {
+        let chars: Vec<char> = token.chars().collect();
+        if !chars.len().is_multiple_of(2) {
+            return Err(ParseCardError::InvalidLength);
+        }
+        for pair in chars.chunks(2) {
+            cards.push(pair.iter().collect::<String>().parse()?);
+        }
+    }
+    Ok(cards)
+}
+
 impl From<(Rank, Suit)> for Card {
     /// Converts a `(Rank, Suit)` pair into a `Card`.
     fn from((rank, suit): (Rank, Suit)) -> Self {
@@ -521,6 +631,23 @@ mod tests {
         assert_eq!("Ax".parse::<Card>(), Err(ParseCardError::InvalidSuit));
     }
 
+    #[test]
+    fn test_parse_cards() {
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        let king = Card::new(Rank::King, Suit::Diamonds);
+        let seven = Card::new(Rank::Seven, Suit::Hearts);
+        for text in ["As Kd 7h", "As,Kd,7h", "AsKd7h", " As, Kd7h ", "as\tkD\n7H"] {
+            assert_eq!(parse_cards(text), Ok(vec![ace, king, seven]), "{text:?}");
+        }
+        assert_eq!(parse_cards(""), Ok(Vec::new()));
+        assert_eq!(parse_cards(" , "), Ok(Vec::new()));
+        assert_eq!(parse_cards("As As"), Ok(vec![ace, ace]));
+
+        assert_eq!(parse_cards("AsK d"), Err(ParseCardError::InvalidLength));
+        assert_eq!(parse_cards("As Xd"), Err(ParseCardError::InvalidRank));
+        assert_eq!(parse_cards("AsKx"), Err(ParseCardError::InvalidSuit));
+    }
+
     #[test]
     fn test_card_index() {
         let card = Card::new(Rank::Two, Suit::Clubs);
@@ -0,0 +1,223 @@
+//! Styled rendering of cards for terminals and logs.
+//!
+//! The plain `Display` impls write suits as letters (`As`). The helpers here take a
+//! `DisplayStyle` to write suit symbols (`A♠`) instead, optionally coloring the red
+//! suits with ANSI escape codes.
+
+use std::fmt;
+
+use super::card::Card;
+use super::hand::Hand;
+
+/// ANSI escape code switching the foreground to red.
+const ANSI_RED: &str = "\x1b[31m";
+
+/// ANSI escape code resetting all attributes.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// How cards are rendered.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum DisplayStyle {
+    /// Suits as letters, e.g. `Ah`; the same as the plain `Display` output.
+    #[default]
+    Ascii,
+    /// Suits as symbols, e.g. `A♥`.
+    Unicode,
+    /// Suits as symbols with hearts and diamonds in red ANSI color.
+    Color,
+}
+
+/// `DisplayStyle` - Accessors
+impl DisplayStyle {
+    /// All styles, plainest first.
+    pub const ALL: [Self; 3] = [Self::Ascii, Self::Unicode, Self::Color];
+
+    /// Lowercase name of the style, e.g. `"unicode"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Ascii => "ascii",
+            Self::Unicode => "unicode",
+            Self::Color => "color",
+        }
+    }
+
+    /// Looks up a style by its lowercase name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|style| style.name() == name)
+    }
+}
+
+/// A card rendered in a `DisplayStyle`, returned by `Card::display`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CardDisplay {
+    card: Card,
+    style: DisplayStyle,
+}
+
+/// `CardDisplay` - Constructors
+impl CardDisplay {
+    /// Renders `card` in `style`.
+    #[must_use]
+    pub const fn new(card: Card, style: DisplayStyle) -> Self {
+        Self { card, style }
+    }
+}
+
+impl fmt::Display for CardDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rank = self.card.rank_enum();
+        let suit = self.card.suit_enum();
+        match self.style {
+            DisplayStyle::Ascii => write!(f, "{rank}{suit}"),
+            DisplayStyle::Color if suit.is_red() => {
+                write!(f, "{ANSI_RED}{rank}{}{ANSI_RESET}", suit.symbol())
+            }
+            DisplayStyle::Unicode | DisplayStyle::Color => write!(f, "{rank}{}", suit.symbol()),
+        }
+    }
+}
+
+/// A run of cards rendered in a `DisplayStyle`, returned by `Board::display` and
+/// `HoleCards::display`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CardsDisplay<'a> {
+    cards: &'a [Card],
+    style: DisplayStyle,
+    separator: &'static str,
+    bracketed: bool,
+}
+
+/// `CardsDisplay` - Constructors
+impl<'a> CardsDisplay<'a> {
+    /// Renders `cards` back to back in `style`, e.g. `A♠K♠`.
+    #[must_use]
+    pub const fn new(cards: &'a [Card], style: DisplayStyle) -> Self {
+        Self {
+            cards,
+            style,
+            separator: "",
+            bracketed: false,
+        }
+    }
+
+    /// Renders `cards` separated by spaces inside brackets, e.g. `[A♠ K♠ 2♥]`.
+    #[must_use]
+    pub const fn bracketed(cards: &'a [Card], style: DisplayStyle) -> Self {
+        Self {
+            cards,
+            style,
+            separator: " ",
+            bracketed: true,
+        }
+    }
+}
+
+impl fmt::Display for CardsDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.bracketed {
+            write!(f, "[")?;
+        }
+        for (i, &card) in self.cards.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", self.separator)?;
+            }
+            write!(f, "{}", card.display(self.style))?;
+        }
+        if self.bracketed {
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+/// A hand rendered in a `DisplayStyle`, returned by `Hand::display`.
+#[derive(Copy, Clone, Debug)]
+pub struct HandDisplay<'a> {
+    hand: &'a Hand,
+    style: DisplayStyle,
+}
+
+/// `HandDisplay` - Constructors
+impl<'a> HandDisplay<'a> {
+    /// Renders `hand` in `style`.
+    #[must_use]
+    pub const fn new(hand: &'a Hand, style: DisplayStyle) -> Self {
+        Self { hand, style }
+    }
+}
+
+impl fmt::Display for HandDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} [{}]",
+            self.hand.rank(),
+            CardsDisplay::new(self.hand.cards(), self.style)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::board::Board;
+    use crate::core::domain::entities::hole_cards::HoleCards;
+
+    #[test]
+    fn test_card_styles() {
+        let ace: Card = "Ah".parse().unwrap();
+        let king: Card = "Ks".parse().unwrap();
+        assert_eq!(
+            ace.display(DisplayStyle::Ascii).to_string(),
+            ace.to_string()
+        );
+        assert_eq!(ace.display(DisplayStyle::Unicode).to_string(), "A♥");
+        assert_eq!(
+            ace.display(DisplayStyle::Color).to_string(),
+            "\x1b[31mA♥\x1b[0m"
+        );
+        assert_eq!(king.display(DisplayStyle::Color).to_string(), "K♠");
+    }
+
+    #[test]
+    fn test_board_hole_cards_and_hand() {
+        let board: Board = "Qs Jd 2c".parse().unwrap();
+        assert_eq!(
+            board.display(DisplayStyle::Ascii).to_string(),
+            board.to_string()
+        );
+        assert_eq!(
+            board.display(DisplayStyle::Unicode).to_string(),
+            "[Q♠ J♦ 2♣]"
+        );
+        assert_eq!(
+            Board::new().display(DisplayStyle::Unicode).to_string(),
+            "[]"
+        );
+
+        let hole: HoleCards = "AsKh".parse().unwrap();
+        assert_eq!(hole.display(DisplayStyle::Ascii).to_string(), "AsKh");
+        assert_eq!(hole.display(DisplayStyle::Unicode).to_string(), "A♠K♥");
+
+        let cards = ["As", "Ks", "Qs", "Js", "Ts"].map(|card| card.parse().unwrap());
+        let royal = Hand::new(cards, 1);
+        assert_eq!(
+            royal.display(DisplayStyle::Ascii).to_string(),
+            royal.to_string()
+        );
+        assert_eq!(
+            royal.display(DisplayStyle::Unicode).to_string(),
+            "Straight Flush [A♠K♠Q♠J♠T♠]"
+        );
+    }
+
+    #[test]
+    fn test_style_names() {
+        for style in DisplayStyle::ALL {
+            assert_eq!(DisplayStyle::from_name(style.name()), Some(style));
+        }
+        assert_eq!(DisplayStyle::from_name("fancy"), None);
+    }
+}
@@ -0,0 +1,269 @@
+//! Bitset of cards for fast membership and dead-card checks.
+
+use std::ops::{BitAnd, BitOr, BitOrAssign, Sub};
+
+use super::card::Card;
+
+/// Bits of the 52 cards of a standard deck.
+const FULL: u64 = (1 << 52) - 1;
+
+/// A set of distinct cards stored as a 64-bit mask, one bit per card index.
+///
+/// Membership, union and intersection are single bit operations, unlike
+/// `Vec<Card>::contains`, which scans the vector on every check.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::card::Card;
+/// use riverrun::core::domain::entities::card_set::CardSet;
+///
+/// let ace = Card::from_string("As").unwrap();
+/// let king = Card::from_string("Kd").unwrap();
+/// let dead = CardSet::from_cards(&[ace, king]);
+/// assert!(dead.contains(ace));
+/// assert_eq!(dead.len(), 2);
+/// assert_eq!(CardSet::full().difference(dead).len(), 50);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CardSet(u64);
+
+/// `CardSet` - Constructors
+impl CardSet {
+    /// The set with no cards.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// The set of all 52 cards.
+    #[must_use]
+    pub const fn full() -> Self {
+        Self(FULL)
+    }
+
+    /// The set of `cards`; duplicates count once.
+    #[must_use]
+    pub fn from_cards(cards: &[Card]) -> Self {
+        cards.iter().copied().collect()
+    }
+
+    /// The set whose bits are `bits`, ignoring bits above the 52 card indices.
+    #[must_use]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits & FULL)
+    }
+}
+
+/// `CardSet` - Accessors
+impl CardSet {
+    /// The underlying mask, with bit `card.index()` set for each card.
+    #[must_use]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Number of cards in the set.
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Whether the set has no cards.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether `card` is in the set.
+    #[must_use]
+    pub const fn contains(self, card: Card) -> bool {
+        self.0 & bit(card) != 0
+    }
+
+    /// Whether the sets share no card.
+    #[must_use]
+    pub const fn is_disjoint(self, other: Self) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    /// Whether every card of this set is in `other`.
+    #[must_use]
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// The cards in the set, in index order.
+    #[must_use]
+    pub const fn iter(self) -> Iter {
+        Iter(self.0)
+    }
+}
+
+/// `CardSet` - Operations
+impl CardSet {
+    /// Adds `card`, returning whether it was not already in the set.
+    pub const fn insert(&mut self, card: Card) -> bool {
+        let fresh = !self.contains(card);
+        self.0 |= bit(card);
+        fresh
+    }
+
+    /// Removes `card`, returning whether it was in the set.
+    pub const fn remove(&mut self, card: Card) -> bool {
+        let present = self.contains(card);
+        self.0 &= !bit(card);
+        present
+    }
+
+    /// Cards in either set.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Cards in both sets.
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Cards in this set but not in `other`.
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+/// Bit of `card` in a mask.
+const fn bit(card: Card) -> u64 {
+    1 << card.index()
+}
+
+impl From<&[Card]> for CardSet {
+    fn from(cards: &[Card]) -> Self {
+        Self::from_cards(cards)
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        Self(cards.into_iter().fold(0, |mask, card| mask | bit(card)))
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, cards: I) {
+        *self = self.union(cards.into_iter().collect());
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl BitOrAssign for CardSet {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.difference(other)
+    }
+}
+
+/// Iterator over the cards of a `CardSet`, in index order.
+#[derive(Clone, Debug)]
+pub struct Iter(u64);
+
+impl Iterator for Iter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Card::from_index(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(notation: &str) -> Vec<Card> {
+        notation
+            .split_whitespace()
+            .map(|card| Card::from_string(card).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = CardSet::from_cards(&cards("As Kd Qh"));
+        let b = CardSet::from_cards(&cards("Kd 2c"));
+        assert_eq!((a | b).len(), 4);
+        assert_eq!(a & b, CardSet::from_cards(&cards("Kd")));
+        assert_eq!((a - b).len(), 2);
+        assert!(!a.is_disjoint(b));
+        assert!(CardSet::from_cards(&cards("As")).is_subset(a));
+        assert!(a.is_subset(CardSet::full()));
+        assert!(CardSet::empty().is_empty());
+    }
+
+    #[test]
+    fn test_insert_and_remove_report_changes() {
+        let ace = Card::from_string("As").unwrap();
+        let mut set = CardSet::empty();
+        assert!(set.insert(ace));
+        assert!(!set.insert(ace));
+        assert!(set.contains(ace));
+        assert!(set.remove(ace));
+        assert!(!set.remove(ace));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_iterates_in_index_order() {
+        let set = CardSet::from_cards(&cards("As 2c Kd 2c"));
+        let collected: Vec<Card> = set.iter().collect();
+        assert_eq!(collected, cards("2c Kd As"));
+        assert_eq!(set.iter().len(), 3);
+        assert_eq!(CardSet::full().iter().count(), 52);
+        assert_eq!(CardSet::from_bits(u64::MAX), CardSet::full());
+    }
+}
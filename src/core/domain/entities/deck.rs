@@ -1,6 +1,12 @@
 //! Deck representation for poker.
 
 use super::card::{Card, Rank, Suit};
+use super::card_set::CardSet;
+use crate::core::domain::errors::CardError;
+use crate::core::ports::outbound::RandomSource;
+
+/// Most cards a board can hold.
+const MAX_BOARD_CARDS: usize = 5;
 
 /// A deck of cards.
 #[derive(Clone, Debug)]
@@ -31,11 +37,39 @@ impl Deck {
         Self { cards }
     }
 
+    /// Creates `decks` standard 52-card decks combined into one, each in order.
+    ///
+    /// Every card appears once per deck, as in a multi-deck shoe.
+    #[must_use]
+    pub fn combined(decks: usize) -> Self {
+        let cards = (0..decks).flat_map(|_| Card::all_cards()).collect();
+        Self { cards }
+    }
+
+    /// Builds the subset of a standard 52-card deck for which `keep` returns `true`,
+    /// in standard order.
+    ///
+    /// The deck has no jokers, so every subset is drawn from the 52 standard cards.
+    #[must_use]
+    pub fn subset(keep: impl Fn(Card) -> bool) -> Self {
+        Self {
+            cards: Card::all_cards().filter(|&card| keep(card)).collect(),
+        }
+    }
+
+    /// Builds the subset of a standard 52-card deck holding only `ranks`, such as
+    /// the 36-card short deck of sixes through aces.
+    #[must_use]
+    pub fn of_ranks(ranks: &[Rank]) -> Self {
+        Self::subset(|card| ranks.contains(&card.rank_enum()))
+    }
+
     /// Builds a standard 52-card deck omitting any cards present in `dead_cards`.
     ///
     /// The returned deck contains every card from a fresh standard deck except those
     /// that are equal to any element in the provided slice. Duplicate entries in
-    /// `dead_cards` have the same effect as a single entry.
+    /// `dead_cards` have the same effect as a single entry. Membership is checked
+    /// against a bitset, so the cost does not grow with the number of dead cards.
     ///
     /// # Arguments
     ///
@@ -46,10 +80,8 @@ impl Deck {
     /// A `Deck` containing all standard cards except those found in `dead_cards`.
     #[must_use]
     pub fn excluding(dead_cards: &[Card]) -> Self {
-        let cards: Vec<Card> = Card::all_cards()
-            .filter(|c| !dead_cards.contains(c))
-            .collect();
-        Self { cards }
+        let dead = CardSet::from_cards(dead_cards);
+        Self::subset(|card| !dead.contains(card))
     }
 }
 
@@ -105,11 +137,19 @@ impl Deck {
 /// Operations
 impl Deck {
     /// Shuffle the deck using the provided random number generator.
+    ///
+    /// Convenience for callers holding a `rand::Rng`; anything that should accept an
+    /// injected generator goes through [`Deck::shuffle_with`] instead.
     pub fn shuffle<R: rand::Rng>(&mut self, rng: &mut R) {
         use rand::seq::SliceRandom;
         self.cards.shuffle(rng);
     }
 
+    /// Shuffle the deck using the provided `RandomSource`.
+    pub fn shuffle_with(&mut self, source: &mut dyn RandomSource) {
+        source.shuffle_cards(&mut self.cards);
+    }
+
     /// Restores the deck to a standard ordered 52-card deck.
     ///
     /// Replaces the deck's current contents with a fresh deck containing all 52 cards in canonical order.
@@ -118,8 +158,11 @@ impl Deck {
     }
 
     /// Remove specific cards from the deck (for dealing known cards).
+    ///
+    /// Every copy of each card is removed from a combined deck.
     pub fn remove(&mut self, cards_to_remove: &[Card]) {
-        self.cards.retain(|c| !cards_to_remove.contains(c));
+        let removed = CardSet::from_cards(cards_to_remove);
+        self.cards.retain(|&c| !removed.contains(c));
     }
 
     /// Deal a single card from the top of the deck.
@@ -221,6 +264,125 @@ impl Default for Deck {
     }
 }
 
+/// Builder for a deck that deals known cards, for scenario tests and trainers.
+///
+/// `Deck` deals from the end: one card to each seat, then a second card to each seat,
+/// then burn and flop, burn and turn, burn and river. `ScriptedDeck` puts the chosen
+/// hole cards and board cards where that order deals them and fills every other
+/// position, burns included, with the unused cards.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::card::Card;
+/// use riverrun::core::domain::entities::deck::ScriptedDeck;
+/// use riverrun::core::domain::entities::game::Game;
+///
+/// let cards = |text: &str| -> Vec<Card> {
+///     text.split_whitespace().map(|card| card.parse().unwrap()).collect()
+/// };
+/// let aces = cards("As Ah");
+/// let deck = ScriptedDeck::new(3)
+///     .with_hole_cards(1, [aces[0], aces[1]])
+///     .with_board(&cards("Ks 7d 2c Ac"))
+///     .build()
+///     .unwrap();
+///
+/// let mut game = Game::with_deck(3, deck).unwrap();
+/// assert!(game.deal_to_river());
+/// assert_eq!(game.player_hole_cards(1), Some(&[aces[0], aces[1]]));
+/// assert_eq!(&game.board().cards()[..4], cards("Ks 7d 2c Ac").as_slice());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptedDeck {
+    hole_cards: Vec<Option<[Card; 2]>>,
+    board: Vec<Card>,
+}
+
+/// Constructors
+impl ScriptedDeck {
+    /// Starts a deck for a table of `num_players` with no known cards.
+    #[must_use]
+    pub fn new(num_players: usize) -> Self {
+        Self {
+            hole_cards: vec![None; num_players],
+            board: Vec::new(),
+        }
+    }
+
+    /// Deals `cards` to the zero-based `seat`. Seats outside the table are ignored.
+    #[must_use]
+    pub fn with_hole_cards(mut self, seat: usize, cards: [Card; 2]) -> Self {
+        if let Some(slot) = self.hole_cards.get_mut(seat) {
+            *slot = Some(cards);
+        }
+        self
+    }
+
+    /// Deals `cards` as the first board cards, flop first; the rest of the board is
+    /// filled like any other unknown card.
+    #[must_use]
+    pub fn with_board(mut self, cards: &[Card]) -> Self {
+        self.board = cards.to_vec();
+        self
+    }
+}
+
+/// Operations
+impl ScriptedDeck {
+    /// Builds the deck, filling unknown positions with the unused cards of a standard
+    /// deck in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::DuplicateCard` if a card is dealt twice, and
+    /// `CardError::InvalidBoardSize` if more than five board cards were given.
+    pub fn build(&self) -> Result<Deck, CardError> {
+        self.build_with(|_| {})
+    }
+
+    /// Builds the deck, filling unknown positions with the unused cards shuffled by
+    /// `source`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`ScriptedDeck::build`].
+    pub fn build_shuffled(&self, source: &mut dyn RandomSource) -> Result<Deck, CardError> {
+        self.build_with(|spare| source.shuffle_cards(spare))
+    }
+
+    fn build_with(&self, arrange: impl FnOnce(&mut Vec<Card>)) -> Result<Deck, CardError> {
+        if self.board.len() > MAX_BOARD_CARDS {
+            return Err(CardError::InvalidBoardSize(self.board.len()));
+        }
+        let mut known = CardSet::empty();
+        for &card in self.hole_cards.iter().flatten().flatten().chain(&self.board) {
+            if !known.insert(card) {
+                return Err(CardError::DuplicateCard(card));
+            }
+        }
+
+        let mut spare: Vec<Card> = Card::all_cards().filter(|&card| !known.contains(card)).collect();
+        arrange(&mut spare);
+        let mut spare = spare.into_iter();
+        let mut order = Vec::with_capacity(52);
+        for round in 0..2 {
+            for hole_cards in &self.hole_cards {
+                order.extend(hole_cards.map(|cards| cards[round]).or_else(|| spare.next()));
+            }
+        }
+        for street in [0..3, 3..4, 4..5] {
+            order.extend(spare.next());
+            for index in street {
+                order.extend(self.board.get(index).copied().or_else(|| spare.next()));
+            }
+        }
+        order.extend(spare);
+        order.reverse();
+        Ok(Deck::from_cards(order))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -397,4 +559,102 @@ mod tests {
         assert!(river.suit() <= 3);
         assert_eq!(deck.remaining(), 32);
     }
+
+    #[test]
+    fn test_combined_decks() {
+        let shoe = Deck::combined(2);
+        assert_eq!(shoe.remaining(), 104);
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        assert_eq!(shoe.cards().iter().filter(|&&card| card == ace).count(), 2);
+        assert!(Deck::combined(0).is_empty());
+
+        let mut shoe = shoe;
+        shoe.remove(&[ace]);
+        assert_eq!(shoe.remaining(), 102);
+    }
+
+    #[test]
+    fn test_subsets() {
+        let short = Deck::of_ranks(&[
+            Rank::Six,
+            Rank::Seven,
+            Rank::Eight,
+            Rank::Nine,
+            Rank::Ten,
+            Rank::Jack,
+            Rank::Queen,
+            Rank::King,
+            Rank::Ace,
+        ]);
+        assert_eq!(short.remaining(), 36);
+        assert!(short.cards().iter().all(|card| card.rank_enum() >= Rank::Six));
+
+        let hearts = Deck::subset(|card| card.suit_enum() == Suit::Hearts);
+        assert_eq!(hearts.remaining(), 13);
+    }
+
+    #[test]
+    fn test_excluding_ignores_duplicates() {
+        let dead = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Spades),
+        ];
+        let deck = Deck::excluding(&dead);
+        assert_eq!(deck.remaining(), 50);
+        assert!(!deck.cards().contains(&dead[0]));
+        assert!(!deck.cards().contains(&dead[1]));
+    }
+
+    #[test]
+    fn test_scripted_deck_deals_known_cards() {
+        let card = |text: &str| Card::from_string(text).unwrap();
+        let board = [card("Ks"), card("7d"), card("2c"), card("Ac"), card("9h")];
+        let mut deck = ScriptedDeck::new(2)
+            .with_hole_cards(0, [card("As"), card("Ah")])
+            .with_hole_cards(1, [card("Kd"), card("Kh")])
+            .with_board(&board)
+            .build()
+            .unwrap();
+        assert_eq!(deck.remaining(), 52);
+
+        let hands = deck.deal_hole_cards(2).unwrap();
+        assert_eq!(hands, vec![[card("As"), card("Ah")], [card("Kd"), card("Kh")]]);
+        assert_eq!(deck.deal_flop().unwrap(), [board[0], board[1], board[2]]);
+        assert_eq!(deck.deal_turn(), Some(board[3]));
+        assert_eq!(deck.deal_river(), Some(board[4]));
+    }
+
+    #[test]
+    fn test_scripted_deck_shuffles_the_rest() {
+        use crate::core::ports::outbound::RandRandomSource;
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let card = |text: &str| Card::from_string(text).unwrap();
+        let scripted = ScriptedDeck::new(2).with_hole_cards(1, [card("2s"), card("2h")]);
+        let mut deck = scripted
+            .build_shuffled(&mut RandRandomSource::new(StdRng::seed_from_u64(42)))
+            .unwrap();
+        assert_ne!(deck.cards(), scripted.build().unwrap().cards());
+        assert_eq!(deck.deal_hole_cards(2).unwrap()[1], [card("2s"), card("2h")]);
+    }
+
+    #[test]
+    fn test_scripted_deck_rejects_invalid_cards() {
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        let king = Card::new(Rank::King, Suit::Spades);
+        assert_eq!(
+            ScriptedDeck::new(2)
+                .with_hole_cards(0, [ace, king])
+                .with_board(&[king])
+                .build()
+                .unwrap_err(),
+            CardError::DuplicateCard(king)
+        );
+        assert_eq!(
+            ScriptedDeck::new(2).with_board(&[ace; 6]).build().unwrap_err(),
+            CardError::InvalidBoardSize(6)
+        );
+    }
 }
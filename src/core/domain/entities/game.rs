@@ -4,6 +4,7 @@ use super::board::Board;
 use crate::core::domain::primitives::Street;
 use super::card::Card;
 use super::deck::Deck;
+use crate::core::ports::outbound::RandomSource;
 
 /// A Texas Hold'em poker game.
 #[derive(Clone, Debug)]
@@ -20,13 +21,14 @@ impl Game {
     ///
     /// The `num_players` must be between 2 and 10 inclusive; returns `None` if the value is out of range.
     /// The returned `Game` has an initialized, shuffled deck, no dealt hole cards, and an empty board.
-    pub fn new<R: rand::Rng>(num_players: usize, rng: &mut R) -> Option<Self> {
+    /// Wrap a `rand::Rng` in `RandRandomSource` to shuffle with it.
+    pub fn new(num_players: usize, source: &mut dyn RandomSource) -> Option<Self> {
         if !(2..=10).contains(&num_players) {
             return None;
         }
 
         let mut deck = Deck::new();
-        deck.shuffle(rng);
+        deck.shuffle_with(source);
 
         Some(Self {
             deck,
@@ -89,8 +91,10 @@ impl Game {
     /// Provides a slice of all players' hole cards in seating order.
     ///
     /// Each element is a two-card array representing a player's hole cards; the slice is empty before hole cards are dealt.
+    /// Only the crate sees every hand at once, to resolve showdowns; show a player their own cards with
+    /// `GameAggregate::player_view`.
     #[must_use]
-    pub fn all_hole_cards(&self) -> &[[Card; 2]] {
+    pub(crate) fn all_hole_cards(&self) -> &[[Card; 2]] {
         &self.hole_cards
     }
 
@@ -183,10 +187,38 @@ impl Game {
         self.deal_river()
     }
 
+    /// Deal another board for running it more than once: the cards already dealt up to
+    /// `from` are shared, and the later streets are dealt afresh, with their burns, from
+    /// the remaining deck.
+    ///
+    /// The game's own board is unchanged. Returns `None` if `from` has not been dealt yet
+    /// or the deck runs out.
+    pub fn deal_runout(&mut self, from: Street) -> Option<Board> {
+        let shared = self.board.cards().get(..from.card_count())?.to_vec();
+        let mut runout = Self {
+            deck: self.deck.clone(),
+            num_players: self.num_players,
+            hole_cards: Vec::new(),
+            board: Board::partial(shared)?,
+        };
+        while !runout.is_showdown() {
+            let dealt = match runout.street() {
+                Street::Preflop => runout.deal_flop(),
+                Street::Flop => runout.deal_turn(),
+                Street::Turn | Street::River => runout.deal_river(),
+            };
+            if !dealt {
+                return None;
+            }
+        }
+        self.deck = runout.deck;
+        Some(runout.board)
+    }
+
     /// Reset the game for a new hand.
-    pub fn reset<R: rand::Rng>(&mut self, rng: &mut R) {
+    pub fn reset(&mut self, source: &mut dyn RandomSource) {
         self.deck.reset();
-        self.deck.shuffle(rng);
+        self.deck.shuffle_with(source);
         self.hole_cards.clear();
         self.board.clear();
     }
@@ -195,10 +227,12 @@ impl Game {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::ports::outbound::{FixedRandomSource, RandRandomSource};
     use rand::SeedableRng;
+    use rand::rngs::StdRng;
 
-    fn make_rng() -> rand::rngs::StdRng {
-        rand::rngs::StdRng::seed_from_u64(42)
+    fn make_rng() -> RandRandomSource<StdRng> {
+        RandRandomSource::new(StdRng::seed_from_u64(42))
     }
 
     #[test]
@@ -287,6 +321,24 @@ mod tests {
         assert_eq!(game.board().len(), 5);
     }
 
+    #[test]
+    fn test_deal_runout_shares_cards_up_to_street() {
+        let mut rng = make_rng();
+        let mut game = Game::new(2, &mut rng).unwrap();
+        game.deal_hole_cards();
+        game.deal_flop();
+        assert!(game.deal_runout(Street::Turn).is_none());
+        game.deal_turn();
+        game.deal_river();
+        let remaining = game.remaining_cards();
+
+        let second = game.deal_runout(Street::Flop).unwrap();
+        assert_eq!(second.cards()[..3], game.board().cards()[..3]);
+        assert_ne!(second.cards()[3..], game.board().cards()[3..]);
+        assert_eq!(game.remaining_cards(), remaining - 4);
+        assert_eq!(game.board().len(), 5);
+    }
+
     #[test]
     fn test_player_hole_cards() {
         let mut rng = make_rng();
@@ -336,4 +388,13 @@ mod tests {
         assert_eq!(game.num_players(), 4);
         assert_eq!(game.remaining_cards(), 52);
     }
+
+    #[test]
+    fn test_new_with_fixed_source_keeps_deck_order() {
+        let mut game = Game::new(2, &mut FixedRandomSource::zero()).unwrap();
+        let mut ordered = Game::with_deck(2, Deck::new()).unwrap();
+        game.deal_hole_cards();
+        ordered.deal_hole_cards();
+        assert_eq!(game.all_hole_cards(), ordered.all_hole_cards());
+    }
 }
@@ -3,7 +3,12 @@
 use std::cmp::Ordering;
 use std::fmt;
 
-use super::card::Card;
+use super::card::{Card, Rank};
+use super::card_display::{DisplayStyle, HandDisplay};
+use super::hole_cards::HoleCards;
+
+/// Number of distinct hand strengths; also the strength of the weakest hand.
+const NUM_STRENGTHS: u16 = 7462;
 
 /// Poker hand category.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -22,6 +27,19 @@ pub enum HandRank {
 
 /// `HandRank` - Constructors
 impl HandRank {
+    /// Every hand category, weakest first.
+    pub const ALL: [Self; 9] = [
+        Self::HighCard,
+        Self::OnePair,
+        Self::TwoPair,
+        Self::ThreeOfAKind,
+        Self::Straight,
+        Self::Flush,
+        Self::FullHouse,
+        Self::FourOfAKind,
+        Self::StraightFlush,
+    ];
+
     /// Map a numeric hand strength (1–7462) to its corresponding poker `HandRank`.
     ///
     /// The mapping follows the standard hand-strength ranges:
@@ -110,6 +128,12 @@ impl Hand {
 
 /// Hand - Accessors
 impl Hand {
+    /// Renders the hand like its `Display` output in `style`, e.g. `Flush [A♠Q♠9♠7♠2♠]`.
+    #[must_use]
+    pub const fn display(&self, style: DisplayStyle) -> HandDisplay<'_> {
+        HandDisplay::new(self, style)
+    }
+
     /// Accesses the five cards comprising the hand.
     ///
     /// # Returns
@@ -154,6 +178,53 @@ impl Hand {
         self.strength
     }
 
+    /// Fraction of the 7462 distinct hand strengths that this hand beats.
+    ///
+    /// Every distinct strength counts once, however many card combinations make it, so a
+    /// royal flush is 1.0, the weakest high card is 0.0, and the best one pair is about
+    /// 0.55. For strength relative to the hands an opponent could hold on a given board,
+    /// see `HandEvaluator::relative_strength`.
+    #[must_use]
+    pub fn percentile(&self) -> f64 {
+        let beaten = NUM_STRENGTHS - self.strength.clamp(1, NUM_STRENGTHS);
+        f64::from(beaten) / f64::from(NUM_STRENGTHS - 1)
+    }
+
+    /// Checks whether `card` is one of the five cards of the hand.
+    #[must_use]
+    pub fn contains(&self, card: Card) -> bool {
+        self.cards.contains(&card)
+    }
+
+    /// Which of `hole_cards` are among the five cards of the hand, in hole-card order.
+    ///
+    /// When a hole card and a board card are interchangeable, the answer depends on
+    /// which one the evaluator kept; `ShowdownSolver` keeps board cards where it can.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::card::Card;
+    /// use riverrun::core::domain::entities::hand::Hand;
+    /// use riverrun::core::domain::entities::hole_cards::HoleCards;
+    ///
+    /// let card = |text| Card::from_string(text).unwrap();
+    /// let hand = Hand::new(["As", "Ah", "Kd", "Kc", "Qs"].map(card), 2479);
+    /// let hole_cards = HoleCards::new(card("Ah"), card("2c"));
+    /// assert_eq!(hand.uses_hole_cards(&hole_cards), [true, false]);
+    /// ```
+    #[must_use]
+    pub fn uses_hole_cards(&self, hole_cards: &HoleCards) -> [bool; 2] {
+        hole_cards.cards().map(|card| self.contains(card))
+    }
+
+    /// Checks whether the hand is made without either of `hole_cards`, so its owner
+    /// plays the board.
+    #[must_use]
+    pub fn plays_the_board(&self, hole_cards: &HoleCards) -> bool {
+        self.uses_hole_cards(hole_cards) == [false, false]
+    }
+
     /// Checks whether the hand has the given rank.
     ///
     /// # Returns
@@ -249,6 +320,58 @@ impl Hand {
 
 /// Hand - Operations
 impl Hand {
+    /// Human-readable description of the hand, naming the ranks that make it and its
+    /// kickers.
+    ///
+    /// The description is built from the five cards grouped by rank, so it names the
+    /// hand the cards make rather than re-evaluating them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::card::Card;
+    /// use riverrun::core::domain::entities::hand::Hand;
+    ///
+    /// let cards = ["As", "Ah", "Kd", "Kc", "Qs"].map(|card| Card::from_string(card).unwrap());
+    /// let hand = Hand::new(cards, 2479);
+    /// assert_eq!(hand.describe(), "Two Pair, Aces and Kings, Queen kicker");
+    /// ```
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let ranks = self.ranks_by_group();
+        match self.rank {
+            HandRank::StraightFlush if self.is_royal_flush() => "Royal Flush".to_string(),
+            HandRank::StraightFlush | HandRank::Straight => {
+                format!("{}, {} high", self.rank, ranks[0].name())
+            }
+            HandRank::FullHouse => format!(
+                "{}, {} full of {}",
+                self.rank,
+                ranks[0].plural(),
+                ranks[1].plural()
+            ),
+            HandRank::TwoPair => format!(
+                "{}, {} and {}{}",
+                self.rank,
+                ranks[0].plural(),
+                ranks[1].plural(),
+                kickers(&ranks[2..])
+            ),
+            HandRank::FourOfAKind | HandRank::ThreeOfAKind | HandRank::OnePair => format!(
+                "{}, {}{}",
+                self.rank,
+                ranks[0].plural(),
+                kickers(&ranks[1..])
+            ),
+            HandRank::Flush | HandRank::HighCard => format!(
+                "{}, {} high{}",
+                self.rank,
+                ranks[0].name(),
+                kickers(&ranks[1..])
+            ),
+        }
+    }
+
     /// Determines whether this hand beats another hand by comparing their strengths.
     ///
     /// # Returns
@@ -284,6 +407,44 @@ impl Hand {
     }
 }
 
+/// Hand - Helpers
+impl Hand {
+    /// Distinct ranks of the cards, largest group first and higher ranks first within
+    /// groups of the same size, so the ranks compare in the order they decide between
+    /// hands of the same category.
+    ///
+    /// The ace of a wheel, A-2-3-4-5, comes last since the straight is five high.
+    pub(crate) fn ranks_by_group(&self) -> Vec<Rank> {
+        let mut counts = [0u8; 13];
+        for card in &self.cards {
+            counts[card.rank_enum() as usize] += 1;
+        }
+        let mut ranks: Vec<Rank> = Rank::all().filter(|&rank| counts[rank as usize] > 0).collect();
+        ranks.sort_by(|a, b| {
+            counts[*b as usize]
+                .cmp(&counts[*a as usize])
+                .then_with(|| b.cmp(a))
+        });
+        let is_straight = matches!(self.rank, HandRank::Straight | HandRank::StraightFlush);
+        if is_straight && ranks.starts_with(&[Rank::Ace, Rank::Five]) {
+            ranks.rotate_left(1);
+        }
+        ranks
+    }
+}
+
+/// Kicker phrase for `ranks`, such as ", Queen kicker", or empty without kickers.
+fn kickers(ranks: &[Rank]) -> String {
+    match ranks {
+        [] => String::new(),
+        [kicker] => format!(", {} kicker", kicker.name()),
+        _ => {
+            let names: Vec<&str> = ranks.iter().map(|rank| rank.name()).collect();
+            format!(", {} kickers", names.join(", "))
+        }
+    }
+}
+
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
         // Lower strength is better, so reverse the comparison
@@ -328,6 +489,23 @@ mod tests {
         Card::new(rank, suit)
     }
 
+    #[test]
+    fn test_percentile() {
+        let cards = [
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Jack, Suit::Spades),
+            card(Rank::Ten, Suit::Spades),
+        ];
+        assert!((Hand::new(cards, 1).percentile() - 1.0).abs() < f64::EPSILON);
+        assert!(Hand::new(cards, 7462).percentile().abs() < f64::EPSILON);
+
+        let best_pair = Hand::new(cards, 3326).percentile();
+        assert!((best_pair - 4136.0 / 7461.0).abs() < 1e-12);
+        assert!(Hand::new(cards, 3325).percentile() > best_pair);
+    }
+
     #[test]
     fn test_hand_rank_from_strength() {
         assert_eq!(HandRank::from_strength(1), HandRank::StraightFlush);
@@ -515,4 +693,64 @@ mod tests {
         assert!(straight_flush.is_straight_flush());
         assert!(!straight_flush.is_royal_flush());
     }
+
+    fn hand(cards: &str, strength: u16) -> Hand {
+        let cards: Vec<Card> = cards
+            .split_whitespace()
+            .map(|card| Card::from_string(card).unwrap())
+            .collect();
+        Hand::new(cards.try_into().unwrap(), strength)
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(hand("As Ks Qs Js Ts", 1).describe(), "Royal Flush");
+        assert_eq!(
+            hand("5h 4h 3h 2h Ah", 10).describe(),
+            "Straight Flush, Five high"
+        );
+        assert_eq!(
+            hand("9c 9d 9h 9s 4c", 100).describe(),
+            "Four of a Kind, Nines, Four kicker"
+        );
+        assert_eq!(
+            hand("Kd As Ah Kc Ad", 167).describe(),
+            "Full House, Aces full of Kings"
+        );
+        assert_eq!(
+            hand("Ad 9d 7d 3d 2d", 400).describe(),
+            "Flush, Ace high, Nine, Seven, Three, Two kickers"
+        );
+        assert_eq!(hand("Ts 9h 8d 7c 6s", 1604).describe(), "Straight, Ten high");
+        assert_eq!(
+            hand("6s 6h 6d Ac 2s", 2000).describe(),
+            "Three of a Kind, Sixes, Ace, Two kickers"
+        );
+        assert_eq!(
+            hand("Qs Ks Kd Ah As", 2479).describe(),
+            "Two Pair, Aces and Kings, Queen kicker"
+        );
+        assert_eq!(
+            hand("Jc Jd 8s 5h 3d", 4500).describe(),
+            "One Pair, Jacks, Eight, Five, Three kickers"
+        );
+        assert_eq!(
+            hand("Kc Td 8s 5h 3d", 6800).describe(),
+            "High Card, King high, Ten, Eight, Five, Three kickers"
+        );
+    }
+
+    #[test]
+    fn test_uses_hole_cards() {
+        let royal = hand("As Ks Qs Js Ts", 1);
+        let card = |text| Card::from_string(text).unwrap();
+        assert!(royal.contains(card("Qs")));
+        assert!(!royal.contains(card("Qh")));
+        assert_eq!(
+            royal.uses_hole_cards(&HoleCards::new(card("2c"), card("Ks"))),
+            [false, true]
+        );
+        assert!(royal.plays_the_board(&HoleCards::new(card("2c"), card("3d"))));
+        assert!(!royal.plays_the_board(&HoleCards::new(card("As"), card("Ks"))));
+    }
 }
@@ -0,0 +1,331 @@
+//! Starting hand classes: the 169 strategically distinct Hold'em starting hands.
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::card::{Card, ParseCardError, Rank, Suit};
+use super::card_set::CardSet;
+use super::hole_cards::HoleCards;
+
+/// Number of distinct starting hand classes.
+pub const NUM_HAND_CLASSES: usize = 169;
+
+/// A starting hand class such as "AA", "AKs", or "T9o".
+///
+/// Hole cards that differ only by suit permutation belong to the same class, which
+/// leaves 13 pocket pairs, 78 suited hands, and 78 offsuit hands.
+///
+/// Classes are indexed 0..169 over the usual 13x13 grid read row by row with aces
+/// first: pairs on the diagonal, suited hands above it, offsuit hands below.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::card::Card;
+/// use riverrun::core::domain::entities::hand_class::HandClass;
+/// use riverrun::core::domain::entities::hole_cards::HoleCards;
+///
+/// let hole = HoleCards::new(Card::from_string("9h").unwrap(), Card::from_string("Td").unwrap());
+/// let class = HandClass::from_hole_cards(&hole);
+/// assert_eq!(class.to_string(), "T9o");
+/// assert_eq!(class.combo_count(), 12);
+/// assert_eq!("T9o".parse::<HandClass>(), Ok(class));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HandClass {
+    high: Rank,
+    low: Rank,
+    suited: bool,
+}
+
+/// `HandClass` - Constructors
+impl HandClass {
+    /// The pocket pair of `rank`.
+    #[must_use]
+    pub const fn pair(rank: Rank) -> Self {
+        Self {
+            high: rank,
+            low: rank,
+            suited: false,
+        }
+    }
+
+    /// Two different ranks of the same suit, in either order.
+    ///
+    /// # Returns
+    /// `None` if the ranks are equal.
+    #[must_use]
+    pub fn suited(first: Rank, second: Rank) -> Option<Self> {
+        (first != second).then(|| Self {
+            high: first.max(second),
+            low: first.min(second),
+            suited: true,
+        })
+    }
+
+    /// Two different ranks of different suits, in either order.
+    ///
+    /// # Returns
+    /// `None` if the ranks are equal; use `pair` for pocket pairs.
+    #[must_use]
+    pub fn offsuit(first: Rank, second: Rank) -> Option<Self> {
+        (first != second).then(|| Self {
+            high: first.max(second),
+            low: first.min(second),
+            suited: false,
+        })
+    }
+
+    /// The class the given hole cards belong to.
+    #[must_use]
+    pub fn from_hole_cards(hole_cards: &HoleCards) -> Self {
+        let first = hole_cards.first().rank_enum();
+        let second = hole_cards.second().rank_enum();
+        Self {
+            high: first.max(second),
+            low: first.min(second),
+            suited: hole_cards.is_suited(),
+        }
+    }
+
+    /// The class at `index` in grid order.
+    ///
+    /// # Returns
+    /// `None` if `index` is not below 169.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_index(index: usize) -> Option<Self> {
+        if index >= NUM_HAND_CLASSES {
+            return None;
+        }
+        // Row and column count down from the ace
+        let row = Rank::from_u8(12 - (index / 13) as u8)?;
+        let column = Rank::from_u8(12 - (index % 13) as u8)?;
+        Some(match row.cmp(&column) {
+            std::cmp::Ordering::Equal => Self::pair(row),
+            std::cmp::Ordering::Greater => Self::suited(row, column)?,
+            std::cmp::Ordering::Less => Self::offsuit(row, column)?,
+        })
+    }
+
+    /// The class at `row` and `column` of the 13x13 grid, both counted from 0 at the ace.
+    ///
+    /// # Returns
+    /// `None` if either coordinate is not below 13.
+    #[must_use]
+    pub fn from_grid(row: usize, column: usize) -> Option<Self> {
+        if row >= 13 || column >= 13 {
+            return None;
+        }
+        Self::from_index(row * 13 + column)
+    }
+
+    /// Every class in grid order, starting with "AA", "AKs", "AQs".
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..NUM_HAND_CLASSES).filter_map(Self::from_index)
+    }
+}
+
+/// `HandClass` - Accessors
+impl HandClass {
+    /// The higher rank (either rank for a pair).
+    #[must_use]
+    pub const fn high(&self) -> Rank {
+        self.high
+    }
+
+    /// The lower rank (either rank for a pair).
+    #[must_use]
+    pub const fn low(&self) -> Rank {
+        self.low
+    }
+
+    /// Returns whether this class is a pocket pair.
+    #[must_use]
+    pub fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    /// Returns whether both cards share a suit.
+    #[must_use]
+    pub const fn is_suited(&self) -> bool {
+        self.suited
+    }
+
+    /// Number of card combinations in this class: 6 for pairs, 4 suited, 12 offsuit.
+    #[must_use]
+    pub fn combo_count(&self) -> usize {
+        if self.is_pair() {
+            6
+        } else if self.suited {
+            4
+        } else {
+            12
+        }
+    }
+
+    /// Position of this class in grid order, below 169.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        let high = 12 - self.high as usize;
+        let low = 12 - self.low as usize;
+        if self.suited {
+            high * 13 + low
+        } else {
+            low * 13 + high
+        }
+    }
+
+    /// Row and column of this class in the 13x13 grid, both counted from 0 at the ace.
+    ///
+    /// Suited hands sit above the diagonal, with the higher rank giving the row, and
+    /// offsuit hands below it, with the higher rank giving the column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::hand_class::HandClass;
+    ///
+    /// let suited: HandClass = "AKs".parse().unwrap();
+    /// let offsuit: HandClass = "AKo".parse().unwrap();
+    /// assert_eq!(suited.grid_position(), (0, 1));
+    /// assert_eq!(offsuit.grid_position(), (1, 0));
+    /// ```
+    #[must_use]
+    pub const fn grid_position(&self) -> (usize, usize) {
+        let index = self.index();
+        (index / 13, index % 13)
+    }
+
+    /// Returns whether `hole_cards` belong to this class.
+    #[must_use]
+    pub fn contains(&self, hole_cards: &HoleCards) -> bool {
+        Self::from_hole_cards(hole_cards) == *self
+    }
+
+    /// Every combination of hole cards in this class.
+    #[must_use]
+    pub fn combos(&self) -> Vec<HoleCards> {
+        let mut combos = Vec::with_capacity(self.combo_count());
+        for high_suit in Suit::all() {
+            for low_suit in Suit::all() {
+                let keep = if self.is_pair() {
+                    (high_suit as u8) < (low_suit as u8)
+                } else {
+                    (high_suit == low_suit) == self.suited
+                };
+                if keep {
+                    combos.push(HoleCards::new(
+                        Card::new(self.high, high_suit),
+                        Card::new(self.low, low_suit),
+                    ));
+                }
+            }
+        }
+        combos
+    }
+
+    /// Combinations of hole cards in this class that use none of the `dead` cards.
+    #[must_use]
+    pub fn live_combos(&self, dead: CardSet) -> Vec<HoleCards> {
+        self.combos()
+            .into_iter()
+            .filter(|combo| !dead.contains(combo.first()) && !dead.contains(combo.second()))
+            .collect()
+    }
+}
+
+impl fmt::Display for HandClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_pair() {
+            write!(f, "{}{}", self.high, self.low)
+        } else {
+            let marker = if self.suited { 's' } else { 'o' };
+            write!(f, "{}{}{marker}", self.high, self.low)
+        }
+    }
+}
+
+impl FromStr for HandClass {
+    type Err = ParseCardError;
+
+    /// Parses a class such as `"QQ"`, `"AKs"`, or `"T9o"`; ranks may come in either order.
+    ///
+    /// Returns `ParseCardError::InvalidSuit` if the suited marker is missing or is not `s`
+    /// or `o`, or is given for a pair.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let (Some(first), Some(second)) = (chars.next(), chars.next()) else {
+            return Err(ParseCardError::InvalidLength);
+        };
+        let marker = chars.next();
+        if chars.next().is_some() {
+            return Err(ParseCardError::InvalidLength);
+        }
+
+        let first: Rank = first.to_string().parse()?;
+        let second: Rank = second.to_string().parse()?;
+        match marker {
+            None if first == second => Ok(Self::pair(first)),
+            Some('s' | 'S') => Self::suited(first, second).ok_or(ParseCardError::InvalidSuit),
+            Some('o' | 'O') => Self::offsuit(first, second).ok_or(ParseCardError::InvalidSuit),
+            _ => Err(ParseCardError::InvalidSuit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_round_trips_in_grid_order() {
+        let all: Vec<HandClass> = HandClass::all().collect();
+        assert_eq!(all.len(), NUM_HAND_CLASSES);
+        for (index, class) in all.iter().enumerate() {
+            assert_eq!(class.index(), index);
+        }
+        assert_eq!(all[0].to_string(), "AA");
+        assert_eq!(all[1].to_string(), "AKs");
+        assert_eq!(all[13].to_string(), "AKo");
+        assert_eq!(all[168].to_string(), "22");
+        assert_eq!(HandClass::from_index(169), None);
+    }
+
+    #[test]
+    fn test_combos_cover_every_hole_card_pair() {
+        let total: usize = HandClass::all().map(|class| class.combos().len()).sum();
+        assert_eq!(total, 1326);
+        for class in HandClass::all() {
+            let combos = class.combos();
+            assert_eq!(combos.len(), class.combo_count());
+            assert!(combos.iter().all(|combo| HandClass::from_hole_cards(combo) == class));
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("KAs".parse(), Ok(HandClass::suited(Rank::Ace, Rank::King).unwrap()));
+        assert_eq!("22".parse(), Ok(HandClass::pair(Rank::Two)));
+        assert_eq!("22s".parse::<HandClass>(), Err(ParseCardError::InvalidSuit));
+        assert_eq!("AK".parse::<HandClass>(), Err(ParseCardError::InvalidSuit));
+        assert_eq!("A".parse::<HandClass>(), Err(ParseCardError::InvalidLength));
+        assert_eq!("AXs".parse::<HandClass>(), Err(ParseCardError::InvalidRank));
+    }
+
+    #[test]
+    fn test_grid_and_live_combos() {
+        for class in HandClass::all() {
+            let (row, column) = class.grid_position();
+            assert_eq!(HandClass::from_grid(row, column), Some(class));
+            assert!(class.combos().iter().all(|combo| class.contains(combo)));
+        }
+        assert_eq!(HandClass::from_grid(13, 0), None);
+        assert_eq!(HandClass::from_grid(12, 12), Some(HandClass::pair(Rank::Two)));
+
+        let kings: HandClass = "KK".parse().unwrap();
+        let dead = CardSet::from_cards(&[Card::from_string("Kh").unwrap()]);
+        assert_eq!(kings.live_combos(dead).len(), 3);
+        assert_eq!(kings.live_combos(CardSet::empty()), kings.combos());
+    }
+}
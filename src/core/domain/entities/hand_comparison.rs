@@ -0,0 +1,281 @@
+//! Explanations of why one evaluated hand beats another.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use super::card::Rank;
+use super::hand::{Hand, HandRank};
+
+/// Ordinal names of the kicker positions.
+const ORDINALS: [&str; 4] = ["first", "second", "third", "fourth"];
+
+/// What decided a comparison between two hands.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonReason {
+    /// The hands are of different categories.
+    Category {
+        /// Category of the better hand.
+        better: HandRank,
+        /// Category of the worse hand.
+        worse: HandRank,
+    },
+    /// Same category, decided by the rank making the hand: the quads, trips, pair, or
+    /// top pair, the high card of a straight, or the top card of a flush or high card.
+    MainRank {
+        /// Rank in the better hand.
+        better: Rank,
+        /// Rank in the worse hand.
+        worse: Rank,
+    },
+    /// Same main rank, decided by the pair of a full house or the low pair of two pair.
+    SecondRank {
+        /// Rank in the better hand.
+        better: Rank,
+        /// Rank in the worse hand.
+        worse: Rank,
+    },
+    /// Same made hand, decided by a kicker.
+    Kicker {
+        /// Which kicker decided, counting from 1 for the highest.
+        position: usize,
+        /// Kicker of the better hand.
+        better: Rank,
+        /// Kicker of the worse hand.
+        worse: Rank,
+    },
+    /// The hands are equal in strength and split the pot.
+    Tie,
+}
+
+impl fmt::Display for ComparisonReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Category { better, worse } => write!(f, "{better} beats {worse}"),
+            Self::MainRank { better, worse } | Self::SecondRank { better, worse } => {
+                write!(f, "{} beats {}", better.name(), worse.name())
+            }
+            Self::Kicker {
+                position,
+                better,
+                worse,
+            } => {
+                let ordinal = ORDINALS.get(position.wrapping_sub(1)).unwrap_or(&"last");
+                write!(
+                    f,
+                    "{} beats {} on the {ordinal} kicker",
+                    better.name(),
+                    worse.name()
+                )
+            }
+            Self::Tie => write!(f, "tie"),
+        }
+    }
+}
+
+/// Comparison of two evaluated hands together with what decided it.
+///
+/// Hands are compared by category, then by the ranks that make the hand, then by
+/// kickers, and the first difference found is the reason.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use riverrun::core::domain::entities::card::{Card, Rank};
+/// use riverrun::core::domain::entities::hand::Hand;
+/// use riverrun::core::domain::entities::hand_comparison::{ComparisonReason, HandComparison};
+///
+/// let card = |text| Card::from_string(text).unwrap();
+/// let queen_kicker = Hand::new(["As", "Ah", "Kd", "Kc", "Qs"].map(card), 2479);
+/// let jack_kicker = Hand::new(["Ad", "Ac", "Kh", "Ks", "Jd"].map(card), 2480);
+///
+/// let comparison = HandComparison::new(&queen_kicker, &jack_kicker);
+/// assert_eq!(comparison.ordering(), Ordering::Greater);
+/// assert_eq!(
+///     comparison.reason(),
+///     ComparisonReason::Kicker { position: 1, better: Rank::Queen, worse: Rank::Jack }
+/// );
+/// assert_eq!(comparison.to_string(), "Queen beats Jack on the first kicker");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HandComparison {
+    ordering: Ordering,
+    reason: ComparisonReason,
+}
+
+/// `HandComparison` - Constructors
+impl HandComparison {
+    /// Compares `first` against `second`.
+    ///
+    /// The ordering follows the hands' strengths. Hands whose cards do not match their
+    /// strength may be explained as a `Tie` even when their strengths differ.
+    #[must_use]
+    pub fn new(first: &Hand, second: &Hand) -> Self {
+        let ordering = first.cmp(second);
+        let (better, worse) = match ordering {
+            Ordering::Less => (second, first),
+            Ordering::Greater => (first, second),
+            Ordering::Equal => {
+                return Self {
+                    ordering,
+                    reason: ComparisonReason::Tie,
+                };
+            }
+        };
+        Self {
+            ordering,
+            reason: Self::decide(better, worse),
+        }
+    }
+}
+
+/// `HandComparison` - Accessors
+impl HandComparison {
+    /// How the first hand compares to the second; `Greater` means the first is better.
+    #[must_use]
+    pub const fn ordering(&self) -> Ordering {
+        self.ordering
+    }
+
+    /// What decided the comparison.
+    #[must_use]
+    pub const fn reason(&self) -> ComparisonReason {
+        self.reason
+    }
+
+    /// Index of the better hand, 0 for the first and 1 for the second, or `None` on a
+    /// tie.
+    #[must_use]
+    pub const fn winner(&self) -> Option<usize> {
+        match self.ordering {
+            Ordering::Greater => Some(0),
+            Ordering::Less => Some(1),
+            Ordering::Equal => None,
+        }
+    }
+
+    /// Whether the hands tie.
+    #[must_use]
+    pub const fn is_tie(&self) -> bool {
+        matches!(self.ordering, Ordering::Equal)
+    }
+}
+
+/// `HandComparison` - Helpers
+impl HandComparison {
+    /// Why `better` beats `worse`.
+    fn decide(better: &Hand, worse: &Hand) -> ComparisonReason {
+        if better.rank() != worse.rank() {
+            return ComparisonReason::Category {
+                better: better.rank(),
+                worse: worse.rank(),
+            };
+        }
+        // Full houses and two pair are made of two ranks before any kicker
+        let made_ranks = match better.rank() {
+            HandRank::FullHouse | HandRank::TwoPair => 2,
+            _ => 1,
+        };
+        better
+            .ranks_by_group()
+            .into_iter()
+            .zip(worse.ranks_by_group())
+            .enumerate()
+            .find(|(_, (better, worse))| better != worse)
+            .map_or(
+                ComparisonReason::Tie,
+                |(position, (better, worse))| match position {
+                    0 => ComparisonReason::MainRank { better, worse },
+                    1 if made_ranks == 2 => ComparisonReason::SecondRank { better, worse },
+                    _ => ComparisonReason::Kicker {
+                        position: position + 1 - made_ranks,
+                        better,
+                        worse,
+                    },
+                },
+            )
+    }
+}
+
+impl fmt::Display for HandComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::card::Card;
+
+    fn hand(cards: &str, strength: u16) -> Hand {
+        let cards: Vec<Card> = cards
+            .split_whitespace()
+            .map(|card| Card::from_string(card).unwrap())
+            .collect();
+        Hand::new(cards.try_into().unwrap(), strength)
+    }
+
+    #[test]
+    fn test_category_and_ranks() {
+        let full_house = hand("Kd Ks Kh 2c 2d", 179);
+        let flush = hand("Ad 9d 7d 3d 2d", 400);
+        let comparison = HandComparison::new(&flush, &full_house);
+        assert_eq!(comparison.winner(), Some(1));
+        assert_eq!(
+            comparison.reason(),
+            ComparisonReason::Category {
+                better: HandRank::FullHouse,
+                worse: HandRank::Flush
+            }
+        );
+        assert_eq!(comparison.to_string(), "Full House beats Flush");
+
+        let kings_full_of_threes = hand("Kd Ks Kh 3c 3d", 178);
+        assert_eq!(
+            HandComparison::new(&kings_full_of_threes, &full_house).reason(),
+            ComparisonReason::SecondRank {
+                better: Rank::Three,
+                worse: Rank::Two
+            }
+        );
+
+        // A six-high straight beats the wheel, whose ace plays low
+        let six_high = hand("6c 5d 4h 3s 2c", 1608);
+        let wheel = hand("Ac 5d 4h 3s 2c", 1609);
+        assert_eq!(
+            HandComparison::new(&six_high, &wheel).reason(),
+            ComparisonReason::MainRank {
+                better: Rank::Six,
+                worse: Rank::Five
+            }
+        );
+    }
+
+    #[test]
+    fn test_kickers_and_ties() {
+        let nine_kicker = hand("Jc Jd As 9h 3d", 3992);
+        let eight_kicker = hand("Jh Js Ad 8c 7d", 3996);
+        let comparison = HandComparison::new(&eight_kicker, &nine_kicker);
+        assert_eq!(comparison.ordering(), Ordering::Less);
+        assert_eq!(
+            comparison.reason(),
+            ComparisonReason::Kicker {
+                position: 2,
+                better: Rank::Nine,
+                worse: Rank::Eight
+            }
+        );
+        assert_eq!(
+            comparison.to_string(),
+            "Nine beats Eight on the second kicker"
+        );
+
+        let same = hand("Jh Js Ac 9d 3c", 3992);
+        let tie = HandComparison::new(&nine_kicker, &same);
+        assert!(tie.is_tie());
+        assert_eq!(tie.winner(), None);
+        assert_eq!(tie.reason(), ComparisonReason::Tie);
+    }
+}
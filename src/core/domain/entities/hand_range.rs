@@ -0,0 +1,377 @@
+//! Weighted ranges of hole cards.
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::card::{Card, Rank};
+use super::card_set::CardSet;
+use super::hand_class::HandClass;
+use super::hole_cards::HoleCards;
+
+/// Number of distinct two-card combinations in a 52-card deck.
+pub const NUM_COMBOS: usize = 1326;
+
+/// Error returned when range notation cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRangeError {
+    /// The comma-separated token that could not be parsed.
+    pub token: String,
+}
+
+impl fmt::Display for ParseRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid range token '{}'", self.token)
+    }
+}
+
+impl std::error::Error for ParseRangeError {}
+
+/// A range of hole cards, each combination holding a weight between 0.0 and 1.0.
+///
+/// A weight is the fraction of the time a player holds that combination when in this
+/// spot, so 0.5 means "half the time". Combinations with weight 0.0 are not in the range.
+///
+/// Ranges parse from the usual comma-separated notation, with an optional `:weight`
+/// suffix on any token:
+/// - `QQ`, `QQ+` (QQ through AA), `QQ-88`
+/// - `AK` (suited and offsuit), `AKs`, `AKo`
+/// - `A9s+` (A9s through AKs), `K9o-K6o`
+/// - `AhKh` for a single combination
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::hand_range::HandRange;
+///
+/// let range: HandRange = "QQ+, AKs, A5s-A2s:0.5".parse().unwrap();
+/// assert_eq!(range.combo_count(), 3 * 6 + 4 + 4 * 4);
+/// assert!((range.total_weight() - 30.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandRange {
+    /// Weight of each combination by combination index.
+    weights: Vec<f64>,
+}
+
+/// `HandRange` - Constructors
+impl HandRange {
+    /// An empty range.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            weights: vec![0.0; NUM_COMBOS],
+        }
+    }
+
+    /// Every combination at full weight.
+    #[must_use]
+    pub fn full() -> Self {
+        Self {
+            weights: vec![1.0; NUM_COMBOS],
+        }
+    }
+
+    /// Every combination of the given classes at full weight.
+    #[must_use]
+    pub fn from_classes(classes: impl IntoIterator<Item = HandClass>) -> Self {
+        let mut range = Self::new();
+        for class in classes {
+            range.add_class(class, 1.0);
+        }
+        range
+    }
+}
+
+impl Default for HandRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `HandRange` - Accessors
+impl HandRange {
+    /// Weight of `hole_cards`, or 0.0 if they are not in the range.
+    #[must_use]
+    pub fn weight(&self, hole_cards: &HoleCards) -> f64 {
+        combo_index(*hole_cards).map_or(0.0, |index| self.weights[index])
+    }
+
+    /// Returns whether `hole_cards` have a positive weight.
+    #[must_use]
+    pub fn contains(&self, hole_cards: &HoleCards) -> bool {
+        self.weight(hole_cards) > 0.0
+    }
+
+    /// Returns whether no combination has a positive weight.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.weights.iter().all(|&weight| weight <= 0.0)
+    }
+
+    /// Number of combinations with a positive weight.
+    #[must_use]
+    pub fn combo_count(&self) -> usize {
+        self.weights.iter().filter(|&&weight| weight > 0.0).count()
+    }
+
+    /// Sum of all weights, i.e. the number of combinations counting partial weights.
+    #[must_use]
+    pub fn total_weight(&self) -> f64 {
+        self.weights.iter().sum()
+    }
+
+    /// Every combination with a positive weight, paired with its weight.
+    pub fn combos(&self) -> impl Iterator<Item = (HoleCards, f64)> + '_ {
+        all_combos()
+            .zip(&self.weights)
+            .filter(|&(_, &weight)| weight > 0.0)
+            .map(|(combo, &weight)| (combo, weight))
+    }
+}
+
+/// `HandRange` - Operations
+impl HandRange {
+    /// Sets the weight of `hole_cards`, clamped to 0.0..=1.0.
+    ///
+    /// Hole cards holding the same card twice are ignored.
+    pub fn set_weight(&mut self, hole_cards: &HoleCards, weight: f64) {
+        if let Some(index) = combo_index(*hole_cards) {
+            self.weights[index] = weight.clamp(0.0, 1.0);
+        }
+    }
+
+    /// Sets the weight of every combination of `class`.
+    pub fn add_class(&mut self, class: HandClass, weight: f64) {
+        for combo in class.combos() {
+            self.set_weight(&combo, weight);
+        }
+    }
+
+    /// Removes every combination holding one of the `dead` cards.
+    pub fn remove_dead(&mut self, dead: &[Card]) {
+        let dead = CardSet::from_cards(dead);
+        for (combo, weight) in all_combos().zip(&mut self.weights) {
+            if combo.cards().iter().any(|&card| dead.contains(card)) {
+                *weight = 0.0;
+            }
+        }
+    }
+
+    /// A copy of this range without the combinations holding one of the `dead` cards.
+    #[must_use]
+    pub fn without(&self, dead: &[Card]) -> Self {
+        let mut range = self.clone();
+        range.remove_dead(dead);
+        range
+    }
+}
+
+impl FromStr for HandRange {
+    type Err = ParseRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut range = Self::new();
+        for token in s
+            .split(',')
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+        {
+            let error = || ParseRangeError {
+                token: token.to_string(),
+            };
+            let (notation, weight) = match token.split_once(':') {
+                Some((notation, weight)) => {
+                    let weight: f64 = weight.trim().parse().map_err(|_| error())?;
+                    if !(0.0..=1.0).contains(&weight) {
+                        return Err(error());
+                    }
+                    (notation.trim(), weight)
+                }
+                None => (token, 1.0),
+            };
+
+            if let Some(combo) = parse_combo(notation) {
+                range.set_weight(&combo, weight);
+                continue;
+            }
+            for class in parse_classes(notation).ok_or_else(error)? {
+                range.add_class(class, weight);
+            }
+        }
+        Ok(range)
+    }
+}
+
+/// Index of an unordered pair of distinct cards, below `NUM_COMBOS`.
+fn combo_index(hole_cards: HoleCards) -> Option<usize> {
+    let first = hole_cards.first().index();
+    let second = hole_cards.second().index();
+    let (high, low) = (first.max(second), first.min(second));
+    (high != low).then(|| high * (high - 1) / 2 + low)
+}
+
+/// Every combination in index order.
+fn all_combos() -> impl Iterator<Item = HoleCards> {
+    (1..52).flat_map(|high| {
+        (0..high).filter_map(move |low| {
+            Some(HoleCards::new(
+                Card::from_index(high)?,
+                Card::from_index(low)?,
+            ))
+        })
+    })
+}
+
+/// Parses a single combination such as `AhKh`.
+fn parse_combo(notation: &str) -> Option<HoleCards> {
+    if notation.len() != 4 || !notation.is_ascii() {
+        return None;
+    }
+    let first: Card = notation[..2].parse().ok()?;
+    let second: Card = notation[2..].parse().ok()?;
+    (first != second).then(|| HoleCards::new(first, second))
+}
+
+/// Parses class notation: a class, a class with `+`, or a span `from-to`.
+fn parse_classes(notation: &str) -> Option<Vec<HandClass>> {
+    // Without a suited marker, a non-pair means both the suited and offsuit classes
+    let expand = |notation: &str| -> Option<Vec<HandClass>> {
+        let class: Option<HandClass> = notation.parse().ok();
+        class.map(|class| vec![class]).or_else(|| {
+            let suited = format!("{notation}s").parse().ok()?;
+            let offsuit = format!("{notation}o").parse().ok()?;
+            Some(vec![suited, offsuit])
+        })
+    };
+
+    if let Some(base) = notation.strip_suffix('+') {
+        let classes = expand(base)?;
+        return Some(
+            classes
+                .into_iter()
+                .filter_map(|class| span(class, None))
+                .flatten()
+                .collect(),
+        );
+    }
+    if let Some((from, to)) = notation.split_once('-') {
+        let (from, to) = (expand(from)?, expand(to)?);
+        if from.len() != to.len() {
+            return None;
+        }
+        let mut classes = Vec::new();
+        for (from, to) in from.into_iter().zip(to) {
+            classes.extend(span(from, Some(to))?);
+        }
+        return Some(classes);
+    }
+    expand(notation)
+}
+
+/// Classes from `from` up to `to`, or up to the strongest class of its kind.
+///
+/// Pairs climb in rank; other classes keep their high card and climb the kicker up to
+/// one below it. `None` if `to` is not of the same kind as `from`.
+fn span(from: HandClass, to: Option<HandClass>) -> Option<Vec<HandClass>> {
+    let ranks: Vec<Rank> = Rank::all().collect();
+    if from.is_pair() {
+        let to = to.unwrap_or(HandClass::pair(Rank::Ace));
+        if !to.is_pair() {
+            return None;
+        }
+        let (low, high) = (from.high().min(to.high()), from.high().max(to.high()));
+        return Some(
+            ranks
+                .into_iter()
+                .filter(|rank| (low..=high).contains(rank))
+                .map(HandClass::pair)
+                .collect(),
+        );
+    }
+
+    let kicker = match to {
+        Some(to)
+            if to.high() == from.high() && to.is_suited() == from.is_suited() && !to.is_pair() =>
+        {
+            to.low()
+        }
+        Some(_) => return None,
+        None => Rank::from_u8(from.high() as u8 - 1)?,
+    };
+    let (low, high) = (from.low().min(kicker), from.low().max(kicker));
+    let make = if from.is_suited() {
+        HandClass::suited
+    } else {
+        HandClass::offsuit
+    };
+    ranks
+        .into_iter()
+        .filter(|rank| (low..=high).contains(rank))
+        .map(|kicker| make(from.high(), kicker))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(text: &str) -> HandRange {
+        text.parse().unwrap()
+    }
+
+    fn hole(text: &str) -> HoleCards {
+        HoleCards::new(text[..2].parse().unwrap(), text[2..].parse().unwrap())
+    }
+
+    #[test]
+    fn test_combo_index_is_dense() {
+        let indexes: Vec<usize> = all_combos()
+            .map(|combo| combo_index(combo).unwrap())
+            .collect();
+        assert_eq!(indexes, (0..NUM_COMBOS).collect::<Vec<_>>());
+        assert_eq!(combo_index(hole("AhKh")), combo_index(hole("KhAh")));
+        assert_eq!(combo_index(hole("AhAh")), None);
+    }
+
+    #[test]
+    fn test_parse_notation() {
+        assert_eq!(range("QQ+").combo_count(), 18);
+        assert_eq!(range("TT-77").combo_count(), 24);
+        assert_eq!(range("AK").combo_count(), 16);
+        assert_eq!(range("A9s+").combo_count(), 20);
+        assert_eq!(range("K9o-K6o").combo_count(), 48);
+        assert_eq!(range("KT+").combo_count(), 48);
+        assert_eq!(range("AhKh, AhKh").combo_count(), 1);
+        assert_eq!(range("").combo_count(), 0);
+
+        let weighted = range("AA:0.25, KK");
+        assert!((weighted.weight(&hole("AsAd")) - 0.25).abs() < f64::EPSILON);
+        assert!((weighted.total_weight() - 7.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for bad in ["AKx", "QQ-AKs", "A9s-K5s", "AA:2", "AhAh", "AK:x"] {
+            assert_eq!(
+                bad.parse::<HandRange>(),
+                Err(ParseRangeError {
+                    token: bad.to_string()
+                }),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_remove_dead() {
+        let mut aces = range("AA, AKs");
+        assert_eq!(aces.combo_count(), 10);
+        let dead = [Card::from_string("As").unwrap()];
+        assert_eq!(aces.without(&dead).combo_count(), 6);
+
+        aces.remove_dead(&dead);
+        assert!(!aces.contains(&hole("AsAd")));
+        assert!(aces.contains(&hole("AhAd")));
+        assert_eq!(HandRange::full().without(&dead).combo_count(), 1275);
+    }
+}
@@ -1,6 +1,13 @@
 //! Hole cards representation for Texas Hold'em.
 
-use super::card::Card;
+use std::str::FromStr;
+
+use super::board::Board;
+use super::card::{Card, ParseCardError, parse_cards};
+use super::card_display::{CardsDisplay, DisplayStyle};
+use super::card_set::CardSet;
+use super::hand_class::HandClass;
+use crate::core::domain::errors::CardError;
 
 /// A player's two private hole cards.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -17,10 +24,28 @@ impl HoleCards {
             cards: [first, second],
         }
     }
+
+    /// Constructs `HoleCards` from two different cards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::DuplicateCard` if both cards are the same.
+    pub fn try_new(first: Card, second: Card) -> Result<Self, CardError> {
+        if first == second {
+            return Err(CardError::DuplicateCard(first));
+        }
+        Ok(Self::new(first, second))
+    }
 }
 
 /// `HoleCards` - Accessors
 impl HoleCards {
+    /// Renders the cards back to back in `style`, e.g. `A♠K♠`.
+    #[must_use]
+    pub const fn display(&self, style: DisplayStyle) -> CardsDisplay<'_> {
+        CardsDisplay::new(&self.cards, style)
+    }
+
     /// Return the first card of the hole cards.
     #[must_use]
     pub const fn first(&self) -> Card {
@@ -83,6 +108,22 @@ impl HoleCards {
         self.gap() == 0
     }
 
+    /// The starting hand class of these cards, such as "AKs", "QQ", or "T9o".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::card::Card;
+    /// use riverrun::core::domain::entities::hole_cards::HoleCards;
+    ///
+    /// let hole = HoleCards::new(Card::from_string("Kh").unwrap(), Card::from_string("Ah").unwrap());
+    /// assert_eq!(hole.class().to_string(), "AKs");
+    /// ```
+    #[must_use]
+    pub fn class(&self) -> HandClass {
+        HandClass::from_hole_cards(self)
+    }
+
     /// Produces a 7-card array by appending a 5-card board to these hole cards.
     #[must_use]
     pub const fn combine_with_board(&self, board: [Card; 5]) -> [Card; 7] {
@@ -98,6 +139,47 @@ impl HoleCards {
     }
 }
 
+/// Checks that no card is dealt twice across every player's hole cards and the board.
+///
+/// Equity and showdown math assume each card exists once; a repeated card silently
+/// skews the results.
+///
+/// # Errors
+///
+/// Returns `CardError::DuplicateCard` with the first card, in player then board order,
+/// that was already seen.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::board::Board;
+/// use riverrun::core::domain::entities::card::Card;
+/// use riverrun::core::domain::entities::hole_cards::{validate_no_duplicates, HoleCards};
+/// use riverrun::core::domain::errors::CardError;
+///
+/// let card = |text| Card::from_string(text).unwrap();
+/// let players = [HoleCards::new(card("As"), card("Kd"))];
+/// let board = Board::with_cards(vec![card("Qh"), card("Kd"), card("2c")]).unwrap();
+/// assert_eq!(
+///     validate_no_duplicates(&players, &board),
+///     Err(CardError::DuplicateCard(card("Kd")))
+/// );
+/// ```
+pub fn validate_no_duplicates(players: &[HoleCards], board: &Board) -> Result<(), CardError> {
+    let mut seen = CardSet::empty();
+    players
+        .iter()
+        .flat_map(HoleCards::cards)
+        .chain(board.cards())
+        .try_for_each(|&card| {
+            if seen.insert(card) {
+                Ok(())
+            } else {
+                Err(CardError::DuplicateCard(card))
+            }
+        })
+}
+
 impl std::fmt::Display for HoleCards {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}{}", self.cards[0], self.cards[1])
@@ -118,6 +200,21 @@ impl From<(Card, Card)> for HoleCards {
     }
 }
 
+impl FromStr for HoleCards {
+    type Err = ParseCardError;
+
+    /// Parses two hole cards such as `"AsKs"`, `"As Ks"`, or `"As,Ks"`.
+    ///
+    /// Returns `ParseCardError::InvalidCount` unless there are exactly two cards, and
+    /// `ParseCardError::DuplicateCard` if they are the same card.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_cards(s)?.as_slice() {
+            &[first, second] => Ok(Self::try_new(first, second)?),
+            cards => Err(ParseCardError::InvalidCount(cards.len())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,6 +234,41 @@ mod tests {
         assert_eq!(hole.second(), card(Rank::King, Suit::Spades));
     }
 
+    #[test]
+    fn test_try_new_rejects_duplicates() {
+        let ace = card(Rank::Ace, Suit::Spades);
+        assert_eq!(HoleCards::try_new(ace, ace), Err(CardError::DuplicateCard(ace)));
+        let king = card(Rank::King, Suit::Spades);
+        assert_eq!(HoleCards::try_new(ace, king), Ok(HoleCards::new(ace, king)));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let ace = card(Rank::Ace, Suit::Spades);
+        let king = card(Rank::King, Suit::Spades);
+        for text in ["AsKs", "As Ks", "As,Ks"] {
+            assert_eq!(text.parse(), Ok(HoleCards::new(ace, king)));
+        }
+        assert_eq!("AsAs".parse::<HoleCards>(), Err(ParseCardError::DuplicateCard(ace)));
+        assert_eq!("As".parse::<HoleCards>(), Err(ParseCardError::InvalidCount(1)));
+        assert_eq!("AsKsQs".parse::<HoleCards>(), Err(ParseCardError::InvalidCount(3)));
+        assert_eq!("AsKx".parse::<HoleCards>(), Err(ParseCardError::InvalidSuit));
+    }
+
+    #[test]
+    fn test_validate_no_duplicates() {
+        let players = [
+            HoleCards::new(card(Rank::Ace, Suit::Spades), card(Rank::King, Suit::Spades)),
+            HoleCards::new(card(Rank::Two, Suit::Hearts), card(Rank::Ace, Suit::Spades)),
+        ];
+        let board = Board::new();
+        assert_eq!(
+            validate_no_duplicates(&players, &board),
+            Err(CardError::DuplicateCard(card(Rank::Ace, Suit::Spades)))
+        );
+        assert_eq!(validate_no_duplicates(&players[..1], &board), Ok(()));
+    }
+
     #[test]
     fn test_is_suited() {
         let suited = HoleCards::new(
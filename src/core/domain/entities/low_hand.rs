@@ -0,0 +1,180 @@
+//! Qualifying low hands for hi-lo split games.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use super::card::{Card, Rank};
+
+/// An eight-or-better low: five distinct ranks from ace through eight.
+///
+/// Aces play low, and straights and flushes do not count against a low. Hands compare
+/// from the highest card down, so 5-4-3-2-A (the wheel) is the best low and 8-7-6-5-4
+/// the worst. Like `Hand`, a lower strength is a better hand.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::card::Rank;
+/// use riverrun::core::domain::entities::low_hand::LowHand;
+///
+/// let wheel = LowHand::from_ranks([Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]).unwrap();
+/// let eight = LowHand::from_ranks([Rank::Eight, Rank::Six, Rank::Four, Rank::Two, Rank::Ace]).unwrap();
+/// assert!(wheel.beats(&eight));
+/// assert_eq!(eight.to_string(), "8-6-4-2-A");
+/// assert_eq!(LowHand::from_ranks([Rank::Nine, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]), None);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LowHand {
+    /// One bit per rank, the ace in bit 0 through the eight in bit 7.
+    mask: u8,
+}
+
+/// `LowHand` - Constructors
+impl LowHand {
+    /// Builds a low from five ranks in any order.
+    ///
+    /// # Returns
+    /// `None` unless the ranks are distinct and all eight or lower.
+    #[must_use]
+    pub fn from_ranks(ranks: [Rank; 5]) -> Option<Self> {
+        let mut mask = 0u8;
+        for rank in ranks {
+            mask |= low_bit(rank)?;
+        }
+        Self::from_mask(mask)
+    }
+
+    /// Builds a low from a rank mask, which must hold exactly five ranks.
+    pub(crate) const fn from_mask(mask: u8) -> Option<Self> {
+        if mask.count_ones() == 5 {
+            Some(Self { mask })
+        } else {
+            None
+        }
+    }
+}
+
+/// `LowHand` - Accessors
+impl LowHand {
+    /// Numeric strength from 31 (the wheel) to 248 (8-7-6-5-4); lower is better.
+    #[must_use]
+    pub const fn strength(&self) -> u8 {
+        self.mask
+    }
+
+    /// The five ranks, highest first with the ace last.
+    #[must_use]
+    pub fn ranks(&self) -> [Rank; 5] {
+        let mut ranks = [Rank::Ace; 5];
+        let bits = (0..8).rev().filter(|bit| self.mask & (1 << bit) != 0);
+        for (slot, bit) in ranks.iter_mut().zip(bits) {
+            *slot = if bit == 0 {
+                Rank::Ace
+            } else {
+                Rank::from_u8(bit - 1).unwrap_or(Rank::Ace)
+            };
+        }
+        ranks
+    }
+
+    /// The highest rank, which names the low (e.g. an "eight low").
+    #[must_use]
+    pub fn high_card(&self) -> Rank {
+        self.ranks()[0]
+    }
+
+    /// Returns whether this low beats `other`.
+    #[must_use]
+    pub const fn beats(&self, other: &Self) -> bool {
+        self.mask < other.mask
+    }
+
+    /// Returns whether this low ties `other`.
+    #[must_use]
+    pub const fn ties(&self, other: &Self) -> bool {
+        self.mask == other.mask
+    }
+}
+
+impl Ord for LowHand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Lower strength is better, so reverse the comparison
+        other.mask.cmp(&self.mask)
+    }
+}
+
+impl PartialOrd for LowHand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for LowHand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, rank) in self.ranks().iter().enumerate() {
+            if i > 0 {
+                write!(f, "-")?;
+            }
+            write!(f, "{rank}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Mask bit of a rank that can play in a low, ace low.
+pub(crate) const fn low_bit(rank: Rank) -> Option<u8> {
+    match rank {
+        Rank::Ace => Some(1),
+        Rank::Two
+        | Rank::Three
+        | Rank::Four
+        | Rank::Five
+        | Rank::Six
+        | Rank::Seven
+        | Rank::Eight => Some(1 << (rank as u8 + 1)),
+        _ => None,
+    }
+}
+
+/// Mask bit of a card's rank if it can play in a low, otherwise 0.
+pub(crate) const fn card_low_bit(card: Card) -> u8 {
+    match low_bit(card.rank_enum()) {
+        Some(bit) => bit,
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_low_ordering() {
+        let low = |ranks| LowHand::from_ranks(ranks).unwrap();
+        let wheel = low([Rank::Ace, Rank::Two, Rank::Three, Rank::Four, Rank::Five]);
+        let six = low([Rank::Six, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]);
+        let eight = low([Rank::Eight, Rank::Seven, Rank::Six, Rank::Five, Rank::Four]);
+        let seven_six = low([Rank::Seven, Rank::Six, Rank::Three, Rank::Two, Rank::Ace]);
+        let seven_five = low([Rank::Seven, Rank::Five, Rank::Four, Rank::Three, Rank::Two]);
+
+        assert_eq!(wheel.strength(), 31);
+        assert_eq!(eight.strength(), 248);
+        assert!(wheel > six && six > seven_five && seven_five > seven_six && seven_six > eight);
+        assert!(seven_five.beats(&seven_six));
+        assert!(wheel.ties(&wheel));
+        assert_eq!(seven_six.high_card(), Rank::Seven);
+        assert_eq!(wheel.to_string(), "5-4-3-2-A");
+    }
+
+    #[test]
+    fn test_from_ranks_rejects_non_lows() {
+        assert_eq!(
+            LowHand::from_ranks([Rank::Two, Rank::Two, Rank::Three, Rank::Four, Rank::Five]),
+            None
+        );
+        assert_eq!(
+            LowHand::from_ranks([Rank::King, Rank::Two, Rank::Three, Rank::Four, Rank::Five]),
+            None
+        );
+    }
+}
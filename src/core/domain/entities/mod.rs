@@ -1,6 +1,12 @@
 pub mod board;
 pub mod card;
+pub mod card_display;
+pub mod card_set;
 pub mod deck;
 pub mod game;
 pub mod hand;
-pub mod hole_cards;
\ No newline at end of file
+pub mod hand_comparison;
+pub mod hand_class;
+pub mod hand_range;
+pub mod hole_cards;
+pub mod low_hand;
\ No newline at end of file
@@ -0,0 +1,304 @@
+//! Domain errors raised when a command violates game invariants.
+
+use std::error::Error;
+use std::fmt;
+
+use super::entities::card::{Card, ParseCardError};
+use super::primitives::{Action, Chips, GameId, PlayerId, SessionId};
+
+/// Error returned when a game command is rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    /// The table must seat between 2 and 10 players.
+    InvalidPlayerCount(usize),
+    /// Blinds must be non-zero with the small blind not exceeding the big blind.
+    InvalidBlinds { small_blind: Chips, big_blind: Chips },
+    /// The button is not one of the seated players.
+    InvalidButton(PlayerId),
+    /// A seated player has no chips to play with.
+    EmptyStack(PlayerId),
+    /// The deck cannot cover hole cards, burns, and a full board.
+    InsufficientDeck { required: usize, available: usize },
+    /// A hand is already being played.
+    HandInProgress,
+    /// No hand is being played.
+    NoHandInProgress,
+    /// The action was rejected by the betting rules.
+    InvalidAction(BettingError),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPlayerCount(count) => {
+                write!(f, "invalid player count: {count} (expected 2-10)")
+            }
+            Self::InvalidBlinds {
+                small_blind,
+                big_blind,
+            } => write!(f, "invalid blinds: {small_blind}/{big_blind}"),
+            Self::InvalidButton(player) => write!(f, "button is not seated: {player}"),
+            Self::EmptyStack(player) => write!(f, "{player} has no chips"),
+            Self::InsufficientDeck {
+                required,
+                available,
+            } => write!(f, "deck has {available} cards, {required} required"),
+            Self::HandInProgress => write!(f, "a hand is already in progress"),
+            Self::NoHandInProgress => write!(f, "no hand in progress"),
+            Self::InvalidAction(err) => write!(f, "invalid action: {err}"),
+        }
+    }
+}
+
+impl Error for GameError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidAction(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<BettingError> for GameError {
+    fn from(err: BettingError) -> Self {
+        Self::InvalidAction(err)
+    }
+}
+
+/// Error returned when cards cannot have been dealt from a single deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardError {
+    /// The card appears more than once among the hole cards and board.
+    DuplicateCard(Card),
+    /// A board holds 0, 3, 4, or 5 cards.
+    InvalidBoardSize(usize),
+}
+
+impl fmt::Display for CardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateCard(card) => write!(f, "duplicate card: {card}"),
+            Self::InvalidBoardSize(count) => {
+                write!(f, "invalid board size: {count} (expected 0, 3, 4, or 5)")
+            }
+        }
+    }
+}
+
+impl Error for CardError {}
+
+impl From<CardError> for ParseCardError {
+    fn from(err: CardError) -> Self {
+        match err {
+            CardError::DuplicateCard(card) => Self::DuplicateCard(card),
+            CardError::InvalidBoardSize(count) => Self::InvalidCount(count),
+        }
+    }
+}
+
+/// Error returned when a betting action is rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BettingError {
+    /// No betting round is in progress.
+    NoActiveRound,
+    /// The player is not seated at the table.
+    UnknownPlayer(PlayerId),
+    /// The player has already folded.
+    PlayerFolded(PlayerId),
+    /// The player is all-in and cannot act further.
+    PlayerAllIn(PlayerId),
+    /// A player acted out of turn.
+    OutOfTurn { expected: PlayerId, actual: PlayerId },
+    /// Every player has acted and the round is waiting to be ended.
+    RoundComplete,
+    /// The player tried to check while facing a bet.
+    CannotCheck { to_call: Chips },
+    /// The player tried to call with nothing owed.
+    NothingToCall,
+    /// The player tried to bet while facing a bet; they must raise instead.
+    BetFacingBet { current_bet: Chips },
+    /// The player tried to raise with no bet to raise; they must bet instead.
+    NoBetToRaise,
+    /// The bet or raise is smaller than the minimum.
+    BelowMinimum { action: Action, min: Chips },
+    /// The bet or raise needs more chips than the player has or the limit allows.
+    ExceedsStack { action: Action, max: Chips },
+    /// The call or all-in amount does not match what the action puts in.
+    WrongAmount { action: Action, expected: Chips },
+    /// The round already has as many bets and raises as the rules allow.
+    RaiseCapReached { cap: u8 },
+    /// Applying the action created or destroyed chips. Only checked in debug builds.
+    ChipsNotConserved(ChipConservationError),
+}
+
+impl fmt::Display for BettingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoActiveRound => write!(f, "no betting round in progress"),
+            Self::UnknownPlayer(player) => write!(f, "{player} is not seated"),
+            Self::PlayerFolded(player) => write!(f, "{player} has folded"),
+            Self::PlayerAllIn(player) => write!(f, "{player} is all-in"),
+            Self::OutOfTurn { expected, actual } => {
+                write!(f, "not {actual}'s turn: waiting on {expected}")
+            }
+            Self::RoundComplete => write!(f, "betting round is complete"),
+            Self::CannotCheck { to_call } => write!(f, "cannot check facing {to_call} to call"),
+            Self::NothingToCall => write!(f, "nothing to call"),
+            Self::BetFacingBet { current_bet } => {
+                write!(f, "cannot bet facing a bet of {current_bet}; raise instead")
+            }
+            Self::NoBetToRaise => write!(f, "no bet to raise; bet instead"),
+            Self::BelowMinimum { action, min } => write!(f, "{action} is below the minimum of {min}"),
+            Self::ExceedsStack { action, max } => write!(f, "{action} exceeds the maximum of {max}"),
+            Self::WrongAmount { action, expected } => {
+                write!(f, "{action} does not match the expected amount of {expected}")
+            }
+            Self::RaiseCapReached { cap } => {
+                write!(f, "betting is capped at {cap} bets and raises this round")
+            }
+            Self::ChipsNotConserved(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for BettingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ChipsNotConserved(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ChipConservationError> for BettingError {
+    fn from(err: ChipConservationError) -> Self {
+        Self::ChipsNotConserved(err)
+    }
+}
+
+/// Error returned when the chips on the table no longer add up to the starting stacks.
+///
+/// Every chip is either behind in a stack or in the pot, so a mismatch means a pot
+/// accounting bug rather than a player mistake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChipConservationError {
+    /// Chips the players started the hand with.
+    pub expected: Chips,
+    /// Chips currently in the players' stacks.
+    pub stacks: Chips,
+    /// Chips currently in the main and side pots.
+    pub pot: Chips,
+}
+
+impl fmt::Display for ChipConservationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "chips not conserved: {} in stacks and {} in the pot, expected {} in total",
+            self.stacks, self.pot, self.expected
+        )
+    }
+}
+
+impl Error for ChipConservationError {}
+
+/// Error returned when a tournament command is rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TournamentError {
+    /// The tournament has not been created yet.
+    NotCreated,
+    /// The tournament has already been created.
+    AlreadyCreated,
+    /// Tables must seat between 2 and 10 players.
+    InvalidTableSize(usize),
+    /// Payout shares must be non-empty and add up to 10,000 basis points.
+    InvalidPayouts,
+    /// Registration is closed because the tournament has started.
+    RegistrationClosed,
+    /// The player is already registered.
+    AlreadyRegistered(SessionId),
+    /// The player is not registered, or has already been eliminated.
+    NotRegistered(SessionId),
+    /// A tournament needs at least two entrants to start.
+    NotEnoughEntrants(usize),
+    /// Too few table IDs were supplied to seat every entrant.
+    NotEnoughTables { required: usize, available: usize },
+    /// A table ID was supplied more than once.
+    DuplicateTable(GameId),
+    /// The tournament is not running.
+    NotRunning,
+}
+
+impl fmt::Display for TournamentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotCreated => write!(f, "tournament has not been created"),
+            Self::AlreadyCreated => write!(f, "tournament already exists"),
+            Self::InvalidTableSize(size) => {
+                write!(f, "invalid table size: {size} (expected 2-10)")
+            }
+            Self::InvalidPayouts => write!(f, "payout shares must add up to 10000 basis points"),
+            Self::RegistrationClosed => write!(f, "registration is closed"),
+            Self::AlreadyRegistered(player) => write!(f, "{player} is already registered"),
+            Self::NotRegistered(player) => write!(f, "{player} is not in the tournament"),
+            Self::NotEnoughEntrants(count) => {
+                write!(f, "{count} entrants registered, at least 2 required")
+            }
+            Self::NotEnoughTables {
+                required,
+                available,
+            } => write!(f, "{available} tables supplied, {required} required"),
+            Self::DuplicateTable(table) => write!(f, "table {table} supplied twice"),
+            Self::NotRunning => write!(f, "tournament is not running"),
+        }
+    }
+}
+
+impl Error for TournamentError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_error_display() {
+        let err = GameError::from(BettingError::OutOfTurn {
+            expected: PlayerId::new(2),
+            actual: PlayerId::new(0),
+        });
+        assert_eq!(
+            err.to_string(),
+            "invalid action: not Player 0's turn: waiting on Player 2"
+        );
+        assert!(err.source().is_some());
+        assert!(GameError::HandInProgress.source().is_none());
+    }
+
+    #[test]
+    fn test_betting_error_display() {
+        let err = BettingError::BelowMinimum {
+            action: Action::Raise(Chips::new(15)),
+            min: Chips::new(20),
+        };
+        assert_eq!(err.to_string(), "Raise to 15 is below the minimum of 20");
+
+        let err = BettingError::CannotCheck {
+            to_call: Chips::new(10),
+        };
+        assert_eq!(err.to_string(), "cannot check facing 10 to call");
+    }
+
+    #[test]
+    fn test_chip_conservation_error_display() {
+        let err = BettingError::from(ChipConservationError {
+            expected: Chips::new(2000),
+            stacks: Chips::new(1900),
+            pot: Chips::new(150),
+        });
+        assert_eq!(
+            err.to_string(),
+            "chips not conserved: 1900 in stacks and 150 in the pot, expected 2000 in total"
+        );
+        assert!(err.source().is_some());
+    }
+}
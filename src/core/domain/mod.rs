@@ -1,2 +1,5 @@
+pub mod aggregates;
 pub mod entities;
+pub mod errors;
+pub mod primitives;
 pub mod services;
@@ -1,10 +1,10 @@
 //! Player actions in poker.
 
-use super::Chips;
+use super::{Chips, PlayerId};
 use std::fmt;
 
 /// A player action in a poker hand.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Action {
     /// Fold the hand
     Fold,
@@ -78,6 +78,19 @@ impl fmt::Display for Action {
     }
 }
 
+/// Outcome of a validated action applied to a betting state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AppliedAction {
+    /// Player who acted.
+    pub player: PlayerId,
+    /// Action as requested.
+    pub action: Action,
+    /// Chips the action moved from the player's stack into the pot.
+    pub amount: Chips,
+    /// Whether the player is all-in after acting.
+    pub all_in: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,9 @@
 //! Betting and pot state primitives.
 
-use super::{Chips, PlayerId};
+use super::{
+    Action, AppliedAction, BetRange, Chips, LegalActions, LimitType, PlayerId, RuleSet, Street,
+};
+use crate::core::domain::errors::{BettingError, ChipConservationError};
 use std::fmt;
 
 /// Represents a pot in a poker hand.
@@ -106,6 +109,10 @@ pub struct BettingRound {
     has_acted: Vec<bool>,
     /// Number of raises made this round.
     raise_count: u8,
+    /// Whether the big blind opened the betting, as it does preflop.
+    opened_by_blind: bool,
+    /// Size of every bet and raise, in fixed-limit rounds.
+    fixed_bet: Option<Chips>,
 }
 
 impl BettingRound {
@@ -118,6 +125,8 @@ impl BettingRound {
             contributions: vec![Chips::ZERO; num_players],
             has_acted: vec![false; num_players],
             raise_count: 0,
+            opened_by_blind: true,
+            fixed_bet: None,
         }
     }
 
@@ -130,9 +139,19 @@ impl BettingRound {
             contributions: vec![Chips::ZERO; num_players],
             has_acted: vec![false; num_players],
             raise_count: 0,
+            opened_by_blind: false,
+            fixed_bet: None,
         }
     }
 
+    /// This round with every bet and raise exactly `size`, as in fixed-limit games.
+    #[must_use]
+    pub const fn with_fixed_bet(mut self, size: Chips) -> Self {
+        self.min_raise = size;
+        self.fixed_bet = Some(size);
+        self
+    }
+
     /// Returns the current bet amount to call.
     #[must_use]
     pub const fn current_bet(&self) -> Chips {
@@ -175,6 +194,23 @@ impl BettingRound {
         self.raise_count
     }
 
+    /// Returns the number of bets and raises this round, counting the big blind as the
+    /// opening bet preflop.
+    #[must_use]
+    pub const fn bets(&self) -> u8 {
+        if self.opened_by_blind {
+            self.raise_count.saturating_add(1)
+        } else {
+            self.raise_count
+        }
+    }
+
+    /// Returns the size of every bet and raise in a fixed-limit round.
+    #[must_use]
+    pub const fn fixed_bet(&self) -> Option<Chips> {
+        self.fixed_bet
+    }
+
     /// Records a bet/raise from a player.
     ///
     /// # Arguments
@@ -212,6 +248,17 @@ impl BettingRound {
         additional
     }
 
+    /// Records a forced blind.
+    ///
+    /// Unlike `record_bet`, posting a blind does not count as acting, so the big blind
+    /// keeps the option to raise when the action is limped around to them.
+    pub fn record_blind(&mut self, player: PlayerId, total_bet: Chips) {
+        if let Some(contribution) = self.contributions.get_mut(player.as_index()) {
+            *contribution = total_bet;
+        }
+        self.current_bet = self.current_bet.max(total_bet);
+    }
+
     /// Records a check action.
     pub fn record_check(&mut self, player: PlayerId) {
         let idx = player.as_index();
@@ -258,6 +305,14 @@ pub struct BettingState {
     is_all_in: Vec<bool>,
     /// Whether each player has folded.
     has_folded: Vec<bool>,
+    /// Player whose turn it is, while turn order is tracked.
+    to_act: Option<PlayerId>,
+    /// Whether turn order is tracked for the current round.
+    tracks_turns: bool,
+    /// Chips the players started with, which stacks and pots must always add up to.
+    starting_chips: Chips,
+    /// Rules limiting bets and raises.
+    rules: RuleSet,
 }
 
 impl BettingState {
@@ -265,6 +320,7 @@ impl BettingState {
     #[must_use]
     pub fn new(stacks: Vec<Chips>) -> Self {
         let num_players = stacks.len();
+        let starting_chips = stacks.iter().fold(Chips::ZERO, |acc, &stack| acc + stack);
         Self {
             main_pot: Pot::new(),
             side_pots: Vec::new(),
@@ -273,9 +329,26 @@ impl BettingState {
             stacks,
             is_all_in: vec![false; num_players],
             has_folded: vec![false; num_players],
+            to_act: None,
+            tracks_turns: false,
+            starting_chips,
+            rules: RuleSet::default(),
         }
     }
 
+    /// This betting state enforcing `rules` instead of uncapped no-limit betting.
+    #[must_use]
+    pub const fn with_rules(mut self, rules: RuleSet) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Returns the rules bets and raises are checked against.
+    #[must_use]
+    pub const fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
     /// Returns the number of players.
     #[must_use]
     pub fn num_players(&self) -> usize {
@@ -355,13 +428,72 @@ impl BettingState {
         self.current_round.as_ref()
     }
 
+    /// Returns the player whose turn it is.
+    ///
+    /// `None` until turn order is established with `set_first_to_act`, and once every
+    /// player has acted.
+    #[must_use]
+    pub const fn to_act(&self) -> Option<PlayerId> {
+        self.to_act
+    }
+
+    /// First seat, clockwise from `from` (inclusive), that still owes an action.
+    ///
+    /// A player owes an action if they can still bet and are either behind the current
+    /// bet or have not acted yet. A lone player who has matched the bet has nobody left
+    /// to bet against, so the round is over.
+    #[must_use]
+    pub fn next_to_act_from(&self, from: PlayerId) -> Option<PlayerId> {
+        if self.players_in_hand() <= 1 {
+            return None;
+        }
+        let round = self.current_round.as_ref()?;
+        let lone = self.active_count() == 1;
+        let num_players = self.num_players();
+
+        (0..num_players)
+            .map(|offset| PlayerId::from((from.as_index() + offset) % num_players))
+            .find(|&player| {
+                let behind = round.player_contribution(player) < round.current_bet();
+                self.is_active(player) && (behind || (!lone && !round.has_acted(player)))
+            })
+    }
+
+    /// Starts tracking turn order for the current round.
+    ///
+    /// The turn goes to the first player from `from` who owes an action; afterwards
+    /// `apply_action` rejects out-of-turn actions and passes the turn clockwise.
+    pub fn set_first_to_act(&mut self, from: PlayerId) {
+        self.tracks_turns = true;
+        self.to_act = self.next_to_act_from(from);
+    }
+
     /// Starts a new betting round.
+    ///
+    /// Turn order is not tracked until `set_first_to_act` is called. In fixed-limit
+    /// games every bet and raise is `big_blind`; use `start_street` to double it on the
+    /// turn and river.
     pub fn start_round(&mut self, big_blind: Chips, is_preflop: bool) {
+        let street = if is_preflop { Street::Preflop } else { Street::Flop };
+        self.start_street(street, big_blind);
+    }
+
+    /// Starts the betting round for `street`.
+    ///
+    /// Turn order is not tracked until `set_first_to_act` is called. In fixed-limit
+    /// games bets and raises are sized by `RuleSet::fixed_bet`.
+    pub fn start_street(&mut self, street: Street, big_blind: Chips) {
         let num_players = self.num_players();
-        self.current_round = Some(if is_preflop {
+        self.tracks_turns = false;
+        self.to_act = None;
+        let round = if street == Street::Preflop {
             BettingRound::new(num_players, big_blind)
         } else {
             BettingRound::new_postflop(num_players, big_blind)
+        };
+        self.current_round = Some(match self.rules.fixed_bet(street, big_blind) {
+            Some(size) => round.with_fixed_bet(size),
+            None => round,
         });
     }
 
@@ -380,8 +512,29 @@ impl BettingState {
         }
 
         if let Some(round) = &mut self.current_round {
-            round.record_bet(player, actual);
+            round.record_blind(player, actual);
         }
+        debug_assert_eq!(self.check_chip_conservation(), Ok(()));
+    }
+
+    /// Posts an ante.
+    ///
+    /// Antes go straight into the pot as dead money: unlike blinds they do not count
+    /// toward the player's bet in the current round. A player without enough chips
+    /// posts what they have and is all-in.
+    pub fn post_ante(&mut self, player: PlayerId, amount: Chips) {
+        let idx = player.as_index();
+        let actual = amount.min(self.stacks[idx]);
+
+        self.stacks[idx] = self.stacks[idx].saturating_sub(actual);
+        self.total_invested[idx] += actual;
+        self.main_pot.add(actual);
+        self.main_pot.add_eligible_player(player);
+
+        if self.stacks[idx].is_zero() {
+            self.is_all_in[idx] = true;
+        }
+        debug_assert_eq!(self.check_chip_conservation(), Ok(()));
     }
 
     /// Records a fold.
@@ -466,9 +619,299 @@ impl BettingState {
         actual
     }
 
-    /// Ends the current betting round.
+    /// Ends the current betting round and splits the chips into main and side pots.
     pub fn end_round(&mut self) {
         self.current_round = None;
+        self.tracks_turns = false;
+        self.to_act = None;
+
+        let mut pots = self.build_pots().into_iter();
+        self.main_pot = pots.next().unwrap_or_default();
+        self.side_pots = pots.collect();
+        debug_assert_eq!(self.check_chip_conservation(), Ok(()));
+    }
+
+    /// Layers every chip invested so far into a main pot and side pots.
+    ///
+    /// Each player who is all-in caps a layer at their total investment: the pot for that
+    /// layer holds what every player put in up to the cap, and only players who invested
+    /// at least that much (or can still put more in) are eligible to win it. Chips from
+    /// folded players stay in the pots they reached, but folded players are never eligible.
+    /// Adjacent layers with the same eligible players are merged.
+    ///
+    /// An uncalled bet ends up as a final pot whose only eligible player is the bettor.
+    ///
+    /// # Returns
+    /// The pots ordered from the main pot outward. There is always at least one pot.
+    #[must_use]
+    pub fn build_pots(&self) -> Vec<Pot> {
+        let num_players = self.num_players();
+        let mut levels: Vec<Chips> = (0..num_players)
+            .filter(|&i| self.is_all_in[i] && !self.has_folded[i])
+            .map(|i| self.total_invested[i])
+            .collect();
+        levels.push(self.total_invested.iter().copied().max().unwrap_or_default());
+        levels.sort_unstable();
+        levels.dedup();
+
+        let mut pots: Vec<Pot> = Vec::new();
+        let mut previous = Chips::ZERO;
+        for level in levels {
+            let amount = self.total_invested.iter().fold(Chips::ZERO, |acc, &invested| {
+                acc + invested.min(level).saturating_sub(invested.min(previous))
+            });
+            let eligible: Vec<PlayerId> = (0..num_players)
+                .filter(|&i| {
+                    !self.has_folded[i] && (self.total_invested[i] >= level || !self.is_all_in[i])
+                })
+                .map(PlayerId::from)
+                .collect();
+            previous = level;
+
+            match pots.last_mut() {
+                Some(last) if last.eligible_players() == eligible.as_slice() => last.add(amount),
+                Some(_) if amount.is_zero() => {}
+                _ => pots.push(Pot::with_players(amount, eligible)),
+            }
+        }
+        pots
+    }
+
+    /// Computes the actions `player` may take in the current betting round.
+    ///
+    /// Folding is always allowed; checking only when nothing is owed. Calling puts in
+    /// the amount owed, capped at the stack. A bet (nobody has bet this round) must be
+    /// at least the minimum raise and a raise must reach `min_raise_to`; a stack too
+    /// short for either can still go all-in. Turn order is not considered.
+    ///
+    /// # Returns
+    /// Empty `LegalActions` if there is no current round or the player has folded or
+    /// is all-in.
+    #[must_use]
+    pub fn legal_actions(&self, player: PlayerId) -> LegalActions {
+        let Some(round) = self.current_round.as_ref() else {
+            return LegalActions::default();
+        };
+        if !self.is_active(player) {
+            return LegalActions::default();
+        }
+
+        let stack = self.stack(player);
+        let to_call = round.amount_to_call(player);
+        let contribution = round.player_contribution(player);
+        let max_total = self.max_raise_to(round, player);
+        let max_bet = max_total - contribution;
+        let facing_bet = !round.current_bet().is_zero();
+        let capped = self.rules.is_capped(round.bets());
+        // Going all-in for more than a call is a raise, so the cap and limit apply to it
+        let all_in_allowed =
+            stack <= to_call || (!capped && contribution + stack <= max_total);
+
+        LegalActions {
+            fold: true,
+            check: to_call.is_zero(),
+            call: (!to_call.is_zero()).then(|| to_call.min(stack)),
+            bet: (!facing_bet && max_bet >= round.min_raise()).then_some(BetRange {
+                min: round.min_raise(),
+                max: max_bet,
+            }),
+            raise: (facing_bet && !capped && max_total >= round.min_raise_to()).then(|| BetRange {
+                min: round.min_raise_to(),
+                max: max_total,
+            }),
+            all_in: (!stack.is_zero() && all_in_allowed).then_some(stack),
+        }
+    }
+
+    /// Checks that `player` may take `action` without changing any state.
+    ///
+    /// # Errors
+    /// Returns a `BettingError` if there is no round in progress, the player cannot act,
+    /// it is not their turn (when turn order is tracked), or the action or its amount is
+    /// not among their `legal_actions`.
+    pub fn validate_action(&self, player: PlayerId, action: Action) -> Result<(), BettingError> {
+        let round = self
+            .current_round
+            .as_ref()
+            .ok_or(BettingError::NoActiveRound)?;
+        if player.as_index() >= self.num_players() {
+            return Err(BettingError::UnknownPlayer(player));
+        }
+        if self.has_folded(player) {
+            return Err(BettingError::PlayerFolded(player));
+        }
+        if self.is_all_in(player) {
+            return Err(BettingError::PlayerAllIn(player));
+        }
+        if self.tracks_turns {
+            match self.to_act {
+                None => return Err(BettingError::RoundComplete),
+                Some(expected) if expected != player => {
+                    return Err(BettingError::OutOfTurn {
+                        expected,
+                        actual: player,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        let legal = self.legal_actions(player);
+        let stack = self.stack(player);
+        let max_total = self.max_raise_to(round, player);
+        let max_bet = max_total - round.player_contribution(player);
+        let raises = matches!(action, Action::Raise(_))
+            || matches!(action, Action::AllIn(amount) if amount > round.amount_to_call(player));
+        if let Some(cap) = self
+            .rules
+            .max_raises()
+            .filter(|&cap| raises && round.bets() >= cap)
+        {
+            return Err(BettingError::RaiseCapReached { cap });
+        }
+
+        match action {
+            Action::Check if legal.check => Ok(()),
+            Action::Check => Err(BettingError::CannotCheck {
+                to_call: round.amount_to_call(player),
+            }),
+            Action::Call(amount) => match legal.call {
+                None => Err(BettingError::NothingToCall),
+                Some(expected) if expected != amount => {
+                    Err(BettingError::WrongAmount { action, expected })
+                }
+                Some(_) => Ok(()),
+            },
+            Action::Bet(_) if !round.current_bet().is_zero() => Err(BettingError::BetFacingBet {
+                current_bet: round.current_bet(),
+            }),
+            Action::Bet(amount) => check_size(action, amount, round.min_raise(), max_bet),
+            Action::Raise(_) if round.current_bet().is_zero() => Err(BettingError::NoBetToRaise),
+            Action::Raise(total) => check_size(action, total, round.min_raise_to(), max_total),
+            Action::AllIn(amount) if amount != stack => Err(BettingError::WrongAmount {
+                action,
+                expected: stack,
+            }),
+            Action::AllIn(_) if legal.all_in.is_none() => Err(BettingError::ExceedsStack {
+                action,
+                max: max_bet,
+            }),
+            Action::Fold | Action::AllIn(_) => Ok(()),
+        }
+    }
+
+    /// Largest raise-to total `player` may make under pot-limit rules: a call followed
+    /// by a raise the size of the pot after that call, or their whole stack if smaller.
+    ///
+    /// Applies whatever the table's limit type is; `legal_actions` only caps raises at
+    /// this amount in pot-limit games. Bets are raises from nothing, so with no bet to
+    /// face this is the largest bet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::primitives::{
+    ///     Action, BettingState, Blinds, Chips, PlayerId, RuleSet,
+    /// };
+    ///
+    /// let blinds = Blinds::new(Chips::new(1), Chips::new(2));
+    /// let mut betting =
+    ///     BettingState::new(vec![Chips::new(200); 3]).with_rules(RuleSet::pot_limit(blinds));
+    /// betting.start_round(Chips::new(2), true);
+    /// betting.post_blind(PlayerId::new(1), Chips::new(1));
+    /// betting.post_blind(PlayerId::new(2), Chips::new(2));
+    /// betting.set_first_to_act(PlayerId::new(0));
+    ///
+    /// // Call 2, making the pot 5, then raise 5 more
+    /// assert_eq!(betting.max_pot_limit_raise(PlayerId::new(0)), Chips::new(7));
+    /// assert!(betting.validate_action(PlayerId::new(0), Action::Raise(Chips::new(8))).is_err());
+    /// ```
+    #[must_use]
+    pub fn max_pot_limit_raise(&self, player: PlayerId) -> Chips {
+        let Some(round) = self.current_round.as_ref() else {
+            return Chips::ZERO;
+        };
+        let to_call = round.amount_to_call(player).min(self.stack(player));
+        let pot_after_call = self.total_pot() + to_call;
+        let all_in = round.player_contribution(player) + self.stack(player);
+        (round.player_contribution(player) + to_call + pot_after_call).min(all_in)
+    }
+
+    /// Largest raise-to total the limit type allows `player` in `round`.
+    fn max_raise_to(&self, round: &BettingRound, player: PlayerId) -> Chips {
+        let all_in = round.player_contribution(player) + self.stack(player);
+        match (self.rules.limit(), round.fixed_bet()) {
+            (LimitType::PotLimit, _) => self.max_pot_limit_raise(player),
+            (LimitType::FixedLimit, Some(size)) => (round.current_bet() + size).min(all_in),
+            (LimitType::NoLimit | LimitType::FixedLimit, _) => all_in,
+        }
+    }
+
+    /// Validates and applies `action` for `player`.
+    ///
+    /// Unlike `fold`, `call`, and `bet_or_raise`, which record whatever they are given,
+    /// this rejects illegal input and leaves the state untouched. When turn order is
+    /// tracked the turn passes to the next player who owes an action.
+    ///
+    /// # Errors
+    /// Returns a `BettingError` under the same conditions as `validate_action`. In debug
+    /// builds, also returns `BettingError::ChipsNotConserved` if the action left stacks
+    /// and pots not adding up to the starting stacks; the action has then already been
+    /// applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::errors::BettingError;
+    /// use riverrun::core::domain::primitives::{Action, BettingState, Chips, PlayerId};
+    ///
+    /// let mut betting = BettingState::new(vec![Chips::new(1000); 2]);
+    /// betting.start_round(Chips::new(10), false);
+    /// betting.set_first_to_act(PlayerId::new(0));
+    ///
+    /// let applied = betting.apply_action(PlayerId::new(0), Action::Bet(Chips::new(50))).unwrap();
+    /// assert_eq!(applied.amount, Chips::new(50));
+    /// assert_eq!(betting.to_act(), Some(PlayerId::new(1)));
+    ///
+    /// let err = betting.apply_action(PlayerId::new(1), Action::Raise(Chips::new(60)));
+    /// assert!(matches!(err, Err(BettingError::BelowMinimum { .. })));
+    /// ```
+    pub fn apply_action(&mut self, player: PlayerId, action: Action) -> Result<AppliedAction, BettingError> {
+        self.validate_action(player, action)?;
+
+        let amount = match action {
+            Action::Fold => {
+                self.fold(player);
+                Chips::ZERO
+            }
+            Action::Check => {
+                self.check(player);
+                Chips::ZERO
+            }
+            Action::Call(_) => self.call(player),
+            Action::Bet(total) | Action::Raise(total) => self.bet_or_raise(player, total),
+            Action::AllIn(amount) => {
+                let contributed = self
+                    .current_round
+                    .as_ref()
+                    .map_or(Chips::ZERO, |round| round.player_contribution(player));
+                self.bet_or_raise(player, contributed + amount)
+            }
+        };
+
+        if self.tracks_turns {
+            let next_seat = PlayerId::from((player.as_index() + 1) % self.num_players());
+            self.to_act = self.next_to_act_from(next_seat);
+        }
+        #[cfg(debug_assertions)]
+        self.check_chip_conservation()?;
+
+        Ok(AppliedAction {
+            player,
+            action,
+            amount,
+            all_in: self.is_all_in(player),
+        })
     }
 
     /// Returns total chips invested by a player.
@@ -479,11 +922,44 @@ impl BettingState {
             .copied()
             .unwrap_or(Chips::ZERO)
     }
+
+    /// Checks that the stacks and pots still add up to the chips the players started with.
+    ///
+    /// `apply_action` runs this after every action in debug builds.
+    ///
+    /// # Errors
+    /// Returns a `ChipConservationError` describing the mismatch if chips were created
+    /// or destroyed.
+    pub fn check_chip_conservation(&self) -> Result<(), ChipConservationError> {
+        let stacks = self.stacks.iter().fold(Chips::ZERO, |acc, &stack| acc + stack);
+        let pot = self.total_pot();
+        if stacks.checked_add(pot) == Some(self.starting_chips) {
+            Ok(())
+        } else {
+            Err(ChipConservationError {
+                expected: self.starting_chips,
+                stacks,
+                pot,
+            })
+        }
+    }
+}
+
+/// Checks that a bet or raise of `amount` lies within `min..=max`.
+fn check_size(action: Action, amount: Chips, min: Chips, max: Chips) -> Result<(), BettingError> {
+    if amount > max {
+        return Err(BettingError::ExceedsStack { action, max });
+    }
+    if amount < min {
+        return Err(BettingError::BelowMinimum { action, min });
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::domain::primitives::Blinds;
 
     #[test]
     fn test_pot_basic() {
@@ -563,6 +1039,35 @@ mod tests {
         assert_eq!(state.stack(sb), Chips::new(995));
         assert_eq!(state.stack(bb), Chips::new(990));
         assert_eq!(state.total_pot(), Chips::new(15));
+
+        // Posting a blind is not an action; the big blind keeps the option
+        let round = state.current_round().unwrap();
+        assert!(!round.has_acted(bb));
+        assert_eq!(round.amount_to_call(sb), Chips::new(5));
+        assert_eq!(round.raise_count(), 0);
+    }
+
+    #[test]
+    fn test_betting_state_antes() {
+        let stacks = vec![Chips::new(1000), Chips::new(1000), Chips::new(3)];
+        let mut state = BettingState::new(stacks);
+        state.start_round(Chips::new(10), true);
+
+        for seat in 0..3 {
+            state.post_ante(PlayerId::new(seat), Chips::new(5));
+        }
+        state.post_blind(PlayerId::new(0), Chips::new(5));
+        state.post_blind(PlayerId::new(1), Chips::new(10));
+
+        assert_eq!(state.total_pot(), Chips::new(28));
+        assert_eq!(state.stack(PlayerId::new(1)), Chips::new(985));
+        assert!(state.is_all_in(PlayerId::new(2)));
+        assert_eq!(state.total_invested(PlayerId::new(2)), Chips::new(3));
+
+        // Antes are dead money: only the blind counts toward the current bet
+        let round = state.current_round().unwrap();
+        assert_eq!(round.player_contribution(PlayerId::new(1)), Chips::new(10));
+        assert_eq!(round.amount_to_call(PlayerId::new(0)), Chips::new(5));
     }
 
     #[test]
@@ -590,4 +1095,431 @@ mod tests {
         assert!(state.is_all_in(p0));
         assert_eq!(state.stack(p0), Chips::ZERO);
     }
+
+    #[test]
+    fn test_legal_actions_facing_big_blind() {
+        let stacks = vec![Chips::new(1000), Chips::new(1000), Chips::new(1000)];
+        let mut state = BettingState::new(stacks);
+        state.start_round(Chips::new(10), true);
+        state.post_blind(PlayerId::new(1), Chips::new(5));
+        state.post_blind(PlayerId::new(2), Chips::new(10));
+
+        let legal = state.legal_actions(PlayerId::new(0));
+        assert!(legal.fold);
+        assert!(!legal.check);
+        assert_eq!(legal.call, Some(Chips::new(10)));
+        assert_eq!(legal.bet, None);
+        assert_eq!(
+            legal.raise,
+            Some(BetRange {
+                min: Chips::new(20),
+                max: Chips::new(1000)
+            })
+        );
+        assert_eq!(legal.all_in, Some(Chips::new(1000)));
+
+        // Big blind may check their option once the small blind completes
+        state.call(PlayerId::new(1));
+        let legal = state.legal_actions(PlayerId::new(2));
+        assert!(legal.check);
+        assert_eq!(legal.call, None);
+        assert_eq!(legal.raise.unwrap().max, Chips::new(1000));
+    }
+
+    #[test]
+    fn test_legal_actions_postflop_and_short_stack() {
+        let stacks = vec![Chips::new(1000), Chips::new(25)];
+        let mut state = BettingState::new(stacks);
+        state.start_round(Chips::new(10), false);
+
+        let legal = state.legal_actions(PlayerId::new(1));
+        assert!(legal.check);
+        assert_eq!(
+            legal.bet,
+            Some(BetRange {
+                min: Chips::new(10),
+                max: Chips::new(25)
+            })
+        );
+
+        state.bet_or_raise(PlayerId::new(0), Chips::new(100));
+        let legal = state.legal_actions(PlayerId::new(1));
+        assert_eq!(legal.call, Some(Chips::new(25)));
+        assert_eq!(legal.raise, None);
+        assert_eq!(legal.all_in, Some(Chips::new(25)));
+    }
+
+    #[test]
+    fn test_legal_actions_inactive_player() {
+        let stacks = vec![Chips::new(1000), Chips::new(1000)];
+        let mut state = BettingState::new(stacks);
+        assert!(state.legal_actions(PlayerId::new(0)).is_empty());
+
+        state.start_round(Chips::new(10), false);
+        state.fold(PlayerId::new(0));
+        assert!(state.legal_actions(PlayerId::new(0)).is_empty());
+        assert!(state.legal_actions(PlayerId::new(5)).is_empty());
+    }
+
+    #[test]
+    fn test_build_pots_without_all_in() {
+        let stacks = vec![Chips::new(1000), Chips::new(1000), Chips::new(1000)];
+        let mut state = BettingState::new(stacks);
+        state.start_round(Chips::new(10), true);
+        state.post_blind(PlayerId::new(1), Chips::new(5));
+        state.post_blind(PlayerId::new(2), Chips::new(10));
+        state.call(PlayerId::new(0));
+        state.call(PlayerId::new(1));
+
+        let pots = state.build_pots();
+        assert_eq!(pots.len(), 1);
+        assert_eq!(pots[0].amount(), Chips::new(30));
+        assert_eq!(pots[0].eligible_count(), 3);
+    }
+
+    #[test]
+    fn test_build_pots_layers_unequal_all_ins() {
+        let p0 = PlayerId::new(0);
+        let p1 = PlayerId::new(1);
+        let p2 = PlayerId::new(2);
+        let p3 = PlayerId::new(3);
+        let stacks = vec![Chips::new(100), Chips::new(300), Chips::new(1000), Chips::new(1000)];
+        let mut state = BettingState::new(stacks);
+        state.start_round(Chips::new(10), false);
+
+        state.bet_or_raise(p3, Chips::new(50));
+        state.bet_or_raise(p0, Chips::new(100));
+        state.bet_or_raise(p1, Chips::new(300));
+        state.call(p2);
+        state.fold(p3);
+
+        let pots = state.build_pots();
+        assert_eq!(pots.len(), 2);
+        assert_eq!(pots[0].amount(), Chips::new(350));
+        assert_eq!(pots[0].eligible_players(), &[p0, p1, p2]);
+        assert_eq!(pots[1].amount(), Chips::new(400));
+        assert_eq!(pots[1].eligible_players(), &[p1, p2]);
+
+        state.end_round();
+        assert_eq!(state.main_pot().amount(), Chips::new(350));
+        assert_eq!(state.side_pots().len(), 1);
+        assert_eq!(state.total_pot(), Chips::new(750));
+    }
+
+    #[test]
+    fn test_build_pots_uncalled_bet() {
+        let p0 = PlayerId::new(0);
+        let p1 = PlayerId::new(1);
+        let stacks = vec![Chips::new(100), Chips::new(1000)];
+        let mut state = BettingState::new(stacks);
+        state.start_round(Chips::new(10), false);
+
+        state.bet_or_raise(p0, Chips::new(100));
+        state.bet_or_raise(p1, Chips::new(400));
+
+        let pots = state.build_pots();
+        assert_eq!(pots.len(), 2);
+        assert_eq!(pots[0].amount(), Chips::new(200));
+        assert_eq!(pots[1].amount(), Chips::new(300));
+        assert_eq!(pots[1].eligible_players(), &[p1]);
+    }
+
+    #[test]
+    fn test_apply_action_rejects_illegal_sizes() {
+        let p0 = PlayerId::new(0);
+        let p1 = PlayerId::new(1);
+        let mut state = BettingState::new(vec![Chips::new(1000), Chips::new(1000)]);
+        assert_eq!(
+            state.apply_action(p0, Action::Check),
+            Err(BettingError::NoActiveRound)
+        );
+
+        state.start_round(Chips::new(10), false);
+        assert_eq!(
+            state.apply_action(p0, Action::Bet(Chips::new(5))),
+            Err(BettingError::BelowMinimum {
+                action: Action::Bet(Chips::new(5)),
+                min: Chips::new(10)
+            })
+        );
+        assert_eq!(
+            state.apply_action(p0, Action::Raise(Chips::new(50))),
+            Err(BettingError::NoBetToRaise)
+        );
+        assert_eq!(
+            state.apply_action(p0, Action::Bet(Chips::new(1001))),
+            Err(BettingError::ExceedsStack {
+                action: Action::Bet(Chips::new(1001)),
+                max: Chips::new(1000)
+            })
+        );
+        assert_eq!(state.total_pot(), Chips::ZERO);
+
+        state.apply_action(p0, Action::Bet(Chips::new(40))).unwrap();
+        assert_eq!(
+            state.apply_action(p1, Action::Check),
+            Err(BettingError::CannotCheck {
+                to_call: Chips::new(40)
+            })
+        );
+        assert_eq!(
+            state.apply_action(p1, Action::Raise(Chips::new(70))),
+            Err(BettingError::BelowMinimum {
+                action: Action::Raise(Chips::new(70)),
+                min: Chips::new(80)
+            })
+        );
+        assert!(matches!(
+            state.apply_action(p1, Action::Call(Chips::new(10))),
+            Err(BettingError::WrongAmount { .. })
+        ));
+    }
+
+    #[test]
+    fn test_apply_action_rejects_folded_and_all_in_players() {
+        let p0 = PlayerId::new(0);
+        let p1 = PlayerId::new(1);
+        let p2 = PlayerId::new(2);
+        let mut state = BettingState::new(vec![Chips::new(100), Chips::new(1000), Chips::new(1000)]);
+        state.start_round(Chips::new(10), false);
+
+        let applied = state.apply_action(p0, Action::AllIn(Chips::new(100))).unwrap();
+        assert_eq!(applied.amount, Chips::new(100));
+        assert!(applied.all_in);
+        state.apply_action(p1, Action::Fold).unwrap();
+
+        assert_eq!(
+            state.apply_action(p0, Action::Check),
+            Err(BettingError::PlayerAllIn(p0))
+        );
+        assert_eq!(
+            state.apply_action(p1, Action::Call(Chips::new(100))),
+            Err(BettingError::PlayerFolded(p1))
+        );
+        assert_eq!(
+            state.apply_action(PlayerId::new(5), Action::Fold),
+            Err(BettingError::UnknownPlayer(PlayerId::new(5)))
+        );
+        assert_eq!(
+            state.apply_action(p2, Action::Call(Chips::new(100))).unwrap().amount,
+            Chips::new(100)
+        );
+    }
+
+    #[test]
+    fn test_apply_action_enforces_turn_order() {
+        let p0 = PlayerId::new(0);
+        let p1 = PlayerId::new(1);
+        let p2 = PlayerId::new(2);
+        let mut state = BettingState::new(vec![Chips::new(1000); 3]);
+        state.start_round(Chips::new(10), false);
+        state.set_first_to_act(p1);
+        assert_eq!(state.to_act(), Some(p1));
+
+        assert_eq!(
+            state.apply_action(p0, Action::Check),
+            Err(BettingError::OutOfTurn {
+                expected: p1,
+                actual: p0
+            })
+        );
+        state.apply_action(p1, Action::Check).unwrap();
+        state.apply_action(p2, Action::Bet(Chips::new(20))).unwrap();
+        state.apply_action(p0, Action::Fold).unwrap();
+
+        // Only the first checker still owes an action
+        assert_eq!(state.to_act(), Some(p1));
+        state.apply_action(p1, Action::Call(Chips::new(20))).unwrap();
+        assert_eq!(state.to_act(), None);
+        assert_eq!(
+            state.apply_action(p2, Action::Check),
+            Err(BettingError::RoundComplete)
+        );
+    }
+
+    #[test]
+    fn test_rules_cap_raises() {
+        let p0 = PlayerId::new(0);
+        let p1 = PlayerId::new(1);
+        let rules = RuleSet::no_limit(Blinds::new(Chips::new(5), Chips::new(10)))
+            .with_max_raises(Some(2));
+        let mut state =
+            BettingState::new(vec![Chips::new(1000), Chips::new(500)]).with_rules(rules);
+        state.start_round(Chips::new(10), false);
+        state.set_first_to_act(p0);
+
+        state.apply_action(p0, Action::Bet(Chips::new(20))).unwrap();
+        state.apply_action(p1, Action::Raise(Chips::new(60))).unwrap();
+
+        let legal = state.legal_actions(p0);
+        assert_eq!(legal.raise, None);
+        assert_eq!(legal.all_in, None);
+        assert_eq!(
+            state.validate_action(p0, Action::Raise(Chips::new(200))),
+            Err(BettingError::RaiseCapReached { cap: 2 })
+        );
+        assert_eq!(
+            state.validate_action(p0, Action::AllIn(Chips::new(980))),
+            Err(BettingError::RaiseCapReached { cap: 2 })
+        );
+        state.apply_action(p0, Action::Call(Chips::new(40))).unwrap();
+        assert_eq!(state.rules().max_raises(), Some(2));
+    }
+
+    #[test]
+    fn test_pot_limit_caps_bets_and_raises_at_the_pot() {
+        let (p0, p1, p2) = (PlayerId::new(0), PlayerId::new(1), PlayerId::new(2));
+        let rules = RuleSet::pot_limit(Blinds::new(Chips::new(1), Chips::new(2)));
+        let mut state = BettingState::new(vec![Chips::new(200), Chips::new(200), Chips::new(6)])
+            .with_rules(rules);
+        state.start_round(Chips::new(2), true);
+        state.post_blind(p1, Chips::new(1));
+        state.post_blind(p2, Chips::new(2));
+        state.set_first_to_act(p0);
+
+        let legal = state.legal_actions(p0);
+        assert_eq!(
+            legal.raise,
+            Some(BetRange {
+                min: Chips::new(4),
+                max: Chips::new(7)
+            })
+        );
+        assert_eq!(legal.all_in, None);
+        assert_eq!(
+            state.validate_action(p0, Action::AllIn(Chips::new(200))),
+            Err(BettingError::ExceedsStack {
+                action: Action::AllIn(Chips::new(200)),
+                max: Chips::new(7)
+            })
+        );
+        state.apply_action(p0, Action::Raise(Chips::new(7))).unwrap();
+
+        // Small blind: call 6 more into a pot of 10, then raise 16
+        assert_eq!(state.max_pot_limit_raise(p1), Chips::new(23));
+        state.apply_action(p1, Action::Call(Chips::new(6))).unwrap();
+        // The big blind's stack is within the limit, so shoving is allowed
+        assert_eq!(state.legal_actions(p2).all_in, Some(Chips::new(4)));
+        state.apply_action(p2, Action::Call(Chips::new(4))).unwrap();
+
+        state.end_round();
+        state.start_round(Chips::new(2), false);
+        state.set_first_to_act(p1);
+        let legal = state.legal_actions(p1);
+        assert_eq!(
+            legal.bet,
+            Some(BetRange {
+                min: Chips::new(2),
+                max: Chips::new(20)
+            })
+        );
+        assert!(state.validate_action(p1, Action::Bet(Chips::new(21))).is_err());
+    }
+
+    #[test]
+    fn test_max_pot_limit_raise_is_capped_by_the_stack() {
+        let p0 = PlayerId::new(0);
+        let mut state = BettingState::new(vec![Chips::new(30), Chips::new(200)]);
+        assert_eq!(state.max_pot_limit_raise(p0), Chips::ZERO);
+        state.start_round(Chips::new(10), false);
+        state.bet_or_raise(PlayerId::new(1), Chips::new(100));
+        assert_eq!(state.max_pot_limit_raise(p0), Chips::new(30));
+    }
+
+    #[test]
+    fn test_fixed_limit_forces_bet_sizes() {
+        let (p0, p1) = (PlayerId::new(0), PlayerId::new(1));
+        let rules = RuleSet::fixed_limit(Blinds::new(Chips::new(5), Chips::new(10)));
+        let mut state = BettingState::new(vec![Chips::new(1000), Chips::new(25)]).with_rules(rules);
+        state.start_street(Street::Flop, Chips::new(10));
+        state.set_first_to_act(p0);
+
+        let fixed = |size| {
+            Some(BetRange {
+                min: Chips::new(size),
+                max: Chips::new(size),
+            })
+        };
+        assert_eq!(state.legal_actions(p0).bet, fixed(10));
+        assert_eq!(state.legal_actions(p0).all_in, None);
+        assert_eq!(
+            state.validate_action(p0, Action::Bet(Chips::new(20))),
+            Err(BettingError::ExceedsStack {
+                action: Action::Bet(Chips::new(20)),
+                max: Chips::new(10)
+            })
+        );
+        state.apply_action(p0, Action::Bet(Chips::new(10))).unwrap();
+        assert_eq!(state.legal_actions(p1).raise, fixed(20));
+        state.apply_action(p1, Action::Raise(Chips::new(20))).unwrap();
+        state.apply_action(p0, Action::Call(Chips::new(10))).unwrap();
+
+        // The turn doubles the bet; a stack short of a full raise may still go all-in
+        state.end_round();
+        state.start_street(Street::Turn, Chips::new(10));
+        state.set_first_to_act(p0);
+        assert_eq!(
+            state.current_round().and_then(BettingRound::fixed_bet),
+            Some(Chips::new(20))
+        );
+        state.apply_action(p0, Action::Bet(Chips::new(20))).unwrap();
+        assert_eq!(state.legal_actions(p1).raise, None);
+        assert_eq!(state.legal_actions(p1).all_in, Some(Chips::new(5)));
+    }
+
+    #[test]
+    fn test_fixed_limit_caps_preflop_at_four_bets() {
+        let (p0, p1, p2) = (PlayerId::new(0), PlayerId::new(1), PlayerId::new(2));
+        let rules = RuleSet::fixed_limit(Blinds::new(Chips::new(5), Chips::new(10)));
+        let mut state = BettingState::new(vec![Chips::new(1000); 3]).with_rules(rules);
+        state.start_round(Chips::new(10), true);
+        state.post_blind(p1, Chips::new(5));
+        state.post_blind(p2, Chips::new(10));
+        state.set_first_to_act(p0);
+
+        state.apply_action(p0, Action::Raise(Chips::new(20))).unwrap();
+        state.apply_action(p1, Action::Raise(Chips::new(30))).unwrap();
+        state.apply_action(p2, Action::Raise(Chips::new(40))).unwrap();
+        assert_eq!(
+            state.validate_action(p0, Action::Raise(Chips::new(50))),
+            Err(BettingError::RaiseCapReached { cap: 4 })
+        );
+        assert_eq!(state.legal_actions(p0).call, Some(Chips::new(20)));
+    }
+
+    #[test]
+    fn test_chips_conserved_through_a_hand() {
+        let mut betting = BettingState::new(vec![Chips::new(300), Chips::new(1000), Chips::new(1000)]);
+        betting.start_round(Chips::new(10), true);
+        betting.post_blind(PlayerId::new(1), Chips::new(5));
+        betting.post_blind(PlayerId::new(2), Chips::new(10));
+        betting.set_first_to_act(PlayerId::new(0));
+        betting.apply_action(PlayerId::new(0), Action::AllIn(Chips::new(300))).unwrap();
+        betting.apply_action(PlayerId::new(1), Action::Raise(Chips::new(600))).unwrap();
+        betting.apply_action(PlayerId::new(2), Action::Fold).unwrap();
+        betting.end_round();
+
+        assert_eq!(betting.check_chip_conservation(), Ok(()));
+        assert_eq!(betting.total_pot(), Chips::new(910));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_apply_action_reports_lost_chips() {
+        let mut betting = BettingState::new(vec![Chips::new(1000); 2]);
+        betting.start_round(Chips::new(10), false);
+        betting.stacks[1] = Chips::new(900);
+
+        let err = betting
+            .apply_action(PlayerId::new(0), Action::Bet(Chips::new(50)))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            BettingError::ChipsNotConserved(ChipConservationError {
+                expected: Chips::new(2000),
+                stacks: Chips::new(1850),
+                pot: Chips::new(50),
+            })
+        );
+    }
 }
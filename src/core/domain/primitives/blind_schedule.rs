@@ -0,0 +1,203 @@
+//! Tournament blind levels and how long each one lasts.
+
+use std::time::Duration;
+
+use super::blinds::Blinds;
+
+/// One level of a blind structure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BlindLevel {
+    /// Blinds and ante in force during the level.
+    pub blinds: Blinds,
+    /// How long the level lasts.
+    pub duration: Duration,
+}
+
+/// `BlindLevel` - Constructors
+impl BlindLevel {
+    /// A level playing `blinds` for `duration`.
+    #[must_use]
+    pub const fn new(blinds: Blinds, duration: Duration) -> Self {
+        Self { blinds, duration }
+    }
+}
+
+/// Blind structure of a tournament: the levels in order, each with its own duration.
+///
+/// The final level never ends; play stays at its blinds however long it runs.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use riverrun::core::domain::primitives::{BlindSchedule, Blinds, Chips};
+///
+/// let schedule = BlindSchedule::uniform(
+///     [
+///         Blinds::new(Chips::new(10), Chips::new(20)),
+///         Blinds::new(Chips::new(15), Chips::new(30)),
+///         Blinds::new(Chips::new(25), Chips::new(50)).with_ante(Chips::new(5)),
+///     ],
+///     Duration::from_mins(15),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(schedule.level_index_at(Duration::from_mins(20)), 1);
+/// assert_eq!(schedule.blinds_at(Duration::from_hours(3)).ante, Chips::new(5));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlindSchedule {
+    levels: Vec<BlindLevel>,
+}
+
+/// `BlindSchedule` - Constructors
+impl BlindSchedule {
+    /// Creates a schedule from `levels`, played in order.
+    ///
+    /// Returns `None` if there are no levels or any level's blinds are invalid.
+    #[must_use]
+    pub fn new(levels: Vec<BlindLevel>) -> Option<Self> {
+        let valid = !levels.is_empty() && levels.iter().all(|level| level.blinds.is_valid());
+        valid.then_some(Self { levels })
+    }
+
+    /// Creates a schedule in which every level lasts `duration`.
+    ///
+    /// Returns `None` under the same conditions as `new`.
+    #[must_use]
+    pub fn uniform(blinds: impl IntoIterator<Item = Blinds>, duration: Duration) -> Option<Self> {
+        Self::new(
+            blinds
+                .into_iter()
+                .map(|blinds| BlindLevel::new(blinds, duration))
+                .collect(),
+        )
+    }
+}
+
+/// `BlindSchedule` - Accessors
+impl BlindSchedule {
+    /// Every level, in the order they are played.
+    #[must_use]
+    pub fn levels(&self) -> &[BlindLevel] {
+        &self.levels
+    }
+
+    /// Number of levels, always at least one.
+    #[must_use]
+    pub const fn num_levels(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Level at `index`, counting from zero.
+    #[must_use]
+    pub fn level(&self, index: usize) -> Option<&BlindLevel> {
+        self.levels.get(index)
+    }
+
+    /// Time from the start of play until level `index` begins.
+    #[must_use]
+    pub fn level_start(&self, index: usize) -> Option<Duration> {
+        (index < self.levels.len()).then(|| {
+            self.levels[..index]
+                .iter()
+                .map(|level| level.duration)
+                .sum()
+        })
+    }
+
+    /// Index of the level being played once `elapsed` has passed since the start.
+    #[must_use]
+    pub fn level_index_at(&self, elapsed: Duration) -> usize {
+        let mut end = Duration::ZERO;
+        for (index, level) in self.levels.iter().enumerate() {
+            end = end.saturating_add(level.duration);
+            if elapsed < end {
+                return index;
+            }
+        }
+        self.levels.len() - 1
+    }
+
+    /// Level being played once `elapsed` has passed since the start.
+    #[must_use]
+    pub fn level_at(&self, elapsed: Duration) -> &BlindLevel {
+        &self.levels[self.level_index_at(elapsed)]
+    }
+
+    /// Blinds in force once `elapsed` has passed since the start.
+    #[must_use]
+    pub fn blinds_at(&self, elapsed: Duration) -> Blinds {
+        self.level_at(elapsed).blinds
+    }
+
+    /// Time left in the level being played at `elapsed`, or `None` during the final
+    /// level.
+    #[must_use]
+    pub fn time_until_next_level(&self, elapsed: Duration) -> Option<Duration> {
+        let next = self.level_index_at(elapsed) + 1;
+        self.level_start(next)
+            .map(|start| start.saturating_sub(elapsed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::primitives::Chips;
+
+    fn blinds(small: u64, big: u64) -> Blinds {
+        Blinds::new(Chips::new(small), Chips::new(big))
+    }
+
+    fn schedule() -> BlindSchedule {
+        BlindSchedule::new(vec![
+            BlindLevel::new(blinds(10, 20), Duration::from_mins(10)),
+            BlindLevel::new(blinds(20, 40), Duration::from_mins(5)),
+            BlindLevel::new(
+                blinds(50, 100).with_ante(Chips::new(10)),
+                Duration::from_mins(10),
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rejects_empty_or_invalid_levels() {
+        assert!(BlindSchedule::new(Vec::new()).is_none());
+        assert!(BlindSchedule::uniform([blinds(20, 10)], Duration::from_mins(10)).is_none());
+    }
+
+    #[test]
+    fn test_levels_advance_with_elapsed_time() {
+        let schedule = schedule();
+        assert_eq!(schedule.num_levels(), 3);
+        assert_eq!(schedule.level_index_at(Duration::ZERO), 0);
+        assert_eq!(schedule.level_index_at(Duration::from_secs(599)), 0);
+        assert_eq!(schedule.level_index_at(Duration::from_mins(10)), 1);
+        assert_eq!(schedule.blinds_at(Duration::from_mins(14)), blinds(20, 40));
+        assert_eq!(schedule.level_index_at(Duration::from_mins(15)), 2);
+
+        // The final level lasts forever
+        assert_eq!(schedule.level_index_at(Duration::from_hours(10)), 2);
+        assert_eq!(schedule.blinds_at(Duration::MAX).ante, Chips::new(10));
+    }
+
+    #[test]
+    fn test_level_boundaries() {
+        let schedule = schedule();
+        assert_eq!(schedule.level_start(0), Some(Duration::ZERO));
+        assert_eq!(schedule.level_start(2), Some(Duration::from_mins(15)));
+        assert_eq!(schedule.level_start(3), None);
+
+        assert_eq!(
+            schedule.time_until_next_level(Duration::from_mins(12)),
+            Some(Duration::from_mins(3))
+        );
+        assert_eq!(
+            schedule.time_until_next_level(Duration::from_mins(20)),
+            None
+        );
+    }
+}
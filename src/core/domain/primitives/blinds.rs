@@ -0,0 +1,85 @@
+//! Forced bets posted before the cards are dealt.
+
+use std::fmt;
+
+use super::chips::Chips;
+
+/// Blind and ante amounts for a hand.
+///
+/// The ante is posted by every player; the blinds only by the two players left of
+/// the button.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Blinds {
+    /// Small blind amount.
+    pub small_blind: Chips,
+    /// Big blind amount.
+    pub big_blind: Chips,
+    /// Ante posted by each player.
+    pub ante: Chips,
+}
+
+/// `Blinds` - Constructors
+impl Blinds {
+    /// Blinds without an ante.
+    #[must_use]
+    pub const fn new(small_blind: Chips, big_blind: Chips) -> Self {
+        Self {
+            small_blind,
+            big_blind,
+            ante: Chips::ZERO,
+        }
+    }
+
+    /// These blinds with each player also posting `ante`.
+    #[must_use]
+    pub const fn with_ante(self, ante: Chips) -> Self {
+        Self { ante, ..self }
+    }
+}
+
+/// `Blinds` - Accessors
+impl Blinds {
+    /// Chips in the pot before any action with `num_players` at the table.
+    #[must_use]
+    pub const fn dead_money(&self, num_players: usize) -> Chips {
+        Chips::new(
+            self.small_blind.value()
+                + self.big_blind.value()
+                + self.ante.value() * num_players as u64,
+        )
+    }
+
+    /// Returns whether the small blind is positive and no larger than the big blind.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        !self.small_blind.is_zero() && self.small_blind.value() <= self.big_blind.value()
+    }
+}
+
+impl fmt::Display for Blinds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.small_blind, self.big_blind)?;
+        if !self.ante.is_zero() {
+            write!(f, " ante {}", self.ante)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blinds() {
+        let blinds = Blinds::new(Chips::new(5), Chips::new(10)).with_ante(Chips::new(1));
+        assert_eq!(blinds.dead_money(6), Chips::new(21));
+        assert_eq!(blinds.to_string(), "5/10 ante 1");
+        assert_eq!(
+            Blinds::new(Chips::new(5), Chips::new(10)).to_string(),
+            "5/10"
+        );
+        assert!(blinds.is_valid());
+        assert!(!Blinds::new(Chips::new(10), Chips::new(5)).is_valid());
+    }
+}
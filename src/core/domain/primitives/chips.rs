@@ -1,6 +1,7 @@
 //! Chip amount representation.
 
 use std::fmt;
+use std::num::TryFromIntError;
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 /// Represents a chip amount in a poker game.
@@ -31,6 +32,32 @@ impl Chips {
         self.0 == 0
     }
 
+    /// Converts a signed amount, such as a net result, failing if it is negative.
+    ///
+    /// # Errors
+    /// Returns an error if `amount` is negative.
+    pub fn try_from_i64(amount: i64) -> Result<Self, TryFromIntError> {
+        u64::try_from(amount).map(Self)
+    }
+
+    /// Checked addition. Returns `None` if the sum would overflow.
+    #[must_use]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(sum) => Some(Self(sum)),
+            None => None,
+        }
+    }
+
+    /// Checked subtraction. Returns `None` if the result would be negative.
+    #[must_use]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(difference) => Some(Self(difference)),
+            None => None,
+        }
+    }
+
     /// Saturating subtraction. Returns zero if result would be negative.
     #[must_use]
     pub const fn saturating_sub(self, other: Self) -> Self {
@@ -58,6 +85,124 @@ impl Chips {
     }
 }
 
+/// How to round a chip amount that does not come out whole.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Round toward zero, dropping any fraction of a chip.
+    #[default]
+    Down,
+    /// Round away from zero, counting any fraction as a whole chip.
+    Up,
+    /// Round to the closest whole chip, with halves rounding up.
+    Nearest,
+}
+
+/// `Chips` - Ratios
+impl Chips {
+    /// Multiplies by `numerator / denominator`, rounding the result with `rounding`.
+    ///
+    /// The product is computed without intermediate overflow and saturates at
+    /// `u64::MAX` chips.
+    ///
+    /// # Panics
+    /// Panics if `denominator` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::primitives::{Chips, RoundingMode};
+    ///
+    /// let pot = Chips::new(100);
+    /// assert_eq!(pot.mul_ratio(2, 3, RoundingMode::Down), Chips::new(66));
+    /// assert_eq!(pot.mul_ratio(2, 3, RoundingMode::Up), Chips::new(67));
+    /// assert_eq!(pot.mul_ratio(2, 3, RoundingMode::Nearest), Chips::new(67));
+    /// ```
+    #[must_use]
+    pub fn mul_ratio(self, numerator: u64, denominator: u64, rounding: RoundingMode) -> Self {
+        assert!(denominator != 0, "ratio denominator must not be zero");
+        let product = u128::from(self.0) * u128::from(numerator);
+        let denominator = u128::from(denominator);
+        let quotient = match rounding {
+            RoundingMode::Down => product / denominator,
+            RoundingMode::Up => product.div_ceil(denominator),
+            RoundingMode::Nearest => (product + denominator / 2) / denominator,
+        };
+        Self(u64::try_from(quotient).unwrap_or(u64::MAX))
+    }
+
+    /// Divides by `divisor`, rounding the result with `rounding`.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    #[must_use]
+    pub fn div_rounded(self, divisor: u64, rounding: RoundingMode) -> Self {
+        self.mul_ratio(1, divisor, rounding)
+    }
+
+    /// Rake taken from a pot of this size: `percent` of it, rounded down to a whole
+    /// chip and capped at `cap`.
+    ///
+    /// `percent` is resolved to hundredths of a percent, enough for rates like 4.5%.
+    /// Negative rates take no rake.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::primitives::Chips;
+    ///
+    /// let cap = Chips::new(300);
+    /// assert_eq!(Chips::new(1_990).rake(5.0, cap), Chips::new(99));
+    /// assert_eq!(Chips::new(2_000).rake(4.5, cap), Chips::new(90));
+    /// assert_eq!(Chips::new(10_000).rake(5.0, cap), cap);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn rake(self, percent: f64, cap: Self) -> Self {
+        // Basis points; the cast saturates, so out-of-range rates clamp to 0 or u64::MAX
+        let basis_points = (percent * 100.0).round() as u64;
+        self.mul_ratio(basis_points, 10_000, RoundingMode::Down).min(cap)
+    }
+}
+
+/// `Chips` - Formatting
+impl Chips {
+    /// This amount measured in big blinds.
+    ///
+    /// Infinite or NaN if `big_blind` is zero.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn in_big_blinds(self, big_blind: Self) -> f64 {
+        self.0 as f64 / big_blind.0 as f64
+    }
+
+    /// Formats this amount in big blinds with up to two decimals, such as `12.5 bb`.
+    ///
+    /// # Panics
+    /// Panics if `big_blind` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::primitives::Chips;
+    ///
+    /// let big_blind = Chips::new(20);
+    /// assert_eq!(Chips::new(250).format_bb(big_blind), "12.5 bb");
+    /// assert_eq!(Chips::new(2_000).format_bb(big_blind), "100 bb");
+    /// assert_eq!(Chips::new(7).format_bb(big_blind), "0.35 bb");
+    /// ```
+    #[must_use]
+    pub fn format_bb(self, big_blind: Self) -> String {
+        assert!(!big_blind.is_zero(), "big blind must not be zero");
+        let hundredths = self.mul_ratio(100, big_blind.0, RoundingMode::Nearest).0;
+        let (whole, fraction) = (hundredths / 100, hundredths % 100);
+        match fraction {
+            0 => format!("{whole} bb"),
+            _ if fraction % 10 == 0 => format!("{whole}.{} bb", fraction / 10),
+            _ => format!("{whole}.{fraction:02} bb"),
+        }
+    }
+}
+
 impl fmt::Display for Chips {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -76,6 +221,14 @@ impl From<u32> for Chips {
     }
 }
 
+impl TryFrom<i64> for Chips {
+    type Error = TryFromIntError;
+
+    fn try_from(amount: i64) -> Result<Self, Self::Error> {
+        Self::try_from_i64(amount)
+    }
+}
+
 impl From<Chips> for u64 {
     fn from(chips: Chips) -> Self {
         chips.0
@@ -156,6 +309,19 @@ mod tests {
         assert_eq!(chips.saturating_sub(Chips::new(150)), Chips::ZERO);
     }
 
+    #[test]
+    fn test_checked_arithmetic() {
+        let chips = Chips::new(100);
+        assert_eq!(chips.checked_add(Chips::new(50)), Some(Chips::new(150)));
+        assert_eq!(Chips::new(u64::MAX).checked_add(Chips::new(1)), None);
+        assert_eq!(chips.checked_sub(Chips::new(100)), Some(Chips::ZERO));
+        assert_eq!(chips.checked_sub(Chips::new(101)), None);
+
+        assert_eq!(Chips::try_from_i64(250), Ok(Chips::new(250)));
+        assert!(Chips::try_from_i64(-1).is_err());
+        assert_eq!(Chips::try_from(0i64), Ok(Chips::ZERO));
+    }
+
     #[test]
     fn test_min_max() {
         let a = Chips::new(100);
@@ -188,4 +354,39 @@ mod tests {
     fn test_display() {
         assert_eq!(Chips::new(1000).to_string(), "1000");
     }
+
+    #[test]
+    fn test_mul_ratio_rounding() {
+        let chips = Chips::new(10);
+        assert_eq!(chips.mul_ratio(1, 4, RoundingMode::Down), Chips::new(2));
+        assert_eq!(chips.mul_ratio(1, 4, RoundingMode::Up), Chips::new(3));
+        assert_eq!(chips.mul_ratio(1, 4, RoundingMode::Nearest), Chips::new(3));
+        assert_eq!(chips.mul_ratio(1, 3, RoundingMode::Nearest), Chips::new(3));
+        assert_eq!(chips.mul_ratio(3, 2, RoundingMode::Down), Chips::new(15));
+        assert_eq!(chips.div_rounded(3, RoundingMode::Up), Chips::new(4));
+        assert_eq!(
+            Chips::new(u64::MAX).mul_ratio(3, 2, RoundingMode::Down),
+            Chips::new(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_rake() {
+        let cap = Chips::new(50);
+        assert_eq!(Chips::new(199).rake(5.0, cap), Chips::new(9));
+        assert_eq!(Chips::new(1000).rake(5.0, cap), cap);
+        assert_eq!(Chips::new(1000).rake(0.0, cap), Chips::ZERO);
+        assert_eq!(Chips::new(1000).rake(-5.0, cap), Chips::ZERO);
+        assert_eq!(Chips::new(100).rake(7.0, Chips::new(u64::MAX)), Chips::new(7));
+    }
+
+    #[test]
+    fn test_format_bb() {
+        let big_blind = Chips::new(100);
+        assert_eq!(Chips::ZERO.format_bb(big_blind), "0 bb");
+        assert_eq!(Chips::new(150).format_bb(big_blind), "1.5 bb");
+        assert_eq!(Chips::new(1).format_bb(big_blind), "0.01 bb");
+        assert_eq!(Chips::new(1).format_bb(Chips::new(3)), "0.33 bb");
+        assert!((Chips::new(250).in_big_blinds(big_blind) - 2.5).abs() < 1e-12);
+    }
 }
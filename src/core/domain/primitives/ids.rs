@@ -1,9 +1,18 @@
 //! Unique identifiers for games and sessions.
+//!
+//! Game and session ids are shared by the domain and every outbound port, so stores,
+//! read models, and notifications all key their records with these newtypes.
 
 use std::fmt;
 
+use super::time::unix_nanos;
+
 /// Unique identifier for a poker game.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// With the `serde` feature it serializes as a plain string.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct GameId(String);
 
 impl GameId {
@@ -24,11 +33,7 @@ impl GameId {
     /// Note: This is a simple implementation. For production, consider using the `uuid` crate.
     #[must_use]
     pub fn generate() -> Self {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos();
+        let timestamp = unix_nanos();
         Self(format!("game-{timestamp:x}"))
     }
 }
@@ -52,7 +57,11 @@ impl From<String> for GameId {
 }
 
 /// Unique identifier for a session (multiple hands).
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// With the `serde` feature it serializes as a plain string.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct SessionId(String);
 
 impl SessionId {
@@ -71,11 +80,7 @@ impl SessionId {
     /// Generates a new random session ID.
     #[must_use]
     pub fn generate() -> Self {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos();
+        let timestamp = unix_nanos();
         Self(format!("session-{timestamp:x}"))
     }
 }
@@ -98,6 +103,26 @@ impl From<String> for SessionId {
     }
 }
 
+/// Explicit string conversions shared by the string-backed ids, so an id's text is
+/// only used where it is asked for.
+macro_rules! string_id {
+    ($($id:ident),+) => {$(
+        impl AsRef<str> for $id {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<$id> for String {
+            fn from(id: $id) -> Self {
+                id.0
+            }
+        }
+    )+};
+}
+
+string_id!(GameId, SessionId);
+
 /// Unique identifier for a hand within a session.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct HandNumber(pub u64);
@@ -170,6 +195,18 @@ mod tests {
         assert_eq!(id.to_string(), "test-session");
     }
 
+    #[test]
+    fn test_ids_convert_to_and_from_strings() {
+        let id = GameId::from("table-1".to_string());
+        assert_eq!(id, GameId::new("table-1"));
+        assert_eq!(id.as_ref(), "table-1");
+        let mut games = std::collections::HashMap::new();
+        games.insert(id.clone(), 1);
+        assert_eq!(games.get(&GameId::from("table-1")), Some(&1));
+        assert_eq!(String::from(id), "table-1");
+        assert!(SessionId::new("a") < SessionId::new("b"));
+    }
+
     #[test]
     fn test_session_id_generate() {
         let id = SessionId::generate();
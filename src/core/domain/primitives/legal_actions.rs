@@ -0,0 +1,144 @@
+//! Legal actions available to a player.
+
+use super::{Action, Chips};
+
+/// Inclusive range of legal sizes for a bet or raise.
+///
+/// For raises the amounts are raise-to totals, matching `Action::Raise`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BetRange {
+    /// Smallest legal amount.
+    pub min: Chips,
+    /// Largest legal amount (the player's whole stack).
+    pub max: Chips,
+}
+
+impl BetRange {
+    /// Returns whether `amount` lies within the range.
+    #[must_use]
+    pub fn contains(self, amount: Chips) -> bool {
+        self.min <= amount && amount <= self.max
+    }
+}
+
+/// The actions a player may take, with the legal sizes for each.
+///
+/// A player who has folded, is all-in, or is not in a betting round has no legal
+/// actions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LegalActions {
+    /// Whether the player may fold.
+    pub fold: bool,
+    /// Whether the player may check.
+    pub check: bool,
+    /// Amount the player would put in by calling, if facing a bet.
+    pub call: Option<Chips>,
+    /// Legal bet sizes, if nobody has bet yet this round.
+    pub bet: Option<BetRange>,
+    /// Legal raise-to totals, if facing a bet the player can raise.
+    pub raise: Option<BetRange>,
+    /// The player's remaining stack, if they can go all-in.
+    pub all_in: Option<Chips>,
+}
+
+/// `LegalActions` - Accessors
+impl LegalActions {
+    /// Returns whether no action is available.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        !self.fold
+            && !self.check
+            && self.call.is_none()
+            && self.bet.is_none()
+            && self.raise.is_none()
+            && self.all_in.is_none()
+    }
+
+    /// Lists one representative action per available kind.
+    ///
+    /// Bets and raises are listed at their minimum size.
+    #[must_use]
+    pub fn actions(&self) -> Vec<Action> {
+        let mut actions = Vec::new();
+        if self.fold {
+            actions.push(Action::Fold);
+        }
+        if self.check {
+            actions.push(Action::Check);
+        }
+        actions.extend(self.call.map(Action::Call));
+        actions.extend(self.bet.map(|range| Action::Bet(range.min)));
+        actions.extend(self.raise.map(|range| Action::Raise(range.min)));
+        actions.extend(self.all_in.map(Action::AllIn));
+        actions
+    }
+
+    /// Returns whether `action`, including its amount, is legal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::primitives::{Action, BetRange, Chips, LegalActions};
+    ///
+    /// let legal = LegalActions {
+    ///     fold: true,
+    ///     call: Some(Chips::new(10)),
+    ///     raise: Some(BetRange { min: Chips::new(20), max: Chips::new(500) }),
+    ///     all_in: Some(Chips::new(490)),
+    ///     ..LegalActions::default()
+    /// };
+    /// assert!(legal.allows(Action::Raise(Chips::new(60))));
+    /// assert!(!legal.allows(Action::Raise(Chips::new(15))));
+    /// assert!(!legal.allows(Action::Check));
+    /// ```
+    #[must_use]
+    pub fn allows(&self, action: Action) -> bool {
+        match action {
+            Action::Fold => self.fold,
+            Action::Check => self.check,
+            Action::Call(amount) => self.call == Some(amount),
+            Action::Bet(amount) => self.bet.is_some_and(|range| range.contains(amount)),
+            Action::Raise(total) => self.raise.is_some_and(|range| range.contains(total)),
+            Action::AllIn(amount) => self.all_in == Some(amount),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_empty() {
+        let legal = LegalActions::default();
+        assert!(legal.is_empty());
+        assert!(legal.actions().is_empty());
+        assert!(!legal.allows(Action::Fold));
+    }
+
+    #[test]
+    fn test_actions_lists_minimum_sizes() {
+        let legal = LegalActions {
+            check: true,
+            fold: true,
+            bet: Some(BetRange {
+                min: Chips::new(10),
+                max: Chips::new(300),
+            }),
+            all_in: Some(Chips::new(300)),
+            ..LegalActions::default()
+        };
+        assert_eq!(
+            legal.actions(),
+            vec![
+                Action::Fold,
+                Action::Check,
+                Action::Bet(Chips::new(10)),
+                Action::AllIn(Chips::new(300))
+            ]
+        );
+        assert!(legal.allows(Action::Bet(Chips::new(300))));
+        assert!(!legal.allows(Action::Bet(Chips::new(301))));
+        assert!(!legal.allows(Action::AllIn(Chips::new(200))));
+    }
+}
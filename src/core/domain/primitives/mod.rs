@@ -3,21 +3,38 @@
 //! This module contains foundational types used throughout the poker engine:
 //! - [`Street`] - Game stages (Preflop, Flop, Turn, River)
 //! - [`PlayerId`], [`Position`] - Player identification and table position
-//! - [`Chips`] - Chip amounts
-//! - [`Action`] - Player actions (Fold, Check, Call, Bet, Raise, `AllIn`)
+//! - [`TableSeating`], [`assign_positions`] - Seat assignments, button rotation, and acting order
+//! - [`Chips`], [`RoundingMode`] - Chip amounts and how to round them
+//! - [`Blinds`] - Blinds and antes posted before the deal
+//! - [`RuleSet`], [`LimitType`], [`RakeScheme`] - Betting structure, stakes, rake, and buy-ins
+//! - [`BlindSchedule`], [`BlindLevel`] - Tournament blind levels and their durations
+//! - [`Action`], [`AppliedAction`] - Player actions (Fold, Check, Call, Bet, Raise, `AllIn`)
 //! - [`GameId`], [`SessionId`], [`HandNumber`] - Unique identifiers
 //! - [`Pot`], [`BettingRound`], [`BettingState`] - Betting and pot management
+//! - [`LegalActions`], [`BetRange`] - Actions available to a player and their sizes
 
 mod action;
 mod betting;
+mod blind_schedule;
+mod blinds;
 mod chips;
 mod ids;
+mod legal_actions;
 mod player;
+mod rules;
+mod seating;
 mod street;
+mod time;
 
-pub use action::Action;
+pub use action::{Action, AppliedAction};
 pub use betting::{BettingRound, BettingState, Pot};
-pub use chips::Chips;
+pub use blind_schedule::{BlindLevel, BlindSchedule};
+pub use blinds::Blinds;
+pub use chips::{Chips, RoundingMode};
 pub use ids::{GameId, HandNumber, SessionId};
+pub use legal_actions::{BetRange, LegalActions};
 pub use player::{PlayerId, Position};
+pub use rules::{LimitType, RakeScheme, RuleSet};
+pub use seating::{assign_positions, postflop_order, preflop_order, TableSeating};
 pub use street::Street;
+pub(crate) use time::unix_nanos;
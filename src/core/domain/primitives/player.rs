@@ -47,6 +47,7 @@ impl From<usize> for PlayerId {
 
 /// Table position in poker.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Position {
     /// Button (dealer position)
     Button,
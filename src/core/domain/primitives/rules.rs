@@ -0,0 +1,320 @@
+//! Table rules: betting structure, stakes, rake, and buy-ins.
+
+use std::fmt;
+
+use super::blinds::Blinds;
+use super::chips::Chips;
+use super::street::Street;
+
+/// Default minimum buy-in, in big blinds.
+const MIN_BUY_IN_BIG_BLINDS: u64 = 20;
+
+/// Default maximum buy-in, in big blinds.
+const MAX_BUY_IN_BIG_BLINDS: u64 = 100;
+
+/// Bets and raises fixed-limit games allow per round unless configured otherwise.
+const FIXED_LIMIT_RAISE_CAP: u8 = 4;
+
+/// How much a player may bet or raise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum LimitType {
+    /// Any amount from the minimum up to the player's whole stack.
+    #[default]
+    NoLimit,
+    /// Any amount from the minimum up to the size of the pot.
+    PotLimit,
+    /// Exactly one small bet on the first two streets and one big bet on the last two.
+    FixedLimit,
+}
+
+impl fmt::Display for LimitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::NoLimit => "no-limit",
+            Self::PotLimit => "pot-limit",
+            Self::FixedLimit => "fixed-limit",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// How the house takes its cut of each pot.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum RakeScheme {
+    /// No rake, e.g. in tournaments or home games.
+    #[default]
+    None,
+    /// `percent` of every pot, capped at `cap`.
+    Percent {
+        /// Share of the pot taken, e.g. 5.0 for 5%.
+        percent: f64,
+        /// Most taken from a single pot.
+        cap: Chips,
+        /// Whether hands that end before the flop are not raked.
+        no_flop_no_drop: bool,
+    },
+}
+
+/// `RakeScheme` - Operations
+impl RakeScheme {
+    /// Rake taken from `pot`, given whether the hand reached the flop.
+    #[must_use]
+    pub fn rake(self, pot: Chips, saw_flop: bool) -> Chips {
+        match self {
+            Self::Percent {
+                no_flop_no_drop: true,
+                ..
+            } if !saw_flop => Chips::ZERO,
+            Self::Percent { percent, cap, .. } => pot.rake(percent, cap),
+            Self::None => Chips::ZERO,
+        }
+    }
+}
+
+/// The rules a table is played under.
+///
+/// Combines the betting structure with the stakes, rake, and buy-in limits, so the
+/// betting engine and action validator can enforce them. The default is no-limit
+/// without stakes, rake, or buy-in limits.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::primitives::{Blinds, Chips, LimitType, RakeScheme, RuleSet};
+///
+/// let rules = RuleSet::pot_limit(Blinds::new(Chips::new(1), Chips::new(2))).with_rake(
+///     RakeScheme::Percent {
+///         percent: 5.0,
+///         cap: Chips::new(6),
+///         no_flop_no_drop: true,
+///     },
+/// );
+/// assert_eq!(rules.limit(), LimitType::PotLimit);
+/// assert!(rules.allows_buy_in(Chips::new(200)));
+/// assert!(!rules.allows_buy_in(Chips::new(201)));
+/// assert_eq!(rules.rake(Chips::new(80), true), Chips::new(4));
+/// assert_eq!(rules.rake(Chips::new(80), false), Chips::ZERO);
+/// assert_eq!(rules.to_string(), "pot-limit 1/2");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RuleSet {
+    limit: LimitType,
+    blinds: Blinds,
+    rake: RakeScheme,
+    min_buy_in: Chips,
+    max_buy_in: Chips,
+    max_raises: Option<u8>,
+}
+
+/// `RuleSet` - Constructors
+impl RuleSet {
+    /// No-limit rules at `blinds`, without rake, buying in for 20 to 100 big blinds.
+    #[must_use]
+    pub const fn no_limit(blinds: Blinds) -> Self {
+        Self::new(LimitType::NoLimit, blinds, None)
+    }
+
+    /// Pot-limit rules at `blinds`, without rake, buying in for 20 to 100 big blinds.
+    #[must_use]
+    pub const fn pot_limit(blinds: Blinds) -> Self {
+        Self::new(LimitType::PotLimit, blinds, None)
+    }
+
+    /// Fixed-limit rules at `blinds`, without rake, buying in for 20 to 100 big blinds.
+    ///
+    /// Bets and raises are one small bet, the big blind, preflop and on the flop, and one
+    /// big bet, twice the big blind, on the turn and river. Betting is capped at four
+    /// bets per round, counting the big blind as the first bet preflop.
+    #[must_use]
+    pub const fn fixed_limit(blinds: Blinds) -> Self {
+        Self::new(LimitType::FixedLimit, blinds, Some(FIXED_LIMIT_RAISE_CAP))
+    }
+
+    /// These rules with `rake` taken from each pot.
+    #[must_use]
+    pub const fn with_rake(mut self, rake: RakeScheme) -> Self {
+        self.rake = rake;
+        self
+    }
+
+    /// These rules with buy-ins from `min` to `max` inclusive.
+    #[must_use]
+    pub const fn with_buy_in(mut self, min: Chips, max: Chips) -> Self {
+        self.min_buy_in = min;
+        self.max_buy_in = max;
+        self
+    }
+
+    /// These rules with at most `max_raises` bets and raises per betting round, counting
+    /// the big blind preflop, or no cap for `None`.
+    #[must_use]
+    pub const fn with_max_raises(mut self, max_raises: Option<u8>) -> Self {
+        self.max_raises = max_raises;
+        self
+    }
+
+    const fn new(limit: LimitType, blinds: Blinds, max_raises: Option<u8>) -> Self {
+        let big_blind = blinds.big_blind.value();
+        Self {
+            limit,
+            blinds,
+            rake: RakeScheme::None,
+            min_buy_in: Chips::new(big_blind.saturating_mul(MIN_BUY_IN_BIG_BLINDS)),
+            max_buy_in: Chips::new(big_blind.saturating_mul(MAX_BUY_IN_BIG_BLINDS)),
+            max_raises,
+        }
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::no_limit(Blinds::default()).with_buy_in(Chips::ZERO, Chips::new(u64::MAX))
+    }
+}
+
+/// `RuleSet` - Accessors
+impl RuleSet {
+    /// The betting structure.
+    #[must_use]
+    pub const fn limit(&self) -> LimitType {
+        self.limit
+    }
+
+    /// Blinds and ante.
+    #[must_use]
+    pub const fn blinds(&self) -> Blinds {
+        self.blinds
+    }
+
+    /// How pots are raked.
+    #[must_use]
+    pub const fn rake_scheme(&self) -> RakeScheme {
+        self.rake
+    }
+
+    /// Smallest stack a player may sit down with.
+    #[must_use]
+    pub const fn min_buy_in(&self) -> Chips {
+        self.min_buy_in
+    }
+
+    /// Largest stack a player may sit down with.
+    #[must_use]
+    pub const fn max_buy_in(&self) -> Chips {
+        self.max_buy_in
+    }
+
+    /// Most bets and raises allowed per betting round, if capped.
+    #[must_use]
+    pub const fn max_raises(&self) -> Option<u8> {
+        self.max_raises
+    }
+
+    /// Returns whether a round with `bets` bets and raises allows no more.
+    #[must_use]
+    pub const fn is_capped(&self, bets: u8) -> bool {
+        match self.max_raises {
+            Some(max) => bets >= max,
+            None => false,
+        }
+    }
+
+    /// Size of every bet and raise on `street` in fixed-limit games with `big_blind`;
+    /// `None` when bets are not fixed.
+    #[must_use]
+    pub const fn fixed_bet(&self, street: Street, big_blind: Chips) -> Option<Chips> {
+        match (self.limit, street) {
+            (LimitType::FixedLimit, Street::Preflop | Street::Flop) => Some(big_blind),
+            (LimitType::FixedLimit, Street::Turn | Street::River) => {
+                Some(Chips::new(big_blind.value().saturating_mul(2)))
+            }
+            (LimitType::NoLimit | LimitType::PotLimit, _) => None,
+        }
+    }
+
+    /// Returns whether a player may sit down with `amount`.
+    #[must_use]
+    pub fn allows_buy_in(&self, amount: Chips) -> bool {
+        self.min_buy_in <= amount && amount <= self.max_buy_in
+    }
+
+    /// Returns whether the blinds are valid and the buy-in range is non-empty.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.blinds.is_valid() && self.min_buy_in <= self.max_buy_in
+    }
+}
+
+/// `RuleSet` - Operations
+impl RuleSet {
+    /// Rake taken from `pot`, given whether the hand reached the flop.
+    #[must_use]
+    pub fn rake(&self, pot: Chips, saw_flop: bool) -> Chips {
+        self.rake.rake(pot, saw_flop)
+    }
+}
+
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.limit, self.blinds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blinds() -> Blinds {
+        Blinds::new(Chips::new(5), Chips::new(10))
+    }
+
+    #[test]
+    fn test_constructors() {
+        let rules = RuleSet::no_limit(blinds());
+        assert_eq!(rules.limit(), LimitType::NoLimit);
+        assert_eq!(rules.min_buy_in(), Chips::new(200));
+        assert_eq!(rules.max_buy_in(), Chips::new(1_000));
+        assert_eq!(rules.max_raises(), None);
+        assert!(!rules.is_capped(u8::MAX));
+        assert!(rules.is_valid());
+
+        let limit = RuleSet::fixed_limit(blinds());
+        assert!(!limit.is_capped(3));
+        assert!(limit.is_capped(4));
+        assert!(!limit.with_max_raises(None).is_capped(4));
+        assert_eq!(limit.to_string(), "fixed-limit 5/10");
+        assert_eq!(
+            limit.fixed_bet(Street::Flop, Chips::new(10)),
+            Some(Chips::new(10))
+        );
+        assert_eq!(
+            limit.fixed_bet(Street::River, Chips::new(10)),
+            Some(Chips::new(20))
+        );
+        assert_eq!(rules.fixed_bet(Street::River, Chips::new(10)), None);
+    }
+
+    #[test]
+    fn test_buy_in_and_rake() {
+        let rules = RuleSet::no_limit(blinds())
+            .with_buy_in(Chips::new(500), Chips::new(2_000))
+            .with_rake(RakeScheme::Percent {
+                percent: 5.0,
+                cap: Chips::new(30),
+                no_flop_no_drop: false,
+            });
+        assert!(!rules.allows_buy_in(Chips::new(499)));
+        assert!(rules.allows_buy_in(Chips::new(2_000)));
+        assert_eq!(rules.rake(Chips::new(100), false), Chips::new(5));
+        assert_eq!(rules.rake(Chips::new(1_000), true), Chips::new(30));
+        assert!(
+            !rules
+                .with_buy_in(Chips::new(2_000), Chips::new(500))
+                .is_valid()
+        );
+
+        let default = RuleSet::default();
+        assert!(default.allows_buy_in(Chips::new(1)));
+        assert_eq!(default.rake(Chips::new(1_000), true), Chips::ZERO);
+    }
+}
@@ -0,0 +1,403 @@
+//! Seat assignments, dealer button rotation, and acting order.
+
+use super::{PlayerId, Position, Street};
+
+/// Largest table supported.
+const MAX_SEATS: usize = 10;
+
+/// Seats at a table and the dealer button.
+///
+/// Seats are identified by `PlayerId` and may be empty. Positions, blinds, and acting
+/// order are computed over the occupied seats only, clockwise from the button. Heads-up,
+/// the button posts the small blind, acts first preflop, and acts last postflop.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::primitives::{PlayerId, Position, Street, TableSeating};
+///
+/// let mut seating = TableSeating::full(4);
+/// seating.set_button(PlayerId::new(0));
+/// assert_eq!(seating.position(PlayerId::new(3)), Some(Position::UTG));
+/// assert_eq!(seating.acting_order(Street::Preflop)[0], PlayerId::new(3));
+/// assert_eq!(seating.acting_order(Street::Flop)[0], PlayerId::new(1));
+///
+/// seating.advance_button();
+/// assert_eq!(seating.button(), Some(PlayerId::new(1)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableSeating {
+    /// Whether each seat is occupied.
+    occupied: Vec<bool>,
+    /// Seat holding the dealer button.
+    button: Option<PlayerId>,
+}
+
+/// `TableSeating` - Constructors
+impl TableSeating {
+    /// Creates a table with `num_seats` empty seats.
+    ///
+    /// # Returns
+    /// `None` unless `num_seats` is between 2 and 10.
+    #[must_use]
+    pub fn new(num_seats: usize) -> Option<Self> {
+        (2..=MAX_SEATS).contains(&num_seats).then(|| Self {
+            occupied: vec![false; num_seats],
+            button: None,
+        })
+    }
+
+    /// Creates a table with every seat occupied and the button on seat 0.
+    ///
+    /// # Panics
+    /// Panics if `num_seats` is not between 2 and 10.
+    #[must_use]
+    pub fn full(num_seats: usize) -> Self {
+        let mut seating = Self::new(num_seats).expect("table size must be between 2 and 10");
+        seating.occupied.fill(true);
+        seating.button = Some(PlayerId::new(0));
+        seating
+    }
+
+    /// Creates a table with every seat occupied and the button on `button`.
+    ///
+    /// # Returns
+    /// `None` unless `num_seats` is between 2 and 10 and `button` is one of the seats.
+    #[must_use]
+    pub fn with_button(num_seats: usize, button: PlayerId) -> Option<Self> {
+        let mut seating = Self::new(num_seats)?;
+        seating.occupied.fill(true);
+        seating.set_button(button).then_some(seating)
+    }
+}
+
+/// `TableSeating` - Accessors
+impl TableSeating {
+    /// Number of seats, occupied or not.
+    #[must_use]
+    pub const fn num_seats(&self) -> usize {
+        self.occupied.len()
+    }
+
+    /// Number of occupied seats.
+    #[must_use]
+    pub fn player_count(&self) -> usize {
+        self.occupied.iter().filter(|&&occupied| occupied).count()
+    }
+
+    /// Returns whether `seat` is occupied.
+    #[must_use]
+    pub fn is_occupied(&self, seat: PlayerId) -> bool {
+        self.occupied.get(seat.as_index()).copied().unwrap_or(false)
+    }
+
+    /// Occupied seats in seat order.
+    #[must_use]
+    pub fn occupied_seats(&self) -> Vec<PlayerId> {
+        (0..self.num_seats())
+            .map(PlayerId::from)
+            .filter(|&seat| self.is_occupied(seat))
+            .collect()
+    }
+
+    /// Seat holding the dealer button.
+    #[must_use]
+    pub const fn button(&self) -> Option<PlayerId> {
+        self.button
+    }
+
+    /// Occupied seats clockwise from the button, starting with the button.
+    ///
+    /// Empty if the button has not been placed.
+    #[must_use]
+    pub fn from_button(&self) -> Vec<PlayerId> {
+        let Some(button) = self.button else {
+            return Vec::new();
+        };
+        let num_seats = self.num_seats();
+        (0..num_seats)
+            .map(|offset| PlayerId::from((button.as_index() + offset) % num_seats))
+            .filter(|&seat| self.is_occupied(seat))
+            .collect()
+    }
+
+    /// Seats posting the small and big blind.
+    ///
+    /// # Returns
+    /// `None` if the button is not placed or fewer than two seats are occupied.
+    #[must_use]
+    pub fn blinds(&self) -> Option<(PlayerId, PlayerId)> {
+        match self.from_button().as_slice() {
+            [] | [_] => None,
+            [button, big] => Some((*button, *big)),
+            [_, small, big, ..] => Some((*small, *big)),
+        }
+    }
+
+    /// Table position of `seat`, following `Position::for_table_size`.
+    #[must_use]
+    pub fn position(&self, seat: PlayerId) -> Option<Position> {
+        let order = self.from_button();
+        let offset = order.iter().position(|&occupant| occupant == seat)?;
+        Position::for_table_size(order.len())?.get(offset).copied()
+    }
+
+    /// Every occupied seat paired with its position, clockwise from the button.
+    #[must_use]
+    pub fn positions(&self) -> Vec<(PlayerId, Position)> {
+        let order = self.from_button();
+        Position::for_table_size(order.len())
+            .map(|positions| order.into_iter().zip(positions).collect())
+            .unwrap_or_default()
+    }
+
+    /// Order in which the occupied seats act on `street`.
+    ///
+    /// Preflop, action starts left of the big blind (UTG) and ends with the big blind.
+    /// Postflop, it starts left of the button and ends with the button. Heads-up, the
+    /// button acts first preflop and last postflop.
+    #[must_use]
+    pub fn acting_order(&self, street: Street) -> Vec<PlayerId> {
+        let mut order = self.from_button();
+        if order.len() < 2 {
+            return order;
+        }
+        let first = match street {
+            Street::Preflop if order.len() == 2 => 0,
+            // Left of the big blind, which wraps to the button three-handed
+            Street::Preflop => 3 % order.len(),
+            _ => 1,
+        };
+        order.rotate_left(first);
+        order
+    }
+}
+
+/// `TableSeating` - Operations
+impl TableSeating {
+    /// Seats a player at `seat`.
+    ///
+    /// # Returns
+    /// `false` if the seat does not exist or is already taken.
+    pub fn sit(&mut self, seat: PlayerId) -> bool {
+        match self.occupied.get_mut(seat.as_index()) {
+            Some(occupied) if !*occupied => {
+                *occupied = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Frees `seat`. The button stays where it is until it next moves.
+    ///
+    /// # Returns
+    /// `false` if the seat was not occupied.
+    pub fn leave(&mut self, seat: PlayerId) -> bool {
+        match self.occupied.get_mut(seat.as_index()) {
+            Some(occupied) if *occupied => {
+                *occupied = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Places the button on `seat`.
+    ///
+    /// # Returns
+    /// `false` (leaving the button unchanged) if `seat` is not occupied.
+    pub fn set_button(&mut self, seat: PlayerId) -> bool {
+        if !self.is_occupied(seat) {
+            return false;
+        }
+        self.button = Some(seat);
+        true
+    }
+
+    /// Moves the button clockwise to the next occupied seat.
+    ///
+    /// If the button has not been placed, it goes to the lowest occupied seat.
+    ///
+    /// # Returns
+    /// The new button seat, or `None` if no seat is occupied.
+    pub fn advance_button(&mut self) -> Option<PlayerId> {
+        let num_seats = self.num_seats();
+        let start = self.button.map_or(0, |button| button.as_index() + 1);
+        self.button = (0..num_seats)
+            .map(|offset| PlayerId::from((start + offset) % num_seats))
+            .find(|&seat| self.is_occupied(seat));
+        self.button
+    }
+}
+
+/// Position of each player for a hand at a full table of `num_players` with the button
+/// on seat `button_seat`, clockwise from the button.
+///
+/// Empty unless `num_players` is between 2 and 10 and `button_seat` is one of the seats.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::primitives::{assign_positions, PlayerId, Position};
+///
+/// let positions = assign_positions(3, 2);
+/// assert_eq!(positions[0], (PlayerId::new(2), Position::Button));
+/// assert_eq!(positions[1], (PlayerId::new(0), Position::SmallBlind));
+/// assert_eq!(positions[2], (PlayerId::new(1), Position::BigBlind));
+/// ```
+#[must_use]
+pub fn assign_positions(num_players: usize, button_seat: usize) -> Vec<(PlayerId, Position)> {
+    TableSeating::with_button(num_players, PlayerId::from(button_seat))
+        .map(|seating| seating.positions())
+        .unwrap_or_default()
+}
+
+/// Players in the order they act preflop at a full table of `num_players` with the
+/// button on seat `button_seat`: from left of the big blind round to the big blind.
+///
+/// Empty unless `num_players` is between 2 and 10 and `button_seat` is one of the seats.
+pub fn preflop_order(num_players: usize, button_seat: usize) -> impl Iterator<Item = PlayerId> {
+    acting_order(num_players, button_seat, Street::Preflop)
+}
+
+/// Players in the order they act after the flop at a full table of `num_players` with
+/// the button on seat `button_seat`: from left of the button round to the button.
+///
+/// Empty unless `num_players` is between 2 and 10 and `button_seat` is one of the seats.
+pub fn postflop_order(num_players: usize, button_seat: usize) -> impl Iterator<Item = PlayerId> {
+    acting_order(num_players, button_seat, Street::Flop)
+}
+
+/// Acting order on `street` at a full table, or nothing for an invalid table.
+fn acting_order(
+    num_players: usize,
+    button_seat: usize,
+    street: Street,
+) -> impl Iterator<Item = PlayerId> {
+    TableSeating::with_button(num_players, PlayerId::from(button_seat))
+        .map(|seating| seating.acting_order(street))
+        .unwrap_or_default()
+        .into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(seat: u8) -> PlayerId {
+        PlayerId::new(seat)
+    }
+
+    #[test]
+    fn test_new_validates_size() {
+        assert!(TableSeating::new(1).is_none());
+        assert!(TableSeating::new(11).is_none());
+        let seating = TableSeating::new(6).unwrap();
+        assert_eq!(seating.player_count(), 0);
+        assert_eq!(seating.button(), None);
+        assert!(seating.blinds().is_none());
+    }
+
+    #[test]
+    fn test_sit_and_leave() {
+        let mut seating = TableSeating::new(3).unwrap();
+        assert!(seating.sit(p(1)));
+        assert!(!seating.sit(p(1)));
+        assert!(!seating.sit(p(3)));
+        assert!(seating.sit(p(2)));
+        assert_eq!(seating.occupied_seats(), vec![p(1), p(2)]);
+
+        assert!(seating.leave(p(1)));
+        assert!(!seating.leave(p(1)));
+        assert_eq!(seating.player_count(), 1);
+    }
+
+    #[test]
+    fn test_button_skips_empty_seats() {
+        let mut seating = TableSeating::new(6).unwrap();
+        for seat in [1, 3, 4] {
+            seating.sit(p(seat));
+        }
+        assert!(!seating.set_button(p(0)));
+        assert_eq!(seating.advance_button(), Some(p(1)));
+        assert_eq!(seating.advance_button(), Some(p(3)));
+        assert_eq!(seating.advance_button(), Some(p(4)));
+        assert_eq!(seating.advance_button(), Some(p(1)));
+        assert_eq!(seating.blinds(), Some((p(3), p(4))));
+    }
+
+    #[test]
+    fn test_positions_over_occupied_seats() {
+        let mut seating = TableSeating::new(9).unwrap();
+        for seat in [0, 2, 5, 7] {
+            seating.sit(p(seat));
+        }
+        seating.set_button(p(5));
+        assert_eq!(
+            seating.positions(),
+            vec![
+                (p(5), Position::Button),
+                (p(7), Position::SmallBlind),
+                (p(0), Position::BigBlind),
+                (p(2), Position::UTG),
+            ]
+        );
+        assert_eq!(seating.position(p(1)), None);
+    }
+
+    #[test]
+    fn test_acting_order() {
+        let mut seating = TableSeating::full(5);
+        seating.set_button(p(2));
+        assert_eq!(
+            seating.acting_order(Street::Preflop),
+            vec![p(0), p(1), p(2), p(3), p(4)]
+        );
+        assert_eq!(
+            seating.acting_order(Street::River),
+            vec![p(3), p(4), p(0), p(1), p(2)]
+        );
+
+        let mut three = TableSeating::full(3);
+        three.set_button(p(1));
+        assert_eq!(three.acting_order(Street::Preflop), vec![p(1), p(2), p(0)]);
+        assert_eq!(three.acting_order(Street::Flop), vec![p(2), p(0), p(1)]);
+    }
+
+    #[test]
+    fn test_heads_up() {
+        let mut seating = TableSeating::full(2);
+        seating.set_button(p(1));
+        assert_eq!(seating.blinds(), Some((p(1), p(0))));
+        assert_eq!(seating.position(p(0)), Some(Position::BigBlind));
+        assert_eq!(seating.acting_order(Street::Preflop), vec![p(1), p(0)]);
+        assert_eq!(seating.acting_order(Street::Turn), vec![p(0), p(1)]);
+    }
+
+    #[test]
+    fn test_assignment_from_button_seat() {
+        assert_eq!(
+            assign_positions(4, 1),
+            vec![
+                (p(1), Position::Button),
+                (p(2), Position::SmallBlind),
+                (p(3), Position::BigBlind),
+                (p(0), Position::UTG),
+            ]
+        );
+        assert_eq!(
+            preflop_order(4, 1).collect::<Vec<_>>(),
+            vec![p(0), p(1), p(2), p(3)]
+        );
+        assert_eq!(
+            postflop_order(4, 1).collect::<Vec<_>>(),
+            vec![p(2), p(3), p(0), p(1)]
+        );
+
+        assert!(assign_positions(4, 4).is_empty());
+        assert!(assign_positions(1, 0).is_empty());
+        assert_eq!(preflop_order(11, 0).count(), 0);
+        assert!(TableSeating::with_button(4, p(4)).is_none());
+    }
+}
@@ -3,7 +3,12 @@
 use std::fmt;
 
 /// The current street/stage of the hand.
+///
+/// This is the one street type shared by the board, the engine, and published
+/// notifications. With the `serde` feature it serializes in lowercase, e.g. `"flop"`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 #[repr(u8)]
 pub enum Street {
     Preflop = 0,
@@ -13,6 +18,28 @@ pub enum Street {
 }
 
 impl Street {
+    /// Every street, in the order they are played.
+    pub const ALL: [Self; 4] = [Self::Preflop, Self::Flop, Self::Turn, Self::River];
+
+    /// Iterate over every street in the order they are played.
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// Iterate over the streets played after this one, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::primitives::Street;
+    ///
+    /// let later: Vec<Street> = Street::Flop.after().collect();
+    /// assert_eq!(later, [Street::Turn, Street::River]);
+    /// ```
+    pub fn after(self) -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter().skip(self as usize + 1)
+    }
+
     /// Returns the number of community cards for this street.
     #[must_use]
     pub const fn card_count(self) -> usize {
@@ -35,6 +62,29 @@ impl Street {
         }
     }
 
+    /// Returns the previous street, if any.
+    #[must_use]
+    pub const fn previous(self) -> Option<Self> {
+        match self {
+            Self::Preflop => None,
+            Self::Flop => Some(Self::Preflop),
+            Self::Turn => Some(Self::Flop),
+            Self::River => Some(Self::Turn),
+        }
+    }
+
+    /// Returns whether this is the first street, before any community cards.
+    #[must_use]
+    pub const fn is_preflop(self) -> bool {
+        matches!(self, Self::Preflop)
+    }
+
+    /// Returns whether community cards have been dealt on this street.
+    #[must_use]
+    pub const fn is_postflop(self) -> bool {
+        !self.is_preflop()
+    }
+
     /// Returns whether this is the final street.
     #[must_use]
     pub const fn is_final(self) -> bool {
@@ -85,6 +135,21 @@ mod tests {
         assert_eq!(Street::River.next(), None);
     }
 
+    #[test]
+    fn test_previous_and_iteration() {
+        assert_eq!(Street::Preflop.previous(), None);
+        assert_eq!(Street::River.previous(), Some(Street::Turn));
+        for street in Street::all() {
+            assert_eq!(street.next().and_then(Street::previous), street.next().map(|_| street));
+        }
+        assert_eq!(Street::all().count(), 4);
+        assert_eq!(Street::Preflop.after().count(), 3);
+        assert_eq!(Street::River.after().count(), 0);
+        assert!(Street::Preflop.is_preflop());
+        assert!(Street::Flop.is_postflop());
+        assert!(!Street::Preflop.is_postflop());
+    }
+
     #[test]
     fn test_is_final() {
         assert!(!Street::Preflop.is_final());
@@ -0,0 +1,36 @@
+//! Reading the system clock for timestamp-based identifiers.
+//!
+//! Lives in the domain so ids can be generated without depending on the ports; the
+//! `Clock` port reads wall-clock time through the same function.
+
+/// Nanoseconds since the Unix epoch, read from the system clock, or zero if the system
+/// time is before the Unix epoch.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub fn unix_nanos() -> u128 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+}
+
+/// Nanoseconds since the Unix epoch, read from JavaScript's `Date.now()`.
+///
+/// `SystemTime::now` panics on `wasm32-unknown-unknown`. `Date.now()` only counts
+/// milliseconds, so each call returns at least a nanosecond more than the last to keep
+/// timestamp-based IDs unique.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn unix_nanos() -> u128 {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static LAST: AtomicU64 = AtomicU64::new(0);
+    let now = (js_sys::Date::now() * 1_000_000.0) as u64;
+    let previous = LAST
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| {
+            Some(now.max(last + 1))
+        })
+        .unwrap_or_default();
+    u128::from(now.max(previous + 1))
+}
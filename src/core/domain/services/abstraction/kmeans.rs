@@ -0,0 +1,332 @@
+//! K-means clustering of feature vectors.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Default cap on the iterations of `KMeans::fit`.
+pub const DEFAULT_MAX_ITERATIONS: usize = 100;
+
+/// How far apart two feature vectors are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Distance {
+    /// Straight-line distance.
+    #[default]
+    Euclidean,
+    /// Earth mover's distance between two histograms over the same ordered bins: the
+    /// mass that must move times how many bins it moves. Suited to equity
+    /// histograms, where mass in neighbouring bins is nearly alike.
+    EarthMovers,
+}
+
+/// `Distance` - Operations
+impl Distance {
+    /// Distance between `a` and `b`, compared element by element up to the shorter.
+    #[must_use]
+    pub fn between(self, a: &[f64], b: &[f64]) -> f64 {
+        match self {
+            Self::Euclidean => a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| (x - y) * (x - y))
+                .sum::<f64>()
+                .sqrt(),
+            Self::EarthMovers => {
+                let mut carried = 0.0;
+                let mut moved = 0.0;
+                for (x, y) in a.iter().zip(b) {
+                    carried += x - y;
+                    moved += f64::abs(carried);
+                }
+                moved
+            }
+        }
+    }
+}
+
+/// K-means clustering with k-means++ seeding.
+///
+/// Centroids start spread out by k-means++ from a seeded generator, so results are
+/// reproducible, and are then refined until no point changes cluster or
+/// `max_iterations` is reached.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::services::abstraction::KMeans;
+///
+/// let points = vec![vec![0.1], vec![0.15], vec![0.9], vec![0.85]];
+/// let clustering = KMeans::new(2).fit(&points);
+/// assert_eq!(clustering.cluster(0), clustering.cluster(1));
+/// assert_ne!(clustering.cluster(0), clustering.cluster(2));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KMeans {
+    clusters: usize,
+    max_iterations: usize,
+    distance: Distance,
+    seed: u64,
+}
+
+/// `KMeans` - Constructors
+impl KMeans {
+    /// Clustering into `clusters` clusters with Euclidean distance.
+    #[must_use]
+    pub const fn new(clusters: usize) -> Self {
+        Self {
+            clusters,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            distance: Distance::Euclidean,
+            seed: 0,
+        }
+    }
+
+    /// This clustering stopping after at most `max_iterations` refinements.
+    #[must_use]
+    pub const fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// This clustering measuring distance with `distance`.
+    #[must_use]
+    pub const fn with_distance(mut self, distance: Distance) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    /// This clustering seeding centroids from `seed` instead of the default seed.
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+/// `KMeans` - Accessors
+impl KMeans {
+    /// Number of clusters asked for.
+    #[must_use]
+    pub const fn clusters(&self) -> usize {
+        self.clusters
+    }
+
+    /// Distance used to assign points to centroids.
+    #[must_use]
+    pub const fn distance(&self) -> Distance {
+        self.distance
+    }
+}
+
+/// `KMeans` - Operations
+impl KMeans {
+    /// Clusters `points`.
+    ///
+    /// Fewer clusters than asked for are formed when there are fewer points, and none
+    /// when there are no points or zero clusters were asked for.
+    #[must_use]
+    pub fn fit(&self, points: &[Vec<f64>]) -> Clustering {
+        let clusters = self.clusters.min(points.len());
+        if clusters == 0 {
+            return Clustering {
+                assignments: vec![0; points.len()],
+                centroids: Vec::new(),
+                distance: self.distance,
+                iterations: 0,
+            };
+        }
+
+        let mut centroids = self.seed_centroids(points, clusters);
+        let mut assignments = self.assign(points, &centroids);
+        let mut iterations = 0;
+        while iterations < self.max_iterations {
+            iterations += 1;
+            update_centroids(points, &assignments, &mut centroids);
+            let next = self.assign(points, &centroids);
+            if next == assignments {
+                break;
+            }
+            assignments = next;
+        }
+        Clustering {
+            assignments,
+            centroids,
+            distance: self.distance,
+            iterations,
+        }
+    }
+
+    /// Picks starting centroids by k-means++: each new centroid is a point drawn with
+    /// probability proportional to its squared distance from the nearest one so far.
+    fn seed_centroids(&self, points: &[Vec<f64>], clusters: usize) -> Vec<Vec<f64>> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut centroids = vec![points[rng.random_range(0..points.len())].clone()];
+        while centroids.len() < clusters {
+            let weights: Vec<f64> = points
+                .iter()
+                .map(|point| {
+                    let nearest = self.nearest(point, &centroids).1;
+                    nearest * nearest
+                })
+                .collect();
+            let total: f64 = weights.iter().sum();
+            if total <= 0.0 {
+                // Every point already sits on a centroid
+                break;
+            }
+            let mut target = rng.random::<f64>() * total;
+            let chosen = weights
+                .iter()
+                .position(|&weight| {
+                    target -= weight;
+                    target < 0.0
+                })
+                .unwrap_or(points.len() - 1);
+            centroids.push(points[chosen].clone());
+        }
+        centroids
+    }
+
+    /// Index of the centroid nearest each point.
+    fn assign(&self, points: &[Vec<f64>], centroids: &[Vec<f64>]) -> Vec<usize> {
+        points
+            .iter()
+            .map(|point| self.nearest(point, centroids).0)
+            .collect()
+    }
+
+    /// Index of and distance to the centroid nearest `point`.
+    fn nearest(&self, point: &[f64], centroids: &[Vec<f64>]) -> (usize, f64) {
+        centroids
+            .iter()
+            .map(|centroid| self.distance.between(point, centroid))
+            .enumerate()
+            .fold((0, f64::INFINITY), |best, (index, distance)| {
+                if distance < best.1 {
+                    (index, distance)
+                } else {
+                    best
+                }
+            })
+    }
+}
+
+/// Moves each centroid to the mean of its points; centroids with no points stay put.
+#[allow(clippy::cast_precision_loss)]
+fn update_centroids(points: &[Vec<f64>], assignments: &[usize], centroids: &mut [Vec<f64>]) {
+    for (cluster, centroid) in centroids.iter_mut().enumerate() {
+        let members: Vec<&Vec<f64>> = points
+            .iter()
+            .zip(assignments)
+            .filter(|&(_, &assigned)| assigned == cluster)
+            .map(|(point, _)| point)
+            .collect();
+        if members.is_empty() {
+            continue;
+        }
+        for (dimension, value) in centroid.iter_mut().enumerate() {
+            let sum: f64 = members.iter().map(|member| member[dimension]).sum();
+            *value = sum / members.len() as f64;
+        }
+    }
+}
+
+/// Result of `KMeans::fit`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Clustering {
+    assignments: Vec<usize>,
+    centroids: Vec<Vec<f64>>,
+    distance: Distance,
+    iterations: usize,
+}
+
+/// `Clustering` - Accessors
+impl Clustering {
+    /// Number of clusters formed.
+    #[must_use]
+    pub const fn num_clusters(&self) -> usize {
+        self.centroids.len()
+    }
+
+    /// Cluster of each point, in the order the points were given.
+    #[must_use]
+    pub fn assignments(&self) -> &[usize] {
+        &self.assignments
+    }
+
+    /// Cluster of the point at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range of the points clustered.
+    #[must_use]
+    pub fn cluster(&self, index: usize) -> usize {
+        self.assignments[index]
+    }
+
+    /// Centre of each cluster.
+    #[must_use]
+    pub fn centroids(&self) -> &[Vec<f64>] {
+        &self.centroids
+    }
+
+    /// Refinement iterations run.
+    #[must_use]
+    pub const fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Cluster whose centroid is nearest `point`, or `None` without clusters.
+    #[must_use]
+    pub fn nearest(&self, point: &[f64]) -> Option<usize> {
+        (0..self.centroids.len()).min_by(|&a, &b| {
+            let distance = |index: usize| self.distance.between(point, &self.centroids[index]);
+            distance(a).total_cmp(&distance(b))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_earth_movers_distance_grows_with_shift() {
+        let low = [1.0, 0.0, 0.0, 0.0];
+        let middle = [0.0, 1.0, 0.0, 0.0];
+        let high = [0.0, 0.0, 0.0, 1.0];
+        assert!((Distance::EarthMovers.between(&low, &middle) - 1.0).abs() < 1e-12);
+        assert!((Distance::EarthMovers.between(&low, &high) - 3.0).abs() < 1e-12);
+        // Euclidean distance cannot tell the two apart
+        assert!(
+            (Distance::Euclidean.between(&low, &middle) - Distance::Euclidean.between(&low, &high))
+                .abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn test_fit_separates_clusters() {
+        let points: Vec<Vec<f64>> = [0.0, 0.05, 0.1, 0.5, 0.55, 0.95, 1.0]
+            .iter()
+            .map(|&x| vec![x])
+            .collect();
+        let clustering = KMeans::new(3).with_seed(7).fit(&points);
+        assert_eq!(clustering.num_clusters(), 3);
+        let clusters = clustering.assignments();
+        assert_eq!(clusters[0], clusters[2]);
+        assert_eq!(clusters[3], clusters[4]);
+        assert_eq!(clusters[5], clusters[6]);
+        assert_ne!(clusters[0], clusters[3]);
+        assert_ne!(clusters[3], clusters[5]);
+        assert_eq!(clustering.nearest(&[0.9]), Some(clusters[6]));
+    }
+
+    #[test]
+    fn test_fit_handles_few_points() {
+        assert_eq!(KMeans::new(3).fit(&[]).num_clusters(), 0);
+        let same = vec![vec![0.5]; 4];
+        let clustering = KMeans::new(3).fit(&same);
+        assert_eq!(clustering.num_clusters(), 1);
+        assert_eq!(clustering.assignments(), &[0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,14 @@
+//! Card abstraction for solvers.
+//!
+//! Solvers cannot afford a strategy for every hand on every board, so hands that play
+//! alike are grouped into buckets. Hands are compared by expected hand strength (EHS),
+//! EHS², and the histogram of their equity over the runouts, and grouped by k-means.
+
+mod kmeans;
+mod strength;
+
+pub use kmeans::{Clustering, DEFAULT_MAX_ITERATIONS, Distance, KMeans};
+pub use strength::{
+    DEFAULT_BINS, DEFAULT_RUNOUT_SAMPLES, DEFAULT_RUNOUTS, HandBuckets, HandStrength,
+    HandStrengthCalculator,
+};
@@ -0,0 +1,409 @@
+//! Expected hand strength and bucketing hands by it.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::deck::Deck;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::ports::inbound::{EquityCalculator, EquityError};
+
+use super::kmeans::KMeans;
+
+/// Runouts sampled per hand by `HandStrengthCalculator::new`.
+pub const DEFAULT_RUNOUTS: usize = 50;
+
+/// Equity samples per runout used by `HandStrengthCalculator::new`.
+pub const DEFAULT_RUNOUT_SAMPLES: u32 = 200;
+
+/// Histogram bins used by `HandStrengthCalculator::new`.
+pub const DEFAULT_BINS: usize = 10;
+
+/// How strong a hand is on a board and how much that may still change.
+///
+/// Built from the hand's river equity against a random hand on each sampled runout.
+/// `ehs` is the mean, `ehs_squared` the mean of the squares, which rewards hands whose
+/// equity swings towards the nuts; `histogram` holds the share of runouts in each
+/// equity bin from 0.0 up to 1.0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HandStrength {
+    ehs: f64,
+    ehs_squared: f64,
+    histogram: Vec<f64>,
+}
+
+/// `HandStrength` - Constructors
+impl HandStrength {
+    /// Summarizes river `equities` into `bins` equal-width histogram bins; at least one
+    /// bin is always kept.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_equities(equities: &[f64], bins: usize) -> Self {
+        let bins = bins.max(1);
+        let mut histogram = vec![0.0; bins];
+        if equities.is_empty() {
+            return Self {
+                ehs: 0.0,
+                ehs_squared: 0.0,
+                histogram,
+            };
+        }
+        let count = equities.len() as f64;
+        for &equity in equities {
+            let equity = equity.clamp(0.0, 1.0);
+            let bin = ((equity * bins as f64) as usize).min(bins - 1);
+            histogram[bin] += 1.0 / count;
+        }
+        Self {
+            ehs: equities.iter().sum::<f64>() / count,
+            ehs_squared: equities.iter().map(|equity| equity * equity).sum::<f64>() / count,
+            histogram,
+        }
+    }
+}
+
+/// `HandStrength` - Accessors
+impl HandStrength {
+    /// Expected hand strength: mean equity over the runouts.
+    #[must_use]
+    pub const fn ehs(&self) -> f64 {
+        self.ehs
+    }
+
+    /// Mean squared equity over the runouts (EHS²).
+    #[must_use]
+    pub const fn ehs_squared(&self) -> f64 {
+        self.ehs_squared
+    }
+
+    /// Variance of the equity over the runouts; zero on the river.
+    #[must_use]
+    pub fn potential(&self) -> f64 {
+        (self.ehs.mul_add(-self.ehs, self.ehs_squared)).max(0.0)
+    }
+
+    /// Share of runouts in each equity bin, lowest equity first.
+    #[must_use]
+    pub fn histogram(&self) -> &[f64] {
+        &self.histogram
+    }
+}
+
+/// Measures `HandStrength` with an `EquityCalculator`, typically the Monte Carlo one.
+///
+/// Boards short of the river are completed with `runouts` random runouts, drawn from a
+/// seeded generator so results are reproducible; each completed board is handed to
+/// the calculator with `samples` samples against one opponent. River boards need no
+/// runouts and are measured once.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::board::Board;
+/// use riverrun::core::domain::entities::hole_cards::HoleCards;
+/// use riverrun::core::domain::services::abstraction::HandStrengthCalculator;
+/// use riverrun::core::domain::services::{CactusKevEvaluator, MonteCarloEquityCalculator};
+///
+/// let calculator = HandStrengthCalculator::new(MonteCarloEquityCalculator::new(CactusKevEvaluator::new()))
+///     .with_runouts(10)
+///     .with_samples(100);
+/// let flop = Board::with_cards(
+///     "Ah 7d 2c".split_whitespace().map(|c| c.parse().unwrap()).collect(),
+/// )
+/// .unwrap();
+/// let hand = |text: &str| HoleCards::new(text[..2].parse().unwrap(), text[2..].parse().unwrap());
+///
+/// let aces = calculator.strength(&hand("AsAd"), &flop).unwrap();
+/// let trash = calculator.strength(&hand("9s4h"), &flop).unwrap();
+/// assert!(aces.ehs() > trash.ehs());
+/// assert!(aces.ehs_squared() <= aces.ehs());
+/// ```
+pub struct HandStrengthCalculator<C: EquityCalculator> {
+    calculator: C,
+    runouts: usize,
+    samples: u32,
+    bins: usize,
+    seed: u64,
+}
+
+/// `HandStrengthCalculator` - Constructors
+impl<C: EquityCalculator> HandStrengthCalculator<C> {
+    /// Measures with `calculator` using `DEFAULT_RUNOUTS`, `DEFAULT_RUNOUT_SAMPLES`,
+    /// and `DEFAULT_BINS`.
+    pub const fn new(calculator: C) -> Self {
+        Self {
+            calculator,
+            runouts: DEFAULT_RUNOUTS,
+            samples: DEFAULT_RUNOUT_SAMPLES,
+            bins: DEFAULT_BINS,
+            seed: 0,
+        }
+    }
+
+    /// This calculator sampling `runouts` runouts per hand.
+    #[must_use]
+    pub const fn with_runouts(mut self, runouts: usize) -> Self {
+        self.runouts = runouts;
+        self
+    }
+
+    /// This calculator asking for `samples` equity samples per runout.
+    #[must_use]
+    pub const fn with_samples(mut self, samples: u32) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// This calculator building histograms with `bins` bins.
+    #[must_use]
+    pub const fn with_bins(mut self, bins: usize) -> Self {
+        self.bins = bins;
+        self
+    }
+
+    /// This calculator drawing runouts from `seed` instead of the default seed.
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+/// `HandStrengthCalculator` - Accessors
+impl<C: EquityCalculator> HandStrengthCalculator<C> {
+    /// The underlying equity calculator.
+    pub const fn calculator(&self) -> &C {
+        &self.calculator
+    }
+
+    /// Number of histogram bins.
+    pub const fn bins(&self) -> usize {
+        self.bins
+    }
+}
+
+/// `HandStrengthCalculator` - Operations
+impl<C: EquityCalculator> HandStrengthCalculator<C> {
+    /// Strength of `hole_cards` on `board`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the calculator's `EquityError` if any runout cannot be measured.
+    pub fn strength(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+    ) -> Result<HandStrength, EquityError> {
+        let missing = 5 - board.len().min(5);
+        if missing == 0 {
+            let equity = self
+                .calculator
+                .calculate_sampled(hole_cards, board, 1, self.samples)?
+                .equity();
+            return Ok(HandStrength::from_equities(&[equity], self.bins));
+        }
+
+        let mut dead = board.cards().to_vec();
+        dead.extend(hole_cards.cards());
+        let mut unseen = Deck::excluding(&dead).to_vec();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let equities: Vec<f64> = (0..self.runouts)
+            .filter_map(|_| {
+                let (runout, _) = unseen.partial_shuffle(&mut rng, missing);
+                let mut cards = board.cards().to_vec();
+                cards.extend_from_slice(runout);
+                let river = Board::with_cards(cards)?;
+                Some(
+                    self.calculator
+                        .calculate_sampled(hole_cards, &river, 1, self.samples)
+                        .map(|result| result.equity()),
+                )
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(HandStrength::from_equities(&equities, self.bins))
+    }
+
+    /// Buckets `hands` on `board` by clustering their equity histograms with `kmeans`.
+    ///
+    /// `Distance::EarthMovers` usually suits histograms best. Hands blocked by the board are skipped. Buckets are numbered from the lowest
+    /// mean EHS up, so bucket 0 holds the weakest hands.
+    ///
+    /// # Errors
+    ///
+    /// Returns the calculator's `EquityError` if any hand's strength cannot be measured.
+    pub fn bucket(
+        &self,
+        hands: &[HoleCards],
+        board: &Board,
+        kmeans: &KMeans,
+    ) -> Result<HandBuckets, EquityError> {
+        let hands: Vec<(HoleCards, HandStrength)> = hands
+            .iter()
+            .filter(|hand| !hand.cards().iter().any(|card| board.cards().contains(card)))
+            .map(|hand| Ok((*hand, self.strength(hand, board)?)))
+            .collect::<Result<_, EquityError>>()?;
+        let points: Vec<Vec<f64>> = hands
+            .iter()
+            .map(|(_, strength)| strength.histogram().to_vec())
+            .collect();
+        let clustering = kmeans.fit(&points);
+
+        // Renumber clusters by mean EHS
+        let num_buckets = clustering.num_clusters();
+        let mean_ehs: Vec<f64> = (0..num_buckets)
+            .map(|cluster| {
+                let members: Vec<f64> = hands
+                    .iter()
+                    .zip(clustering.assignments())
+                    .filter(|&(_, &assigned)| assigned == cluster)
+                    .map(|((_, strength), _)| strength.ehs())
+                    .collect();
+                #[allow(clippy::cast_precision_loss)]
+                let mean = members.iter().sum::<f64>() / members.len().max(1) as f64;
+                mean
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..num_buckets).collect();
+        order.sort_by(|&a, &b| mean_ehs[a].total_cmp(&mean_ehs[b]));
+        let mut rank = vec![0; num_buckets];
+        for (bucket, &cluster) in order.iter().enumerate() {
+            rank[cluster] = bucket;
+        }
+
+        Ok(HandBuckets {
+            hands: hands
+                .into_iter()
+                .zip(clustering.assignments())
+                .map(|((hand, strength), &cluster)| (hand, strength, rank[cluster]))
+                .collect(),
+            num_buckets,
+        })
+    }
+}
+
+/// Hands grouped into buckets of similar strength by `HandStrengthCalculator::bucket`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HandBuckets {
+    hands: Vec<(HoleCards, HandStrength, usize)>,
+    num_buckets: usize,
+}
+
+/// `HandBuckets` - Accessors
+impl HandBuckets {
+    /// Number of buckets.
+    #[must_use]
+    pub const fn num_buckets(&self) -> usize {
+        self.num_buckets
+    }
+
+    /// Bucket of `hole_cards`, in either card order, or `None` if it was not bucketed.
+    #[must_use]
+    pub fn bucket(&self, hole_cards: &HoleCards) -> Option<usize> {
+        self.find(*hole_cards).map(|&(_, _, bucket)| bucket)
+    }
+
+    /// Measured strength of `hole_cards`, or `None` if it was not bucketed.
+    #[must_use]
+    pub fn strength(&self, hole_cards: &HoleCards) -> Option<&HandStrength> {
+        self.find(*hole_cards).map(|(_, strength, _)| strength)
+    }
+
+    /// Hands in `bucket`.
+    pub fn hands_in(&self, bucket: usize) -> impl Iterator<Item = &HoleCards> {
+        self.hands
+            .iter()
+            .filter(move |&&(_, _, assigned)| assigned == bucket)
+            .map(|(hand, _, _)| hand)
+    }
+
+    /// The entry for `hole_cards` in either card order.
+    fn find(&self, hole_cards: HoleCards) -> Option<&(HoleCards, HandStrength, usize)> {
+        let [first, second] = *hole_cards.cards();
+        self.hands.iter().find(|(hand, _, _)| {
+            hand.cards() == &[first, second] || hand.cards() == &[second, first]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::services::abstraction::Distance;
+    use crate::core::domain::services::equity::MonteCarloEquityCalculator;
+    use crate::core::domain::services::evaluation::CactusKevEvaluator;
+
+    fn hand(text: &str) -> HoleCards {
+        HoleCards::new(text[..2].parse().unwrap(), text[2..].parse().unwrap())
+    }
+
+    fn board(text: &str) -> Board {
+        Board::with_cards(
+            text.split_whitespace()
+                .map(|c| c.parse().unwrap())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    fn calculator() -> HandStrengthCalculator<MonteCarloEquityCalculator<CactusKevEvaluator>> {
+        HandStrengthCalculator::new(MonteCarloEquityCalculator::new(CactusKevEvaluator::new()))
+            .with_runouts(8)
+            .with_samples(150)
+            .with_bins(5)
+    }
+
+    #[test]
+    fn test_from_equities_summarizes_runouts() {
+        let strength = HandStrength::from_equities(&[0.0, 0.5, 1.0, 1.0], 4);
+        assert!((strength.ehs() - 0.625).abs() < 1e-12);
+        assert!((strength.ehs_squared() - 2.25 / 4.0).abs() < 1e-12);
+        assert!((strength.potential() - (0.5625 - 0.390_625)).abs() < 1e-12);
+        assert_eq!(strength.histogram(), &[0.25, 0.0, 0.25, 0.5]);
+    }
+
+    #[test]
+    fn test_river_strength_has_no_potential() {
+        let strength = calculator()
+            .strength(&hand("AsAd"), &board("Ah 7d 2c 9s 4h"))
+            .unwrap();
+        assert!(strength.ehs() > 0.9);
+        assert!(strength.potential().abs() < 1e-12);
+        assert_eq!(
+            strength
+                .histogram()
+                .iter()
+                .filter(|&&share| share > 0.0)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_bucket_orders_buckets_by_strength() {
+        let hands = [
+            hand("AsAd"),
+            hand("7h7s"),
+            hand("9s4h"),
+            hand("3c5d"),
+            hand("Ah2d"),
+        ];
+        let buckets = calculator().bucket(
+            &hands,
+            &board("Ah 7d 2c"),
+            &KMeans::new(2).with_distance(Distance::EarthMovers),
+        )
+        .unwrap();
+        assert_eq!(buckets.num_buckets(), 2);
+        // The blocked hand is left out
+        assert_eq!(buckets.bucket(&hand("Ah2d")), None);
+        assert_eq!(buckets.bucket(&hand("AdAs")), Some(1));
+        assert_eq!(buckets.bucket(&hand("7h7s")), Some(1));
+        assert_eq!(buckets.bucket(&hand("9s4h")), Some(0));
+        assert_eq!(buckets.hands_in(0).count() + buckets.hands_in(1).count(), 4);
+    }
+}
@@ -0,0 +1,291 @@
+//! Card removal analysis: how hero's hole cards reshape an opponent's range.
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hand::HandRank;
+use crate::core::domain::entities::hand_range::HandRange;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::ports::inbound::HandEvaluator;
+
+/// Weighted combination counts before and after removing hero's cards.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ComboDelta {
+    /// Weighted combinations left once the board is removed.
+    pub before: f64,
+    /// Weighted combinations left once the board and hero's cards are removed.
+    pub after: f64,
+}
+
+/// `ComboDelta` - Accessors
+impl ComboDelta {
+    /// Weighted combinations hero's cards remove.
+    #[must_use]
+    pub fn removed(&self) -> f64 {
+        self.before - self.after
+    }
+
+    /// Share of the combinations hero's cards remove, or 0.0 if there were none.
+    #[must_use]
+    pub fn fraction_removed(&self) -> f64 {
+        if self.before > 0.0 {
+            self.removed() / self.before
+        } else {
+            0.0
+        }
+    }
+}
+
+/// `ComboDelta` - Operations
+impl ComboDelta {
+    fn add(&mut self, other: Self) {
+        self.before += other.before;
+        self.after += other.after;
+    }
+}
+
+/// Which combinations of an opponent's range hero's hole cards block.
+///
+/// Combinations sharing a card with the board are never counted. Of the rest, those
+/// sharing a card with hero are blocked. The nuts are the combinations making the
+/// strongest hand category any two cards can make on the board, such as every flush
+/// on a three-flush board. Bluffs work best with cards that block the nuts while
+/// leaving the hands the opponent would fold untouched.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::board::Board;
+/// use riverrun::core::domain::entities::card::Card;
+/// use riverrun::core::domain::entities::hand::HandRank;
+/// use riverrun::core::domain::entities::hand_range::HandRange;
+/// use riverrun::core::domain::entities::hole_cards::HoleCards;
+/// use riverrun::core::domain::services::{BlockerAnalysis, CactusKevEvaluator};
+///
+/// let cards = |text: &str| -> Vec<Card> {
+///     text.split_whitespace().map(|c| c.parse().unwrap()).collect()
+/// };
+/// let board = Board::with_cards(cards("Kh 8h 3h 2c Jd")).unwrap();
+/// let range: HandRange = "AhQh, QhTh, 99".parse().unwrap();
+/// // The ace of hearts blocks one of the two flushes
+/// let hero = cards("Ah 5c");
+/// let analysis = BlockerAnalysis::analyze(
+///     &CactusKevEvaluator::new(),
+///     &HoleCards::new(hero[0], hero[1]),
+///     &board,
+///     &range,
+/// );
+/// assert_eq!(analysis.nut_rank(), Some(HandRank::Flush));
+/// assert_eq!(analysis.nuts().before, 2.0);
+/// assert_eq!(analysis.nuts().after, 1.0);
+/// assert_eq!(analysis.blocked_nuts().len(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockerAnalysis {
+    total: ComboDelta,
+    nut_rank: Option<HandRank>,
+    nuts: ComboDelta,
+    by_rank: Vec<(HandRank, ComboDelta)>,
+    blocked: Vec<HoleCards>,
+    blocked_nuts: Vec<HoleCards>,
+    unblocked_nuts: Vec<HoleCards>,
+}
+
+/// `BlockerAnalysis` - Constructors
+impl BlockerAnalysis {
+    /// Analyzes how `hero` blocks `range` on `board`.
+    ///
+    /// Preflop there are no made hands, so only the totals and blocked combinations
+    /// are reported: the nut rank is `None` and the per-category breakdown is empty.
+    pub fn analyze<E: HandEvaluator + ?Sized>(
+        evaluator: &E,
+        hero: &HoleCards,
+        board: &Board,
+        range: &HandRange,
+    ) -> Self {
+        let board_cards = board.cards();
+        let made_rank = |combo: &HoleCards| -> Option<HandRank> {
+            let mut cards = board_cards.to_vec();
+            cards.extend_from_slice(combo.cards());
+            evaluator
+                .evaluate_best_fast(&cards)
+                .map(HandRank::from_strength)
+        };
+        let nut_rank = HandRange::full()
+            .without(board_cards)
+            .combos()
+            .filter_map(|(combo, _)| made_rank(&combo))
+            .max();
+
+        let mut analysis = Self {
+            total: ComboDelta::default(),
+            nut_rank,
+            nuts: ComboDelta::default(),
+            by_rank: Vec::new(),
+            blocked: Vec::new(),
+            blocked_nuts: Vec::new(),
+            unblocked_nuts: Vec::new(),
+        };
+        for (combo, weight) in range.without(board_cards).combos() {
+            let is_blocked = shares_card(combo, *hero);
+            let after = if is_blocked { 0.0 } else { weight };
+            let delta = ComboDelta {
+                before: weight,
+                after,
+            };
+            analysis.total.add(delta);
+            if is_blocked {
+                analysis.blocked.push(combo);
+            }
+
+            let Some(rank) = made_rank(&combo) else {
+                continue;
+            };
+            match analysis.by_rank.iter_mut().find(|(made, _)| *made == rank) {
+                Some((_, counts)) => counts.add(delta),
+                None => analysis.by_rank.push((rank, delta)),
+            }
+            if Some(rank) == nut_rank {
+                analysis.nuts.add(delta);
+                if is_blocked {
+                    analysis.blocked_nuts.push(combo);
+                } else {
+                    analysis.unblocked_nuts.push(combo);
+                }
+            }
+        }
+        analysis
+            .by_rank
+            .sort_by_key(|&(rank, _)| std::cmp::Reverse(rank));
+        analysis
+    }
+}
+
+/// `BlockerAnalysis` - Accessors
+impl BlockerAnalysis {
+    /// Combinations in the whole range.
+    #[must_use]
+    pub const fn total(&self) -> ComboDelta {
+        self.total
+    }
+
+    /// Strongest hand category any two cards can make on the board; `None` preflop.
+    #[must_use]
+    pub const fn nut_rank(&self) -> Option<HandRank> {
+        self.nut_rank
+    }
+
+    /// Combinations in the range making the nut category.
+    #[must_use]
+    pub const fn nuts(&self) -> ComboDelta {
+        self.nuts
+    }
+
+    /// Combinations by the category they make, strongest category first.
+    ///
+    /// Categories the range does not make are left out.
+    #[must_use]
+    pub fn by_rank(&self) -> &[(HandRank, ComboDelta)] {
+        &self.by_rank
+    }
+
+    /// Combinations in `rank`, or zero counts if the range does not make it.
+    #[must_use]
+    pub fn rank(&self, rank: HandRank) -> ComboDelta {
+        self.by_rank
+            .iter()
+            .find(|(made, _)| *made == rank)
+            .map_or_else(ComboDelta::default, |&(_, counts)| counts)
+    }
+
+    /// Every range combination sharing a card with hero.
+    #[must_use]
+    pub fn blocked(&self) -> &[HoleCards] {
+        &self.blocked
+    }
+
+    /// Nut combinations sharing a card with hero.
+    #[must_use]
+    pub fn blocked_nuts(&self) -> &[HoleCards] {
+        &self.blocked_nuts
+    }
+
+    /// Nut combinations hero leaves available to the opponent.
+    #[must_use]
+    pub fn unblocked_nuts(&self) -> &[HoleCards] {
+        &self.unblocked_nuts
+    }
+}
+
+fn shares_card(combo: HoleCards, hero: HoleCards) -> bool {
+    let hero: &[Card] = hero.cards();
+    combo.cards().iter().any(|card| hero.contains(card))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::services::CactusKevEvaluator;
+
+    fn cards(text: &str) -> Vec<Card> {
+        text.split_whitespace()
+            .map(|card| card.parse().unwrap())
+            .collect()
+    }
+
+    fn analyze(hero: &str, board: &str, range: &str) -> BlockerAnalysis {
+        let hero = cards(hero);
+        BlockerAnalysis::analyze(
+            &CactusKevEvaluator::new(),
+            &HoleCards::new(hero[0], hero[1]),
+            &Board::with_cards(cards(board)).unwrap(),
+            &range.parse().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_nut_flush_blocker() {
+        let range = "AhKh, AhQh, QhJh, 22+, AKo";
+        // The ace of hearts blocks every nut flush with an ace
+        let analysis = analyze("Ah 4c", "Th 7h 3h 2c 9s", range);
+        assert_eq!(analysis.nut_rank(), Some(HandRank::Flush));
+        assert!((analysis.nuts().before - 3.0).abs() < 1e-9);
+        assert!((analysis.nuts().after - 1.0).abs() < 1e-9);
+        assert_eq!(analysis.blocked_nuts().len(), 2);
+        assert_eq!(analysis.unblocked_nuts().len(), 1);
+
+        // A small club blocks none of the flushes
+        let analysis = analyze("4c 5c", "Th 7h 3h 2c 9s", range);
+        assert!(analysis.blocked_nuts().is_empty());
+        assert!(analysis.nuts().fraction_removed().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_totals_and_categories() {
+        let analysis = analyze("Ks Kd", "Kh 8c 3d", "KK, 88, AK");
+        // The board leaves 3 KK, 3 88, and 12 AK combos
+        assert!((analysis.total().before - 18.0).abs() < 1e-9);
+        // Hero's kings leave no KK and only the AK combos with the king of clubs
+        assert!((analysis.total().after - 7.0).abs() < 1e-9);
+        assert_eq!(analysis.nut_rank(), Some(HandRank::ThreeOfAKind));
+        assert!((analysis.rank(HandRank::ThreeOfAKind).removed() - 3.0).abs() < 1e-9);
+        assert!((analysis.rank(HandRank::OnePair).fraction_removed() - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(analysis.by_rank()[0].0, HandRank::ThreeOfAKind);
+        assert_eq!(analysis.rank(HandRank::Flush), ComboDelta::default());
+    }
+
+    #[test]
+    fn test_preflop_counts_only() {
+        let hero = cards("As Ad");
+        let analysis = BlockerAnalysis::analyze(
+            &CactusKevEvaluator::new(),
+            &HoleCards::new(hero[0], hero[1]),
+            &Board::new(),
+            &"AA, AKs".parse().unwrap(),
+        );
+        assert_eq!(analysis.nut_rank(), None);
+        assert!(analysis.by_rank().is_empty());
+        assert!((analysis.total().before - 10.0).abs() < 1e-9);
+        assert!((analysis.total().after - 3.0).abs() < 1e-9);
+        assert_eq!(analysis.blocked().len(), 7);
+    }
+}
@@ -0,0 +1,264 @@
+//! Expected value of facing a bet: folding, calling, or moving all in.
+
+use crate::core::domain::entities::hand_range::HandRange;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::primitives::Chips;
+use crate::core::domain::services::equity::PreflopEquityTable;
+use crate::core::ports::inbound::EquityResult;
+
+/// Expected value of each option when facing a bet.
+///
+/// EVs are in chips and measured against folding, so folding is always worth 0.0 and
+/// chips already in the pot are treated as spent. The pot includes the bet being
+/// faced. Stacks cap what can be won: a hero too short to call loses only their
+/// stack and the uncovered part of the bet goes back to the opponent.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::primitives::Chips;
+/// use riverrun::core::domain::services::analysis::EvCalculator;
+///
+/// // Facing a pot-sized bet: 100 in the pot plus a 100 bet
+/// let spot = EvCalculator::new(Chips::new(200), Chips::new(100), Chips::new(1000), Chips::new(900));
+/// assert!((spot.pot_odds() - 2.0).abs() < 1e-12);
+/// assert!((spot.required_equity() - 1.0 / 3.0).abs() < 1e-12);
+/// // With 40% equity, calling gains 0.4 * 300 - 100 chips
+/// assert!((spot.ev_call(0.4) - 20.0).abs() < 1e-9);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EvCalculator {
+    pot: Chips,
+    to_call: Chips,
+    hero_stack: Chips,
+    villain_stack: Chips,
+}
+
+/// `EvCalculator` - Constructors
+impl EvCalculator {
+    /// A decision facing a bet.
+    ///
+    /// # Arguments
+    /// * `pot` - Chips in the middle, including the bet being faced
+    /// * `to_call` - Chips hero must add to call
+    /// * `hero_stack` - Hero's chips behind before acting
+    /// * `villain_stack` - The bettor's chips behind after betting
+    #[must_use]
+    pub const fn new(pot: Chips, to_call: Chips, hero_stack: Chips, villain_stack: Chips) -> Self {
+        Self {
+            pot,
+            to_call,
+            hero_stack,
+            villain_stack,
+        }
+    }
+}
+
+/// `EvCalculator` - Accessors
+impl EvCalculator {
+    /// Chips hero puts in by calling, capped by hero's stack.
+    #[must_use]
+    pub const fn call_amount(&self) -> Chips {
+        self.to_call.min(self.hero_stack)
+    }
+
+    /// Chips hero puts in by moving all in, capped by what the opponent can match.
+    #[must_use]
+    pub const fn shove_amount(&self) -> Chips {
+        self.hero_stack.min(Chips::new(
+            self.to_call
+                .value()
+                .saturating_add(self.villain_stack.value()),
+        ))
+    }
+
+    /// Ratio of the pot to the price of calling, e.g. 2.0 for 2-to-1.
+    ///
+    /// Infinite when there is nothing to call.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn pot_odds(&self) -> f64 {
+        let call = self.call_amount();
+        if call.is_zero() {
+            return f64::INFINITY;
+        }
+        self.contested_pot() / call.value() as f64
+    }
+
+    /// Equity at which calling breaks even against folding.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn required_equity(&self) -> f64 {
+        let call = self.call_amount().value() as f64;
+        if call == 0.0 {
+            return 0.0;
+        }
+        call / (self.contested_pot() + call)
+    }
+
+    /// How often the opponent must fold for a shove with `equity_when_called` to break
+    /// even, between 0.0 and 1.0.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn required_fold_equity(&self, equity_when_called: f64) -> f64 {
+        let called = self.ev_when_called(equity_when_called);
+        if called >= 0.0 {
+            return 0.0;
+        }
+        // Solves fold * pot + (1 - fold) * called = 0
+        let pot = self.pot.value() as f64;
+        (-called / (pot - called)).clamp(0.0, 1.0)
+    }
+}
+
+/// `EvCalculator` - Operations
+impl EvCalculator {
+    /// EV of folding, which is the baseline.
+    #[must_use]
+    pub const fn ev_fold(&self) -> f64 {
+        0.0
+    }
+
+    /// EV of calling with `equity`, the chance of winning the pot at showdown.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn ev_call(&self, equity: f64) -> f64 {
+        let call = self.call_amount().value() as f64;
+        equity.mul_add(self.contested_pot() + call, -call)
+    }
+
+    /// EV of calling with the equity of `result`.
+    #[must_use]
+    pub fn ev_call_result(&self, result: &EquityResult) -> f64 {
+        self.ev_call(result.equity())
+    }
+
+    /// EV of calling preflop with `hero` against the opponent's `range`.
+    ///
+    /// # Returns
+    /// `None` if `hero` blocks every combination of the range.
+    #[must_use]
+    pub fn ev_call_vs_range(
+        &self,
+        table: &PreflopEquityTable,
+        hero: &HoleCards,
+        range: &HandRange,
+    ) -> Option<f64> {
+        table
+            .lookup_range(hero, range)
+            .map(|equity| self.ev_call(equity))
+    }
+
+    /// EV of moving all in when the opponent folds `fold_probability` of the time and
+    /// hero has `equity_when_called` against the hands that call.
+    ///
+    /// A hero who cannot more than call is simply calling.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn ev_shove(&self, fold_probability: f64, equity_when_called: f64) -> f64 {
+        if self.hero_stack <= self.to_call {
+            return self.ev_call(equity_when_called);
+        }
+        let fold_probability = fold_probability.clamp(0.0, 1.0);
+        let pot = self.pot.value() as f64;
+        fold_probability.mul_add(
+            pot,
+            (1.0 - fold_probability) * self.ev_when_called(equity_when_called),
+        )
+    }
+}
+
+/// `EvCalculator` - Helpers
+impl EvCalculator {
+    /// Pot hero can win by calling: the uncovered part of the bet is returned.
+    #[allow(clippy::cast_precision_loss)]
+    const fn contested_pot(&self) -> f64 {
+        let returned = self.to_call.saturating_sub(self.call_amount());
+        self.pot.saturating_sub(returned).value() as f64
+    }
+
+    /// EV of a shove that gets called.
+    #[allow(clippy::cast_precision_loss)]
+    fn ev_when_called(&self, equity: f64) -> f64 {
+        let shove = self.shove_amount().value() as f64;
+        let matched = self.shove_amount().saturating_sub(self.to_call).value() as f64;
+        equity.mul_add(self.pot.value() as f64 + shove + matched, -shove)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::card::Card;
+
+    fn spot(pot: u64, to_call: u64, hero: u64, villain: u64) -> EvCalculator {
+        EvCalculator::new(
+            Chips::new(pot),
+            Chips::new(to_call),
+            Chips::new(hero),
+            Chips::new(villain),
+        )
+    }
+
+    #[test]
+    fn test_call_ev_and_pot_odds() {
+        let half_pot = spot(150, 50, 500, 500);
+        assert!((half_pot.pot_odds() - 3.0).abs() < 1e-12);
+        assert!((half_pot.required_equity() - 0.25).abs() < 1e-12);
+        assert!(half_pot.ev_call(0.25).abs() < 1e-9);
+        assert!(half_pot.ev_call(0.2) < 0.0);
+        assert!(
+            (half_pot.ev_call_result(&EquityResult::from_counts(1, 0, 1, 1)) - 50.0).abs() < 1e-9
+        );
+        assert!(half_pot.ev_fold().abs() < f64::EPSILON);
+
+        let check = spot(100, 0, 500, 500);
+        assert!(check.pot_odds().is_infinite());
+        assert!(check.required_equity().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_short_stack_call() {
+        // Hero has only 40 of the 100 bet; 60 goes back to the bettor
+        let short = spot(200, 100, 40, 500);
+        assert_eq!(short.call_amount(), Chips::new(40));
+        assert!((short.pot_odds() - 3.5).abs() < 1e-12);
+        assert!((short.ev_call(0.5) - 50.0).abs() < 1e-9);
+        // Shoving is the same as calling
+        assert!((short.ev_shove(0.9, 0.5) - short.ev_call(0.5)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_shove_ev() {
+        // 100 bet into 100; hero shoves 400 and the bettor has 200 behind
+        let spot = spot(200, 100, 400, 200);
+        assert_eq!(spot.shove_amount(), Chips::new(300));
+        // Called: 30% of a 200 + 300 + 200 pot, less the 300 shoved
+        assert!((spot.ev_shove(0.0, 0.3) - -90.0).abs() < 1e-9);
+        assert!((spot.ev_shove(1.0, 0.3) - 200.0).abs() < 1e-9);
+
+        let needed = spot.required_fold_equity(0.3);
+        assert!((needed - 90.0 / 290.0).abs() < 1e-12);
+        assert!(spot.ev_shove(needed, 0.3).abs() < 1e-9);
+        assert!(spot.required_fold_equity(0.6).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_call_vs_range() {
+        let table = PreflopEquityTable::shared();
+        let hero = HoleCards::new(
+            Card::from_string("Ah").unwrap(),
+            Card::from_string("Qd").unwrap(),
+        );
+        let range: HandRange = "22+, AJ+, KQ".parse().unwrap();
+        let equity = table.lookup_range(&hero, &range).unwrap();
+
+        let jam = spot(1050, 950, 1000, 0);
+        let ev = jam.ev_call_vs_range(table, &hero, &range).unwrap();
+        assert!((ev - jam.ev_call(equity)).abs() < 1e-9);
+        assert_eq!(
+            jam.ev_call_vs_range(table, &hero, &"AhQd".parse().unwrap()),
+            None
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! How often a starting hand ends up as each hand category by the river.
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hand::HandRank;
+use crate::core::domain::entities::hand_class::HandClass;
+use crate::core::domain::services::utils::combinations_iter;
+use crate::core::ports::inbound::HandEvaluator;
+use crate::core::ports::outbound::RandomSource;
+
+/// Probability of a starting hand making each `HandRank` by the river, for teaching
+/// material and as a sanity check on an evaluator.
+///
+/// Every combination of a class makes each category equally often, since the suits
+/// are interchangeable, so one combination stands in for the class. Boards are either
+/// enumerated, all 2,118,760 of them, or sampled.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::hand::HandRank;
+/// use riverrun::core::domain::services::analysis::RankFrequencies;
+/// use riverrun::core::domain::services::CactusKevEvaluator;
+/// use riverrun::core::ports::outbound::RandRandomSource;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut source = RandRandomSource::new(StdRng::seed_from_u64(1));
+/// let table = RankFrequencies::sampled(
+///     &CactusKevEvaluator::new(),
+///     "76s".parse().unwrap(),
+///     20_000,
+///     &mut source,
+/// );
+/// assert_eq!(table.total(), 20_000);
+/// // 76s makes a straight a little under a tenth of the time
+/// assert!((0.05..0.12).contains(&table.probability(HandRank::Straight)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RankFrequencies {
+    class: HandClass,
+    counts: [u64; HandRank::ALL.len()],
+    total: u64,
+}
+
+/// `RankFrequencies` - Constructors
+impl RankFrequencies {
+    /// Counts the category made on every possible board.
+    pub fn exhaustive<E: HandEvaluator + ?Sized>(evaluator: &E, class: HandClass) -> Self {
+        let (hole, deck) = Self::deal(class);
+        let mut table = Self::empty(class);
+        for indices in combinations_iter(deck.len(), 5) {
+            let board = [
+                deck[indices[0]],
+                deck[indices[1]],
+                deck[indices[2]],
+                deck[indices[3]],
+                deck[indices[4]],
+            ];
+            table.record(evaluator, hole, board);
+        }
+        table
+    }
+
+    /// Counts the category made on `samples` boards drawn at random with `source`.
+    pub fn sampled<E: HandEvaluator + ?Sized>(
+        evaluator: &E,
+        class: HandClass,
+        samples: usize,
+        source: &mut dyn RandomSource,
+    ) -> Self {
+        let (hole, mut deck) = Self::deal(class);
+        let mut table = Self::empty(class);
+        for _ in 0..samples {
+            // Partial Fisher-Yates: the first five cards become a uniform random board
+            for i in 0..5 {
+                let j = i + source.random_index(deck.len() - i);
+                deck.swap(i, j);
+            }
+            table.record(
+                evaluator,
+                hole,
+                [deck[0], deck[1], deck[2], deck[3], deck[4]],
+            );
+        }
+        table
+    }
+}
+
+/// `RankFrequencies` - Accessors
+impl RankFrequencies {
+    /// The starting hand class.
+    #[must_use]
+    pub const fn class(&self) -> HandClass {
+        self.class
+    }
+
+    /// Boards counted.
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Boards on which the hand made `rank`.
+    #[must_use]
+    pub const fn count(&self, rank: HandRank) -> u64 {
+        self.counts[rank as usize]
+    }
+
+    /// Share of boards on which the hand made `rank`, or 0.0 if none were counted.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn probability(&self, rank: HandRank) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.count(rank) as f64 / self.total as f64
+    }
+
+    /// Probability of every category, weakest first.
+    pub fn iter(&self) -> impl Iterator<Item = (HandRank, f64)> + '_ {
+        HandRank::ALL
+            .into_iter()
+            .map(|rank| (rank, self.probability(rank)))
+    }
+}
+
+/// `RankFrequencies` - Helpers
+impl RankFrequencies {
+    const fn empty(class: HandClass) -> Self {
+        Self {
+            class,
+            counts: [0; HandRank::ALL.len()],
+            total: 0,
+        }
+    }
+
+    /// A combination of `class` and the 50 cards left once it is dealt.
+    fn deal(class: HandClass) -> ([Card; 2], Vec<Card>) {
+        let combo = class.combos()[0];
+        let hole = [combo.first(), combo.second()];
+        let deck = Card::all_cards()
+            .filter(|card| !hole.contains(card))
+            .collect();
+        (hole, deck)
+    }
+
+    fn record<E: HandEvaluator + ?Sized>(
+        &mut self,
+        evaluator: &E,
+        hole: [Card; 2],
+        board: [Card; 5],
+    ) {
+        let [b0, b1, b2, b3, b4] = board;
+        let strength = evaluator.evaluate_7cards_fast(&[hole[0], hole[1], b0, b1, b2, b3, b4]);
+        self.counts[HandRank::from_strength(strength) as usize] += 1;
+        self.total += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::services::CactusKevEvaluator;
+    use crate::core::ports::outbound::RandRandomSource;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn sampled(class: &str, samples: usize) -> RankFrequencies {
+        let mut source = RandRandomSource::new(StdRng::seed_from_u64(3));
+        RankFrequencies::sampled(
+            &CactusKevEvaluator::new(),
+            class.parse().unwrap(),
+            samples,
+            &mut source,
+        )
+    }
+
+    #[test]
+    fn test_pocket_pair_never_ends_as_high_card() {
+        let table = sampled("88", 5_000);
+        assert_eq!(table.count(HandRank::HighCard), 0);
+        assert!(table.probability(HandRank::ThreeOfAKind) > 0.1);
+        let sum: f64 = table.iter().map(|(_, probability)| probability).sum();
+        assert!((sum - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_suited_hands_make_more_flushes() {
+        let suited = sampled("76s", 10_000);
+        let offsuit = sampled("76o", 10_000);
+        assert!(suited.probability(HandRank::Flush) > 3.0 * offsuit.probability(HandRank::Flush));
+        assert_eq!(suited.class().to_string(), "76s");
+    }
+
+    #[test]
+    fn test_exhaustive_counts_every_board() {
+        let table = RankFrequencies::exhaustive(&CactusKevEvaluator::new(), "AA".parse().unwrap());
+        assert_eq!(table.total(), 2_118_760);
+        assert_eq!(table.count(HandRank::HighCard), 0);
+        assert_eq!(table.count(HandRank::FourOfAKind), 17_848);
+        assert_eq!(table.count(HandRank::StraightFlush), 216);
+    }
+
+    #[test]
+    fn test_empty_table() {
+        let table = sampled("AKo", 0);
+        assert_eq!(table.total(), 0);
+        assert!(table.probability(HandRank::OnePair).abs() < f64::EPSILON);
+    }
+}
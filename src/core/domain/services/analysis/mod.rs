@@ -0,0 +1,11 @@
+mod blockers;
+mod ev;
+mod frequencies;
+mod progression;
+mod runouts;
+
+pub use blockers::{BlockerAnalysis, ComboDelta};
+pub use ev::EvCalculator;
+pub use frequencies::RankFrequencies;
+pub use progression::{HandProgression, StreetSnapshot};
+pub use runouts::{RunoutConstraint, RunoutSampler};
@@ -0,0 +1,202 @@
+//! How a hand's strength and equity change from street to street.
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hand::{Hand, HandRank};
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::primitives::Street;
+use crate::core::domain::services::utils::card_combinations;
+use crate::core::ports::inbound::{EquityCalculator, EquityError, EquityResult, HandEvaluator};
+
+/// A hand as it stood on one street.
+#[derive(Clone, Debug)]
+pub struct StreetSnapshot {
+    /// The street.
+    pub street: Street,
+    /// The board as dealt by this street.
+    pub board: Board,
+    /// Best five-card hand with the board; `None` preflop.
+    pub hand: Option<Hand>,
+    /// Equity against random opponents with this board.
+    pub equity: EquityResult,
+}
+
+/// `StreetSnapshot` - Accessors
+impl StreetSnapshot {
+    /// Category of the best hand; `None` preflop.
+    #[must_use]
+    pub fn rank(&self) -> Option<HandRank> {
+        self.hand.as_ref().map(Hand::rank)
+    }
+}
+
+/// Timeline of a hand's strength across the streets of a runout, for hand reviews and
+/// coaching reports.
+///
+/// For each street up to the board given, it evaluates the best hand made so far and
+/// the equity against random opponents with only that street's cards known.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::hand::HandRank;
+/// use riverrun::core::domain::primitives::Street;
+/// use riverrun::core::domain::services::analysis::HandProgression;
+/// use riverrun::core::domain::services::{CactusKevEvaluator, SmartEquityCalculator};
+///
+/// let evaluator = CactusKevEvaluator::new();
+/// let progression = HandProgression::new(
+///     evaluator.clone(),
+///     SmartEquityCalculator::new(evaluator).with_samples(2_000),
+/// );
+/// let timeline = progression
+///     .analyze(&"AhKh".parse().unwrap(), &"Qh 7h 2c 9s Jh".parse().unwrap())
+///     .unwrap();
+///
+/// let streets: Vec<Street> = timeline.iter().map(|snapshot| snapshot.street).collect();
+/// assert_eq!(streets, [Street::Preflop, Street::Flop, Street::Turn, Street::River]);
+/// assert_eq!(timeline[1].rank(), Some(HandRank::HighCard));
+/// assert_eq!(timeline[3].rank(), Some(HandRank::Flush));
+/// ```
+#[derive(Clone, Debug)]
+pub struct HandProgression<E, C> {
+    evaluator: E,
+    calculator: C,
+    opponents: usize,
+}
+
+/// `HandProgression` - Constructors
+impl<E: HandEvaluator, C: EquityCalculator> HandProgression<E, C> {
+    /// Analyzer evaluating with `evaluator` and computing heads-up equity with
+    /// `calculator`.
+    #[must_use]
+    pub const fn new(evaluator: E, calculator: C) -> Self {
+        Self {
+            evaluator,
+            calculator,
+            opponents: 1,
+        }
+    }
+
+    /// Computes equity against `opponents` random hands instead of one.
+    #[must_use]
+    pub const fn with_opponents(mut self, opponents: usize) -> Self {
+        self.opponents = opponents;
+        self
+    }
+}
+
+/// `HandProgression` - Operations
+impl<E: HandEvaluator, C: EquityCalculator> HandProgression<E, C> {
+    /// One snapshot per street from preflop up to the street of `board`.
+    ///
+    /// # Errors
+    /// Returns an `EquityError` if a card repeats or equity cannot be calculated.
+    pub fn analyze(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+    ) -> Result<Vec<StreetSnapshot>, EquityError> {
+        Street::all()
+            .take_while(|&street| street <= board.street())
+            .map(|street| {
+                let dealt = Board::partial(board.cards()[..street.card_count()].to_vec())
+                    .unwrap_or_default();
+                self.snapshot(street, hole_cards, dealt)
+            })
+            .collect()
+    }
+
+    /// Snapshot of `hole_cards` with the `dealt` board.
+    ///
+    /// # Errors
+    /// Returns an `EquityError` if a card repeats or equity cannot be calculated.
+    pub fn snapshot(
+        &self,
+        street: Street,
+        hole_cards: &HoleCards,
+        dealt: Board,
+    ) -> Result<StreetSnapshot, EquityError> {
+        let equity = self
+            .calculator
+            .calculate(hole_cards, &dealt, self.opponents)?;
+        Ok(StreetSnapshot {
+            street,
+            hand: self.best_hand(*hole_cards, &dealt),
+            board: dealt,
+            equity,
+        })
+    }
+}
+
+/// `HandProgression` - Helpers
+impl<E: HandEvaluator, C: EquityCalculator> HandProgression<E, C> {
+    /// Best five-card hand among the hole cards and board; `None` preflop.
+    fn best_hand(&self, hole_cards: HoleCards, board: &Board) -> Option<Hand> {
+        let cards: Vec<Card> = hole_cards
+            .cards()
+            .iter()
+            .chain(board.cards())
+            .copied()
+            .collect();
+        if let Ok(seven) = <[Card; 7]>::try_from(cards.as_slice()) {
+            return Some(self.evaluator.evaluate_7cards(seven));
+        }
+        card_combinations(&cards, 5)
+            .filter_map(|five| five.try_into().ok())
+            .map(|five| self.evaluator.evaluate_5cards(five))
+            .min_by_key(Hand::strength)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::services::{CactusKevEvaluator, SmartEquityCalculator};
+
+    fn progression()
+    -> HandProgression<CactusKevEvaluator, SmartEquityCalculator<CactusKevEvaluator>> {
+        let evaluator = CactusKevEvaluator::new();
+        HandProgression::new(
+            evaluator.clone(),
+            SmartEquityCalculator::new(evaluator).with_samples(2_000),
+        )
+    }
+
+    #[test]
+    fn test_timeline_tracks_each_street() {
+        let hole: HoleCards = "8c8d".parse().unwrap();
+        let board: Board = "8s Kh 2d Kc 3s".parse().unwrap();
+        let timeline = progression().analyze(&hole, &board).unwrap();
+
+        assert_eq!(timeline.len(), 4);
+        assert_eq!(timeline[0].rank(), None);
+        assert!(timeline[0].board.is_empty());
+        assert_eq!(timeline[1].rank(), Some(HandRank::ThreeOfAKind));
+        assert_eq!(timeline[1].board.len(), 3);
+        assert_eq!(timeline[2].rank(), Some(HandRank::FullHouse));
+        assert_eq!(timeline[3].rank(), Some(HandRank::FullHouse));
+        assert!(timeline[1].equity.equity() > timeline[0].equity.equity());
+        assert!(timeline[3].equity.equity() > 0.95);
+    }
+
+    #[test]
+    fn test_partial_board_stops_at_its_street() {
+        let hole: HoleCards = "AsKs".parse().unwrap();
+        let board: Board = "Qs Js 2h".parse().unwrap();
+        let timeline = progression()
+            .with_opponents(2)
+            .analyze(&hole, &board)
+            .unwrap();
+        assert_eq!(
+            timeline.last().map(|snapshot| snapshot.street),
+            Some(Street::Flop)
+        );
+
+        let clash: Board = "As Js 2h".parse().unwrap();
+        assert!(matches!(
+            progression().analyze(&hole, &clash),
+            Err(EquityError::DuplicateCard(_))
+        ));
+    }
+}
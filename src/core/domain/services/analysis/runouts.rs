@@ -0,0 +1,295 @@
+//! Sampling turn and river runouts with a chosen board texture, for training drills.
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_set::CardSet;
+use crate::core::domain::primitives::Street;
+use crate::core::domain::services::utils::card_combinations;
+use crate::core::ports::outbound::RandomSource;
+
+/// Ranks in each five-card straight, as bit masks over rank indices (0 is a deuce).
+const STRAIGHTS: [u16; 10] = [
+    0b1_0000_0000_1111, // wheel: A-2-3-4-5
+    0b0_0000_0001_1111,
+    0b0_0000_0011_1110,
+    0b0_0000_0111_1100,
+    0b0_0000_1111_1000,
+    0b0_0001_1111_0000,
+    0b0_0011_1110_0000,
+    0b0_0111_1100_0000,
+    0b0_1111_1000_0000,
+    0b1_1111_0000_0000,
+];
+
+/// A property the cards dealt after the current board must have.
+///
+/// Each constraint compares the board before the runout with the board after it, so a
+/// flop that already has three hearts does not count as completing a flush.
+#[derive(Clone, Copy, Debug)]
+pub enum RunoutConstraint {
+    /// The runout puts a third card of some suit on the board, making a flush possible.
+    CompletesFlush,
+    /// The runout puts three ranks of some straight on the board, making a straight
+    /// possible.
+    CompletesStraight,
+    /// The runout pairs the board, or pairs itself.
+    PairsBoard,
+    /// A runout card ranks above every card already on the board.
+    Overcard,
+    /// None of the other constraints hold: the runout changes little.
+    Blank,
+    /// Any test of the board before the runout and the cards it adds.
+    Custom(fn(&[Card], &[Card]) -> bool),
+}
+
+/// `RunoutConstraint` - Operations
+impl RunoutConstraint {
+    /// Returns whether dealing `runout` onto `board` satisfies this constraint.
+    #[must_use]
+    pub fn matches(self, board: &[Card], runout: &[Card]) -> bool {
+        let before = Texture::of(board.iter());
+        let after = Texture::of(board.iter().chain(runout));
+        match self {
+            Self::CompletesFlush => after.max_suit >= 3 && before.max_suit < 3,
+            Self::CompletesStraight => after.straight_possible() && !before.straight_possible(),
+            Self::PairsBoard => after.paired,
+            Self::Overcard => runout.iter().any(|card| card.rank() > before.high_rank),
+            Self::Blank => [
+                Self::CompletesFlush,
+                Self::CompletesStraight,
+                Self::PairsBoard,
+                Self::Overcard,
+            ]
+            .iter()
+            .all(|constraint| !constraint.matches(board, runout)),
+            Self::Custom(test) => test(board, runout),
+        }
+    }
+}
+
+/// Suit, rank, and pairing summary of a set of board cards.
+struct Texture {
+    max_suit: u8,
+    ranks: u16,
+    paired: bool,
+    high_rank: u8,
+}
+
+impl Texture {
+    fn of<'a>(cards: impl Iterator<Item = &'a Card>) -> Self {
+        let mut suits = [0u8; 4];
+        let mut texture = Self {
+            max_suit: 0,
+            ranks: 0,
+            paired: false,
+            high_rank: 0,
+        };
+        for card in cards {
+            let suit = &mut suits[usize::from(card.suit())];
+            *suit += 1;
+            texture.max_suit = texture.max_suit.max(*suit);
+            let rank = 1 << card.rank();
+            texture.paired |= texture.ranks & rank != 0;
+            texture.ranks |= rank;
+            texture.high_rank = texture.high_rank.max(card.rank());
+        }
+        texture
+    }
+
+    /// Whether three ranks of a single straight are present.
+    fn straight_possible(&self) -> bool {
+        STRAIGHTS
+            .iter()
+            .any(|straight| (straight & self.ranks).count_ones() >= 3)
+    }
+}
+
+/// Samples runouts of a flop or turn that satisfy every configured constraint, for
+/// scenario-based drills such as "play the turn when the flush gets there".
+///
+/// Runouts are dealt to the river from the cards not on the board and not dead, and
+/// every matching runout is equally likely.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::services::analysis::{RunoutConstraint, RunoutSampler};
+/// use riverrun::core::ports::outbound::FixedRandomSource;
+///
+/// let sampler = RunoutSampler::new("Ah 7h 2c 9s".parse().unwrap())
+///     .unwrap()
+///     .with_constraint(RunoutConstraint::CompletesFlush);
+/// // Eleven hearts remain, and the river must be one of them
+/// assert_eq!(sampler.count(), 11);
+///
+/// let board = sampler.sample(&mut FixedRandomSource::zero()).unwrap();
+/// assert_eq!(board.len(), 5);
+/// assert!(board.cards()[4].to_string().ends_with('h'));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RunoutSampler {
+    board: Board,
+    dead: CardSet,
+    constraints: Vec<RunoutConstraint>,
+}
+
+/// `RunoutSampler` - Constructors
+impl RunoutSampler {
+    /// Sampler for runouts of `board`, without constraints.
+    ///
+    /// Returns `None` unless `board` is a flop or a turn.
+    #[must_use]
+    pub fn new(board: Board) -> Option<Self> {
+        matches!(board.street(), Street::Flop | Street::Turn).then(|| Self {
+            board,
+            dead: CardSet::empty(),
+            constraints: Vec::new(),
+        })
+    }
+
+    /// Excludes `cards`, e.g. known hole cards, from the runouts.
+    #[must_use]
+    pub fn with_dead_cards(mut self, cards: &[Card]) -> Self {
+        for &card in cards {
+            self.dead.insert(card);
+        }
+        self
+    }
+
+    /// Requires every runout to satisfy `constraint` as well as any added before.
+    #[must_use]
+    pub fn with_constraint(mut self, constraint: RunoutConstraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+}
+
+/// `RunoutSampler` - Accessors
+impl RunoutSampler {
+    /// The board being run out.
+    #[must_use]
+    pub const fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Cards that can still be dealt: not on the board and not dead.
+    #[must_use]
+    pub fn remaining_deck(&self) -> Vec<Card> {
+        Card::all_cards()
+            .filter(|&card| !self.dead.contains(card) && !self.board.cards().contains(&card))
+            .collect()
+    }
+}
+
+/// `RunoutSampler` - Operations
+impl RunoutSampler {
+    /// Every runout to the river satisfying the constraints, each in dealing order.
+    #[must_use]
+    pub fn runouts(&self) -> Vec<Vec<Card>> {
+        let deck = self.remaining_deck();
+        card_combinations(&deck, 5 - self.board.len())
+            .filter(|runout| {
+                self.constraints
+                    .iter()
+                    .all(|constraint| constraint.matches(self.board.cards(), runout))
+            })
+            .collect()
+    }
+
+    /// Number of runouts satisfying the constraints.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.runouts().len()
+    }
+
+    /// A river board completed by a matching runout chosen uniformly with `source`, or
+    /// `None` if no runout matches.
+    pub fn sample(&self, source: &mut dyn RandomSource) -> Option<Board> {
+        self.sample_n(1, source).pop()
+    }
+
+    /// `n` river boards completed by matching runouts, chosen independently with
+    /// `source`; empty if no runout matches.
+    pub fn sample_n(&self, n: usize, source: &mut dyn RandomSource) -> Vec<Board> {
+        let runouts = self.runouts();
+        if runouts.is_empty() {
+            return Vec::new();
+        }
+        (0..n)
+            .filter_map(|_| {
+                let runout = &runouts[source.random_index(runouts.len())];
+                Board::partial([self.board.cards(), runout].concat())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ports::outbound::RandRandomSource;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn cards(text: &str) -> Vec<Card> {
+        text.split_whitespace()
+            .map(|card| Card::from_string(card).unwrap())
+            .collect()
+    }
+
+    fn sampler(board: &str) -> RunoutSampler {
+        RunoutSampler::new(Board::partial(cards(board)).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_constraints_compare_before_and_after() {
+        let flop = cards("Ah 7h 2c");
+        assert!(RunoutConstraint::CompletesFlush.matches(&flop, &cards("Kh 3d")));
+        assert!(!RunoutConstraint::CompletesFlush.matches(&cards("Ah 7h 2h"), &cards("Kh 3d")));
+        assert!(RunoutConstraint::CompletesStraight.matches(&flop, &cards("5d Kc")));
+        assert!(!RunoutConstraint::CompletesStraight.matches(&flop, &cards("9d Kc")));
+        assert!(RunoutConstraint::PairsBoard.matches(&flop, &cards("7d Kc")));
+        assert!(RunoutConstraint::PairsBoard.matches(&flop, &cards("Kd Kc")));
+        assert!(RunoutConstraint::Overcard.matches(&cards("Qs 7h 2c"), &cards("Kd 3c")));
+        assert!(RunoutConstraint::Blank.matches(&cards("Qs 7h 2c"), &cards("Jd 3s")));
+        assert!(!RunoutConstraint::Blank.matches(&flop, &cards("Kh 3d")));
+    }
+
+    #[test]
+    fn test_runouts_respect_dead_cards_and_constraints() {
+        let sampler = sampler("Ah 7h 2c");
+        assert_eq!(sampler.count(), 1_176);
+
+        let pairing = sampler
+            .with_dead_cards(&cards("Ad As"))
+            .with_constraint(RunoutConstraint::PairsBoard);
+        assert!(pairing.runouts().iter().all(|runout| {
+            RunoutConstraint::PairsBoard.matches(&cards("Ah 7h 2c"), runout)
+                && !runout.contains(&cards("Ad")[0])
+        }));
+        assert!(pairing.count() < 1_176);
+    }
+
+    #[test]
+    fn test_sample_completes_the_board() {
+        let sampler = sampler("Ks Qd 4c").with_constraint(RunoutConstraint::Custom(|_, runout| {
+            runout.iter().all(|card| card.rank() < 4)
+        }));
+        let mut source = RandRandomSource::new(StdRng::seed_from_u64(7));
+        let boards = sampler.sample_n(20, &mut source);
+        assert_eq!(boards.len(), 20);
+        assert!(
+            boards.iter().all(|board| board.is_complete()
+                && board.cards()[3..].iter().all(|card| card.rank() < 4))
+        );
+
+        let impossible = sampler.with_constraint(RunoutConstraint::Overcard);
+        assert!(impossible.sample(&mut source).is_none());
+    }
+
+    #[test]
+    fn test_new_needs_flop_or_turn() {
+        assert!(RunoutSampler::new(Board::new()).is_none());
+        assert!(RunoutSampler::new(Board::partial(cards("Ks Qd 4c 7h 2s")).unwrap()).is_none());
+    }
+}
@@ -0,0 +1,240 @@
+//! Street-by-street equity of known hands, reusing the evaluations of earlier streets.
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_set::CardSet;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::ports::inbound::{EquityError, EquityResult, HandEvaluator};
+
+use super::super::utils::card_combinations;
+use super::{check_known, Tally};
+
+/// Exact equity of known hands that can follow the board as it is dealt, for UIs
+/// tracking an all-in street by street.
+///
+/// Building the context evaluates every runout of the board once for every player.
+/// Each `advance` keeps only the runouts containing the new card, so the turn and
+/// river need no further evaluation. From the flop there are at most 1,081 runouts;
+/// preflop there are over a million, so build contexts on the flop or later.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::board::Board;
+/// use riverrun::core::domain::entities::hole_cards::HoleCards;
+/// use riverrun::core::domain::services::equity::EquityContext;
+/// use riverrun::core::domain::services::CactusKevEvaluator;
+///
+/// let players: Vec<HoleCards> = ["AsKs", "QhQd"].iter().map(|hand| hand.parse().unwrap()).collect();
+/// let flop: Board = "Qs 7s 2c".parse().unwrap();
+///
+/// let mut context = EquityContext::new(&CactusKevEvaluator::new(), &players, &flop, &[]).unwrap();
+/// assert_eq!(context.runouts(), 990);
+/// let on_flop = context.equities();
+/// assert!(on_flop[1].equity() > on_flop[0].equity());
+///
+/// // The nut flush comes in on the turn
+/// context.advance("9s".parse().unwrap()).unwrap();
+/// assert_eq!(context.runouts(), 44);
+/// assert!(context.equities()[0].equity() > 0.7);
+/// ```
+#[derive(Clone, Debug)]
+pub struct EquityContext {
+    players: Vec<HoleCards>,
+    board: Board,
+    known: CardSet,
+    /// Cards still to come of every runout, `5 - board.len()` per runout.
+    runout_cards: Vec<Card>,
+    /// Strength of every player on every runout, `players.len()` per runout.
+    strengths: Vec<u16>,
+}
+
+/// `EquityContext` - Constructors
+impl EquityContext {
+    /// Evaluates every runout of `board` for `players` with `evaluator`, with `dead`
+    /// cards removed from the runouts.
+    ///
+    /// # Errors
+    /// Returns `EquityError::UnsupportedConfiguration` without players or with more than
+    /// five board cards, and `EquityError::DuplicateCard` if any card appears twice.
+    pub fn new<E: HandEvaluator + ?Sized>(
+        evaluator: &E,
+        players: &[HoleCards],
+        board: &Board,
+        dead: &[Card],
+    ) -> Result<Self, EquityError> {
+        let known = check_known(players, board, dead)?;
+        let remaining: Vec<Card> = Card::all_cards()
+            .filter(|&card| !known.contains(card))
+            .collect();
+
+        let mut context = Self {
+            players: players.to_vec(),
+            board: board.clone(),
+            known,
+            runout_cards: Vec::new(),
+            strengths: Vec::new(),
+        };
+        let mut full_board = board.cards().to_vec();
+        for runout in card_combinations(&remaining, 5 - board.len()) {
+            full_board.truncate(board.len());
+            full_board.extend_from_slice(&runout);
+            let Ok(full_board) = <[Card; 5]>::try_from(full_board.as_slice()) else {
+                continue;
+            };
+            context.runout_cards.extend_from_slice(&runout);
+            context.strengths.extend(players.iter().map(|player| {
+                evaluator.evaluate_7cards_fast(&player.combine_with_board(full_board))
+            }));
+        }
+        Ok(context)
+    }
+}
+
+/// `EquityContext` - Accessors
+impl EquityContext {
+    /// The players' hole cards.
+    #[must_use]
+    pub fn players(&self) -> &[HoleCards] {
+        &self.players
+    }
+
+    /// The board dealt so far.
+    #[must_use]
+    pub const fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Number of runouts still possible.
+    #[must_use]
+    pub const fn runouts(&self) -> usize {
+        self.strengths.len() / self.players.len()
+    }
+}
+
+/// `EquityContext` - Operations
+impl EquityContext {
+    /// Exact equity of every player over the runouts still possible, in player order.
+    ///
+    /// Tied players split the pot between themselves only, so the equities sum to one.
+    #[must_use]
+    pub fn equities(&self) -> Vec<EquityResult> {
+        let mut tallies = vec![Tally::default(); self.players.len()];
+        for strengths in self.strengths.chunks_exact(self.players.len()) {
+            let best = strengths.iter().copied().min().unwrap_or_default();
+            let winners = strengths
+                .iter()
+                .filter(|&&strength| strength == best)
+                .count();
+            for (tally, &strength) in tallies.iter_mut().zip(strengths) {
+                tally.record(strength == best, winners);
+            }
+        }
+        tallies.iter().map(Tally::result).collect()
+    }
+
+    /// Deals `card` onto the board, keeping only the runouts that contain it.
+    ///
+    /// # Errors
+    /// Returns `EquityError::UnsupportedConfiguration` if the board is complete, and
+    /// `EquityError::DuplicateCard` if `card` is already on the board, held by a player,
+    /// or dead.
+    pub fn advance(&mut self, card: Card) -> Result<(), EquityError> {
+        let missing = 5 - self.board.len();
+        if missing == 0 {
+            return Err(EquityError::UnsupportedConfiguration(
+                "a board holds at most five cards",
+            ));
+        }
+        if !self.known.insert(card) {
+            return Err(EquityError::DuplicateCard(card));
+        }
+        let mut cards = self.board.cards().to_vec();
+        cards.push(card);
+        self.board = Board::partial(cards).ok_or(EquityError::UnsupportedConfiguration(
+            "a board holds at most five cards",
+        ))?;
+
+        let mut runout_cards = Vec::with_capacity(self.runout_cards.len() / missing);
+        let mut strengths = Vec::with_capacity(self.strengths.len() / missing);
+        for (runout, player_strengths) in self
+            .runout_cards
+            .chunks_exact(missing)
+            .zip(self.strengths.chunks_exact(self.players.len()))
+        {
+            if runout.contains(&card) {
+                runout_cards.extend(runout.iter().filter(|&&other| other != card));
+                strengths.extend_from_slice(player_strengths);
+            }
+        }
+        self.runout_cards = runout_cards;
+        self.strengths = strengths;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::services::equity::ExhaustiveEquityCalculator;
+    use crate::core::domain::services::evaluation::CactusKevEvaluator;
+
+    fn players(hands: &[&str]) -> Vec<HoleCards> {
+        hands.iter().map(|hand| hand.parse().unwrap()).collect()
+    }
+
+    fn card(text: &str) -> Card {
+        Card::from_string(text).unwrap()
+    }
+
+    fn assert_matches_fresh(context: &EquityContext) {
+        let calculator = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let fresh = calculator
+            .calculate_all(context.players(), context.board(), &[])
+            .unwrap();
+        for (advanced, fresh) in context.equities().iter().zip(&fresh) {
+            assert!((advanced.equity() - fresh.equity()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_advance_matches_fresh_calculation() {
+        let players = players(&["AhKd", "8c8s", "JhTh"]);
+        let flop: Board = "8h 9h 2d".parse().unwrap();
+        let mut context =
+            EquityContext::new(&CactusKevEvaluator::new(), &players, &flop, &[]).unwrap();
+        assert_matches_fresh(&context);
+
+        context.advance(card("Kc")).unwrap();
+        assert_eq!(context.board().len(), 4);
+        assert_matches_fresh(&context);
+
+        context.advance(card("Qh")).unwrap();
+        assert_eq!(context.runouts(), 1);
+        let equities = context.equities();
+        assert!((equities[2].equity() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_advance_rejects_known_cards_and_full_boards() {
+        let players = players(&["AhKd", "8c8s"]);
+        let turn: Board = "8h 9h 2d 3c".parse().unwrap();
+        let mut context =
+            EquityContext::new(&CactusKevEvaluator::new(), &players, &turn, &[card("4s")]).unwrap();
+        assert_eq!(context.runouts(), 43);
+
+        assert_eq!(
+            context.advance(card("Ah")),
+            Err(EquityError::DuplicateCard(card("Ah")))
+        );
+        assert_eq!(
+            context.advance(card("4s")),
+            Err(EquityError::DuplicateCard(card("4s")))
+        );
+        context.advance(card("5s")).unwrap();
+        assert!(matches!(
+            context.advance(card("6s")),
+            Err(EquityError::UnsupportedConfiguration(_))
+        ));
+    }
+}
@@ -7,9 +7,22 @@
 
 use crate::core::domain::entities::board::Board;
 use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_set::CardSet;
 use crate::core::domain::entities::deck::Deck;
+use crate::core::domain::entities::hand_range::HandRange;
 use crate::core::domain::entities::hole_cards::HoleCards;
-use crate::core::ports::inbound::{EquityCalculator, EquityResult, HandEvaluator};
+use crate::core::ports::inbound::{
+    CancellationToken, EquityCalculator, EquityDistribution, EquityError, EquityProgress,
+    EquityResult, HandEvaluator,
+};
+use crate::telemetry;
+
+use super::super::utils::{binomial, card_combinations};
+use super::{check_cards, check_deal, check_known, Tally};
+use super::progress::ProgressReporter;
+
+/// Most opponents enumerated exactly once the flop is out.
+pub const MAX_EXACT_OPPONENTS: usize = 3;
 
 pub struct ExhaustiveEquityCalculator<E: HandEvaluator> {
     evaluator: E,
@@ -41,33 +54,56 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
         dead_cards.extend_from_slice(board.cards());
         Deck::excluding(&dead_cards)
     }
-}
 
-impl<E: HandEvaluator> EquityCalculator for ExhaustiveEquityCalculator<E> {
     /// Selects and runs the appropriate exhaustive equity calculation for the given board stage.
     ///
     /// The function builds the remaining deck from the hero's hole cards and the board, then dispatches
     /// to the river/turn/flop/preflop calculation implementation depending on how many board cards
-    /// are present. If the board length is an unsupported intermediate size, returns zeroed counts.
+    /// are present. Partial boards of one or two cards are completed by enumerating every runout.
     ///
     /// # Returns
     ///
-    /// An `EquityResult` containing aggregated win/tie/loss counts for the provided `num_opponents`.
+    /// An `EquityResult` containing aggregated win/tie/loss counts for the provided `num_opponents`,
+    /// or `EquityError::Infeasible` for more than one opponent before the flop or more than
+    /// `MAX_EXACT_OPPONENTS` after it.
+    fn run(
+        &self,
+        hole_cards: HoleCards,
+        board: &Board,
+        num_opponents: usize,
+        progress: &mut ProgressReporter<'_>,
+    ) -> Result<EquityResult, EquityError> {
+        let _span = telemetry::equity_span("exhaustive", num_opponents, board.len());
+        check_cards(hole_cards, board)?;
+        let remaining = Self::remaining_deck(hole_cards, board);
+        check_deal(remaining.remaining(), board.len(), num_opponents)?;
+        if num_opponents > MAX_EXACT_OPPONENTS || (board.len() < 3 && num_opponents > 1) {
+            return Err(EquityError::Infeasible {
+                num_opponents,
+                board_cards: board.len(),
+            });
+        }
+
+        let result = match board.len() {
+            5 => self.calculate_river(hole_cards, board, &remaining, num_opponents, progress),
+            4 => self.calculate_turn(hole_cards, board, &remaining, num_opponents, progress),
+            3 => self.calculate_flop(hole_cards, board, &remaining, num_opponents, progress),
+            0 => self.calculate_preflop(hole_cards, &remaining, num_opponents, progress),
+            _ => self.calculate_partial(hole_cards, board, &remaining, num_opponents, progress),
+        };
+        telemetry::equity_samples("exhaustive", result.samples());
+        Ok(result)
+    }
+}
+
+impl<E: HandEvaluator> EquityCalculator for ExhaustiveEquityCalculator<E> {
     fn calculate(
         &self,
         hole_cards: &HoleCards,
         board: &Board,
         num_opponents: usize,
-    ) -> EquityResult {
-        let remaining = Self::remaining_deck(*hole_cards, board);
-
-        match board.len() {
-            5 => self.calculate_river(*hole_cards, board, &remaining, num_opponents),
-            4 => self.calculate_turn(*hole_cards, board, &remaining, num_opponents),
-            3 => self.calculate_flop(*hole_cards, board, &remaining, num_opponents),
-            0 => self.calculate_preflop(*hole_cards, &remaining, num_opponents),
-            _ => EquityResult::from_counts(0, 0, 0, num_opponents),
-        }
+    ) -> Result<EquityResult, EquityError> {
+        self.run(*hole_cards, board, num_opponents, &mut ProgressReporter::silent())
     }
 
     /// Calculates exact equity using full enumeration, ignoring any requested sample count.
@@ -82,10 +118,301 @@ impl<E: HandEvaluator> EquityCalculator for ExhaustiveEquityCalculator<E> {
         board: &Board,
         num_opponents: usize,
         _samples: u32,
-    ) -> EquityResult {
+    ) -> Result<EquityResult, EquityError> {
         // Exhaustive calculator ignores sample count - always does full enumeration
         self.calculate(hole_cards, board, num_opponents)
     }
+
+    /// Runs the exhaustive enumeration, reporting progress once per outer runout iteration.
+    ///
+    /// On cancellation the counts accumulated so far are returned.
+    fn calculate_with_progress(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+        num_opponents: usize,
+        progress: &mut dyn FnMut(EquityProgress),
+        cancel: &CancellationToken,
+    ) -> Result<EquityResult, EquityError> {
+        let mut reporter = ProgressReporter::new(progress, cancel);
+        self.run(*hole_cards, board, num_opponents, &mut reporter)
+    }
+}
+
+/// `ExhaustiveEquityCalculator` - Runout Breakdown
+impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
+    /// Computes exact equity for every possible turn card, in deck order.
+    ///
+    /// Each entry pairs an unseen card with hero's equity once it lands on the turn, for
+    /// rendering which cards help or hurt. Empty unless `flop` holds exactly three cards and
+    /// the calculation is feasible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::board::Board;
+    /// use riverrun::core::domain::entities::card::{Card, Suit};
+    /// use riverrun::core::domain::entities::hole_cards::HoleCards;
+    /// use riverrun::core::domain::services::{CactusKevEvaluator, ExhaustiveEquityCalculator};
+    ///
+    /// let cards = |text: &str| -> Vec<Card> {
+    ///     text.split_whitespace().map(|c| c.parse().unwrap()).collect()
+    /// };
+    /// let hole = cards("As Ks");
+    /// let flop = Board::with_cards(cards("Qs 7s 2c")).unwrap();
+    ///
+    /// let calculator = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+    /// let by_turn = calculator.equity_by_turn_card(&HoleCards::new(hole[0], hole[1]), &flop, 1);
+    /// assert_eq!(by_turn.len(), 47);
+    /// // Every spade completes the nut flush
+    /// let spades = by_turn.iter().filter(|(card, _)| card.suit_enum() == Suit::Spades);
+    /// assert!(spades.clone().all(|(_, result)| result.equity() > 0.9));
+    /// assert_eq!(spades.count(), 9);
+    /// ```
+    #[must_use]
+    pub fn equity_by_turn_card(
+        &self,
+        hole_cards: &HoleCards,
+        flop: &Board,
+        num_opponents: usize,
+    ) -> Vec<(Card, EquityResult)> {
+        self.equity_by_next_card(*hole_cards, flop, num_opponents, Board::deal_turn)
+    }
+
+    /// Computes exact equity for every possible river card, in deck order.
+    ///
+    /// Empty unless `turn_board` holds exactly four cards and the calculation is feasible.
+    #[must_use]
+    pub fn equity_by_river_card(
+        &self,
+        hole_cards: &HoleCards,
+        turn_board: &Board,
+        num_opponents: usize,
+    ) -> Vec<(Card, EquityResult)> {
+        self.equity_by_next_card(*hole_cards, turn_board, num_opponents, Board::deal_river)
+    }
+
+    /// Runs the exhaustive calculation once per unseen card, after `deal` adds it to `board`.
+    fn equity_by_next_card(
+        &self,
+        hole_cards: HoleCards,
+        board: &Board,
+        num_opponents: usize,
+        deal: fn(&mut Board, Card) -> bool,
+    ) -> Vec<(Card, EquityResult)> {
+        Self::remaining_deck(hole_cards, board)
+            .cards()
+            .iter()
+            .filter_map(|&card| {
+                let mut next = board.clone();
+                if !deal(&mut next, card) {
+                    return None;
+                }
+                let result = self
+                    .run(hole_cards, &next, num_opponents, &mut ProgressReporter::silent())
+                    .ok()?;
+                Some((card, result))
+            })
+            .collect()
+    }
+}
+
+/// `ExhaustiveEquityCalculator` - Known Hands
+impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
+    /// Computes exact equity of `hero` against opponents holding exactly `villains`.
+    ///
+    /// Only the board runouts are enumerated, so this answers all-in matchups such as
+    /// aces against kings directly. With several villains hero wins when ahead of all of
+    /// them and ties when level with the best; as with `calculate`, tie equity is split
+    /// evenly across every player. Returns a zeroed result if `villains` is empty or any
+    /// card appears twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::board::Board;
+    /// use riverrun::core::domain::entities::card::Card;
+    /// use riverrun::core::domain::entities::hole_cards::HoleCards;
+    /// use riverrun::core::domain::services::{CactusKevEvaluator, ExhaustiveEquityCalculator};
+    ///
+    /// let hand = |text: &str| -> HoleCards {
+    ///     let cards: Vec<Card> = text.split_whitespace().map(|c| c.parse().unwrap()).collect();
+    ///     HoleCards::new(cards[0], cards[1])
+    /// };
+    /// let flop = Board::with_cards(
+    ///     "Qs 7s 2c".split_whitespace().map(|c| c.parse().unwrap()).collect(),
+    /// )
+    /// .unwrap();
+    ///
+    /// let calculator = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+    /// let result = calculator.calculate_vs_hands(&hand("As Ks"), &[hand("Qh Qd")], &flop);
+    /// // Every turn and river pair from the 45 unseen cards
+    /// assert_eq!(result.samples(), 990);
+    /// assert!(result.equity() < 0.5);
+    /// ```
+    #[must_use]
+    pub fn calculate_vs_hands(
+        &self,
+        hero: &HoleCards,
+        villains: &[HoleCards],
+        board: &Board,
+    ) -> EquityResult {
+        let num_opponents = villains.len();
+        let mut dead_cards = board.cards().to_vec();
+        dead_cards.extend(hero.cards());
+        dead_cards.extend(villains.iter().flat_map(HoleCards::cards));
+
+        let mut seen = CardSet::empty();
+        let distinct = dead_cards.iter().all(|&card| seen.insert(card));
+        if villains.is_empty() || !distinct {
+            return EquityResult::from_counts(0, 0, 0, num_opponents);
+        }
+
+        let remaining = Deck::excluding(&dead_cards);
+        let mut runout = board.cards().to_vec();
+        let mut wins = 0u64;
+        let mut ties = 0u64;
+        let mut losses = 0u64;
+        let missing = 5usize.saturating_sub(board.cards().len());
+        for cards in card_combinations(remaining.cards(), missing) {
+            runout.truncate(board.cards().len());
+            runout.extend_from_slice(&cards);
+            let Ok(full_board) = <[Card; 5]>::try_from(runout.as_slice()) else {
+                continue;
+            };
+            let hero_strength = self
+                .evaluator
+                .evaluate_7cards_fast(&hero.combine_with_board(full_board));
+            let best_villain = villains
+                .iter()
+                .map(|villain| {
+                    self.evaluator
+                        .evaluate_7cards_fast(&villain.combine_with_board(full_board))
+                })
+                .min();
+
+            match best_villain.map_or(std::cmp::Ordering::Less, |best| hero_strength.cmp(&best)) {
+                std::cmp::Ordering::Less => wins += 1,
+                std::cmp::Ordering::Equal => ties += 1,
+                std::cmp::Ordering::Greater => losses += 1,
+            }
+        }
+
+        EquityResult::from_counts(wins, ties, losses, num_opponents)
+    }
+
+    /// Computes exact equity of every one of `players` at once, with `dead` cards
+    /// removed from the runouts.
+    ///
+    /// Each runout is evaluated once for all players, rather than once per player as
+    /// calling `calculate_vs_hands` for each would. Tied players split the pot between
+    /// themselves only, so the equities sum to one.
+    ///
+    /// # Errors
+    /// Returns `EquityError::UnsupportedConfiguration` without players or with more than
+    /// five board cards, and `EquityError::DuplicateCard` if any card appears twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::board::Board;
+    /// use riverrun::core::domain::entities::hole_cards::HoleCards;
+    /// use riverrun::core::domain::services::{CactusKevEvaluator, ExhaustiveEquityCalculator};
+    ///
+    /// let players: Vec<HoleCards> = ["AsKs", "QhQd", "7c6c"]
+    ///     .iter()
+    ///     .map(|hand| hand.parse().unwrap())
+    ///     .collect();
+    /// let flop: Board = "Qs 7s 2c".parse().unwrap();
+    ///
+    /// let calculator = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+    /// let results = calculator.calculate_all(&players, &flop, &[]).unwrap();
+    /// let total: f64 = results.iter().map(|result| result.equity()).sum();
+    /// assert!((total - 1.0).abs() < 1e-9);
+    /// assert!(results[1].equity() > results[0].equity());
+    /// ```
+    pub fn calculate_all(
+        &self,
+        players: &[HoleCards],
+        board: &Board,
+        dead: &[Card],
+    ) -> Result<Vec<EquityResult>, EquityError> {
+        let known = check_known(players, board, dead)?;
+        let missing = 5 - board.len();
+        let remaining = Deck::subset(|card| !known.contains(card));
+        let mut runout = board.cards().to_vec();
+        let mut tallies = vec![Tally::default(); players.len()];
+        let mut strengths = vec![0u16; players.len()];
+        for cards in card_combinations(remaining.cards(), missing) {
+            runout.truncate(board.len());
+            runout.extend_from_slice(&cards);
+            let Ok(full_board) = <[Card; 5]>::try_from(runout.as_slice()) else {
+                continue;
+            };
+            for (strength, player) in strengths.iter_mut().zip(players) {
+                *strength = self
+                    .evaluator
+                    .evaluate_7cards_fast(&player.combine_with_board(full_board));
+            }
+            let best = strengths.iter().copied().min().unwrap_or_default();
+            let winners = strengths.iter().filter(|&&strength| strength == best).count();
+            for (tally, &strength) in tallies.iter_mut().zip(&strengths) {
+                tally.record(strength == best, winners);
+            }
+        }
+
+        Ok(tallies.iter().map(Tally::result).collect())
+    }
+
+    /// Buckets hero's equity against every combination of `range` into `buckets`
+    /// equal-width buckets.
+    ///
+    /// Each combination not blocked by hero or the board is evaluated exactly with
+    /// `calculate_vs_hands` and added with its range weight, so the result shows
+    /// whether hero is crushing or crushed by parts of the range rather than just the
+    /// average. Every runout is enumerated once per combination, which is quick from
+    /// the flop on but slow preflop against a wide range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::board::Board;
+    /// use riverrun::core::domain::entities::card::Card;
+    /// use riverrun::core::domain::entities::hand_range::HandRange;
+    /// use riverrun::core::domain::entities::hole_cards::HoleCards;
+    /// use riverrun::core::domain::services::{CactusKevEvaluator, ExhaustiveEquityCalculator};
+    ///
+    /// let hero = HoleCards::new("3s".parse().unwrap(), "3d".parse().unwrap());
+    /// let board = Board::with_cards(
+    ///     "Qd 7s 2c 3h".split_whitespace().map(|c| c.parse().unwrap()).collect(),
+    /// )
+    /// .unwrap();
+    /// let range: HandRange = "QQ, AKs".parse().unwrap();
+    ///
+    /// let calculator = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+    /// let distribution = calculator.equity_distribution(&hero, &range, &board, 4);
+    /// // Far ahead of ace-king, far behind the set of queens
+    /// assert_eq!(distribution.combos(), 3 + 4);
+    /// assert!((distribution.bucket_frequency(3) - 4.0 / 7.0).abs() < 1e-12);
+    /// assert!((distribution.bucket_frequency(0) - 3.0 / 7.0).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn equity_distribution(
+        &self,
+        hero: &HoleCards,
+        range: &HandRange,
+        board: &Board,
+        buckets: usize,
+    ) -> EquityDistribution {
+        let mut dead_cards = board.cards().to_vec();
+        dead_cards.extend(hero.cards());
+        let mut distribution = EquityDistribution::new(buckets);
+        for (villain, weight) in range.without(&dead_cards).combos() {
+            let result = self.calculate_vs_hands(hero, &[villain], board);
+            distribution.record(result.equity(), weight);
+        }
+        distribution
+    }
 }
 
 /// `ExhaustiveEquityCalculator` - Calculation Methods
@@ -97,6 +424,7 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
         board: &Board,
         remaining: &Deck,
         num_opponents: usize,
+        progress: &mut ProgressReporter<'_>,
     ) -> EquityResult {
         let board_array = board.as_array().unwrap();
         let hero_cards = hole_cards.combine_with_board(board_array);
@@ -109,7 +437,11 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
         let cards = remaining.cards();
 
         if num_opponents == 1 {
+            progress.start(cards.len() as u64);
             for i in 0..cards.len() {
+                if progress.is_cancelled() {
+                    break;
+                }
                 for j in (i + 1)..cards.len() {
                     let opp_hole = HoleCards::new(cards[i], cards[j]);
                     let opp_cards = opp_hole.combine_with_board(board_array);
@@ -121,10 +453,15 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
                         std::cmp::Ordering::Greater => losses += 1,
                     }
                 }
+                progress.advance(1);
             }
         } else {
             // Multi-way exhaustive is expensive but possible for small opponent counts
-            self.enumerate_multiway(hole_cards, &board_array, remaining, num_opponents, &mut wins, &mut ties, &mut losses);
+            progress.start(1);
+            if !progress.is_cancelled() {
+                self.enumerate_multiway(hole_cards, &board_array, remaining, num_opponents, &mut wins, &mut ties, &mut losses);
+                progress.advance(1);
+            }
         }
 
         EquityResult::from_counts(wins, ties, losses, num_opponents)
@@ -142,6 +479,7 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
         board: &Board,
         remaining: &Deck,
         num_opponents: usize,
+        progress: &mut ProgressReporter<'_>,
     ) -> EquityResult {
         let board_cards = board.cards();
         let cards = remaining.cards();
@@ -149,8 +487,12 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
         let mut ties = 0u64;
         let mut losses = 0u64;
 
+        progress.start(cards.len() as u64);
         if num_opponents == 1 {
             for (river_idx, &river_card) in cards.iter().enumerate() {
+                if progress.is_cancelled() {
+                    break;
+                }
                 let full_board = [
                     board_cards[0],
                     board_cards[1],
@@ -182,10 +524,14 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
                         }
                     }
                 }
+                progress.advance(1);
             }
         } else {
             // For multi-way on turn, enumerate each river then multiway
             for (river_idx, &river_card) in cards.iter().enumerate() {
+                if progress.is_cancelled() {
+                    break;
+                }
                 let full_board = [
                     board_cards[0],
                     board_cards[1],
@@ -202,6 +548,7 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
                 let river_deck = Deck::from_cards(remaining_after_river);
 
                 self.enumerate_multiway(hole_cards, &full_board, &river_deck, num_opponents, &mut wins, &mut ties, &mut losses);
+                progress.advance(1);
             }
         }
 
@@ -233,6 +580,7 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
         board: &Board,
         remaining: &Deck,
         num_opponents: usize,
+        progress: &mut ProgressReporter<'_>,
     ) -> EquityResult {
         let board_cards = board.cards();
         let cards = remaining.cards();
@@ -240,8 +588,12 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
         let mut ties = 0u64;
         let mut losses = 0u64;
 
+        progress.start(cards.len() as u64);
         if num_opponents == 1 {
             for turn_idx in 0..cards.len() {
+                if progress.is_cancelled() {
+                    break;
+                }
                 for river_idx in (turn_idx + 1)..cards.len() {
                     let full_board = [
                         board_cards[0],
@@ -275,10 +627,14 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
                         }
                     }
                 }
+                progress.advance(1);
             }
         } else {
             // Multi-way flop enumeration - very expensive
             for turn_idx in 0..cards.len() {
+                if progress.is_cancelled() {
+                    break;
+                }
                 for river_idx in (turn_idx + 1)..cards.len() {
                     let full_board = [
                         board_cards[0],
@@ -297,6 +653,7 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
 
                     self.enumerate_multiway(hole_cards, &full_board, &runout_deck, num_opponents, &mut wins, &mut ties, &mut losses);
                 }
+                progress.advance(1);
             }
         }
 
@@ -311,6 +668,9 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
     /// For `num_opponents > 1` exhaustive enumeration is computationally infeasible; the method
     /// returns an `EquityResult` with zeroed counts in that case.
     ///
+    /// With the `parallel` feature, boards are enumerated on every available core, each
+    /// taking the next unclaimed two-card board prefix until none are left.
+    ///
     /// # Parameters
     ///
     /// - `num_opponents`: number of opponents at the table; only `1` is supported for exhaustive calculation.
@@ -324,53 +684,211 @@ impl<E: HandEvaluator> ExhaustiveEquityCalculator<E> {
         hole_cards: HoleCards,
         remaining: &Deck,
         num_opponents: usize,
+        progress: &mut ProgressReporter<'_>,
     ) -> EquityResult {
+        if num_opponents != 1 {
+            // Multi-way preflop exhaustive is computationally infeasible; `run` rejects it
+            return EquityResult::from_counts(0, 0, 0, num_opponents);
+        }
+
+        // Enumerate all boards and opponent hands, reporting once per two-card board prefix
         let cards = remaining.cards();
+        let prefixes: Vec<(usize, usize)> = (0..cards.len())
+            .flat_map(|b0| ((b0 + 1)..cards.len()).map(move |b1| (b0, b1)))
+            .collect();
+        progress.start(prefixes.len() as u64);
+        let [wins, ties, losses] = self.enumerate_prefixes(hole_cards, cards, &prefixes, progress);
+
+        EquityResult::from_counts(wins, ties, losses, num_opponents)
+    }
+
+    /// Win, tie, and loss counts over every board starting with each of `prefixes`,
+    /// advancing `progress` once per prefix and stopping early when it is cancelled.
+    #[cfg(not(feature = "parallel"))]
+    fn enumerate_prefixes(
+        &self,
+        hole_cards: HoleCards,
+        cards: &[Card],
+        prefixes: &[(usize, usize)],
+        progress: &mut ProgressReporter<'_>,
+    ) -> [u64; 3] {
+        let mut counts = [0; 3];
+        for &prefix in prefixes {
+            if progress.is_cancelled() {
+                break;
+            }
+            let found = self.enumerate_prefix(hole_cards, cards, prefix);
+            for (count, found) in counts.iter_mut().zip(found) {
+                *count += found;
+            }
+            progress.advance(1);
+        }
+        counts
+    }
+
+    /// Win, tie, and loss counts over every board starting with each of `prefixes`,
+    /// advancing `progress` once per prefix and stopping early when it is cancelled.
+    ///
+    /// Each thread keeps its own counts, merged once every prefix is claimed. Progress
+    /// is reported from the calling thread, so the callback need not be `Send`.
+    #[cfg(feature = "parallel")]
+    fn enumerate_prefixes(
+        &self,
+        hole_cards: HoleCards,
+        cards: &[Card],
+        prefixes: &[(usize, usize)],
+        progress: &mut ProgressReporter<'_>,
+    ) -> [u64; 3] {
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+        use std::sync::mpsc;
+
+        let threads = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .min(prefixes.len())
+            .max(1);
+        let next = AtomicUsize::new(0);
+        let stop = AtomicBool::new(progress.is_cancelled());
+        let (done, finished) = mpsc::channel();
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|_| {
+                    let done = done.clone();
+                    let (next, stop) = (&next, &stop);
+                    scope.spawn(move || {
+                        let mut counts = [0; 3];
+                        while !stop.load(Ordering::Relaxed) {
+                            let Some(&prefix) = prefixes.get(next.fetch_add(1, Ordering::Relaxed))
+                            else {
+                                break;
+                            };
+                            let found = self.enumerate_prefix(hole_cards, cards, prefix);
+                            for (count, found) in counts.iter_mut().zip(found) {
+                                *count += found;
+                            }
+                            // The calling thread receives until every worker has finished
+                            let _ = done.send(());
+                        }
+                        counts
+                    })
+                })
+                .collect();
+            drop(done);
+
+            for () in finished {
+                progress.advance(1);
+                if progress.is_cancelled() {
+                    stop.store(true, Ordering::Relaxed);
+                }
+            }
+            let mut counts = [0; 3];
+            for handle in handles {
+                let found = handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+                for (count, found) in counts.iter_mut().zip(found) {
+                    *count += found;
+                }
+            }
+            counts
+        })
+    }
+
+    /// Win, tie, and loss counts over every board whose first two cards are `cards[b0]`
+    /// and `cards[b1]` and every opponent hand left.
+    fn enumerate_prefix(&self, hole_cards: HoleCards, cards: &[Card], (b0, b1): (usize, usize)) -> [u64; 3] {
         let mut wins = 0u64;
         let mut ties = 0u64;
         let mut losses = 0u64;
 
-        if num_opponents == 1 {
-            // Enumerate all boards and opponent hands
-            for b0 in 0..cards.len() {
-                for b1 in (b0 + 1)..cards.len() {
-                    for b2 in (b1 + 1)..cards.len() {
-                        for b3 in (b2 + 1)..cards.len() {
-                            for b4 in (b3 + 1)..cards.len() {
-                                let full_board = [cards[b0], cards[b1], cards[b2], cards[b3], cards[b4]];
-                                let hero_cards = hole_cards.combine_with_board(full_board);
-                                let hero_strength = self.evaluator.evaluate_7cards_fast(&hero_cards);
-
-                                let board_indices = [b0, b1, b2, b3, b4];
-                                for i in 0..cards.len() {
-                                    if board_indices.contains(&i) {
-                                        continue;
-                                    }
-                                    for j in (i + 1)..cards.len() {
-                                        if board_indices.contains(&j) {
-                                            continue;
-                                        }
+        for b2 in (b1 + 1)..cards.len() {
+            for b3 in (b2 + 1)..cards.len() {
+                for b4 in (b3 + 1)..cards.len() {
+                    let full_board = [cards[b0], cards[b1], cards[b2], cards[b3], cards[b4]];
+                    let hero_cards = hole_cards.combine_with_board(full_board);
+                    let hero_strength = self.evaluator.evaluate_7cards_fast(&hero_cards);
 
-                                        let opp_hole = HoleCards::new(cards[i], cards[j]);
-                                        let opp_cards = opp_hole.combine_with_board(full_board);
-                                        let opp_strength = self.evaluator.evaluate_7cards_fast(&opp_cards);
+                    let board_set = CardSet::from_cards(&full_board);
+                    for i in 0..cards.len() {
+                        if board_set.contains(cards[i]) {
+                            continue;
+                        }
+                        for j in (i + 1)..cards.len() {
+                            if board_set.contains(cards[j]) {
+                                continue;
+                            }
 
-                                        match hero_strength.cmp(&opp_strength) {
-                                            std::cmp::Ordering::Less => wins += 1,
-                                            std::cmp::Ordering::Equal => ties += 1,
-                                            std::cmp::Ordering::Greater => losses += 1,
-                                        }
-                                    }
-                                }
+                            let opp_hole = HoleCards::new(cards[i], cards[j]);
+                            let opp_cards = opp_hole.combine_with_board(full_board);
+                            let opp_strength = self.evaluator.evaluate_7cards_fast(&opp_cards);
+
+                            match hero_strength.cmp(&opp_strength) {
+                                std::cmp::Ordering::Less => wins += 1,
+                                std::cmp::Ordering::Equal => ties += 1,
+                                std::cmp::Ordering::Greater => losses += 1,
                             }
                         }
                     }
                 }
             }
-        } else {
-            // Multi-way preflop exhaustive is computationally infeasible
-            // Return empty result - user should use Monte Carlo instead
-            return EquityResult::from_counts(0, 0, 0, num_opponents);
+        }
+
+        [wins, ties, losses]
+    }
+
+    /// Computes exact equity on a partial board of one or two cards by enumerating every
+    /// completing runout and every single-opponent hole-card combination.
+    ///
+    /// Such boards never arise in Hold'em dealing but do in stud-like analyses and custom
+    /// tools. As preflop, `run` rejects more than one opponent as infeasible.
+    fn calculate_partial(
+        &self,
+        hole_cards: HoleCards,
+        board: &Board,
+        remaining: &Deck,
+        num_opponents: usize,
+        progress: &mut ProgressReporter<'_>,
+    ) -> EquityResult {
+        let cards = remaining.cards();
+        let missing = 5 - board.len();
+        let mut wins = 0u64;
+        let mut ties = 0u64;
+        let mut losses = 0u64;
+
+        // Report once per runout
+        progress.start(binomial(cards.len(), missing) as u64);
+        let mut full = board.cards().to_vec();
+        for runout in card_combinations(cards, missing) {
+            if progress.is_cancelled() {
+                break;
+            }
+            full.truncate(board.len());
+            full.extend_from_slice(&runout);
+            let Ok(full_board) = <[Card; 5]>::try_from(full.as_slice()) else {
+                continue;
+            };
+            let hero_strength = self
+                .evaluator
+                .evaluate_7cards_fast(&hole_cards.combine_with_board(full_board));
+
+            let dealt = CardSet::from_cards(&runout);
+            for i in 0..cards.len() {
+                if dealt.contains(cards[i]) {
+                    continue;
+                }
+                for &second in &cards[i + 1..] {
+                    if dealt.contains(second) {
+                        continue;
+                    }
+                    let opponent = HoleCards::new(cards[i], second);
+                    let opp_strength = self
+                        .evaluator
+                        .evaluate_7cards_fast(&opponent.combine_with_board(full_board));
+                    match hero_strength.cmp(&opp_strength) {
+                        std::cmp::Ordering::Less => wins += 1,
+                        std::cmp::Ordering::Equal => ties += 1,
+                        std::cmp::Ordering::Greater => losses += 1,
+                    }
+                }
+            }
+            progress.advance(1);
         }
 
         EquityResult::from_counts(wins, ties, losses, num_opponents)
@@ -525,7 +1043,7 @@ mod tests {
             card(Rank::Seven, Suit::Clubs),
         ]);
 
-        let result = calc.calculate(&hole_cards, &board, 1);
+        let result = calc.calculate(&hole_cards, &board, 1).unwrap();
         assert!(result.equity() > 0.85);
     }
 
@@ -546,7 +1064,404 @@ mod tests {
             card(Rank::Eight, Suit::Clubs),
         ]);
 
-        let result = calc.calculate(&hole_cards, &board, 1);
+        let result = calc.calculate(&hole_cards, &board, 1).unwrap();
         assert!(result.equity() < 0.20);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_equity_by_river_card() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let hole_cards = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+        );
+        let turn = make_board(vec![
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Seven, Suit::Spades),
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Nine, Suit::Diamonds),
+        ]);
+
+        let by_river = calc.equity_by_river_card(&hole_cards, &turn, 1);
+        assert_eq!(by_river.len(), 46);
+        assert!(by_river.iter().all(|(_, result)| result.samples() == 990));
+
+        // The runouts together make up the turn equity
+        let total = calc.calculate(&hole_cards, &turn, 1).unwrap();
+        let combined: f64 =
+            by_river.iter().map(|(_, result)| result.equity()).sum::<f64>() / 46.0;
+        assert!((combined - total.equity()).abs() < 1e-9);
+
+        // Pairing the ace helps, and the three remaining aces are all there
+        let aces: Vec<_> = by_river
+            .iter()
+            .filter(|(card, _)| card.rank_enum() == Rank::Ace)
+            .collect();
+        assert_eq!(aces.len(), 3);
+        assert!(aces.iter().all(|(_, result)| result.equity() > total.equity()));
+
+        // Only the next street can be broken down
+        assert!(calc.equity_by_turn_card(&hole_cards, &turn, 1).is_empty());
+        assert!(calc.equity_by_river_card(&hole_cards, &Board::new(), 1).is_empty());
+    }
+
+    #[test]
+    fn test_progress_reaches_total() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let hole_cards = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+        );
+        let board = make_board(vec![
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Nine, Suit::Diamonds),
+        ]);
+
+        let mut updates = Vec::new();
+        let result = calc.calculate_with_progress(
+            &hole_cards,
+            &board,
+            1,
+            &mut |p| updates.push(p),
+            &CancellationToken::new(),
+        ).unwrap();
+
+        let last = updates.last().unwrap();
+        assert!(last.is_done());
+        assert_eq!(last.total, 46);
+        assert!(updates.windows(2).all(|w| w[0].completed <= w[1].completed));
+        assert_eq!(result.samples(), calc.calculate(&hole_cards, &board, 1).unwrap().samples());
+    }
+
+    #[test]
+    fn test_cancel_stops_enumeration() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let hole_cards = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+        );
+        let board = make_board(vec![
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Two, Suit::Clubs),
+        ]);
+        let full = calc.calculate(&hole_cards, &board, 1).unwrap();
+
+        let token = CancellationToken::new();
+        let handle = token.clone();
+        let result = calc.calculate_with_progress(
+            &hole_cards,
+            &board,
+            1,
+            &mut |p| {
+                if p.completed == 3 {
+                    handle.cancel();
+                }
+            },
+            &token,
+        ).unwrap();
+
+        assert!(result.samples() > 0);
+        assert!(result.samples() < full.samples());
+    }
+
+    #[test]
+    fn test_preflop_cancelled_before_start() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let hole_cards = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+        );
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut updates = Vec::new();
+        let result = calc.calculate_with_progress(
+            &hole_cards,
+            &Board::new(),
+            1,
+            &mut |p| updates.push(p),
+            &token,
+        ).unwrap();
+
+        // One unit of work per two-card board prefix, none of them enumerated
+        assert_eq!(updates.last().unwrap().total, binomial(50, 2) as u64);
+        assert_eq!(result.samples(), 0);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_preflop_matches_serial_counts() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let hole_cards = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+        );
+        // A short deck keeps the enumeration quick while still sharing it between threads
+        let cards: Vec<Card> = Card::all_cards()
+            .filter(|card| !hole_cards.cards().contains(card))
+            .step_by(4)
+            .collect();
+        let prefixes: Vec<(usize, usize)> = (0..cards.len())
+            .flat_map(|b0| ((b0 + 1)..cards.len()).map(move |b1| (b0, b1)))
+            .collect();
+
+        let serial = prefixes.iter().fold([0; 3], |mut counts, &prefix| {
+            let found = calc.enumerate_prefix(hole_cards, &cards, prefix);
+            for (count, found) in counts.iter_mut().zip(found) {
+                *count += found;
+            }
+            counts
+        });
+        assert_eq!(
+            serial.iter().sum::<u64>(),
+            (binomial(13, 5) * binomial(8, 2)) as u64
+        );
+
+        let token = CancellationToken::new();
+        let mut completed = 0;
+        let mut callback = |p: EquityProgress| completed = p.completed;
+        let mut progress = ProgressReporter::new(&mut callback, &token);
+        progress.start(prefixes.len() as u64);
+        let parallel = calc.enumerate_prefixes(hole_cards, &cards, &prefixes, &mut progress);
+        assert_eq!(parallel, serial);
+        assert_eq!(completed, prefixes.len() as u64);
+    }
+
+    #[test]
+    fn test_vs_hands_enumerates_runouts_only() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let aces = HoleCards::new(
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Ace, Suit::Diamonds),
+        );
+        let kings = HoleCards::new(
+            card(Rank::King, Suit::Hearts),
+            card(Rank::King, Suit::Diamonds),
+        );
+        let turn = make_board(vec![
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Seven, Suit::Spades),
+            card(Rank::Nine, Suit::Diamonds),
+            card(Rank::Jack, Suit::Clubs),
+        ]);
+
+        // Only the two remaining kings beat the aces
+        let result = calc.calculate_vs_hands(&aces, &[kings], &turn);
+        assert_eq!(result.samples(), 44);
+        assert!((result.win_rate() - 42.0 / 44.0).abs() < 1e-12);
+        assert!((result.lose_rate() - 2.0 / 44.0).abs() < 1e-12);
+
+        let mut river = turn;
+        river.deal_river(card(Rank::King, Suit::Clubs));
+        let result = calc.calculate_vs_hands(&aces, &[kings], &river);
+        assert_eq!(result.samples(), 1);
+        assert!(result.equity().abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_vs_hands_multiway() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let hero = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+        );
+        let villains = [
+            HoleCards::new(card(Rank::Queen, Suit::Hearts), card(Rank::Queen, Suit::Diamonds)),
+            HoleCards::new(card(Rank::Eight, Suit::Clubs), card(Rank::Seven, Suit::Clubs)),
+        ];
+        let flop = make_board(vec![
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Seven, Suit::Spades),
+            card(Rank::Two, Suit::Clubs),
+        ]);
+
+        let both = calc.calculate_vs_hands(&hero, &villains, &flop);
+        let one = calc.calculate_vs_hands(&hero, &villains[..1], &flop);
+        assert_eq!(both.samples(), binomial(43, 2) as u64);
+        assert_eq!(one.samples(), binomial(45, 2) as u64);
+
+        // Everyone plays the royal flush on the board and splits three ways
+        let royal = make_board(vec![
+            card(Rank::Ace, Suit::Clubs),
+            card(Rank::King, Suit::Clubs),
+            card(Rank::Queen, Suit::Clubs),
+            card(Rank::Jack, Suit::Clubs),
+            card(Rank::Ten, Suit::Clubs),
+        ]);
+        let split = calc.calculate_vs_hands(&hero, &villains, &royal);
+        assert!((split.tie_rate() - 1.0).abs() < 1e-12);
+        assert!((split.equity() - 1.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_vs_hands_rejects_invalid_input() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let hero = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+        );
+        let overlapping = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Two, Suit::Hearts),
+        );
+
+        assert_eq!(calc.calculate_vs_hands(&hero, &[], &Board::new()).samples(), 0);
+        assert_eq!(calc.calculate_vs_hands(&hero, &[overlapping], &Board::new()).samples(), 0);
+    }
+
+    #[test]
+    fn test_calculate_all_matches_heads_up() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let players: Vec<HoleCards> = ["AsAh", "KdKc"].iter().map(|h| h.parse().unwrap()).collect();
+        let flop: Board = "Ks 7h 2c".parse().unwrap();
+
+        let results = calc.calculate_all(&players, &flop, &[]).unwrap();
+        let heads_up = calc.calculate_vs_hands(&players[0], &players[1..], &flop);
+        assert!((results[0].equity() - heads_up.equity()).abs() < 1e-12);
+        assert!((results[0].equity() + results[1].equity() - 1.0).abs() < 1e-12);
+        assert_eq!(results[0].samples(), 990);
+    }
+
+    #[test]
+    fn test_calculate_all_splits_ties_among_tied_players() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let players: Vec<HoleCards> =
+            ["AcQd", "AdQh", "AhJc"].iter().map(|h| h.parse().unwrap()).collect();
+        let river: Board = "Ks 8d 7h 4c 2s".parse().unwrap();
+
+        let results = calc.calculate_all(&players, &river, &[]).unwrap();
+        assert!((results[0].equity() - 0.5).abs() < 1e-12);
+        assert!((results[1].equity() - 0.5).abs() < 1e-12);
+        assert!((results[0].tie_rate() - 1.0).abs() < 1e-12);
+        assert!(results[2].equity().abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_calculate_all_three_way_with_dead_cards() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let players: Vec<HoleCards> =
+            ["AsAh", "KdKc", "QsQh"].iter().map(|h| h.parse().unwrap()).collect();
+        let flop: Board = "7c 5d 2s".parse().unwrap();
+        let dead = [card(Rank::Ace, Suit::Diamonds), card(Rank::Queen, Suit::Clubs)];
+
+        let results = calc.calculate_all(&players, &flop, &dead).unwrap();
+        assert_eq!(results[0].samples(), 820);
+        let total: f64 = results.iter().map(EquityResult::equity).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(results[0].equity() > results[1].equity());
+        assert!(results[1].equity() > results[2].equity());
+    }
+
+    #[test]
+    fn test_calculate_all_rejects_invalid_input() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let players: Vec<HoleCards> = ["AsAh", "KdKc"].iter().map(|h| h.parse().unwrap()).collect();
+
+        assert!(matches!(
+            calc.calculate_all(&[], &Board::new(), &[]),
+            Err(EquityError::UnsupportedConfiguration(_))
+        ));
+        assert!(matches!(
+            calc.calculate_all(&players, &"As 7h 2c".parse().unwrap(), &[]),
+            Err(EquityError::DuplicateCard(_))
+        ));
+        assert!(matches!(
+            calc.calculate_all(&players, &Board::new(), &[card(Rank::King, Suit::Clubs)]),
+            Err(EquityError::DuplicateCard(_))
+        ));
+    }
+
+    #[test]
+    fn test_equity_distribution_buckets_range_on_river() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let hero = HoleCards::new(
+            card(Rank::King, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+        );
+        let river = make_board(vec![
+            card(Rank::King, Suit::Diamonds),
+            card(Rank::Seven, Suit::Spades),
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Nine, Suit::Hearts),
+            card(Rank::Eight, Suit::Diamonds),
+        ]);
+        // Aces lose to the set and jack-ten has the straight; no kings are left
+        let range: HandRange = "AA, JT, KK".parse().unwrap();
+
+        let distribution = calc.equity_distribution(&hero, &range, &river, 4);
+        assert_eq!(distribution.num_buckets(), 4);
+        assert_eq!(distribution.combos(), 6 + 16);
+        assert!((distribution.bucket_weight(3) - 6.0).abs() < 1e-12);
+        assert!((distribution.bucket_weight(0) - 16.0).abs() < 1e-12);
+        assert!(distribution.bucket_weight(1).abs() < 1e-12);
+        assert!((distribution.equity() - 6.0 / 22.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_partial_board_enumerates_runouts() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let hero = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Ace, Suit::Hearts),
+        );
+        let board = Board::partial(vec![
+            card(Rank::Seven, Suit::Clubs),
+            card(Rank::Two, Suit::Diamonds),
+        ])
+        .unwrap();
+
+        // Stop after the first hundred of the C(48, 3) completions
+        let token = CancellationToken::new();
+        let handle = token.clone();
+        let mut total = 0;
+        let result = calc.calculate_with_progress(
+            &hero,
+            &board,
+            1,
+            &mut |p| {
+                total = p.total;
+                if p.completed == 100 {
+                    handle.cancel();
+                }
+            },
+            &token,
+        ).unwrap();
+        assert_eq!(total, binomial(48, 3) as u64);
+        // Each completion is played against every pair of the 45 cards left
+        assert_eq!(result.samples(), 100 * binomial(45, 2) as u64);
+        assert!(result.equity() > 0.5);
+    }
+
+    #[test]
+    fn test_rejects_infeasible_enumeration() {
+        let calc = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new());
+        let hero = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Ace, Suit::Hearts),
+        );
+        let flop = make_board(vec![
+            card(Rank::King, Suit::Diamonds),
+            card(Rank::Seven, Suit::Clubs),
+            card(Rank::Two, Suit::Hearts),
+        ]);
+
+        assert_eq!(
+            calc.calculate(&hero, &Board::new(), 2).unwrap_err(),
+            EquityError::Infeasible {
+                num_opponents: 2,
+                board_cards: 0
+            }
+        );
+        assert!(matches!(
+            calc.calculate(&hero, &flop, MAX_EXACT_OPPONENTS + 1),
+            Err(EquityError::Infeasible { .. })
+        ));
+        assert!(matches!(
+            calc.calculate(&hero, &flop, 0),
+            Err(EquityError::UnsupportedConfiguration(_))
+        ));
+    }
+}
@@ -1,5 +1,105 @@
+mod comparison;
+mod context;
 mod exhaustive;
 mod monte_carlo;
+mod preflop_table;
+mod progress;
+mod request;
+mod smart;
 
-pub use exhaustive::ExhaustiveEquityCalculator;
-pub use monte_carlo::{MonteCarloEquityCalculator, DEFAULT_SAMPLES};
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_set::CardSet;
+use crate::core::domain::entities::hole_cards::{validate_no_duplicates, HoleCards};
+use crate::core::domain::errors::CardError;
+use crate::core::ports::inbound::{EquityError, EquityResult};
+
+pub use comparison::{ComparisonOptions, HandComparison};
+pub use context::EquityContext;
+pub use exhaustive::{ExhaustiveEquityCalculator, MAX_EXACT_OPPONENTS};
+pub use monte_carlo::{
+    MonteCarloEquityCalculator, DEFAULT_SAMPLES, MAX_PRECISION_SAMPLES, PRECISION_BATCH,
+};
+pub use request::EquityRequestBuilder;
+pub use preflop_table::{PreflopEquityTable, SHIPPED_SAMPLES};
+pub use smart::{enumeration_cost, EquityBackend, SmartEquityCalculator, DEFAULT_MAX_EXACT_COST};
+
+/// Checks that `available` unseen cards can complete a board of `board_cards` and deal
+/// two to each of `num_opponents`.
+const fn check_deal(available: usize, board_cards: usize, num_opponents: usize) -> Result<(), EquityError> {
+    if num_opponents == 0 {
+        return Err(EquityError::UnsupportedConfiguration("equity needs at least one opponent"));
+    }
+    if board_cards > 5 {
+        return Err(EquityError::UnsupportedConfiguration("a board holds at most five cards"));
+    }
+    let needed = 5 - board_cards + num_opponents * 2;
+    if available < needed {
+        return Err(EquityError::NotEnoughCards { needed, available });
+    }
+    Ok(())
+}
+
+/// Checks that `hole_cards` and `board` share no card and repeat none.
+fn check_cards(hole_cards: HoleCards, board: &Board) -> Result<(), EquityError> {
+    validate_no_duplicates(&[hole_cards], board).map_err(|err| match err {
+        CardError::DuplicateCard(card) => EquityError::DuplicateCard(card),
+        CardError::InvalidBoardSize(_) => {
+            EquityError::UnsupportedConfiguration("a board holds at most five cards")
+        }
+    })
+}
+
+/// Checks that there is at least one player and that `players`, `board`, and `dead`
+/// repeat no card, returning every card they hold.
+fn check_known(players: &[HoleCards], board: &Board, dead: &[Card]) -> Result<CardSet, EquityError> {
+    if players.is_empty() {
+        return Err(EquityError::UnsupportedConfiguration("equity needs at least one player"));
+    }
+    if board.len() > 5 {
+        return Err(EquityError::UnsupportedConfiguration("a board holds at most five cards"));
+    }
+    let mut known = CardSet::empty();
+    let mut cards = board.cards().iter().chain(players.iter().flat_map(HoleCards::cards)).chain(dead);
+    match cards.find(|&&card| !known.insert(card)) {
+        Some(&card) => Err(EquityError::DuplicateCard(card)),
+        None => Ok(known),
+    }
+}
+
+/// One player's outcomes over a set of runouts of known hands.
+#[derive(Clone, Copy, Debug, Default)]
+struct Tally {
+    wins: u64,
+    ties: u64,
+    losses: u64,
+    tie_shares: f64,
+    tie_shares_squared: f64,
+}
+
+impl Tally {
+    /// Records a runout the player won (alone or with `winners - 1` others) or lost.
+    #[allow(clippy::cast_precision_loss)]
+    fn record(&mut self, won: bool, winners: usize) {
+        match (won, winners) {
+            (false, _) => self.losses += 1,
+            (true, 1) => self.wins += 1,
+            (true, _) => {
+                let share = 1.0 / winners as f64;
+                self.ties += 1;
+                self.tie_shares += share;
+                self.tie_shares_squared += share * share;
+            }
+        }
+    }
+
+    fn result(&self) -> EquityResult {
+        EquityResult::from_tie_shares(
+            self.wins,
+            self.ties,
+            self.losses,
+            self.tie_shares,
+            self.tie_shares_squared,
+        )
+    }
+}
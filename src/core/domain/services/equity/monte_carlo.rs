@@ -4,15 +4,43 @@
 //! Fast and suitable for all board states, especially preflop where
 //! exhaustive enumeration is infeasible.
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::AddAssign;
+
 use crate::core::domain::entities::board::Board;
 use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_set::CardSet;
 use crate::core::domain::entities::deck::Deck;
+use crate::core::domain::entities::hand::HandRank;
+use crate::core::domain::entities::hand_range::HandRange;
 use crate::core::domain::entities::hole_cards::HoleCards;
-use crate::core::ports::inbound::{EquityCalculator, EquityResult, HandEvaluator};
+use crate::core::ports::inbound::{
+    CancellationToken, EquityCalculator, EquityError, EquityProgress, EquityResult,
+    HandCategoryDistribution, HandEvaluator,
+};
+use crate::telemetry;
+
+use super::{
+    check_cards, check_deal, check_known, ComparisonOptions, EquityRequestBuilder, HandComparison,
+};
+use super::progress::ProgressReporter;
 
 /// Default number of Monte Carlo iterations.
 pub const DEFAULT_SAMPLES: u32 = 10_000;
 
+/// Number of iterations between progress reports and cancellation checks.
+const PROGRESS_INTERVAL: u32 = 1_000;
+
+/// Attempts at dealing every range a hand before a request is rejected as impossible.
+const MAX_RANGE_DEALS: u32 = 10_000;
+
+/// Samples drawn by `calculate_until` between precision checks.
+pub const PRECISION_BATCH: u32 = 1_000;
+
+/// Most samples `calculate_until` draws before giving up on its target margin.
+pub const MAX_PRECISION_SAMPLES: u64 = 10_000_000;
+
 pub struct MonteCarloEquityCalculator<E: HandEvaluator> {
     evaluator: E,
     default_samples: u32,
@@ -68,7 +96,7 @@ impl<E: HandEvaluator> EquityCalculator for MonteCarloEquityCalculator<E> {
         hole_cards: &HoleCards,
         board: &Board,
         num_opponents: usize,
-    ) -> EquityResult {
+    ) -> Result<EquityResult, EquityError> {
         self.calculate_sampled(hole_cards, board, num_opponents, self.default_samples)
     }
 
@@ -84,11 +112,29 @@ impl<E: HandEvaluator> EquityCalculator for MonteCarloEquityCalculator<E> {
         board: &Board,
         num_opponents: usize,
         samples: u32,
-    ) -> EquityResult {
+    ) -> Result<EquityResult, EquityError> {
+        check_cards(*hole_cards, board)?;
         let remaining = Self::remaining_deck(*hole_cards, board);
-        let cards_to_deal = 5 - board.len();
 
-        self.simulate(*hole_cards, board.cards(), &remaining, num_opponents, cards_to_deal, samples)
+        self.simulate(*hole_cards, board.cards(), &remaining, num_opponents, samples, &mut ProgressReporter::silent())
+    }
+
+    /// Runs the default number of samples, reporting progress every `PROGRESS_INTERVAL` iterations.
+    ///
+    /// On cancellation the samples drawn so far are returned.
+    fn calculate_with_progress(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+        num_opponents: usize,
+        progress: &mut dyn FnMut(EquityProgress),
+        cancel: &CancellationToken,
+    ) -> Result<EquityResult, EquityError> {
+        check_cards(*hole_cards, board)?;
+        let remaining = Self::remaining_deck(*hole_cards, board);
+        let mut reporter = ProgressReporter::new(progress, cancel);
+
+        self.simulate(*hole_cards, board.cards(), &remaining, num_opponents, self.default_samples, &mut reporter)
     }
 }
 
@@ -106,49 +152,99 @@ impl<E: HandEvaluator> MonteCarloEquityCalculator<E> {
     /// - `board_cards`: the current shared board cards (0..5 cards).
     /// - `remaining`: deck of unseen cards to sample from (must exclude `hole_cards` and `board_cards`).
     /// - `num_opponents`: number of opponents to simulate (each receives two hole cards).
-    /// - `cards_to_deal`: number of runout cards to deal to complete a 5-card board (0..5 - `board_cards.len()`).
     /// - `iterations`: number of Monte Carlo samples to perform.
+    /// - `progress`: receives progress updates; stops the simulation early when cancelled.
     ///
     /// # Returns
     ///
     /// An `EquityResult` constructed from the accumulated win, tie, and loss counts for the hero against
-    /// `num_opponents`.
+    /// `num_opponents`, or an `EquityError` if the remaining cards cannot complete the deal.
+    #[allow(clippy::too_many_arguments)]
     fn simulate(
         &self,
         hole_cards: HoleCards,
         board_cards: &[Card],
         remaining: &Deck,
         num_opponents: usize,
-        cards_to_deal: usize,
         iterations: u32,
-    ) -> EquityResult {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-
+        progress: &mut ProgressReporter<'_>,
+    ) -> Result<EquityResult, EquityError> {
+        let _span = telemetry::equity_span("monte_carlo", num_opponents, board_cards.len());
         let cards = remaining.to_vec();
-        let mut wins = 0u64;
-        let mut ties = 0u64;
-        let mut losses = 0u64;
+        let mut seed = initial_seed(hole_cards, board_cards);
+        check_deal(cards.len(), board_cards.len(), num_opponents)?;
 
-        // Deterministic seed for reproducibility
-        let mut hasher = DefaultHasher::new();
-        hole_cards.first().index().hash(&mut hasher);
-        hole_cards.second().index().hash(&mut hasher);
-        board_cards.len().hash(&mut hasher);
-        let mut seed = hasher.finish();
+        progress.start(u64::from(iterations));
+        let tally = self.sample(
+            hole_cards,
+            board_cards,
+            &cards,
+            num_opponents,
+            &[],
+            iterations,
+            &mut seed,
+            progress,
+            None,
+        );
+        if !progress.is_cancelled() {
+            progress.finish();
+        }
+
+        Ok(tally.result(num_opponents))
+    }
 
+    /// Draws `iterations` samples, continuing the random sequence held in `seed`.
+    ///
+    /// Each sample deals every opponent in `ranges` a hand from their range, then
+    /// `num_opponents` more opponents random cards. `cards` must hold enough unseen
+    /// cards to complete the board and deal every opponent. Progress is advanced every
+    /// `PROGRESS_INTERVAL` samples, and sampling stops early when `progress` is
+    /// cancelled. When `categories` is given, every player's hand category is recorded
+    /// in it.
+    #[allow(clippy::too_many_arguments)]
+    fn sample(
+        &self,
+        hole_cards: HoleCards,
+        board_cards: &[Card],
+        cards: &[Card],
+        num_opponents: usize,
+        ranges: &[WeightedCombos],
+        iterations: u32,
+        seed: &mut u64,
+        progress: &mut ProgressReporter<'_>,
+        mut categories: Option<&mut HandCategoryDistribution>,
+    ) -> Tally {
+        let cards_to_deal = 5 - board_cards.len();
         let total_cards_needed = cards_to_deal + (num_opponents * 2);
+        let mut tally = Tally::default();
+        let mut opponent_strengths = Vec::with_capacity(num_opponents + ranges.len());
+        let mut range_hands = Vec::with_capacity(ranges.len());
 
-        if cards.len() < total_cards_needed {
-            return EquityResult::from_counts(0, 0, 0, num_opponents);
-        }
+        for iteration in 0..iterations {
+            if iteration % PROGRESS_INTERVAL == 0 {
+                if iteration > 0 {
+                    progress.advance(u64::from(PROGRESS_INTERVAL));
+                }
+                if progress.is_cancelled() {
+                    break;
+                }
+            }
+
+            let mut shuffled = if ranges.is_empty() {
+                cards.to_vec()
+            } else {
+                // Redeal the ranged hands until none of them share a card
+                let held = loop {
+                    if let Some(held) = deal_ranges(ranges, seed, &mut range_hands) {
+                        break held;
+                    }
+                };
+                cards.iter().copied().filter(|&card| !held.contains(card)).collect()
+            };
 
-        for _ in 0..iterations {
             // Fisher-Yates partial shuffle using LCG
-            let mut shuffled = cards.clone();
             for i in 0..total_cards_needed {
-                seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
-                let j = i + ((seed >> 33) as usize % (shuffled.len() - i));
+                let j = i + next_index(seed, shuffled.len() - i);
                 shuffled.swap(i, j);
             }
 
@@ -168,38 +264,628 @@ impl<E: HandEvaluator> MonteCarloEquityCalculator<E> {
             let mut hero_wins = true;
             let mut any_tie = false;
             let opp_start = cards_to_deal;
+            opponent_strengths.clear();
 
-            for opp in 0..num_opponents {
+            let random_hands = (0..num_opponents).map(|opp| {
                 let opp_idx = opp_start + (opp * 2);
-                let opp_hole = HoleCards::new(shuffled[opp_idx], shuffled[opp_idx + 1]);
+                HoleCards::new(shuffled[opp_idx], shuffled[opp_idx + 1])
+            });
+            for opp_hole in range_hands.iter().copied().chain(random_hands) {
                 let opp_cards = opp_hole.combine_with_board(full_board);
                 let opp_strength = self.evaluator.evaluate_7cards_fast(&opp_cards);
+                opponent_strengths.push(opp_strength);
 
                 if opp_strength < hero_strength {
                     hero_wins = false;
-                    break;
+                    // Recording categories needs every opponent's hand
+                    if categories.is_none() {
+                        break;
+                    }
                 } else if opp_strength == hero_strength {
                     any_tie = true;
                 }
             }
 
+            if let Some(categories) = categories.as_deref_mut() {
+                categories.record(
+                    HandRank::from_strength(hero_strength),
+                    opponent_strengths
+                        .iter()
+                        .map(|&strength| HandRank::from_strength(strength)),
+                );
+            }
+
             if !hero_wins {
-                losses += 1;
+                tally.losses += 1;
             } else if any_tie {
-                ties += 1;
+                tally.ties += 1;
             } else {
-                wins += 1;
+                tally.wins += 1;
+            }
+        }
+
+        telemetry::equity_samples("monte_carlo", tally.wins + tally.ties + tally.losses);
+        tally
+    }
+}
+
+/// `MonteCarloEquityCalculator` - Precision Control
+impl<E: HandEvaluator> MonteCarloEquityCalculator<E> {
+    /// Samples in batches until the equity is known to within `margin` at `confidence`.
+    ///
+    /// After each batch of `PRECISION_BATCH` samples the margin of error, `z * std_error`
+    /// for the normal quantile `z` of `confidence`, is compared with `margin`. Sampling
+    /// stops once it is small enough or after `MAX_PRECISION_SAMPLES` samples, so check
+    /// `confidence_interval` on the result for the precision actually achieved.
+    ///
+    /// # Arguments
+    /// * `hole_cards` - Your hole cards
+    /// * `board` - Current community cards
+    /// * `num_opponents` - Number of opponents
+    /// * `confidence` - Confidence level, such as 0.95 for 95%
+    /// * `margin` - Target half-width of the confidence interval, such as 0.005
+    ///
+    /// # Errors
+    /// Returns an `EquityError` when there are no opponents or too few unseen cards to
+    /// complete the deal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::board::Board;
+    /// use riverrun::core::domain::entities::card::Card;
+    /// use riverrun::core::domain::entities::hole_cards::HoleCards;
+    /// use riverrun::core::domain::services::{CactusKevEvaluator, MonteCarloEquityCalculator};
+    ///
+    /// let calculator = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+    /// let hole = HoleCards::new(Card::from_string("Ts").unwrap(), Card::from_string("9s").unwrap());
+    ///
+    /// let result = calculator.calculate_until(&hole, &Board::new(), 1, 0.95, 0.02).unwrap();
+    /// assert!(result.margin_of_error(0.95) <= 0.02);
+    /// let (low, high) = result.confidence_interval(0.95);
+    /// assert!(low < result.equity() && result.equity() < high);
+    /// ```
+    pub fn calculate_until(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+        num_opponents: usize,
+        confidence: f64,
+        margin: f64,
+    ) -> Result<EquityResult, EquityError> {
+        let _span = telemetry::equity_span("monte_carlo", num_opponents, board.len());
+        check_cards(*hole_cards, board)?;
+        let cards = Self::remaining_deck(*hole_cards, board).to_vec();
+        check_deal(cards.len(), board.len(), num_opponents)?;
+
+        let mut seed = initial_seed(*hole_cards, board.cards());
+        let mut tally = Tally::default();
+        loop {
+            tally += self.sample(
+                *hole_cards,
+                board.cards(),
+                &cards,
+                num_opponents,
+                &[],
+                PRECISION_BATCH,
+                &mut seed,
+                &mut ProgressReporter::silent(),
+                None,
+            );
+            let result = tally.result(num_opponents);
+            if result.margin_of_error(confidence) <= margin
+                || result.samples() >= MAX_PRECISION_SAMPLES
+            {
+                return Ok(result);
+            }
+        }
+    }
+}
+
+/// `MonteCarloEquityCalculator` - Hand Categories
+impl<E: HandEvaluator> MonteCarloEquityCalculator<E> {
+    /// Estimates equity like `calculate_sampled`, also recording how often hero and the
+    /// opponents make each hand category by the river.
+    ///
+    /// Uses the same samples as `calculate_sampled`, so the equity matches it exactly.
+    ///
+    /// # Errors
+    /// Returns an `EquityError` under the same conditions as `calculate_sampled`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::board::Board;
+    /// use riverrun::core::domain::entities::card::Card;
+    /// use riverrun::core::domain::entities::hand::HandRank;
+    /// use riverrun::core::domain::entities::hole_cards::HoleCards;
+    /// use riverrun::core::domain::services::{CactusKevEvaluator, MonteCarloEquityCalculator};
+    ///
+    /// let cards = |text: &str| -> Vec<Card> {
+    ///     text.split_whitespace().map(|c| c.parse().unwrap()).collect()
+    /// };
+    /// let hole = cards("Ah Kh");
+    /// let board = Board::with_cards(cards("Qh 7h 2c")).unwrap();
+    ///
+    /// let calculator = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+    /// let (equity, categories) = calculator
+    ///     .calculate_with_categories(&HoleCards::new(hole[0], hole[1]), &board, 1, 10_000)
+    ///     .unwrap();
+    /// // A flush draw on the flop gets there about 35% of the time
+    /// assert!((categories.hero_frequency(HandRank::Flush) - 0.35).abs() < 0.03);
+    /// assert_eq!(categories.samples(), equity.samples());
+    /// ```
+    pub fn calculate_with_categories(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+        num_opponents: usize,
+        samples: u32,
+    ) -> Result<(EquityResult, HandCategoryDistribution), EquityError> {
+        let _span = telemetry::equity_span("monte_carlo", num_opponents, board.len());
+        let mut categories = HandCategoryDistribution::new();
+        check_cards(*hole_cards, board)?;
+        let cards = Self::remaining_deck(*hole_cards, board).to_vec();
+        check_deal(cards.len(), board.len(), num_opponents)?;
+
+        let mut seed = initial_seed(*hole_cards, board.cards());
+        let tally = self.sample(
+            *hole_cards,
+            board.cards(),
+            &cards,
+            num_opponents,
+            &[],
+            samples,
+            &mut seed,
+            &mut ProgressReporter::silent(),
+            Some(&mut categories),
+        );
+        Ok((tally.result(num_opponents), categories))
+    }
+}
+
+/// `MonteCarloEquityCalculator` - Requests
+impl<E: HandEvaluator> MonteCarloEquityCalculator<E> {
+    /// Runs everything `request` configures; see `EquityRequestBuilder::run`.
+    pub(super) fn run(&self, request: &EquityRequestBuilder) -> Result<EquityResult, EquityError> {
+        let board_cards = request.board.cards();
+        let num_opponents = request.num_opponents();
+        let _span = telemetry::equity_span("monte_carlo", num_opponents, board_cards.len());
+        let known = check_known(&[request.hero], &request.board, &request.dead)?;
+        let cards: Vec<Card> = Card::all_cards().filter(|&card| !known.contains(card)).collect();
+        check_deal(cards.len(), board_cards.len(), num_opponents)?;
+
+        let known: Vec<Card> = known.iter().collect();
+        let ranges = request
+            .ranges
+            .iter()
+            .map(|range| WeightedCombos::new(&range.without(&known)))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(EquityError::UnsupportedConfiguration("a range has no hand left to deal"))?;
+        let seed = request.seed.unwrap_or_else(|| initial_seed(request.hero, board_cards));
+        let mut probe = seed;
+        let mut hands = Vec::new();
+        if !(0..MAX_RANGE_DEALS).any(|_| deal_ranges(&ranges, &mut probe, &mut hands).is_some()) {
+            return Err(EquityError::UnsupportedConfiguration(
+                "the ranges cannot all be dealt at once",
+            ));
+        }
+
+        // Thread 0 continues the single-threaded sequence, so one thread matches
+        // `calculate_sampled`
+        let mut seeds: Vec<u64> = (0..request.threads as u64)
+            .map(|thread| seed.wrapping_add(thread.wrapping_mul(0x9E37_79B9_7F4A_7C15)))
+            .collect();
+        let batch = if request.precision.is_some() {
+            PRECISION_BATCH
+        } else {
+            request.samples.unwrap_or(self.default_samples)
+        };
+        let mut strata = Stratum::split(request, &cards, ranges.is_empty(), batch);
+        let deal = |iterations, strata: &mut [Stratum], seeds: &mut [u64]| {
+            let shares = Stratum::allocate(strata, iterations);
+            for (stratum, share) in strata.iter_mut().zip(shares) {
+                stratum.tally += self.sample_threads(
+                    request.hero,
+                    &stratum.board,
+                    &stratum.cards,
+                    request.opponents,
+                    &ranges,
+                    share,
+                    seeds,
+                );
             }
+            EquityResult::from_strata(
+                strata
+                    .iter()
+                    .map(|stratum| (stratum.probability, stratum.tally.result(num_opponents))),
+            )
+        };
+
+        let Some((confidence, margin)) = request.precision else {
+            return Ok(deal(batch, &mut strata, &mut seeds));
+        };
+        loop {
+            let result = deal(PRECISION_BATCH, &mut strata, &mut seeds);
+            if result.margin_of_error(confidence) <= margin
+                || result.samples() >= MAX_PRECISION_SAMPLES
+            {
+                return Ok(result);
+            }
+        }
+    }
+
+    /// Draws `iterations` samples split evenly over one thread per seed in `seeds`.
+    #[allow(clippy::too_many_arguments)]
+    fn sample_threads(
+        &self,
+        hole_cards: HoleCards,
+        board_cards: &[Card],
+        cards: &[Card],
+        num_opponents: usize,
+        ranges: &[WeightedCombos],
+        iterations: u32,
+        seeds: &mut [u64],
+    ) -> Tally {
+        let sample = |iterations, seed: &mut u64| {
+            self.sample(
+                hole_cards,
+                board_cards,
+                cards,
+                num_opponents,
+                ranges,
+                iterations,
+                seed,
+                &mut ProgressReporter::silent(),
+                None,
+            )
+        };
+        if let [seed] = seeds {
+            return sample(iterations, seed);
         }
 
-        EquityResult::from_counts(wins, ties, losses, num_opponents)
+        let threads = u32::try_from(seeds.len()).unwrap_or(u32::MAX);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .zip(seeds.iter_mut())
+                .map(|(thread, seed)| {
+                    let share = iterations / threads + u32::from(thread < iterations % threads);
+                    scope.spawn(move || sample(share, seed))
+                })
+                .collect();
+            let mut tally = Tally::default();
+            for handle in handles {
+                tally += handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            }
+            tally
+        })
     }
 }
 
+/// `MonteCarloEquityCalculator` - Comparisons
+impl<E: HandEvaluator> MonteCarloEquityCalculator<E> {
+    /// Compares the equity of `first` and `second` on `board` by scoring both hands
+    /// against the same sampled opponents and runouts.
+    ///
+    /// Sharing every deal makes the two equities move together, so their difference is
+    /// measured more precisely than by two separate runs of the same size: heads-up, its
+    /// standard error is about a third of theirs for hands like `QQ` and `JJ`, and about
+    /// 60% for `AhKh` and `AcKd` on a heart draw, where only the flush outs differ.
+    /// Opponents and runouts are dealt from cards neither hand holds, so the hands may
+    /// share a card, and each hand is measured against opponents blocked by both.
+    ///
+    /// # Errors
+    /// Returns `EquityError::DuplicateCard` if either hand shares a card with the board
+    /// or repeats one, and `EquityError::NotEnoughCards` or
+    /// `EquityError::UnsupportedConfiguration` when the deal is impossible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::board::Board;
+    /// use riverrun::core::domain::services::equity::ComparisonOptions;
+    /// use riverrun::core::domain::services::{CactusKevEvaluator, MonteCarloEquityCalculator};
+    ///
+    /// let calculator = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+    /// let board: Board = "Qh 7h 2c".parse().unwrap();
+    /// let options = ComparisonOptions::new().with_antithetic();
+    /// let comparison = calculator
+    ///     .compare_hands(&"AhKh".parse().unwrap(), &"AcKd".parse().unwrap(), &board, &options)
+    ///     .unwrap();
+    /// // The flush draw is worth a lot more than the overcards alone
+    /// let (low, _) = comparison.confidence_interval(0.95);
+    /// assert!(low > 0.05);
+    /// ```
+    pub fn compare_hands(
+        &self,
+        first: &HoleCards,
+        second: &HoleCards,
+        board: &Board,
+        options: &ComparisonOptions,
+    ) -> Result<HandComparison, EquityError> {
+        let board_cards = board.cards();
+        let _span = telemetry::equity_span("monte_carlo", options.opponents, board_cards.len());
+        check_cards(*first, board)?;
+        check_cards(*second, board)?;
+        let mut known = CardSet::empty();
+        for &card in board_cards.iter().chain(first.cards()).chain(second.cards()) {
+            known.insert(card);
+        }
+        let cards: Vec<Card> = Card::all_cards().filter(|&card| !known.contains(card)).collect();
+        check_deal(cards.len(), board_cards.len(), options.opponents)?;
+
+        let mut seed = options.seed.unwrap_or_else(|| {
+            initial_seed(*first, board_cards) ^ initial_seed(*second, board_cards).rotate_left(32)
+        });
+        let mirrors: &[bool] = if options.antithetic { &[false, true] } else { &[false] };
+        let deals: u32 = if options.antithetic { 2 } else { 1 };
+        let observations = options.samples.div_ceil(deals);
+        let mut offsets = vec![0; 5 - board_cards.len() + options.opponents * 2];
+        let (mut first_sum, mut second_sum, mut deltas, mut deltas_squared) = (0.0, 0.0, 0.0, 0.0);
+        for _ in 0..observations {
+            for (dealt, offset) in offsets.iter_mut().enumerate() {
+                *offset = next_index(&mut seed, cards.len() - dealt);
+            }
+            let (mut first_share, mut second_share) = (0.0, 0.0);
+            for &mirrored in mirrors {
+                let [first_deal, second_deal] = self.score_pair(
+                    [*first, *second],
+                    board_cards,
+                    &cards,
+                    options.opponents,
+                    &offsets,
+                    mirrored,
+                );
+                first_share += first_deal / f64::from(deals);
+                second_share += second_deal / f64::from(deals);
+            }
+            let delta = first_share - second_share;
+            first_sum += first_share;
+            second_sum += second_share;
+            deltas += delta;
+            deltas_squared += delta * delta;
+        }
+
+        let samples = u64::from(observations) * u64::from(deals);
+        telemetry::equity_samples("monte_carlo", samples);
+        Ok(HandComparison::from_sums(
+            samples,
+            u64::from(observations),
+            first_sum,
+            second_sum,
+            deltas,
+            deltas_squared,
+        ))
+    }
+
+    /// Pot shares won by each of `hands` on one deal from `cards`, shuffled by swapping
+    /// the `i`th card with the one `offsets[i]` places after it, or that many places
+    /// before the end of the deck when `mirrored`.
+    #[allow(clippy::cast_precision_loss)]
+    fn score_pair(
+        &self,
+        hands: [HoleCards; 2],
+        board_cards: &[Card],
+        cards: &[Card],
+        num_opponents: usize,
+        offsets: &[usize],
+        mirrored: bool,
+    ) -> [f64; 2] {
+        let mut shuffled = cards.to_vec();
+        for (i, &offset) in offsets.iter().enumerate() {
+            let offset = if mirrored { shuffled.len() - i - 1 - offset } else { offset };
+            shuffled.swap(i, i + offset);
+        }
+
+        let (runout, dealt) = shuffled.split_at(5 - board_cards.len());
+        let mut full_board = [Card::from_index(0).unwrap(); 5];
+        full_board[..board_cards.len()].copy_from_slice(board_cards);
+        full_board[board_cards.len()..].copy_from_slice(runout);
+        let opponent_strengths: Vec<u16> = dealt
+            .chunks_exact(2)
+            .take(num_opponents)
+            .map(|pair| {
+                let opponent = HoleCards::new(pair[0], pair[1]);
+                self.evaluator.evaluate_7cards_fast(&opponent.combine_with_board(full_board))
+            })
+            .collect();
+
+        hands.map(|hand| {
+            let strength = self
+                .evaluator
+                .evaluate_7cards_fast(&hand.combine_with_board(full_board));
+            if opponent_strengths.iter().any(|&opponent| opponent < strength) {
+                return 0.0;
+            }
+            let tied = opponent_strengths.iter().filter(|&&opponent| opponent == strength).count();
+            1.0 / (tied + 1) as f64
+        })
+    }
+}
+
+/// Win, tie, and loss counts accumulated over samples.
+#[derive(Debug, Default, Clone, Copy)]
+struct Tally {
+    wins: u64,
+    ties: u64,
+    losses: u64,
+}
+
+impl Tally {
+    fn result(self, num_opponents: usize) -> EquityResult {
+        EquityResult::from_counts(self.wins, self.ties, self.losses, num_opponents)
+    }
+}
+
+impl AddAssign for Tally {
+    fn add_assign(&mut self, other: Self) {
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.losses += other.losses;
+    }
+}
+
+/// Deterministic seed for reproducibility.
+fn initial_seed(hole_cards: HoleCards, board_cards: &[Card]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hole_cards.first().index().hash(&mut hasher);
+    hole_cards.second().index().hash(&mut hasher);
+    board_cards.len().hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The runouts starting with one next board card, or every runout when a request is
+/// not stratified, and the samples drawn from them so far.
+struct Stratum {
+    board: Vec<Card>,
+    /// Unseen cards once `board` is dealt.
+    cards: Vec<Card>,
+    /// Chance of a runout falling in this stratum.
+    probability: f64,
+    /// Samples drawn from this stratum relative to the others.
+    share: f64,
+    tally: Tally,
+}
+
+impl Stratum {
+    /// Fewest samples a stratum is dealt at once, so each has a standard error.
+    const MIN_SAMPLES: u32 = 2;
+
+    /// The strata of `request`, whose unseen cards are `cards`: one per next board card
+    /// when stratifying is asked for and `uniform`, since every next card is then
+    /// equally likely, and `batch` samples give each stratum at least `MIN_SAMPLES`.
+    /// Otherwise a single stratum.
+    #[allow(clippy::cast_precision_loss)]
+    fn split(
+        request: &EquityRequestBuilder,
+        cards: &[Card],
+        uniform: bool,
+        batch: u32,
+    ) -> Vec<Self> {
+        let board = request.board.cards();
+        let enough = u32::try_from(cards.len())
+            .is_ok_and(|strata| batch / Self::MIN_SAMPLES >= strata);
+        if !request.stratified || !uniform || board.len() >= 5 || !enough {
+            return vec![Self {
+                board: board.to_vec(),
+                cards: cards.to_vec(),
+                probability: 1.0,
+                share: 1.0,
+                tally: Tally::default(),
+            }];
+        }
+        let probability = 1.0 / cards.len() as f64;
+        cards
+            .iter()
+            .map(|&next| {
+                let emphasis = request
+                    .emphasis
+                    .filter(|(constraint, _)| constraint.matches(board, &[next]))
+                    .map_or(1.0, |(_, factor)| factor.max(f64::MIN_POSITIVE));
+                Self {
+                    board: [board, &[next]].concat(),
+                    cards: cards.iter().copied().filter(|&card| card != next).collect(),
+                    probability,
+                    share: probability * emphasis,
+                    tally: Tally::default(),
+                }
+            })
+            .collect()
+    }
+
+    /// Splits exactly `iterations` samples between `strata`: `MIN_SAMPLES` each when
+    /// there are enough, as `split` ensures, and the rest in proportion to their shares.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn allocate(strata: &[Self], iterations: u32) -> Vec<u32> {
+        let count = u32::try_from(strata.len()).unwrap_or(u32::MAX);
+        let floor = if iterations / Self::MIN_SAMPLES >= count {
+            Self::MIN_SAMPLES
+        } else {
+            0
+        };
+        let rest = iterations - floor * count;
+        let total: f64 = strata.iter().map(|stratum| stratum.share).sum();
+
+        // Rounding the running total keeps the sum exact and each share within one
+        // sample of its proportion
+        let mut cumulative = 0.0;
+        let mut dealt = 0;
+        strata
+            .iter()
+            .enumerate()
+            .map(|(index, stratum)| {
+                cumulative += stratum.share;
+                let upto = if index + 1 == strata.len() {
+                    rest
+                } else {
+                    ((f64::from(rest) * cumulative / total).round() as u32).clamp(dealt, rest)
+                };
+                let extra = upto - dealt;
+                dealt = upto;
+                floor + extra
+            })
+            .collect()
+    }
+}
+
+/// The hands of a range with positive weight, for drawing one by weight.
+struct WeightedCombos {
+    combos: Vec<HoleCards>,
+    /// Running total of the weights, ending with the total weight.
+    cumulative: Vec<f64>,
+}
+
+impl WeightedCombos {
+    /// The hands of `range`, or `None` if it is empty.
+    fn new(range: &HandRange) -> Option<Self> {
+        let mut total = 0.0;
+        let (combos, cumulative) = range
+            .combos()
+            .map(|(combo, weight)| {
+                total += weight;
+                (combo, total)
+            })
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        (!combos.is_empty()).then_some(Self { combos, cumulative })
+    }
+
+    /// A hand drawn with probability proportional to its weight.
+    #[allow(clippy::cast_precision_loss)]
+    fn pick(&self, seed: &mut u64) -> HoleCards {
+        let total = self.cumulative.last().copied().unwrap_or_default();
+        *seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+        let target = (*seed >> 11) as f64 / (1u64 << 53) as f64 * total;
+        let index = self.cumulative.partition_point(|&bound| bound <= target);
+        self.combos[index.min(self.combos.len() - 1)]
+    }
+}
+
+/// Deals every range a hand into `hands`, returning the cards dealt, or `None` if two
+/// hands share a card.
+fn deal_ranges(ranges: &[WeightedCombos], seed: &mut u64, hands: &mut Vec<HoleCards>) -> Option<CardSet> {
+    let mut held = CardSet::empty();
+    hands.clear();
+    for range in ranges {
+        let hand = range.pick(seed);
+        if !hand.cards().iter().all(|&card| held.insert(card)) {
+            return None;
+        }
+        hands.push(hand);
+    }
+    Some(held)
+}
+
+/// Next index below `bound` from the LCG sequence held in `seed`.
+const fn next_index(seed: &mut u64, bound: usize) -> usize {
+    *seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+    (*seed >> 33) as usize % bound
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::domain::entities::card::{Rank, Suit};
+    use crate::core::domain::services::analysis::RunoutConstraint;
     use crate::core::domain::services::evaluation::CactusKevEvaluator;
 
     fn card(rank: Rank, suit: Suit) -> Card {
@@ -221,7 +907,7 @@ mod tests {
 
         let board = Board::new(); // Preflop
 
-        let result = calc.calculate(&hole_cards, &board, 1);
+        let result = calc.calculate(&hole_cards, &board, 1).unwrap();
 
         // Pocket aces preflop ~85% equity heads-up
         assert!(result.equity() > 0.80);
@@ -239,7 +925,7 @@ mod tests {
 
         let board = Board::new();
 
-        let result = calc.calculate(&hole_cards, &board, 1);
+        let result = calc.calculate(&hole_cards, &board, 1).unwrap();
 
         // 72o is worst hand, ~35% equity heads-up
         assert!(result.equity() > 0.30);
@@ -261,7 +947,7 @@ mod tests {
             card(Rank::Two, Suit::Hearts),
         ]);
 
-        let result = calc.calculate(&hole_cards, &board, 1);
+        let result = calc.calculate(&hole_cards, &board, 1).unwrap();
 
         // AKs with nut flush draw + straight draw
         assert!(result.equity() > 0.50);
@@ -278,9 +964,9 @@ mod tests {
 
         let board = Board::new();
 
-        let result_1 = calc.calculate(&hole_cards, &board, 1);
-        let result_3 = calc.calculate(&hole_cards, &board, 3);
-        let result_5 = calc.calculate(&hole_cards, &board, 5);
+        let result_1 = calc.calculate(&hole_cards, &board, 1).unwrap();
+        let result_3 = calc.calculate(&hole_cards, &board, 3).unwrap();
+        let result_5 = calc.calculate(&hole_cards, &board, 5).unwrap();
 
         // Equity decreases with more opponents
         assert!(result_1.equity() > result_3.equity());
@@ -301,7 +987,7 @@ mod tests {
         let board = Board::new();
 
         // Using custom sample count
-        let result = calc.calculate_sampled(&hole_cards, &board, 1, 500);
+        let result = calc.calculate_sampled(&hole_cards, &board, 1, 500).unwrap();
         assert_eq!(result.samples(), 500);
     }
 
@@ -322,9 +1008,261 @@ mod tests {
             card(Rank::Seven, Suit::Clubs),
         ]);
 
-        let result = calc.calculate(&hole_cards, &board, 1);
+        let result = calc.calculate(&hole_cards, &board, 1).unwrap();
 
         // Pocket aces on safe board
         assert!(result.equity() > 0.80);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_calculate_until_reaches_margin() {
+        let calc = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+        let hole_cards = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+        );
+
+        let loose = calc.calculate_until(&hole_cards, &Board::new(), 1, 0.95, 0.02).unwrap();
+        let tight = calc.calculate_until(&hole_cards, &Board::new(), 1, 0.95, 0.005).unwrap();
+        assert!(loose.margin_of_error(0.95) <= 0.02);
+        assert!(tight.margin_of_error(0.95) <= 0.005);
+        assert!(tight.samples() > loose.samples());
+        assert_eq!(tight.samples() % u64::from(PRECISION_BATCH), 0);
+        // AKo is about 65% against a random hand
+        let (low, high) = tight.confidence_interval(0.95);
+        assert!(low < 0.66 && high > 0.64, "{low}..{high}");
+    }
+
+    #[test]
+    fn test_calculate_until_stops_on_certain_result() {
+        let calc = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+        let royal = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+        );
+        let board = make_board(vec![
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Jack, Suit::Spades),
+            card(Rank::Ten, Suit::Spades),
+        ]);
+
+        let result = calc.calculate_until(&royal, &board, 1, 0.99, 0.001).unwrap();
+        assert_eq!(result.samples(), u64::from(PRECISION_BATCH));
+        assert!((result.equity() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_categories_match_plain_equity() {
+        let calc = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+        let hole_cards = HoleCards::new(
+            card(Rank::Eight, Suit::Spades),
+            card(Rank::Eight, Suit::Hearts),
+        );
+
+        let plain = calc.calculate_sampled(&hole_cards, &Board::new(), 2, 4_000).unwrap();
+        let (equity, categories) =
+            calc.calculate_with_categories(&hole_cards, &Board::new(), 2, 4_000).unwrap();
+        assert!((equity.equity() - plain.equity()).abs() < f64::EPSILON);
+        assert_eq!(categories.samples(), 4_000);
+
+        // A pocket pair never finishes with high card, and ends with just trips about 12%
+        assert_eq!(categories.hero_count(HandRank::HighCard), 0);
+        let trips = categories.hero_frequency(HandRank::ThreeOfAKind);
+        assert!((0.08..0.16).contains(&trips), "{trips}");
+        let villain_total: u64 = HandRank::ALL
+            .iter()
+            .map(|&rank| categories.villain_count(rank))
+            .sum();
+        assert_eq!(villain_total, 8_000);
+    }
+
+    #[test]
+    fn test_progress_reaches_total() {
+        let calc = MonteCarloEquityCalculator::with_samples(CactusKevEvaluator::new(), 2_500);
+        let hole_cards = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Ace, Suit::Hearts),
+        );
+
+        let mut updates = Vec::new();
+        let result = calc.calculate_with_progress(
+            &hole_cards,
+            &Board::new(),
+            1,
+            &mut |p| updates.push(p),
+            &CancellationToken::new(),
+        ).unwrap();
+
+        assert_eq!(result.samples(), 2_500);
+        assert_eq!(updates.first().unwrap().completed, 0);
+        let last = updates.last().unwrap();
+        assert_eq!(last.total, 2_500);
+        assert!(last.is_done());
+    }
+
+    #[test]
+    fn test_cancelled_before_start() {
+        let calc = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+        let hole_cards = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Ace, Suit::Hearts),
+        );
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let result = calc.calculate_with_progress(&hole_cards, &Board::new(), 1, &mut |_| {}, &token).unwrap();
+        assert_eq!(result.samples(), 0);
+    }
+
+    #[test]
+    fn test_cancel_mid_simulation() {
+        let calc = MonteCarloEquityCalculator::with_samples(CactusKevEvaluator::new(), 5_000);
+        let hole_cards = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Ace, Suit::Hearts),
+        );
+        let token = CancellationToken::new();
+        let handle = token.clone();
+
+        let result = calc.calculate_with_progress(
+            &hole_cards,
+            &Board::new(),
+            1,
+            &mut |p| {
+                if p.completed >= 2_000 {
+                    handle.cancel();
+                }
+            },
+            &token,
+        ).unwrap();
+
+        assert_eq!(result.samples(), 2_000);
+    }
+
+    #[test]
+    fn test_rejects_deals_without_enough_cards() {
+        let calc = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+        let hole_cards = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Ace, Suit::Hearts),
+        );
+
+        // Five board cards and two for each of 23 opponents need 51 of the 50 unseen
+        assert_eq!(
+            calc.calculate(&hole_cards, &Board::new(), 23).unwrap_err(),
+            EquityError::NotEnoughCards {
+                needed: 51,
+                available: 50
+            }
+        );
+        assert!(calc.calculate(&hole_cards, &Board::new(), 22).is_ok());
+
+        // A hole card repeated on the board is rejected rather than dealt twice
+        let board = make_board(vec![
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Seven, Suit::Diamonds),
+        ]);
+        assert_eq!(
+            calc.calculate(&hole_cards, &board, 1).unwrap_err(),
+            EquityError::DuplicateCard(card(Rank::Ace, Suit::Hearts))
+        );
+        assert!(calc.calculate_until(&hole_cards, &Board::new(), 0, 0.95, 0.01).is_err());
+    }
+
+    #[test]
+    fn test_strata_share_exactly_the_samples_asked_for() {
+        let hero: HoleCards = "AhKh".parse().unwrap();
+        let board: Board = "Qh 7h 2c".parse().unwrap();
+        let request = EquityRequestBuilder::new(hero)
+            .with_board(board.clone())
+            .with_opponents(1)
+            .with_emphasis(RunoutConstraint::PairsBoard, 4.0);
+        let dealt: Vec<Card> = hero.cards().iter().chain(board.cards()).copied().collect();
+        let cards: Vec<Card> = Card::all_cards().filter(|card| !dealt.contains(card)).collect();
+
+        for samples in [94, 95, 1_000, 20_001] {
+            let strata = Stratum::split(&request, &cards, true, samples);
+            assert_eq!(strata.len(), 47);
+            let shares = Stratum::allocate(&strata, samples);
+            assert_eq!(shares.iter().sum::<u32>(), samples);
+            assert!(shares.iter().all(|&share| share >= Stratum::MIN_SAMPLES));
+        }
+
+        // Too few samples to deal every next card twice leave a single stratum
+        let strata = Stratum::split(&request, &cards, true, 93);
+        assert_eq!(strata.len(), 1);
+        assert_eq!(Stratum::allocate(&strata, 93), [93]);
+
+        let calc = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+        for samples in [50, 95] {
+            let result = request.clone().with_samples(samples).run(&calc).unwrap();
+            assert_eq!(result.samples(), u64::from(samples));
+        }
+    }
+
+    #[test]
+    fn test_compare_hands_shares_runouts() {
+        let calc = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+        let suited = HoleCards::new(
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::King, Suit::Hearts),
+        );
+        let offsuit = HoleCards::new(
+            card(Rank::Ace, Suit::Clubs),
+            card(Rank::King, Suit::Diamonds),
+        );
+        let board = make_board(vec![
+            card(Rank::Queen, Suit::Hearts),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Two, Suit::Clubs),
+        ]);
+        let options = ComparisonOptions::new().with_samples(5_000);
+
+        // A hand compared with itself differs on no runout
+        let same = calc.compare_hands(&suited, &suited, &board, &options).unwrap();
+        assert!(same.delta().abs() < f64::EPSILON);
+        assert!(same.std_error().abs() < f64::EPSILON);
+
+        // Paired differences vary less than two separate runs: about 60% of their
+        // standard error on a flush draw, as documented on `compare_hands`
+        let paired = calc.compare_hands(&suited, &offsuit, &board, &options).unwrap();
+        let first = calc.calculate_sampled(&suited, &board, 1, 5_000).unwrap();
+        let second = calc.calculate_sampled(&offsuit, &board, 1, 5_000).unwrap();
+        assert_eq!(paired.samples(), 5_000);
+        assert!(paired.std_error() < 0.65 * first.std_error().hypot(second.std_error()));
+        assert!((paired.first_equity() - first.equity()).abs() < 0.05);
+
+        let antithetic = calc
+            .compare_hands(&suited, &offsuit, &board, &options.with_antithetic())
+            .unwrap();
+        assert_eq!(antithetic.samples(), 5_000);
+        let spread = paired.std_error().hypot(antithetic.std_error());
+        assert!((antithetic.delta() - paired.delta()).abs() < 4.0 * spread);
+        assert!(antithetic.confidence_interval(0.95).0 > 0.0);
+    }
+
+    #[test]
+    fn test_compare_hands_rejects_board_overlap() {
+        let calc = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+        let aces = HoleCards::new(
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Ace, Suit::Hearts),
+        );
+        let kings = HoleCards::new(
+            card(Rank::King, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+        );
+        let board = make_board(vec![
+            card(Rank::King, Suit::Hearts),
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Seven, Suit::Diamonds),
+        ]);
+        let options = ComparisonOptions::new();
+        assert_eq!(
+            calc.compare_hands(&aces, &kings, &board, &options).unwrap_err(),
+            EquityError::DuplicateCard(card(Rank::King, Suit::Hearts))
+        );
+        assert!(calc.compare_hands(&aces, &kings, &Board::new(), &options.with_opponents(0)).is_err());
+    }
+}
@@ -0,0 +1,355 @@
+//! Precomputed heads-up preflop equities between starting hand classes.
+//!
+//! Exhaustive preflop enumeration runs over 1.7 million boards per matchup, so the
+//! crate ships a 169 x 169 class matrix estimated ahead of time and answers preflop
+//! queries with a lookup.
+
+use std::sync::OnceLock;
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_set::CardSet;
+use crate::core::domain::entities::hand_class::{HandClass, NUM_HAND_CLASSES};
+use crate::core::domain::entities::hand_range::HandRange;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::ports::inbound::HandEvaluator;
+
+/// Samples per matchup used to generate the shipped table.
+pub const SHIPPED_SAMPLES: u32 = 200_000;
+
+/// Shipped equities: the upper triangle of the matrix, row by row, as little-endian
+/// `u16` fractions of `u16::MAX`.
+static SHIPPED: &[u8] = include_bytes!("preflop_equity.bin");
+
+/// Number of matchups between two different classes.
+const NUM_MATCHUPS: usize = NUM_HAND_CLASSES * (NUM_HAND_CLASSES - 1) / 2;
+
+/// Cards left in the deck once both players hold their hole cards.
+const UNSEEN_CARDS: usize = 48;
+
+/// Heads-up all-in preflop equity of every starting hand class against every other.
+///
+/// Entries average over every pair of non-overlapping combinations of the two classes,
+/// so card removal between the classes is accounted for. The shipped table was sampled
+/// with `SHIPPED_SAMPLES` runouts per matchup, which puts entries within about a third
+/// of a percentage point of the exact values; regenerate it with the
+/// `preflop_equity_table` example.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::hand_class::HandClass;
+/// use riverrun::core::domain::services::equity::PreflopEquityTable;
+///
+/// let table = PreflopEquityTable::shared();
+/// let aces = "AA".parse::<HandClass>().unwrap();
+/// let kings = "KK".parse::<HandClass>().unwrap();
+///
+/// assert!((table.lookup(aces, kings) - 0.82).abs() < 0.01);
+/// assert!((table.vs_random(aces) - 0.85).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreflopEquityTable {
+    /// Equity of the row class against the column class, row-major by class index.
+    equities: Vec<f64>,
+    /// Equity of each class against a uniformly random hand, by class index.
+    vs_random: Vec<f64>,
+}
+
+/// `PreflopEquityTable` - Constructors
+impl PreflopEquityTable {
+    /// The table shipped with the crate, decoded on first use.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is corrupt, which the crate's tests rule out.
+    #[must_use]
+    pub fn shared() -> &'static Self {
+        static TABLE: OnceLock<PreflopEquityTable> = OnceLock::new();
+        TABLE.get_or_init(|| {
+            Self::from_bytes(SHIPPED).expect("shipped preflop equity table is well-formed")
+        })
+    }
+
+    /// Estimates every matchup with `samples` Monte Carlo runouts each.
+    ///
+    /// Sampling is deterministic, so the same evaluator and sample count always produce
+    /// the same table. This evaluates 14,196 matchups and takes a while.
+    #[must_use]
+    pub fn generate<E: HandEvaluator>(evaluator: &E, samples: u32) -> Self {
+        let mut upper = Vec::with_capacity(NUM_MATCHUPS);
+        for hero in HandClass::all() {
+            for villain in HandClass::all().skip(hero.index() + 1) {
+                upper.push(Self::estimate_matchup(evaluator, hero, villain, samples));
+            }
+        }
+        Self::from_upper_triangle(&upper)
+    }
+
+    /// Decodes a table written by `to_bytes`.
+    ///
+    /// # Returns
+    /// `None` if `bytes` is not the size of an encoded table.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != NUM_MATCHUPS * 2 {
+            return None;
+        }
+        let upper: Vec<f64> = bytes
+            .chunks_exact(2)
+            .map(|pair| f64::from(u16::from_le_bytes([pair[0], pair[1]])) / f64::from(u16::MAX))
+            .collect();
+        Some(Self::from_upper_triangle(&upper))
+    }
+
+    /// Builds the full matrix from the equities of each class against every later class.
+    fn from_upper_triangle(upper: &[f64]) -> Self {
+        let mut equities = vec![0.5; NUM_HAND_CLASSES * NUM_HAND_CLASSES];
+        let mut values = upper.iter().copied();
+        for hero in 0..NUM_HAND_CLASSES {
+            for villain in hero + 1..NUM_HAND_CLASSES {
+                let equity = values.next().unwrap_or(0.5);
+                equities[hero * NUM_HAND_CLASSES + villain] = equity;
+                equities[villain * NUM_HAND_CLASSES + hero] = 1.0 - equity;
+            }
+        }
+        let vs_random = vs_random_equities(&equities);
+        Self {
+            equities,
+            vs_random,
+        }
+    }
+}
+
+/// `PreflopEquityTable` - Accessors
+impl PreflopEquityTable {
+    /// Equity of `hero` against `villain`, between 0.0 and 1.0.
+    ///
+    /// A class against itself is exactly 0.5.
+    #[must_use]
+    pub fn lookup(&self, hero: HandClass, villain: HandClass) -> f64 {
+        self.equities[hero.index() * NUM_HAND_CLASSES + villain.index()]
+    }
+
+    /// Equity of `hero` against `villain` using the classes the hole cards belong to.
+    ///
+    /// Suit interactions between the two specific hands, such as sharing a suit, are
+    /// averaged out.
+    #[must_use]
+    pub fn lookup_hole_cards(&self, hero: &HoleCards, villain: &HoleCards) -> f64 {
+        self.lookup(
+            HandClass::from_hole_cards(hero),
+            HandClass::from_hole_cards(villain),
+        )
+    }
+
+    /// Equity of `hero` against `range`, weighting each combination by its range weight.
+    ///
+    /// Combinations sharing a card with `hero` are left out.
+    ///
+    /// # Returns
+    /// `None` if no combination of the range is left.
+    #[must_use]
+    pub fn lookup_range(&self, hero: &HoleCards, range: &HandRange) -> Option<f64> {
+        let (weighted, total) = range
+            .without(hero.cards())
+            .combos()
+            .fold((0.0, 0.0), |(weighted, total), (villain, weight)| {
+                (
+                    weight.mul_add(self.lookup_hole_cards(hero, &villain), weighted),
+                    total + weight,
+                )
+            });
+        (total > 0.0).then(|| weighted / total)
+    }
+
+    /// Equity of `hero` against a uniformly random hand.
+    ///
+    /// Each villain class is weighted by how many of its combinations remain once
+    /// hero's cards are removed.
+    #[must_use]
+    pub fn vs_random(&self, hero: HandClass) -> f64 {
+        self.vs_random[hero.index()]
+    }
+
+    /// Encodes the table for `from_bytes`, two bytes per matchup.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(NUM_MATCHUPS * 2);
+        for hero in 0..NUM_HAND_CLASSES {
+            for villain in hero + 1..NUM_HAND_CLASSES {
+                let equity = self.equities[hero * NUM_HAND_CLASSES + villain];
+                let scaled = (equity.clamp(0.0, 1.0) * f64::from(u16::MAX)).round() as u16;
+                bytes.extend_from_slice(&scaled.to_le_bytes());
+            }
+        }
+        bytes
+    }
+}
+
+/// `PreflopEquityTable` - Operations
+impl PreflopEquityTable {
+    /// Estimates the equity of `hero` against `villain` from `samples` random boards.
+    ///
+    /// Samples cycle through every non-overlapping pair of combinations so each pair is
+    /// weighted equally, and each sample deals a random five-card board.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn estimate_matchup<E: HandEvaluator>(
+        evaluator: &E,
+        hero: HandClass,
+        villain: HandClass,
+        samples: u32,
+    ) -> f64 {
+        let matchups: Vec<(HoleCards, HoleCards, [Card; UNSEEN_CARDS])> = hero
+            .combos()
+            .into_iter()
+            .flat_map(|hero| {
+                villain
+                    .combos()
+                    .into_iter()
+                    .filter(move |&villain| !overlaps(hero, villain))
+                    .map(move |villain| (hero, villain, unseen(hero, villain)))
+            })
+            .collect();
+        if matchups.is_empty() || samples == 0 {
+            return 0.5;
+        }
+
+        // Deterministic seed per matchup for reproducible tables
+        let mut seed = (hero.index() * NUM_HAND_CLASSES + villain.index()) as u64 ^ 0x9E37_79B9_7F4A_7C15;
+        // Two points per win and one per tie
+        let mut points = 0u64;
+        for sample in 0..samples as usize {
+            let (hero_cards, villain_cards, mut deck) = matchups[sample % matchups.len()];
+
+            // Partial Fisher-Yates shuffle using LCG
+            for i in 0..5 {
+                seed = seed
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                let j = i + ((seed >> 33) as usize % (UNSEEN_CARDS - i));
+                deck.swap(i, j);
+            }
+            let board = [deck[0], deck[1], deck[2], deck[3], deck[4]];
+
+            let hero_strength = evaluator.evaluate_7cards_fast(&hero_cards.combine_with_board(board));
+            let villain_strength =
+                evaluator.evaluate_7cards_fast(&villain_cards.combine_with_board(board));
+            // Lower strength is better
+            points += match hero_strength.cmp(&villain_strength) {
+                std::cmp::Ordering::Less => 2,
+                std::cmp::Ordering::Equal => 1,
+                std::cmp::Ordering::Greater => 0,
+            };
+        }
+        points as f64 / (2.0 * f64::from(samples))
+    }
+}
+
+/// Returns whether two hands share a card.
+fn overlaps(a: HoleCards, b: HoleCards) -> bool {
+    !CardSet::from_cards(a.cards()).is_disjoint(CardSet::from_cards(b.cards()))
+}
+
+/// The 48 cards held by neither hand.
+fn unseen(hero: HoleCards, villain: HoleCards) -> [Card; UNSEEN_CARDS] {
+    let mut cards = [hero.first(); UNSEEN_CARDS];
+    let held = CardSet::from_cards(hero.cards()) | CardSet::from_cards(villain.cards());
+    let remaining = (CardSet::full() - held).iter();
+    for (slot, card) in cards.iter_mut().zip(remaining) {
+        *slot = card;
+    }
+    cards
+}
+
+/// Weights each row of `equities` by the villain combinations left after card removal.
+#[allow(clippy::cast_precision_loss)]
+fn vs_random_equities(equities: &[f64]) -> Vec<f64> {
+    let combos: Vec<Vec<HoleCards>> = HandClass::all().map(|class| class.combos()).collect();
+    combos
+        .iter()
+        .enumerate()
+        .map(|(hero, hero_combos)| {
+            let mut weighted = 0.0;
+            let mut total = 0.0;
+            for (villain, villain_combos) in combos.iter().enumerate() {
+                let weight = hero_combos
+                    .iter()
+                    .map(|&hero| villain_combos.iter().filter(|&&villain| !overlaps(hero, villain)).count())
+                    .sum::<usize>() as f64;
+                weighted += weight * equities[hero * NUM_HAND_CLASSES + villain];
+                total += weight;
+            }
+            weighted / total
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::services::evaluation::PerfectHashEvaluator;
+
+    fn class(text: &str) -> HandClass {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_shipped_table_matches_known_equities() {
+        let table = PreflopEquityTable::shared();
+        let cases = [
+            ("AA", "KK", 0.820),
+            ("AKs", "QQ", 0.461),
+            ("AKo", "22", 0.470),
+            ("KQs", "AJo", 0.438),
+            ("87s", "AKo", 0.414),
+        ];
+        for (hero, villain, expected) in cases {
+            let equity = table.lookup(class(hero), class(villain));
+            assert!((equity - expected).abs() < 0.01, "{hero} vs {villain}: {equity}");
+            let reverse = table.lookup(class(villain), class(hero));
+            assert!((equity + reverse - 1.0).abs() < 1e-4);
+        }
+        assert!((table.lookup(class("T9s"), class("T9s")) - 0.5).abs() < f64::EPSILON);
+
+        assert!((table.vs_random(class("AA")) - 0.852).abs() < 0.01);
+        assert!((table.vs_random(class("72o")) - 0.346).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_lookup_range() {
+        let table = PreflopEquityTable::shared();
+        let hero = HoleCards::new(Card::from_string("As").unwrap(), Card::from_string("Ks").unwrap());
+        let kings: HandRange = "KK".parse().unwrap();
+        // Only the three king pairs without the king of spades are left
+        let expected = table.lookup(class("AKs"), class("KK"));
+        assert!((table.lookup_range(&hero, &kings).unwrap() - expected).abs() < 1e-9);
+
+        let mixed: HandRange = "QQ, 72o:0.5".parse().unwrap();
+        let equity = table.lookup_range(&hero, &mixed).unwrap();
+        let queens = table.lookup(class("AKs"), class("QQ"));
+        let junk = table.lookup(class("AKs"), class("72o"));
+        // 6 queen combos at full weight and 12 junk combos at half weight
+        assert!((equity - f64::midpoint(queens, junk)).abs() < 1e-9);
+
+        let blocked: HandRange = "AsKs".parse().unwrap();
+        assert_eq!(table.lookup_range(&hero, &blocked), None);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let table = PreflopEquityTable::shared();
+        let bytes = table.to_bytes();
+        assert_eq!(bytes, SHIPPED);
+        assert!(PreflopEquityTable::from_bytes(&bytes[1..]).is_none());
+    }
+
+    #[test]
+    fn test_estimate_matchup_agrees_with_table() {
+        let evaluator = PerfectHashEvaluator::new();
+        let (hero, villain) = (class("JJ"), class("AKs"));
+        let estimate = PreflopEquityTable::estimate_matchup(&evaluator, hero, villain, 20_000);
+        let shipped = PreflopEquityTable::shared().lookup(hero, villain);
+        assert!((estimate - shipped).abs() < 0.02, "{estimate} vs {shipped}");
+    }
+}
@@ -0,0 +1,76 @@
+//! Progress reporting and cancellation shared by the equity calculators.
+
+use crate::core::ports::inbound::{CancellationToken, EquityProgress};
+
+/// Progress callback and cancellation state threaded through calculation loops.
+///
+/// A silent reporter (no callback, no token) is used by the plain `calculate` paths so
+/// that every calculator has a single enumeration implementation.
+pub struct ProgressReporter<'a> {
+    callback: Option<&'a mut dyn FnMut(EquityProgress)>,
+    cancel: Option<&'a CancellationToken>,
+    completed: u64,
+    total: u64,
+}
+
+/// `ProgressReporter` - Constructors
+impl<'a> ProgressReporter<'a> {
+    /// Create a reporter that neither reports progress nor observes cancellation.
+    pub const fn silent() -> Self {
+        Self {
+            callback: None,
+            cancel: None,
+            completed: 0,
+            total: 0,
+        }
+    }
+
+    /// Create a reporter forwarding updates to `callback` and observing `cancel`.
+    pub fn new(
+        callback: &'a mut dyn FnMut(EquityProgress),
+        cancel: &'a CancellationToken,
+    ) -> Self {
+        Self {
+            callback: Some(callback),
+            cancel: Some(cancel),
+            completed: 0,
+            total: 0,
+        }
+    }
+}
+
+/// `ProgressReporter` - Operations
+impl ProgressReporter<'_> {
+    /// Set the total amount of work and report that nothing has completed yet.
+    pub fn start(&mut self, total: u64) {
+        self.total = total;
+        self.completed = 0;
+        self.report();
+    }
+
+    /// Record `units` of completed work and report the new progress.
+    pub fn advance(&mut self, units: u64) {
+        self.completed = (self.completed + units).min(self.total);
+        self.report();
+    }
+
+    /// Mark all work as completed and report it.
+    pub fn finish(&mut self) {
+        self.completed = self.total;
+        self.report();
+    }
+
+    /// Whether the caller has requested cancellation.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_some_and(CancellationToken::is_cancelled)
+    }
+
+    fn report(&mut self) {
+        if let Some(callback) = self.callback.as_mut() {
+            callback(EquityProgress {
+                completed: self.completed,
+                total: self.total,
+            });
+        }
+    }
+}
@@ -0,0 +1,389 @@
+//! Builder for Monte Carlo equity runs.
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hand_range::HandRange;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::services::analysis::RunoutConstraint;
+use crate::core::ports::inbound::{EquityError, EquityResult, HandEvaluator};
+use crate::core::ports::outbound::{CacheKey, CacheKeyBuilder};
+
+use super::monte_carlo::MonteCarloEquityCalculator;
+
+/// Everything a Monte Carlo equity run can be configured with, gathered in one place
+/// instead of positional arguments.
+///
+/// Opponents either hold random cards, added with `with_opponents`, or a hand from a
+/// range, added with `with_range`. Unless configured otherwise, a run draws the
+/// calculator's default sample count on one thread, seeded from hero's cards and the
+/// board, so it reproduces `calculate_sampled` exactly.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::services::equity::EquityRequestBuilder;
+/// use riverrun::core::domain::services::{CactusKevEvaluator, MonteCarloEquityCalculator};
+///
+/// let calculator = MonteCarloEquityCalculator::new(CactusKevEvaluator::new());
+/// let result = EquityRequestBuilder::new("AsKs".parse().unwrap())
+///     .with_board("Qs 7s 2c".parse().unwrap())
+///     .with_range("QQ+, AK".parse().unwrap())
+///     .with_opponents(1)
+///     .with_seed(42)
+///     .with_threads(2)
+///     .with_samples(20_000)
+///     .run(&calculator)
+///     .unwrap();
+/// assert_eq!(result.samples(), 20_000);
+/// assert!((0.2..0.6).contains(&result.equity()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct EquityRequestBuilder {
+    pub(super) hero: HoleCards,
+    pub(super) board: Board,
+    pub(super) opponents: usize,
+    pub(super) ranges: Vec<HandRange>,
+    pub(super) dead: Vec<Card>,
+    pub(super) samples: Option<u32>,
+    pub(super) seed: Option<u64>,
+    pub(super) precision: Option<(f64, f64)>,
+    pub(super) threads: usize,
+    pub(super) stratified: bool,
+    pub(super) emphasis: Option<(RunoutConstraint, f64)>,
+}
+
+/// `EquityRequestBuilder` - Constructors
+impl EquityRequestBuilder {
+    /// A request for `hero`'s equity preflop, with no opponents yet.
+    #[must_use]
+    pub const fn new(hero: HoleCards) -> Self {
+        Self {
+            hero,
+            board: Board::new(),
+            opponents: 0,
+            ranges: Vec::new(),
+            dead: Vec::new(),
+            samples: None,
+            seed: None,
+            precision: None,
+            threads: 1,
+            stratified: false,
+            emphasis: None,
+        }
+    }
+
+    /// This request on `board`.
+    #[must_use]
+    pub fn with_board(mut self, board: Board) -> Self {
+        self.board = board;
+        self
+    }
+
+    /// This request with `count` more opponents holding random cards.
+    #[must_use]
+    pub const fn with_opponents(mut self, count: usize) -> Self {
+        self.opponents += count;
+        self
+    }
+
+    /// This request with one more opponent, holding a hand from `range` drawn by weight.
+    #[must_use]
+    pub fn with_range(mut self, range: HandRange) -> Self {
+        self.ranges.push(range);
+        self
+    }
+
+    /// This request with one more opponent for each of `ranges`.
+    #[must_use]
+    pub fn with_ranges(mut self, ranges: impl IntoIterator<Item = HandRange>) -> Self {
+        self.ranges.extend(ranges);
+        self
+    }
+
+    /// This request with `cards` removed from the deck, e.g. folded or burned cards.
+    #[must_use]
+    pub fn with_dead_cards(mut self, cards: &[Card]) -> Self {
+        self.dead.extend_from_slice(cards);
+        self
+    }
+
+    /// This request drawing `samples` samples instead of the calculator's default.
+    ///
+    /// Ignored when a target precision is set.
+    #[must_use]
+    pub const fn with_samples(mut self, samples: u32) -> Self {
+        self.samples = Some(samples);
+        self
+    }
+
+    /// This request seeded with `seed`, for runs that differ between calls but can be
+    /// replayed.
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// This request sampling until the equity is known to within `margin` at
+    /// `confidence`, as `calculate_until` does.
+    #[must_use]
+    pub const fn with_precision(mut self, confidence: f64, margin: f64) -> Self {
+        self.precision = Some((confidence, margin));
+        self
+    }
+
+    /// This request sampling on `threads` threads, at least one.
+    ///
+    /// Each thread draws its own share of the samples from its own random sequence, so
+    /// the result depends on the thread count but is still reproducible.
+    #[must_use]
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// This request drawing its samples evenly over every possible next board card,
+    /// rather than leaving the split to chance, so small edges converge faster.
+    ///
+    /// Ignored on the river and with ranged opponents, whose hands make some next cards
+    /// likelier than others, and when there are too few samples to draw two after
+    /// every next card.
+    #[must_use]
+    pub const fn with_stratification(mut self) -> Self {
+        self.stratified = true;
+        self
+    }
+
+    /// This request stratified, drawing `factor` times as many samples after each next
+    /// card that satisfies `constraint`, such as `RunoutConstraint::PairsBoard`.
+    ///
+    /// Every next card keeps its true probability when the results are combined, so
+    /// the equity stays unbiased while rare runouts are measured more precisely.
+    #[must_use]
+    pub const fn with_emphasis(mut self, constraint: RunoutConstraint, factor: f64) -> Self {
+        self.stratified = true;
+        self.emphasis = Some((constraint, factor));
+        self
+    }
+}
+
+/// `EquityRequestBuilder` - Accessors
+impl EquityRequestBuilder {
+    /// Hero's hole cards.
+    #[must_use]
+    pub const fn hero(&self) -> &HoleCards {
+        &self.hero
+    }
+
+    /// The board dealt so far.
+    #[must_use]
+    pub const fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Number of opponents, random and ranged.
+    #[must_use]
+    pub const fn num_opponents(&self) -> usize {
+        self.opponents + self.ranges.len()
+    }
+}
+
+/// `EquityRequestBuilder` - Caching
+impl EquityRequestBuilder {
+    /// Key identifying the result of running this request with `calculator`, for
+    /// storing it in a `ResultCache`.
+    ///
+    /// The key covers every setting, the calculator's default sample count when none is
+    /// set, and its evaluator's version. Requests differing only in the order of hero's
+    /// cards, the board, or the dead cards share a key, since they estimate the same
+    /// equity.
+    ///
+    /// # Returns
+    /// `None` when the request emphasises a `RunoutConstraint::Custom`, whose test
+    /// cannot be hashed.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn cache_key<E: HandEvaluator>(
+        &self,
+        calculator: &MonteCarloEquityCalculator<E>,
+    ) -> Option<CacheKey> {
+        let emphasis = match self.emphasis {
+            None => 0,
+            Some((RunoutConstraint::CompletesFlush, _)) => 1,
+            Some((RunoutConstraint::CompletesStraight, _)) => 2,
+            Some((RunoutConstraint::PairsBoard, _)) => 3,
+            Some((RunoutConstraint::Overcard, _)) => 4,
+            Some((RunoutConstraint::Blank, _)) => 5,
+            Some((RunoutConstraint::Custom(_), _)) => return None,
+        };
+        let cards = |key: CacheKeyBuilder, cards: &[Card]| {
+            let mut indexes: Vec<u8> = cards.iter().map(|card| card.index() as u8).collect();
+            indexes.sort_unstable();
+            key.bytes(&indexes)
+        };
+
+        let mut key = CacheKey::builder("equity").u32(calculator.evaluator().version());
+        key = cards(key, self.hero.cards());
+        key = cards(key, self.board.cards());
+        key = cards(key, &self.dead);
+        key = key.u64(self.ranges.len() as u64);
+        for range in &self.ranges {
+            key = key.u64(range.combo_count() as u64);
+            for (combo, weight) in range.combos() {
+                key = cards(key, combo.cards()).f64(weight);
+            }
+        }
+        key = key
+            .u64(self.opponents as u64)
+            .u32(self.samples.unwrap_or_else(|| calculator.default_samples()))
+            .bool(self.seed.is_some())
+            .u64(self.seed.unwrap_or_default())
+            .bool(self.precision.is_some());
+        let (confidence, margin) = self.precision.unwrap_or_default();
+        Some(
+            key.f64(confidence)
+                .f64(margin)
+                .u64(self.threads as u64)
+                .bool(self.stratified)
+                .u32(emphasis)
+                .f64(self.emphasis.map_or(0.0, |(_, factor)| factor))
+                .finish(),
+        )
+    }
+}
+
+/// `EquityRequestBuilder` - Operations
+impl EquityRequestBuilder {
+    /// Runs this request with `calculator`.
+    ///
+    /// # Errors
+    /// Returns `EquityError::DuplicateCard` if hero, the board, and the dead cards share
+    /// a card, `EquityError::NotEnoughCards` if the deck cannot complete the deal, and
+    /// `EquityError::UnsupportedConfiguration` without opponents, or when a range has no
+    /// hand left that can be dealt.
+    pub fn run<E: HandEvaluator>(
+        &self,
+        calculator: &MonteCarloEquityCalculator<E>,
+    ) -> Result<EquityResult, EquityError> {
+        calculator.run(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::services::equity::ExhaustiveEquityCalculator;
+    use crate::core::domain::services::evaluation::CactusKevEvaluator;
+    use crate::core::ports::inbound::EquityCalculator;
+
+    fn calculator() -> MonteCarloEquityCalculator<CactusKevEvaluator> {
+        MonteCarloEquityCalculator::with_samples(CactusKevEvaluator::new(), 5_000)
+    }
+
+    fn card(text: &str) -> Card {
+        Card::from_string(text).unwrap()
+    }
+
+    #[test]
+    fn test_defaults_match_calculate_sampled() {
+        let hero: HoleCards = "JhTh".parse().unwrap();
+        let flop: Board = "9h 8c 2h".parse().unwrap();
+        let calculator = calculator();
+
+        let built = EquityRequestBuilder::new(hero)
+            .with_board(flop.clone())
+            .with_opponents(2)
+            .run(&calculator)
+            .unwrap();
+        let sampled = calculator.calculate_sampled(&hero, &flop, 2, 5_000).unwrap();
+        assert_eq!(built.samples(), 5_000);
+        assert!((built.equity() - sampled.equity()).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ranges_and_dead_cards_shape_the_deal() {
+        let hero: HoleCards = "KhKd".parse().unwrap();
+        let request = EquityRequestBuilder::new(hero).with_seed(7);
+
+        // Only aces remain in the opponent's range once the queens are dead
+        let against_aces = request
+            .clone()
+            .with_range("AA, QQ".parse().unwrap())
+            .with_dead_cards(&[card("Qs"), card("Qh"), card("Qd"), card("Qc")])
+            .run(&calculator())
+            .unwrap();
+        assert!((0.15..0.25).contains(&against_aces.equity()));
+
+        let against_queens = request
+            .with_range("QQ".parse().unwrap())
+            .run(&calculator())
+            .unwrap();
+        assert!(against_queens.equity() > 0.75);
+    }
+
+    #[test]
+    fn test_seed_threads_and_precision() {
+        let hero: HoleCards = "7c7d".parse().unwrap();
+        let request = EquityRequestBuilder::new(hero).with_opponents(1).with_seed(3);
+
+        let first = request.clone().with_threads(4).run(&calculator()).unwrap();
+        let again = request.clone().with_threads(4).run(&calculator()).unwrap();
+        assert_eq!(first.samples(), 5_000);
+        assert!((first.equity() - again.equity()).abs() < f64::EPSILON);
+
+        let precise = request.with_precision(0.95, 0.01).run(&calculator()).unwrap();
+        assert!(precise.margin_of_error(0.95) <= 0.01);
+        assert!((precise.equity() - 0.66).abs() < 0.03);
+    }
+
+    #[test]
+    fn test_stratification_stays_unbiased() {
+        let hero: HoleCards = "AhKh".parse().unwrap();
+        let flop: Board = "Qh 7h 2c".parse().unwrap();
+        let exact = ExhaustiveEquityCalculator::new(CactusKevEvaluator::new())
+            .calculate(&hero, &flop, 1)
+            .unwrap();
+        let request = EquityRequestBuilder::new(hero)
+            .with_board(flop)
+            .with_opponents(1)
+            .with_samples(20_000);
+
+        let plain = request.run(&calculator()).unwrap();
+        let stratified = request.clone().with_stratification().run(&calculator()).unwrap();
+        assert_eq!(stratified.samples(), 20_000);
+        assert!(stratified.std_error() < plain.std_error());
+        assert!((stratified.equity() - exact.equity()).abs() < 3.0 * stratified.std_error());
+
+        let emphasised = request
+            .with_emphasis(RunoutConstraint::PairsBoard, 4.0)
+            .run(&calculator())
+            .unwrap();
+        assert!((emphasised.equity() - exact.equity()).abs() < 3.0 * emphasised.std_error());
+    }
+
+    #[test]
+    fn test_impossible_requests_are_rejected() {
+        let hero: HoleCards = "AhAd".parse().unwrap();
+        let request = EquityRequestBuilder::new(hero);
+        assert!(matches!(
+            request.run(&calculator()),
+            Err(EquityError::UnsupportedConfiguration(_))
+        ));
+        assert!(matches!(
+            request.clone().with_opponents(1).with_dead_cards(&[card("Ah")]).run(&calculator()),
+            Err(EquityError::DuplicateCard(dead)) if dead == card("Ah")
+        ));
+        // Two opponents cannot both hold the last two aces
+        assert!(matches!(
+            request
+                .clone()
+                .with_ranges(["AA".parse().unwrap(), "AA".parse().unwrap()])
+                .run(&calculator()),
+            Err(EquityError::UnsupportedConfiguration(_))
+        ));
+        assert!(matches!(
+            request.with_range("AhKh".parse().unwrap()).run(&calculator()),
+            Err(EquityError::UnsupportedConfiguration(_))
+        ));
+    }
+}
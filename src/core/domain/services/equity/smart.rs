@@ -0,0 +1,274 @@
+//! Equity calculator choosing between exact enumeration and Monte Carlo sampling.
+//!
+//! Enumeration is exact but its cost grows quickly with unseen board cards and
+//! opponents, so it is only used when the number of hands to evaluate is small.
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::ports::inbound::{
+    CancellationToken, EquityCalculator, EquityError, EquityProgress, EquityResult, HandEvaluator,
+};
+
+use super::super::utils::binomial;
+use super::exhaustive::{ExhaustiveEquityCalculator, MAX_EXACT_OPPONENTS};
+use super::monte_carlo::MonteCarloEquityCalculator;
+
+/// Default most showdowns enumerated before falling back to Monte Carlo.
+///
+/// Covers the river and turn heads-up; a heads-up flop needs about a million.
+pub const DEFAULT_MAX_EXACT_COST: u64 = 100_000;
+
+/// How `SmartEquityCalculator` will run a calculation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EquityBackend {
+    /// Exact enumeration of every runout and opponent hand.
+    Exhaustive,
+    /// Monte Carlo sampling.
+    MonteCarlo {
+        /// Samples to draw.
+        samples: u32,
+    },
+}
+
+/// Equity calculator that enumerates exactly when that is cheap and samples otherwise.
+///
+/// The cost of enumeration is estimated from the street and number of opponents as
+/// the number of showdowns to evaluate. Up to `max_exact_cost` of them are enumerated
+/// with `ExhaustiveEquityCalculator`; anything larger, or anything enumeration cannot
+/// handle such as multiway preflop, is sampled with `MonteCarloEquityCalculator`.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::board::Board;
+/// use riverrun::core::domain::entities::hole_cards::HoleCards;
+/// use riverrun::core::domain::services::CactusKevEvaluator;
+/// use riverrun::core::domain::services::equity::{EquityBackend, SmartEquityCalculator};
+/// use riverrun::core::ports::inbound::EquityCalculator;
+///
+/// let cards = |text: &str| -> Vec<_> {
+///     text.split_whitespace().map(|c| c.parse().unwrap()).collect()
+/// };
+/// let hole = cards("As Ks");
+/// let hole = HoleCards::new(hole[0], hole[1]);
+/// let turn = Board::with_cards(cards("Qs 7s 2c 9d")).unwrap();
+///
+/// let calculator = SmartEquityCalculator::new(CactusKevEvaluator::new());
+/// assert_eq!(calculator.backend(&turn, 1), EquityBackend::Exhaustive);
+/// assert!(matches!(calculator.backend(&Board::new(), 3), EquityBackend::MonteCarlo { .. }));
+///
+/// // 46 rivers against every pair of the 45 cards left
+/// assert_eq!(calculator.calculate(&hole, &turn, 1).unwrap().samples(), 46 * 990);
+/// ```
+pub struct SmartEquityCalculator<E: HandEvaluator> {
+    exhaustive: ExhaustiveEquityCalculator<E>,
+    monte_carlo: MonteCarloEquityCalculator<E>,
+    max_exact_cost: u64,
+}
+
+/// `SmartEquityCalculator` - Constructors
+impl<E: HandEvaluator + Clone> SmartEquityCalculator<E> {
+    /// Create a calculator using `evaluator` for both backends, with the default cost
+    /// limit and sample count.
+    pub fn new(evaluator: E) -> Self {
+        Self {
+            exhaustive: ExhaustiveEquityCalculator::new(evaluator.clone()),
+            monte_carlo: MonteCarloEquityCalculator::new(evaluator),
+            max_exact_cost: DEFAULT_MAX_EXACT_COST,
+        }
+    }
+
+    /// This calculator drawing `samples` samples whenever it falls back to Monte Carlo.
+    #[must_use]
+    pub fn with_samples(mut self, samples: u32) -> Self {
+        let evaluator = self.monte_carlo.evaluator().clone();
+        self.monte_carlo = MonteCarloEquityCalculator::with_samples(evaluator, samples);
+        self
+    }
+}
+
+/// `SmartEquityCalculator` - Builders
+impl<E: HandEvaluator> SmartEquityCalculator<E> {
+    /// This calculator enumerating up to `max_exact_cost` showdowns before sampling.
+    #[must_use]
+    pub const fn with_max_exact_cost(mut self, max_exact_cost: u64) -> Self {
+        self.max_exact_cost = max_exact_cost;
+        self
+    }
+}
+
+/// `SmartEquityCalculator` - Accessors
+impl<E: HandEvaluator> SmartEquityCalculator<E> {
+    /// Most showdowns enumerated before falling back to Monte Carlo.
+    pub const fn max_exact_cost(&self) -> u64 {
+        self.max_exact_cost
+    }
+
+    /// Samples drawn when falling back to Monte Carlo.
+    pub const fn samples(&self) -> u32 {
+        self.monte_carlo.default_samples()
+    }
+}
+
+/// `SmartEquityCalculator` - Operations
+impl<E: HandEvaluator> SmartEquityCalculator<E> {
+    /// Backend used for `num_opponents` opponents on `board`.
+    pub fn backend(&self, board: &Board, num_opponents: usize) -> EquityBackend {
+        self.backend_sampling(board, num_opponents, self.samples())
+    }
+
+    /// Backend used when Monte Carlo would draw `samples` samples.
+    fn backend_sampling(&self, board: &Board, num_opponents: usize, samples: u32) -> EquityBackend {
+        match enumeration_cost(board.len(), num_opponents) {
+            Some(cost) if cost <= self.max_exact_cost => EquityBackend::Exhaustive,
+            _ => EquityBackend::MonteCarlo { samples },
+        }
+    }
+}
+
+/// Number of showdowns exact enumeration evaluates with `board_cards` cards on the
+/// board against `num_opponents` opponents, or `None` when enumeration is not
+/// supported.
+///
+/// Each completion of the board is played against every way of dealing each
+/// opponent two of the cards left.
+#[must_use]
+pub fn enumeration_cost(board_cards: usize, num_opponents: usize) -> Option<u64> {
+    if board_cards > 5
+        || num_opponents == 0
+        || num_opponents > MAX_EXACT_OPPONENTS
+        || (board_cards < 3 && num_opponents > 1)
+    {
+        return None;
+    }
+    let unseen = 52 - 2 - board_cards;
+    let missing = 5 - board_cards;
+    let mut cost = binomial(unseen, missing) as u64;
+    for opponent in 0..num_opponents {
+        let left = unseen.checked_sub(missing + 2 * opponent)?;
+        cost = cost.saturating_mul(binomial(left, 2) as u64);
+    }
+    Some(cost)
+}
+
+impl<E: HandEvaluator> EquityCalculator for SmartEquityCalculator<E> {
+    fn calculate(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+        num_opponents: usize,
+    ) -> Result<EquityResult, EquityError> {
+        self.calculate_sampled(hole_cards, board, num_opponents, self.samples())
+    }
+
+    /// Enumerates exactly when cheap, ignoring `samples`, and otherwise draws `samples`
+    /// Monte Carlo samples.
+    fn calculate_sampled(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+        num_opponents: usize,
+        samples: u32,
+    ) -> Result<EquityResult, EquityError> {
+        match self.backend_sampling(board, num_opponents, samples) {
+            EquityBackend::Exhaustive => {
+                self.exhaustive.calculate(hole_cards, board, num_opponents)
+            }
+            EquityBackend::MonteCarlo { samples } => {
+                self.monte_carlo
+                    .calculate_sampled(hole_cards, board, num_opponents, samples)
+            }
+        }
+    }
+
+    /// Runs the chosen backend, which reports progress and honours cancellation.
+    fn calculate_with_progress(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+        num_opponents: usize,
+        progress: &mut dyn FnMut(EquityProgress),
+        cancel: &CancellationToken,
+    ) -> Result<EquityResult, EquityError> {
+        match self.backend(board, num_opponents) {
+            EquityBackend::Exhaustive => self.exhaustive.calculate_with_progress(
+                hole_cards,
+                board,
+                num_opponents,
+                progress,
+                cancel,
+            ),
+            EquityBackend::MonteCarlo { .. } => self.monte_carlo.calculate_with_progress(
+                hole_cards,
+                board,
+                num_opponents,
+                progress,
+                cancel,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::card::Card;
+    use crate::core::domain::services::equity::DEFAULT_SAMPLES;
+    use crate::core::domain::services::evaluation::CactusKevEvaluator;
+
+    fn cards(text: &str) -> Vec<Card> {
+        text.split_whitespace()
+            .map(|card| Card::from_string(card).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_enumeration_cost() {
+        // On the river only the opponent's 990 hands are left to deal
+        assert_eq!(enumeration_cost(5, 1), Some(990));
+        assert_eq!(enumeration_cost(4, 1), Some(46 * 990));
+        assert_eq!(enumeration_cost(3, 1), Some(1081 * 990));
+        assert_eq!(enumeration_cost(5, 2), Some(990 * 903));
+        assert_eq!(enumeration_cost(0, 2), None);
+        assert_eq!(enumeration_cost(5, 0), None);
+        assert_eq!(enumeration_cost(5, MAX_EXACT_OPPONENTS + 1), None);
+    }
+
+    #[test]
+    fn test_dispatches_by_cost() {
+        let calculator = SmartEquityCalculator::new(CactusKevEvaluator::new()).with_samples(2_000);
+        let hole = cards("Ah Kh");
+        let hole = HoleCards::new(hole[0], hole[1]);
+        let flop = Board::with_cards(cards("Qh 7h 2c")).unwrap();
+
+        assert_eq!(
+            calculator.backend(&flop, 1),
+            EquityBackend::MonteCarlo { samples: 2_000 }
+        );
+        assert_eq!(
+            calculator.calculate(&hole, &flop, 1).unwrap().samples(),
+            2_000
+        );
+
+        // Raising the limit enumerates the flop exactly
+        let exact = calculator.with_max_exact_cost(2_000_000);
+        assert_eq!(exact.backend(&flop, 1), EquityBackend::Exhaustive);
+        assert_eq!(
+            exact.calculate(&hole, &flop, 1).unwrap().samples(),
+            1081 * 990
+        );
+    }
+
+    #[test]
+    fn test_sampled_count_is_used_for_monte_carlo() {
+        let calculator = SmartEquityCalculator::new(CactusKevEvaluator::new());
+        let hole = cards("8s 8h");
+        let hole = HoleCards::new(hole[0], hole[1]);
+
+        let result = calculator
+            .calculate_sampled(&hole, &Board::new(), 3, 1_500)
+            .unwrap();
+        assert_eq!(result.samples(), 1_500);
+        assert_eq!(calculator.samples(), DEFAULT_SAMPLES);
+    }
+}
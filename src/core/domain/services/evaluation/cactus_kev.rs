@@ -4,33 +4,49 @@
 //! - Flush hands: O(1) lookup via rank bits
 //! - Non-flush hands: O(log n) binary search via prime product
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use crate::core::domain::entities::card::Card;
 use crate::core::domain::entities::hand::Hand;
 use crate::core::ports::inbound::HandEvaluator;
 
 use super::super::utils::FIVE_FROM_SEVEN;
 use super::hand_rank_tables::HandRankTables;
+#[cfg(feature = "simd")]
+use super::simd;
 
 /// Hand evaluator using Cactus Kev's prime product algorithm.
+///
+/// Evaluators built with `new` share one process-wide copy of the lookup tables, so
+/// constructing one per request is cheap.
+#[derive(Clone)]
 pub struct CactusKevEvaluator {
-    tables: HandRankTables,
+    tables: Arc<HandRankTables>,
 }
 
 /// `CactusKevEvaluator` - Constructors
 impl CactusKevEvaluator {
-    /// Constructs a `CactusKevEvaluator` initialized with the default precomputed hand-rank tables.
+    /// Constructs a `CactusKevEvaluator` using the shared precomputed hand-rank tables.
+    ///
+    /// The tables are built by the first call and reused afterwards; see
+    /// `HandRankTables::shared`.
     #[must_use] 
     pub fn new() -> Self {
-        Self {
-            tables: HandRankTables::new(),
-        }
+        Self::with_shared_tables(Arc::clone(HandRankTables::shared_arc()))
     }
 
     /// Create an evaluator that uses the provided precomputed hand rank tables.
     ///
     /// The `tables` argument supplies the precomputed lookup data used for fast hand evaluation.
     #[must_use] 
-    pub const fn with_tables(tables: HandRankTables) -> Self {
+    pub fn with_tables(tables: HandRankTables) -> Self {
+        Self::with_shared_tables(Arc::new(tables))
+    }
+
+    /// Create an evaluator over tables that may be shared with other evaluators.
+    #[must_use]
+    pub const fn with_shared_tables(tables: Arc<HandRankTables>) -> Self {
         Self { tables }
     }
 }
@@ -41,7 +57,7 @@ impl CactusKevEvaluator {
     ///
     /// Returns a reference to the underlying `HandRankTables`. 
     #[must_use] 
-    pub const fn tables(&self) -> &HandRankTables {
+    pub fn tables(&self) -> &HandRankTables {
         &self.tables
     }
 }
@@ -100,26 +116,7 @@ impl HandEvaluator for CactusKevEvaluator {
     /// Panics if the prime-product lookup does not yield a known hand rank.
     #[inline]
     fn evaluate_5cards_fast(&self, cards: &[Card; 5]) -> u16 {
-        let suit_and = cards[0].0 & cards[1].0 & cards[2].0 & cards[3].0 & cards[4].0 & 0xF000;
-
-        if suit_and != 0 {
-            let rank_bits = cards[0].rank_bits()
-                | cards[1].rank_bits()
-                | cards[2].rank_bits()
-                | cards[3].rank_bits()
-                | cards[4].rank_bits();
-            return self.tables.lookup_flush(rank_bits);
-        }
-
-        let prime_product = cards[0].prime()
-            * cards[1].prime()
-            * cards[2].prime()
-            * cards[3].prime()
-            * cards[4].prime();
-
-        self.tables
-            .lookup_unique(prime_product)
-            .unwrap_or_else(|| panic!("Invalid hand with prime product: {prime_product}"))
+        self.tables.lookup5(cards)
     }
 
     /// Evaluates seven cards by testing every five-card combination and returning the best hand rank.
@@ -130,6 +127,9 @@ impl HandEvaluator for CactusKevEvaluator {
     /// # Returns
     ///
     /// `u16` containing the best hand rank found; lower values represent stronger hands (1 is a royal flush).
+    ///
+    /// With the `simd` feature, eight combinations are prepared at a time in SIMD lanes.
+    #[cfg(not(feature = "simd"))]
     fn evaluate_7cards_fast(&self, cards: &[Card; 7]) -> u16 {
         let mut best_rank = u16::MAX;
 
@@ -155,6 +155,86 @@ impl HandEvaluator for CactusKevEvaluator {
 
         best_rank
     }
+
+    /// Evaluates seven cards with the 21 combinations prepared in SIMD lanes.
+    #[cfg(feature = "simd")]
+    fn evaluate_7cards_fast(&self, cards: &[Card; 7]) -> u16 {
+        simd::best_of_seven(&self.tables, cards)
+    }
+
+    /// Evaluates each hand with a flush-aware path that avoids the per-combination suit
+    /// check of `evaluate_7cards_fast`.
+    ///
+    /// A hand without a flush is worth the same as any other with the same seven ranks,
+    /// so those results are cached by rank for the rest of the batch and each distinct
+    /// set of ranks is only looked up once.
+    fn evaluate_batch(&self, hands: &[[Card; 7]]) -> Vec<u16> {
+        let mut by_ranks: HashMap<u64, u16> = HashMap::new();
+        hands
+            .iter()
+            .map(|cards| {
+                if let Some(strength) = self.flush_strength(cards) {
+                    return strength;
+                }
+                let key = cards
+                    .iter()
+                    .fold(1u64, |key, card| key * u64::from(card.prime()));
+                *by_ranks
+                    .entry(key)
+                    .or_insert_with(|| self.non_flush_strength(cards))
+            })
+            .collect()
+    }
+}
+
+/// `CactusKevEvaluator` - Batch Evaluation
+impl CactusKevEvaluator {
+    /// Best flush strength among seven cards, or `None` if fewer than five share a suit.
+    ///
+    /// With five or more cards of one suit, the other two cards cannot make a full house
+    /// or quads, so the best hand is the best five-card subset of that suit.
+    fn flush_strength(&self, cards: &[Card; 7]) -> Option<u16> {
+        let mut suit_counts = [0u8; 4];
+        for card in cards {
+            suit_counts[card.suit() as usize] += 1;
+        }
+        let suit = suit_counts.iter().position(|&count| count >= 5)?;
+
+        let mut suited = [0u32; 7];
+        let mut count = 0;
+        for card in cards.iter().filter(|card| card.suit() as usize == suit) {
+            suited[count] = card.rank_bits();
+            count += 1;
+        }
+        // Combinations of seven indices that stay below `count` choose among the suited cards
+        FIVE_FROM_SEVEN
+            .iter()
+            .filter(|combo| combo[4] < count)
+            .map(|combo| {
+                let rank_bits = combo.iter().fold(0, |bits, &i| bits | suited[i]);
+                self.tables.lookup_flush(rank_bits)
+            })
+            .min()
+    }
+
+    /// Best strength among seven cards known not to contain a flush.
+    fn non_flush_strength(&self, cards: &[Card; 7]) -> u16 {
+        let primes = cards.map(|card| card.prime());
+        let mut best_rank = u16::MAX;
+        for combo in FIVE_FROM_SEVEN {
+            let prime_product = primes[combo[0]]
+                * primes[combo[1]]
+                * primes[combo[2]]
+                * primes[combo[3]]
+                * primes[combo[4]];
+            let rank = self
+                .tables
+                .lookup_unique(prime_product)
+                .unwrap_or_else(|| panic!("Invalid hand with prime product: {prime_product}"));
+            best_rank = best_rank.min(rank);
+        }
+        best_rank
+    }
 }
 
 #[cfg(test)]
@@ -338,6 +418,43 @@ mod tests {
         assert!(hand.is_royal_flush());
     }
 
+    #[test]
+    fn test_batch_matches_scalar() {
+        use rand::SeedableRng;
+        use rand::seq::SliceRandom;
+
+        let evaluator = CactusKevEvaluator::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let mut deck: Vec<Card> = Card::all_cards().collect();
+        let mut hands: Vec<[Card; 7]> = (0..2_000)
+            .map(|_| {
+                deck.shuffle(&mut rng);
+                deck[..7].try_into().unwrap()
+            })
+            .collect();
+        // Six and seven suited cards, including a straight flush hidden among them
+        let spades: Vec<Card> = Card::all_cards()
+            .filter(|card| card.suit_enum() == Suit::Spades)
+            .collect();
+        hands.push(spades[..7].try_into().unwrap());
+        hands.push(spades[6..13].try_into().unwrap());
+        hands.push([
+            card(Rank::Nine, Suit::Hearts),
+            card(Rank::Eight, Suit::Hearts),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Six, Suit::Hearts),
+            card(Rank::Five, Suit::Hearts),
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Nine, Suit::Clubs),
+        ]);
+
+        let scalar: Vec<u16> = hands
+            .iter()
+            .map(|cards| evaluator.evaluate_7cards_fast(cards))
+            .collect();
+        assert_eq!(evaluator.evaluate_batch(&hands), scalar);
+    }
+
     #[test]
     fn test_hand_ordering() {
         let evaluator = CactusKevEvaluator::new();
@@ -370,4 +487,16 @@ mod tests {
         assert!(four_kind.beats(&high_card));
         assert!(royal_flush.beats(&high_card));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_new_shares_tables() {
+        let first = CactusKevEvaluator::new();
+        let second = CactusKevEvaluator::new();
+        assert!(std::ptr::eq(first.tables(), second.tables()));
+        assert!(std::ptr::eq(first.tables(), HandRankTables::shared()));
+
+        let custom = CactusKevEvaluator::with_tables(HandRankTables::new());
+        assert!(!std::ptr::eq(custom.tables(), first.tables()));
+        assert!(std::ptr::eq(custom.clone().tables(), custom.tables()));
+    }
+}
@@ -0,0 +1,166 @@
+//! Evaluator adapter that cross-checks two backends against each other.
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hand::Hand;
+use crate::core::ports::inbound::HandEvaluator;
+
+/// Hand evaluator that runs two evaluators and asserts they agree.
+///
+/// Results always come from `primary`. In debug and test builds `secondary` evaluates
+/// every hand too, and any difference in strength panics with the cards involved; in
+/// release builds `secondary` is never called. Wrap a new backend as `primary` with a
+/// trusted evaluator as `secondary` to run it through the existing test suites.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::services::{
+///     CactusKevEvaluator, DualEvaluator, PerfectHashEvaluator,
+/// };
+/// use riverrun::core::ports::inbound::HandEvaluator;
+///
+/// let evaluator = DualEvaluator::new(PerfectHashEvaluator::new(), CactusKevEvaluator::new());
+/// let cards = ["As", "Ks", "Qs", "Js", "Ts", "2h", "3d"].map(|card| card.parse().unwrap());
+/// assert_eq!(evaluator.evaluate_7cards_fast(&cards), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DualEvaluator<P, S> {
+    primary: P,
+    secondary: S,
+}
+
+/// `DualEvaluator` - Constructors
+impl<P: HandEvaluator, S: HandEvaluator> DualEvaluator<P, S> {
+    /// Cross-checks `primary`, whose results are returned, against `secondary`.
+    #[must_use]
+    pub const fn new(primary: P, secondary: S) -> Self {
+        Self { primary, secondary }
+    }
+}
+
+/// `DualEvaluator` - Accessors
+impl<P, S> DualEvaluator<P, S> {
+    /// The evaluator whose results are returned.
+    #[must_use]
+    pub const fn primary(&self) -> &P {
+        &self.primary
+    }
+
+    /// The evaluator `primary` is checked against.
+    #[must_use]
+    pub const fn secondary(&self) -> &S {
+        &self.secondary
+    }
+
+    /// Unwraps the two evaluators.
+    #[must_use]
+    pub fn into_parts(self) -> (P, S) {
+        (self.primary, self.secondary)
+    }
+}
+
+/// `DualEvaluator` - Helpers
+impl<P, S: HandEvaluator> DualEvaluator<P, S> {
+    /// Panics in debug builds if `secondary` does not give `cards` strength `strength`.
+    #[inline]
+    fn check_5cards(&self, cards: &[Card; 5], strength: u16) {
+        debug_assert_eq!(
+            strength,
+            self.secondary.evaluate_5cards_fast(cards),
+            "evaluators disagree on {cards:?}"
+        );
+    }
+
+    /// Panics in debug builds if `secondary` does not give `cards` strength `strength`.
+    #[inline]
+    fn check_7cards(&self, cards: &[Card; 7], strength: u16) {
+        debug_assert_eq!(
+            strength,
+            self.secondary.evaluate_7cards_fast(cards),
+            "evaluators disagree on {cards:?}"
+        );
+    }
+}
+
+impl<P: HandEvaluator, S: HandEvaluator> HandEvaluator for DualEvaluator<P, S> {
+    fn evaluate_5cards(&self, cards: [Card; 5]) -> Hand {
+        let hand = self.primary.evaluate_5cards(cards);
+        self.check_5cards(&cards, hand.strength());
+        hand
+    }
+
+    fn evaluate_7cards(&self, cards: [Card; 7]) -> Hand {
+        let hand = self.primary.evaluate_7cards(cards);
+        self.check_7cards(&cards, hand.strength());
+        hand
+    }
+
+    fn evaluate_5cards_fast(&self, cards: &[Card; 5]) -> u16 {
+        let strength = self.primary.evaluate_5cards_fast(cards);
+        self.check_5cards(cards, strength);
+        strength
+    }
+
+    fn evaluate_7cards_fast(&self, cards: &[Card; 7]) -> u16 {
+        let strength = self.primary.evaluate_7cards_fast(cards);
+        self.check_7cards(cards, strength);
+        strength
+    }
+
+    fn evaluate_batch(&self, hands: &[[Card; 7]]) -> Vec<u16> {
+        let strengths = self.primary.evaluate_batch(hands);
+        for (cards, &strength) in hands.iter().zip(&strengths) {
+            self.check_7cards(cards, strength);
+        }
+        strengths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::services::evaluation::{CactusKevEvaluator, ReferenceEvaluator};
+
+    /// Scores every hand as a royal flush.
+    struct Flattering;
+
+    impl HandEvaluator for Flattering {
+        fn evaluate_5cards(&self, cards: [Card; 5]) -> Hand {
+            Hand::new(cards, 1)
+        }
+        fn evaluate_7cards(&self, cards: [Card; 7]) -> Hand {
+            Hand::new([cards[0], cards[1], cards[2], cards[3], cards[4]], 1)
+        }
+        fn evaluate_5cards_fast(&self, _cards: &[Card; 5]) -> u16 {
+            1
+        }
+        fn evaluate_7cards_fast(&self, _cards: &[Card; 7]) -> u16 {
+            1
+        }
+    }
+
+    fn seven_cards(text: &str) -> [Card; 7] {
+        let cards: Vec<Card> = text.split(' ').map(|card| card.parse().unwrap()).collect();
+        cards.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_agreeing_evaluators() {
+        let evaluator = DualEvaluator::new(CactusKevEvaluator::new(), ReferenceEvaluator::new());
+        let cards = seven_cards("9c 9d 9h 2s 2c Kd 4h");
+        let hand = evaluator.evaluate_7cards(cards);
+        assert!(hand.is_full_house());
+        assert_eq!(
+            evaluator.evaluate_batch(&[cards]),
+            vec![evaluator.primary().evaluate_7cards_fast(&cards)]
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "evaluators disagree")]
+    fn test_disagreement_panics() {
+        let evaluator = DualEvaluator::new(Flattering, CactusKevEvaluator::new());
+        evaluator.evaluate_7cards_fast(&seven_cards("9c 9d 9h 2s 2c Kd 4h"));
+    }
+}
@@ -4,6 +4,12 @@
 //! prime product hashing.
 
 use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+use crate::core::domain::entities::card::Card;
 
 use super::super::utils::{combinations, is_straight_pattern};
 
@@ -13,6 +19,16 @@ pub const PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
 /// Worst possible hand rank (7-high).
 pub const WORST_RANK: u16 = 7462;
 
+/// Entries in the flush lookup table, one per 13-bit rank pattern.
+const FLUSH_ENTRIES: usize = 8192;
+
+/// Leading bytes of an encoded table, including the format version.
+const MAGIC: [u8; 4] = *b"RRT1";
+
+/// Tables generated ahead of time, decoded by `shared` instead of generating them.
+#[cfg(feature = "embedded-tables")]
+static EMBEDDED: &[u8] = include_bytes!("hand_rank_tables.bin");
+
 /// Precomputed lookup tables for fast hand evaluation.
 ///
 /// Two lookup structures:
@@ -21,6 +37,12 @@ pub const WORST_RANK: u16 = 7462;
 pub struct HandRankTables {
     pub flush_lookup: Vec<u16>,
     pub unique5: Vec<(u32, u16)>,
+    /// Prime products of `unique5`, stored apart so SIMD lanes can gather them.
+    #[cfg(feature = "simd")]
+    pub(crate) unique5_products: Vec<u32>,
+    /// Ranks of `unique5`, in the same order as `unique5_products`.
+    #[cfg(feature = "simd")]
+    pub(crate) unique5_ranks: Vec<u16>,
 }
 
 /// `HandRankTables` - Constructors
@@ -34,7 +56,7 @@ impl HandRankTables {
     /// The tables are populated once in descending hand strength order so that ranks reflect poker hand strength.
     #[must_use] 
     pub fn new() -> Self {
-        let mut flush_lookup = vec![WORST_RANK; 8192];
+        let mut flush_lookup = vec![WORST_RANK; FLUSH_ENTRIES];
         let mut unique5_map: HashMap<u32, u16> = HashMap::new();
 
         let mut current_rank = 1u16;
@@ -54,10 +76,67 @@ impl HandRankTables {
         let mut unique5: Vec<(u32, u16)> = unique5_map.into_iter().collect();
         unique5.sort_by_key(|(product, _)| *product);
 
-        Self {
-            flush_lookup,
-            unique5,
+        Self::from_parts(flush_lookup, unique5)
+    }
+
+    /// Process-wide tables, built on first use and shared by every evaluator.
+    ///
+    /// With the `embedded-tables` feature, the tables are decoded from a copy generated
+    /// ahead of time and compiled into the binary rather than generated at startup.
+    #[must_use]
+    pub fn shared() -> &'static Self {
+        Self::shared_arc()
+    }
+
+    /// Handle to the process-wide tables returned by `shared`.
+    pub(crate) fn shared_arc() -> &'static Arc<Self> {
+        static TABLES: OnceLock<Arc<HandRankTables>> = OnceLock::new();
+        TABLES.get_or_init(|| Arc::new(Self::initial()))
+    }
+
+    /// Decodes tables written by `to_bytes`.
+    ///
+    /// # Returns
+    /// `None` if `bytes` is not an encoded table, is truncated, or lists non-flush
+    /// products out of order.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let rest = bytes.strip_prefix(&MAGIC)?;
+        let (count, rest) = rest.split_first_chunk::<4>()?;
+        let count = u32::from_le_bytes(*count) as usize;
+        if rest.len() != FLUSH_ENTRIES * 2 + count.checked_mul(6)? {
+            return None;
+        }
+        let (flush, unique) = rest.split_at(FLUSH_ENTRIES * 2);
+
+        let flush_lookup = flush
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        let unique5: Vec<(u32, u16)> = unique
+            .chunks_exact(6)
+            .map(|entry| {
+                let product = u32::from_le_bytes([entry[0], entry[1], entry[2], entry[3]]);
+                (product, u16::from_le_bytes([entry[4], entry[5]]))
+            })
+            .collect();
+        if !unique5.windows(2).all(|pair| pair[0].0 < pair[1].0) {
+            return None;
         }
+
+        Some(Self::from_parts(flush_lookup, unique5))
+    }
+
+    /// Loads tables saved by `save_to`.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, or one of kind `InvalidData` if it
+    /// does not hold encoded tables.
+    pub fn load_from(path: impl AsRef<Path>) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        Self::from_bytes(&bytes).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "not an encoded hand rank table")
+        })
     }
 }
 
@@ -70,6 +149,34 @@ impl Default for HandRankTables {
 
 /// `HandRankTables` - Accessors
 impl HandRankTables {
+    /// Encodes the tables for `from_bytes`.
+    ///
+    /// The encoding is a four-byte header, the non-flush entry count, the flush table,
+    /// then each non-flush product and rank, all little-endian; about 53 KB in total.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 + self.flush_lookup.len() * 2 + self.unique5.len() * 6);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&(self.unique5.len() as u32).to_le_bytes());
+        for rank in &self.flush_lookup {
+            bytes.extend_from_slice(&rank.to_le_bytes());
+        }
+        for (product, rank) in &self.unique5 {
+            bytes.extend_from_slice(&product.to_le_bytes());
+            bytes.extend_from_slice(&rank.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Writes the tables to `path` so `load_from` can skip generating them.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save_to(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_bytes())
+    }
+
     /// Retrieve the hand rank for a flush pattern identified by a rank-bit index.
     ///
     /// `rank_bits` is the rank-bit index into the flush lookup table (valid range: 0..8192).
@@ -96,6 +203,65 @@ impl HandRankTables {
             .ok()
             .map(|idx| self.unique5[idx].1)
     }
+
+    /// Strength of a five-card hand: the flush table when all five share a suit, and
+    /// the prime-product table otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prime-product lookup does not yield a known hand rank, which only
+    /// happens when a card is repeated.
+    #[inline]
+    #[must_use]
+    pub fn lookup5(&self, cards: &[Card; 5]) -> u16 {
+        let suit_and = cards[0].0 & cards[1].0 & cards[2].0 & cards[3].0 & cards[4].0 & 0xF000;
+
+        if suit_and != 0 {
+            let rank_bits = cards[0].rank_bits()
+                | cards[1].rank_bits()
+                | cards[2].rank_bits()
+                | cards[3].rank_bits()
+                | cards[4].rank_bits();
+            return self.lookup_flush(rank_bits);
+        }
+
+        let prime_product = cards[0].prime()
+            * cards[1].prime()
+            * cards[2].prime()
+            * cards[3].prime()
+            * cards[4].prime();
+
+        self.lookup_unique(prime_product)
+            .unwrap_or_else(|| panic!("Invalid hand with prime product: {prime_product}"))
+    }
+}
+
+/// `HandRankTables` - Helpers
+impl HandRankTables {
+    /// Assembles tables from a flush table and sorted non-flush entries.
+    #[cfg_attr(not(feature = "simd"), allow(clippy::missing_const_for_fn))]
+    fn from_parts(flush_lookup: Vec<u16>, unique5: Vec<(u32, u16)>) -> Self {
+        Self {
+            flush_lookup,
+            #[cfg(feature = "simd")]
+            unique5_products: unique5.iter().map(|&(product, _)| product).collect(),
+            #[cfg(feature = "simd")]
+            unique5_ranks: unique5.iter().map(|&(_, rank)| rank).collect(),
+            unique5,
+        }
+    }
+
+    /// Tables backing `shared`.
+    #[cfg(feature = "embedded-tables")]
+    fn initial() -> Self {
+        Self::from_bytes(EMBEDDED).expect("embedded hand rank tables are well-formed")
+    }
+
+    /// Tables backing `shared`.
+    #[cfg(not(feature = "embedded-tables"))]
+    fn initial() -> Self {
+        Self::new()
+    }
 }
 
 // Straight flush bit patterns (A-high to wheel)
@@ -299,4 +465,41 @@ fn generate_high_card(map: &mut HashMap<u32, u16>, mut rank: u16) {
         map.insert(product, rank);
         rank += 1;
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let tables = HandRankTables::shared();
+        let bytes = tables.to_bytes();
+        let decoded = HandRankTables::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.flush_lookup, tables.flush_lookup);
+        assert_eq!(decoded.unique5, tables.unique5);
+
+        assert!(HandRankTables::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+        assert!(HandRankTables::from_bytes(&bytes[1..]).is_none());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("riverrun-tables-{}.bin", std::process::id()));
+        HandRankTables::shared().save_to(&path).unwrap();
+        let loaded = HandRankTables::load_from(&path).unwrap();
+        assert_eq!(loaded.unique5, HandRankTables::shared().unique5);
+
+        fs::write(&path, b"not tables").unwrap();
+        let error = HandRankTables::load_from(&path).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "embedded-tables")]
+    #[test]
+    fn test_embedded_tables_match_generated() {
+        let generated = HandRankTables::new();
+        assert_eq!(EMBEDDED, generated.to_bytes());
+    }
+}
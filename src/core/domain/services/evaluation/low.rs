@@ -0,0 +1,157 @@
+//! Eight-or-better low hand evaluator for hi-lo split games.
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::low_hand::{card_low_bit, LowHand};
+
+/// Evaluates the best eight-or-better low a player can make.
+///
+/// Lows depend only on the distinct ranks ace through eight, so evaluation is a few bit
+/// operations rather than a table lookup.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::card::Card;
+/// use riverrun::core::domain::services::evaluation::LowEvaluator;
+///
+/// let cards = |text: &str| -> Vec<Card> {
+///     text.split_whitespace().map(|c| c.parse().unwrap()).collect()
+/// };
+/// let evaluator = LowEvaluator::new();
+///
+/// let low = evaluator.evaluate(&cards("Ah 3d 6c 7s 2h Kd 3c")).unwrap();
+/// assert_eq!(low.to_string(), "7-6-3-2-A");
+/// // Omaha plays three board cards, so a board with two low cards makes no low
+/// let board = cards("Kh Qd 7c 8s 9h");
+/// assert!(evaluator.evaluate(&[cards("Ah 2d 3c Ks"), board.clone()].concat()).is_some());
+/// assert_eq!(evaluator.evaluate_omaha(&cards("Ah 2d 3c Ks"), &board), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LowEvaluator;
+
+/// `LowEvaluator` - Constructors
+impl LowEvaluator {
+    /// Creates a low evaluator.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// `LowEvaluator` - Operations
+impl LowEvaluator {
+    /// Best low using any five of `cards`, as in hold'em and stud hi-lo.
+    ///
+    /// # Returns
+    /// `None` if the cards hold fewer than five distinct ranks eight or lower.
+    #[must_use]
+    pub fn evaluate(&self, cards: &[Card]) -> Option<LowHand> {
+        let mut mask = cards
+            .iter()
+            .fold(0u8, |mask, &card| mask | card_low_bit(card));
+        // The five lowest ranks make the best low
+        while mask.count_ones() > 5 {
+            mask &= !(0x80 >> mask.leading_zeros());
+        }
+        LowHand::from_mask(mask)
+    }
+
+    /// Best low using exactly two of `hole_cards` and three of `board`, as in Omaha hi-lo.
+    ///
+    /// # Returns
+    /// `None` if no such combination makes an eight-or-better low.
+    #[must_use]
+    pub fn evaluate_omaha(&self, hole_cards: &[Card], board: &[Card]) -> Option<LowHand> {
+        let hole_masks = distinct_masks(hole_cards, 2);
+        let board_masks = distinct_masks(board, 3);
+
+        hole_masks
+            .iter()
+            .flat_map(|&hole| {
+                board_masks
+                    .iter()
+                    .filter(move |&&board| hole & board == 0)
+                    .map(move |&board| hole | board)
+            })
+            .min()
+            .and_then(LowHand::from_mask)
+    }
+}
+
+/// Rank masks of every way to choose `count` low cards of distinct ranks from `cards`.
+fn distinct_masks(cards: &[Card], count: u32) -> Vec<u8> {
+    let bits: Vec<u8> = cards
+        .iter()
+        .map(|&card| card_low_bit(card))
+        .filter(|&bit| bit != 0)
+        .collect();
+
+    let mut chosen = vec![0u8];
+    for bit in bits {
+        // Extend every partial choice that does not already hold this rank
+        let extended: Vec<u8> = chosen
+            .iter()
+            .filter(|&&mask| mask & bit == 0 && mask.count_ones() < count)
+            .map(|&mask| mask | bit)
+            .collect();
+        chosen.extend(extended);
+    }
+    let mut masks: Vec<u8> = chosen
+        .into_iter()
+        .filter(|mask| mask.count_ones() == count)
+        .collect();
+    masks.sort_unstable();
+    masks.dedup();
+    masks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::card::Rank;
+
+    fn cards(text: &str) -> Vec<Card> {
+        text.split_whitespace()
+            .map(|c| c.parse().unwrap())
+            .collect()
+    }
+
+    fn low(text: &str) -> Option<String> {
+        LowEvaluator::new()
+            .evaluate(&cards(text))
+            .map(|low| low.to_string())
+    }
+
+    #[test]
+    fn test_evaluate_any_five() {
+        assert_eq!(low("Ah 2d 3c 4s 5h").as_deref(), Some("5-4-3-2-A"));
+        // Pairs and high cards are skipped, and the lowest five ranks play
+        assert_eq!(low("8h 2d 2c 4s 5h 7d Ac").as_deref(), Some("7-5-4-2-A"));
+        assert_eq!(low("8h 2d 2c 4s 9h Kd Ac"), None);
+        assert_eq!(low("Ah 2d 3c 4s"), None);
+    }
+
+    #[test]
+    fn test_evaluate_omaha() {
+        let evaluator = LowEvaluator::new();
+        let board = cards("2h 5d 7c Ks 8h");
+
+        let best = evaluator
+            .evaluate_omaha(&cards("Ad 3s 4c 4h"), &board)
+            .unwrap();
+        assert_eq!(best.to_string(), "7-5-3-2-A");
+
+        // The deuce on board counterfeits A-2, which must then play the eight
+        let counterfeit = evaluator
+            .evaluate_omaha(&cards("Ad As 2c Kh"), &board)
+            .unwrap();
+        assert_eq!(counterfeit.to_string(), "8-7-5-2-A");
+        assert_eq!(counterfeit.high_card(), Rank::Eight);
+
+        // Only two low cards on board
+        assert_eq!(
+            evaluator.evaluate_omaha(&cards("Ad 2s 3c 4h"), &cards("5h 9d Tc Ks Qh")),
+            None
+        );
+    }
+}
@@ -1,7 +1,21 @@
 //! Hand evaluation services.
 
 mod cactus_kev;
+mod dual;
 mod hand_rank_tables;
+mod low;
+mod perfect_hash;
+mod reference;
+#[cfg(feature = "simd")]
+mod simd;
+mod verification;
 
 pub use cactus_kev::CactusKevEvaluator;
+pub use dual::DualEvaluator;
 pub use hand_rank_tables::HandRankTables;
+pub use low::LowEvaluator;
+pub use perfect_hash::{PerfectHashEvaluator, PerfectHashTables, NON_FLUSH_7_ENTRIES};
+pub use reference::ReferenceEvaluator;
+pub use verification::{
+    verify_5card_against, verify_5card_exhaustive, EvaluatorMismatch, FIVE_CARD_HANDS,
+};
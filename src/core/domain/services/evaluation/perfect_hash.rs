@@ -0,0 +1,458 @@
+//! Hand evaluator using perfect-hash lookup tables for 7-card hands.
+//!
+//! Instead of evaluating all 21 five-card subsets, a 7-card hand is resolved
+//! in a couple of table lookups:
+//! - Flush hands (5+ cards of one suit): O(1) lookup via the suit's rank bits
+//! - Non-flush hands: O(1) lookup via a minimal perfect hash of the rank counts
+//!
+//! The rank-count hash maps every multiset of 7 ranks (each rank appearing at
+//! most 4 times) onto a dense index in `0..49205`.
+
+use std::sync::{Arc, OnceLock};
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hand::Hand;
+use crate::core::ports::inbound::HandEvaluator;
+
+use super::super::utils::FIVE_FROM_SEVEN;
+use super::hand_rank_tables::{HandRankTables, PRIMES};
+
+/// Number of distinct rank multisets for 7 cards (each rank at most 4 times).
+pub const NON_FLUSH_7_ENTRIES: usize = 49_205;
+
+/// Maximum number of cards of a single rank.
+const MAX_RANK_COUNT: usize = 4;
+
+/// Number of cards in a 7-card hand.
+const HAND_SIZE: usize = 7;
+
+/// Precomputed lookup tables for perfect-hash 7-card evaluation.
+///
+/// Two lookup structures:
+/// - `flush7`: 8192-entry table indexed by the rank bits of the flush suit
+/// - `non_flush7`: 49205-entry table indexed by the rank-count perfect hash
+pub struct PerfectHashTables {
+    flush7: Vec<u16>,
+    non_flush7: Vec<u16>,
+    hash_weights: [[[u32; MAX_RANK_COUNT + 1]; HAND_SIZE + 1]; 13],
+}
+
+/// `PerfectHashTables` - Constructors
+impl PerfectHashTables {
+    /// Builds the 7-card lookup tables from the 5-card Cactus Kev tables.
+    ///
+    /// Every 13-bit rank mask with at least five bits set is resolved to its best 5-card
+    /// flush, and every 7-card rank multiset is resolved to its best 5-card non-flush hand.
+    #[must_use]
+    pub fn from_rank_tables(tables: &HandRankTables) -> Self {
+        let hash_weights = build_hash_weights();
+
+        let mut flush7 = vec![0u16; 8192];
+        for (mask, entry) in flush7.iter_mut().enumerate() {
+            if mask.count_ones() >= 5 {
+                *entry = best_flush(tables, mask);
+            }
+        }
+
+        let mut non_flush7 = vec![0u16; NON_FLUSH_7_ENTRIES];
+        let mut counts = [0u8; 13];
+        fill_non_flush(tables, &hash_weights, &mut non_flush7, &mut counts, 0, HAND_SIZE);
+
+        Self {
+            flush7,
+            non_flush7,
+            hash_weights,
+        }
+    }
+
+    /// Process-wide tables derived from `HandRankTables::shared`, built on first use
+    /// and shared by every evaluator.
+    #[must_use]
+    pub fn shared() -> &'static Self {
+        Self::shared_arc()
+    }
+
+    /// Handle to the process-wide tables returned by `shared`.
+    fn shared_arc() -> &'static Arc<Self> {
+        static TABLES: OnceLock<Arc<PerfectHashTables>> = OnceLock::new();
+        TABLES.get_or_init(|| Arc::new(Self::from_rank_tables(HandRankTables::shared())))
+    }
+}
+
+impl Default for PerfectHashTables {
+    fn default() -> Self {
+        Self::from_rank_tables(HandRankTables::shared())
+    }
+}
+
+/// `PerfectHashTables` - Accessors
+impl PerfectHashTables {
+    /// Best strength of each 13-bit rank mask of a flush suit, or 0 for masks with
+    /// fewer than five ranks.
+    #[must_use]
+    pub fn flush7(&self) -> &[u16] {
+        &self.flush7
+    }
+
+    /// Best non-flush strength of each 7-card rank multiset, indexed by `hash_counts`.
+    #[must_use]
+    pub fn non_flush7(&self) -> &[u16] {
+        &self.non_flush7
+    }
+
+    /// Compute the perfect-hash index for a vector of 13 rank counts summing to 7.
+    ///
+    /// The index is the lexicographic position of `counts` among all count vectors with
+    /// the same total, which makes the hash both collision-free and dense.
+    #[inline]
+    #[must_use]
+    pub fn hash_counts(&self, counts: &[u8; 13]) -> usize {
+        hash_with(&self.hash_weights, counts)
+    }
+
+    /// Look up the best 5-card strength for a 7-card hand.
+    #[inline]
+    #[must_use]
+    pub fn lookup7(&self, cards: &[Card; 7]) -> u16 {
+        let mut suit_counts = [0u8; 4];
+        let mut suit_masks = [0u32; 4];
+        let mut rank_counts = [0u8; 13];
+
+        for card in cards {
+            let suit = card.suit() as usize;
+            suit_counts[suit] += 1;
+            suit_masks[suit] |= card.rank_bits();
+            rank_counts[card.rank() as usize] += 1;
+        }
+
+        for suit in 0..4 {
+            if suit_counts[suit] >= 5 {
+                return self.flush7[suit_masks[suit] as usize];
+            }
+        }
+
+        self.non_flush7[self.hash_counts(&rank_counts)]
+    }
+}
+
+/// Hand evaluator resolving 7-card hands directly through perfect-hash lookups.
+///
+/// 5-card hands are evaluated with the Cactus Kev tables; 7-card hands skip the
+/// 21-combination loop entirely, which makes this backend well suited to large
+/// equity runs.
+///
+/// Evaluators built with `new` share one process-wide copy of both tables, so
+/// constructing one per request is cheap.
+#[derive(Clone)]
+pub struct PerfectHashEvaluator {
+    tables: Arc<HandRankTables>,
+    perfect: Arc<PerfectHashTables>,
+}
+
+/// `PerfectHashEvaluator` - Constructors
+impl PerfectHashEvaluator {
+    /// Constructs a `PerfectHashEvaluator` using the shared precomputed tables.
+    ///
+    /// The tables are built by the first call and reused afterwards; see
+    /// `HandRankTables::shared` and `PerfectHashTables::shared`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tables: Arc::clone(HandRankTables::shared_arc()),
+            perfect: Arc::clone(PerfectHashTables::shared_arc()),
+        }
+    }
+
+    /// Create an evaluator from existing 5-card hand rank tables.
+    ///
+    /// The 7-card perfect-hash tables are derived from `tables`.
+    #[must_use]
+    pub fn with_tables(tables: HandRankTables) -> Self {
+        let perfect = PerfectHashTables::from_rank_tables(&tables);
+        Self {
+            tables: Arc::new(tables),
+            perfect: Arc::new(perfect),
+        }
+    }
+}
+
+/// `PerfectHashEvaluator` - Accessors
+impl PerfectHashEvaluator {
+    /// Provides access to the underlying 5-card hand-rank tables.
+    #[must_use]
+    pub fn tables(&self) -> &HandRankTables {
+        &self.tables
+    }
+
+    /// Provides access to the 7-card perfect-hash tables.
+    #[must_use]
+    pub fn perfect_tables(&self) -> &PerfectHashTables {
+        &self.perfect
+    }
+}
+
+impl Default for PerfectHashEvaluator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HandEvaluator for PerfectHashEvaluator {
+    fn evaluate_5cards(&self, cards: [Card; 5]) -> Hand {
+        let strength = self.evaluate_5cards_fast(&cards);
+        Hand::new(cards, strength)
+    }
+
+    /// Evaluate seven cards and recover the 5-card subset that produces the best strength.
+    ///
+    /// The strength comes from the perfect-hash lookup; the 21 subsets are only scanned to
+    /// report which cards make up the hand.
+    fn evaluate_7cards(&self, cards: [Card; 7]) -> Hand {
+        let best = self.evaluate_7cards_fast(&cards);
+
+        for combo in FIVE_FROM_SEVEN {
+            let hand_cards = [
+                cards[combo[0]],
+                cards[combo[1]],
+                cards[combo[2]],
+                cards[combo[3]],
+                cards[combo[4]],
+            ];
+            if self.evaluate_5cards_fast(&hand_cards) == best {
+                return Hand::new(hand_cards, best);
+            }
+        }
+
+        unreachable!("best 7-card strength must come from one of its 5-card subsets")
+    }
+
+    /// Evaluate a five-card hand using the Cactus Kev flush and prime-product tables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prime-product lookup does not yield a known hand rank.
+    #[inline]
+    fn evaluate_5cards_fast(&self, cards: &[Card; 5]) -> u16 {
+        self.tables.lookup5(cards)
+    }
+
+    #[inline]
+    fn evaluate_7cards_fast(&self, cards: &[Card; 7]) -> u16 {
+        self.perfect.lookup7(cards)
+    }
+}
+
+/// Count the rank vectors of length `len` (entries `0..=4`) whose entries sum to `total`.
+fn count_vectors(len: usize, total: usize, memo: &mut [[u32; HAND_SIZE + 1]; 14]) -> u32 {
+    if len == 0 {
+        return u32::from(total == 0);
+    }
+    if memo[len][total] != u32::MAX {
+        return memo[len][total];
+    }
+    let count = (0..=MAX_RANK_COUNT.min(total))
+        .map(|digit| count_vectors(len - 1, total - digit, memo))
+        .sum();
+    memo[len][total] = count;
+    count
+}
+
+/// Build the per-position weights used by the rank-count perfect hash.
+///
+/// `weights[position][remaining][count]` is the number of count vectors that precede any
+/// vector with `count` at `position`, given `remaining` cards still to place.
+fn build_hash_weights() -> [[[u32; MAX_RANK_COUNT + 1]; HAND_SIZE + 1]; 13] {
+    let mut memo = [[u32::MAX; HAND_SIZE + 1]; 14];
+    let mut weights = [[[0u32; MAX_RANK_COUNT + 1]; HAND_SIZE + 1]; 13];
+
+    for (position, per_remaining) in weights.iter_mut().enumerate() {
+        let suffix_len = 12 - position;
+        for (remaining, per_count) in per_remaining.iter_mut().enumerate() {
+            let mut acc = 0u32;
+            for (count, weight) in per_count.iter_mut().enumerate() {
+                *weight = acc;
+                if count <= remaining {
+                    acc += count_vectors(suffix_len, remaining - count, &mut memo);
+                }
+            }
+        }
+    }
+    weights
+}
+
+/// Best flush strength among all 5-bit subsets of a 13-bit rank mask.
+#[allow(clippy::cast_possible_truncation)]
+fn best_flush(tables: &HandRankTables, mask: usize) -> u16 {
+    let ranks: Vec<u32> = (0..13u32).filter(|r| mask & (1 << r) != 0).collect();
+    let mut best = u16::MAX;
+
+    for a in 0..ranks.len() {
+        for b in (a + 1)..ranks.len() {
+            for c in (b + 1)..ranks.len() {
+                for d in (c + 1)..ranks.len() {
+                    for e in (d + 1)..ranks.len() {
+                        let bits = (1 << ranks[a])
+                            | (1 << ranks[b])
+                            | (1 << ranks[c])
+                            | (1 << ranks[d])
+                            | (1 << ranks[e]);
+                        best = best.min(tables.lookup_flush(bits));
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Recursively enumerate every 7-card rank multiset and store its best non-flush strength.
+#[allow(clippy::cast_possible_truncation)]
+fn fill_non_flush(
+    tables: &HandRankTables,
+    weights: &[[[u32; MAX_RANK_COUNT + 1]; HAND_SIZE + 1]; 13],
+    table: &mut [u16],
+    counts: &mut [u8; 13],
+    position: usize,
+    remaining: usize,
+) {
+    if position == 13 {
+        if remaining == 0 {
+            let index = hash_with(weights, counts);
+            table[index] = best_non_flush(tables, counts);
+        }
+        return;
+    }
+
+    for count in 0..=MAX_RANK_COUNT.min(remaining) {
+        counts[position] = count as u8;
+        fill_non_flush(tables, weights, table, counts, position + 1, remaining - count);
+    }
+    counts[position] = 0;
+}
+
+/// Perfect hash of rank counts against a weight table.
+#[inline]
+fn hash_with(weights: &[[[u32; MAX_RANK_COUNT + 1]; HAND_SIZE + 1]; 13], counts: &[u8; 13]) -> usize {
+    let mut remaining = HAND_SIZE;
+    let mut index = 0u32;
+    for (position, &count) in counts.iter().enumerate() {
+        index += weights[position][remaining][count as usize];
+        remaining -= count as usize;
+        if remaining == 0 {
+            break;
+        }
+    }
+    index as usize
+}
+
+/// Best non-flush strength among all 5-card subsets of a 7-card rank multiset.
+fn best_non_flush(tables: &HandRankTables, counts: &[u8; 13]) -> u16 {
+    let mut primes = [0u32; HAND_SIZE];
+    let mut filled = 0;
+    for (rank, &count) in counts.iter().enumerate() {
+        for _ in 0..count {
+            primes[filled] = PRIMES[rank];
+            filled += 1;
+        }
+    }
+
+    FIVE_FROM_SEVEN
+        .iter()
+        .filter_map(|combo| {
+            let product = combo.iter().map(|&i| primes[i]).product();
+            tables.lookup_unique(product)
+        })
+        .min()
+        .unwrap_or(u16::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::card::{Rank, Suit};
+    use crate::core::domain::services::evaluation::CactusKevEvaluator;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    fn card(rank: Rank, suit: Suit) -> Card {
+        Card::new(rank, suit)
+    }
+
+    #[test]
+    fn test_hash_is_dense_and_unique() {
+        let tables = PerfectHashTables::shared();
+        assert_eq!(tables.non_flush7().len(), NON_FLUSH_7_ENTRIES);
+        // Every slot is filled with a valid strength
+        assert!(tables.non_flush7().iter().all(|&s| (1..=7462).contains(&s)));
+        assert!(std::ptr::eq(
+            PerfectHashEvaluator::new().perfect_tables(),
+            PerfectHashEvaluator::new().perfect_tables()
+        ));
+    }
+
+    #[test]
+    fn test_royal_flush_7cards() {
+        let evaluator = PerfectHashEvaluator::new();
+        let cards = [
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Jack, Suit::Spades),
+            card(Rank::Ten, Suit::Spades),
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Three, Suit::Diamonds),
+        ];
+        assert_eq!(evaluator.evaluate_7cards_fast(&cards), 1);
+        assert!(evaluator.evaluate_7cards(cards).is_royal_flush());
+    }
+
+    #[test]
+    fn test_quads_over_flush_draw() {
+        let evaluator = PerfectHashEvaluator::new();
+        let cards = [
+            card(Rank::Nine, Suit::Spades),
+            card(Rank::Nine, Suit::Hearts),
+            card(Rank::Nine, Suit::Diamonds),
+            card(Rank::Nine, Suit::Clubs),
+            card(Rank::Two, Suit::Spades),
+            card(Rank::Five, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+        ];
+        let hand = evaluator.evaluate_7cards(cards);
+        assert!(hand.is_four_of_a_kind());
+    }
+
+    #[test]
+    fn test_matches_cactus_kev_on_random_hands() {
+        let perfect = PerfectHashEvaluator::new();
+        let cactus = CactusKevEvaluator::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let mut deck: Vec<Card> = Card::all_cards().collect();
+
+        for _ in 0..20_000 {
+            deck.shuffle(&mut rng);
+            let cards = [deck[0], deck[1], deck[2], deck[3], deck[4], deck[5], deck[6]];
+            assert_eq!(
+                perfect.evaluate_7cards_fast(&cards),
+                cactus.evaluate_7cards_fast(&cards),
+            );
+        }
+    }
+
+    #[test]
+    fn test_evaluate_7cards_returns_matching_subset() {
+        let evaluator = PerfectHashEvaluator::new();
+        let cards = [
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::King, Suit::Diamonds),
+            card(Rank::King, Suit::Clubs),
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Seven, Suit::Diamonds),
+        ];
+        let hand = evaluator.evaluate_7cards(cards);
+        assert!(hand.is_two_pair());
+        assert_eq!(evaluator.evaluate_5cards_fast(hand.cards()), hand.strength());
+    }
+}
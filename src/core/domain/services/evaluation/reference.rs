@@ -0,0 +1,226 @@
+//! Slow, straightforward hand evaluator used to check the fast ones.
+//!
+//! Hands are ranked by their category and kickers, computed directly from rank counts
+//! with no prime products, bit tricks or precomputed lookup tables. Strengths use the
+//! same 1..=7462 numbering as the fast evaluators, so results can be compared exactly.
+
+use std::cmp::Reverse;
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hand::{Hand, HandRank};
+use crate::core::ports::inbound::HandEvaluator;
+
+use super::super::utils::FIVE_FROM_SEVEN;
+use super::hand_rank_tables::WORST_RANK;
+
+/// Number of ranks in a deck.
+const NUM_RANKS: u8 = 13;
+
+/// Rank index of the five, the top card of the wheel (A-2-3-4-5).
+const FIVE: u8 = 3;
+
+/// Rank index of the ace.
+const ACE: u8 = 12;
+
+/// What a five-card hand is compared by: its category, then its rank groups from most
+/// to least significant.
+type HandKey = (HandRank, [u8; 5]);
+
+/// Hand evaluator that ranks hands by category and kickers.
+///
+/// Orders of magnitude slower than `CactusKevEvaluator`; intended as ground truth for
+/// `verify_5card_exhaustive` and `DualEvaluator`, not for hot loops.
+#[derive(Clone, Debug)]
+pub struct ReferenceEvaluator {
+    /// Every distinct hand key, strongest first; a key's strength is its position + 1.
+    keys: Vec<HandKey>,
+}
+
+/// `ReferenceEvaluator` - Constructors
+impl ReferenceEvaluator {
+    /// Constructs a `ReferenceEvaluator` by enumerating every distinct five-card hand.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut keys = Vec::new();
+        let mut ranks = [0u8; 5];
+        collect_keys(&mut keys, &mut ranks, 0, 0);
+        keys.sort_unstable_by_key(|&key| Reverse(key));
+        keys.dedup();
+        debug_assert_eq!(keys.len(), usize::from(WORST_RANK));
+        Self { keys }
+    }
+}
+
+impl Default for ReferenceEvaluator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `ReferenceEvaluator` - Helpers
+impl ReferenceEvaluator {
+    /// Strength of the hand with `key`, 1 being a royal flush.
+    fn strength(&self, key: HandKey) -> u16 {
+        let position = self
+            .keys
+            .binary_search_by(|probe| key.cmp(probe))
+            .expect("every five-card hand has a key");
+        u16::try_from(position + 1).expect("at most 7462 hand strengths")
+    }
+}
+
+impl HandEvaluator for ReferenceEvaluator {
+    fn evaluate_5cards(&self, cards: [Card; 5]) -> Hand {
+        let strength = self.evaluate_5cards_fast(&cards);
+        Hand::new(cards, strength)
+    }
+
+    fn evaluate_7cards(&self, cards: [Card; 7]) -> Hand {
+        FIVE_FROM_SEVEN
+            .iter()
+            .map(|combo| self.evaluate_5cards(combo.map(|i| cards[i])))
+            .min_by_key(Hand::strength)
+            .expect("seven cards hold 21 five-card hands")
+    }
+
+    fn evaluate_5cards_fast(&self, cards: &[Card; 5]) -> u16 {
+        let ranks = cards.map(|card| card.rank());
+        let flush = cards.iter().all(|card| card.same_suit(&cards[0]));
+        self.strength(hand_key(ranks, flush))
+    }
+
+    fn evaluate_7cards_fast(&self, cards: &[Card; 7]) -> u16 {
+        self.evaluate_7cards(*cards).strength()
+    }
+}
+
+/// Pushes the key of every five-rank multiset extending `ranks[..filled]`, as a flush
+/// and as a non-flush where the ranks allow it.
+fn collect_keys(keys: &mut Vec<HandKey>, ranks: &mut [u8; 5], filled: usize, lowest: u8) {
+    if filled == ranks.len() {
+        let counts = rank_counts(*ranks);
+        if counts.iter().all(|&count| count <= 4) {
+            keys.push(hand_key(*ranks, false));
+            if counts.iter().all(|&count| count <= 1) {
+                keys.push(hand_key(*ranks, true));
+            }
+        }
+        return;
+    }
+    for rank in lowest..NUM_RANKS {
+        ranks[filled] = rank;
+        collect_keys(keys, ranks, filled + 1, rank);
+    }
+}
+
+/// How many of `ranks` fall on each rank.
+fn rank_counts(ranks: [u8; 5]) -> [u8; NUM_RANKS as usize] {
+    let mut counts = [0u8; NUM_RANKS as usize];
+    for rank in ranks {
+        counts[usize::from(rank)] += 1;
+    }
+    counts
+}
+
+/// Category and tiebreaking ranks of five cards with `ranks`, all suited if `flush`.
+fn hand_key(ranks: [u8; 5], flush: bool) -> HandKey {
+    let counts = rank_counts(ranks);
+
+    // Rank groups ordered by size, then by rank: the order hands are compared in
+    let mut groups = [(0u8, 0u8); 5];
+    let mut len = 0;
+    for rank in (0..NUM_RANKS).rev() {
+        let count = counts[usize::from(rank)];
+        if count > 0 {
+            groups[len] = (count, rank);
+            len += 1;
+        }
+    }
+    let groups = &mut groups[..len];
+    groups.sort_by_key(|&(count, _)| Reverse(count));
+
+    let mut tiebreak = [0u8; 5];
+    for (slot, &(_, rank)) in tiebreak.iter_mut().zip(groups.iter()) {
+        *slot = rank;
+    }
+
+    let straight_high = (groups.len() == 5)
+        .then(|| {
+            let high = groups[0].1;
+            let low = groups[4].1;
+            if high - low == 4 {
+                Some(high)
+            } else if high == ACE && groups[1].1 == FIVE {
+                Some(FIVE)
+            } else {
+                None
+            }
+        })
+        .flatten();
+
+    let category = match (straight_high, flush, groups[0].0, groups.len()) {
+        (Some(high), true, _, _) => return (HandRank::StraightFlush, [high, 0, 0, 0, 0]),
+        (_, _, 4, _) => HandRank::FourOfAKind,
+        (_, _, 3, 2) => HandRank::FullHouse,
+        (_, true, _, _) => HandRank::Flush,
+        (Some(high), false, _, _) => return (HandRank::Straight, [high, 0, 0, 0, 0]),
+        (_, _, 3, _) => HandRank::ThreeOfAKind,
+        (_, _, 2, 3) => HandRank::TwoPair,
+        (_, _, 2, _) => HandRank::OnePair,
+        _ => HandRank::HighCard,
+    };
+    (category, tiebreak)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards<const N: usize>(text: &str) -> [Card; N] {
+        let cards: Vec<Card> = text.split(' ').map(|card| card.parse().unwrap()).collect();
+        cards.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_known_strengths() {
+        let evaluator = ReferenceEvaluator::new();
+        assert_eq!(evaluator.evaluate_5cards_fast(&cards("As Ks Qs Js Ts")), 1);
+        assert_eq!(evaluator.evaluate_5cards_fast(&cards("5h 4h 3h 2h Ah")), 10);
+        assert_eq!(
+            evaluator.evaluate_5cards_fast(&cards("7c 5d 4h 3s 2c")),
+            WORST_RANK
+        );
+    }
+
+    #[test]
+    fn test_categories() {
+        let evaluator = ReferenceEvaluator::new();
+        for (hand, rank) in [
+            ("9c 9d 9h 9s 2c", HandRank::FourOfAKind),
+            ("9c 9d 9h 2s 2c", HandRank::FullHouse),
+            ("Kh 9h 7h 4h 2h", HandRank::Flush),
+            ("5c 4d 3h 2s Ac", HandRank::Straight),
+            ("9c 9d 9h Ks 2c", HandRank::ThreeOfAKind),
+            ("9c 9d Kh Ks 2c", HandRank::TwoPair),
+            ("9c 9d Kh Qs 2c", HandRank::OnePair),
+            ("Ac 9d Kh Qs 2c", HandRank::HighCard),
+        ] {
+            assert_eq!(
+                evaluator.evaluate_5cards(cards(hand)).rank(),
+                rank,
+                "{hand}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_best_of_seven() {
+        let evaluator = ReferenceEvaluator::new();
+        let hand = evaluator.evaluate_7cards(cards("As Ah Kd Kc Qs 2h 7d"));
+        assert!(hand.is_two_pair());
+        assert_eq!(
+            evaluator.evaluate_5cards_fast(hand.cards()),
+            hand.strength()
+        );
+    }
+}
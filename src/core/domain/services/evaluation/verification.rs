@@ -0,0 +1,144 @@
+//! Exhaustive correctness checks for hand evaluators.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_display::{CardsDisplay, DisplayStyle};
+use crate::core::ports::inbound::HandEvaluator;
+
+use super::reference::ReferenceEvaluator;
+
+/// Number of distinct five-card hands, C(52, 5).
+pub const FIVE_CARD_HANDS: usize = 2_598_960;
+
+/// A hand two evaluators score differently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvaluatorMismatch {
+    /// The hand that was scored.
+    pub cards: [Card; 5],
+    /// Strength given by the reference evaluator.
+    pub expected: u16,
+    /// Strength given by the evaluator under test.
+    pub actual: u16,
+}
+
+impl fmt::Display for EvaluatorMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} scored {}, expected {}",
+            CardsDisplay::bracketed(&self.cards, DisplayStyle::Ascii),
+            self.actual,
+            self.expected
+        )
+    }
+}
+
+impl Error for EvaluatorMismatch {}
+
+/// Checks `evaluator` against `ReferenceEvaluator` on every five-card hand.
+///
+/// Takes a few seconds in release builds; run it whenever an evaluator backend or its
+/// tables change.
+///
+/// # Returns
+///
+/// The number of hands checked, `FIVE_CARD_HANDS`.
+///
+/// # Errors
+///
+/// Returns the first hand whose strength differs from the reference.
+pub fn verify_5card_exhaustive<E: HandEvaluator>(
+    evaluator: &E,
+) -> Result<usize, EvaluatorMismatch> {
+    verify_5card_against(evaluator, &ReferenceEvaluator::new())
+}
+
+/// Checks `evaluator` against `reference` on every five-card hand.
+///
+/// # Errors
+///
+/// Returns the first hand whose strength differs from `reference`'s.
+pub fn verify_5card_against<E: HandEvaluator, R: HandEvaluator>(
+    evaluator: &E,
+    reference: &R,
+) -> Result<usize, EvaluatorMismatch> {
+    let deck: Vec<Card> = Card::all_cards().collect();
+    let n = deck.len();
+    let mut checked = 0;
+    for a in 0..n {
+        for b in a + 1..n {
+            for c in b + 1..n {
+                for d in c + 1..n {
+                    for e in d + 1..n {
+                        let cards = [deck[a], deck[b], deck[c], deck[d], deck[e]];
+                        let expected = reference.evaluate_5cards_fast(&cards);
+                        let actual = evaluator.evaluate_5cards_fast(&cards);
+                        if actual != expected {
+                            return Err(EvaluatorMismatch {
+                                cards,
+                                expected,
+                                actual,
+                            });
+                        }
+                        checked += 1;
+                    }
+                }
+            }
+        }
+    }
+    Ok(checked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::hand::Hand;
+    use crate::core::domain::services::evaluation::{CactusKevEvaluator, PerfectHashEvaluator};
+
+    /// Cactus Kev, except that the wheel straight flush is scored as a royal flush.
+    struct WheelBug(CactusKevEvaluator);
+
+    impl HandEvaluator for WheelBug {
+        fn evaluate_5cards(&self, cards: [Card; 5]) -> Hand {
+            Hand::new(cards, self.evaluate_5cards_fast(&cards))
+        }
+        fn evaluate_7cards(&self, cards: [Card; 7]) -> Hand {
+            self.0.evaluate_7cards(cards)
+        }
+        fn evaluate_5cards_fast(&self, cards: &[Card; 5]) -> u16 {
+            match self.0.evaluate_5cards_fast(cards) {
+                10 => 1,
+                strength => strength,
+            }
+        }
+        fn evaluate_7cards_fast(&self, cards: &[Card; 7]) -> u16 {
+            self.0.evaluate_7cards_fast(cards)
+        }
+    }
+
+    #[test]
+    fn test_cactus_kev_matches_reference() {
+        assert_eq!(
+            verify_5card_exhaustive(&CactusKevEvaluator::new()),
+            Ok(FIVE_CARD_HANDS)
+        );
+    }
+
+    #[test]
+    fn test_perfect_hash_matches_reference() {
+        assert_eq!(
+            verify_5card_exhaustive(&PerfectHashEvaluator::new()),
+            Ok(FIVE_CARD_HANDS)
+        );
+    }
+
+    #[test]
+    fn test_mismatch_is_reported() {
+        let mismatch = verify_5card_exhaustive(&WheelBug(CactusKevEvaluator::new())).unwrap_err();
+        assert_eq!(mismatch.expected, 10);
+        assert_eq!(mismatch.actual, 1);
+        assert!(mismatch.to_string().ends_with("scored 1, expected 10"));
+    }
+}
@@ -1,8 +1,20 @@
+pub mod abstraction;
+pub mod analysis;
 pub mod equity;
 pub mod evaluation;
+pub mod solver;
 pub mod solving;
+pub mod strategy;
 pub mod utils;
 
-pub use equity::{ExhaustiveEquityCalculator, MonteCarloEquityCalculator};
-pub use evaluation::CactusKevEvaluator;
-pub use solving::ShowdownSolver;
\ No newline at end of file
+pub use analysis::{BlockerAnalysis, EvCalculator, HandProgression, RunoutSampler};
+pub use equity::{
+    EquityContext, EquityRequestBuilder, ExhaustiveEquityCalculator, MonteCarloEquityCalculator,
+    PreflopEquityTable, SmartEquityCalculator,
+};
+pub use evaluation::{
+    CactusKevEvaluator, DualEvaluator, LowEvaluator, PerfectHashEvaluator, ReferenceEvaluator,
+};
+pub use solver::MccfrSolver;
+pub use solving::{HiLoShowdownSolver, PotDistributor, ShowdownSolver};
+pub use strategy::PushFoldSolver;
\ No newline at end of file
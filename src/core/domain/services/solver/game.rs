@@ -0,0 +1,556 @@
+//! Abstracted two-player postflop games for the solvers.
+
+use std::fmt;
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_set::CardSet;
+use crate::core::domain::entities::hand_range::HandRange;
+use crate::core::domain::entities::hole_cards::{validate_no_duplicates, HoleCards};
+use crate::core::domain::errors::CardError;
+use crate::core::domain::primitives::{Action, Chips, PlayerId, Street};
+use crate::core::ports::outbound::{CacheKey, CacheKeyBuilder};
+
+/// Bet sizes used by `SolverGame::new`, as fractions of the pot.
+pub const DEFAULT_BET_SIZES: [f64; 2] = [0.5, 1.0];
+
+/// Raises allowed after the opening bet of each street by `SolverGame::new`.
+pub const DEFAULT_MAX_RAISES: usize = 1;
+
+/// Error describing a game the solvers cannot play.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolverError {
+    /// The board does not have four or five cards.
+    InvalidBoard {
+        /// Number of cards given.
+        cards: usize,
+    },
+    /// A card appears twice on the board.
+    DuplicateBoardCard(Card),
+    /// A player has no combination left once board cards are removed.
+    EmptyRange(PlayerId),
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidBoard { cards } => {
+                write!(f, "board must have 4 or 5 cards, got {cards}")
+            }
+            Self::DuplicateBoardCard(card) => write!(f, "duplicate board card: {card}"),
+            Self::EmptyRange(player) => write!(f, "range of {player} is empty"),
+        }
+    }
+}
+
+impl std::error::Error for SolverError {}
+
+impl From<CardError> for SolverError {
+    fn from(err: CardError) -> Self {
+        match err {
+            CardError::DuplicateCard(card) => Self::DuplicateBoardCard(card),
+            CardError::InvalidBoardSize(cards) => Self::InvalidBoard { cards },
+        }
+    }
+}
+
+/// A heads-up game starting on the turn or river, with bets limited to a few sizes.
+///
+/// Player 0 is out of position and acts first on each street; player 1 is in position.
+/// Both start with `stack` behind and `pot` already in the middle. Each street allows a
+/// bet of every size in `bet_sizes` (as a fraction of the pot) plus all in, and then up
+/// to `max_raises` raises sized the same way relative to the pot after calling. When
+/// the game starts on the turn the river card is a chance event.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::board::Board;
+/// use riverrun::core::domain::entities::hand_range::HandRange;
+/// use riverrun::core::domain::primitives::Chips;
+/// use riverrun::core::domain::services::solver::SolverGame;
+///
+/// let board = Board::with_cards(
+///     "Kh 9d 5c 2s 2h".split_whitespace().map(|c| c.parse().unwrap()).collect(),
+/// )
+/// .unwrap();
+/// let ranges: [HandRange; 2] = ["QQ".parse().unwrap(), "KK, 76s".parse().unwrap()];
+/// let game = SolverGame::new(board, ranges, Chips::new(100), Chips::new(100))
+///     .unwrap()
+///     .with_bet_sizes(vec![0.75])
+///     .with_max_raises(0);
+/// assert_eq!(game.bet_sizes(), &[0.75]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolverGame {
+    board: Board,
+    ranges: [HandRange; 2],
+    pot: Chips,
+    stack: Chips,
+    bet_sizes: Vec<f64>,
+    max_raises: usize,
+}
+
+/// `SolverGame` - Constructors
+impl SolverGame {
+    /// A game on `board` between `ranges`, using `DEFAULT_BET_SIZES` and
+    /// `DEFAULT_MAX_RAISES`.
+    ///
+    /// Combinations blocked by the board are removed from the ranges.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SolverError` if the board is not a turn or river board, repeats a card,
+    /// or leaves a range empty.
+    pub fn new(
+        board: Board,
+        ranges: [HandRange; 2],
+        pot: Chips,
+        stack: Chips,
+    ) -> Result<Self, SolverError> {
+        let cards = board.cards();
+        if !matches!(board.street(), Street::Turn | Street::River) {
+            return Err(SolverError::InvalidBoard { cards: cards.len() });
+        }
+        validate_no_duplicates(&[], &board)?;
+        let ranges = ranges.map(|range| range.without(cards));
+        if let Some(player) = ranges.iter().position(HandRange::is_empty) {
+            return Err(SolverError::EmptyRange(PlayerId::from(player)));
+        }
+        Ok(Self {
+            board,
+            ranges,
+            pot,
+            stack,
+            bet_sizes: DEFAULT_BET_SIZES.to_vec(),
+            max_raises: DEFAULT_MAX_RAISES,
+        })
+    }
+
+    /// This game betting only the given fractions of the pot, besides all in.
+    #[must_use]
+    pub fn with_bet_sizes(mut self, bet_sizes: Vec<f64>) -> Self {
+        self.bet_sizes = bet_sizes;
+        self
+    }
+
+    /// This game allowing `max_raises` raises after the opening bet of each street.
+    #[must_use]
+    pub const fn with_max_raises(mut self, max_raises: usize) -> Self {
+        self.max_raises = max_raises;
+        self
+    }
+}
+
+/// `SolverGame` - Accessors
+impl SolverGame {
+    /// Board the game starts on.
+    #[must_use]
+    pub const fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Range of `player`, without combinations blocked by the board.
+    #[must_use]
+    pub fn range(&self, player: PlayerId) -> Option<&HandRange> {
+        self.ranges.get(player.as_index())
+    }
+
+    /// Chips in the pot before the game starts.
+    #[must_use]
+    pub const fn pot(&self) -> Chips {
+        self.pot
+    }
+
+    /// Chips each player has behind.
+    #[must_use]
+    pub const fn stack(&self) -> Chips {
+        self.stack
+    }
+
+    /// Bet sizes as fractions of the pot.
+    #[must_use]
+    pub fn bet_sizes(&self) -> &[f64] {
+        &self.bet_sizes
+    }
+
+    /// Raises allowed after the opening bet of each street.
+    #[must_use]
+    pub const fn max_raises(&self) -> usize {
+        self.max_raises
+    }
+
+    /// Start of the `CacheKey` of solving this game with the solver named `solver`,
+    /// ranking showdowns with an evaluator of version `evaluator_version`.
+    ///
+    /// The key covers every setting of the game; callers add the solver's own
+    /// settings, such as its iterations, before finishing it. The board is kept in
+    /// order, since a cached profile reports information sets with the board as dealt.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn cache_key(&self, solver: &str, evaluator_version: u32) -> CacheKeyBuilder {
+        let board: Vec<u8> = self.board.cards().iter().map(|card| card.index() as u8).collect();
+        let mut key = CacheKey::builder("solver")
+            .bytes(solver.as_bytes())
+            .u32(evaluator_version)
+            .bytes(&board);
+        for range in &self.ranges {
+            key = key.u64(range.combo_count() as u64);
+            for (combo, weight) in range.combos() {
+                let mut indexes = combo.cards().map(|card| card.index() as u8);
+                indexes.sort_unstable();
+                key = key.bytes(&indexes).f64(weight);
+            }
+        }
+        key = key
+            .u64(self.pot.value())
+            .u64(self.stack.value())
+            .u64(self.bet_sizes.len() as u64);
+        for &size in &self.bet_sizes {
+            key = key.f64(size);
+        }
+        key.u64(self.max_raises as u64)
+    }
+}
+
+/// `SolverGame` - Operations
+impl SolverGame {
+    /// The state before anyone has acted.
+    pub(super) fn root(&self) -> GameState {
+        GameState {
+            board: self.board.cards().to_vec(),
+            history: Vec::new(),
+            contributed: [0; 2],
+            street_bets: [0; 2],
+            to_act: 0,
+            street_actions: 0,
+            aggressions: 0,
+            node: Node::Decision,
+        }
+    }
+
+    /// Actions available at `state` to the player to act.
+    pub(super) fn actions(&self, state: &GameState) -> Vec<Action> {
+        let player = state.to_act;
+        let opponent = 1 - player;
+        let behind = self.stack.value() - state.contributed[player];
+        let opponent_behind = self.stack.value() - state.contributed[opponent];
+        let to_call = state.street_bets[opponent] - state.street_bets[player];
+        let pot = self.pot.value() + state.contributed[0] + state.contributed[1];
+
+        let mut actions = Vec::new();
+        if to_call == 0 {
+            actions.push(Action::Check);
+            if behind > 0 && opponent_behind > 0 {
+                for size in self.sizes(pot, behind) {
+                    actions.push(if size >= behind {
+                        Action::AllIn(Chips::new(behind))
+                    } else {
+                        Action::Bet(Chips::new(size))
+                    });
+                }
+                actions.push(Action::AllIn(Chips::new(behind)));
+            }
+        } else {
+            actions.push(Action::Fold);
+            actions.push(Action::Call(Chips::new(to_call.min(behind))));
+            if behind > to_call && opponent_behind > 0 && state.aggressions <= self.max_raises {
+                let raise_to = |size: u64| state.street_bets[opponent] + size;
+                for size in self.sizes(pot + to_call, behind) {
+                    let added = raise_to(size) - state.street_bets[player];
+                    actions.push(if added >= behind {
+                        Action::AllIn(Chips::new(behind))
+                    } else {
+                        Action::Raise(Chips::new(raise_to(size)))
+                    });
+                }
+                actions.push(Action::AllIn(Chips::new(behind)));
+            }
+        }
+        actions.dedup();
+        actions
+    }
+
+    /// Bet sizes for a pot of `pot`, capped at `behind` and never below one chip.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn sizes(&self, pot: u64, behind: u64) -> Vec<u64> {
+        let mut sizes: Vec<u64> = self
+            .bet_sizes
+            .iter()
+            .map(|fraction| ((pot as f64 * fraction).round() as u64).clamp(1, behind))
+            .collect();
+        sizes.sort_unstable();
+        sizes.dedup();
+        sizes
+    }
+
+    /// The state after `card` is dealt at a chance node.
+    pub(super) fn deal(&self, state: &GameState, card: Card) -> GameState {
+        let mut next = state.clone();
+        next.board.push(card);
+        next.street_bets = [0; 2];
+        next.street_actions = 0;
+        next.aggressions = 0;
+        next.to_act = 0;
+        let all_in = state
+            .contributed
+            .iter()
+            .any(|&contributed| contributed >= self.stack.value());
+        next.node = if all_in {
+            Node::Showdown
+        } else {
+            Node::Decision
+        };
+        next
+    }
+
+    /// Chips won by each player at a terminal `state`, relative to their stack at the
+    /// start of the game. `winner` is the showdown result: `Some(player)` or `None` for
+    /// a split.
+    #[allow(clippy::cast_precision_loss)]
+    pub(super) fn payoffs(&self, state: &GameState, winner: Option<usize>) -> [f64; 2] {
+        let pot = self.pot.value() as f64;
+        let matched = state.contributed[0].min(state.contributed[1]) as f64;
+        let winner = match state.node {
+            Node::Fold(folder) => Some(1 - folder),
+            _ => winner,
+        };
+        match winner {
+            Some(0) => [pot + matched, -matched],
+            Some(_) => [-matched, pot + matched],
+            None => [pot / 2.0, pot / 2.0],
+        }
+    }
+}
+
+/// Kind of node a `GameState` is at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Node {
+    /// A player is to act.
+    Decision,
+    /// The river card is to be dealt.
+    Chance,
+    /// The hand is over and goes to showdown.
+    Showdown,
+    /// The given player folded.
+    Fold(usize),
+}
+
+/// Public state of a game in progress.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct GameState {
+    pub(super) board: Vec<Card>,
+    pub(super) history: Vec<Action>,
+    pub(super) contributed: [u64; 2],
+    pub(super) street_bets: [u64; 2],
+    pub(super) to_act: usize,
+    pub(super) street_actions: usize,
+    pub(super) aggressions: usize,
+    pub(super) node: Node,
+}
+
+/// `GameState` - Operations
+impl GameState {
+    /// The state after the player to act takes `action`.
+    pub(super) fn apply(&self, action: Action) -> Self {
+        let mut next = self.clone();
+        let player = self.to_act;
+        let opponent = 1 - player;
+        next.history.push(action);
+        next.street_actions += 1;
+
+        let added = match action {
+            Action::Fold => {
+                next.node = Node::Fold(player);
+                return next;
+            }
+            Action::Check => 0,
+            Action::Raise(to) => to.value() - self.street_bets[player],
+            Action::Call(chips) | Action::Bet(chips) | Action::AllIn(chips) => chips.value(),
+        };
+        next.contributed[player] += added;
+        next.street_bets[player] += added;
+
+        let aggressive = next.street_bets[player] > next.street_bets[opponent];
+        if aggressive {
+            next.aggressions += 1;
+            next.to_act = opponent;
+        } else if action == Action::Check && next.street_actions < 2 {
+            next.to_act = opponent;
+        } else {
+            next.node = if next.board.len() < 5 {
+                Node::Chance
+            } else {
+                Node::Showdown
+            };
+        }
+        next
+    }
+}
+
+/// What a player knows when they act: their cards, the board, and every action so far.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InfoSet {
+    /// Player to act.
+    pub player: PlayerId,
+    /// The player's hole cards.
+    pub hole_cards: HoleCards,
+    /// Community cards, including a river dealt during the game.
+    pub board: Vec<Card>,
+    /// Actions taken since the start of the game, across streets.
+    pub history: Vec<Action>,
+}
+
+/// `InfoSet` - Constructors
+impl InfoSet {
+    /// The information set of `player` holding `hole_cards` after `history` on `board`.
+    #[must_use]
+    pub const fn new(
+        player: PlayerId,
+        hole_cards: HoleCards,
+        board: Vec<Card>,
+        history: Vec<Action>,
+    ) -> Self {
+        Self {
+            player,
+            hole_cards,
+            board,
+            history,
+        }
+    }
+}
+
+/// `InfoSet` - Accessors
+impl InfoSet {
+    /// Order-independent key for the information set.
+    pub(super) fn key(&self) -> InfoSetKey {
+        InfoSetKey::new(
+            self.player.as_index(),
+            self.hole_cards.cards(),
+            &self.board,
+            &self.history,
+        )
+    }
+}
+
+/// Hashable form of an `InfoSet`, with cards stored as sets so their order does not
+/// matter.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) struct InfoSetKey {
+    player: usize,
+    hole_cards: CardSet,
+    board: CardSet,
+    history: Vec<Action>,
+}
+
+/// `InfoSetKey` - Constructors
+impl InfoSetKey {
+    pub(super) fn new(
+        player: usize,
+        hole_cards: &[Card],
+        board: &[Card],
+        history: &[Action],
+    ) -> Self {
+        Self {
+            player,
+            hole_cards: CardSet::from_cards(hole_cards),
+            board: CardSet::from_cards(board),
+            history: history.to_vec(),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(text: &str) -> Vec<Card> {
+        text.split_whitespace()
+            .map(|c| c.parse().unwrap())
+            .collect()
+    }
+
+    fn ranges() -> [HandRange; 2] {
+        ["QQ".parse().unwrap(), "KK".parse().unwrap()]
+    }
+
+    fn river_game() -> SolverGame {
+        let board = Board::with_cards(cards("Kh 9d 5c 2s 2h")).unwrap();
+        SolverGame::new(board, ranges(), Chips::new(100), Chips::new(200))
+            .unwrap()
+            .with_bet_sizes(vec![0.5, 1.0])
+    }
+
+    #[test]
+    fn test_actions_follow_bet_abstraction() {
+        let game = river_game();
+        let root = game.root();
+        assert_eq!(
+            game.actions(&root),
+            vec![
+                Action::Check,
+                Action::Bet(Chips::new(50)),
+                Action::Bet(Chips::new(100)),
+                Action::AllIn(Chips::new(200)),
+            ]
+        );
+
+        // Half-pot raise of a half-pot bet: 50 to call, then half of the 200 pot
+        let bet = root.apply(Action::Bet(Chips::new(50)));
+        assert_eq!(
+            game.actions(&bet),
+            vec![
+                Action::Fold,
+                Action::Call(Chips::new(50)),
+                Action::Raise(Chips::new(150)),
+                Action::AllIn(Chips::new(200)),
+            ]
+        );
+
+        // The one raise is used up
+        let raise = bet.apply(Action::Raise(Chips::new(150)));
+        assert_eq!(
+            game.actions(&raise),
+            vec![Action::Fold, Action::Call(Chips::new(100))]
+        );
+        let called = raise.apply(Action::Call(Chips::new(100)));
+        assert_eq!(called.node, Node::Showdown);
+        let [first, second] = game.payoffs(&called, Some(1));
+        assert!((first + 150.0).abs() < 1e-12);
+        assert!((second - 250.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_turn_closes_into_chance_node() {
+        let board = Board::with_cards(cards("Kh 9d 5c 2s")).unwrap();
+        let game = SolverGame::new(board, ranges(), Chips::new(100), Chips::new(200)).unwrap();
+        let checked = game.root().apply(Action::Check);
+        assert_eq!(checked.node, Node::Decision);
+        let closed = checked.apply(Action::Check);
+        assert_eq!(closed.node, Node::Chance);
+
+        let river = game.deal(&closed, "3d".parse().unwrap());
+        assert_eq!(river.node, Node::Decision);
+        assert_eq!(river.board.len(), 5);
+        assert_eq!(river.to_act, 0);
+    }
+
+    #[test]
+    fn test_invalid_games_are_rejected() {
+        let flop = Board::with_cards(cards("Kh 9d 5c")).unwrap();
+        assert_eq!(
+            SolverGame::new(flop, ranges(), Chips::new(10), Chips::new(10)).unwrap_err(),
+            SolverError::InvalidBoard { cards: 3 }
+        );
+
+        let blocked = Board::with_cards(cards("Kh Kd Ks Kc 2h")).unwrap();
+        assert_eq!(
+            SolverGame::new(blocked, ranges(), Chips::new(10), Chips::new(10)).unwrap_err(),
+            SolverError::EmptyRange(PlayerId::new(1))
+        );
+    }
+}
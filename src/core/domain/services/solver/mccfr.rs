@@ -0,0 +1,391 @@
+//! Monte Carlo counterfactual regret minimization.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::deck::Deck;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::primitives::{Action, PlayerId};
+use crate::core::ports::inbound::HandEvaluator;
+
+use super::game::{GameState, InfoSet, InfoSetKey, Node, SolverGame};
+use super::profile::{StrategyProfile, normalized};
+
+/// Regrets and average strategy accumulated at one information set.
+#[derive(Clone, Debug)]
+struct InfoSetData {
+    info_set: InfoSet,
+    actions: Vec<Action>,
+    regrets: Vec<f64>,
+    strategy_sum: Vec<f64>,
+}
+
+/// `InfoSetData` - Operations
+impl InfoSetData {
+    /// Current strategy by regret matching: actions in proportion to their positive
+    /// regret, or uniformly when none is positive.
+    fn current_strategy(&self) -> Vec<f64> {
+        normalized(self.regrets.iter().map(|&regret| regret.max(0.0)).collect())
+    }
+}
+
+/// Approximates a Nash equilibrium of a `SolverGame` by external-sampling MCCFR.
+///
+/// Every iteration deals each player a hand from their range, weighted by range
+/// weight, and then walks the game tree once per player. The walking player tries all
+/// of their actions and updates regrets; the opponent's actions and the river card are
+/// sampled. The average strategy over all iterations converges to an equilibrium, which
+/// `profile` reports per information set.
+///
+/// Hands are dealt from a seeded generator, so runs are reproducible.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::board::Board;
+/// use riverrun::core::domain::entities::hand_range::HandRange;
+/// use riverrun::core::domain::entities::hole_cards::HoleCards;
+/// use riverrun::core::domain::primitives::{Action, Chips, PlayerId};
+/// use riverrun::core::domain::services::CactusKevEvaluator;
+/// use riverrun::core::domain::services::solver::{InfoSet, MccfrSolver, SolverGame};
+///
+/// let board: Vec<_> = "Kh 9d 5c 2s 2h".split_whitespace().map(|c| c.parse().unwrap()).collect();
+/// // A bluff catcher against a range of nothing but full houses
+/// let ranges: [HandRange; 2] = ["QQ".parse().unwrap(), "KK".parse().unwrap()];
+/// let game = SolverGame::new(Board::with_cards(board.clone()).unwrap(), ranges, Chips::new(100), Chips::new(200))
+///     .unwrap()
+///     .with_bet_sizes(vec![1.0])
+///     .with_max_raises(0);
+///
+/// let mut solver = MccfrSolver::new(game, CactusKevEvaluator::new());
+/// solver.run(2000);
+/// let profile = solver.profile();
+///
+/// let queens = HoleCards::new("Qs".parse().unwrap(), "Qc".parse().unwrap());
+/// let facing_bet = InfoSet::new(
+///     PlayerId::new(0),
+///     queens,
+///     board,
+///     vec![Action::Check, Action::Bet(Chips::new(100))],
+/// );
+/// assert!(profile.frequency(&facing_bet, Action::Fold).unwrap() > 0.9);
+/// ```
+pub struct MccfrSolver<E: HandEvaluator> {
+    game: SolverGame,
+    evaluator: E,
+    combos: [Vec<(HoleCards, f64)>; 2],
+    info_sets: HashMap<InfoSetKey, InfoSetData>,
+    iterations: u64,
+    rng: StdRng,
+}
+
+/// `MccfrSolver` - Constructors
+impl<E: HandEvaluator> MccfrSolver<E> {
+    /// A solver for `game` that evaluates showdowns with `evaluator`.
+    pub fn new(game: SolverGame, evaluator: E) -> Self {
+        let combos = [PlayerId::new(0), PlayerId::new(1)].map(|player| {
+            game.range(player)
+                .map(|range| range.combos().collect())
+                .unwrap_or_default()
+        });
+        Self {
+            game,
+            evaluator,
+            combos,
+            info_sets: HashMap::new(),
+            iterations: 0,
+            rng: StdRng::seed_from_u64(0),
+        }
+    }
+
+    /// Deals hands and river cards from `seed` instead of the default seed.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+}
+
+/// `MccfrSolver` - Accessors
+impl<E: HandEvaluator> MccfrSolver<E> {
+    /// The game being solved.
+    pub const fn game(&self) -> &SolverGame {
+        &self.game
+    }
+
+    /// Iterations run so far.
+    pub const fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    /// Information sets reached so far.
+    pub fn info_set_count(&self) -> usize {
+        self.info_sets.len()
+    }
+
+    /// The average strategy over every iteration so far.
+    pub fn profile(&self) -> StrategyProfile {
+        let strategies = self
+            .info_sets
+            .iter()
+            .map(|(key, data)| {
+                let frequencies = normalized(data.strategy_sum.clone());
+                let strategy = data.actions.iter().copied().zip(frequencies).collect();
+                (key.clone(), (data.info_set.clone(), strategy))
+            })
+            .collect();
+        StrategyProfile::new(strategies, self.iterations)
+    }
+}
+
+/// `MccfrSolver` - Operations
+impl<E: HandEvaluator> MccfrSolver<E> {
+    /// Runs `iterations` more iterations.
+    pub fn run(&mut self, iterations: u64) {
+        let root = self.game.root();
+        for _ in 0..iterations {
+            let Some(hands) = self.deal_hands() else {
+                continue;
+            };
+            for traverser in 0..2 {
+                self.traverse(&root, traverser, hands);
+            }
+            self.iterations += 1;
+        }
+    }
+
+    /// Deals a non-overlapping hand to each player, or `None` if the ranges only hold
+    /// overlapping hands.
+    fn deal_hands(&mut self) -> Option<[HoleCards; 2]> {
+        const ATTEMPTS: usize = 100;
+        for _ in 0..ATTEMPTS {
+            let first = sample(&self.combos[0], &mut self.rng)?;
+            let second = sample(&self.combos[1], &mut self.rng)?;
+            if !first
+                .cards()
+                .iter()
+                .any(|card| second.cards().contains(card))
+            {
+                return Some([first, second]);
+            }
+        }
+        None
+    }
+
+    /// Walks the tree below `state`, returning the expected payoff to `traverser`.
+    fn traverse(&mut self, state: &GameState, traverser: usize, hands: [HoleCards; 2]) -> f64 {
+        match state.node {
+            Node::Fold(_) => self.game.payoffs(state, None)[traverser],
+            Node::Showdown => {
+                let winner = self.showdown(&state.board, hands);
+                self.game.payoffs(state, winner)[traverser]
+            }
+            Node::Chance => {
+                let mut dead = state.board.clone();
+                dead.extend(hands.iter().flat_map(HoleCards::cards));
+                let deck = Deck::excluding(&dead);
+                let card = deck.cards()[self.rng.random_range(0..deck.remaining())];
+                let next = self.game.deal(state, card);
+                self.traverse(&next, traverser, hands)
+            }
+            Node::Decision => self.decide(state, traverser, hands),
+        }
+    }
+
+    /// Handles a decision node during `traverse`.
+    fn decide(&mut self, state: &GameState, traverser: usize, hands: [HoleCards; 2]) -> f64 {
+        let player = state.to_act;
+        let key = InfoSetKey::new(player, hands[player].cards(), &state.board, &state.history);
+        let data = self.info_sets.entry(key.clone()).or_insert_with(|| {
+            let actions = self.game.actions(state);
+            InfoSetData {
+                info_set: InfoSet::new(
+                    PlayerId::from(player),
+                    hands[player],
+                    state.board.clone(),
+                    state.history.clone(),
+                ),
+                regrets: vec![0.0; actions.len()],
+                strategy_sum: vec![0.0; actions.len()],
+                actions,
+            }
+        });
+        let strategy = data.current_strategy();
+        let actions = data.actions.clone();
+
+        if player != traverser {
+            for (sum, probability) in data.strategy_sum.iter_mut().zip(&strategy) {
+                *sum += probability;
+            }
+            let index = self.sample_index(&strategy);
+            let next = state.apply(actions[index]);
+            return self.traverse(&next, traverser, hands);
+        }
+
+        let utilities: Vec<f64> = actions
+            .iter()
+            .map(|&action| {
+                let next = state.apply(action);
+                self.traverse(&next, traverser, hands)
+            })
+            .collect();
+        let value: f64 = utilities
+            .iter()
+            .zip(&strategy)
+            .map(|(utility, probability)| utility * probability)
+            .sum();
+        if let Some(data) = self.info_sets.get_mut(&key) {
+            for (regret, utility) in data.regrets.iter_mut().zip(&utilities) {
+                *regret += utility - value;
+            }
+        }
+        value
+    }
+
+    /// Index drawn with the given probabilities.
+    fn sample_index(&mut self, probabilities: &[f64]) -> usize {
+        let mut target = self.rng.random::<f64>();
+        for (index, probability) in probabilities.iter().enumerate() {
+            if target < *probability {
+                return index;
+            }
+            target -= probability;
+        }
+        probabilities.len() - 1
+    }
+
+    /// Winning player on a complete `board`, or `None` for a split.
+    fn showdown(&self, board: &[Card], hands: [HoleCards; 2]) -> Option<usize> {
+        let board = <[Card; 5]>::try_from(board).ok()?;
+        let [first, second] = hands.map(|hand| {
+            self.evaluator
+                .evaluate_7cards_fast(&hand.combine_with_board(board))
+        });
+        match first.cmp(&second) {
+            std::cmp::Ordering::Less => Some(0),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some(1),
+        }
+    }
+}
+
+impl<E: HandEvaluator> fmt::Debug for MccfrSolver<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MccfrSolver")
+            .field("game", &self.game)
+            .field("iterations", &self.iterations)
+            .field("info_sets", &self.info_sets.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Hand drawn from `combos` in proportion to their weights.
+fn sample(combos: &[(HoleCards, f64)], rng: &mut StdRng) -> Option<HoleCards> {
+    let total: f64 = combos.iter().map(|&(_, weight)| weight).sum();
+    let mut target = rng.random::<f64>() * total;
+    for &(combo, weight) in combos {
+        if target < weight {
+            return Some(combo);
+        }
+        target -= weight;
+    }
+    combos.last().map(|&(combo, _)| combo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::board::Board;
+    use crate::core::domain::entities::hand_range::HandRange;
+    use crate::core::domain::primitives::Chips;
+    use crate::core::domain::services::evaluation::CactusKevEvaluator;
+
+    fn cards(text: &str) -> Vec<Card> {
+        text.split_whitespace()
+            .map(|c| c.parse().unwrap())
+            .collect()
+    }
+
+    fn hand(text: &str) -> HoleCards {
+        let cards = cards(text);
+        HoleCards::new(cards[0], cards[1])
+    }
+
+    fn solver(board: &str, ranges: [&str; 2]) -> MccfrSolver<CactusKevEvaluator> {
+        let board = Board::with_cards(cards(board)).unwrap();
+        let ranges = ranges.map(|range| range.parse::<HandRange>().unwrap());
+        let game = SolverGame::new(board, ranges, Chips::new(100), Chips::new(200))
+            .unwrap()
+            .with_bet_sizes(vec![1.0])
+            .with_max_raises(0);
+        MccfrSolver::new(game, CactusKevEvaluator::new())
+    }
+
+    #[test]
+    fn test_bluff_catcher_calls_against_only_air() {
+        // In position holds nothing but missed draws
+        let mut solver = solver("Kh 9d 5c 2s 2h", ["QQ", "76s"]);
+        solver.run(3000);
+        let profile = solver.profile();
+
+        let facing_bet = InfoSet::new(
+            PlayerId::new(0),
+            hand("Qs Qc"),
+            cards("Kh 9d 5c 2s 2h"),
+            vec![Action::Check, Action::Bet(Chips::new(100))],
+        );
+        assert!(
+            profile
+                .frequency(&facing_bet, Action::Call(Chips::new(100)))
+                .unwrap()
+                > 0.9
+        );
+        // Card order does not matter
+        let reordered = InfoSet::new(
+            PlayerId::new(0),
+            hand("Qc Qs"),
+            cards("2h 2s 5c 9d Kh"),
+            facing_bet.history.clone(),
+        );
+        assert_eq!(profile.strategy(&reordered), profile.strategy(&facing_bet));
+    }
+
+    #[test]
+    fn test_nut_hand_bets_for_value() {
+        let mut solver = solver("Kh 9d 5c 2s 2h", ["QQ, 76s", "KK"]);
+        solver.run(3000);
+        let profile = solver.profile();
+
+        let checked_to = InfoSet::new(
+            PlayerId::new(1),
+            hand("Ks Kd"),
+            cards("Kh 9d 5c 2s 2h"),
+            vec![Action::Check],
+        );
+        let check = profile.frequency(&checked_to, Action::Check).unwrap();
+        assert!(check < 0.1);
+    }
+
+    #[test]
+    fn test_turn_game_deals_river() {
+        let mut solver = solver("Kh 9d 5c 2s", ["QQ", "AK"]).with_seed(4);
+        solver.run(200);
+        assert_eq!(solver.iterations(), 200);
+
+        let profile = solver.profile();
+        assert_eq!(profile.len(), solver.info_set_count());
+        assert!(
+            profile
+                .iter()
+                .any(|(info_set, _)| info_set.board.len() == 5)
+        );
+        for (_, strategy) in profile.iter() {
+            let total: f64 = strategy.iter().map(|&(_, frequency)| frequency).sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+    }
+}
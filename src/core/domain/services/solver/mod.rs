@@ -0,0 +1,16 @@
+//! Game-theoretic solvers for small abstracted hold'em games.
+//!
+//! A `SolverGame` describes a heads-up spot from the turn or river with a handful of
+//! bet sizes. `MccfrSolver` approximates its equilibrium with Monte Carlo
+//! counterfactual regret minimization, and `RiverSolver` solves river spots exactly
+//! across both full ranges with CFR+.
+
+mod game;
+mod mccfr;
+mod profile;
+mod river;
+
+pub use game::{DEFAULT_BET_SIZES, DEFAULT_MAX_RAISES, InfoSet, SolverError, SolverGame};
+pub use mccfr::MccfrSolver;
+pub use profile::StrategyProfile;
+pub use river::RiverSolver;
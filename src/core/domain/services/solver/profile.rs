@@ -0,0 +1,208 @@
+//! Strategies found by the solvers.
+
+use std::collections::HashMap;
+
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::primitives::{Action, Chips, PlayerId};
+
+use super::game::{InfoSet, InfoSetKey};
+
+/// Average strategy found by a solver, queryable per information set.
+#[derive(Clone, Debug)]
+pub struct StrategyProfile {
+    strategies: HashMap<InfoSetKey, (InfoSet, Vec<(Action, f64)>)>,
+    iterations: u64,
+}
+
+/// `StrategyProfile` - Constructors
+impl StrategyProfile {
+    /// A profile of the given strategies, keyed by information set.
+    pub(super) const fn new(
+        strategies: HashMap<InfoSetKey, (InfoSet, Vec<(Action, f64)>)>,
+        iterations: u64,
+    ) -> Self {
+        Self {
+            strategies,
+            iterations,
+        }
+    }
+}
+
+/// `StrategyProfile` - Accessors
+impl StrategyProfile {
+    /// Iterations the profile was averaged over.
+    #[must_use]
+    pub const fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    /// Number of information sets in the profile.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strategies.len()
+    }
+
+    /// Returns whether the profile has no information sets.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strategies.is_empty()
+    }
+
+    /// Each available action at `info_set` with how often to take it, or `None` if the
+    /// solver never reached it. The order of hole and board cards does not matter.
+    #[must_use]
+    pub fn strategy(&self, info_set: &InfoSet) -> Option<&[(Action, f64)]> {
+        self.strategies
+            .get(&info_set.key())
+            .map(|(_, strategy)| strategy.as_slice())
+    }
+
+    /// How often to take `action` at `info_set`: 0.0 if it is not available there, and
+    /// `None` if the solver never reached the information set.
+    #[must_use]
+    pub fn frequency(&self, info_set: &InfoSet, action: Action) -> Option<f64> {
+        let strategy = self.strategy(info_set)?;
+        Some(
+            strategy
+                .iter()
+                .find(|(candidate, _)| *candidate == action)
+                .map_or(0.0, |&(_, frequency)| frequency),
+        )
+    }
+
+    /// Every information set with its strategy, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&InfoSet, &[(Action, f64)])> {
+        self.strategies
+            .values()
+            .map(|(info_set, strategy)| (info_set, strategy.as_slice()))
+    }
+}
+
+/// `StrategyProfile` - Operations
+impl StrategyProfile {
+    /// The profile encoded as bytes, for storing in a `ResultCache`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let len = |bytes: &mut Vec<u8>, len: usize| {
+            bytes.extend_from_slice(&(len as u64).to_le_bytes());
+        };
+        bytes.extend_from_slice(&self.iterations.to_le_bytes());
+        len(&mut bytes, self.strategies.len());
+        for (info_set, strategy) in self.strategies.values() {
+            bytes.push(info_set.player.as_index() as u8);
+            bytes.extend(info_set.hole_cards.cards().map(|card| card.index() as u8));
+            len(&mut bytes, info_set.board.len());
+            bytes.extend(info_set.board.iter().map(|card| card.index() as u8));
+            len(&mut bytes, info_set.history.len());
+            for &action in &info_set.history {
+                write_action(&mut bytes, action);
+            }
+            len(&mut bytes, strategy.len());
+            for &(action, frequency) in strategy {
+                write_action(&mut bytes, action);
+                bytes.extend_from_slice(&frequency.to_bits().to_le_bytes());
+            }
+        }
+        bytes
+    }
+
+    /// The profile encoded in `bytes` by `to_bytes`, or `None` if they are not one.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader(bytes);
+        let iterations = reader.u64()?;
+        let entries = reader.len()?;
+        let mut strategies = HashMap::new();
+        for _ in 0..entries {
+            let player = PlayerId::new(reader.u8()?);
+            let hole_cards = HoleCards::new(reader.card()?, reader.card()?);
+            let board = (0..reader.len()?)
+                .map(|_| reader.card())
+                .collect::<Option<Vec<_>>>()?;
+            let history = (0..reader.len()?)
+                .map(|_| reader.action())
+                .collect::<Option<Vec<_>>>()?;
+            let strategy = (0..reader.len()?)
+                .map(|_| Some((reader.action()?, f64::from_bits(reader.u64()?))))
+                .collect::<Option<Vec<_>>>()?;
+            let info_set = InfoSet::new(player, hole_cards, board, history);
+            strategies.insert(info_set.key(), (info_set, strategy));
+        }
+        reader.0.is_empty().then(|| Self::new(strategies, iterations))
+    }
+}
+
+/// Append `action` to `bytes` as a tag and its chips.
+fn write_action(bytes: &mut Vec<u8>, action: Action) {
+    let tag = match action {
+        Action::Fold => 0,
+        Action::Check => 1,
+        Action::Call(_) => 2,
+        Action::Bet(_) => 3,
+        Action::Raise(_) => 4,
+        Action::AllIn(_) => 5,
+    };
+    bytes.push(tag);
+    bytes.extend_from_slice(&action.amount().value().to_le_bytes());
+}
+
+/// Bytes of an encoded profile not read yet.
+struct Reader<'a>(&'a [u8]);
+
+/// `Reader` - Operations
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let (head, rest) = self.0.split_first_chunk()?;
+        self.0 = rest;
+        Some(*head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take().map(|[byte]| byte)
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take().map(u64::from_le_bytes)
+    }
+
+    /// A length, which can never exceed the bytes left, so a corrupt one fails fast.
+    fn len(&mut self) -> Option<usize> {
+        usize::try_from(self.u64()?)
+            .ok()
+            .filter(|&len| len <= self.0.len())
+    }
+
+    fn card(&mut self) -> Option<Card> {
+        Card::from_index(usize::from(self.u8()?))
+    }
+
+    fn action(&mut self) -> Option<Action> {
+        let tag = self.u8()?;
+        let chips = Chips::new(self.u64()?);
+        match tag {
+            0 => Some(Action::Fold),
+            1 => Some(Action::Check),
+            2 => Some(Action::Call(chips)),
+            3 => Some(Action::Bet(chips)),
+            4 => Some(Action::Raise(chips)),
+            5 => Some(Action::AllIn(chips)),
+            _ => None,
+        }
+    }
+}
+
+/// `values` scaled to sum to 1.0, or uniform if they sum to zero.
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn normalized(mut values: Vec<f64>) -> Vec<f64> {
+    let total: f64 = values.iter().sum();
+    if total > 0.0 {
+        for value in &mut values {
+            *value /= total;
+        }
+    } else {
+        let uniform = 1.0 / values.len() as f64;
+        values.fill(uniform);
+    }
+    values
+}
@@ -0,0 +1,555 @@
+//! Exact river subgame solving with CFR+.
+
+use std::collections::HashMap;
+
+use crate::core::domain::entities::card_set::CardSet;
+use crate::core::domain::entities::hand_range::HandRange;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::primitives::{Action, PlayerId};
+use crate::core::ports::inbound::HandEvaluator;
+
+use super::game::{GameState, InfoSet, Node, SolverError, SolverGame};
+use super::profile::{StrategyProfile, normalized};
+
+/// A combination of a player's range with its precomputed showdown strength.
+#[derive(Clone, Debug)]
+struct RangeHand {
+    hole_cards: HoleCards,
+    mask: CardSet,
+    weight: f64,
+    strength: u16,
+}
+
+/// A node of the betting tree with the regrets and strategy sums of every hand of the
+/// player to act, stored hand by hand.
+#[derive(Clone, Debug)]
+struct TreeNode {
+    state: GameState,
+    children: Vec<(Action, usize)>,
+    regrets: Vec<f64>,
+    strategy_sum: Vec<f64>,
+}
+
+/// What a walk of the tree computes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Walk {
+    /// Update regrets and strategy sums of the walking player.
+    Train,
+    /// Value of the walking player's best response to the average strategy.
+    BestResponse,
+    /// Value of the average strategy.
+    Evaluate,
+}
+
+/// New regrets and added strategy weight for one hand at one node, from a training
+/// walk.
+#[derive(Clone, Debug)]
+struct Update {
+    node: usize,
+    hand: usize,
+    regrets: Vec<f64>,
+    strategy: Vec<f64>,
+}
+
+/// Solves a river `SolverGame` exactly, across every hand of both ranges, with CFR+.
+///
+/// Unlike `MccfrSolver` nothing is sampled: each iteration walks the full betting tree
+/// once per player, carrying the probability of every hand reaching each node, so
+/// strategies converge quickly and `exploitability` measures how far from equilibrium
+/// they still are. The cost of a showdown grows with the product of the range sizes,
+/// so this suits a single river spot rather than a whole game.
+///
+/// EVs are in chips won from the start of the river, with the starting pot counting as
+/// won by whoever takes it down, so the two players' EVs sum to the pot.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::board::Board;
+/// use riverrun::core::domain::entities::hand_range::HandRange;
+/// use riverrun::core::domain::primitives::{Chips, PlayerId};
+/// use riverrun::core::domain::services::CactusKevEvaluator;
+/// use riverrun::core::domain::services::solver::{RiverSolver, SolverGame};
+///
+/// let board = Board::with_cards(
+///     "Kh 9d 5c 2s 2h".split_whitespace().map(|c| c.parse().unwrap()).collect(),
+/// )
+/// .unwrap();
+/// let ranges: [HandRange; 2] = ["QQ".parse().unwrap(), "KK, 76s".parse().unwrap()];
+/// let game = SolverGame::new(board, ranges, Chips::new(100), Chips::new(100)).unwrap();
+///
+/// let mut solver = RiverSolver::new(game, &CactusKevEvaluator::new()).unwrap();
+/// solver.run(500);
+/// assert!(solver.exploitability() < 1.0);
+/// let total = solver.range_ev(PlayerId::new(0)) + solver.range_ev(PlayerId::new(1));
+/// assert!((total - 100.0).abs() < 1e-6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RiverSolver {
+    game: SolverGame,
+    hands: [Vec<RangeHand>; 2],
+    nodes: Vec<TreeNode>,
+    iterations: u64,
+}
+
+/// `RiverSolver` - Constructors
+impl RiverSolver {
+    /// A solver for `game`, ranking showdowns with `evaluator`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SolverError::InvalidBoard` if the game starts before the river.
+    pub fn new(game: SolverGame, evaluator: &impl HandEvaluator) -> Result<Self, SolverError> {
+        let Some(board) = game.board().as_array() else {
+            return Err(SolverError::InvalidBoard {
+                cards: game.board().len(),
+            });
+        };
+        let hands = [PlayerId::new(0), PlayerId::new(1)].map(|player| {
+            game.range(player)
+                .into_iter()
+                .flat_map(HandRange::combos)
+                .map(|(hole_cards, weight)| RangeHand {
+                    hole_cards,
+                    mask: CardSet::from_cards(hole_cards.cards()),
+                    weight,
+                    strength: evaluator.evaluate_7cards_fast(&hole_cards.combine_with_board(board)),
+                })
+                .collect()
+        });
+        let mut solver = Self {
+            game,
+            hands,
+            nodes: Vec::new(),
+            iterations: 0,
+        };
+        let root = solver.game.root();
+        solver.build(&root);
+        Ok(solver)
+    }
+
+    /// Adds `state` and everything below it to the tree, returning its index.
+    fn build(&mut self, state: &GameState) -> usize {
+        let index = self.nodes.len();
+        let actions = if state.node == Node::Decision {
+            self.game.actions(state)
+        } else {
+            Vec::new()
+        };
+        let size = actions.len() * self.hands[state.to_act].len();
+        self.nodes.push(TreeNode {
+            state: state.clone(),
+            children: Vec::new(),
+            regrets: vec![0.0; size],
+            strategy_sum: vec![0.0; size],
+        });
+        for action in actions {
+            let child = self.build(&state.apply(action));
+            self.nodes[index].children.push((action, child));
+        }
+        index
+    }
+}
+
+/// `RiverSolver` - Accessors
+impl RiverSolver {
+    /// The game being solved.
+    #[must_use]
+    pub const fn game(&self) -> &SolverGame {
+        &self.game
+    }
+
+    /// Iterations run so far.
+    #[must_use]
+    pub const fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    /// Number of nodes in the betting tree, including terminal ones.
+    #[must_use]
+    pub const fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Average strategy of the player to act with `hole_cards` after `history`, or
+    /// `None` if no such decision exists or the hand is not in their range.
+    #[must_use]
+    pub fn strategy(
+        &self,
+        history: &[Action],
+        hole_cards: &HoleCards,
+    ) -> Option<Vec<(Action, f64)>> {
+        let index = self.find(history)?;
+        let node = &self.nodes[index];
+        let hand = self.hand_index(node.state.to_act, *hole_cards)?;
+        let actions = node.children.iter().map(|&(action, _)| action);
+        Some(actions.zip(self.average_strategy(index, hand)).collect())
+    }
+
+    /// Average strategy at every decision, for every hand, as a `StrategyProfile`.
+    #[must_use]
+    pub fn profile(&self) -> StrategyProfile {
+        let mut strategies = HashMap::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            if node.children.is_empty() {
+                continue;
+            }
+            let player = node.state.to_act;
+            for (hand, range_hand) in self.hands[player].iter().enumerate() {
+                let info_set = InfoSet::new(
+                    PlayerId::from(player),
+                    range_hand.hole_cards,
+                    node.state.board.clone(),
+                    node.state.history.clone(),
+                );
+                let actions = node.children.iter().map(|&(action, _)| action);
+                let strategy = actions.zip(self.average_strategy(index, hand)).collect();
+                strategies.insert(info_set.key(), (info_set, strategy));
+            }
+        }
+        StrategyProfile::new(strategies, self.iterations)
+    }
+
+    /// EV of `player` holding `hole_cards` when both players follow the average
+    /// strategy, or `None` if the hand is not in their range or every opposing hand is
+    /// blocked.
+    #[must_use]
+    pub fn ev(&self, player: PlayerId, hole_cards: &HoleCards) -> Option<f64> {
+        let player = player.as_index();
+        let hand = self.hand_index(player, *hole_cards)?;
+        let values = self.values(player, Walk::Evaluate, &mut Vec::new());
+        let reach = self.opposing_weight(player, hand);
+        (reach > 0.0).then(|| values[hand] / reach)
+    }
+
+    /// EV of `player`'s whole range when both players follow the average strategy.
+    #[must_use]
+    pub fn range_ev(&self, player: PlayerId) -> f64 {
+        self.range_value(player.as_index(), Walk::Evaluate)
+    }
+
+    /// Average chips per hand that each player could gain by deviating from the average
+    /// strategy, against an opponent who does not. Zero at an exact equilibrium.
+    #[must_use]
+    pub fn exploitability(&self) -> f64 {
+        let best_responses =
+            self.range_value(0, Walk::BestResponse) + self.range_value(1, Walk::BestResponse);
+        #[allow(clippy::cast_precision_loss)]
+        let pot = self.game.pot().value() as f64;
+        (best_responses - pot) / 2.0
+    }
+}
+
+/// `RiverSolver` - Operations
+impl RiverSolver {
+    /// Runs `iterations` more iterations, each updating both players in turn.
+    pub fn run(&mut self, iterations: u64) {
+        for _ in 0..iterations {
+            self.iterations += 1;
+            for player in 0..2 {
+                self.walk_root(player, Walk::Train);
+            }
+        }
+    }
+
+    /// Walks the whole tree for `player` from their range weights and applies any
+    /// updates the walk produced.
+    fn walk_root(&mut self, player: usize, walk: Walk) -> Vec<f64> {
+        let mut updates = Vec::new();
+        let values = self.values(player, walk, &mut updates);
+        for update in updates {
+            let node = &mut self.nodes[update.node];
+            let slots = update.hand * node.children.len()..;
+            for (regret, new) in node.regrets[slots.clone()].iter_mut().zip(update.regrets) {
+                *regret = new;
+            }
+            for (sum, added) in node.strategy_sum[slots].iter_mut().zip(update.strategy) {
+                *sum += added;
+            }
+        }
+        values
+    }
+
+    /// Values of `player`'s hands from their range weights, collecting training
+    /// updates into `updates`.
+    fn values(&self, player: usize, walk: Walk, updates: &mut Vec<Update>) -> Vec<f64> {
+        let reach = self
+            .hands
+            .each_ref()
+            .map(|hands| hands.iter().map(|hand| hand.weight).collect::<Vec<f64>>());
+        self.walk(0, player, [&reach[0], &reach[1]], walk, updates)
+    }
+
+    /// Value of `player`'s whole range, averaged over every unblocked pair of hands.
+    fn range_value(&self, player: usize, walk: Walk) -> f64 {
+        let values = self.values(player, walk, &mut Vec::new());
+        let mut total = 0.0;
+        let mut pairs = 0.0;
+        for (hand, range_hand) in self.hands[player].iter().enumerate() {
+            total += range_hand.weight * values[hand];
+            pairs += range_hand.weight * self.opposing_weight(player, hand);
+        }
+        if pairs > 0.0 { total / pairs } else { 0.0 }
+    }
+
+    /// Returns each of `traverser`'s hands' value below node `index`, given how likely
+    /// each hand of each player is to reach it.
+    ///
+    /// Training updates are collected rather than applied: no node is visited twice in
+    /// one walk and strategies below a node do not depend on it, so applying them
+    /// afterwards gives the same result.
+    fn walk(
+        &self,
+        index: usize,
+        traverser: usize,
+        reach: [&[f64]; 2],
+        walk: Walk,
+        updates: &mut Vec<Update>,
+    ) -> Vec<f64> {
+        let node = &self.nodes[index];
+        if node.children.is_empty() {
+            return self.terminal(&node.state, traverser, reach[1 - traverser]);
+        }
+        let player = node.state.to_act;
+        let children = node.children.clone();
+        let num_hands = self.hands[player].len();
+        let strategies: Vec<Vec<f64>> = (0..num_hands)
+            .map(|hand| match walk {
+                Walk::Train => self.current_strategy(index, hand),
+                Walk::BestResponse | Walk::Evaluate => self.average_strategy(index, hand),
+            })
+            .collect();
+
+        if player != traverser {
+            let mut values = vec![0.0; self.hands[traverser].len()];
+            for (action, &(_, child)) in children.iter().enumerate() {
+                let child_reach: Vec<f64> = reach[player]
+                    .iter()
+                    .zip(&strategies)
+                    .map(|(reach, strategy)| reach * strategy[action])
+                    .collect();
+                let mut next = reach;
+                next[player] = &child_reach;
+                for (value, child_value) in values
+                    .iter_mut()
+                    .zip(self.walk(child, traverser, next, walk, updates))
+                {
+                    *value += child_value;
+                }
+            }
+            return values;
+        }
+
+        let child_values: Vec<Vec<f64>> = children
+            .iter()
+            .map(|&(_, child)| self.walk(child, traverser, reach, walk, updates))
+            .collect();
+        let num_actions = children.len();
+        #[allow(clippy::cast_precision_loss)]
+        let weight = self.iterations as f64;
+        let node = &self.nodes[index];
+        (0..num_hands)
+            .map(|hand| {
+                let utilities = child_values.iter().map(|values| values[hand]);
+                if walk == Walk::BestResponse {
+                    return utilities.fold(f64::NEG_INFINITY, f64::max);
+                }
+                let value: f64 = utilities
+                    .clone()
+                    .zip(&strategies[hand])
+                    .map(|(u, p)| u * p)
+                    .sum();
+                if walk == Walk::Train {
+                    let slot = hand * num_actions;
+                    updates.push(Update {
+                        node: index,
+                        hand,
+                        regrets: utilities
+                            .zip(&node.regrets[slot..slot + num_actions])
+                            .map(|(utility, regret)| (regret + utility - value).max(0.0))
+                            .collect(),
+                        strategy: strategies[hand]
+                            .iter()
+                            .map(|probability| weight * reach[player][hand] * probability)
+                            .collect(),
+                    });
+                }
+                value
+            })
+            .collect()
+    }
+
+    /// Each of `traverser`'s hands' value at a terminal `state`, summed over the
+    /// opposing hands weighted by `opposing_reach`.
+    fn terminal(&self, state: &GameState, traverser: usize, opposing_reach: &[f64]) -> Vec<f64> {
+        let opponent = 1 - traverser;
+        let folded = matches!(state.node, Node::Fold(_));
+        let fold_payoff = self.game.payoffs(state, None)[traverser];
+        let showdown =
+            [Some(0), Some(1), None].map(|winner| self.game.payoffs(state, winner)[traverser]);
+        self.hands[traverser]
+            .iter()
+            .map(|hand| {
+                self.hands[opponent]
+                    .iter()
+                    .zip(opposing_reach)
+                    .filter(|(other, _)| hand.mask.is_disjoint(other.mask))
+                    .map(|(other, reach)| {
+                        let payoff = if folded {
+                            fold_payoff
+                        } else {
+                            match hand.strength.cmp(&other.strength) {
+                                std::cmp::Ordering::Less => showdown[traverser],
+                                std::cmp::Ordering::Greater => showdown[opponent],
+                                std::cmp::Ordering::Equal => showdown[2],
+                            }
+                        };
+                        reach * payoff
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+/// `RiverSolver` - Helpers
+impl RiverSolver {
+    /// Index of the decision reached by `history`, if any.
+    fn find(&self, history: &[Action]) -> Option<usize> {
+        history
+            .iter()
+            .try_fold(0, |index, action| {
+                self.nodes[index]
+                    .children
+                    .iter()
+                    .find(|(candidate, _)| candidate == action)
+                    .map(|&(_, child)| child)
+            })
+            .filter(|&index| !self.nodes[index].children.is_empty())
+    }
+
+    /// Position of `hole_cards` in `player`'s range, in either card order.
+    fn hand_index(&self, player: usize, hole_cards: HoleCards) -> Option<usize> {
+        let target = CardSet::from_cards(hole_cards.cards());
+        self.hands
+            .get(player)?
+            .iter()
+            .position(|hand| hand.mask == target)
+    }
+
+    /// Total weight of opposing hands not blocked by `player`'s `hand`.
+    fn opposing_weight(&self, player: usize, hand: usize) -> f64 {
+        let mask = self.hands[player][hand].mask;
+        self.hands[1 - player]
+            .iter()
+            .filter(|other| other.mask.is_disjoint(mask))
+            .map(|other| other.weight)
+            .sum()
+    }
+
+    /// Regret-matching strategy of `hand` at node `index`.
+    fn current_strategy(&self, index: usize, hand: usize) -> Vec<f64> {
+        normalized(self.slots(&self.nodes[index].regrets, index, hand).to_vec())
+    }
+
+    /// Average strategy of `hand` at node `index`.
+    fn average_strategy(&self, index: usize, hand: usize) -> Vec<f64> {
+        normalized(
+            self.slots(&self.nodes[index].strategy_sum, index, hand)
+                .to_vec(),
+        )
+    }
+
+    /// The entries of `values` belonging to `hand` at node `index`.
+    fn slots<'a>(&self, values: &'a [f64], index: usize, hand: usize) -> &'a [f64] {
+        let num_actions = self.nodes[index].children.len();
+        &values[hand * num_actions..(hand + 1) * num_actions]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::board::Board;
+    use crate::core::domain::entities::card::Card;
+    use crate::core::domain::primitives::Chips;
+    use crate::core::domain::services::evaluation::CactusKevEvaluator;
+
+    const BOARD: &str = "Kh 9d 5c 2s 2h";
+
+    fn hand(text: &str) -> HoleCards {
+        let cards: Vec<Card> = text
+            .split_whitespace()
+            .map(|c| c.parse().unwrap())
+            .collect();
+        HoleCards::new(cards[0], cards[1])
+    }
+
+    /// A pot-sized river shove with a bluff catcher facing value hands and missed draws.
+    fn polarized() -> RiverSolver {
+        let board = Board::with_cards(
+            BOARD
+                .split_whitespace()
+                .map(|c| c.parse().unwrap())
+                .collect(),
+        )
+        .unwrap();
+        let ranges: [HandRange; 2] = ["QQ".parse().unwrap(), "KK, 76s".parse().unwrap()];
+        let game = SolverGame::new(board, ranges, Chips::new(100), Chips::new(100))
+            .unwrap()
+            .with_bet_sizes(vec![1.0]);
+        RiverSolver::new(game, &CactusKevEvaluator::new()).unwrap()
+    }
+
+    #[test]
+    fn test_polarized_river_matches_theory() {
+        let mut solver = polarized();
+        solver.run(2000);
+        assert!(solver.exploitability() < 0.5);
+
+        // Facing a pot-sized bet the bluff catcher calls half the time
+        let shove = [Action::Check, Action::AllIn(Chips::new(100))];
+        let call = solver.strategy(&shove, &hand("Qs Qh")).unwrap();
+        assert_eq!(call[1].0, Action::Call(Chips::new(100)));
+        assert!((call[1].1 - 0.5).abs() < 0.05);
+
+        // Value always bets, and air bluffs enough for one bluff per two value combos
+        let checked = [Action::Check];
+        let value = solver.strategy(&checked, &hand("Ks Kd")).unwrap();
+        assert!(value[1].1 > 0.95);
+        let bluff = solver.strategy(&checked, &hand("7c 6c")).unwrap();
+        assert!((bluff[1].1 - 0.375).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_evs_sum_to_pot() {
+        let mut solver = polarized();
+        solver.run(500);
+        let total = solver.range_ev(PlayerId::new(0)) + solver.range_ev(PlayerId::new(1));
+        assert!((total - 100.0).abs() < 1e-6);
+
+        // Kings win the pot at least, and more when called
+        let kings = solver.ev(PlayerId::new(1), &hand("Kd Ks")).unwrap();
+        assert!(kings >= 100.0);
+        assert_eq!(solver.ev(PlayerId::new(1), &hand("Qs Qh")), None);
+        // Both players decide twice: first in and facing a shove
+        assert_eq!(solver.profile().len(), 6 * 2 + (3 + 4) * 2);
+    }
+
+    #[test]
+    fn test_turn_games_are_rejected() {
+        let board = Board::with_cards(
+            "Kh 9d 5c 2s"
+                .split_whitespace()
+                .map(|c| c.parse().unwrap())
+                .collect(),
+        )
+        .unwrap();
+        let ranges: [HandRange; 2] = ["QQ".parse().unwrap(), "KK".parse().unwrap()];
+        let game = SolverGame::new(board, ranges, Chips::new(100), Chips::new(100)).unwrap();
+        assert_eq!(
+            RiverSolver::new(game, &CactusKevEvaluator::new()).unwrap_err(),
+            SolverError::InvalidBoard { cards: 4 }
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Split-pot showdown solver for eight-or-better hi-lo games.
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::entities::low_hand::LowHand;
+use crate::core::domain::services::evaluation::LowEvaluator;
+use crate::core::ports::inbound::{HandEvaluator, HiLoShowdownResult, ShowdownResult, MAX_PLAYERS};
+
+/// Determines the high and low winners of hi-lo showdowns.
+///
+/// Supports hold'em and stud hi-lo, where any five cards play for each half, and Omaha
+/// hi-lo, where each half uses exactly two hole cards and three board cards.
+pub struct HiLoShowdownSolver<E: HandEvaluator> {
+    evaluator: E,
+    low_evaluator: LowEvaluator,
+}
+
+/// `HiLoShowdownSolver` - Constructors
+impl<E: HandEvaluator> HiLoShowdownSolver<E> {
+    /// Creates a solver that uses `evaluator` for the high half.
+    pub const fn new(evaluator: E) -> Self {
+        Self {
+            evaluator,
+            low_evaluator: LowEvaluator::new(),
+        }
+    }
+}
+
+/// `HiLoShowdownSolver` - Accessors
+impl<E: HandEvaluator> HiLoShowdownSolver<E> {
+    /// Access the underlying high-hand evaluator.
+    pub const fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+}
+
+/// `HiLoShowdownSolver` - Operations
+impl<E: HandEvaluator> HiLoShowdownSolver<E> {
+    /// Determine the high and low winners of a hold'em hi-lo hand.
+    ///
+    /// # Panics
+    /// Panics if the board is not complete or there are more than `MAX_PLAYERS` players.
+    pub fn solve(&self, players: &[HoleCards], board: &Board) -> HiLoShowdownResult {
+        let board_cards = board
+            .as_array()
+            .expect("Board must be complete (5 cards) for showdown");
+
+        Self::resolve(players.iter().map(|hole_cards| {
+            let seven_cards = hole_cards.combine_with_board(board_cards);
+            (
+                self.evaluator.evaluate_7cards_fast(&seven_cards),
+                self.low_evaluator.evaluate(&seven_cards),
+            )
+        }))
+    }
+
+    /// Determine the high and low winners of an Omaha hi-lo hand.
+    ///
+    /// # Panics
+    /// Panics if the board is not complete or there are more than `MAX_PLAYERS` players.
+    pub fn solve_omaha(&self, players: &[[Card; 4]], board: &Board) -> HiLoShowdownResult {
+        let board_cards = board
+            .as_array()
+            .expect("Board must be complete (5 cards) for showdown");
+
+        Self::resolve(players.iter().map(|hole_cards| {
+            (
+                self.omaha_high(hole_cards, &board_cards),
+                self.low_evaluator.evaluate_omaha(hole_cards, &board_cards),
+            )
+        }))
+    }
+
+    /// Determine the high and low winners of a seven card stud hi-lo hand.
+    ///
+    /// # Panics
+    /// Panics if there are more than `MAX_PLAYERS` players.
+    pub fn solve_stud(&self, players: &[[Card; 7]]) -> HiLoShowdownResult {
+        Self::resolve(players.iter().map(|cards| {
+            (
+                self.evaluator.evaluate_7cards_fast(cards),
+                self.low_evaluator.evaluate(cards),
+            )
+        }))
+    }
+}
+
+/// `HiLoShowdownSolver` - Helpers
+impl<E: HandEvaluator> HiLoShowdownSolver<E> {
+    /// Best high strength using exactly two hole cards and three board cards.
+    fn omaha_high(&self, hole_cards: &[Card; 4], board: &[Card; 5]) -> u16 {
+        let mut best = u16::MAX;
+        for i in 0..4 {
+            for j in (i + 1)..4 {
+                for a in 0..5 {
+                    for b in (a + 1)..5 {
+                        for c in (b + 1)..5 {
+                            let cards =
+                                [hole_cards[i], hole_cards[j], board[a], board[b], board[c]];
+                            best = best.min(self.evaluator.evaluate_5cards_fast(&cards));
+                        }
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    /// Picks the winners of each half from every player's high strength and low.
+    fn resolve(players: impl Iterator<Item = (u16, Option<LowHand>)>) -> HiLoShowdownResult {
+        let mut high = ShowdownResult {
+            winners: [0; MAX_PLAYERS],
+            winner_count: 0,
+        };
+        let mut low = high.clone();
+        let mut best_high = u16::MAX;
+        let mut best_low: Option<LowHand> = None;
+
+        for (player_idx, (strength, low_hand)) in players.enumerate() {
+            assert!(
+                player_idx < MAX_PLAYERS,
+                "players.len() must be <= MAX_PLAYERS"
+            );
+
+            if strength < best_high {
+                best_high = strength;
+                high.winners[0] = player_idx;
+                high.winner_count = 1;
+            } else if strength == best_high {
+                high.winners[high.winner_count] = player_idx;
+                high.winner_count += 1;
+            }
+
+            let Some(low_hand) = low_hand else {
+                continue;
+            };
+            match best_low {
+                Some(best) if best.beats(&low_hand) => {}
+                Some(best) if best.ties(&low_hand) => {
+                    low.winners[low.winner_count] = player_idx;
+                    low.winner_count += 1;
+                }
+                _ => {
+                    best_low = Some(low_hand);
+                    low.winners[0] = player_idx;
+                    low.winner_count = 1;
+                }
+            }
+        }
+
+        HiLoShowdownResult {
+            high,
+            low: best_low.map(|_| low),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::primitives::Chips;
+    use crate::core::domain::services::evaluation::CactusKevEvaluator;
+
+    fn cards(text: &str) -> Vec<Card> {
+        text.split_whitespace()
+            .map(|c| c.parse().unwrap())
+            .collect()
+    }
+
+    fn hole(text: &str) -> HoleCards {
+        let cards = cards(text);
+        HoleCards::new(cards[0], cards[1])
+    }
+
+    fn board(text: &str) -> Board {
+        Board::with_cards(cards(text)).unwrap()
+    }
+
+    #[test]
+    fn test_holdem_split_and_scoop() {
+        let solver = HiLoShowdownSolver::new(CactusKevEvaluator::new());
+
+        // Kings win high, A-2 wins low
+        let result = solver.solve(&[hole("Kh Kd"), hole("Ac 2c")], &board("Ks 7d 5c 4h 9s"));
+        assert_eq!(result.high.single_winner(), Some(0));
+        assert_eq!(result.low.as_ref().unwrap().single_winner(), Some(1));
+        assert_eq!(result.scooper(), None);
+        assert_eq!(
+            result.split_pot(Chips::new(101)),
+            vec![(0, Chips::new(51)), (1, Chips::new(50))]
+        );
+
+        // No low possible with only two low cards on board
+        let result = solver.solve(&[hole("Kh Kd"), hole("Ac 2c")], &board("Ks Qd 5c 4h 9s"));
+        assert!(!result.has_low());
+        assert_eq!(result.scooper(), Some(0));
+        assert!((result.pot_share(0) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_omaha_quartering() {
+        let solver = HiLoShowdownSolver::new(CactusKevEvaluator::new());
+        let players = [
+            // A-2 for the nut low and trip kings for high
+            cards("Ac 2d Kh Kd").try_into().unwrap(),
+            // The same low, but only a pair of queens
+            cards("Ah 2s Qc Qd").try_into().unwrap(),
+            // No low
+            cards("Jc Jd Th 9h").try_into().unwrap(),
+        ];
+        let result = solver.solve_omaha(&players, &board("3c 4h 6s Ks 9d"));
+
+        assert_eq!(result.high.single_winner(), Some(0));
+        assert_eq!(result.low.as_ref().unwrap().winner_indices(), &[0, 1]);
+        assert!((result.pot_share(0) - 0.75).abs() < 1e-12);
+        assert!((result.pot_share(1) - 0.25).abs() < 1e-12);
+        assert!(result.pot_share(2).abs() < f64::EPSILON);
+        assert_eq!(
+            result.split_pot(Chips::new(100)),
+            vec![(0, Chips::new(75)), (1, Chips::new(25))]
+        );
+    }
+
+    #[test]
+    fn test_stud() {
+        let solver = HiLoShowdownSolver::new(CactusKevEvaluator::new());
+        let players = [
+            cards("Ac 2d 3h 4s 8c Kd Kh").try_into().unwrap(),
+            cards("Qc Qd Qh 9s 8d 7h 6s").try_into().unwrap(),
+        ];
+        let result = solver.solve_stud(&players);
+        assert_eq!(result.high.single_winner(), Some(1));
+        assert_eq!(result.low.unwrap().single_winner(), Some(0));
+    }
+}
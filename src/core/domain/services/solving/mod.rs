@@ -1,3 +1,7 @@
+mod hi_lo;
+mod pot_distributor;
 mod showdown;
 
-pub use showdown::ShowdownSolver;
+pub use hi_lo::HiLoShowdownSolver;
+pub use pot_distributor::{OddChipRule, Payouts, PotAward, PotDistributor};
+pub use showdown::ShowdownSolver;
\ No newline at end of file
@@ -0,0 +1,289 @@
+//! Pot distribution: turns a showdown result into chip awards.
+
+use crate::core::domain::entities::hand::Hand;
+use crate::core::domain::primitives::{BettingState, Chips, PlayerId, Pot};
+use crate::core::ports::inbound::ShowdownResultWithHands;
+
+/// Rule deciding who receives the chips left over when a pot does not split evenly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum OddChipRule {
+    /// Odd chips go one at a time to the tied winners in seat order, lowest seat first.
+    #[default]
+    LowestSeat,
+    /// Odd chips go one at a time to the tied winners clockwise from the button,
+    /// starting with the first seat to its left.
+    LeftOfButton(PlayerId),
+}
+
+impl OddChipRule {
+    /// Sorts tied winners so the first entries are the ones receiving odd chips.
+    fn order(self, winners: &mut [PlayerId]) {
+        match self {
+            Self::LowestSeat => winners.sort_unstable(),
+            Self::LeftOfButton(button) => {
+                // Seats after the button come first, wrapping around to the button itself
+                winners.sort_unstable_by_key(|player| (player.value() <= button.value(), player.value()));
+            }
+        }
+    }
+}
+
+/// Chips awarded to one player from one pot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PotAward {
+    /// Index of the pot in the distributed list (0 is the main pot).
+    pub pot_index: usize,
+    /// Index of the board the chips were won on, when the board was run more than once.
+    pub run: usize,
+    /// Player receiving the chips.
+    pub player: PlayerId,
+    /// Chips awarded.
+    pub amount: Chips,
+}
+
+/// All awards from distributing a hand's pots.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Payouts {
+    awards: Vec<PotAward>,
+}
+
+/// `Payouts` - Accessors
+impl Payouts {
+    /// Individual awards, ordered by pot and then by seat order of the odd-chip rule.
+    #[must_use]
+    pub fn awards(&self) -> &[PotAward] {
+        &self.awards
+    }
+
+    /// Total chips awarded to `player` across all pots.
+    #[must_use]
+    pub fn amount_for(&self, player: PlayerId) -> Chips {
+        self.awards
+            .iter()
+            .filter(|award| award.player == player)
+            .fold(Chips::ZERO, |acc, award| acc + award.amount)
+    }
+
+    /// Total chips awarded.
+    #[must_use]
+    pub fn total(&self) -> Chips {
+        self.awards
+            .iter()
+            .fold(Chips::ZERO, |acc, award| acc + award.amount)
+    }
+}
+
+/// Domain service that awards pots to showdown winners.
+///
+/// Each pot is contested only by its eligible players: the best hand among them wins
+/// it, ties split it evenly, and any odd chips are handed out according to the
+/// configured `OddChipRule`. A pot with a single eligible player (everyone else folded,
+/// or an uncalled bet) goes to that player without needing a hand.
+///
+/// `ShowdownResultWithHands::hands` must be indexed by seat, i.e. the solver was given
+/// every seat's hole cards in `PlayerId` order. Its winner list is not used, since the
+/// winner of a side pot can differ from the overall winner.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PotDistributor {
+    odd_chip_rule: OddChipRule,
+}
+
+/// `PotDistributor` - Constructors
+impl PotDistributor {
+    /// Creates a distributor using the given odd-chip rule.
+    #[must_use]
+    pub const fn new(odd_chip_rule: OddChipRule) -> Self {
+        Self { odd_chip_rule }
+    }
+}
+
+/// `PotDistributor` - Accessors
+impl PotDistributor {
+    /// The rule used to hand out odd chips.
+    #[must_use]
+    pub const fn odd_chip_rule(&self) -> OddChipRule {
+        self.odd_chip_rule
+    }
+}
+
+/// `PotDistributor` - Operations
+impl PotDistributor {
+    /// Distributes the layered pots of `betting` (see `BettingState::build_pots`).
+    #[must_use]
+    pub fn distribute(&self, betting: &BettingState, showdown: &ShowdownResultWithHands) -> Payouts {
+        self.distribute_pots(&betting.build_pots(), showdown)
+    }
+
+    /// Distributes `pots` according to the hands in `showdown`.
+    ///
+    /// Eligible players without an evaluated hand cannot win a contested pot. A pot whose
+    /// eligible players have no hands at all is not awarded.
+    #[must_use]
+    pub fn distribute_pots(&self, pots: &[Pot], showdown: &ShowdownResultWithHands) -> Payouts {
+        self.distribute_runouts(pots, std::slice::from_ref(showdown))
+    }
+
+    /// Distributes `pots` when the board was run out once per entry of `showdowns`.
+    ///
+    /// Each contested pot is split evenly across the runouts, the first runouts taking
+    /// any odd chips, and each share goes to the best eligible hands on that runout. A
+    /// pot with a single eligible player goes to them whole, as run 0.
+    #[must_use]
+    pub fn distribute_runouts(&self, pots: &[Pot], showdowns: &[ShowdownResultWithHands]) -> Payouts {
+        let mut awards = Vec::new();
+
+        for (pot_index, pot) in pots.iter().enumerate() {
+            if pot.amount().is_zero() {
+                continue;
+            }
+
+            if let [only] = pot.eligible_players() {
+                awards.push(PotAward {
+                    pot_index,
+                    run: 0,
+                    player: *only,
+                    amount: pot.amount(),
+                });
+                continue;
+            }
+
+            let runs = showdowns.len() as u64;
+            let mut odd_runs = pot.amount().value() % runs.max(1);
+            for (run, showdown) in showdowns.iter().enumerate() {
+                let extra = u64::from(odd_runs > 0);
+                odd_runs -= extra;
+                let amount = pot.amount().value() / runs + extra;
+                let winners = self.winners(pot.eligible_players(), showdown);
+                if winners.is_empty() {
+                    continue;
+                }
+
+                let count = winners.len() as u64;
+                let share = amount / count;
+                let odd_chips = amount % count;
+                awards.extend(winners.into_iter().zip(0..).map(|(player, position)| PotAward {
+                    pot_index,
+                    run,
+                    player,
+                    amount: Chips::new(share + u64::from(position < odd_chips)),
+                }));
+            }
+        }
+
+        Payouts { awards }
+    }
+}
+
+/// `PotDistributor` - Helpers
+impl PotDistributor {
+    /// Players among `eligible` holding the best hand in `showdown`, in odd-chip order.
+    fn winners(self, eligible: &[PlayerId], showdown: &ShowdownResultWithHands) -> Vec<PlayerId> {
+        let strength = |player: PlayerId| showdown.hands.get(player.as_index()).map(Hand::strength);
+        let Some(best) = eligible.iter().copied().filter_map(strength).min() else {
+            return Vec::new();
+        };
+        let mut winners: Vec<PlayerId> = eligible
+            .iter()
+            .copied()
+            .filter(|&player| strength(player) == Some(best))
+            .collect();
+        self.odd_chip_rule.order(&mut winners);
+        winners
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::card::Card;
+    use crate::core::ports::inbound::MAX_PLAYERS;
+
+    fn p(seat: u8) -> PlayerId {
+        PlayerId::new(seat)
+    }
+
+    /// Showdown where seat `i` holds a hand of strength `strengths[i]` (lower is better).
+    fn showdown(strengths: &[u16]) -> ShowdownResultWithHands {
+        ShowdownResultWithHands {
+            winners: [0; MAX_PLAYERS],
+            winner_count: 0,
+            hands: strengths
+                .iter()
+                .map(|&s| Hand::new([Card::from_index(0).unwrap(); 5], s))
+                .collect(),
+            hole_cards: Vec::new(),
+        }
+    }
+
+    fn pot(amount: u64, eligible: &[u8]) -> Pot {
+        Pot::with_players(Chips::new(amount), eligible.iter().copied().map(p).collect())
+    }
+
+    #[test]
+    fn test_single_winner_takes_pot() {
+        let payouts = PotDistributor::default().distribute_pots(&[pot(300, &[0, 1, 2])], &showdown(&[500, 20, 900]));
+        assert_eq!(payouts.amount_for(p(1)), Chips::new(300));
+        assert_eq!(payouts.awards().len(), 1);
+    }
+
+    #[test]
+    fn test_side_pot_goes_to_best_eligible_hand() {
+        // Seat 0 has the best hand but is only eligible for the main pot
+        let pots = [pot(300, &[0, 1, 2]), pot(400, &[1, 2])];
+        let payouts = PotDistributor::default().distribute_pots(&pots, &showdown(&[10, 600, 300]));
+
+        assert_eq!(payouts.amount_for(p(0)), Chips::new(300));
+        assert_eq!(payouts.amount_for(p(2)), Chips::new(400));
+        assert_eq!(payouts.amount_for(p(1)), Chips::ZERO);
+        assert_eq!(payouts.total(), Chips::new(700));
+    }
+
+    #[test]
+    fn test_split_pot_odd_chip_lowest_seat() {
+        let payouts = PotDistributor::default().distribute_pots(&[pot(101, &[0, 1, 2])], &showdown(&[50, 100, 50]));
+        assert_eq!(payouts.amount_for(p(0)), Chips::new(51));
+        assert_eq!(payouts.amount_for(p(2)), Chips::new(50));
+        assert_eq!(payouts.total(), Chips::new(101));
+    }
+
+    #[test]
+    fn test_split_pot_odd_chip_left_of_button() {
+        let distributor = PotDistributor::new(OddChipRule::LeftOfButton(p(1)));
+        let payouts = distributor.distribute_pots(&[pot(101, &[0, 1, 2])], &showdown(&[50, 100, 50]));
+        assert_eq!(payouts.amount_for(p(2)), Chips::new(51));
+        assert_eq!(payouts.amount_for(p(0)), Chips::new(50));
+    }
+
+    #[test]
+    fn test_uncontested_pot_needs_no_hand() {
+        let payouts = PotDistributor::default().distribute_pots(&[pot(15, &[2])], &showdown(&[]));
+        assert_eq!(payouts.amount_for(p(2)), Chips::new(15));
+    }
+
+    #[test]
+    fn test_runouts_split_pot_between_boards() {
+        // Seat 0 wins the first board, seat 1 the second, and they chop the third
+        let showdowns = [showdown(&[10, 600]), showdown(&[600, 10]), showdown(&[50, 50])];
+        let pots = [pot(301, &[0, 1]), pot(40, &[1])];
+        let payouts = PotDistributor::default().distribute_runouts(&pots, &showdowns);
+
+        assert_eq!(payouts.amount_for(p(0)), Chips::new(101 + 50));
+        assert_eq!(payouts.amount_for(p(1)), Chips::new(100 + 50 + 40));
+        assert_eq!(payouts.total(), Chips::new(341));
+        assert!(payouts.awards().iter().any(|award| award.run == 2));
+    }
+
+    #[test]
+    fn test_distribute_from_betting_state() {
+        let mut betting = BettingState::new(vec![Chips::new(100), Chips::new(1000), Chips::new(1000)]);
+        betting.start_round(Chips::new(10), false);
+        betting.bet_or_raise(p(0), Chips::new(100));
+        betting.bet_or_raise(p(1), Chips::new(300));
+        betting.call(p(2));
+        betting.end_round();
+
+        let payouts = PotDistributor::default().distribute(&betting, &showdown(&[10, 600, 300]));
+        assert_eq!(payouts.amount_for(p(0)), Chips::new(300));
+        assert_eq!(payouts.amount_for(p(2)), Chips::new(400));
+    }
+}
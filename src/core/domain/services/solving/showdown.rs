@@ -4,11 +4,16 @@
 //! and determine the winner(s) / ties.
 
 use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::deck::Deck;
 use crate::core::domain::entities::hand::Hand;
-use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::entities::hole_cards::{validate_no_duplicates, HoleCards};
 use crate::core::ports::inbound::{
-    HandEvaluator, HandSolver, ShowdownResult, ShowdownResultWithHands, MAX_PLAYERS,
+    HandEvaluator, HandSolver, RunoutResult, ShowdownResult, ShowdownResultWithHands, MAX_PLAYERS,
 };
+use crate::core::ports::outbound::RandomSource;
+
+use super::super::utils::{card_combinations, FIVE_FROM_SEVEN};
 pub struct ShowdownSolver<E: HandEvaluator> {
     evaluator: E,
 }
@@ -33,16 +38,22 @@ impl<E: HandEvaluator> ShowdownSolver<E> {
     }
 }
 
-impl<E: HandEvaluator> HandSolver for ShowdownSolver<E> {
-    fn solve(&self, players: &[HoleCards], board: &Board) -> ShowdownResult {
+/// `ShowdownSolver` - Helpers
+impl<E: HandEvaluator> ShowdownSolver<E> {
+    /// Panics unless there are at most `MAX_PLAYERS` players and no card is dealt twice,
+    /// checked in release builds too since a repeated card silently skews the result.
+    fn check_cards(players: &[HoleCards], board: &Board) {
         assert!(
             players.len() <= MAX_PLAYERS,
             "players.len() must be <= MAX_PLAYERS"
         );
-        let board_cards = board
-            .as_array()
-            .expect("Board must be complete (5 cards) for showdown");
+        if let Err(err) = validate_no_duplicates(players, board) {
+            panic!("showdown needs distinct cards: {err}");
+        }
+    }
 
+    /// Winner(s) among `players` on the complete board `board_cards`.
+    fn showdown(&self, players: &[HoleCards], board_cards: [Card; 5]) -> ShowdownResult {
         let mut best_strength = u16::MAX;
         let mut winners = [0usize; MAX_PLAYERS];
         let mut winner_count = 0;
@@ -67,11 +78,65 @@ impl<E: HandEvaluator> HandSolver for ShowdownSolver<E> {
         }
     }
 
+    /// Best five-card hand from `hole_cards` and `board_cards`, made with as few hole
+    /// cards as possible.
+    ///
+    /// A hole card that only duplicates the rank of a board card (or a suit, in a flush)
+    /// does not play, so players are credited with playing the board whenever they can.
+    fn best_hand(&self, hole_cards: HoleCards, board_cards: [Card; 5]) -> Hand {
+        let seven_cards = hole_cards.combine_with_board(board_cards);
+        let strength = self.evaluator.evaluate_7cards_fast(&seven_cards);
+        // Hole cards sit at indices 0 and 1 of the seven
+        let hole_cards_used = |combo: &[usize; 5]| combo.iter().filter(|&&i| i < 2).count();
+        (0..=2)
+            .flat_map(|used| {
+                FIVE_FROM_SEVEN
+                    .iter()
+                    .filter(move |combo| hole_cards_used(combo) == used)
+            })
+            .map(|combo| combo.map(|i| seven_cards[i]))
+            .find(|cards| self.evaluator.evaluate_5cards_fast(cards) == strength)
+            .map_or_else(
+                || self.evaluator.evaluate_7cards(seven_cards),
+                |cards| Hand::new(cards, strength),
+            )
+    }
+
+    /// Cards that can still come on `board`: those not held by a player or already dealt.
+    fn unseen_cards(players: &[HoleCards], board: &Board) -> Vec<Card> {
+        let mut dead_cards: Vec<Card> = players
+            .iter()
+            .flat_map(|hole_cards| [hole_cards.first(), hole_cards.second()])
+            .collect();
+        dead_cards.extend_from_slice(board.cards());
+        Deck::excluding(&dead_cards).to_vec()
+    }
+
+    /// Solves `board` completed with `runout` and records it in `result`.
+    fn record_runout(
+        &self,
+        players: &[HoleCards],
+        board: &Board,
+        runout: &[Card],
+        result: &mut RunoutResult,
+    ) {
+        let cards: Vec<Card> = board.cards().iter().chain(runout).copied().collect();
+        let cards = cards.try_into().expect("runout completes the board");
+        result.record(&self.showdown(players, cards));
+    }
+}
+
+impl<E: HandEvaluator> HandSolver for ShowdownSolver<E> {
+    fn solve(&self, players: &[HoleCards], board: &Board) -> ShowdownResult {
+        Self::check_cards(players, board);
+        let board_cards = board
+            .as_array()
+            .expect("Board must be complete (5 cards) for showdown");
+        self.showdown(players, board_cards)
+    }
+
     fn solve_with_hands(&self, players: &[HoleCards], board: &Board) -> ShowdownResultWithHands {
-        assert!(
-            players.len() <= MAX_PLAYERS,
-            "players.len() must be <= MAX_PLAYERS"
-        );
+        Self::check_cards(players, board);
         let board_cards = board
             .as_array()
             .expect("Board must be complete (5 cards) for showdown");
@@ -82,8 +147,7 @@ impl<E: HandEvaluator> HandSolver for ShowdownSolver<E> {
         let mut hands: Vec<Hand> = Vec::with_capacity(players.len());
 
         for (player_idx, hole_cards) in players.iter().enumerate() {
-            let seven_cards = hole_cards.combine_with_board(board_cards);
-            let hand = self.evaluator.evaluate_7cards(seven_cards);
+            let hand = self.best_hand(*hole_cards, board_cards);
             let strength = hand.strength();
             hands.push(hand);
 
@@ -101,7 +165,40 @@ impl<E: HandEvaluator> HandSolver for ShowdownSolver<E> {
             winners,
             winner_count,
             hands,
+            hole_cards: players.to_vec(),
+        }
+    }
+
+    fn solve_all_runouts(&self, players: &[HoleCards], board: &Board) -> RunoutResult {
+        Self::check_cards(players, board);
+        let unseen = Self::unseen_cards(players, board);
+        let mut result = RunoutResult::new(players.len());
+        for runout in card_combinations(&unseen, 5 - board.len()) {
+            self.record_runout(players, board, &runout, &mut result);
+        }
+        result
+    }
+
+    fn solve_sampled_runouts(
+        &self,
+        players: &[HoleCards],
+        board: &Board,
+        samples: u32,
+        source: &mut dyn RandomSource,
+    ) -> RunoutResult {
+        Self::check_cards(players, board);
+        let mut unseen = Self::unseen_cards(players, board);
+        let missing = 5 - board.len();
+        let mut result = RunoutResult::new(players.len());
+        for _ in 0..samples {
+            // Partial Fisher-Yates: only the cards dealt need shuffling
+            for i in 0..missing {
+                let j = i + source.random_index(unseen.len() - i);
+                unseen.swap(i, j);
+            }
+            self.record_runout(players, board, &unseen[..missing], &mut result);
         }
+        result
     }
 }
 
@@ -142,6 +239,21 @@ mod tests {
         assert_eq!(result.winner_indices(), &[0]);
     }
 
+    #[test]
+    #[should_panic(expected = "showdown needs distinct cards")]
+    fn test_duplicate_cards_panic_in_every_build() {
+        let solver = ShowdownSolver::new(CactusKevEvaluator::new());
+        let players = [HoleCards::new(card(Rank::Ace, Suit::Spades), card(Rank::King, Suit::Spades))];
+        let board = make_board(vec![
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+            card(Rank::Queen, Suit::Diamonds),
+            card(Rank::Jack, Suit::Clubs),
+            card(Rank::Nine, Suit::Spades),
+        ]);
+        solver.solve(&players, &board);
+    }
+
     #[test]
     fn test_tie() {
         let solver = ShowdownSolver::new(CactusKevEvaluator::new());
@@ -227,4 +339,117 @@ mod tests {
         assert!(winning_hands[1].is_straight());
         assert!(winning_hands[0].ties(winning_hands[1]));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_all_runouts_from_turn() {
+        let solver = ShowdownSolver::new(CactusKevEvaluator::new());
+
+        let players = vec![
+            HoleCards::new(card(Rank::Ace, Suit::Hearts), card(Rank::Ace, Suit::Diamonds)),
+            HoleCards::new(card(Rank::King, Suit::Hearts), card(Rank::King, Suit::Diamonds)),
+        ];
+        let board = Board::partial(vec![
+            card(Rank::Ace, Suit::Clubs),
+            card(Rank::King, Suit::Clubs),
+            card(Rank::Two, Suit::Spades),
+            card(Rank::Seven, Suit::Diamonds),
+        ])
+        .unwrap();
+
+        // Kings need the last king for quads
+        let result = solver.solve_all_runouts(&players, &board);
+        assert_eq!(result.runouts, 44);
+        assert_eq!(result.wins, vec![43, 1]);
+        assert_eq!(result.ties, vec![0, 0]);
+        assert!((result.win_probability(1) - 1.0 / 44.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_all_runouts_from_flop_split_ties() {
+        let solver = ShowdownSolver::new(CactusKevEvaluator::new());
+
+        let players = vec![
+            HoleCards::new(card(Rank::Ace, Suit::Spades), card(Rank::King, Suit::Spades)),
+            HoleCards::new(card(Rank::Ace, Suit::Hearts), card(Rank::King, Suit::Hearts)),
+            HoleCards::new(card(Rank::Seven, Suit::Clubs), card(Rank::Seven, Suit::Diamonds)),
+        ];
+        let board = Board::partial(vec![
+            card(Rank::Ace, Suit::Diamonds),
+            card(Rank::Eight, Suit::Clubs),
+            card(Rank::Two, Suit::Hearts),
+        ])
+        .unwrap();
+
+        let result = solver.solve_all_runouts(&players, &board);
+        assert_eq!(result.runouts, 43 * 42 / 2);
+        assert!(result.tie_probability(0) > 0.5);
+        assert_eq!(result.ties[0], result.ties[1]);
+        let total: f64 = (0..3).map(|player| result.equity(player)).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sampled_runouts_estimate_exact() {
+        use crate::core::ports::outbound::RandRandomSource;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let solver = ShowdownSolver::new(CactusKevEvaluator::new());
+
+        let players = vec![
+            HoleCards::new(card(Rank::Ace, Suit::Spades), card(Rank::Ace, Suit::Hearts)),
+            HoleCards::new(card(Rank::Nine, Suit::Clubs), card(Rank::Eight, Suit::Clubs)),
+        ];
+        let flop = Board::partial(vec![
+            card(Rank::Ten, Suit::Clubs),
+            card(Rank::Seven, Suit::Diamonds),
+            card(Rank::Two, Suit::Clubs),
+        ])
+        .unwrap();
+
+        let exact = solver.solve_all_runouts(&players, &flop);
+        let mut source = RandRandomSource::new(StdRng::seed_from_u64(7));
+        let sampled = solver.solve_sampled_runouts(&players, &flop, 2_000, &mut source);
+        assert_eq!(sampled.runouts, 2_000);
+        assert!((sampled.equity(0) - exact.equity(0)).abs() < 0.05);
+
+        // A complete board has one runout, the showdown itself
+        let river = Board::with_cards(vec![
+            card(Rank::Ten, Suit::Clubs),
+            card(Rank::Seven, Suit::Diamonds),
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Six, Suit::Hearts),
+            card(Rank::Three, Suit::Spades),
+        ])
+        .unwrap();
+        let result = solver.solve_all_runouts(&players, &river);
+        assert_eq!(result.runouts, 1);
+        assert_eq!(result.wins, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_hole_cards_used() {
+        let solver = ShowdownSolver::new(CactusKevEvaluator::new());
+
+        let players = vec![
+            // Queen duplicates the board's queen kicker, so the board plays
+            HoleCards::new(card(Rank::Queen, Suit::Clubs), card(Rank::Two, Suit::Clubs)),
+            HoleCards::new(card(Rank::Ace, Suit::Clubs), card(Rank::Three, Suit::Hearts)),
+        ];
+        let board = make_board(vec![
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Ace, Suit::Diamonds),
+            card(Rank::King, Suit::Spades),
+            card(Rank::King, Suit::Diamonds),
+            card(Rank::Queen, Suit::Hearts),
+        ]);
+
+        let result = solver.solve_with_hands(&players, &board);
+        assert_eq!(result.uses_hole_cards(0), Some([false, false]));
+        assert_eq!(result.plays_the_board(0), Some(true));
+        assert_eq!(result.uses_hole_cards(1), Some([true, false]));
+        assert_eq!(result.plays_the_board(1), Some(false));
+        assert_eq!(result.uses_hole_cards(2), None);
+        assert_eq!(result.single_winner(), Some(1));
+    }
+}
@@ -0,0 +1,216 @@
+//! Baseline bots to benchmark other strategies against.
+
+use std::sync::Mutex;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::core::domain::aggregates::PlayerView;
+use crate::core::domain::entities::hand_class::HandClass;
+use crate::core::domain::primitives::Action;
+use crate::core::domain::services::equity::PreflopEquityTable;
+use crate::core::ports::inbound::Strategy;
+
+/// Equity against a random hand above which `PushFoldStrategy::new` moves all in.
+pub const DEFAULT_PUSH_EQUITY: f64 = 0.55;
+
+/// Calling station: never folds or raises.
+///
+/// Checks when it can, otherwise calls, and goes all in when a call would cost its
+/// whole stack.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::aggregates::{GameAggregate, GameCommand};
+/// use riverrun::core::domain::entities::deck::Deck;
+/// use riverrun::core::domain::primitives::{Action, Chips, HandNumber, PlayerId};
+/// use riverrun::core::domain::services::strategy::AlwaysCallStrategy;
+/// use riverrun::core::ports::inbound::Strategy;
+///
+/// let mut game = GameAggregate::new();
+/// game.execute(&GameCommand::StartHand {
+///     hand_number: HandNumber::new(1),
+///     stacks: vec![Chips::new(1000); 2],
+///     button: PlayerId::new(0),
+///     small_blind: Chips::new(5),
+///     big_blind: Chips::new(10),
+///     ante: Chips::ZERO,
+///     deck: Deck::new(),
+/// })
+/// .unwrap();
+///
+/// let view = game.player_view(PlayerId::new(0)).unwrap();
+/// assert_eq!(AlwaysCallStrategy.act(&view), Action::Call(Chips::new(5)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AlwaysCallStrategy;
+
+impl Strategy for AlwaysCallStrategy {
+    fn act(&self, view: &PlayerView) -> Action {
+        let legal = &view.legal_actions;
+        if legal.check {
+            Action::Check
+        } else if let Some(amount) = legal.call {
+            Action::Call(amount)
+        } else if let Some(stack) = legal.all_in {
+            Action::AllIn(stack)
+        } else {
+            Action::Fold
+        }
+    }
+}
+
+/// Picks uniformly among the kinds of legal action, sizing bets and raises at the
+/// minimum.
+///
+/// Seeded, so a simulation between random bots can be reproduced.
+#[derive(Debug)]
+pub struct RandomStrategy {
+    rng: Mutex<StdRng>,
+}
+
+/// `RandomStrategy` - Constructors
+impl RandomStrategy {
+    /// Creates a random bot whose choices are determined by `seed`.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+}
+
+impl Strategy for RandomStrategy {
+    fn act(&self, view: &PlayerView) -> Action {
+        let actions = view.legal_actions.actions();
+        if actions.is_empty() {
+            return Action::Fold;
+        }
+        let index = self
+            .rng
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .random_range(0..actions.len());
+        actions[index]
+    }
+}
+
+/// Short-stack bot that moves all in preflop with strong enough hands and gives up
+/// otherwise.
+///
+/// A hand is strong enough when its equity against a random hand, from the shipped
+/// `PreflopEquityTable`, reaches the threshold; the same test decides whether to call
+/// someone else's jam. After the flop it checks when it can and folds to any bet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PushFoldStrategy {
+    min_equity: f64,
+}
+
+/// `PushFoldStrategy` - Constructors
+impl PushFoldStrategy {
+    /// Creates a bot jamming hands with at least `DEFAULT_PUSH_EQUITY`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_min_equity(DEFAULT_PUSH_EQUITY)
+    }
+
+    /// Creates a bot jamming hands with at least `min_equity` against a random hand.
+    #[must_use]
+    pub const fn with_min_equity(min_equity: f64) -> Self {
+        Self { min_equity }
+    }
+}
+
+/// `PushFoldStrategy` - Accessors
+impl PushFoldStrategy {
+    /// Equity against a random hand needed to move all in.
+    #[must_use]
+    pub const fn min_equity(&self) -> f64 {
+        self.min_equity
+    }
+}
+
+impl Default for PushFoldStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Strategy for PushFoldStrategy {
+    fn act(&self, view: &PlayerView) -> Action {
+        let legal = &view.legal_actions;
+        let class = HandClass::from_hole_cards(&view.hole_cards);
+        let strong = PreflopEquityTable::shared().vs_random(class) >= self.min_equity;
+        if view.street.is_preflop() && strong {
+            if let Some(stack) = legal.all_in {
+                return Action::AllIn(stack);
+            }
+            if let Some(amount) = legal.call {
+                return Action::Call(amount);
+            }
+        }
+        if legal.check {
+            Action::Check
+        } else {
+            Action::Fold
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::aggregates::{GameAggregate, GameCommand};
+    use crate::core::domain::entities::card::Card;
+    use crate::core::domain::entities::deck::Deck;
+    use crate::core::domain::primitives::{Chips, HandNumber, PlayerId};
+
+    /// Two-handed game where the button holds `button_cards`, facing the big blind.
+    fn heads_up(button_cards: [&str; 2]) -> GameAggregate {
+        let button: Vec<Card> = button_cards.iter().map(|c| c.parse().unwrap()).collect();
+        // Dealt from the end, one card to each seat at a time
+        let dealt = [
+            "7d".parse().unwrap(),
+            button[1],
+            "2c".parse().unwrap(),
+            button[0],
+        ];
+        let mut cards = Deck::excluding(&dealt).to_vec();
+        cards.extend(dealt);
+        let mut game = GameAggregate::new();
+        game.execute(&GameCommand::StartHand {
+            hand_number: HandNumber::new(1),
+            stacks: vec![Chips::new(200); 2],
+            button: PlayerId::new(0),
+            small_blind: Chips::new(5),
+            big_blind: Chips::new(10),
+            ante: Chips::ZERO,
+            deck: Deck::from_cards(cards),
+        })
+        .unwrap();
+        game
+    }
+
+    #[test]
+    fn test_push_fold_jams_strong_hands() {
+        let strategy = PushFoldStrategy::new();
+        let game = heads_up(["As", "Ah"]);
+        let view = game.player_view(PlayerId::new(0)).unwrap();
+        assert_eq!(strategy.act(&view), Action::AllIn(Chips::new(195)));
+
+        let game = heads_up(["3s", "8h"]);
+        let view = game.player_view(PlayerId::new(0)).unwrap();
+        assert_eq!(strategy.act(&view), Action::Fold);
+    }
+
+    #[test]
+    fn test_random_strategy_only_picks_legal_actions() {
+        let strategy = RandomStrategy::new(3);
+        let game = heads_up(["As", "Ah"]);
+        let view = game.player_view(PlayerId::new(0)).unwrap();
+        for _ in 0..20 {
+            assert!(view.legal_actions.allows(strategy.act(&view)));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod baseline;
+mod preflop_chart;
+mod push_fold;
+
+pub use baseline::{AlwaysCallStrategy, DEFAULT_PUSH_EQUITY, PushFoldStrategy, RandomStrategy};
+pub use preflop_chart::{
+    ActionFrequencies, ChartAction, ChartSituation, ParseChartError, PreflopChart,
+};
+pub use push_fold::{DEFAULT_ITERATIONS, PushFoldChart, PushFoldSolver};
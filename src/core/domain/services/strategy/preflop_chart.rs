@@ -0,0 +1,465 @@
+//! Preflop charts: how often to fold, call, or raise each starting hand.
+//!
+//! A chart is split into spots, one per position and facing action. Each spot assigns
+//! every one of the 169 hand classes a set of action frequencies, so both simple
+//! "raise these hands" charts and mixed solver output can be encoded.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::core::domain::entities::hand_class::{HandClass, NUM_HAND_CLASSES};
+use crate::core::domain::entities::hand_range::{HandRange, ParseRangeError};
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::primitives::Position;
+
+/// Action a player is facing when they first act preflop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ChartSituation {
+    /// Folded to the player: raise first in or fold.
+    Rfi,
+    /// Facing a single open raise.
+    VsRaise,
+    /// Facing a re-raise after opening.
+    VsThreeBet,
+}
+
+/// Kind of action a chart can recommend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChartAction {
+    /// Fold the hand.
+    Fold,
+    /// Call, or check in the big blind.
+    Call,
+    /// Open, raise, or re-raise.
+    Raise,
+}
+
+/// How often to take each action with a hand.
+///
+/// Raise and call frequencies are stored; the fold frequency is whatever is left, so
+/// the three always sum to 1.0.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionFrequencies {
+    raise: f64,
+    call: f64,
+}
+
+/// `ActionFrequencies` - Constructors
+impl ActionFrequencies {
+    /// Always fold.
+    pub const FOLD: Self = Self {
+        raise: 0.0,
+        call: 0.0,
+    };
+    /// Always call.
+    pub const CALL: Self = Self {
+        raise: 0.0,
+        call: 1.0,
+    };
+    /// Always raise.
+    pub const RAISE: Self = Self {
+        raise: 1.0,
+        call: 0.0,
+    };
+
+    /// Raises `raise` of the time and calls `call` of the time, folding otherwise.
+    ///
+    /// Frequencies are clamped to 0.0..=1.0, and the call frequency is capped so that
+    /// the two never exceed 1.0 together.
+    #[must_use]
+    pub fn new(raise: f64, call: f64) -> Self {
+        let raise = raise.clamp(0.0, 1.0);
+        let call = call.clamp(0.0, 1.0 - raise);
+        Self { raise, call }
+    }
+}
+
+/// `ActionFrequencies` - Accessors
+impl ActionFrequencies {
+    /// Frequency of raising.
+    #[must_use]
+    pub const fn raise(&self) -> f64 {
+        self.raise
+    }
+
+    /// Frequency of calling.
+    #[must_use]
+    pub const fn call(&self) -> f64 {
+        self.call
+    }
+
+    /// Frequency of folding.
+    #[must_use]
+    pub fn fold(&self) -> f64 {
+        (1.0 - self.raise - self.call).max(0.0)
+    }
+
+    /// Frequency of `action`.
+    #[must_use]
+    pub fn frequency(&self, action: ChartAction) -> f64 {
+        match action {
+            ChartAction::Fold => self.fold(),
+            ChartAction::Call => self.call,
+            ChartAction::Raise => self.raise,
+        }
+    }
+
+    /// The most frequent action; ties favour raising, then calling.
+    #[must_use]
+    pub fn primary(&self) -> ChartAction {
+        if self.raise >= self.call && self.raise >= self.fold() {
+            ChartAction::Raise
+        } else if self.call >= self.fold() {
+            ChartAction::Call
+        } else {
+            ChartAction::Fold
+        }
+    }
+
+    /// Returns whether the hand is always folded.
+    #[must_use]
+    pub fn is_fold(&self) -> bool {
+        self.raise <= 0.0 && self.call <= 0.0
+    }
+}
+
+/// Preflop chart keyed by position and facing action.
+///
+/// Hands a chart says nothing about, including every hand of a spot that was never
+/// added, are folded. With the `serde` feature a chart serializes as a list of spots,
+/// each naming its hands by class (`"AKs"`), and only hands that are not pure folds are
+/// written.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::hole_cards::HoleCards;
+/// use riverrun::core::domain::primitives::Position;
+/// use riverrun::core::domain::services::strategy::{
+///     ActionFrequencies, ChartAction, ChartSituation, PreflopChart,
+/// };
+///
+/// let chart = PreflopChart::new()
+///     .with_range(Position::Button, ChartSituation::Rfi, "22+, A2s+, KTo+", ActionFrequencies::RAISE)
+///     .unwrap()
+///     .with_range(Position::BigBlind, ChartSituation::VsRaise, "A5s", ActionFrequencies::new(0.4, 0.6))
+///     .unwrap();
+///
+/// let hand = |text: &str| -> HoleCards {
+///     HoleCards::new(text[..2].parse().unwrap(), text[2..].parse().unwrap())
+/// };
+/// let open = chart.lookup(Position::Button, ChartSituation::Rfi, &hand("AsTs"));
+/// assert_eq!(open.primary(), ChartAction::Raise);
+///
+/// let defend = chart.lookup(Position::BigBlind, ChartSituation::VsRaise, &hand("Ah5h"));
+/// assert!((defend.call() - 0.6).abs() < 1e-12);
+///
+/// let trash = chart.lookup(Position::Button, ChartSituation::Rfi, &hand("7c2d"));
+/// assert!(trash.is_fold());
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "ChartData", try_from = "ChartData")
+)]
+pub struct PreflopChart {
+    spots: HashMap<(Position, ChartSituation), Vec<ActionFrequencies>>,
+}
+
+/// `PreflopChart` - Constructors
+impl PreflopChart {
+    /// A chart that folds every hand.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This chart with `class` taking `frequencies` in the spot.
+    #[must_use]
+    pub fn with_class(
+        mut self,
+        position: Position,
+        situation: ChartSituation,
+        class: HandClass,
+        frequencies: ActionFrequencies,
+    ) -> Self {
+        self.set(position, situation, class, frequencies);
+        self
+    }
+
+    /// This chart with every class of `range` taking `frequencies` in the spot.
+    ///
+    /// `range` uses `HandRange` notation such as `"QQ+, AKs, A5s-A2s"`. A class is
+    /// included when any of its combinations is in the range; weights are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseRangeError` if `range` is not valid range notation.
+    pub fn with_range(
+        mut self,
+        position: Position,
+        situation: ChartSituation,
+        range: &str,
+        frequencies: ActionFrequencies,
+    ) -> Result<Self, ParseRangeError> {
+        let range: HandRange = range.parse()?;
+        for class in HandClass::all() {
+            if class.combos().iter().any(|combo| range.contains(combo)) {
+                self.set(position, situation, class, frequencies);
+            }
+        }
+        Ok(self)
+    }
+}
+
+/// `PreflopChart` - Accessors
+impl PreflopChart {
+    /// Frequencies for `hole_cards` in the spot.
+    #[must_use]
+    pub fn lookup(
+        &self,
+        position: Position,
+        situation: ChartSituation,
+        hole_cards: &HoleCards,
+    ) -> ActionFrequencies {
+        self.lookup_class(position, situation, HandClass::from_hole_cards(hole_cards))
+    }
+
+    /// Frequencies for `class` in the spot.
+    #[must_use]
+    pub fn lookup_class(
+        &self,
+        position: Position,
+        situation: ChartSituation,
+        class: HandClass,
+    ) -> ActionFrequencies {
+        self.spots
+            .get(&(position, situation))
+            .map_or(ActionFrequencies::FOLD, |hands| hands[class.index()])
+    }
+
+    /// Returns whether the chart has any entry for the spot.
+    #[must_use]
+    pub fn has_spot(&self, position: Position, situation: ChartSituation) -> bool {
+        self.spots.contains_key(&(position, situation))
+    }
+
+    /// Combinations taking `action` in the spot, each weighted by its frequency.
+    #[must_use]
+    pub fn range(
+        &self,
+        position: Position,
+        situation: ChartSituation,
+        action: ChartAction,
+    ) -> HandRange {
+        let mut range = HandRange::new();
+        for class in HandClass::all() {
+            let frequency = self
+                .lookup_class(position, situation, class)
+                .frequency(action);
+            if frequency > 0.0 {
+                range.add_class(class, frequency);
+            }
+        }
+        range
+    }
+}
+
+/// `PreflopChart` - Operations
+impl PreflopChart {
+    /// Sets the frequencies of `class` in the spot.
+    pub fn set(
+        &mut self,
+        position: Position,
+        situation: ChartSituation,
+        class: HandClass,
+        frequencies: ActionFrequencies,
+    ) {
+        self.spots
+            .entry((position, situation))
+            .or_insert_with(|| vec![ActionFrequencies::FOLD; NUM_HAND_CLASSES])[class.index()] =
+            frequencies;
+    }
+}
+
+/// Error importing a chart whose hands are not valid hand classes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseChartError {
+    hand: String,
+}
+
+impl fmt::Display for ParseChartError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid hand class in chart: {}", self.hand)
+    }
+}
+
+impl std::error::Error for ParseChartError {}
+
+/// Serialized form of a chart.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ChartData {
+    spots: Vec<SpotData>,
+}
+
+/// Serialized form of one spot of a chart.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SpotData {
+    position: Position,
+    situation: ChartSituation,
+    hands: std::collections::BTreeMap<String, ActionFrequencies>,
+}
+
+#[cfg(feature = "serde")]
+impl From<PreflopChart> for ChartData {
+    fn from(chart: PreflopChart) -> Self {
+        let mut spots: Vec<SpotData> = chart
+            .spots
+            .into_iter()
+            .map(|((position, situation), hands)| SpotData {
+                position,
+                situation,
+                hands: HandClass::all()
+                    .zip(hands)
+                    .filter(|(_, frequencies)| !frequencies.is_fold())
+                    .map(|(class, frequencies)| (class.to_string(), frequencies))
+                    .collect(),
+            })
+            .collect();
+        spots.sort_by_key(|spot| (spot.position.abbrev(), spot.situation));
+        Self { spots }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ChartData> for PreflopChart {
+    type Error = ParseChartError;
+
+    fn try_from(data: ChartData) -> Result<Self, Self::Error> {
+        let mut chart = Self::new();
+        for spot in data.spots {
+            chart
+                .spots
+                .entry((spot.position, spot.situation))
+                .or_insert_with(|| vec![ActionFrequencies::FOLD; NUM_HAND_CLASSES]);
+            for (hand, frequencies) in spot.hands {
+                let class: HandClass = hand.parse().map_err(|_| ParseChartError { hand })?;
+                let frequencies = ActionFrequencies::new(frequencies.raise, frequencies.call);
+                chart.set(spot.position, spot.situation, class, frequencies);
+            }
+        }
+        Ok(chart)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn class(text: &str) -> HandClass {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_unknown_spots_and_hands_fold() {
+        let chart = PreflopChart::new()
+            .with_range(
+                Position::Cutoff,
+                ChartSituation::Rfi,
+                "TT+",
+                ActionFrequencies::RAISE,
+            )
+            .unwrap();
+        let rfi = |text| chart.lookup_class(Position::Cutoff, ChartSituation::Rfi, class(text));
+
+        assert_eq!(rfi("JJ"), ActionFrequencies::RAISE);
+        assert!(rfi("99").is_fold());
+        assert!(chart.has_spot(Position::Cutoff, ChartSituation::Rfi));
+        assert!(!chart.has_spot(Position::Cutoff, ChartSituation::VsRaise));
+        assert!(
+            chart
+                .lookup_class(Position::UTG, ChartSituation::Rfi, class("AA"))
+                .is_fold()
+        );
+        assert!(
+            PreflopChart::new()
+                .with_range(
+                    Position::UTG,
+                    ChartSituation::Rfi,
+                    "ZZ",
+                    ActionFrequencies::RAISE
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_range_weights_combos_by_frequency() {
+        let chart = PreflopChart::new()
+            .with_class(
+                Position::BigBlind,
+                ChartSituation::VsRaise,
+                class("AKo"),
+                ActionFrequencies::new(0.25, 0.75),
+            )
+            .with_class(
+                Position::BigBlind,
+                ChartSituation::VsRaise,
+                class("QQ"),
+                ActionFrequencies::CALL,
+            );
+
+        let calls = chart.range(
+            Position::BigBlind,
+            ChartSituation::VsRaise,
+            ChartAction::Call,
+        );
+        assert_eq!(calls.combo_count(), 12 + 6);
+        // Three quarters of the offsuit ace-kings plus every queen pair
+        assert!((calls.total_weight() - 15.0).abs() < 1e-9);
+        let folds = chart.range(
+            Position::BigBlind,
+            ChartSituation::VsRaise,
+            ChartAction::Fold,
+        );
+        assert_eq!(folds.combo_count(), 1326 - 18);
+    }
+
+    #[test]
+    fn test_action_frequencies_fill_with_folds() {
+        let mixed = ActionFrequencies::new(0.7, 0.6);
+        assert!((mixed.call() - 0.3).abs() < 1e-12);
+        assert!(mixed.fold().abs() < 1e-12);
+        assert_eq!(mixed.primary(), ChartAction::Raise);
+
+        let passive = ActionFrequencies::new(0.1, 0.2);
+        assert!((passive.fold() - 0.7).abs() < 1e-12);
+        assert_eq!(passive.primary(), ChartAction::Fold);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_chart_json_round_trip() {
+        let chart = PreflopChart::new().with_class(
+            Position::Button,
+            ChartSituation::VsThreeBet,
+            class("AKs"),
+            ActionFrequencies::new(0.5, 0.5),
+        );
+        let json = serde_json::to_string(&chart).unwrap();
+        assert_eq!(
+            json,
+            r#"{"spots":[{"position":"Button","situation":"vs_three_bet","hands":{"AKs":{"raise":0.5,"call":0.5}}}]}"#
+        );
+        assert_eq!(serde_json::from_str::<PreflopChart>(&json).unwrap(), chart);
+
+        let invalid = json.replace("AKs", "AKx");
+        assert!(serde_json::from_str::<PreflopChart>(&invalid).is_err());
+    }
+}
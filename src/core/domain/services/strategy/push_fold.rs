@@ -0,0 +1,427 @@
+//! Push/fold equilibrium for short-stacked preflop play.
+//!
+//! With a short stack the only sensible preflop options are moving all in or folding,
+//! which shrinks the game enough to solve: each player picks, per starting hand class,
+//! how often to jam when folded to, and how often to call each earlier jam.
+
+use std::sync::OnceLock;
+
+use crate::core::domain::entities::hand_class::{HandClass, NUM_HAND_CLASSES};
+use crate::core::domain::entities::hand_range::HandRange;
+use crate::core::domain::primitives::{Blinds, Chips};
+use crate::core::domain::services::equity::PreflopEquityTable;
+
+/// Fictitious play iterations used by `PushFoldSolver::new`.
+pub const DEFAULT_ITERATIONS: usize = 500;
+
+/// Solver for the jam-or-fold game.
+///
+/// Players are given in preflop action order, ending with the small blind and the big
+/// blind; heads-up that is the button (small blind) followed by the big blind. Each
+/// player except the big blind jams or folds when folded to, and each player after a
+/// jammer calls or folds. Showdown equities come from a `PreflopEquityTable`, weighted
+/// by card removal between the two players' hands.
+///
+/// The equilibrium is found by fictitious play: every iteration each player best
+/// responds to the others' average strategies, so the reported frequencies are
+/// averages and hands on the boundary of a range may be mixed. In multiway spots the
+/// first caller closes the action, so ranges ignore overcalls and three-way pots.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::hand_class::HandClass;
+/// use riverrun::core::domain::primitives::{Blinds, Chips};
+/// use riverrun::core::domain::services::strategy::PushFoldSolver;
+///
+/// // Heads-up with 10 big blinds each
+/// let blinds = Blinds::new(Chips::new(50), Chips::new(100));
+/// let chart = PushFoldSolver::new().solve(&[Chips::new(1000), Chips::new(1000)], blinds);
+///
+/// let class = |text: &str| text.parse::<HandClass>().unwrap();
+/// assert!(chart.push_frequency(0, class("K2s")) > 0.9);
+/// assert!(chart.call_frequency(0, 1, class("A9o")) > 0.9);
+/// assert!(chart.call_frequency(0, 1, class("32o")) < 0.1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PushFoldSolver<'a> {
+    table: &'a PreflopEquityTable,
+    iterations: usize,
+}
+
+/// `PushFoldSolver` - Constructors
+impl PushFoldSolver<'static> {
+    /// A solver using the shipped preflop equity table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_table(PreflopEquityTable::shared())
+    }
+}
+
+impl Default for PushFoldSolver<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `PushFoldSolver` - Constructors
+impl<'a> PushFoldSolver<'a> {
+    /// A solver reading showdown equities from `table`.
+    #[must_use]
+    pub const fn with_table(table: &'a PreflopEquityTable) -> Self {
+        Self {
+            table,
+            iterations: DEFAULT_ITERATIONS,
+        }
+    }
+
+    /// This solver running `iterations` rounds of fictitious play.
+    #[must_use]
+    pub const fn with_iterations(self, iterations: usize) -> Self {
+        Self { iterations, ..self }
+    }
+}
+
+/// `PushFoldSolver` - Accessors
+impl PushFoldSolver<'_> {
+    /// Rounds of fictitious play per solve.
+    #[must_use]
+    pub const fn iterations(&self) -> usize {
+        self.iterations
+    }
+}
+
+/// `PushFoldSolver` - Operations
+impl PushFoldSolver<'_> {
+    /// Solves the jam-or-fold game for `stacks`, given in preflop action order.
+    ///
+    /// # Panics
+    /// Panics if there are fewer than two stacks or the blinds are not valid.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn solve(&self, stacks: &[Chips], blinds: Blinds) -> PushFoldChart {
+        assert!(stacks.len() >= 2, "push/fold needs at least two players");
+        assert!(blinds.is_valid(), "invalid blinds: {blinds}");
+
+        let spot = Spot::new(stacks, blinds);
+        let game = Game {
+            spot: &spot,
+            equities: self.equities(),
+            weights: removal_weights(),
+        };
+        let players = stacks.len();
+        let mut push = vec![vec![1.0; NUM_HAND_CLASSES]; players - 1];
+        let mut call = vec![vec![vec![0.5; NUM_HAND_CLASSES]; players]; players - 1];
+
+        for iteration in 0..self.iterations {
+            let step = 1.0 / (iteration as f64 + 2.0);
+            // Every best response is taken against the same averages before any update
+            let push_best: Vec<Vec<bool>> = (0..players - 1)
+                .map(|jammer| {
+                    (0..NUM_HAND_CLASSES)
+                        .map(|class| game.push_gain(jammer, class, &call[jammer]) > 0.0)
+                        .collect()
+                })
+                .collect();
+            for jammer in 0..players - 1 {
+                for (caller, calls) in call[jammer].iter_mut().enumerate().skip(jammer + 1) {
+                    let best: Vec<bool> = (0..NUM_HAND_CLASSES)
+                        .map(|class| game.call_gain(jammer, caller, class, &push[jammer]) > 0.0)
+                        .collect();
+                    mix(calls, &best, step);
+                }
+                mix(&mut push[jammer], &push_best[jammer], step);
+            }
+        }
+
+        PushFoldChart {
+            stacks: spot.stacks,
+            push,
+            call,
+        }
+    }
+
+    /// Equity of each class against each class, row-major by class index.
+    fn equities(&self) -> Vec<f64> {
+        let classes: Vec<HandClass> = HandClass::all().collect();
+        classes
+            .iter()
+            .flat_map(|&hero| classes.iter().map(move |&villain| (hero, villain)))
+            .map(|(hero, villain)| self.table.lookup(hero, villain))
+            .collect()
+    }
+}
+
+/// Equilibrium jam and call frequencies from `PushFoldSolver::solve`.
+///
+/// Positions index the stacks given to the solver. Frequencies are between 0.0 and
+/// 1.0; positions without the option, such as the big blind jamming, report 0.0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PushFoldChart {
+    /// Starting stacks in big blinds.
+    stacks: Vec<f64>,
+    /// Jam frequency of each position by class index.
+    push: Vec<Vec<f64>>,
+    /// Call frequency of each caller against each jammer by class index.
+    call: Vec<Vec<Vec<f64>>>,
+}
+
+/// `PushFoldChart` - Accessors
+impl PushFoldChart {
+    /// Number of players in the solved spot.
+    #[must_use]
+    pub const fn num_players(&self) -> usize {
+        self.stacks.len()
+    }
+
+    /// Starting stack of `position` in big blinds.
+    #[must_use]
+    pub fn stack_in_big_blinds(&self, position: usize) -> Option<f64> {
+        self.stacks.get(position).copied()
+    }
+
+    /// How often `position` jams `class` when folded to.
+    #[must_use]
+    pub fn push_frequency(&self, position: usize, class: HandClass) -> f64 {
+        self.push
+            .get(position)
+            .map_or(0.0, |frequencies| frequencies[class.index()])
+    }
+
+    /// How often `caller` calls a jam from `jammer` with `class`.
+    #[must_use]
+    pub fn call_frequency(&self, jammer: usize, caller: usize, class: HandClass) -> f64 {
+        if caller <= jammer {
+            return 0.0;
+        }
+        self.call
+            .get(jammer)
+            .and_then(|callers| callers.get(caller))
+            .map_or(0.0, |frequencies| frequencies[class.index()])
+    }
+
+    /// Jamming range of `position`, weighted by frequency.
+    #[must_use]
+    pub fn push_range(&self, position: usize) -> HandRange {
+        let mut range = HandRange::new();
+        for class in HandClass::all() {
+            range.add_class(class, self.push_frequency(position, class));
+        }
+        range
+    }
+
+    /// Calling range of `caller` against a jam from `jammer`, weighted by frequency.
+    #[must_use]
+    pub fn call_range(&self, jammer: usize, caller: usize) -> HandRange {
+        let mut range = HandRange::new();
+        for class in HandClass::all() {
+            range.add_class(class, self.call_frequency(jammer, caller, class));
+        }
+        range
+    }
+}
+
+/// Stacks and forced bets in big blinds.
+#[derive(Debug)]
+struct Spot {
+    stacks: Vec<f64>,
+    /// Stack behind once the ante is posted.
+    live: Vec<f64>,
+    blind: Vec<f64>,
+    ante: Vec<f64>,
+    total_antes: f64,
+}
+
+impl Spot {
+    #[allow(clippy::cast_precision_loss)]
+    fn new(stacks: &[Chips], blinds: Blinds) -> Self {
+        let big_blind = blinds.big_blind.value() as f64;
+        let in_big_blinds = |chips: Chips| chips.value() as f64 / big_blind;
+        let stacks: Vec<f64> = stacks.iter().map(|&stack| in_big_blinds(stack)).collect();
+        let ante: Vec<f64> = stacks
+            .iter()
+            .map(|&stack| stack.min(in_big_blinds(blinds.ante)))
+            .collect();
+        let live: Vec<f64> = stacks
+            .iter()
+            .zip(&ante)
+            .map(|(stack, ante)| stack - ante)
+            .collect();
+
+        let players = stacks.len();
+        let mut blind = vec![0.0; players];
+        blind[players - 2] = live[players - 2].min(in_big_blinds(blinds.small_blind));
+        blind[players - 1] = live[players - 1].min(1.0);
+        Self {
+            total_antes: ante.iter().sum(),
+            stacks,
+            live,
+            blind,
+            ante,
+        }
+    }
+
+    /// Result of folding after posting.
+    fn fold(&self, player: usize) -> f64 {
+        -(self.ante[player] + self.blind[player])
+    }
+
+    /// Result of jamming and getting no call.
+    fn steal(&self, jammer: usize) -> f64 {
+        let blinds: f64 = self.blind.iter().sum::<f64>() - self.blind[jammer];
+        self.total_antes - self.ante[jammer] + blinds
+    }
+
+    /// Effective stack and final pot when `caller` calls a jam from `jammer`.
+    fn showdown(&self, jammer: usize, caller: usize) -> (f64, f64) {
+        let effective = self.live[jammer].min(self.live[caller]);
+        let folded_blinds =
+            self.blind.iter().sum::<f64>() - self.blind[jammer] - self.blind[caller];
+        (
+            effective,
+            2.0f64.mul_add(effective, self.total_antes) + folded_blinds,
+        )
+    }
+}
+
+/// The spot with the equity data needed to evaluate strategies.
+struct Game<'a> {
+    spot: &'a Spot,
+    equities: Vec<f64>,
+    weights: &'static [f64],
+}
+
+impl Game<'_> {
+    /// How much better jamming `class` does than folding it.
+    fn push_gain(&self, jammer: usize, class: usize, calls: &[Vec<f64>]) -> f64 {
+        let mut reach = 1.0;
+        let mut value = 0.0;
+        for (caller, call) in calls.iter().enumerate().skip(jammer + 1) {
+            let (called, equity) = self.versus(class, call);
+            let (effective, pot) = self.spot.showdown(jammer, caller);
+            value += reach * called * (equity.mul_add(pot, -effective) - self.spot.ante[jammer]);
+            reach *= 1.0 - called;
+        }
+        value += reach * self.spot.steal(jammer);
+        value - self.spot.fold(jammer)
+    }
+
+    /// How much better calling a jam with `class` does than folding it.
+    fn call_gain(&self, jammer: usize, caller: usize, class: usize, push: &[f64]) -> f64 {
+        let (_, equity) = self.versus(class, push);
+        let (effective, pot) = self.spot.showdown(jammer, caller);
+        equity.mul_add(pot, -effective) - self.spot.ante[caller] - self.spot.fold(caller)
+    }
+
+    /// Chance the opponent holds a hand in `range`, and `class`'s equity against it.
+    fn versus(&self, class: usize, range: &[f64]) -> (f64, f64) {
+        let row = class * NUM_HAND_CLASSES;
+        let (mut total, mut in_range, mut won) = (0.0, 0.0, 0.0);
+        for (villain, &frequency) in range.iter().enumerate() {
+            let weight = self.weights[row + villain];
+            total += weight;
+            in_range += weight * frequency;
+            won += weight * frequency * self.equities[row + villain];
+        }
+        if in_range > 0.0 {
+            (in_range / total, won / in_range)
+        } else {
+            (0.0, 0.5)
+        }
+    }
+}
+
+/// Moves each frequency `step` of the way toward its best response.
+fn mix(strategy: &mut [f64], best: &[bool], step: f64) {
+    for (frequency, &best) in strategy.iter_mut().zip(best) {
+        *frequency += (f64::from(u8::from(best)) - *frequency) * step;
+    }
+}
+
+/// Non-overlapping combination pairs between each two classes, row-major by class index.
+#[allow(clippy::cast_precision_loss)]
+fn removal_weights() -> &'static [f64] {
+    static WEIGHTS: OnceLock<Vec<f64>> = OnceLock::new();
+    WEIGHTS.get_or_init(|| {
+        let combos: Vec<_> = HandClass::all().map(|class| class.combos()).collect();
+        combos
+            .iter()
+            .flat_map(|hero| combos.iter().map(move |villain| (hero, villain)))
+            .map(|(hero, villain)| {
+                hero.iter()
+                    .flat_map(|hero| villain.iter().map(move |villain| (hero, villain)))
+                    .filter(|(hero, villain)| {
+                        !hero
+                            .cards()
+                            .iter()
+                            .any(|card| villain.cards().contains(card))
+                    })
+                    .count() as f64
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn class(text: &str) -> HandClass {
+        text.parse().unwrap()
+    }
+
+    fn heads_up(big_blinds: u64) -> PushFoldChart {
+        let blinds = Blinds::new(Chips::new(5), Chips::new(10));
+        let stack = Chips::new(big_blinds * 10);
+        PushFoldSolver::new().solve(&[stack, stack], blinds)
+    }
+
+    fn percentage(range: &HandRange) -> f64 {
+        range.total_weight() / 1326.0
+    }
+
+    #[test]
+    fn test_heads_up_matches_known_equilibrium() {
+        // Published heads-up solutions at 10 big blinds jam about 58% and call about 37%
+        let chart = heads_up(10);
+        let pushed = percentage(&chart.push_range(0));
+        let called = percentage(&chart.call_range(0, 1));
+        assert!((0.53..0.63).contains(&pushed), "{pushed}");
+        assert!((0.32..0.42).contains(&called), "{called}");
+
+        assert!(chart.push_frequency(0, class("AA")) > 0.99);
+        assert!(chart.call_frequency(0, 1, class("AA")) > 0.99);
+        assert!(chart.call_frequency(0, 1, class("72o")) < 0.01);
+        assert!(chart.push_frequency(1, class("AA")).abs() < f64::EPSILON);
+        assert_eq!(chart.stack_in_big_blinds(1), Some(10.0));
+    }
+
+    #[test]
+    fn test_ranges_widen_as_stacks_shrink() {
+        let widths: Vec<f64> = [2, 10, 20]
+            .into_iter()
+            .map(|big_blinds| percentage(&heads_up(big_blinds).push_range(0)))
+            .collect();
+        assert!(widths[0] > 0.85, "{widths:?}");
+        assert!(
+            widths.windows(2).all(|pair| pair[0] > pair[1]),
+            "{widths:?}"
+        );
+    }
+
+    #[test]
+    fn test_multiway_early_position_is_tighter() {
+        let blinds = Blinds::new(Chips::new(5), Chips::new(10)).with_ante(Chips::new(1));
+        let stacks = [Chips::new(100); 3];
+        let chart = PushFoldSolver::new().solve(&stacks, blinds);
+        assert_eq!(chart.num_players(), 3);
+
+        let button = percentage(&chart.push_range(0));
+        let small_blind = percentage(&chart.push_range(1));
+        assert!(button < small_blind, "{button} vs {small_blind}");
+        // Both blinds defend against the button
+        assert!(chart.call_frequency(0, 1, class("AA")) > 0.99);
+        assert!(chart.call_frequency(0, 2, class("AA")) > 0.99);
+        assert!(chart.call_frequency(1, 0, class("AA")).abs() < f64::EPSILON);
+    }
+}
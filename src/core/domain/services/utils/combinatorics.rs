@@ -1,5 +1,7 @@
 //! Combinatorics utilities for poker hand evaluation.
 
+use crate::core::domain::entities::card::Card;
+
 /// All 21 ways to choose 5 cards from 7.
 pub const FIVE_FROM_SEVEN: [[usize; 5]; 21] = [
     [0, 1, 2, 3, 4],
@@ -48,41 +50,84 @@ pub fn binomial(n: usize, k: usize) -> usize {
 ///
 /// Returns an empty vector if k > n. If k == 0, returns a vector containing a single empty combination.
 /// Each combination is a `Vec<usize>` of length k with indices in ascending order.
+/// Prefer [`combinations_iter`] when the combinations are visited once.
 #[must_use] 
 pub fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
-    if k > n {
-        return Vec::new();
+    let mut result = Vec::with_capacity(binomial(n, k));
+    result.extend(combinations_iter(n, k));
+    result
+}
+
+/// Lazily yield all k-sized combinations of indices 0..n-1, in lexicographic order.
+///
+/// Yields the same combinations as [`combinations`] without building the full list up front.
+#[must_use] 
+pub fn combinations_iter(n: usize, k: usize) -> Combinations {
+    Combinations {
+        n,
+        indices: (0..k).collect(),
+        done: k > n,
     }
-    if k == 0 {
-        return vec![Vec::new()];
+}
+
+/// Lazily yield all k-sized combinations of `cards`, each in the order the cards appear.
+///
+/// Yields nothing if k exceeds the number of cards.
+#[must_use] 
+pub fn card_combinations(cards: &[Card], k: usize) -> CardCombinations<'_> {
+    CardCombinations {
+        cards,
+        indices: combinations_iter(cards.len(), k),
     }
+}
 
-    let mut result = Vec::with_capacity(binomial(n, k));
-    let mut indices: Vec<usize> = (0..k).collect();
+/// Iterator over k-sized combinations of indices, returned by [`combinations_iter`].
+#[derive(Clone, Debug)]
+pub struct Combinations {
+    n: usize,
+    indices: Vec<usize>,
+    done: bool,
+}
 
-    loop {
-        result.push(indices.clone());
+impl Iterator for Combinations {
+    type Item = Vec<usize>;
 
-        // Find rightmost index that can be incremented
-        let mut i = k;
-        while i > 0 {
-            i -= 1;
-            if indices[i] != i + n - k {
-                break;
-            }
+    fn next(&mut self) -> Option<Vec<usize>> {
+        if self.done {
+            return None;
         }
+        let current = self.indices.clone();
 
-        if indices[i] == i + n - k {
-            break; // Done
+        // Find rightmost index that can be incremented
+        let k = self.indices.len();
+        match (0..k).rev().find(|&i| self.indices[i] != i + self.n - k) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in (i + 1)..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
         }
 
-        indices[i] += 1;
-        for j in (i + 1)..k {
-            indices[j] = indices[j - 1] + 1;
-        }
+        Some(current)
     }
+}
 
-    result
+/// Iterator over k-sized combinations of cards, returned by [`card_combinations`].
+#[derive(Clone, Debug)]
+pub struct CardCombinations<'a> {
+    cards: &'a [Card],
+    indices: Combinations,
+}
+
+impl Iterator for CardCombinations<'_> {
+    type Item = Vec<Card>;
+
+    fn next(&mut self) -> Option<Vec<Card>> {
+        let indices = self.indices.next()?;
+        Some(indices.into_iter().map(|i| self.cards[i]).collect())
+    }
 }
 
 /// Determine whether five card ranks form a straight, including the wheel (A-2-3-4-5).
@@ -132,6 +177,30 @@ mod tests {
         assert!(c.contains(&vec![2, 3]));
     }
 
+    #[test]
+    fn test_combinations_iter_matches_combinations() {
+        for (n, k) in [(4, 2), (7, 5), (5, 0), (3, 4), (0, 0)] {
+            let lazy: Vec<Vec<usize>> = combinations_iter(n, k).collect();
+            assert_eq!(lazy.len(), binomial(n, k));
+            assert_eq!(lazy, combinations(n, k));
+        }
+        assert_eq!(combinations_iter(4, 2).next(), Some(vec![0, 1]));
+        assert_eq!(combinations_iter(4, 2).last(), Some(vec![2, 3]));
+    }
+
+    #[test]
+    fn test_card_combinations() {
+        let cards: Vec<Card> = ["As", "Kd", "Qh"]
+            .iter()
+            .map(|card| Card::from_string(card).unwrap())
+            .collect();
+        let pairs: Vec<Vec<Card>> = card_combinations(&cards, 2).collect();
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[0], vec![cards[0], cards[1]]);
+        assert_eq!(pairs[2], vec![cards[1], cards[2]]);
+        assert_eq!(card_combinations(&cards, 4).count(), 0);
+    }
+
     #[test]
     fn test_five_from_seven() {
         assert_eq!(FIVE_FROM_SEVEN.len(), 21);
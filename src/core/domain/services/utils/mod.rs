@@ -1,5 +1,10 @@
 //! Shared utilities for domain services.
 
 pub mod combinatorics;
+pub mod stats;
 
-pub use combinatorics::{binomial, combinations, is_straight_pattern, FIVE_FROM_SEVEN};
+pub use combinatorics::{
+    binomial, card_combinations, combinations, combinations_iter, is_straight_pattern,
+    CardCombinations, Combinations, FIVE_FROM_SEVEN,
+};
+pub use stats::normal_quantile;
@@ -1,7 +1,14 @@
 //! Equity calculation port for poker hand analysis.
 
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
 use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::hand::HandRank;
 use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::services::utils::normal_quantile;
 
 /// Result of an equity calculation.
 #[derive(Debug, Clone, Copy)]
@@ -11,6 +18,7 @@ pub struct EquityResult {
     tie_rate: f64,
     lose_rate: f64,
     samples: u64,
+    std_error: f64,
 }
 
 /// `EquityResult` - Constructors
@@ -53,6 +61,7 @@ impl EquityResult {
                 tie_rate: 0.0,
                 lose_rate: 0.0,
                 samples: 0,
+                std_error: 0.0,
             };
         }
 
@@ -65,14 +74,105 @@ impl EquityResult {
         let tie_share = tie_rate / (num_opponents + 1) as f64;
         let equity = win_rate + tie_share;
 
+        // Each sample is worth 1 for a win, a tie share for a tie, and 0 for a loss
+        let share = 1.0 / (num_opponents + 1) as f64;
+        let mean_square = (tie_rate * share).mul_add(share, win_rate);
+        let variance = equity.mul_add(-equity, mean_square).max(0.0);
+
         Self {
             equity,
             win_rate,
             tie_rate,
             lose_rate,
             samples: total,
+            std_error: (variance / total_f).sqrt(),
+        }
+    }
+
+    /// Create an `EquityResult` from counts where each tie paid out an exact pot share.
+    ///
+    /// Unlike `from_counts`, which assumes every player shares every tie, this suits
+    /// known hands where only the tied players split the pot: a tie three ways pays a
+    /// third, a tie two ways a half.
+    ///
+    /// # Parameters
+    ///
+    /// - `wins`, `ties`, `losses` — outcome counts, as for `from_counts`.
+    /// - `tie_shares` — pot shares won across all tied samples, e.g. 1.5 for three
+    ///   two-way ties.
+    /// - `tie_shares_squared` — sum of the squares of those shares, for the standard
+    ///   error.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_tie_shares(
+        wins: u64,
+        ties: u64,
+        losses: u64,
+        tie_shares: f64,
+        tie_shares_squared: f64,
+    ) -> Self {
+        let total = wins + ties + losses;
+        if total == 0 {
+            return Self::from_counts(0, 0, 0, 1);
+        }
+
+        let total_f = total as f64;
+        let win_rate = wins as f64 / total_f;
+        let equity = win_rate + tie_shares / total_f;
+        let mean_square = win_rate + tie_shares_squared / total_f;
+        let variance = equity.mul_add(-equity, mean_square).max(0.0);
+
+        Self {
+            equity,
+            win_rate,
+            tie_rate: ties as f64 / total_f,
+            lose_rate: losses as f64 / total_f,
+            samples: total,
+            std_error: (variance / total_f).sqrt(),
         }
     }
+
+    /// Combine results estimated separately over disjoint strata of the outcomes, each
+    /// paired with the probability of its stratum.
+    ///
+    /// The combination stays unbiased however the samples were split between strata, so
+    /// rare strata may be sampled far more often than they occur. Probabilities are
+    /// normalised to sum to one, and every stratum with a positive probability needs at
+    /// least one sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::ports::inbound::EquityResult;
+    /// // A stratum seen one time in ten, sampled as often as the rest
+    /// let rare = EquityResult::from_counts(100, 0, 0, 1);
+    /// let common = EquityResult::from_counts(50, 0, 50, 1);
+    /// let combined = EquityResult::from_strata([(0.1, rare), (0.9, common)]);
+    /// assert!((combined.equity() - 0.55).abs() < 1e-12);
+    /// assert_eq!(combined.samples(), 200);
+    /// ```
+    #[must_use]
+    pub fn from_strata(strata: impl IntoIterator<Item = (f64, Self)>) -> Self {
+        let strata: Vec<(f64, Self)> = strata.into_iter().collect();
+        let total: f64 = strata.iter().map(|&(probability, _)| probability).sum();
+        if total <= 0.0 {
+            return Self::from_counts(0, 0, 0, 1);
+        }
+
+        let mut combined = Self::from_counts(0, 0, 0, 1);
+        let mut variance = 0.0;
+        for (probability, result) in strata {
+            let weight = probability / total;
+            combined.equity = weight.mul_add(result.equity, combined.equity);
+            combined.win_rate = weight.mul_add(result.win_rate, combined.win_rate);
+            combined.tie_rate = weight.mul_add(result.tie_rate, combined.tie_rate);
+            combined.lose_rate = weight.mul_add(result.lose_rate, combined.lose_rate);
+            combined.samples += result.samples;
+            variance = (weight * weight).mul_add(result.std_error * result.std_error, variance);
+        }
+        combined.std_error = variance.sqrt();
+        combined
+    }
 }
 
 /// `EquityResult` - Accessors
@@ -224,6 +324,92 @@ impl EquityResult {
     pub const fn samples(&self) -> u64 {
         self.samples
     }
+
+    /// Standard error of `equity`, treating the samples as independent random draws.
+    ///
+    /// Exhaustive results are exact, so for them this only describes how much a random
+    /// sample of the same size would vary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::ports::inbound::EquityResult;
+    /// let r = EquityResult::from_counts(50, 0, 50, 1);
+    /// assert!((r.std_error() - 0.05).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub const fn std_error(&self) -> f64 {
+        self.std_error
+    }
+
+    /// Half-width of the confidence interval around `equity` at `confidence`, such as
+    /// 0.95 for 95%.
+    #[must_use]
+    pub fn margin_of_error(&self, confidence: f64) -> f64 {
+        normal_quantile(f64::midpoint(1.0, confidence)) * self.std_error
+    }
+
+    /// Interval around `equity` holding the true equity with probability `confidence`,
+    /// clamped to 0.0..=1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::ports::inbound::EquityResult;
+    /// let r = EquityResult::from_counts(50, 0, 50, 1);
+    /// let (low, high) = r.confidence_interval(0.95);
+    /// assert!((low - 0.402).abs() < 1e-3 && (high - 0.598).abs() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn confidence_interval(&self, confidence: f64) -> (f64, f64) {
+        let margin = self.margin_of_error(confidence);
+        (
+            (self.equity - margin).max(0.0),
+            (self.equity + margin).min(1.0),
+        )
+    }
+}
+
+/// Length of an `EquityResult` encoded by `to_bytes`.
+const ENCODED_RESULT_LEN: usize = 48;
+
+/// `EquityResult` - Encoding
+impl EquityResult {
+    /// This result as little-endian bytes, for storing in a result cache.
+    pub(crate) fn to_bytes(self) -> [u8; ENCODED_RESULT_LEN] {
+        let mut bytes = [0; ENCODED_RESULT_LEN];
+        let fields = [
+            self.equity.to_bits(),
+            self.win_rate.to_bits(),
+            self.tie_rate.to_bits(),
+            self.lose_rate.to_bits(),
+            self.samples,
+            self.std_error.to_bits(),
+        ];
+        for (chunk, field) in bytes.chunks_exact_mut(8).zip(fields) {
+            chunk.copy_from_slice(&field.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// The result encoded in `bytes` by `to_bytes`, or `None` if they are not one.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != ENCODED_RESULT_LEN {
+            return None;
+        }
+        let mut fields = bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap_or_default()));
+        let mut next = || fields.next().unwrap_or_default();
+        Some(Self {
+            equity: f64::from_bits(next()),
+            win_rate: f64::from_bits(next()),
+            tie_rate: f64::from_bits(next()),
+            lose_rate: f64::from_bits(next()),
+            samples: next(),
+            std_error: f64::from_bits(next()),
+        })
+    }
 }
 
 impl std::fmt::Display for EquityResult {
@@ -240,6 +426,383 @@ impl std::fmt::Display for EquityResult {
     }
 }
 
+/// How often each hand category was made at showdown over an equity calculation.
+///
+/// Hero makes one hand per sample; every opponent's hand is counted too, so with
+/// several opponents villain frequencies are per opponent hand.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::entities::hand::HandRank;
+/// use riverrun::core::ports::inbound::HandCategoryDistribution;
+///
+/// let mut categories = HandCategoryDistribution::new();
+/// categories.record(HandRank::Flush, [HandRank::OnePair]);
+/// categories.record(HandRank::OnePair, [HandRank::TwoPair]);
+/// assert_eq!(categories.samples(), 2);
+/// assert!((categories.hero_frequency(HandRank::Flush) - 0.5).abs() < 1e-12);
+/// assert!((categories.villain_frequency(HandRank::Flush)).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HandCategoryDistribution {
+    hero: [u64; 9],
+    villain: [u64; 9],
+    samples: u64,
+    villain_hands: u64,
+}
+
+/// `HandCategoryDistribution` - Constructors
+impl HandCategoryDistribution {
+    /// A distribution with no samples.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            hero: [0; 9],
+            villain: [0; 9],
+            samples: 0,
+            villain_hands: 0,
+        }
+    }
+}
+
+/// `HandCategoryDistribution` - Accessors
+impl HandCategoryDistribution {
+    /// Number of samples recorded.
+    #[must_use]
+    pub const fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    /// Samples in which hero made `rank`.
+    #[must_use]
+    pub const fn hero_count(&self, rank: HandRank) -> u64 {
+        self.hero[rank as usize]
+    }
+
+    /// Opponent hands that made `rank`.
+    #[must_use]
+    pub const fn villain_count(&self, rank: HandRank) -> u64 {
+        self.villain[rank as usize]
+    }
+
+    /// Fraction of samples in which hero made `rank`, or 0.0 without samples.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn hero_frequency(&self, rank: HandRank) -> f64 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+        self.hero_count(rank) as f64 / self.samples as f64
+    }
+
+    /// Fraction of opponent hands that made `rank`, or 0.0 without samples.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn villain_frequency(&self, rank: HandRank) -> f64 {
+        if self.villain_hands == 0 {
+            return 0.0;
+        }
+        self.villain_count(rank) as f64 / self.villain_hands as f64
+    }
+}
+
+/// `HandCategoryDistribution` - Operations
+impl HandCategoryDistribution {
+    /// Records one sample in which hero made `hero` and the opponents made `villains`.
+    pub fn record(&mut self, hero: HandRank, villains: impl IntoIterator<Item = HandRank>) {
+        self.samples += 1;
+        self.hero[hero as usize] += 1;
+        for villain in villains {
+            self.villain_hands += 1;
+            self.villain[villain as usize] += 1;
+        }
+    }
+}
+
+impl std::fmt::Display for HandCategoryDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for rank in HandRank::ALL.into_iter().rev() {
+            writeln!(
+                f,
+                "{:<16} hero {:>6.2}%  villain {:>6.2}%",
+                rank.name(),
+                self.hero_frequency(rank) * 100.0,
+                self.villain_frequency(rank) * 100.0
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// How hero's equity against each hand of a range is spread across equity buckets.
+///
+/// Each opponent combination contributes its range weight to the bucket holding
+/// hero's equity against it, so a polarized spot shows mass at both ends while a
+/// merged one clusters in the middle. Bucket `i` of `n` covers equities from `i / n`
+/// up to `(i + 1) / n`; the last bucket also includes an equity of exactly 1.0.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::ports::inbound::EquityDistribution;
+///
+/// let mut distribution = EquityDistribution::new(4);
+/// distribution.record(0.9, 1.0);
+/// distribution.record(0.1, 3.0);
+/// assert_eq!(distribution.bucket_bounds(3), (0.75, 1.0));
+/// assert!((distribution.bucket_frequency(0) - 0.75).abs() < 1e-12);
+/// assert!((distribution.equity() - 0.3).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquityDistribution {
+    buckets: Vec<f64>,
+    combos: usize,
+    total_weight: f64,
+    weighted_equity: f64,
+}
+
+/// `EquityDistribution` - Constructors
+impl EquityDistribution {
+    /// A distribution with `buckets` equal-width buckets and no combinations; at least
+    /// one bucket is always kept.
+    #[must_use]
+    pub fn new(buckets: usize) -> Self {
+        Self {
+            buckets: vec![0.0; buckets.max(1)],
+            combos: 0,
+            total_weight: 0.0,
+            weighted_equity: 0.0,
+        }
+    }
+}
+
+/// `EquityDistribution` - Accessors
+impl EquityDistribution {
+    /// Number of buckets.
+    #[must_use]
+    pub const fn num_buckets(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Number of combinations recorded.
+    #[must_use]
+    pub const fn combos(&self) -> usize {
+        self.combos
+    }
+
+    /// Sum of the weights of every combination recorded.
+    #[must_use]
+    pub const fn total_weight(&self) -> f64 {
+        self.total_weight
+    }
+
+    /// Lower and upper equity of bucket `index`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn bucket_bounds(&self, index: usize) -> (f64, f64) {
+        let width = self.buckets.len() as f64;
+        (index as f64 / width, (index + 1) as f64 / width)
+    }
+
+    /// Range weight in bucket `index`, or 0.0 if there is no such bucket.
+    #[must_use]
+    pub fn bucket_weight(&self, index: usize) -> f64 {
+        self.buckets.get(index).copied().unwrap_or_default()
+    }
+
+    /// Fraction of the range weight in bucket `index`, or 0.0 without combinations.
+    #[must_use]
+    pub fn bucket_frequency(&self, index: usize) -> f64 {
+        if self.total_weight <= 0.0 {
+            return 0.0;
+        }
+        self.bucket_weight(index) / self.total_weight
+    }
+
+    /// Fraction of the range weight in each bucket, lowest equity first.
+    #[must_use]
+    pub fn frequencies(&self) -> Vec<f64> {
+        (0..self.buckets.len())
+            .map(|index| self.bucket_frequency(index))
+            .collect()
+    }
+
+    /// Hero's overall equity against the range, weighting each combination by its
+    /// range weight, or 0.0 without combinations.
+    #[must_use]
+    pub fn equity(&self) -> f64 {
+        if self.total_weight <= 0.0 {
+            return 0.0;
+        }
+        self.weighted_equity / self.total_weight
+    }
+}
+
+/// `EquityDistribution` - Operations
+impl EquityDistribution {
+    /// Records a combination of the range with weight `weight` against which hero has
+    /// `equity`. Equities outside 0.0..=1.0 are clamped.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn record(&mut self, equity: f64, weight: f64) {
+        let equity = equity.clamp(0.0, 1.0);
+        let last = self.buckets.len() - 1;
+        let index = ((equity * self.buckets.len() as f64) as usize).min(last);
+        self.buckets[index] += weight;
+        self.combos += 1;
+        self.total_weight += weight;
+        self.weighted_equity += equity * weight;
+    }
+}
+
+impl std::fmt::Display for EquityDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for index in 0..self.buckets.len() {
+            let (low, high) = self.bucket_bounds(index);
+            writeln!(
+                f,
+                "{:>5.1}%-{:>5.1}%  {:>6.2}%",
+                low * 100.0,
+                high * 100.0,
+                self.bucket_frequency(index) * 100.0
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Snapshot of how far a long-running equity calculation has progressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EquityProgress {
+    /// Units of work completed so far.
+    pub completed: u64,
+    /// Total units of work for the calculation.
+    pub total: u64,
+}
+
+/// `EquityProgress` - Accessors
+impl EquityProgress {
+    /// Fraction of the work completed, between 0.0 and 1.0.
+    ///
+    /// Returns 1.0 when the total amount of work is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::ports::inbound::EquityProgress;
+    /// let progress = EquityProgress { completed: 25, total: 100 };
+    /// assert!((progress.fraction() - 0.25).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.completed as f64 / self.total as f64
+        }
+    }
+
+    /// Whether all units of work have been completed.
+    #[must_use]
+    pub const fn is_done(&self) -> bool {
+        self.completed >= self.total
+    }
+}
+
+/// Cooperative cancellation flag shared between a caller and a running calculation.
+///
+/// Cloning the token yields a handle to the same flag, so a UI thread can keep one
+/// clone and cancel a calculation running on another thread.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+/// `CancellationToken` - Constructors
+impl CancellationToken {
+    /// Create a token that has not been cancelled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// `CancellationToken` - Operations
+impl CancellationToken {
+    /// Request cancellation of every calculation observing this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether cancellation has been requested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::ports::inbound::CancellationToken;
+    /// let token = CancellationToken::new();
+    /// let handle = token.clone();
+    /// handle.cancel();
+    /// assert!(token.is_cancelled());
+    /// ```
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Error describing an equity calculation that cannot be run.
+///
+/// Returned instead of an empty result, which would read as zero equity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EquityError {
+    /// Exact enumeration would take too long, such as multiway preflop; estimate the
+    /// equity by Monte Carlo instead.
+    Infeasible {
+        /// Number of opponents asked for.
+        num_opponents: usize,
+        /// Number of cards on the board.
+        board_cards: usize,
+    },
+    /// Too few unseen cards remain to complete the board and deal every opponent.
+    NotEnoughCards {
+        /// Cards needed to complete the board and deal every opponent.
+        needed: usize,
+        /// Unseen cards left in the deck.
+        available: usize,
+    },
+    /// The card appears more than once among the hole cards and board.
+    DuplicateCard(Card),
+    /// The calculator does not support the inputs, such as having no opponents.
+    UnsupportedConfiguration(&'static str),
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Infeasible {
+                num_opponents,
+                board_cards,
+            } => write!(
+                f,
+                "exact equity against {num_opponents} opponents with {board_cards} board cards is infeasible"
+            ),
+            Self::NotEnoughCards { needed, available } => {
+                write!(f, "need {needed} unseen cards, only {available} remain")
+            }
+            Self::DuplicateCard(card) => write!(f, "duplicate card: {card}"),
+            Self::UnsupportedConfiguration(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl std::error::Error for EquityError {}
+
 /// Port for calculating poker hand equity.
 ///
 /// Equity represents the probability of winning (plus share of ties)
@@ -254,8 +817,15 @@ pub trait EquityCalculator: Send + Sync {
     ///
     /// # Returns
     /// An `EquityResult` containing win/tie/lose percentages.
-    fn calculate(&self, hole_cards: &HoleCards, board: &Board, num_opponents: usize)
-        -> EquityResult;
+    ///
+    /// # Errors
+    /// Returns an `EquityError` if the calculation cannot be run for these inputs.
+    fn calculate(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+        num_opponents: usize,
+    ) -> Result<EquityResult, EquityError>;
 
     /// Calculate equity with a specific number of samples/iterations.
     ///
@@ -267,11 +837,102 @@ pub trait EquityCalculator: Send + Sync {
     /// * `board` - Current community cards
     /// * `num_opponents` - Number of opponents
     /// * `samples` - Number of samples/iterations to run
+    ///
+    /// # Errors
+    /// Returns an `EquityError` if the calculation cannot be run for these inputs.
     fn calculate_sampled(
         &self,
         hole_cards: &HoleCards,
         board: &Board,
         num_opponents: usize,
         samples: u32,
-    ) -> EquityResult;
-}
\ No newline at end of file
+    ) -> Result<EquityResult, EquityError>;
+
+    /// Calculate equity while reporting progress and honouring cancellation.
+    ///
+    /// `progress` is invoked periodically with the amount of work completed. If `cancel`
+    /// is triggered, the calculation stops early and returns the result aggregated over
+    /// the work completed so far (check `samples()` to see how much was evaluated).
+    ///
+    /// The default implementation runs `calculate` as a single unit of work.
+    ///
+    /// # Arguments
+    /// * `hole_cards` - Your hole cards
+    /// * `board` - Current community cards
+    /// * `num_opponents` - Number of opponents
+    /// * `progress` - Callback receiving progress updates
+    /// * `cancel` - Token used to abort the calculation
+    ///
+    /// # Errors
+    /// Returns an `EquityError` if the calculation cannot be run for these inputs.
+    /// Cancellation is not an error.
+    fn calculate_with_progress(
+        &self,
+        hole_cards: &HoleCards,
+        board: &Board,
+        num_opponents: usize,
+        progress: &mut dyn FnMut(EquityProgress),
+        cancel: &CancellationToken,
+    ) -> Result<EquityResult, EquityError> {
+        if cancel.is_cancelled() {
+            return Ok(EquityResult::from_counts(0, 0, 0, num_opponents));
+        }
+        progress(EquityProgress { completed: 0, total: 1 });
+        let result = self.calculate(hole_cards, board, num_opponents)?;
+        progress(EquityProgress { completed: 1, total: 1 });
+        Ok(result)
+    }
+}
+
+/// Forwards every `EquityCalculator` method through a pointer to a calculator, so a
+/// calculator chosen at runtime can be shared as `Arc<dyn EquityCalculator>`.
+macro_rules! forward_equity_calculator {
+    ($($pointer:ty),+) => {$(
+        impl<C: EquityCalculator + ?Sized> EquityCalculator for $pointer {
+            fn calculate(
+                &self,
+                hole_cards: &HoleCards,
+                board: &Board,
+                num_opponents: usize,
+            ) -> Result<EquityResult, EquityError> {
+                (**self).calculate(hole_cards, board, num_opponents)
+            }
+
+            fn calculate_sampled(
+                &self,
+                hole_cards: &HoleCards,
+                board: &Board,
+                num_opponents: usize,
+                samples: u32,
+            ) -> Result<EquityResult, EquityError> {
+                (**self).calculate_sampled(hole_cards, board, num_opponents, samples)
+            }
+
+            fn calculate_with_progress(
+                &self,
+                hole_cards: &HoleCards,
+                board: &Board,
+                num_opponents: usize,
+                progress: &mut dyn FnMut(EquityProgress),
+                cancel: &CancellationToken,
+            ) -> Result<EquityResult, EquityError> {
+                (**self).calculate_with_progress(hole_cards, board, num_opponents, progress, cancel)
+            }
+        }
+    )+};
+}
+
+forward_equity_calculator!(&C, Arc<C>, Box<C>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_std_error_counts_tie_shares() {
+        // Every sample ties three ways: equity is always a third, so nothing varies
+        let result = EquityResult::from_counts(0, 30, 0, 2);
+        assert!(result.std_error() < 1e-6);
+        assert_eq!(EquityResult::from_counts(0, 0, 0, 1).confidence_interval(0.95), (0.0, 0.0));
+    }
+}
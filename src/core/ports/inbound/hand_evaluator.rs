@@ -1,11 +1,27 @@
+use std::sync::Arc;
+
+use crate::core::domain::entities::board::Board;
 use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_set::CardSet;
 use crate::core::domain::entities::hand::Hand;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::services::utils::combinations_iter;
+
+/// Version of the strength scale the built-in evaluators share, where 1 is a royal
+/// flush and 7462 the worst high card.
+///
+/// Bumped whenever a change could alter any computed strength, which invalidates every
+/// result cached under the old version.
+pub const EVALUATOR_VERSION: u32 = 1;
 
 /// Port for evaluating poker hands.
 ///
 /// This trait defines the interface for hand evaluation algorithms.
 /// Implementations can use different strategies (Cactus Kev, Two Plus Two, etc.)
 /// while consumers remain decoupled from the specific algorithm.
+///
+/// References, `Arc`s, and `Box`es of evaluators are evaluators too, including
+/// `Box<dyn HandEvaluator>`, so calculators can share one evaluator across threads.
 pub trait HandEvaluator: Send + Sync {
     /// Evaluate a 5-card hand and return the full Hand entity.
     ///
@@ -42,4 +58,245 @@ pub trait HandEvaluator: Send + Sync {
     /// # Returns
     /// The best possible 5-card hand strength from the 7 cards.
     fn evaluate_7cards_fast(&self, cards: &[Card; 7]) -> u16;
+
+    /// Evaluate many 7-card hands and return their strengths in the same order.
+    ///
+    /// Bulk workloads such as equity enumeration should prefer this over calling
+    /// `evaluate_7cards_fast` in a loop, since implementations can amortize work
+    /// across the batch.
+    fn evaluate_batch(&self, hands: &[[Card; 7]]) -> Vec<u16> {
+        hands
+            .iter()
+            .map(|cards| self.evaluate_7cards_fast(cards))
+            .collect()
+    }
+
+    /// Evaluate the best 5-card hand among 5 to 7 cards and return only its strength.
+    ///
+    /// Useful when the number of cards depends on the street, such as hole cards
+    /// combined with a flop or turn.
+    ///
+    /// # Returns
+    /// `None` unless `cards` holds 5 to 7 cards.
+    fn evaluate_best_fast(&self, cards: &[Card]) -> Option<u16> {
+        if let Ok(five) = <&[Card; 5]>::try_from(cards) {
+            return Some(self.evaluate_5cards_fast(five));
+        }
+        if let Ok(seven) = <&[Card; 7]>::try_from(cards) {
+            return Some(self.evaluate_7cards_fast(seven));
+        }
+        if cards.len() != 6 {
+            return None;
+        }
+        combinations_iter(cards.len(), 5)
+            .map(|combo| {
+                self.evaluate_5cards_fast(&[
+                    cards[combo[0]],
+                    cards[combo[1]],
+                    cards[combo[2]],
+                    cards[combo[3]],
+                    cards[combo[4]],
+                ])
+            })
+            .min()
+    }
+
+    /// Fraction of possible opponent hole cards that `hole_cards` beats on `board`.
+    ///
+    /// Every two-card combination not on the board or in `hole_cards` is a possible
+    /// opponent hand; ties count as half a win. Unlike equity, future cards are not
+    /// considered, so this measures current strength only.
+    ///
+    /// # Returns
+    /// `None` unless the board holds 3 to 5 cards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::domain::entities::board::Board;
+    /// use riverrun::core::domain::entities::card::Card;
+    /// use riverrun::core::domain::entities::hole_cards::HoleCards;
+    /// use riverrun::core::domain::services::CactusKevEvaluator;
+    /// use riverrun::core::ports::inbound::HandEvaluator;
+    ///
+    /// let cards = |text: &str| -> Vec<Card> {
+    ///     text.split_whitespace().map(|c| c.parse().unwrap()).collect()
+    /// };
+    /// let hole = cards("As Ah");
+    /// let board = Board::with_cards(cards("Ad 7c 2h")).unwrap();
+    ///
+    /// let evaluator = CactusKevEvaluator::new();
+    /// let strength = evaluator.relative_strength(&HoleCards::new(hole[0], hole[1]), &board);
+    /// assert!(strength.unwrap() > 0.99);
+    /// assert_eq!(evaluator.relative_strength(&HoleCards::new(hole[0], hole[1]), &Board::new()), None);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    fn relative_strength(&self, hole_cards: &HoleCards, board: &Board) -> Option<f64> {
+        if !(3..=5).contains(&board.len()) {
+            return None;
+        }
+        let mut hero = board.cards().to_vec();
+        hero.extend_from_slice(hole_cards.cards());
+        let hero_strength = self.evaluate_best_fast(&hero)?;
+
+        let unseen: Vec<Card> = (CardSet::full() - CardSet::from_cards(&hero)).iter().collect();
+        let mut villain = board.cards().to_vec();
+        villain.extend_from_slice(&[unseen[0], unseen[1]]);
+        let (mut ahead, mut tied, mut total) = (0u32, 0u32, 0u32);
+        for (index, &first) in unseen.iter().enumerate() {
+            for &second in &unseen[index + 1..] {
+                villain[board.len()] = first;
+                villain[board.len() + 1] = second;
+                let villain_strength = self.evaluate_best_fast(&villain)?;
+                total += 1;
+                if hero_strength < villain_strength {
+                    ahead += 1;
+                } else if hero_strength == villain_strength {
+                    tied += 1;
+                }
+            }
+        }
+        Some((f64::from(ahead) + f64::from(tied) / 2.0) / f64::from(total))
+    }
+
+    /// Version of the strengths this evaluator returns, hashed into the keys of cached
+    /// results so that a changed evaluator never serves stale ones.
+    ///
+    /// Evaluators on their own strength scale should return something other than
+    /// `EVALUATOR_VERSION`.
+    fn version(&self) -> u32 {
+        EVALUATOR_VERSION
+    }
+}
+
+/// Forwards every `HandEvaluator` method through a pointer to an evaluator, so one
+/// table-backed evaluator can be shared by many calculators and threads without
+/// cloning it.
+macro_rules! forward_hand_evaluator {
+    ($($pointer:ty),+) => {$(
+        impl<E: HandEvaluator + ?Sized> HandEvaluator for $pointer {
+            fn evaluate_5cards(&self, cards: [Card; 5]) -> Hand {
+                (**self).evaluate_5cards(cards)
+            }
+
+            fn evaluate_7cards(&self, cards: [Card; 7]) -> Hand {
+                (**self).evaluate_7cards(cards)
+            }
+
+            fn evaluate_5cards_fast(&self, cards: &[Card; 5]) -> u16 {
+                (**self).evaluate_5cards_fast(cards)
+            }
+
+            fn evaluate_7cards_fast(&self, cards: &[Card; 7]) -> u16 {
+                (**self).evaluate_7cards_fast(cards)
+            }
+
+            fn evaluate_batch(&self, hands: &[[Card; 7]]) -> Vec<u16> {
+                (**self).evaluate_batch(hands)
+            }
+
+            fn evaluate_best_fast(&self, cards: &[Card]) -> Option<u16> {
+                (**self).evaluate_best_fast(cards)
+            }
+
+            fn relative_strength(&self, hole_cards: &HoleCards, board: &Board) -> Option<f64> {
+                (**self).relative_strength(hole_cards, board)
+            }
+
+            fn version(&self) -> u32 {
+                (**self).version()
+            }
+        }
+    )+};
+}
+
+forward_hand_evaluator!(&E, Arc<E>, Box<E>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::hand::HandRank;
+    use crate::core::domain::services::{CactusKevEvaluator, PerfectHashEvaluator};
+
+    fn cards(text: &str) -> Vec<Card> {
+        text.split_whitespace().map(|card| card.parse().unwrap()).collect()
+    }
+
+    fn strength(hole: &str, board: &str) -> Option<f64> {
+        let hole = cards(hole);
+        let board = Board::with_cards(cards(board)).unwrap();
+        CactusKevEvaluator::new().relative_strength(&HoleCards::new(hole[0], hole[1]), &board)
+    }
+
+    #[test]
+    fn test_relative_strength_nuts_and_air() {
+        // Royal flush on the river beats every holding
+        assert_eq!(strength("As Ks", "Qs Js Ts 2d 3c"), Some(1.0));
+        // Board plays for everyone without a better hand: 7-high straight on board
+        let chopped = strength("2c 2d", "3h 4d 5s 6c 7h").unwrap();
+        assert!(chopped < 0.5);
+        assert!(strength("7c 2d", "Ah Kd Qs 9c 4h").unwrap() < 0.15);
+    }
+
+    #[test]
+    fn test_evaluate_batch_default() {
+        // The perfect hash evaluator uses the default implementation
+        let evaluator = PerfectHashEvaluator::new();
+        let hands: Vec<[Card; 7]> = ["As Ks Qs Js Ts 2h 3d", "2c 3d 4h 5s 7c 8d Th"]
+            .iter()
+            .map(|text| cards(text).try_into().unwrap())
+            .collect();
+        let strengths = evaluator.evaluate_batch(&hands);
+        assert_eq!(strengths, vec![1, evaluator.evaluate_7cards_fast(&hands[1])]);
+        assert!(evaluator.evaluate_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_evaluate_best_fast() {
+        let evaluator = CactusKevEvaluator::new();
+        // Six cards hold a flush among their 5-card subsets
+        let flush = evaluator.evaluate_best_fast(&cards("2h 5h 9h Jh Kh Ac")).unwrap();
+        assert_eq!(HandRank::from_strength(flush), HandRank::Flush);
+        assert_eq!(evaluator.evaluate_best_fast(&cards("2h 5h 9h Jh")), None);
+    }
+
+    #[test]
+    fn test_relative_strength_on_turn() {
+        let top_pair = strength("Ah Qd", "As 8c 5d 2h").unwrap();
+        let under_pair = strength("3h 3d", "As 8c 5d 2h").unwrap();
+        assert!(top_pair > 0.85);
+        assert!(under_pair < top_pair);
+        let hole = cards("Ah Qd");
+        let hole = HoleCards::new(hole[0], hole[1]);
+        assert_eq!(CactusKevEvaluator::new().relative_strength(&hole, &Board::new()), None);
+    }
+
+    #[test]
+    fn test_shared_evaluator_handles() {
+        use crate::core::domain::services::ExhaustiveEquityCalculator;
+        use crate::core::ports::inbound::EquityCalculator;
+
+        fn royal_flush(evaluator: impl HandEvaluator) -> Option<u16> {
+            evaluator.evaluate_best_fast(&cards("As Ks Qs Js Ts 2h 3d"))
+        }
+        let evaluator = Arc::new(CactusKevEvaluator::new());
+        let boxed: Box<dyn HandEvaluator> = Box::new(PerfectHashEvaluator::new());
+        assert_eq!(royal_flush(evaluator.as_ref()), Some(1));
+        assert_eq!(royal_flush(Arc::clone(&evaluator)), Some(1));
+        assert_eq!(royal_flush(boxed), Some(1));
+
+        let hero: HoleCards = "AhAd".parse().unwrap();
+        let board = Board::with_cards(cards("Ac 7s 2c 9d Kh")).unwrap();
+        let equities = std::thread::scope(|scope| {
+            [0, 1]
+                .map(|_| {
+                    let calculator = ExhaustiveEquityCalculator::new(Arc::clone(&evaluator));
+                    let board = &board;
+                    scope.spawn(move || calculator.calculate(&hero, board, 1).unwrap().equity())
+                })
+                .map(|worker| worker.join().unwrap())
+        });
+        assert!((equities[0] - equities[1]).abs() < 1e-12);
+        assert!(equities[0] > 0.95);
+    }
 }
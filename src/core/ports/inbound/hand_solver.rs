@@ -1,6 +1,10 @@
+use std::sync::Arc;
+
 use crate::core::domain::entities::board::Board;
 use crate::core::domain::entities::hand::Hand;
 use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::primitives::Chips;
+use crate::core::ports::outbound::RandomSource;
 
 /// Maximum number of players supported in a hand.
 pub const MAX_PLAYERS: usize = 10;
@@ -90,6 +94,8 @@ pub struct ShowdownResultWithHands {
     pub winner_count: usize,
     /// Evaluated hand for each player.
     pub hands: Vec<Hand>,
+    /// Hole cards of each player, in the same order as `hands`.
+    pub hole_cards: Vec<HoleCards>,
 }
 
 /// `ShowdownResultWithHands` - Accessors
@@ -99,7 +105,7 @@ impl ShowdownResultWithHands {
     /// # Examples
     ///
     /// ```ignore
-    /// let result = ShowdownResultWithHands { winners: [3, 5, 0, 0, 0, 0, 0, 0, 0, 0], winner_count: 2, hands: vec![] };
+    /// let result = ShowdownResultWithHands { winners: [3, 5, 0, 0, 0, 0, 0, 0, 0, 0], winner_count: 2, hands: vec![], hole_cards: vec![] };
     /// assert_eq!(result.winner_indices(), &[3, 5]);
     /// ```
     #[must_use]
@@ -112,7 +118,7 @@ impl ShowdownResultWithHands {
     /// # Examples
     ///
     /// ```ignore
-    /// let res = ShowdownResultWithHands { winners: [0; MAX_PLAYERS], winner_count: 1, hands: vec![] };
+    /// let res = ShowdownResultWithHands { winners: [0; MAX_PLAYERS], winner_count: 1, hands: vec![], hole_cards: vec![] };
     /// assert!(res.is_single_winner());
     /// ```
     #[must_use]
@@ -125,7 +131,7 @@ impl ShowdownResultWithHands {
     /// # Examples
     ///
     /// ```ignore
-    /// let res = ShowdownResultWithHands { winners: [0usize; MAX_PLAYERS], winner_count: 2, hands: vec![] };
+    /// let res = ShowdownResultWithHands { winners: [0usize; MAX_PLAYERS], winner_count: 2, hands: vec![], hole_cards: vec![] };
     /// assert!(res.is_tie());
     /// ```
     #[must_use]
@@ -142,7 +148,7 @@ impl ShowdownResultWithHands {
     /// # Examples
     ///
     /// ```ignore
-    /// let res = ShowdownResultWithHands { winners: [2, 0, 0, 0, 0, 0, 0, 0, 0, 0], winner_count: 1, hands: vec![] };
+    /// let res = ShowdownResultWithHands { winners: [2, 0, 0, 0, 0, 0, 0, 0, 0, 0], winner_count: 1, hands: vec![], hole_cards: vec![] };
     /// assert_eq!(res.single_winner(), Some(2));
     /// ```
     #[must_use]
@@ -196,6 +202,231 @@ impl ShowdownResultWithHands {
     pub fn hand(&self, player_idx: usize) -> Option<&Hand> {
         self.hands.get(player_idx)
     }
+
+    /// Which of the player's hole cards are part of their best five-card hand.
+    ///
+    /// # Returns
+    /// `Some([first, second])` telling whether each hole card plays, `None` if the
+    /// player is not in the result.
+    #[must_use]
+    pub fn uses_hole_cards(&self, player_idx: usize) -> Option<[bool; 2]> {
+        let hand = self.hands.get(player_idx)?;
+        Some(hand.uses_hole_cards(self.hole_cards.get(player_idx)?))
+    }
+
+    /// Whether the player's best hand is made from the board alone.
+    ///
+    /// # Returns
+    /// `Some(true)` when neither hole card plays, `None` if the player is not in the
+    /// result.
+    #[must_use]
+    pub fn plays_the_board(&self, player_idx: usize) -> Option<bool> {
+        self.uses_hole_cards(player_idx)
+            .map(|used| used == [false, false])
+    }
+}
+
+/// Result of a hi-lo split-pot showdown.
+///
+/// Half the pot goes to the best high hand and half to the best qualifying low. When
+/// nobody qualifies for low, the high hand scoops. A player tying for one half receives
+/// a share of that half only, so a split low "quarters" the pot.
+#[derive(Debug, Clone)]
+pub struct HiLoShowdownResult {
+    /// Winner(s) of the high half.
+    pub high: ShowdownResult,
+    /// Winner(s) of the low half, or `None` if no player made a qualifying low.
+    pub low: Option<ShowdownResult>,
+}
+
+/// `HiLoShowdownResult` - Accessors
+impl HiLoShowdownResult {
+    /// Returns whether any player made a qualifying low.
+    #[must_use]
+    pub const fn has_low(&self) -> bool {
+        self.low.is_some()
+    }
+
+    /// The player winning the whole pot alone, if any.
+    ///
+    /// # Returns
+    /// `Some(index)` when one player wins the high outright and the low outright (or
+    /// there is no low), `None` otherwise.
+    #[must_use]
+    pub const fn scooper(&self) -> Option<usize> {
+        match (self.high.single_winner(), &self.low) {
+            (Some(high), None) => Some(high),
+            (Some(high), Some(low)) => match low.single_winner() {
+                Some(low) if low == high => Some(high),
+                _ => None,
+            },
+            (None, _) => None,
+        }
+    }
+
+    /// Fraction of the pot won by `player_idx`, between 0.0 and 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use riverrun::core::ports::inbound::{HiLoShowdownResult, ShowdownResult, MAX_PLAYERS};
+    ///
+    /// let winners = |indices: &[usize]| {
+    ///     let mut winners = [0; MAX_PLAYERS];
+    ///     winners[..indices.len()].copy_from_slice(indices);
+    ///     ShowdownResult { winners, winner_count: indices.len() }
+    /// };
+    /// // Player 0 wins high and ties player 1 for low
+    /// let result = HiLoShowdownResult { high: winners(&[0]), low: Some(winners(&[0, 1])) };
+    /// assert!((result.pot_share(0) - 0.75).abs() < 1e-12);
+    /// assert!((result.pot_share(1) - 0.25).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn pot_share(&self, player_idx: usize) -> f64 {
+        let share = |result: &ShowdownResult| {
+            if result.winner_indices().contains(&player_idx) {
+                1.0 / result.winner_count as f64
+            } else {
+                0.0
+            }
+        };
+        self.low.as_ref().map_or_else(
+            || share(&self.high),
+            |low| f64::midpoint(share(&self.high), share(low)),
+        )
+    }
+
+    /// Splits `pot` chips between the winners, returning `(player index, chips)` pairs.
+    ///
+    /// The high half takes the odd chip when the pot does not halve evenly, and within
+    /// each half any odd chips go to the tied winners in player order. A player winning
+    /// in both halves appears once.
+    #[must_use]
+    pub fn split_pot(&self, pot: Chips) -> Vec<(usize, Chips)> {
+        let mut awards: Vec<(usize, Chips)> = Vec::new();
+        let mut award = |result: &ShowdownResult, amount: u64| {
+            let mut winners = result.winner_indices().to_vec();
+            winners.sort_unstable();
+            let count = winners.len() as u64;
+            for (i, player) in winners.into_iter().enumerate() {
+                let odd_chip = u64::from((i as u64) < amount % count);
+                let chips = Chips::new(amount / count + odd_chip);
+                match awards.iter_mut().find(|(winner, _)| *winner == player) {
+                    Some((_, total)) => *total += chips,
+                    None => awards.push((player, chips)),
+                }
+            }
+        };
+
+        match &self.low {
+            Some(low) => {
+                let low_half = pot.value() / 2;
+                award(&self.high, pot.value() - low_half);
+                award(low, low_half);
+            }
+            None => award(&self.high, pot.value()),
+        }
+        awards.sort_unstable_by_key(|&(player, _)| player);
+        awards
+    }
+}
+
+/// Outcome of a showdown over many completions of an incomplete board.
+///
+/// Each field is indexed by player, in the order the players were given.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunoutResult {
+    /// Runouts each player won alone.
+    pub wins: Vec<u64>,
+    /// Runouts each player tied for the best hand.
+    pub ties: Vec<u64>,
+    /// Pots each player won, with tied pots split evenly between the winners.
+    pub shares: Vec<f64>,
+    /// Runouts solved.
+    pub runouts: u64,
+}
+
+/// `RunoutResult` - Constructors
+impl RunoutResult {
+    /// An empty result for `num_players` players.
+    #[must_use]
+    pub fn new(num_players: usize) -> Self {
+        Self {
+            wins: vec![0; num_players],
+            ties: vec![0; num_players],
+            shares: vec![0.0; num_players],
+            runouts: 0,
+        }
+    }
+}
+
+/// `RunoutResult` - Accessors
+impl RunoutResult {
+    /// Number of players.
+    #[must_use]
+    pub const fn num_players(&self) -> usize {
+        self.wins.len()
+    }
+
+    /// Probability that `player_idx` wins alone, or 0.0 when nothing was solved.
+    ///
+    /// # Panics
+    /// Panics if `player_idx` is out of range.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn win_probability(&self, player_idx: usize) -> f64 {
+        self.fraction(self.wins[player_idx] as f64)
+    }
+
+    /// Probability that `player_idx` ties for the best hand.
+    ///
+    /// # Panics
+    /// Panics if `player_idx` is out of range.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn tie_probability(&self, player_idx: usize) -> f64 {
+        self.fraction(self.ties[player_idx] as f64)
+    }
+
+    /// Expected share of the pot won by `player_idx`; the equities of all players sum to 1.
+    ///
+    /// # Panics
+    /// Panics if `player_idx` is out of range.
+    #[must_use]
+    pub fn equity(&self, player_idx: usize) -> f64 {
+        self.fraction(self.shares[player_idx])
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn fraction(&self, count: f64) -> f64 {
+        if self.runouts == 0 {
+            0.0
+        } else {
+            count / self.runouts as f64
+        }
+    }
+}
+
+/// `RunoutResult` - Operations
+impl RunoutResult {
+    /// Counts the showdown of one more runout.
+    ///
+    /// # Panics
+    /// Panics if a winner index is out of range.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn record(&mut self, showdown: &ShowdownResult) {
+        let share = 1.0 / showdown.winner_count as f64;
+        for &winner in showdown.winner_indices() {
+            if showdown.is_tie() {
+                self.ties[winner] += 1;
+            } else {
+                self.wins[winner] += 1;
+            }
+            self.shares[winner] += share;
+        }
+        self.runouts += 1;
+    }
 }
 
 /// Port for determining the winner(s) of a poker hand.
@@ -214,7 +445,8 @@ pub trait HandSolver: Send + Sync {
     /// A `ShowdownResult` containing winner indices and count.
     ///
     /// # Panics
-    /// Panics if the board is not complete (doesn't have 5 cards).
+    /// Panics if the board is not complete (doesn't have 5 cards), there are more than
+    /// `MAX_PLAYERS` players, or a card is dealt twice (see `validate_no_duplicates`).
     fn solve(&self, players: &[HoleCards], board: &Board) -> ShowdownResult;
 
     /// Determine the winner(s) and evaluated hands of a Texas Hold'em hand.
@@ -227,6 +459,84 @@ pub trait HandSolver: Send + Sync {
     /// A `ShowdownResultWithHands` containing winners and each player's full Hand.
     ///
     /// # Panics
-    /// Panics if the board is not complete (doesn't have 5 cards).
+    /// Panics if the board is not complete (doesn't have 5 cards), there are more than
+    /// `MAX_PLAYERS` players, or a card is dealt twice (see `validate_no_duplicates`).
     fn solve_with_hands(&self, players: &[HoleCards], board: &Board) -> ShowdownResultWithHands;
-}
\ No newline at end of file
+
+    /// Solve every way of completing an incomplete board, for players all in before
+    /// the river.
+    ///
+    /// Exact, but the runouts multiply quickly: 990 from the flop, but about 1.7
+    /// million before it with two players. Use `solve_sampled_runouts` when that is too
+    /// many. A complete board has a single runout.
+    ///
+    /// # Arguments
+    /// * `players` - Slice of hole cards for each player
+    /// * `board` - The community cards dealt so far
+    ///
+    /// # Returns
+    /// A `RunoutResult` with each player's chance of winning over the runouts.
+    ///
+    /// # Panics
+    /// Panics if there are more than `MAX_PLAYERS` players or a card is dealt twice
+    /// (see `validate_no_duplicates`).
+    fn solve_all_runouts(&self, players: &[HoleCards], board: &Board) -> RunoutResult;
+
+    /// Solve `samples` random completions of an incomplete board.
+    ///
+    /// # Arguments
+    /// * `players` - Slice of hole cards for each player
+    /// * `board` - The community cards dealt so far
+    /// * `samples` - Number of runouts to deal
+    /// * `source` - Source of randomness for dealing the runouts
+    ///
+    /// # Returns
+    /// A `RunoutResult` estimating each player's chance of winning.
+    ///
+    /// # Panics
+    /// Panics if there are more than `MAX_PLAYERS` players or a card is dealt twice
+    /// (see `validate_no_duplicates`).
+    fn solve_sampled_runouts(
+        &self,
+        players: &[HoleCards],
+        board: &Board,
+        samples: u32,
+        source: &mut dyn RandomSource,
+    ) -> RunoutResult;
+}
+
+/// Forwards every `HandSolver` method through a pointer to a solver, so a solver
+/// chosen at runtime can be shared as `Arc<dyn HandSolver>`.
+macro_rules! forward_hand_solver {
+    ($($pointer:ty),+) => {$(
+        impl<S: HandSolver + ?Sized> HandSolver for $pointer {
+            fn solve(&self, players: &[HoleCards], board: &Board) -> ShowdownResult {
+                (**self).solve(players, board)
+            }
+
+            fn solve_with_hands(
+                &self,
+                players: &[HoleCards],
+                board: &Board,
+            ) -> ShowdownResultWithHands {
+                (**self).solve_with_hands(players, board)
+            }
+
+            fn solve_all_runouts(&self, players: &[HoleCards], board: &Board) -> RunoutResult {
+                (**self).solve_all_runouts(players, board)
+            }
+
+            fn solve_sampled_runouts(
+                &self,
+                players: &[HoleCards],
+                board: &Board,
+                samples: u32,
+                source: &mut dyn RandomSource,
+            ) -> RunoutResult {
+                (**self).solve_sampled_runouts(players, board, samples, source)
+            }
+        }
+    )+};
+}
+
+forward_hand_solver!(&S, Arc<S>, Box<S>);
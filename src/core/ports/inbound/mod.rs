@@ -1,7 +1,15 @@
 mod equity_calculator;
 mod hand_evaluator;
 mod hand_solver;
+mod strategy;
 
-pub use equity_calculator::{EquityCalculator, EquityResult};
-pub use hand_evaluator::HandEvaluator;
-pub use hand_solver::{HandSolver, ShowdownResult, ShowdownResultWithHands, MAX_PLAYERS};
+pub use equity_calculator::{
+    CancellationToken, EquityCalculator, EquityDistribution, EquityError, EquityProgress, EquityResult,
+    HandCategoryDistribution,
+};
+pub use hand_evaluator::{HandEvaluator, EVALUATOR_VERSION};
+pub use hand_solver::{
+    HandSolver, HiLoShowdownResult, RunoutResult, ShowdownResult, ShowdownResultWithHands,
+    MAX_PLAYERS,
+};
+pub use strategy::Strategy;
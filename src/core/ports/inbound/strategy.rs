@@ -0,0 +1,12 @@
+use crate::core::domain::aggregates::PlayerView;
+use crate::core::domain::primitives::Action;
+
+/// Port for a bot that decides how to play.
+///
+/// A strategy sees only what its player can see, through a `PlayerView`, and is asked
+/// to act whenever it is that player's turn. It must return one of the view's legal
+/// actions; a `SimulationRunner` checks or folds for a strategy that does not.
+pub trait Strategy: Send + Sync {
+    /// Chooses the action to take from `view`.
+    fn act(&self, view: &PlayerView) -> Action;
+}
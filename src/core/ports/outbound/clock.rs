@@ -1,16 +1,36 @@
 //! Clock port for time-related operations.
 
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Mutex, PoisonError},
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+use crate::core::domain::primitives::unix_nanos;
 
 /// Unix timestamp in milliseconds.
 pub type Timestamp = u64;
 
+/// Future returned by `Clock::sleep_until`.
+#[cfg(feature = "async")]
+pub type Sleep<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
 /// Port for time operations.
 ///
 /// This trait abstracts time to allow:
 /// - Dependency injection of different time sources
 /// - Deterministic testing with fixed/controlled time
 /// - Mocking time progression in simulations
+///
+/// `now` is wall-clock time for recording when things happened. Durations, such as
+/// how long a player has been thinking, should be measured with `now_instant`, which
+/// never jumps when the system time is adjusted.
 pub trait Clock: Send + Sync {
     /// Get the current timestamp in milliseconds since Unix epoch.
     fn now(&self) -> Timestamp;
@@ -19,6 +39,67 @@ pub trait Clock: Send + Sync {
     fn system_time(&self) -> SystemTime {
         UNIX_EPOCH + Duration::from_millis(self.now())
     }
+
+    /// Monotonic time since an arbitrary origin fixed for the life of the clock.
+    ///
+    /// Successive readings never decrease, so their difference is a reliable elapsed
+    /// time. Defaults to `now` for clocks that never move backwards.
+    fn now_instant(&self) -> Duration {
+        Duration::from_millis(self.now())
+    }
+
+    /// Waits until `now_instant` reaches `deadline` without blocking the calling
+    /// thread; resolves at once if it has.
+    ///
+    /// The future needs no particular runtime: a helper thread wakes the task once the
+    /// remaining time has passed, and the clock is read again then. Test clocks
+    /// override this to jump straight to `deadline` instead of waiting.
+    #[cfg(feature = "async")]
+    fn sleep_until(&self, deadline: Duration) -> Sleep<'_> {
+        Box::pin(SleepUntil {
+            clock: self,
+            deadline,
+            waker: Arc::default(),
+        })
+    }
+}
+
+/// Future waiting for a clock to reach a deadline.
+#[cfg(feature = "async")]
+struct SleepUntil<'a, C: ?Sized> {
+    clock: &'a C,
+    deadline: Duration,
+    /// Waker of the task, held while a timer thread is running to wake it.
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+#[cfg(feature = "async")]
+impl<C: Clock + ?Sized> Future for SleepUntil<'_, C> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let remaining = self.deadline.saturating_sub(self.clock.now_instant());
+        if remaining.is_zero() {
+            return Poll::Ready(());
+        }
+        let timing = self
+            .waker
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace(cx.waker().clone())
+            .is_some();
+        if !timing {
+            let waker = Arc::clone(&self.waker);
+            thread::spawn(move || {
+                thread::sleep(remaining);
+                let waker = waker.lock().unwrap_or_else(PoisonError::into_inner).take();
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            });
+        }
+        Poll::Pending
+    }
 }
 
 /// A clock that uses the system time.
@@ -28,10 +109,25 @@ pub struct SystemClock;
 impl Clock for SystemClock {
     #[allow(clippy::cast_possible_truncation)]
     fn now(&self) -> Timestamp {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("system time before Unix epoch")
-            .as_millis() as u64
+        (unix_nanos() / 1_000_000) as u64
+    }
+
+    /// Time since the first reading in this process, from `std::time::Instant`.
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    fn now_instant(&self) -> Duration {
+        use std::sync::LazyLock;
+        use std::time::Instant;
+
+        static ORIGIN: LazyLock<Instant> = LazyLock::new(Instant::now);
+        ORIGIN.elapsed()
+    }
+
+    /// `Instant` is unavailable on `wasm32-unknown-unknown`; `unix_nanos` never
+    /// decreases there, so it serves as the monotonic reading.
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    #[allow(clippy::cast_possible_truncation)]
+    fn now_instant(&self) -> Duration {
+        Duration::from_nanos(unix_nanos() as u64)
     }
 }
 
@@ -63,6 +159,77 @@ impl Clock for FixedClock {
     }
 }
 
+/// A clock that only moves when told to, for testing timers and schedules.
+///
+/// Clones share the same time, so a test can keep one handle and advance the clock
+/// while the code under test reads from another. With the `async` feature,
+/// `sleep_until` advances the clock to the deadline and resolves at once.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use riverrun::core::ports::outbound::{Clock, TestClock};
+///
+/// let clock = TestClock::new(1_000);
+/// let started = clock.now_instant();
+/// clock.advance(Duration::from_secs(3));
+/// assert_eq!(clock.now(), 4_000);
+/// assert_eq!(clock.now_instant() - started, Duration::from_secs(3));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TestClock {
+    start: Timestamp,
+    /// Nanoseconds advanced since `start`.
+    elapsed: Arc<AtomicU64>,
+}
+
+/// `TestClock` - Constructors
+impl TestClock {
+    /// Create a test clock reading `start`.
+    #[must_use]
+    pub fn new(start: Timestamp) -> Self {
+        Self {
+            start,
+            elapsed: Arc::default(),
+        }
+    }
+}
+
+/// `TestClock` - Operations
+impl TestClock {
+    /// Move the clock forward by `duration`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn advance(&self, duration: Duration) {
+        self.elapsed
+            .fetch_add(duration.as_nanos() as u64, Ordering::SeqCst);
+    }
+
+    /// Move the clock forward to `now_instant() == instant`; earlier instants are
+    /// ignored, so the clock never goes backwards.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn advance_to(&self, instant: Duration) {
+        self.elapsed
+            .fetch_max(instant.as_nanos() as u64, Ordering::SeqCst);
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> Timestamp {
+        self.start + self.elapsed.load(Ordering::SeqCst) / 1_000_000
+    }
+
+    fn now_instant(&self) -> Duration {
+        Duration::from_nanos(self.elapsed.load(Ordering::SeqCst))
+    }
+
+    #[cfg(feature = "async")]
+    fn sleep_until(&self, deadline: Duration) -> Sleep<'_> {
+        self.advance_to(deadline);
+        Box::pin(std::future::ready(()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,6 +256,65 @@ mod tests {
         assert_eq!(clock.now(), 0);
     }
 
+    #[test]
+    fn test_system_clock_instant_is_monotonic() {
+        let clock = SystemClock;
+        let earlier = clock.now_instant();
+        std::thread::sleep(Duration::from_millis(2));
+        assert!(clock.now_instant() >= earlier + Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_test_clock_advances_shared_time() {
+        let clock = TestClock::new(5_000);
+        let handle = clock.clone();
+        handle.advance(Duration::from_millis(1_500));
+        assert_eq!(clock.now(), 6_500);
+        assert_eq!(clock.now_instant(), Duration::from_millis(1_500));
+
+        clock.advance_to(Duration::from_secs(10));
+        assert_eq!(handle.now(), 15_000);
+        clock.advance_to(Duration::from_secs(1));
+        assert_eq!(clock.now_instant(), Duration::from_secs(10));
+    }
+
+    /// Poll `future` on this thread until it resolves, parking between wakes.
+    #[cfg(feature = "async")]
+    fn block_on(future: impl Future<Output = ()>) {
+        use std::task::Wake;
+
+        struct Unpark(thread::Thread);
+
+        impl Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+        while future.as_mut().poll(&mut cx).is_pending() {
+            thread::park();
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn test_sleep_until_waits_for_the_deadline() {
+        let clock = SystemClock;
+        let deadline = clock.now_instant() + Duration::from_millis(5);
+        block_on(clock.sleep_until(deadline));
+        assert!(clock.now_instant() >= deadline);
+
+        // A test clock jumps to the deadline, and a past deadline resolves at once
+        let clock = TestClock::new(0);
+        block_on(clock.sleep_until(Duration::from_secs(30)));
+        assert_eq!(clock.now(), 30_000);
+        block_on(clock.sleep_until(Duration::from_secs(1)));
+        assert_eq!(clock.now(), 30_000);
+    }
+
     #[test]
     fn test_system_time_conversion() {
         let clock = FixedClock::new(1_577_836_800_000); // 2020-01-01 00:00:00 UTC
@@ -8,7 +8,14 @@ use super::read_model::PlayerId;
 ///
 /// These are simplified events for external consumers (UI, webhooks).
 /// Full event details are stored in the event store.
+///
+/// With the `serde` feature, notifications serialize as objects tagged by a
+/// snake-case `type` field, e.g. `{"type":"game_ended","game_id":"g1","timestamp":0}`.
+/// Publishers send them wrapped in a `VersionedNotification`. Cards are written like
+/// `"Ah"`, and amounts are in chips.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum GameNotification {
     /// A new game has started.
     GameStarted {
@@ -28,11 +35,40 @@ pub enum GameNotification {
     /// Hole cards have been dealt.
     HoleCardsDealt { game_id: GameId, timestamp: Timestamp },
 
+    /// A player acted.
+    ActionTaken {
+        game_id: GameId,
+        timestamp: Timestamp,
+        player_id: PlayerId,
+        action: PlayerAction,
+        /// Chips in every pot after the action.
+        pot_after: u64,
+    },
+
+    /// It is a player's turn to act.
+    ActionRequired {
+        game_id: GameId,
+        timestamp: Timestamp,
+        player_id: PlayerId,
+        /// Chips the player would put in by calling; zero if they can check.
+        to_call: u64,
+        /// Smallest legal bet or raise-to total; zero if the player cannot bet or raise.
+        min_raise: u64,
+        /// Milliseconds the player has to act.
+        time_bank: u64,
+    },
+
     /// Community cards dealt (flop/turn/river).
     StreetDealt {
         game_id: GameId,
         timestamp: Timestamp,
         street: Street,
+        /// The cards dealt on this street.
+        #[cfg_attr(feature = "serde", serde(default))]
+        cards: Vec<String>,
+        /// Chips in every pot as the street is dealt.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pot: u64,
     },
 
     /// The hand has reached showdown.
@@ -40,21 +76,54 @@ pub enum GameNotification {
         game_id: GameId,
         timestamp: Timestamp,
         winner_ids: Vec<PlayerId>,
+        /// Chips in every pot before they were awarded.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pot: u64,
+        /// The winning hand rank (e.g., "Full House", "Flush").
+        #[cfg_attr(feature = "serde", serde(default))]
+        winning_hand_rank: Option<String>,
+        /// Chips awarded to each winner.
+        #[cfg_attr(feature = "serde", serde(default))]
+        winnings: Vec<Winnings>,
     },
 
     /// The game has ended.
     GameEnded { game_id: GameId, timestamp: Timestamp },
 }
 
-/// Street enum for notifications (separate from domain to avoid coupling).
+/// A player's action, as sent in `GameNotification::ActionTaken`.
+///
+/// With the `serde` feature, actions serialize as `{"kind":"fold"}` or, for those with
+/// an amount, `{"kind":"raise","amount":60}`. Raise amounts are raise-to totals.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Street {
-    Preflop,
-    Flop,
-    Turn,
-    River,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "amount", rename_all = "snake_case")
+)]
+pub enum PlayerAction {
+    Fold,
+    Check,
+    Call(u64),
+    Bet(u64),
+    Raise(u64),
+    AllIn(u64),
 }
 
+/// Chips awarded to one player at showdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Winnings {
+    /// The player awarded the chips.
+    pub player_id: PlayerId,
+    /// Chips awarded, summed over every pot the player won a share of.
+    pub amount: u64,
+}
+
+/// Street of a `StreetDealt` notification: the domain street, re-exported so
+/// notifications and the engine share one type.
+pub use crate::core::domain::primitives::Street;
+
 impl GameNotification {
     /// Get the game ID from any notification.
     #[must_use]
@@ -63,6 +132,8 @@ impl GameNotification {
             Self::GameStarted { game_id, .. }
             | Self::PlayerJoined { game_id, .. }
             | Self::HoleCardsDealt { game_id, .. }
+            | Self::ActionTaken { game_id, .. }
+            | Self::ActionRequired { game_id, .. }
             | Self::StreetDealt { game_id, .. }
             | Self::Showdown { game_id, .. }
             | Self::GameEnded { game_id, .. } => game_id,
@@ -76,6 +147,8 @@ impl GameNotification {
             Self::GameStarted { timestamp, .. }
             | Self::PlayerJoined { timestamp, .. }
             | Self::HoleCardsDealt { timestamp, .. }
+            | Self::ActionTaken { timestamp, .. }
+            | Self::ActionRequired { timestamp, .. }
             | Self::StreetDealt { timestamp, .. }
             | Self::Showdown { timestamp, .. }
             | Self::GameEnded { timestamp, .. } => *timestamp,
@@ -83,6 +156,63 @@ impl GameNotification {
     }
 }
 
+/// Version of the notification schema written by this crate.
+///
+/// Version 1 notifications carried no cards or amounts; version 2 added them to
+/// `StreetDealt` and `Showdown`, and version 3 added `ActionTaken` and
+/// `ActionRequired`. Fields added since version 1 default when missing, so
+/// notifications of every version deserialize.
+pub const NOTIFICATION_VERSION: u32 = 3;
+
+/// A `GameNotification` tagged with the schema version it was written with.
+///
+/// With the `serde` feature, the version is a `version` field alongside the
+/// notification's own, e.g.
+/// `{"version":3,"type":"game_ended","game_id":"g1","timestamp":0}`. A payload without
+/// one is read as version 1.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::ports::outbound::{
+///     GameNotification, NOTIFICATION_VERSION, VersionedNotification,
+/// };
+///
+/// let versioned = VersionedNotification::new(GameNotification::GameEnded {
+///     game_id: "g1".into(),
+///     timestamp: 0,
+/// });
+/// assert_eq!(versioned.version, NOTIFICATION_VERSION);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VersionedNotification {
+    /// Schema version the notification was written with.
+    #[cfg_attr(feature = "serde", serde(default = "legacy_version"))]
+    pub version: u32,
+    /// The notification.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub notification: GameNotification,
+}
+
+/// `VersionedNotification` - Constructors
+impl VersionedNotification {
+    /// Tags `notification` with the current `NOTIFICATION_VERSION`.
+    #[must_use]
+    pub const fn new(notification: GameNotification) -> Self {
+        Self {
+            version: NOTIFICATION_VERSION,
+            notification,
+        }
+    }
+}
+
+/// Version of payloads written before notifications were versioned.
+#[cfg(feature = "serde")]
+const fn legacy_version() -> u32 {
+    1
+}
+
 /// Port for publishing game notifications in real-time.
 ///
 /// Implementations can:
@@ -119,30 +249,90 @@ mod tests {
     #[test]
     fn test_notification_game_id() {
         let notification = GameNotification::GameStarted {
-            game_id: "game-123".to_string(),
+            game_id: "game-123".into(),
             timestamp: 1000,
             num_players: 4,
             player_ids: vec![],
         };
-        assert_eq!(notification.game_id(), "game-123");
+        assert_eq!(notification.game_id(), &GameId::from("game-123"));
     }
 
     #[test]
     fn test_notification_timestamp() {
         let notification = GameNotification::StreetDealt {
-            game_id: "game-123".to_string(),
+            game_id: "game-123".into(),
             timestamp: 2000,
             street: Street::Flop,
+            cards: vec!["Ah".to_string(), "Kd".to_string(), "2c".to_string()],
+            pot: 30,
         };
         assert_eq!(notification.timestamp(), 2000);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_versioned_notification_json() {
+        let showdown = VersionedNotification::new(GameNotification::Showdown {
+            game_id: "g1".into(),
+            timestamp: 5,
+            winner_ids: vec!["alice".to_string()],
+            pot: 200,
+            winning_hand_rank: Some("Flush".to_string()),
+            winnings: vec![Winnings {
+                player_id: "alice".to_string(),
+                amount: 200,
+            }],
+        });
+        let json = serde_json::to_string(&showdown).unwrap();
+        assert_eq!(
+            json,
+            r#"{"version":3,"type":"showdown","game_id":"g1","timestamp":5,"winner_ids":["alice"],"pot":200,"winning_hand_rank":"Flush","winnings":[{"player_id":"alice","amount":200}]}"#
+        );
+        assert_eq!(serde_json::from_str::<VersionedNotification>(&json).unwrap(), showdown);
+
+        // Payloads from before versioning read as version 1 with empty new fields
+        let legacy: VersionedNotification = serde_json::from_str(
+            r#"{"type":"street_dealt","game_id":"g1","timestamp":5,"street":"turn"}"#,
+        )
+        .unwrap();
+        assert_eq!(legacy.version, 1);
+        assert_eq!(
+            legacy.notification,
+            GameNotification::StreetDealt {
+                game_id: "g1".into(),
+                timestamp: 5,
+                street: Street::Turn,
+                cards: vec![],
+                pot: 0,
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_action_notification_json() {
+        let taken = GameNotification::ActionTaken {
+            game_id: "g1".into(),
+            timestamp: 5,
+            player_id: "alice".to_string(),
+            action: PlayerAction::Raise(60),
+            pot_after: 75,
+        };
+        assert_eq!(
+            serde_json::to_string(&taken).unwrap(),
+            r#"{"type":"action_taken","game_id":"g1","timestamp":5,"player_id":"alice","action":{"kind":"raise","amount":60},"pot_after":75}"#
+        );
+        let fold = serde_json::to_string(&PlayerAction::Fold).unwrap();
+        assert_eq!(fold, r#"{"kind":"fold"}"#);
+        assert_eq!(serde_json::from_str::<PlayerAction>(&fold).unwrap(), PlayerAction::Fold);
+    }
+
     #[test]
     fn test_noop_publisher() {
         let publisher = NoOpPublisher;
         // Should not panic
         publisher.publish(GameNotification::GameEnded {
-            game_id: "game-1".to_string(),
+            game_id: "game-1".into(),
             timestamp: 0,
         });
     }
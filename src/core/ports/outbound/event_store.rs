@@ -5,8 +5,9 @@ use std::fmt;
 
 use super::clock::Timestamp;
 
-/// Unique identifier for a game/aggregate.
-pub type GameId = String;
+/// Unique identifier for a game/aggregate: the domain's `GameId`, so event streams are
+/// keyed by the same type the aggregates use.
+pub use crate::core::domain::primitives::GameId;
 
 /// Version number for optimistic concurrency control.
 pub type Version = u64;
@@ -159,11 +160,11 @@ mod tests {
 
     #[test]
     fn test_stored_event_creation() {
-        let event = StoredEvent::new("test_event", 1, 1000, "game-1".to_string());
+        let event = StoredEvent::new("test_event", 1, 1000, "game-1".into());
         assert_eq!(event.event, "test_event");
         assert_eq!(event.version, 1);
         assert_eq!(event.timestamp, 1000);
-        assert_eq!(event.game_id, "game-1");
+        assert_eq!(event.game_id, GameId::from("game-1"));
     }
 
     #[test]
@@ -177,7 +178,7 @@ mod tests {
             "concurrency conflict: expected version 5, actual 7"
         );
 
-        let err = EventStoreError::NotFound("game-123".to_string());
+        let err = EventStoreError::NotFound("game-123".into());
         assert_eq!(err.to_string(), "game not found: game-123");
 
         let err = EventStoreError::StorageError("connection failed".to_string());
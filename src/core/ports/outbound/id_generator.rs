@@ -2,6 +2,8 @@
 
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use crate::core::domain::primitives::unix_nanos;
+
 /// Port for generating unique identifiers.
 ///
 /// This trait abstracts ID generation to allow:
@@ -92,12 +94,7 @@ impl SimpleUuidGenerator {
 
 impl IdGenerator for SimpleUuidGenerator {
     fn generate(&self) -> String {
-        use std::time::{SystemTime, UNIX_EPOCH};
-
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos();
+        let timestamp = unix_nanos();
 
         // Simple pseudo-random using timestamp bits
         let random_part = timestamp.wrapping_mul(6_364_136_223_846_793_005);
@@ -12,10 +12,13 @@ mod event_store;
 mod id_generator;
 mod random_source;
 mod read_model;
+mod result_cache;
 mod snapshot_store;
 
 // Time
-pub use clock::{Clock, FixedClock, SystemClock, Timestamp};
+pub use clock::{Clock, FixedClock, SystemClock, TestClock, Timestamp};
+#[cfg(feature = "async")]
+pub use clock::Sleep;
 
 // Event Sourcing
 pub use event_store::{EventStore, EventStoreError, GameId, StoredEvent, Version};
@@ -23,12 +26,22 @@ pub use snapshot_store::{Snapshot, SnapshotError, SnapshotPolicy, SnapshotStore}
 
 // Read Models (Projections)
 pub use read_model::{
-    ActiveGameState, ActiveGameStore, HandId, HandSummary, HandSummaryStore, PlayerId,
-    PlayerStats, PlayerStatsStore, ReadModelError,
+    ActiveGameState, ActiveGameStore, AllInEv, BankrollPoint, HandCursor, HandId, HandPage,
+    HandQuery, HandSummary, HandSummaryStore, LeaderboardWindow, PeriodStats, PlayerId,
+    PlayerStats, PlayerStatsStore, ReadModelError, STATS_PERIOD_MILLIS, SessionId, SessionStats,
+    SessionStatsStore, period_start,
+};
+
+// Computation Caching
+pub use result_cache::{
+    CacheKey, CacheKeyBuilder, ResultCache, ResultCacheError, CACHE_FORMAT_VERSION,
 };
 
 // Real-time Notifications
-pub use event_publisher::{GameNotification, NoOpPublisher, NotificationPublisher, Street};
+pub use event_publisher::{
+    GameNotification, NOTIFICATION_VERSION, NoOpPublisher, NotificationPublisher, PlayerAction,
+    Street, VersionedNotification, Winnings,
+};
 
 // Utilities
 pub use id_generator::{IdGenerator, SequentialIdGenerator, SimpleUuidGenerator};
@@ -15,6 +15,9 @@ pub type PlayerId = String;
 /// Unique identifier for a hand record.
 pub type HandId = String;
 
+/// Unique identifier for a player's session at a table: the domain's `SessionId`.
+pub use crate::core::domain::primitives::SessionId;
+
 /// Error type for read model operations.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ReadModelError {
@@ -61,6 +64,34 @@ pub struct HandSummary {
     pub is_tie: bool,
     /// The winning hand rank (e.g., "Full House", "Flush").
     pub winning_hand_rank: Option<String>,
+    /// Total chips awarded from every pot.
+    pub pot: u64,
+    /// Expected against actual winnings of each player all-in before the river; empty
+    /// unless the hand was decided by an all-in runout.
+    pub all_in_ev: Vec<AllInEv>,
+}
+
+/// One player's share of an all-in: what their equity entitled them to against what
+/// the runout gave them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllInEv {
+    /// The player's ID.
+    pub player_id: PlayerId,
+    /// Share of the pots the player was expected to win, from 0 to 1.
+    pub equity: f64,
+    /// Chips the player's equity was worth, rounded to the nearest chip.
+    pub expected: u64,
+    /// Chips the player was actually awarded.
+    pub actual: u64,
+}
+
+impl AllInEv {
+    /// Chips won above (positive) or below (negative) expectation.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn luck(&self) -> i64 {
+        self.actual as i64 - self.expected as i64
+    }
 }
 
 impl HandSummary {
@@ -71,6 +102,170 @@ impl HandSummary {
     }
 }
 
+/// Filters for `HandSummaryStore::search`; a hand must match every filter that is set.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::ports::outbound::HandQuery;
+///
+/// let query = HandQuery::new()
+///     .player("alice".to_string())
+///     .between(1_000, 2_000)
+///     .winning_hand_rank("Flush")
+///     .min_pot(500);
+/// assert!(!query.ties_only);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HandQuery {
+    /// Only hands this player took part in.
+    pub player_id: Option<PlayerId>,
+    /// Only hands started at or after this time.
+    pub from: Option<Timestamp>,
+    /// Only hands started at or before this time.
+    pub to: Option<Timestamp>,
+    /// Only hands won with this rank, compared case-insensitively.
+    pub winning_hand_rank: Option<String>,
+    /// Only hands whose pot is at least this many chips.
+    pub min_pot: Option<u64>,
+    /// Only hands that ended in a tie.
+    pub ties_only: bool,
+}
+
+/// `HandQuery` - Constructors
+impl HandQuery {
+    /// A query matching every hand.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict to hands `player_id` took part in.
+    #[must_use]
+    pub fn player(mut self, player_id: PlayerId) -> Self {
+        self.player_id = Some(player_id);
+        self
+    }
+
+    /// Restrict to hands started between `from` and `to`, inclusive.
+    #[must_use]
+    pub const fn between(mut self, from: Timestamp, to: Timestamp) -> Self {
+        self.from = Some(from);
+        self.to = Some(to);
+        self
+    }
+
+    /// Restrict to hands won with `rank`, e.g. "Full House".
+    #[must_use]
+    pub fn winning_hand_rank(mut self, rank: impl Into<String>) -> Self {
+        self.winning_hand_rank = Some(rank.into());
+        self
+    }
+
+    /// Restrict to hands with a pot of at least `chips`.
+    #[must_use]
+    pub const fn min_pot(mut self, chips: u64) -> Self {
+        self.min_pot = Some(chips);
+        self
+    }
+
+    /// Restrict to hands that ended in a tie.
+    #[must_use]
+    pub const fn ties_only(mut self) -> Self {
+        self.ties_only = true;
+        self
+    }
+}
+
+/// `HandQuery` - Operations
+impl HandQuery {
+    /// Returns whether `hand` passes every filter.
+    #[must_use]
+    pub fn matches(&self, hand: &HandSummary) -> bool {
+        self.player_id
+            .as_ref()
+            .is_none_or(|player| hand.player_ids.contains(player))
+            && self.from.is_none_or(|from| hand.started_at >= from)
+            && self.to.is_none_or(|to| hand.started_at <= to)
+            && self.winning_hand_rank.as_ref().is_none_or(|rank| {
+                hand.winning_hand_rank
+                    .as_ref()
+                    .is_some_and(|won| won.eq_ignore_ascii_case(rank))
+            })
+            && self.min_pot.is_none_or(|min_pot| hand.pot >= min_pot)
+            && (!self.ties_only || hand.is_tie)
+    }
+
+    /// Checks that the query can match anything.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::InvalidQuery` if the time range starts after it ends.
+    pub fn validate(&self) -> Result<(), ReadModelError> {
+        match (self.from, self.to) {
+            (Some(from), Some(to)) if from > to => Err(ReadModelError::InvalidQuery(format!(
+                "time range starts at {from} after it ends at {to}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Position in a `HandSummaryStore::search` listing: the last hand of a page.
+///
+/// Searches list the most recent hands first, so the next page starts with the hand
+/// ordered just before this one by start time, then hand ID. Unlike an offset, a cursor
+/// stays on the same hands while new ones are saved.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HandCursor {
+    /// Start time of the last hand returned.
+    pub started_at: Timestamp,
+    /// ID of the last hand returned.
+    pub hand_id: HandId,
+}
+
+/// `HandCursor` - Operations
+impl HandCursor {
+    /// Cursor positioned at `hand`.
+    #[must_use]
+    pub fn at(hand: &HandSummary) -> Self {
+        Self {
+            started_at: hand.started_at,
+            hand_id: hand.hand_id.clone(),
+        }
+    }
+
+    /// Returns whether `hand` comes after the cursor in a search listing.
+    #[must_use]
+    pub fn precedes(&self, hand: &HandSummary) -> bool {
+        (hand.started_at, &hand.hand_id) < (self.started_at, &self.hand_id)
+    }
+}
+
+/// One page of `HandSummaryStore::search` results.
+#[derive(Debug, Clone)]
+pub struct HandPage {
+    /// Matching hands, most recent first.
+    pub hands: Vec<HandSummary>,
+    /// Cursor for the next page, or `None` if this is the last.
+    pub next: Option<HandCursor>,
+}
+
+/// `HandPage` - Constructors
+impl HandPage {
+    /// Builds a page from up to `limit + 1` hands, the extra one showing that more
+    /// follow.
+    #[must_use]
+    pub fn from_lookahead(mut hands: Vec<HandSummary>, limit: usize) -> Self {
+        let next = if hands.len() > limit {
+            hands.truncate(limit);
+            hands.last().map(HandCursor::at)
+        } else {
+            None
+        };
+        Self { hands, next }
+    }
+}
+
 /// Port for storing and querying hand summaries.
 pub trait HandSummaryStore: Send + Sync {
     /// Save or update a hand summary.
@@ -118,9 +313,28 @@ pub trait HandSummaryStore: Send + Sync {
     /// # Errors
     /// Returns `ReadModelError::StorageError` on I/O errors.
     fn count_by_player(&self, player_id: &PlayerId) -> Result<u64, ReadModelError>;
+
+    /// Find hands matching `query`, most recent first, starting after `cursor`.
+    ///
+    /// Pass `None` for the first page and the returned `HandPage::next` for the
+    /// following ones.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::InvalidQuery` if the query's time range starts after it
+    /// ends, or `ReadModelError::StorageError` on I/O errors.
+    fn search(
+        &self,
+        query: &HandQuery,
+        cursor: Option<&HandCursor>,
+        limit: usize,
+    ) -> Result<HandPage, ReadModelError>;
 }
 
 /// Player statistics read model.
+///
+/// Besides hands played and won, it counts the events behind the standard HUD
+/// statistics. The ratio methods turn the counts into percentages, each over the hands
+/// in which the player had the chance to do the thing measured.
 #[derive(Debug, Clone, Default)]
 pub struct PlayerStats {
     /// The player's ID.
@@ -131,6 +345,34 @@ pub struct PlayerStats {
     pub hands_won: u64,
     /// Timestamp of last hand played.
     pub last_played_at: Option<Timestamp>,
+    /// Hands in which the player voluntarily put chips in preflop.
+    pub vpip_hands: u64,
+    /// Hands in which the player bet or raised preflop.
+    pub pfr_hands: u64,
+    /// Hands in which the player acted preflop facing a single raise.
+    pub three_bet_opportunities: u64,
+    /// Hands in which the player re-raised a single preflop raise.
+    pub three_bets: u64,
+    /// Hands in which the player raised last preflop and was checked to on the flop.
+    pub cbet_opportunities: u64,
+    /// Hands in which the player made a continuation bet on the flop.
+    pub cbets: u64,
+    /// Hands in which the player was still in when the flop was dealt.
+    pub flops_seen: u64,
+    /// Hands the player took to showdown.
+    pub showdowns: u64,
+    /// Showdowns at which the player was awarded chips.
+    pub showdowns_won: u64,
+    /// Bets and raises made after the flop was dealt.
+    pub postflop_aggressive_actions: u64,
+    /// Calls made after the flop was dealt.
+    pub postflop_calls: u64,
+    /// Hands in which the player was all-in, or called an all-in, before the river.
+    pub all_in_hands: u64,
+    /// Chips the player's equity was worth over those hands.
+    pub all_in_expected_winnings: u64,
+    /// Chips the player was actually awarded over those hands.
+    pub all_in_winnings: u64,
 }
 
 impl PlayerStats {
@@ -144,6 +386,139 @@ impl PlayerStats {
             (self.hands_won as f64 / self.hands_played as f64) * 100.0
         }
     }
+
+    /// Voluntarily put money in pot: percentage of hands played.
+    #[must_use]
+    pub fn vpip(&self) -> f64 {
+        percentage(self.vpip_hands, self.hands_played)
+    }
+
+    /// Preflop raise: percentage of hands played.
+    #[must_use]
+    pub fn pfr(&self) -> f64 {
+        percentage(self.pfr_hands, self.hands_played)
+    }
+
+    /// Percentage of chances to re-raise a single preflop raise that were taken.
+    #[must_use]
+    pub fn three_bet(&self) -> f64 {
+        percentage(self.three_bets, self.three_bet_opportunities)
+    }
+
+    /// Percentage of chances to continuation-bet the flop that were taken.
+    #[must_use]
+    pub fn cbet(&self) -> f64 {
+        percentage(self.cbets, self.cbet_opportunities)
+    }
+
+    /// Went to showdown: percentage of flops seen.
+    #[must_use]
+    pub fn wtsd(&self) -> f64 {
+        percentage(self.showdowns, self.flops_seen)
+    }
+
+    /// Won money at showdown: percentage of showdowns.
+    #[must_use]
+    pub fn wsd(&self) -> f64 {
+        percentage(self.showdowns_won, self.showdowns)
+    }
+
+    /// Postflop bets and raises per call.
+    ///
+    /// A player who never called gets their number of bets and raises.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn aggression_factor(&self) -> f64 {
+        self.postflop_aggressive_actions as f64 / self.postflop_calls.max(1) as f64
+    }
+
+    /// Chips won in all-ins above (positive) or below (negative) what the player's
+    /// equity was worth; subtracting it from results gives luck-adjusted winnings.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn all_in_luck(&self) -> i64 {
+        self.all_in_winnings as i64 - self.all_in_expected_winnings as i64
+    }
+}
+
+/// `part` as a percentage of `whole`, or zero when `whole` is zero.
+#[allow(clippy::cast_precision_loss)]
+fn percentage(part: u64, whole: u64) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        (part as f64 / whole as f64) * 100.0
+    }
+}
+
+/// Length of the periods `PeriodStats` are kept for: one UTC day, in milliseconds.
+pub const STATS_PERIOD_MILLIS: Timestamp = 86_400_000;
+
+/// Start of the stats period containing `at`.
+#[must_use]
+pub const fn period_start(at: Timestamp) -> Timestamp {
+    at - at % STATS_PERIOD_MILLIS
+}
+
+/// Hands played and won by one player over a period.
+///
+/// Stores keep one per player per `STATS_PERIOD_MILLIS`, keyed by `period_start`;
+/// time-windowed leaderboards sum them from the start of the window.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PeriodStats {
+    /// The player's ID.
+    pub player_id: PlayerId,
+    /// Start of the period.
+    pub period_start: Timestamp,
+    /// Hands played in the period.
+    pub hands_played: u64,
+    /// Hands won in the period.
+    pub hands_won: u64,
+}
+
+impl PeriodStats {
+    /// Empty stats for `player_id` over the period containing `at`.
+    #[must_use]
+    pub const fn new(player_id: PlayerId, at: Timestamp) -> Self {
+        Self {
+            player_id,
+            period_start: period_start(at),
+            hands_played: 0,
+            hands_won: 0,
+        }
+    }
+
+    /// Calculate win rate as a percentage.
+    #[must_use]
+    pub fn win_rate(&self) -> f64 {
+        percentage(self.hands_won, self.hands_played)
+    }
+}
+
+/// A rolling leaderboard window ending at the current period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeaderboardWindow {
+    /// The current day and the six before it.
+    Weekly,
+    /// The current day and the 29 before it.
+    Monthly,
+}
+
+impl LeaderboardWindow {
+    /// Number of periods in the window.
+    #[must_use]
+    pub const fn periods(self) -> u64 {
+        match self {
+            Self::Weekly => 7,
+            Self::Monthly => 30,
+        }
+    }
+
+    /// Start of the window ending in the period containing `now`.
+    #[must_use]
+    pub const fn since(self, now: Timestamp) -> Timestamp {
+        period_start(now).saturating_sub((self.periods() - 1) * STATS_PERIOD_MILLIS)
+    }
 }
 
 /// Port for storing and querying player statistics.
@@ -175,10 +550,194 @@ pub trait PlayerStatsStore: Send + Sync {
         min_hands: u64,
         limit: usize,
     ) -> Result<Vec<PlayerStats>, ReadModelError>;
+
+    /// Get a player's stats for the period containing `at`, empty if none are stored.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` on I/O errors.
+    fn get_period(
+        &self,
+        player_id: &PlayerId,
+        at: Timestamp,
+    ) -> Result<PeriodStats, ReadModelError>;
+
+    /// Save a player's stats for one period.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` on I/O errors.
+    fn save_period(&self, stats: &PeriodStats) -> Result<(), ReadModelError>;
+
+    /// Get top players by wins over the periods from the one containing `from` on.
+    ///
+    /// Each entry sums a player's periods, with `period_start` set to the first
+    /// period counted.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` on I/O errors.
+    fn top_by_wins_since(
+        &self,
+        from: Timestamp,
+        limit: usize,
+    ) -> Result<Vec<PeriodStats>, ReadModelError>;
+
+    /// Get top players by wins over a rolling `window` ending at `now`.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` on I/O errors.
+    fn leaderboard(
+        &self,
+        window: LeaderboardWindow,
+        now: Timestamp,
+        limit: usize,
+    ) -> Result<Vec<PeriodStats>, ReadModelError> {
+        self.top_by_wins_since(window.since(now), limit)
+    }
+}
+
+/// A player's net result at one point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BankrollPoint {
+    /// When the result changed.
+    pub timestamp: Timestamp,
+    /// Net chips won (positive) or lost (negative) up to this point.
+    pub bankroll: i64,
+}
+
+/// Session statistics read model: one player's money in and out over a session.
+///
+/// Built by recording buy-ins, cash-outs, and the pot awards of every hand played.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionStats {
+    /// The session's ID.
+    pub session_id: SessionId,
+    /// The player playing the session.
+    pub player_id: PlayerId,
+    /// Timestamp of the first buy-in.
+    pub started_at: Timestamp,
+    /// Timestamp of the most recent buy-in, cash-out, or hand.
+    pub last_activity_at: Timestamp,
+    /// Total chips bought, including rebuys and top-ups.
+    pub buy_ins: u64,
+    /// Total chips taken off the table.
+    pub cash_outs: u64,
+    /// Chips currently on the table.
+    pub stack: u64,
+    /// Total hands played.
+    pub hands_played: u64,
+    /// Hands in which the player was awarded at least one pot.
+    pub hands_won: u64,
+    /// Net result after each hand, oldest first.
+    pub history: Vec<BankrollPoint>,
+}
+
+impl SessionStats {
+    /// Create stats for a session starting at `started_at`, before any buy-in.
+    #[must_use]
+    pub const fn new(session_id: SessionId, player_id: PlayerId, started_at: Timestamp) -> Self {
+        Self {
+            session_id,
+            player_id,
+            started_at,
+            last_activity_at: started_at,
+            buy_ins: 0,
+            cash_outs: 0,
+            stack: 0,
+            hands_played: 0,
+            hands_won: 0,
+            history: Vec::new(),
+        }
+    }
+
+    /// Net chips won (positive) or lost (negative), counting the chips still on the table.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn net_result(&self) -> i64 {
+        (self.cash_outs as i64 + self.stack as i64) - self.buy_ins as i64
+    }
+
+    /// Record buying `amount` chips.
+    pub const fn record_buy_in(&mut self, amount: u64, at: Timestamp) {
+        self.buy_ins += amount;
+        self.stack += amount;
+        self.last_activity_at = at;
+    }
+
+    /// Record taking `amount` chips off the table, capped at the current stack.
+    pub const fn record_cash_out(&mut self, amount: u64, at: Timestamp) {
+        let amount = if amount < self.stack { amount } else { self.stack };
+        self.cash_outs += amount;
+        self.stack -= amount;
+        self.last_activity_at = at;
+    }
+
+    /// Record a hand in which the player put `contributed` chips in the pot and was
+    /// awarded `awarded` chips from it.
+    pub fn record_hand(&mut self, contributed: u64, awarded: u64, at: Timestamp) {
+        self.stack = self.stack.saturating_sub(contributed) + awarded;
+        self.hands_played += 1;
+        if awarded > 0 {
+            self.hands_won += 1;
+        }
+        self.last_activity_at = at;
+        self.history.push(BankrollPoint {
+            timestamp: at,
+            bankroll: self.net_result(),
+        });
+    }
+}
+
+/// Port for storing and querying session statistics.
+pub trait SessionStatsStore: Send + Sync {
+    /// Save or update session stats.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` on I/O errors.
+    fn save(&self, stats: &SessionStats) -> Result<(), ReadModelError>;
+
+    /// Get stats for a session.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::NotFound` if the session doesn't exist.
+    fn get(&self, session_id: &SessionId) -> Result<SessionStats, ReadModelError>;
+
+    /// Find every session of a player, oldest first.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` on I/O errors.
+    fn find_by_player(&self, player_id: &PlayerId) -> Result<Vec<SessionStats>, ReadModelError>;
+
+    /// A player's running net result across all of their sessions, oldest first.
+    ///
+    /// # Errors
+    /// Returns `ReadModelError::StorageError` on I/O errors.
+    fn bankroll_history(&self, player_id: &PlayerId) -> Result<Vec<BankrollPoint>, ReadModelError> {
+        let mut changes: Vec<(Timestamp, i64)> = Vec::new();
+        for session in self.find_by_player(player_id)? {
+            let mut previous = 0;
+            for point in session.history {
+                changes.push((point.timestamp, point.bankroll - previous));
+                previous = point.bankroll;
+            }
+        }
+        changes.sort_by_key(|&(timestamp, _)| timestamp);
+
+        let mut bankroll = 0;
+        Ok(changes
+            .into_iter()
+            .map(|(timestamp, change)| {
+                bankroll += change;
+                BankrollPoint {
+                    timestamp,
+                    bankroll,
+                }
+            })
+            .collect())
+    }
 }
 
 /// Active game state read model for quick lookups.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ActiveGameState {
     /// The game ID.
     pub game_id: GameId,
@@ -235,7 +794,7 @@ mod tests {
     fn test_hand_summary_duration() {
         let summary = HandSummary {
             hand_id: "hand-1".to_string(),
-            game_id: "game-1".to_string(),
+            game_id: "game-1".into(),
             started_at: 1000,
             ended_at: 3500,
             num_players: 4,
@@ -243,10 +802,73 @@ mod tests {
             winner_ids: vec![],
             is_tie: false,
             winning_hand_rank: None,
+            pot: 0,
+            all_in_ev: Vec::new(),
         };
         assert_eq!(summary.duration_ms(), 2500);
     }
 
+    #[test]
+    fn test_hand_query_matches_every_filter() {
+        let hand = HandSummary {
+            hand_id: "hand-1".to_string(),
+            game_id: "game-1".into(),
+            started_at: 1000,
+            ended_at: 2000,
+            num_players: 2,
+            player_ids: vec!["alice".to_string(), "bob".to_string()],
+            winner_ids: vec!["alice".to_string(), "bob".to_string()],
+            is_tie: true,
+            winning_hand_rank: Some("Full House".to_string()),
+            pot: 400,
+            all_in_ev: Vec::new(),
+        };
+
+        let query = HandQuery::new()
+            .player("bob".to_string())
+            .between(1000, 1000)
+            .winning_hand_rank("full house")
+            .min_pot(400)
+            .ties_only();
+        assert!(query.matches(&hand));
+        assert!(!query.clone().min_pot(401).matches(&hand));
+        assert!(!query.clone().player("carol".to_string()).matches(&hand));
+        assert!(!HandQuery::new().winning_hand_rank("Flush").matches(&hand));
+        assert!(!HandQuery::new().between(0, 999).matches(&hand));
+
+        assert!(query.validate().is_ok());
+        assert!(matches!(
+            HandQuery::new().between(5, 4).validate(),
+            Err(ReadModelError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_hand_page_from_lookahead() {
+        let hand = |id: &str, started_at| HandSummary {
+            hand_id: id.to_string(),
+            game_id: "game-1".into(),
+            started_at,
+            ended_at: started_at,
+            num_players: 2,
+            player_ids: vec![],
+            winner_ids: vec![],
+            is_tie: false,
+            winning_hand_rank: None,
+            pot: 0,
+            all_in_ev: Vec::new(),
+        };
+
+        let page = HandPage::from_lookahead(vec![hand("b", 20), hand("a", 20), hand("c", 10)], 2);
+        let next = page.next.unwrap();
+        assert_eq!((next.started_at, next.hand_id.as_str()), (20, "a"));
+        assert!(next.precedes(&hand("c", 10)));
+        assert!(next.precedes(&hand("0", 20)));
+        assert!(!next.precedes(&hand("a", 20)));
+
+        assert!(HandPage::from_lookahead(vec![hand("a", 1)], 1).next.is_none());
+    }
+
     #[test]
     fn test_player_stats_win_rate() {
         let stats = PlayerStats {
@@ -254,16 +876,65 @@ mod tests {
             hands_played: 100,
             hands_won: 25,
             last_played_at: Some(1000),
+            ..PlayerStats::default()
         };
         assert!((stats.win_rate() - 25.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_player_stats_hud_ratios() {
+        let stats = PlayerStats {
+            hands_played: 40,
+            vpip_hands: 10,
+            pfr_hands: 6,
+            three_bet_opportunities: 8,
+            three_bets: 2,
+            cbet_opportunities: 4,
+            cbets: 3,
+            flops_seen: 12,
+            showdowns: 3,
+            showdowns_won: 2,
+            postflop_aggressive_actions: 9,
+            postflop_calls: 6,
+            ..PlayerStats::default()
+        };
+        assert!((stats.vpip() - 25.0).abs() < 1e-9);
+        assert!((stats.pfr() - 15.0).abs() < 1e-9);
+        assert!((stats.three_bet() - 25.0).abs() < 1e-9);
+        assert!((stats.cbet() - 75.0).abs() < 1e-9);
+        assert!((stats.wtsd() - 25.0).abs() < 1e-9);
+        assert!((stats.wsd() - 200.0 / 3.0).abs() < 1e-9);
+        assert!((stats.aggression_factor() - 1.5).abs() < 1e-9);
+
+        let empty = PlayerStats::default();
+        assert!(empty.cbet().abs() < f64::EPSILON);
+        assert!(empty.aggression_factor().abs() < f64::EPSILON);
+    }
+
     #[test]
     fn test_player_stats_win_rate_zero_hands() {
         let stats = PlayerStats::default();
         assert!((stats.win_rate() - 0.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_session_stats_tracks_money_in_and_out() {
+        let mut stats = SessionStats::new("s1".into(), "alice".to_string(), 100);
+        stats.record_buy_in(1000, 100);
+        stats.record_hand(50, 0, 200);
+        stats.record_hand(200, 500, 300);
+        stats.record_buy_in(500, 350);
+        stats.record_cash_out(5000, 400);
+
+        assert_eq!(stats.stack, 0);
+        assert_eq!(stats.cash_outs, 1750);
+        assert_eq!(stats.net_result(), 250);
+        assert_eq!((stats.hands_played, stats.hands_won), (2, 1));
+        assert_eq!(stats.last_activity_at, 400);
+        let bankroll: Vec<i64> = stats.history.iter().map(|point| point.bankroll).collect();
+        assert_eq!(bankroll, [-50, 250]);
+    }
+
     #[test]
     fn test_read_model_error_display() {
         let err = ReadModelError::NotFound("hand-123".to_string());
@@ -0,0 +1,216 @@
+//! Result cache port for reusing completed computations.
+//!
+//! Equity and solver runs are deterministic for a given request, so their results can
+//! be stored under a canonical encoding of the request and returned instantly when it
+//! is repeated, such as when a hand review is reopened.
+
+use std::error::Error;
+use std::fmt;
+
+/// Version of the cache key scheme, hashed into every key.
+///
+/// Bumped whenever a change to the key or entry encoding would make old entries
+/// unreadable, so they are missed rather than misread.
+pub const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// Error type for result cache operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResultCacheError {
+    /// A storage or I/O error occurred.
+    StorageError(String),
+}
+
+impl fmt::Display for ResultCacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StorageError(msg) => write!(f, "storage error: {msg}"),
+        }
+    }
+}
+
+impl Error for ResultCacheError {}
+
+/// Canonical encoding of a request, identifying its cached result, with a hash of the
+/// encoding for naming and indexing entries.
+///
+/// Keys are built with `CacheKey::builder`, which hashes with 64-bit FNV-1a so a key's
+/// hash is the same on every platform and in every process, unlike `std`'s `Hasher`s.
+/// Different requests can share a hash, so caches store the whole encoding with each
+/// entry and only return an entry whose encoding matches.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::ports::outbound::CacheKey;
+///
+/// let key = CacheKey::builder("equity").u64(10_000).bytes(&[1, 2]).finish();
+/// assert_eq!(key, CacheKey::builder("equity").u64(10_000).bytes(&[1, 2]).finish());
+/// assert_ne!(key, CacheKey::builder("solver").u64(10_000).bytes(&[1, 2]).finish());
+/// assert_eq!(key.to_string().len(), 16);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CacheKey {
+    hash: u64,
+    encoding: Vec<u8>,
+}
+
+/// `CacheKey` - Constructors
+impl CacheKey {
+    /// Start a key for a request of the kind `namespace`, such as `"equity"`.
+    pub fn builder(namespace: &str) -> CacheKeyBuilder {
+        CacheKeyBuilder::new().u32(CACHE_FORMAT_VERSION).bytes(namespace.as_bytes())
+    }
+}
+
+/// `CacheKey` - Accessors
+impl CacheKey {
+    /// The hash of the key's encoding.
+    #[must_use]
+    pub const fn value(&self) -> u64 {
+        self.hash
+    }
+
+    /// The canonical encoding of the request.
+    #[must_use]
+    pub fn encoding(&self) -> &[u8] {
+        &self.encoding
+    }
+}
+
+impl fmt::Display for CacheKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.hash)
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Builds a `CacheKey` from the fields of a request, in order.
+///
+/// Variable-length fields are prefixed with their length, so `bytes(b"ab")` followed
+/// by `bytes(b"c")` differs from `bytes(b"a")` followed by `bytes(b"bc")`.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CacheKeyBuilder {
+    hash: u64,
+    encoding: Vec<u8>,
+}
+
+/// `CacheKeyBuilder` - Constructors
+impl CacheKeyBuilder {
+    /// A builder with nothing encoded yet.
+    const fn new() -> Self {
+        Self {
+            hash: FNV_OFFSET,
+            encoding: Vec::new(),
+        }
+    }
+}
+
+/// `CacheKeyBuilder` - Operations
+impl CacheKeyBuilder {
+    /// Hash `bytes`, prefixed with their length.
+    pub fn bytes(self, bytes: &[u8]) -> Self {
+        self.u64(bytes.len() as u64).raw(bytes)
+    }
+
+    /// Hash a `u32`.
+    pub fn u32(self, value: u32) -> Self {
+        self.raw(&value.to_le_bytes())
+    }
+
+    /// Hash a `u64`.
+    pub fn u64(self, value: u64) -> Self {
+        self.raw(&value.to_le_bytes())
+    }
+
+    /// Hash an `f64` by its bits, so `0.1` always hashes the same way.
+    pub fn f64(self, value: f64) -> Self {
+        self.u64(value.to_bits())
+    }
+
+    /// Hash a `bool`.
+    pub fn bool(self, value: bool) -> Self {
+        self.raw(&[u8::from(value)])
+    }
+
+    /// The finished key.
+    #[must_use]
+    pub fn finish(self) -> CacheKey {
+        CacheKey {
+            hash: self.hash,
+            encoding: self.encoding,
+        }
+    }
+
+    fn raw(mut self, bytes: &[u8]) -> Self {
+        self.hash = bytes
+            .iter()
+            .fold(self.hash, |hash, &byte| (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME));
+        self.encoding.extend_from_slice(bytes);
+        self
+    }
+}
+
+/// Port for storing the encoded results of completed computations by key.
+///
+/// Caches are free to evict any entry at any time, so a miss only means the result
+/// has to be computed again. Callers encode and decode results themselves; a cache
+/// stores bytes.
+pub trait ResultCache: Send + Sync {
+    /// The entry stored under `key`, if it is still cached.
+    ///
+    /// # Errors
+    /// Returns `ResultCacheError::StorageError` on I/O errors.
+    fn get(&self, key: &CacheKey) -> Result<Option<Vec<u8>>, ResultCacheError>;
+
+    /// Store `value` under `key`, replacing any entry already there and evicting
+    /// others if the cache is full.
+    ///
+    /// # Errors
+    /// Returns `ResultCacheError::StorageError` on I/O errors.
+    fn put(&self, key: &CacheKey, value: &[u8]) -> Result<(), ResultCacheError>;
+
+    /// Remove every entry.
+    ///
+    /// # Errors
+    /// Returns `ResultCacheError::StorageError` on I/O errors.
+    fn clear(&self) -> Result<(), ResultCacheError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_is_stable() {
+        // FNV-1a of nothing is its offset basis; keys must never drift between releases
+        assert_eq!(CacheKeyBuilder::new().finish().value(), FNV_OFFSET);
+        let key = CacheKeyBuilder::new().raw(b"a").finish();
+        assert_eq!(key.value(), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(key.encoding(), b"a");
+    }
+
+    #[test]
+    fn test_keys_sharing_a_hash_differ() {
+        let key = CacheKey::builder("test").u64(1).finish();
+        let collision = CacheKey {
+            hash: key.value(),
+            encoding: b"another request".to_vec(),
+        };
+        assert_ne!(key, collision);
+    }
+
+    #[test]
+    fn test_fields_are_delimited() {
+        let split = |first: &[u8], second: &[u8]| {
+            CacheKey::builder("test").bytes(first).bytes(second).finish()
+        };
+        assert_ne!(split(b"ab", b"c"), split(b"a", b"bc"));
+        assert_ne!(
+            CacheKey::builder("test").f64(0.0).finish(),
+            CacheKey::builder("test").f64(-0.0).finish()
+        );
+    }
+}
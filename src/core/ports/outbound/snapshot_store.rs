@@ -142,16 +142,16 @@ mod tests {
 
     #[test]
     fn test_snapshot_creation() {
-        let snapshot = Snapshot::new("test_state", 10, 1000, "game-1".to_string());
+        let snapshot = Snapshot::new("test_state", 10, 1000, "game-1".into());
         assert_eq!(snapshot.state, "test_state");
         assert_eq!(snapshot.version, 10);
         assert_eq!(snapshot.timestamp, 1000);
-        assert_eq!(snapshot.game_id, "game-1");
+        assert_eq!(snapshot.game_id, GameId::from("game-1"));
     }
 
     #[test]
     fn test_snapshot_error_display() {
-        let err = SnapshotError::NotFound("game-123".to_string());
+        let err = SnapshotError::NotFound("game-123".into());
         assert_eq!(err.to_string(), "snapshot not found: game-123");
 
         let err = SnapshotError::StorageError("disk full".to_string());
@@ -0,0 +1,446 @@
+//! C ABI for embedding the engine in other languages, built with the `ffi` feature.
+//!
+//! Cards are passed as `u8` indices, `rank * 4 + suit`, with ranks from 0 (two) to
+//! 12 (ace) and suits 0 = clubs, 1 = diamonds, 2 = hearts, 3 = spades; so `0` is the
+//! two of clubs and `51` the ace of spades. Every function writes its result through an
+//! out pointer and returns a `RiverrunStatus`, leaving the output untouched on failure.
+//!
+//! The matching header is `include/riverrun.h`, generated by cbindgen:
+//!
+//! ```text
+//! cbindgen --config cbindgen.toml --output include/riverrun.h
+//! ```
+//!
+//! ```text
+//! uint8_t cards[7] = {51, 47, 43, 39, 35, 0, 5};  // As Ks Qs Js Ts 2c 3d
+//! RiverrunHand hand;
+//! if (riverrun_evaluate(cards, 7, &hand) == RIVERRUN_STATUS_OK) {
+//!     printf("%s\n", riverrun_hand_rank_name(hand.rank));  // Straight Flush
+//! }
+//! ```
+
+use std::ffi::{CStr, c_char};
+use std::slice;
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::Card;
+use crate::core::domain::entities::card_set::CardSet;
+use crate::core::domain::entities::hand::Hand;
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::services::utils::card_combinations;
+use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver, SmartEquityCalculator};
+use crate::core::ports::inbound::{EquityCalculator, HandEvaluator, HandSolver, MAX_PLAYERS};
+
+/// Most hands `riverrun_showdown` compares.
+pub const RIVERRUN_MAX_PLAYERS: usize = MAX_PLAYERS;
+
+/// Names of the hand categories, indexed by `RiverrunHand::rank`.
+const RANK_NAMES: [&CStr; 9] = [
+    c"High Card",
+    c"One Pair",
+    c"Two Pair",
+    c"Three of a Kind",
+    c"Straight",
+    c"Flush",
+    c"Full House",
+    c"Four of a Kind",
+    c"Straight Flush",
+];
+
+/// Outcome of an FFI call.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RiverrunStatus {
+    /// The call succeeded and its output was written.
+    Ok = 0,
+    /// A required pointer was null.
+    NullPointer = 1,
+    /// A card index was 52 or more.
+    InvalidCard = 2,
+    /// The same card was passed twice.
+    DuplicateCard = 3,
+    /// Too few or too many cards or players were passed.
+    InvalidCount = 4,
+    /// The equity calculation failed, e.g. too many opponents for the cards left.
+    EquityFailed = 5,
+}
+
+/// Best five-card hand, written by `riverrun_evaluate`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RiverrunHand {
+    /// Strength from 1 (royal flush) to 7462 (seven high); lower is stronger.
+    pub strength: u16,
+    /// Hand category from 0 (high card) to 8 (straight flush); see
+    /// `riverrun_hand_rank_name`.
+    pub rank: u8,
+    /// Indices of the five cards making the hand.
+    pub best_five: [u8; 5],
+}
+
+/// Equity of a hand, written by `riverrun_equity`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RiverrunEquity {
+    /// Share of the pot won on average, from 0 to 1, counting ties as split pots.
+    pub equity: f64,
+    /// Fraction of runouts won outright.
+    pub win: f64,
+    /// Fraction of runouts tied.
+    pub tie: f64,
+    /// Fraction of runouts lost.
+    pub loss: f64,
+    /// Runouts evaluated, enumerated or sampled.
+    pub samples: u64,
+}
+
+/// Winners of a showdown, written by `riverrun_showdown`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RiverrunShowdown {
+    /// Indices of the winning players; only the first `num_winners` are set.
+    pub winners: [u8; RIVERRUN_MAX_PLAYERS],
+    /// Number of winners; more than one for a split pot.
+    pub num_winners: u8,
+    /// Strength of each player's best hand; only the first `num_players` are set.
+    pub strengths: [u16; RIVERRUN_MAX_PLAYERS],
+}
+
+/// Evaluates the best five-card hand among `len` cards, five to seven.
+///
+/// # Safety
+///
+/// `cards` must point to `len` readable bytes and `out` to a writable `RiverrunHand`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn riverrun_evaluate(
+    cards: *const u8,
+    len: usize,
+    out: *mut RiverrunHand,
+) -> RiverrunStatus {
+    if !(5..=7).contains(&len) {
+        return RiverrunStatus::InvalidCount;
+    }
+    // SAFETY: the caller guarantees `cards` holds `len` bytes
+    let cards = match unsafe { read_cards(cards, len) } {
+        Ok(cards) => cards,
+        Err(status) => return status,
+    };
+    let evaluator = CactusKevEvaluator::new();
+    let hand = card_combinations(&cards, 5)
+        .filter_map(|five| five.try_into().ok())
+        .map(|five| evaluator.evaluate_5cards(five))
+        .min_by_key(Hand::strength);
+    let Some(hand) = hand else {
+        return RiverrunStatus::InvalidCount;
+    };
+    // SAFETY: the caller guarantees `out` is writable
+    unsafe {
+        write(
+            out,
+            RiverrunHand {
+                strength: hand.strength(),
+                rank: hand.rank() as u8,
+                best_five: hand.cards().map(card_index),
+            },
+        )
+    }
+}
+
+/// Equity of the two `hole_cards` on a board of `board_len` cards (0, 3, 4 or 5)
+/// against `opponents` random hands.
+///
+/// Small calculations are enumerated exactly; larger ones draw `samples` Monte Carlo
+/// samples, or the default number when `samples` is 0.
+///
+/// # Safety
+///
+/// `hole_cards` must point to 2 readable bytes, `board` to `board_len` readable bytes
+/// (or be null when `board_len` is 0), and `out` to a writable `RiverrunEquity`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn riverrun_equity(
+    hole_cards: *const u8,
+    board: *const u8,
+    board_len: usize,
+    opponents: usize,
+    samples: u32,
+    out: *mut RiverrunEquity,
+) -> RiverrunStatus {
+    if board_len > 5 {
+        return RiverrunStatus::InvalidCount;
+    }
+    // SAFETY: the caller guarantees `hole_cards` holds 2 bytes and `board` `board_len`
+    let cards = match unsafe { read_cards_pair(hole_cards, 2, board, board_len) } {
+        Ok(cards) => cards,
+        Err(status) => return status,
+    };
+    let hole_cards = HoleCards::new(cards[0], cards[1]);
+    let Ok(board) = Board::try_with_cards(cards[2..].to_vec()) else {
+        return RiverrunStatus::InvalidCount;
+    };
+
+    let calculator = SmartEquityCalculator::new(CactusKevEvaluator::new());
+    let calculator = if samples == 0 {
+        calculator
+    } else {
+        calculator.with_samples(samples)
+    };
+    let Ok(result) = calculator.calculate(&hole_cards, &board, opponents) else {
+        return RiverrunStatus::EquityFailed;
+    };
+    // SAFETY: the caller guarantees `out` is writable
+    unsafe {
+        write(
+            out,
+            RiverrunEquity {
+                equity: result.equity(),
+                win: result.win_rate(),
+                tie: result.tie_rate(),
+                loss: result.lose_rate(),
+                samples: result.samples(),
+            },
+        )
+    }
+}
+
+/// Winner(s) among `num_players` hands on a complete board.
+///
+/// `hole_cards` holds two cards per player, the first player's two first.
+///
+/// # Safety
+///
+/// `hole_cards` must point to `2 * num_players` readable bytes, `board` to 5 readable
+/// bytes, and `out` to a writable `RiverrunShowdown`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn riverrun_showdown(
+    hole_cards: *const u8,
+    num_players: usize,
+    board: *const u8,
+    out: *mut RiverrunShowdown,
+) -> RiverrunStatus {
+    if num_players == 0 || num_players > RIVERRUN_MAX_PLAYERS {
+        return RiverrunStatus::InvalidCount;
+    }
+    // SAFETY: the caller guarantees `hole_cards` holds `2 * num_players` bytes and
+    // `board` 5
+    let cards = match unsafe { read_cards_pair(hole_cards, 2 * num_players, board, 5) } {
+        Ok(cards) => cards,
+        Err(status) => return status,
+    };
+    let (hole_cards, board_cards) = cards.split_at(2 * num_players);
+    let players: Vec<HoleCards> = hole_cards
+        .chunks_exact(2)
+        .map(|pair| HoleCards::new(pair[0], pair[1]))
+        .collect();
+    let Ok(board) = Board::try_with_cards(board_cards.to_vec()) else {
+        return RiverrunStatus::InvalidCount;
+    };
+
+    let result = ShowdownSolver::new(CactusKevEvaluator::new()).solve_with_hands(&players, &board);
+    let mut showdown = RiverrunShowdown {
+        winners: [0; RIVERRUN_MAX_PLAYERS],
+        num_winners: 0,
+        strengths: [0; RIVERRUN_MAX_PLAYERS],
+    };
+    for (slot, &winner) in showdown.winners.iter_mut().zip(result.winner_indices()) {
+        *slot = u8::try_from(winner).unwrap_or(u8::MAX);
+        showdown.num_winners += 1;
+    }
+    for (slot, hand) in showdown.strengths.iter_mut().zip(&result.hands) {
+        *slot = hand.strength();
+    }
+    // SAFETY: the caller guarantees `out` is writable
+    unsafe { write(out, showdown) }
+}
+
+/// Name of hand category `rank` as a static NUL-terminated string, e.g.
+/// `"Full House"`, or null if `rank` is above 8.
+#[unsafe(no_mangle)]
+pub extern "C" fn riverrun_hand_rank_name(rank: u8) -> *const c_char {
+    RANK_NAMES
+        .get(usize::from(rank))
+        .map_or(std::ptr::null(), |name| name.as_ptr())
+}
+
+/// Index of `card`, `rank * 4 + suit`.
+#[allow(clippy::cast_possible_truncation)]
+const fn card_index(card: Card) -> u8 {
+    card.index() as u8
+}
+
+/// Reads `len` card indices from `ptr`, rejecting invalid and repeated cards.
+///
+/// # Safety
+///
+/// `ptr` must point to `len` readable bytes, or may be null when `len` is 0.
+unsafe fn read_cards(ptr: *const u8, len: usize) -> Result<Vec<Card>, RiverrunStatus> {
+    // SAFETY: forwarded to the caller
+    unsafe { read_cards_pair(ptr, len, std::ptr::null(), 0) }
+}
+
+/// Reads `first_len` card indices from `first` followed by `second_len` from `second`,
+/// rejecting invalid cards and cards repeated within or across the two.
+///
+/// # Safety
+///
+/// Each pointer must point to its length in readable bytes, or may be null when its
+/// length is 0.
+unsafe fn read_cards_pair(
+    first: *const u8,
+    first_len: usize,
+    second: *const u8,
+    second_len: usize,
+) -> Result<Vec<Card>, RiverrunStatus> {
+    let mut cards = Vec::with_capacity(first_len + second_len);
+    let mut seen = CardSet::empty();
+    for (ptr, len) in [(first, first_len), (second, second_len)] {
+        if len == 0 {
+            continue;
+        }
+        if ptr.is_null() {
+            return Err(RiverrunStatus::NullPointer);
+        }
+        // SAFETY: the caller guarantees `ptr` holds `len` readable bytes
+        let indices = unsafe { slice::from_raw_parts(ptr, len) };
+        for &index in indices {
+            let card = Card::from_index(usize::from(index)).ok_or(RiverrunStatus::InvalidCard)?;
+            if !seen.insert(card) {
+                return Err(RiverrunStatus::DuplicateCard);
+            }
+            cards.push(card);
+        }
+    }
+    Ok(cards)
+}
+
+/// Writes `value` to `out`.
+///
+/// # Safety
+///
+/// `out` must be null or point to a writable `T`.
+unsafe fn write<T>(out: *mut T, value: T) -> RiverrunStatus {
+    if out.is_null() {
+        return RiverrunStatus::NullPointer;
+    }
+    // SAFETY: `out` is non-null and the caller guarantees it is writable
+    unsafe { out.write(value) };
+    RiverrunStatus::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::hand::HandRank;
+
+    fn indices(text: &str) -> Vec<u8> {
+        text.split(' ')
+            .map(|card| card_index(card.parse().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let cards = indices("As Ks Qs Js Ts 2c 3d");
+        assert_eq!(cards[..2], [51, 47]);
+        let mut hand = RiverrunHand {
+            strength: 0,
+            rank: 0,
+            best_five: [0; 5],
+        };
+        let status = unsafe { riverrun_evaluate(cards.as_ptr(), cards.len(), &raw mut hand) };
+        assert_eq!(status, RiverrunStatus::Ok);
+        assert_eq!(hand.strength, 1);
+        assert_eq!(hand.rank, HandRank::StraightFlush as u8);
+        let mut best_five = hand.best_five;
+        best_five.sort_unstable();
+        assert_eq!(best_five, [35, 39, 43, 47, 51]);
+
+        let status = unsafe { riverrun_evaluate(cards.as_ptr(), 4, &raw mut hand) };
+        assert_eq!(status, RiverrunStatus::InvalidCount);
+        let status = unsafe { riverrun_evaluate(cards.as_ptr(), 5, std::ptr::null_mut()) };
+        assert_eq!(status, RiverrunStatus::NullPointer);
+    }
+
+    #[test]
+    fn test_invalid_cards() {
+        let mut hand = RiverrunHand {
+            strength: 0,
+            rank: 0,
+            best_five: [0; 5],
+        };
+        let cards = [0, 1, 2, 3, 52];
+        let status = unsafe { riverrun_evaluate(cards.as_ptr(), 5, &raw mut hand) };
+        assert_eq!(status, RiverrunStatus::InvalidCard);
+        let cards = [0, 1, 2, 3, 3];
+        let status = unsafe { riverrun_evaluate(cards.as_ptr(), 5, &raw mut hand) };
+        assert_eq!(status, RiverrunStatus::DuplicateCard);
+        assert_eq!(hand.strength, 0);
+    }
+
+    #[test]
+    fn test_equity() {
+        let hole_cards = indices("As Ah");
+        let board = indices("Ad Kc 7h 2s");
+        let mut equity = RiverrunEquity {
+            equity: 0.0,
+            win: 0.0,
+            tie: 0.0,
+            loss: 0.0,
+            samples: 0,
+        };
+        let status = unsafe {
+            riverrun_equity(
+                hole_cards.as_ptr(),
+                board.as_ptr(),
+                board.len(),
+                1,
+                0,
+                &raw mut equity,
+            )
+        };
+        assert_eq!(status, RiverrunStatus::Ok);
+        assert!(equity.equity > 0.95);
+        assert_eq!(equity.samples, 46 * 990);
+
+        let status = unsafe {
+            riverrun_equity(
+                hole_cards.as_ptr(),
+                hole_cards.as_ptr(),
+                2,
+                1,
+                0,
+                &raw mut equity,
+            )
+        };
+        assert_eq!(status, RiverrunStatus::DuplicateCard);
+    }
+
+    #[test]
+    fn test_showdown() {
+        let hole_cards = indices("As Ks Qh Qd 7c 7d");
+        let board = indices("Qs Js 2h 7h 3d");
+        let mut showdown = RiverrunShowdown {
+            winners: [0; RIVERRUN_MAX_PLAYERS],
+            num_winners: 0,
+            strengths: [0; RIVERRUN_MAX_PLAYERS],
+        };
+        let status =
+            unsafe { riverrun_showdown(hole_cards.as_ptr(), 3, board.as_ptr(), &raw mut showdown) };
+        assert_eq!(status, RiverrunStatus::Ok);
+        assert_eq!(showdown.num_winners, 1);
+        assert_eq!(showdown.winners[0], 1);
+        assert!(showdown.strengths[1] < showdown.strengths[2]);
+
+        let status =
+            unsafe { riverrun_showdown(hole_cards.as_ptr(), 0, board.as_ptr(), &raw mut showdown) };
+        assert_eq!(status, RiverrunStatus::InvalidCount);
+    }
+
+    #[test]
+    fn test_hand_rank_names() {
+        for rank in HandRank::ALL {
+            let name = unsafe { CStr::from_ptr(riverrun_hand_rank_name(rank as u8)) };
+            assert_eq!(name.to_str().unwrap(), rank.name());
+        }
+        assert!(riverrun_hand_rank_name(9).is_null());
+    }
+}
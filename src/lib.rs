@@ -1,5 +1,22 @@
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
 pub mod core;
 
 // Adapters
-// pub mod cli;
-// pub mod server;
+pub mod adapters;
+#[cfg(feature = "cli")]
+pub mod cli;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "server")]
+pub mod server;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+// Benchmark and test support
+pub mod bench_utils;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+
+// Instrumentation
+mod telemetry;
@@ -0,0 +1,18 @@
+//! `riverrun` command line tool; see `riverrun::cli`.
+
+use std::process::ExitCode;
+
+use riverrun::cli;
+
+fn main() -> ExitCode {
+    match cli::run(std::env::args().skip(1)) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{}", cli::USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,365 @@
+//! JSON request and response types, and the routes serving them.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::{ServerLimits, Services};
+use super::http::{Request, Response};
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::{Card, parse_cards};
+use crate::core::domain::entities::card_set::CardSet;
+use crate::core::domain::entities::hand::Hand;
+use crate::core::domain::entities::hole_cards::{HoleCards, validate_no_duplicates};
+use crate::core::domain::services::utils::card_combinations;
+use crate::core::ports::inbound::{EquityCalculator, HandEvaluator, HandSolver, MAX_PLAYERS};
+
+/// Body of `POST /v1/evaluate`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvaluateRequest {
+    /// Five to seven cards, e.g. `"As Ks Qs Js Ts 2h 3d"`.
+    pub cards: String,
+}
+
+/// A five-card hand in a response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HandResponse {
+    /// Hand category, e.g. `"Two Pair"`.
+    pub rank: String,
+    /// Full description, e.g. `"Two Pair, Aces and Kings, Queen kicker"`.
+    pub description: String,
+    /// The five cards making the hand.
+    pub best_five: Vec<String>,
+    /// Strength from 1 (royal flush) to 7462 (seven high); lower is stronger.
+    pub strength: u16,
+    /// Fraction of hand strengths this hand beats or ties, from 0 to 1.
+    pub percentile: f64,
+}
+
+impl From<&Hand> for HandResponse {
+    fn from(hand: &Hand) -> Self {
+        Self {
+            rank: hand.rank().name().to_string(),
+            description: hand.describe(),
+            best_five: hand.cards().iter().map(ToString::to_string).collect(),
+            strength: hand.strength(),
+            percentile: hand.percentile(),
+        }
+    }
+}
+
+/// Body of `POST /v1/equity`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EquityRequest {
+    /// Hole cards, e.g. `"AsKs"`.
+    pub hand: String,
+    /// Board cards, e.g. `"Qs Js 2h"`; empty preflop.
+    #[serde(default)]
+    pub board: String,
+    /// Number of random opponents, at most `ServerLimits::max_opponents`.
+    #[serde(default = "one")]
+    pub opponents: usize,
+    /// Monte Carlo samples, at most `ServerLimits::max_samples`; the calculator's
+    /// default when omitted.
+    #[serde(default)]
+    pub samples: Option<u32>,
+}
+
+/// Response of `POST /v1/equity`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquityResponse {
+    /// Share of the pot won on average, from 0 to 1, counting ties as split pots.
+    pub equity: f64,
+    /// Fraction of runouts won outright.
+    pub win: f64,
+    /// Fraction of runouts tied.
+    pub tie: f64,
+    /// Fraction of runouts lost.
+    pub loss: f64,
+    /// Runouts evaluated, enumerated or sampled.
+    pub samples: u64,
+}
+
+/// Body of `POST /v1/showdown`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShowdownRequest {
+    /// Each player's hole cards, e.g. `["AsKs", "QhQd"]`.
+    pub hands: Vec<String>,
+    /// The complete five-card board.
+    pub board: String,
+}
+
+/// Response of `POST /v1/showdown`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShowdownResponse {
+    /// Indices of the winning hands; more than one for a split pot.
+    pub winners: Vec<usize>,
+    /// Each player's best hand, in request order.
+    pub hands: Vec<HandResponse>,
+}
+
+/// Body of every error response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    /// What went wrong.
+    pub error: String,
+}
+
+/// Default number of opponents.
+const fn one() -> usize {
+    1
+}
+
+/// Routes `request` to its endpoint.
+pub(super) fn handle<E, C, S>(
+    services: &Services<E, C, S>,
+    limits: &ServerLimits,
+    request: &Request,
+) -> Response
+where
+    E: HandEvaluator,
+    C: EquityCalculator,
+    S: HandSolver,
+{
+    let result = match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/health") => Ok(ok(&serde_json::json!({ "status": "ok" }))),
+        ("POST", "/v1/evaluate") => parse(&request.body).and_then(|body| evaluate(services, &body)),
+        ("POST", "/v1/equity") => {
+            parse(&request.body).and_then(|body| equity(services, limits, &body))
+        }
+        ("POST", "/v1/showdown") => parse(&request.body).and_then(|body| showdown(services, &body)),
+        (_, "/health" | "/v1/evaluate" | "/v1/equity" | "/v1/showdown") => {
+            Err(error("405 Method Not Allowed", "method not allowed"))
+        }
+        _ => Err(error("404 Not Found", "not found")),
+    };
+    result.unwrap_or_else(|response| response)
+}
+
+/// `200 OK` with `body` as JSON.
+pub(super) fn ok(body: &impl Serialize) -> Response {
+    Response {
+        status: "200 OK",
+        body: serde_json::to_string(body).unwrap_or_default(),
+    }
+}
+
+/// An error response with `status`, e.g. `400 Bad Request`.
+pub(super) fn error(status: &'static str, message: impl Into<String>) -> Response {
+    Response {
+        status,
+        body: serde_json::to_string(&ErrorResponse {
+            error: message.into(),
+        })
+        .unwrap_or_default(),
+    }
+}
+
+/// `400 Bad Request` describing `message`.
+#[allow(clippy::needless_pass_by_value)] // taken by value to be usable with `map_err`
+fn bad_request(message: impl ToString) -> Response {
+    error("400 Bad Request", message.to_string())
+}
+
+/// Decodes a JSON request body.
+fn parse<T: DeserializeOwned>(body: &[u8]) -> Result<T, Response> {
+    serde_json::from_slice(body).map_err(bad_request)
+}
+
+fn evaluate<E, C, S>(
+    services: &Services<E, C, S>,
+    request: &EvaluateRequest,
+) -> Result<Response, Response>
+where
+    E: HandEvaluator,
+{
+    let cards = parse_cards(&request.cards).map_err(bad_request)?;
+    if !(5..=7).contains(&cards.len()) {
+        return Err(bad_request("cards must hold five to seven cards"));
+    }
+    check_distinct(&cards)?;
+    let hand = match <[Card; 7]>::try_from(cards.as_slice()) {
+        Ok(seven) => services.evaluator.evaluate_7cards(seven),
+        Err(_) => card_combinations(&cards, 5)
+            .filter_map(|five| five.try_into().ok())
+            .map(|five| services.evaluator.evaluate_5cards(five))
+            .min_by_key(Hand::strength)
+            .ok_or_else(|| bad_request("cards must hold five to seven cards"))?,
+    };
+    Ok(ok(&HandResponse::from(&hand)))
+}
+
+fn equity<E, C, S>(
+    services: &Services<E, C, S>,
+    limits: &ServerLimits,
+    request: &EquityRequest,
+) -> Result<Response, Response>
+where
+    C: EquityCalculator,
+{
+    if request.opponents > limits.max_opponents() {
+        return Err(bad_request(format!(
+            "opponents must be at most {}",
+            limits.max_opponents()
+        )));
+    }
+    if request.samples.is_some_and(|samples| samples > limits.max_samples()) {
+        return Err(bad_request(format!(
+            "samples must be at most {}",
+            limits.max_samples()
+        )));
+    }
+    let hole_cards: HoleCards = request.hand.parse().map_err(bad_request)?;
+    let board: Board = request.board.parse().map_err(bad_request)?;
+    let opponents = request.opponents;
+    let result = request
+        .samples
+        .map_or_else(
+            || services.equity.calculate(&hole_cards, &board, opponents),
+            |samples| {
+                services
+                    .equity
+                    .calculate_sampled(&hole_cards, &board, opponents, samples)
+            },
+        )
+        .map_err(bad_request)?;
+    Ok(ok(&EquityResponse {
+        equity: result.equity(),
+        win: result.win_rate(),
+        tie: result.tie_rate(),
+        loss: result.lose_rate(),
+        samples: result.samples(),
+    }))
+}
+
+fn showdown<E, C, S>(
+    services: &Services<E, C, S>,
+    request: &ShowdownRequest,
+) -> Result<Response, Response>
+where
+    S: HandSolver,
+{
+    let players = request
+        .hands
+        .iter()
+        .map(|hand| hand.parse())
+        .collect::<Result<Vec<HoleCards>, _>>()
+        .map_err(bad_request)?;
+    if players.is_empty() || players.len() > MAX_PLAYERS {
+        return Err(bad_request(format!(
+            "hands must hold 1 to {MAX_PLAYERS} hands"
+        )));
+    }
+    let board: Board = request.board.parse().map_err(bad_request)?;
+    if board.len() != 5 {
+        return Err(bad_request("board must hold five cards"));
+    }
+    validate_no_duplicates(&players, &board).map_err(bad_request)?;
+    let result = services.solver.solve_with_hands(&players, &board);
+    Ok(ok(&ShowdownResponse {
+        winners: result.winner_indices().to_vec(),
+        hands: result.hands.iter().map(HandResponse::from).collect(),
+    }))
+}
+
+/// Rejects `cards` if any card appears twice.
+fn check_distinct(cards: &[Card]) -> Result<(), Response> {
+    let mut seen = CardSet::empty();
+    if let Some(card) = cards.iter().find(|&&card| !seen.insert(card)) {
+        return Err(bad_request(format!("card {card} appears twice")));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(path: &str, body: &str) -> Response {
+        let request = Request {
+            method: "POST".to_string(),
+            path: path.to_string(),
+            query: String::new(),
+            body: body.as_bytes().to_vec(),
+        };
+        handle(&Services::standard(), &ServerLimits::default(), &request)
+    }
+
+    fn body<T: DeserializeOwned>(response: &Response) -> T {
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let response = post("/v1/evaluate", r#"{"cards": "As Ks Qs Js Ts 2h 3d"}"#);
+        assert_eq!(response.status, "200 OK");
+        let hand: HandResponse = body(&response);
+        assert_eq!(hand.strength, 1);
+        assert_eq!(hand.description, "Royal Flush");
+
+        let response = post("/v1/evaluate", r#"{"cards": "As Ah Kd Kc Qs 2h"}"#);
+        assert_eq!(body::<HandResponse>(&response).rank, "Two Pair");
+
+        let response = post("/v1/evaluate", r#"{"cards": "As As Kd Kc Qs"}"#);
+        assert_eq!(response.status, "400 Bad Request");
+        assert_eq!(
+            body::<ErrorResponse>(&response).error,
+            "card As appears twice"
+        );
+    }
+
+    #[test]
+    fn test_equity() {
+        let response = post("/v1/equity", r#"{"hand": "AsAh", "board": "Ad Kc 7h 2s"}"#);
+        assert_eq!(response.status, "200 OK");
+        let equity: EquityResponse = body(&response);
+        assert!(equity.equity > 0.95);
+        assert_eq!(equity.samples, 46 * 990);
+
+        let response = post("/v1/equity", r#"{"hand": "AsAh", "board": "As"}"#);
+        assert_eq!(response.status, "400 Bad Request");
+    }
+
+    #[test]
+    fn test_equity_limits() {
+        let response = post("/v1/equity", r#"{"hand": "AsAh", "samples": 4294967295}"#);
+        assert_eq!(response.status, "400 Bad Request");
+        assert_eq!(
+            body::<ErrorResponse>(&response).error,
+            "samples must be at most 1000000"
+        );
+
+        let response = post("/v1/equity", r#"{"hand": "AsAh", "opponents": 1000}"#);
+        assert_eq!(
+            body::<ErrorResponse>(&response).error,
+            "opponents must be at most 9"
+        );
+    }
+
+    #[test]
+    fn test_showdown() {
+        let response = post(
+            "/v1/showdown",
+            r#"{"hands": ["AsKs", "QhQd"], "board": "Qs Js 2h 7c 3d"}"#,
+        );
+        assert_eq!(response.status, "200 OK");
+        let showdown: ShowdownResponse = body(&response);
+        assert_eq!(showdown.winners, vec![1]);
+        assert_eq!(showdown.hands[1].rank, "Three of a Kind");
+
+        let response = post(
+            "/v1/showdown",
+            r#"{"hands": ["AsKs"], "board": "Qs Js 2h"}"#,
+        );
+        assert_eq!(
+            body::<ErrorResponse>(&response).error,
+            "board must hold five cards"
+        );
+    }
+
+    #[test]
+    fn test_routing_errors() {
+        assert_eq!(post("/v1/nope", "{}").status, "404 Not Found");
+        assert_eq!(post("/health", "").status, "405 Method Not Allowed");
+        assert_eq!(post("/v1/evaluate", "not json").status, "400 Bad Request");
+    }
+}
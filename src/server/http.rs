@@ -0,0 +1,223 @@
+//! Just enough HTTP/1.1 to read one request and write one response per connection.
+
+use std::io::{self, BufRead, BufReader, Read};
+
+/// Upper bound on the size of the request line and headers.
+const MAX_HEAD_BYTES: usize = 8 * 1024;
+
+/// Upper bound on the size of a request body.
+pub(super) const MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// An HTTP request.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Request {
+    /// Request method, e.g. `POST`.
+    pub method: String,
+    /// Request path, without any query string.
+    pub path: String,
+    /// Query string after the `?`, still percent-encoded.
+    pub query: String,
+    /// Request body, empty without a `Content-Length` header.
+    pub body: Vec<u8>,
+}
+
+/// Why a request could not be read.
+#[derive(Debug)]
+pub(super) enum RequestError {
+    /// The request is not valid HTTP.
+    Malformed(&'static str),
+    /// The body is larger than `MAX_BODY_BYTES`.
+    TooLarge,
+    /// The connection failed or timed out.
+    Io(io::Error),
+}
+
+impl From<io::Error> for RequestError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Reads a request from `stream`.
+///
+/// # Errors
+/// Returns `RequestError` if the request is malformed, too large, or cannot be read.
+pub(super) fn read_request(stream: impl Read) -> Result<Request, RequestError> {
+    let mut reader = BufReader::new(stream);
+    let mut head = (&mut reader).take(MAX_HEAD_BYTES as u64);
+
+    let mut request_line = String::new();
+    head.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts
+        .next()
+        .ok_or(RequestError::Malformed("missing method"))?
+        .to_string();
+    let target = parts
+        .next()
+        .ok_or(RequestError::Malformed("missing request target"))?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let (path, query) = (path.to_string(), query.to_string());
+
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if head.read_line(&mut line)? == 0 {
+            return Err(RequestError::Malformed("request ended before its headers"));
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = value
+                .trim()
+                .parse()
+                .map_err(|_| RequestError::Malformed("invalid Content-Length"))?;
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Err(RequestError::TooLarge);
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(Request {
+        method,
+        path,
+        query,
+        body,
+    })
+}
+
+/// `Request` - Accessors
+impl Request {
+    /// Decoded value of the first `name` query parameter, if present.
+    ///
+    /// # Errors
+    /// Returns the parameter name if its value is not valid percent-encoded UTF-8.
+    pub fn param(&self, name: &str) -> Result<Option<String>, String> {
+        self.query
+            .split('&')
+            .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+            .find(|&(key, _)| key == name)
+            .map(|(_, value)| decode(value).ok_or_else(|| name.to_string()))
+            .transpose()
+    }
+}
+
+/// Decodes a percent-encoded path segment or query value, treating `+` as a space.
+pub(super) fn decode(encoded: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut rest = encoded.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = std::str::from_utf8(rest.get(..2)?).ok()?;
+                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+                rest = &rest[2..];
+            }
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// An HTTP response with a JSON body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Response {
+    /// Status line after the version, e.g. `200 OK`.
+    pub status: &'static str,
+    /// JSON body.
+    pub body: String,
+}
+
+/// `Response` - Operations
+impl Response {
+    /// The response as bytes to write, closing the connection afterwards.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        format!(
+            "HTTP/1.1 {}\r\n\
+             Content-Type: application/json\r\n\
+             Content-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            self.status,
+            self.body.len(),
+            self.body
+        )
+        .into_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_request() {
+        let raw = "POST /v1/evaluate?pretty HTTP/1.1\r\nHost: x\r\ncontent-length: 4\r\n\r\nbody";
+        let request = read_request(raw.as_bytes()).unwrap();
+        assert_eq!(
+            request,
+            Request {
+                method: "POST".to_string(),
+                path: "/v1/evaluate".to_string(),
+                query: "pretty".to_string(),
+                body: b"body".to_vec(),
+            }
+        );
+
+        let raw = "GET /health HTTP/1.1\r\n\r\n";
+        assert!(read_request(raw.as_bytes()).unwrap().body.is_empty());
+    }
+
+    #[test]
+    fn test_query_params() {
+        let raw = "GET /hands?player=J%C3%BCrgen+K&limit=5&ties HTTP/1.1\r\n\r\n";
+        let request = read_request(raw.as_bytes()).unwrap();
+        assert_eq!(request.param("player"), Ok(Some("Jürgen K".to_string())));
+        assert_eq!(request.param("limit"), Ok(Some("5".to_string())));
+        assert_eq!(request.param("ties"), Ok(Some(String::new())));
+        assert_eq!(request.param("cursor"), Ok(None));
+
+        assert_eq!(decode("100%25"), Some("100%".to_string()));
+        assert_eq!(decode("%4"), None);
+        assert_eq!(decode("%FF"), None);
+    }
+
+    #[test]
+    fn test_rejects_bad_requests() {
+        assert!(matches!(
+            read_request(&b""[..]),
+            Err(RequestError::Malformed(_))
+        ));
+        assert!(matches!(
+            read_request("POST / HTTP/1.1\r\nContent-Length: x\r\n\r\n".as_bytes()),
+            Err(RequestError::Malformed(_))
+        ));
+        let too_large = format!(
+            "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_BYTES + 1
+        );
+        assert!(matches!(
+            read_request(too_large.as_bytes()),
+            Err(RequestError::TooLarge)
+        ));
+    }
+
+    #[test]
+    fn test_response_bytes() {
+        let response = Response {
+            status: "200 OK",
+            body: "{}".to_string(),
+        };
+        let text = String::from_utf8(response.to_bytes()).unwrap();
+        assert!(text.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(text.contains("Content-Length: 2\r\n"));
+        assert!(text.ends_with("\r\n\r\n{}"));
+    }
+}
@@ -0,0 +1,500 @@
+//! HTTP services exposing the inbound ports and the read models.
+//!
+//! Speaks just enough HTTP/1.1 to serve one JSON request per connection, so no web
+//! framework is needed. `HttpServer::bind` serves poker math, with every endpoint
+//! backed by an inbound port:
+//!
+//! | Endpoint | Port | Request | Response |
+//! |---|---|---|---|
+//! | `POST /v1/evaluate` | `HandEvaluator` | `EvaluateRequest` | `HandResponse` |
+//! | `POST /v1/equity` | `EquityCalculator` | `EquityRequest` | `EquityResponse` |
+//! | `POST /v1/showdown` | `HandSolver` | `ShowdownRequest` | `ShowdownResponse` |
+//! | `GET /health` | | | `{"status": "ok"}` |
+//!
+//! Invalid requests get a `400 Bad Request` with an `ErrorResponse` body, as do
+//! equity requests for more samples or opponents than the server's `ServerLimits`
+//! allow. Connections beyond `ServerLimits::max_connections` get a `503 Service
+//! Unavailable`.
+//!
+//! ```text
+//! curl -d '{"hand": "AsKs", "board": "Qs Js 2h", "opponents": 2}' localhost:8080/v1/equity
+//! ```
+//!
+//! `HttpServer::bind_read_models` serves the projections to dashboards:
+//!
+//! | Endpoint | Store | Response |
+//! |---|---|---|
+//! | `GET /players/{id}/stats` | `PlayerStatsStore` | `PlayerStatsResponse` |
+//! | `GET /hands?player=&from=&to=&rank=&min_pot=&ties=&limit=&cursor=` | `HandSummaryStore` | `HandPageResponse` |
+//! | `GET /hands/{id}` | `HandSummaryStore` | `HandSummaryResponse` |
+//! | `GET /games/active?limit=&offset=` | `ActiveGameStore` | `ActiveGamesResponse` |
+//! | `GET /games/{id}` | `ActiveGameStore` | `ActiveGameState` |
+//!
+//! Lists are paginated by `limit` (default 50, at most 500) and either the `next`
+//! cursor or `next_offset` of the previous page. Read model failures map to `404 Not
+//! Found` (`NotFound`), `400 Bad Request` (`InvalidQuery`) and `500 Internal Server
+//! Error` (`StorageError`).
+
+mod api;
+mod http;
+mod queries;
+
+use std::io::{self, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver, SmartEquityCalculator};
+use crate::core::ports::inbound::{EquityCalculator, HandEvaluator, HandSolver, MAX_PLAYERS};
+use crate::core::ports::outbound::{ActiveGameStore, HandSummaryStore, PlayerStatsStore};
+
+use self::http::{Request, RequestError, Response, read_request};
+
+pub use api::{
+    EquityRequest, EquityResponse, ErrorResponse, EvaluateRequest, HandResponse, ShowdownRequest,
+    ShowdownResponse,
+};
+pub use queries::{
+    ActiveGamesResponse, HandPageResponse, HandSummaryResponse, PlayerStatsResponse,
+};
+
+/// How long a client may take to send its request or accept the response.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default `ServerLimits::max_samples`.
+const DEFAULT_MAX_SAMPLES: u32 = 1_000_000;
+
+/// Default `ServerLimits::max_connections`.
+const DEFAULT_MAX_CONNECTIONS: usize = 64;
+
+/// Bounds on the work clients can ask of an `HttpServer`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use riverrun::server::{HttpServer, ServerLimits, Services};
+///
+/// let limits = ServerLimits::new().with_max_samples(100_000).with_max_connections(16);
+/// let server = HttpServer::bind_with_limits("127.0.0.1:8080", Services::standard(), limits);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerLimits {
+    samples: u32,
+    opponents: usize,
+    connections: usize,
+}
+
+impl Default for ServerLimits {
+    fn default() -> Self {
+        Self {
+            samples: DEFAULT_MAX_SAMPLES,
+            opponents: MAX_PLAYERS - 1,
+            connections: DEFAULT_MAX_CONNECTIONS,
+        }
+    }
+}
+
+/// `ServerLimits` - Constructors
+impl ServerLimits {
+    /// A million samples, a full table of opponents, and 64 connections at once.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject equity requests for more than `max_samples` samples.
+    #[must_use]
+    pub const fn with_max_samples(mut self, max_samples: u32) -> Self {
+        self.samples = max_samples;
+        self
+    }
+
+    /// Reject equity requests against more than `max_opponents` opponents.
+    #[must_use]
+    pub const fn with_max_opponents(mut self, max_opponents: usize) -> Self {
+        self.opponents = max_opponents;
+        self
+    }
+
+    /// Handle at most `max_connections` connections at once, and at least one.
+    #[must_use]
+    pub fn with_max_connections(mut self, max_connections: usize) -> Self {
+        self.connections = max_connections.max(1);
+        self
+    }
+}
+
+/// `ServerLimits` - Accessors
+impl ServerLimits {
+    /// Most samples an equity request may ask for.
+    #[must_use]
+    pub const fn max_samples(&self) -> u32 {
+        self.samples
+    }
+
+    /// Most opponents an equity request may ask for.
+    #[must_use]
+    pub const fn max_opponents(&self) -> usize {
+        self.opponents
+    }
+
+    /// Most connections handled at once.
+    #[must_use]
+    pub const fn max_connections(&self) -> usize {
+        self.connections
+    }
+}
+
+/// The port implementations an `HttpServer` serves.
+#[derive(Debug, Clone)]
+pub struct Services<E, C, S> {
+    /// Serves `POST /v1/evaluate`.
+    pub evaluator: E,
+    /// Serves `POST /v1/equity`.
+    pub equity: C,
+    /// Serves `POST /v1/showdown`.
+    pub solver: S,
+}
+
+/// `Services` - Constructors
+impl<E: HandEvaluator, C: EquityCalculator, S: HandSolver> Services<E, C, S> {
+    /// Serves the given port implementations.
+    pub const fn new(evaluator: E, equity: C, solver: S) -> Self {
+        Self {
+            evaluator,
+            equity,
+            solver,
+        }
+    }
+}
+
+/// `Services` - Constructors
+impl
+    Services<
+        CactusKevEvaluator,
+        SmartEquityCalculator<CactusKevEvaluator>,
+        ShowdownSolver<CactusKevEvaluator>,
+    >
+{
+    /// The crate's standard implementations, all backed by `CactusKevEvaluator`.
+    #[must_use]
+    pub fn standard() -> Self {
+        let evaluator = CactusKevEvaluator::new();
+        Self::new(
+            evaluator.clone(),
+            SmartEquityCalculator::new(evaluator.clone()),
+            ShowdownSolver::new(evaluator),
+        )
+    }
+}
+
+/// The read model stores an `HttpServer` queries.
+#[derive(Debug, Clone)]
+pub struct ReadModels<H, P, A> {
+    /// Serves `GET /hands`.
+    pub hands: H,
+    /// Serves `GET /players/{id}/stats`.
+    pub players: P,
+    /// Serves `GET /games/active`.
+    pub games: A,
+}
+
+/// `ReadModels` - Constructors
+impl<H: HandSummaryStore, P: PlayerStatsStore, A: ActiveGameStore> ReadModels<H, P, A> {
+    /// Queries the given stores.
+    pub const fn new(hands: H, players: P, games: A) -> Self {
+        Self {
+            hands,
+            players,
+            games,
+        }
+    }
+}
+
+/// HTTP server answering JSON requests with its `Services` or `ReadModels`.
+///
+/// Connections are accepted on a background thread that stops when the server is
+/// dropped, and each request is handled on a thread of its own, up to
+/// `ServerLimits::max_connections` at once.
+///
+/// # Examples
+///
+/// ```no_run
+/// use riverrun::server::{HttpServer, Services};
+///
+/// let server = HttpServer::bind("127.0.0.1:8080", Services::standard()).unwrap();
+/// println!("listening on {}", server.local_addr());
+/// ```
+#[derive(Debug)]
+pub struct HttpServer {
+    shutdown: Arc<AtomicBool>,
+    local_addr: SocketAddr,
+}
+
+/// `HttpServer` - Constructors
+impl HttpServer {
+    /// Listen for requests on `addr`, serving them with `services` within the default
+    /// `ServerLimits`.
+    ///
+    /// # Errors
+    /// Returns an error if the address cannot be bound.
+    pub fn bind<E, C, S>(addr: impl ToSocketAddrs, services: Services<E, C, S>) -> io::Result<Self>
+    where
+        E: HandEvaluator + 'static,
+        C: EquityCalculator + 'static,
+        S: HandSolver + 'static,
+    {
+        Self::bind_with_limits(addr, services, ServerLimits::default())
+    }
+
+    /// Listen for requests on `addr`, serving them with `services` within `limits`.
+    ///
+    /// # Errors
+    /// Returns an error if the address cannot be bound.
+    pub fn bind_with_limits<E, C, S>(
+        addr: impl ToSocketAddrs,
+        services: Services<E, C, S>,
+        limits: ServerLimits,
+    ) -> io::Result<Self>
+    where
+        E: HandEvaluator + 'static,
+        C: EquityCalculator + 'static,
+        S: HandSolver + 'static,
+    {
+        Self::listen(addr, limits.max_connections(), move |request| {
+            api::handle(&services, &limits, request)
+        })
+    }
+
+    /// Listen for queries on `addr`, answering them from `read_models` within the
+    /// default `ServerLimits`.
+    ///
+    /// # Errors
+    /// Returns an error if the address cannot be bound.
+    pub fn bind_read_models<H, P, A>(
+        addr: impl ToSocketAddrs,
+        read_models: ReadModels<H, P, A>,
+    ) -> io::Result<Self>
+    where
+        H: HandSummaryStore + 'static,
+        P: PlayerStatsStore + 'static,
+        A: ActiveGameStore + 'static,
+    {
+        Self::bind_read_models_with_limits(addr, read_models, ServerLimits::default())
+    }
+
+    /// Listen for queries on `addr`, answering them from `read_models` within `limits`.
+    ///
+    /// # Errors
+    /// Returns an error if the address cannot be bound.
+    pub fn bind_read_models_with_limits<H, P, A>(
+        addr: impl ToSocketAddrs,
+        read_models: ReadModels<H, P, A>,
+        limits: ServerLimits,
+    ) -> io::Result<Self>
+    where
+        H: HandSummaryStore + 'static,
+        P: PlayerStatsStore + 'static,
+        A: ActiveGameStore + 'static,
+    {
+        Self::listen(addr, limits.max_connections(), move |request| {
+            queries::handle(&read_models, request)
+        })
+    }
+}
+
+/// `HttpServer` - Helpers
+impl HttpServer {
+    fn listen<F>(addr: impl ToSocketAddrs, max_connections: usize, handler: F) -> io::Result<Self>
+    where
+        F: Fn(&Request) -> Response + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind(addr)?;
+        let local_addr = listener.local_addr()?;
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let stopping = Arc::clone(&shutdown);
+        let handler = Arc::new(handler);
+        thread::Builder::new()
+            .name("http-accept".to_string())
+            .spawn(move || accept_loop(&listener, &stopping, max_connections, &handler))?;
+
+        Ok(Self {
+            shutdown,
+            local_addr,
+        })
+    }
+}
+
+/// `HttpServer` - Accessors
+impl HttpServer {
+    /// Address clients connect to.
+    #[must_use]
+    pub const fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Drop for HttpServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wake the accept thread so it sees the shutdown flag
+        let _ = TcpStream::connect(self.local_addr);
+    }
+}
+
+/// A connection being handled, counted in the shared total until dropped.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn accept_loop<F>(
+    listener: &TcpListener,
+    shutdown: &AtomicBool,
+    max_connections: usize,
+    handler: &Arc<F>,
+) where
+    F: Fn(&Request) -> Response + Send + Sync + 'static,
+{
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+        let Ok(stream) = stream else {
+            continue;
+        };
+        if active.fetch_add(1, Ordering::SeqCst) >= max_connections {
+            active.fetch_sub(1, Ordering::SeqCst);
+            let _ = turn_away(stream);
+            continue;
+        }
+        let slot = ConnectionSlot(Arc::clone(&active));
+        let handler = Arc::clone(handler);
+        // A failed request only affects that client, and a thread that cannot be
+        // spawned drops its slot with the closure
+        let _ = thread::Builder::new()
+            .name("http-request".to_string())
+            .spawn(move || {
+                let _slot = slot;
+                serve(stream, &*handler)
+            });
+    }
+}
+
+/// Answers `503 Service Unavailable` without reading the request, so a busy server
+/// spends no time on it.
+fn turn_away(mut stream: TcpStream) -> io::Result<()> {
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+    let response = api::error("503 Service Unavailable", "too many connections");
+    stream.write_all(&response.to_bytes())
+}
+
+/// Reads one request from `stream` and writes `handler`'s response.
+fn serve(mut stream: TcpStream, handler: &impl Fn(&Request) -> Response) -> io::Result<()> {
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+
+    let response = match read_request(&stream) {
+        Ok(request) => handler(&request),
+        Err(RequestError::Malformed(reason)) => api::error("400 Bad Request", reason),
+        Err(RequestError::TooLarge) => {
+            api::error("413 Payload Too Large", "request body too large")
+        }
+        Err(RequestError::Io(err)) => return Err(err),
+    };
+    stream.write_all(&response.to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    fn request(server: &HttpServer, raw: &str) -> String {
+        let mut stream = TcpStream::connect(server.local_addr()).unwrap();
+        stream.set_read_timeout(Some(CLIENT_TIMEOUT)).unwrap();
+        stream.write_all(raw.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_serves_requests() {
+        let server = HttpServer::bind("127.0.0.1:0", Services::standard()).unwrap();
+
+        let body = r#"{"hands": ["AsKs", "QhQd"], "board": "Qs Js 2h 7c 3d"}"#;
+        let response = request(
+            &server,
+            &format!(
+                "POST /v1/showdown HTTP/1.1\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            ),
+        );
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        let (_, json) = response.split_once("\r\n\r\n").unwrap();
+        let showdown: ShowdownResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(showdown.winners, vec![1]);
+
+        let response = request(&server, "GET /health HTTP/1.1\r\n\r\n");
+        assert!(response.ends_with(r#"{"status":"ok"}"#));
+    }
+
+    #[test]
+    fn test_rejects_oversized_and_malformed_requests() {
+        let server = HttpServer::bind("127.0.0.1:0", Services::standard()).unwrap();
+        let response = request(
+            &server,
+            "POST /v1/equity HTTP/1.1\r\nContent-Length: 1000000\r\n\r\n",
+        );
+        assert!(response.starts_with("HTTP/1.1 413 Payload Too Large\r\n"));
+        let response = request(&server, "\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+    }
+
+    #[test]
+    fn test_turns_away_connections_beyond_the_limit() {
+        let limits = ServerLimits::new().with_max_connections(1);
+        let server = HttpServer::bind_with_limits("127.0.0.1:0", Services::standard(), limits)
+            .unwrap();
+        // Holds the only slot until it sends a request
+        let mut idle = TcpStream::connect(server.local_addr()).unwrap();
+
+        let mut busy = TcpStream::connect(server.local_addr()).unwrap();
+        busy.set_read_timeout(Some(CLIENT_TIMEOUT)).unwrap();
+        let mut response = String::new();
+        busy.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+
+        idle.write_all(b"GET /health HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        idle.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    }
+
+    #[test]
+    fn test_serves_read_models() {
+        use crate::adapters::in_memory::{
+            InMemoryActiveGameStore, InMemoryHandSummaryStore, InMemoryPlayerStatsStore,
+        };
+
+        let read_models = ReadModels::new(
+            InMemoryHandSummaryStore::new(),
+            InMemoryPlayerStatsStore::new(),
+            InMemoryActiveGameStore::new(),
+        );
+        let server = HttpServer::bind_read_models("127.0.0.1:0", read_models).unwrap();
+        let response = request(&server, "GET /games/active HTTP/1.1\r\n\r\n");
+        assert!(response.ends_with(r#"{"games":[],"total":0,"next_offset":null}"#));
+        let response = request(&server, "GET /hands/missing HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    }
+}
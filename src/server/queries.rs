@@ -0,0 +1,419 @@
+//! JSON views of the read models, and the routes serving them.
+
+use serde::{Deserialize, Serialize};
+
+use super::ReadModels;
+use super::api::{error, ok};
+use super::http::{Request, Response, decode};
+use crate::core::ports::outbound::{
+    ActiveGameState, ActiveGameStore, HandCursor, HandQuery, HandSummary, HandSummaryStore,
+    PlayerStats, PlayerStatsStore, ReadModelError, Timestamp,
+};
+
+/// Page size when a request does not give a `limit`.
+const DEFAULT_LIMIT: usize = 50;
+
+/// Largest `limit` a request may ask for.
+const MAX_LIMIT: usize = 500;
+
+/// Response of `GET /players/{id}/stats`; ratios are percentages from 0 to 100.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerStatsResponse {
+    /// The player.
+    pub player_id: String,
+    /// Hands dealt in.
+    pub hands_played: u64,
+    /// Hands won, including split pots.
+    pub hands_won: u64,
+    /// When the player's last hand started, in milliseconds since the epoch.
+    pub last_played_at: Option<Timestamp>,
+    /// Share of hands won.
+    pub win_rate: f64,
+    /// Voluntarily put money in pot.
+    pub vpip: f64,
+    /// Preflop raise.
+    pub pfr: f64,
+    /// Three-bets per opportunity.
+    pub three_bet: f64,
+    /// Continuation bets per opportunity.
+    pub cbet: f64,
+    /// Went to showdown, per flop seen.
+    pub wtsd: f64,
+    /// Won at showdown, per showdown.
+    pub wsd: f64,
+    /// Postflop bets and raises per call.
+    pub aggression_factor: f64,
+    /// All-in winnings above (or below) their equity.
+    pub all_in_luck: i64,
+}
+
+impl From<&PlayerStats> for PlayerStatsResponse {
+    fn from(stats: &PlayerStats) -> Self {
+        Self {
+            player_id: stats.player_id.clone(),
+            hands_played: stats.hands_played,
+            hands_won: stats.hands_won,
+            last_played_at: stats.last_played_at,
+            win_rate: stats.win_rate(),
+            vpip: stats.vpip(),
+            pfr: stats.pfr(),
+            three_bet: stats.three_bet(),
+            cbet: stats.cbet(),
+            wtsd: stats.wtsd(),
+            wsd: stats.wsd(),
+            aggression_factor: stats.aggression_factor(),
+            all_in_luck: stats.all_in_luck(),
+        }
+    }
+}
+
+/// A completed hand, as returned by `GET /hands` and `GET /hands/{id}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandSummaryResponse {
+    /// The hand.
+    pub hand_id: String,
+    /// The game the hand was played in.
+    pub game_id: String,
+    /// When the hand started, in milliseconds since the epoch.
+    pub started_at: Timestamp,
+    /// When the hand ended, in milliseconds since the epoch.
+    pub ended_at: Timestamp,
+    /// Players dealt in.
+    pub player_ids: Vec<String>,
+    /// Players awarded the pot; more than one for a split pot.
+    pub winner_ids: Vec<String>,
+    /// Whether the pot was split.
+    pub is_tie: bool,
+    /// Category of the winning hand, if it reached showdown.
+    pub winning_hand_rank: Option<String>,
+    /// Total pot in chips.
+    pub pot: u64,
+}
+
+impl From<&HandSummary> for HandSummaryResponse {
+    fn from(hand: &HandSummary) -> Self {
+        Self {
+            hand_id: hand.hand_id.clone(),
+            game_id: hand.game_id.to_string(),
+            started_at: hand.started_at,
+            ended_at: hand.ended_at,
+            player_ids: hand.player_ids.clone(),
+            winner_ids: hand.winner_ids.clone(),
+            is_tie: hand.is_tie,
+            winning_hand_rank: hand.winning_hand_rank.clone(),
+            pot: hand.pot,
+        }
+    }
+}
+
+/// Response of `GET /hands`, newest hands first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandPageResponse {
+    /// Hands on this page.
+    pub hands: Vec<HandSummaryResponse>,
+    /// `cursor` to pass for the next page; `None` on the last page.
+    pub next: Option<String>,
+}
+
+/// Response of `GET /games/active`, most recently updated games first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveGamesResponse {
+    /// Games on this page.
+    pub games: Vec<ActiveGameState>,
+    /// Active games across all pages.
+    pub total: u64,
+    /// `offset` to pass for the next page; `None` on the last page.
+    pub next_offset: Option<usize>,
+}
+
+/// Routes `request` to its query.
+pub(super) fn handle<H, P, A>(read_models: &ReadModels<H, P, A>, request: &Request) -> Response
+where
+    H: HandSummaryStore,
+    P: PlayerStatsStore,
+    A: ActiveGameStore,
+{
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let result = match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["health"]) => Ok(ok(&serde_json::json!({ "status": "ok" }))),
+        ("GET", ["players", id, "stats"]) => segment(id).and_then(|id| {
+            let stats = read_models.players.get(&id).map_err(read_model_error)?;
+            Ok(ok(&PlayerStatsResponse::from(&stats)))
+        }),
+        ("GET", ["hands"]) => hands(read_models, request),
+        ("GET", ["hands", id]) => segment(id).and_then(|id| {
+            let hand = read_models.hands.get(&id).map_err(read_model_error)?;
+            Ok(ok(&HandSummaryResponse::from(&hand)))
+        }),
+        ("GET", ["games", "active"]) => active_games(read_models, request),
+        ("GET", ["games", id]) => segment(id).and_then(|id| {
+            let game = read_models.games.get(&id.into()).map_err(read_model_error)?;
+            Ok(ok(&game))
+        }),
+        (_, ["health" | "hands"] | ["hands" | "games", _] | ["players", _, "stats"]) => {
+            Err(error("405 Method Not Allowed", "method not allowed"))
+        }
+        _ => Err(error("404 Not Found", "not found")),
+    };
+    result.unwrap_or_else(|response| response)
+}
+
+/// Maps a read model failure to its HTTP status.
+#[allow(clippy::needless_pass_by_value)] // taken by value to be usable with `map_err`
+fn read_model_error(err: ReadModelError) -> Response {
+    let status = match err {
+        ReadModelError::NotFound(_) => "404 Not Found",
+        ReadModelError::InvalidQuery(_) => "400 Bad Request",
+        ReadModelError::StorageError(_) => "500 Internal Server Error",
+    };
+    error(status, err.to_string())
+}
+
+/// Decodes a path segment such as a player id.
+fn segment(encoded: &str) -> Result<String, Response> {
+    decode(encoded).ok_or_else(|| error("400 Bad Request", "invalid percent-encoding in path"))
+}
+
+/// Decoded query parameter `name`, parsed as `T`.
+fn param<T: std::str::FromStr>(request: &Request, name: &str) -> Result<Option<T>, Response> {
+    let invalid = || {
+        error(
+            "400 Bad Request",
+            format!("invalid query parameter `{name}`"),
+        )
+    };
+    request
+        .param(name)
+        .map_err(|_| invalid())?
+        .map(|value| value.parse().map_err(|_| invalid()))
+        .transpose()
+}
+
+/// The `limit` query parameter, defaulting to `DEFAULT_LIMIT`.
+fn limit(request: &Request) -> Result<usize, Response> {
+    match param(request, "limit")?.unwrap_or(DEFAULT_LIMIT) {
+        0 => Err(error("400 Bad Request", "limit must be at least 1")),
+        limit => Ok(limit.min(MAX_LIMIT)),
+    }
+}
+
+/// `GET /hands?player=&from=&to=&rank=&min_pot=&ties=&limit=&cursor=`
+fn hands<H, P, A>(
+    read_models: &ReadModels<H, P, A>,
+    request: &Request,
+) -> Result<Response, Response>
+where
+    H: HandSummaryStore,
+{
+    let query = HandQuery {
+        player_id: param(request, "player")?,
+        from: param(request, "from")?,
+        to: param(request, "to")?,
+        winning_hand_rank: param(request, "rank")?,
+        min_pot: param(request, "min_pot")?,
+        ties_only: param(request, "ties")?.unwrap_or(false),
+    };
+    query.validate().map_err(read_model_error)?;
+    let cursor = param::<String>(request, "cursor")?
+        .map(|cursor| parse_cursor(&cursor))
+        .transpose()?;
+
+    let page = read_models
+        .hands
+        .search(&query, cursor.as_ref(), limit(request)?)
+        .map_err(read_model_error)?;
+    Ok(ok(&HandPageResponse {
+        hands: page.hands.iter().map(HandSummaryResponse::from).collect(),
+        next: page.next.as_ref().map(format_cursor),
+    }))
+}
+
+/// `GET /games/active?limit=&offset=`
+fn active_games<H, P, A>(
+    read_models: &ReadModels<H, P, A>,
+    request: &Request,
+) -> Result<Response, Response>
+where
+    A: ActiveGameStore,
+{
+    let limit = limit(request)?;
+    let offset = param(request, "offset")?.unwrap_or(0);
+    let games = read_models.games.list_active().map_err(read_model_error)?;
+    let total = games.len() as u64;
+    let next_offset = (games.len() > offset + limit).then_some(offset + limit);
+    Ok(ok(&ActiveGamesResponse {
+        games: games.into_iter().skip(offset).take(limit).collect(),
+        total,
+        next_offset,
+    }))
+}
+
+/// Encodes `cursor` as `{started_at}-{hand_id}`.
+fn format_cursor(cursor: &HandCursor) -> String {
+    format!("{}-{}", cursor.started_at, cursor.hand_id)
+}
+
+/// Parses a cursor written by `format_cursor`.
+fn parse_cursor(cursor: &str) -> Result<HandCursor, Response> {
+    cursor
+        .split_once('-')
+        .and_then(|(started_at, hand_id)| {
+            Some(HandCursor {
+                started_at: started_at.parse().ok()?,
+                hand_id: hand_id.to_string(),
+            })
+        })
+        .ok_or_else(|| error("400 Bad Request", "invalid query parameter `cursor`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::in_memory::{
+        InMemoryActiveGameStore, InMemoryHandSummaryStore, InMemoryPlayerStatsStore,
+    };
+    use crate::server::ErrorResponse;
+    use serde::de::DeserializeOwned;
+
+    type Stores =
+        ReadModels<InMemoryHandSummaryStore, InMemoryPlayerStatsStore, InMemoryActiveGameStore>;
+
+    fn stores() -> Stores {
+        let read_models = ReadModels::new(
+            InMemoryHandSummaryStore::new(),
+            InMemoryPlayerStatsStore::new(),
+            InMemoryActiveGameStore::new(),
+        );
+        for (i, started_at) in [100, 200, 300].into_iter().enumerate() {
+            read_models
+                .hands
+                .save(&HandSummary {
+                    hand_id: format!("hand-{i}"),
+                    game_id: "game-1".into(),
+                    started_at,
+                    ended_at: started_at + 50,
+                    num_players: 2,
+                    player_ids: vec!["alice".to_string(), "bob smith".to_string()],
+                    winner_ids: vec!["alice".to_string()],
+                    is_tie: false,
+                    winning_hand_rank: Some("Flush".to_string()),
+                    pot: 100 * (i as u64 + 1),
+                    all_in_ev: Vec::new(),
+                })
+                .unwrap();
+        }
+        read_models
+            .players
+            .save(&PlayerStats {
+                player_id: "bob smith".to_string(),
+                hands_played: 4,
+                hands_won: 1,
+                ..PlayerStats::default()
+            })
+            .unwrap();
+        for id in ["game-1", "game-2", "game-3"] {
+            read_models
+                .games
+                .save(&ActiveGameState {
+                    game_id: id.into(),
+                    num_players: 2,
+                    player_ids: Vec::new(),
+                    current_street: "Flop".to_string(),
+                    is_showdown: false,
+                    updated_at: 0,
+                })
+                .unwrap();
+        }
+        read_models
+    }
+
+    fn get(read_models: &Stores, target: &str) -> Response {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let request = Request {
+            method: "GET".to_string(),
+            path: path.to_string(),
+            query: query.to_string(),
+            body: Vec::new(),
+        };
+        handle(read_models, &request)
+    }
+
+    fn body<T: DeserializeOwned>(response: &Response) -> T {
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    #[test]
+    fn test_player_stats() {
+        let read_models = stores();
+        let response = get(&read_models, "/players/bob%20smith/stats");
+        assert_eq!(response.status, "200 OK");
+        let stats: PlayerStatsResponse = body(&response);
+        assert_eq!(stats.hands_played, 4);
+        assert!((stats.win_rate - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hands_paginate_newest_first() {
+        let read_models = stores();
+        let first: HandPageResponse = body(&get(&read_models, "/hands?player=alice&limit=2"));
+        let ids: Vec<&str> = first
+            .hands
+            .iter()
+            .map(|hand| hand.hand_id.as_str())
+            .collect();
+        assert_eq!(ids, ["hand-2", "hand-1"]);
+        let next = first.next.unwrap();
+        assert_eq!(next, "200-hand-1");
+
+        let rest: HandPageResponse =
+            body(&get(&read_models, &format!("/hands?limit=2&cursor={next}")));
+        assert_eq!(rest.hands.len(), 1);
+        assert_eq!(rest.hands[0].hand_id, "hand-0");
+        assert!(rest.next.is_none());
+
+        let filtered: HandPageResponse =
+            body(&get(&read_models, "/hands?from=150&to=250&min_pot=200"));
+        assert_eq!(filtered.hands.len(), 1);
+
+        let hand: HandSummaryResponse = body(&get(&read_models, "/hands/hand-0"));
+        assert_eq!(hand.pot, 100);
+    }
+
+    #[test]
+    fn test_active_games_paginate() {
+        let read_models = stores();
+        let page: ActiveGamesResponse = body(&get(&read_models, "/games/active?limit=2"));
+        assert_eq!(
+            (page.games.len(), page.total, page.next_offset),
+            (2, 3, Some(2))
+        );
+        let page: ActiveGamesResponse = body(&get(&read_models, "/games/active?limit=2&offset=2"));
+        assert_eq!((page.games.len(), page.next_offset), (1, None));
+        assert_eq!(get(&read_models, "/games/game-2").status, "200 OK");
+    }
+
+    #[test]
+    fn test_error_mapping() {
+        let read_models = stores();
+        let response = get(&read_models, "/hands/missing");
+        assert_eq!(response.status, "404 Not Found");
+        assert_eq!(body::<ErrorResponse>(&response).error, "not found: missing");
+        assert_eq!(
+            get(&read_models, "/hands?from=5&to=4").status,
+            "400 Bad Request"
+        );
+        assert_eq!(
+            get(&read_models, "/hands?limit=many").status,
+            "400 Bad Request"
+        );
+        assert_eq!(
+            get(&read_models, "/hands?cursor=nope").status,
+            "400 Bad Request"
+        );
+        assert_eq!(get(&read_models, "/nowhere").status, "404 Not Found");
+        assert_eq!(
+            read_model_error(ReadModelError::StorageError("down".to_string())).status,
+            "500 Internal Server Error"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Optional `tracing` instrumentation, enabled by the `telemetry` feature.
+//!
+//! Equity calculations and hands run inside spans, and counters and latencies are
+//! emitted as events whose fields follow the `tracing-opentelemetry` metric
+//! conventions: `monotonic_counter.*` fields are counters and `histogram.*` fields are
+//! histograms. Any `tracing` subscriber can consume them; without the feature every
+//! helper compiles to nothing.
+//!
+//! | Metric | Kind | Fields |
+//! |---|---|---|
+//! | `equity_samples` | counter | `calculator` |
+//! | `cache_hits`, `cache_misses` | counter | `cache` |
+//! | `event_store_appends` | counter | `store` |
+//! | `event_store_append_seconds` | histogram | `store` |
+//! | `hands_started`, `hands_completed` | counter | |
+//! | `projection_updates` | counter | `projection` |
+#![cfg_attr(
+    not(feature = "telemetry"),
+    allow(unused_variables, clippy::missing_const_for_fn)
+)]
+
+#[cfg(feature = "telemetry")]
+use std::time::Instant;
+
+/// Guard keeping a span entered until it is dropped.
+#[must_use = "the span is exited when the guard is dropped"]
+pub struct SpanGuard {
+    #[cfg(feature = "telemetry")]
+    _entered: tracing::span::EnteredSpan,
+}
+
+/// Start time of an operation whose latency is recorded.
+#[derive(Clone, Copy)]
+pub struct Timer {
+    #[cfg(feature = "telemetry")]
+    start: Instant,
+}
+
+/// `Timer` - Constructors
+impl Timer {
+    /// Starts timing; without the `telemetry` feature the clock is not read.
+    pub fn start() -> Self {
+        Self {
+            #[cfg(feature = "telemetry")]
+            start: Instant::now(),
+        }
+    }
+}
+
+/// Enters a span covering one equity calculation.
+pub fn equity_span(
+    calculator: &'static str,
+    num_opponents: usize,
+    board_cards: usize,
+) -> SpanGuard {
+    SpanGuard {
+        #[cfg(feature = "telemetry")]
+        _entered: tracing::info_span!("equity", calculator, num_opponents, board_cards).entered(),
+    }
+}
+
+/// Enters a span covering one hand engine command, e.g. `"start_hand"`.
+pub fn hand_engine_span(operation: &'static str) -> SpanGuard {
+    SpanGuard {
+        #[cfg(feature = "telemetry")]
+        _entered: tracing::info_span!("hand_engine", operation).entered(),
+    }
+}
+
+/// Counts the showdowns or samples run by an equity calculation.
+pub fn equity_samples(calculator: &'static str, samples: u64) {
+    #[cfg(feature = "telemetry")]
+    tracing::debug!(monotonic_counter.equity_samples = samples, calculator);
+}
+
+/// Counts a lookup in `cache` as a hit or a miss.
+// The only cache so far is the file event store's index, which needs `serde`
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+pub fn cache_lookup(cache: &'static str, hit: bool) {
+    #[cfg(feature = "telemetry")]
+    if hit {
+        tracing::trace!(monotonic_counter.cache_hits = 1_u64, cache);
+    } else {
+        tracing::trace!(monotonic_counter.cache_misses = 1_u64, cache);
+    }
+}
+
+/// Records a successful append of `events` events to `store` started at `timer`.
+pub fn event_store_append(store: &'static str, events: usize, timer: Timer) {
+    #[cfg(feature = "telemetry")]
+    tracing::debug!(
+        monotonic_counter.event_store_appends = 1_u64,
+        histogram.event_store_append_seconds = timer.start.elapsed().as_secs_f64(),
+        events,
+        store
+    );
+}
+
+/// Counts a hand dealt by the hand engine.
+pub fn hand_started() {
+    #[cfg(feature = "telemetry")]
+    tracing::info!(monotonic_counter.hands_started = 1_u64);
+}
+
+/// Counts a hand played to completion by the hand engine.
+pub fn hand_completed() {
+    #[cfg(feature = "telemetry")]
+    tracing::info!(monotonic_counter.hands_completed = 1_u64);
+}
+
+/// Counts an update applied by `projection`.
+pub fn projection_update(projection: &'static str) {
+    #[cfg(feature = "telemetry")]
+    tracing::debug!(monotonic_counter.projection_updates = 1_u64, projection);
+}
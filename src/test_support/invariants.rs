@@ -0,0 +1,214 @@
+//! Checks of properties every correct evaluator and betting state must satisfy.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::core::application::{HandOutcome, HandSetup};
+use crate::core::domain::entities::card::{Card, Suit};
+use crate::core::domain::errors::ChipConservationError;
+use crate::core::domain::primitives::{BettingState, Chips};
+use crate::core::ports::inbound::HandEvaluator;
+
+/// Every suit, in `Suit` order.
+const SUITS: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+/// A broken invariant, with what is needed to reproduce it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// Relabelling the suits changed a hand's strength.
+    SuitSymmetry {
+        /// The hand as given.
+        cards: Vec<Card>,
+        /// The hand after relabelling its suits.
+        permuted: Vec<Card>,
+        /// Strength of `cards`.
+        expected: u16,
+        /// Strength of `permuted`.
+        actual: u16,
+    },
+    /// Chips were created or destroyed during betting.
+    ChipsNotConserved(ChipConservationError),
+    /// A finished hand's final stacks do not add up to its starting stacks.
+    HandChipsNotConserved {
+        /// Chips the seats started the hand with.
+        expected: Chips,
+        /// Chips the seats finished the hand with.
+        actual: Chips,
+    },
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SuitSymmetry {
+                cards,
+                permuted,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "relabelling suits of {cards:?} as {permuted:?} changed its strength from {expected} to {actual}"
+            ),
+            Self::ChipsNotConserved(err) => write!(f, "{err}"),
+            Self::HandChipsNotConserved { expected, actual } => {
+                write!(f, "hand ended with {actual} in stacks, expected {expected}")
+            }
+        }
+    }
+}
+
+impl Error for InvariantViolation {}
+
+/// All 24 ways of relabelling the four suits; `permutation[suit as usize]` is the new
+/// suit of `suit`. The identity comes first.
+#[must_use]
+pub fn suit_permutations() -> Vec<[Suit; 4]> {
+    let mut permutations = Vec::with_capacity(24);
+    for a in SUITS {
+        for b in SUITS {
+            for c in SUITS {
+                for d in SUITS {
+                    let permutation = [a, b, c, d];
+                    let distinct = (0..4).all(|i| !permutation[i + 1..].contains(&permutation[i]));
+                    if distinct {
+                        permutations.push(permutation);
+                    }
+                }
+            }
+        }
+    }
+    permutations
+}
+
+/// `card` with its suit relabelled by `permutation`.
+#[must_use]
+pub const fn permute_suit(card: Card, permutation: [Suit; 4]) -> Card {
+    Card::new(card.rank_enum(), permutation[card.suit_enum() as usize])
+}
+
+/// Checks that `evaluator` gives `cards` the same strength under every relabelling
+/// of the suits.
+///
+/// # Errors
+///
+/// Returns `InvariantViolation::SuitSymmetry` for the first relabelling that changes
+/// the strength.
+///
+/// # Examples
+///
+/// ```
+/// use riverrun::core::domain::services::CactusKevEvaluator;
+/// use riverrun::test_support::invariants::check_suit_symmetry;
+///
+/// let cards = ["As", "Ks", "Qs", "Js", "9h", "3d", "2c"].map(|card| card.parse().unwrap());
+/// assert_eq!(check_suit_symmetry(&CactusKevEvaluator::new(), &cards), Ok(()));
+/// ```
+pub fn check_suit_symmetry<E: HandEvaluator>(
+    evaluator: &E,
+    cards: &[Card; 7],
+) -> Result<(), InvariantViolation> {
+    let expected = evaluator.evaluate_7cards_fast(cards);
+    for permutation in suit_permutations() {
+        let permuted = cards.map(|card| permute_suit(card, permutation));
+        let actual = evaluator.evaluate_7cards_fast(&permuted);
+        if actual != expected {
+            return Err(InvariantViolation::SuitSymmetry {
+                cards: cards.to_vec(),
+                permuted: permuted.to_vec(),
+                expected,
+                actual,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks that `betting`'s stacks and pots add up to the chips it started with.
+///
+/// # Errors
+///
+/// Returns `InvariantViolation::ChipsNotConserved` describing the mismatch.
+pub fn check_chip_conservation(betting: &BettingState) -> Result<(), InvariantViolation> {
+    betting
+        .check_chip_conservation()
+        .map_err(InvariantViolation::ChipsNotConserved)
+}
+
+/// Checks that a finished hand paid out exactly the chips its seats started with.
+///
+/// # Errors
+///
+/// Returns `InvariantViolation::HandChipsNotConserved` if the final stacks add up to
+/// more or fewer chips than `setup`'s stacks.
+pub fn check_hand_chips(
+    setup: &HandSetup,
+    outcome: &HandOutcome,
+) -> Result<(), InvariantViolation> {
+    let total = |stacks: &[Chips]| stacks.iter().fold(Chips::ZERO, |acc, &stack| acc + stack);
+    let expected = total(&setup.stacks);
+    let actual = total(outcome.final_stacks());
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(InvariantViolation::HandChipsNotConserved { expected, actual })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::hand::Hand;
+    use crate::core::domain::primitives::PlayerId;
+    use crate::core::domain::services::CactusKevEvaluator;
+
+    #[test]
+    fn test_suit_permutations() {
+        let permutations = suit_permutations();
+        assert_eq!(permutations.len(), 24);
+        assert_eq!(permutations[0], SUITS);
+
+        let card: Card = "Ah".parse().unwrap();
+        let swapped = [Suit::Clubs, Suit::Diamonds, Suit::Spades, Suit::Hearts];
+        assert_eq!(permute_suit(card, swapped), "As".parse().unwrap());
+    }
+
+    #[test]
+    fn test_suit_symmetry_detects_broken_evaluator() {
+        /// Scores hands by how many spades they hold.
+        struct SpadeCounter;
+        impl HandEvaluator for SpadeCounter {
+            fn evaluate_5cards(&self, cards: [Card; 5]) -> Hand {
+                CactusKevEvaluator::new().evaluate_5cards(cards)
+            }
+            fn evaluate_7cards(&self, cards: [Card; 7]) -> Hand {
+                CactusKevEvaluator::new().evaluate_7cards(cards)
+            }
+            fn evaluate_5cards_fast(&self, cards: &[Card; 5]) -> u16 {
+                CactusKevEvaluator::new().evaluate_5cards_fast(cards)
+            }
+            fn evaluate_7cards_fast(&self, cards: &[Card; 7]) -> u16 {
+                cards.iter().fold(0, |count, card| {
+                    count + u16::from(card.suit_enum() == Suit::Spades)
+                })
+            }
+        }
+
+        let cards = ["As", "Ks", "Qh", "Jh", "9h", "3d", "2c"].map(|card| card.parse().unwrap());
+        assert_eq!(
+            check_suit_symmetry(&CactusKevEvaluator::new(), &cards),
+            Ok(())
+        );
+        assert!(matches!(
+            check_suit_symmetry(&SpadeCounter, &cards),
+            Err(InvariantViolation::SuitSymmetry { expected: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_chip_conservation() {
+        let mut betting = BettingState::new(vec![Chips::new(100); 3]);
+        betting.start_round(Chips::new(10), true);
+        betting.post_blind(PlayerId::new(0), Chips::new(5));
+        assert_eq!(check_chip_conservation(&betting), Ok(()));
+    }
+}
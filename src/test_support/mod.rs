@@ -0,0 +1,25 @@
+//! Shared generators and invariant checks for property-based tests.
+//!
+//! Enabled by the `test-support` feature, so downstream crates can test their own
+//! evaluators and table logic against the same generators and invariants used here:
+//!
+//! ```text
+//! [dev-dependencies]
+//! riverrun = { version = "0.1", features = ["test-support"] }
+//! ```
+//!
+//! `strategies` holds `proptest` strategies for cards, deals, and betting states, and
+//! `invariants` holds checks such as evaluator symmetry under suit relabelling and
+//! chip conservation that return an `InvariantViolation` describing any failure.
+
+pub mod invariants;
+pub mod strategies;
+
+pub use invariants::{
+    InvariantViolation, check_chip_conservation, check_hand_chips, check_suit_symmetry,
+    permute_suit, suit_permutations,
+};
+pub use strategies::{
+    betting_state, board, board_of, card, deal, distinct_cards, hole_cards, replay_betting,
+    seven_cards, suit_permutation,
+};
@@ -0,0 +1,217 @@
+//! `proptest` strategies for cards, deals, and betting states.
+//!
+//! Card strategies never repeat a card within one value, so every generated deal could
+//! have come from a single deck. `Card`, `HoleCards`, and `Board` also implement
+//! `Arbitrary`, so `any::<Board>()` works in `proptest!` blocks.
+
+use proptest::arbitrary::Arbitrary;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::{select, subsequence};
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::{Card, Suit};
+use crate::core::domain::entities::hole_cards::HoleCards;
+use crate::core::domain::primitives::{BettingState, Chips, PlayerId};
+
+/// Every card, in index order.
+fn all_cards() -> Vec<Card> {
+    (0..52).filter_map(Card::from_index).collect()
+}
+
+/// Any single card.
+pub fn card() -> impl Strategy<Value = Card> {
+    select(all_cards())
+}
+
+/// `count` distinct cards in random order.
+///
+/// # Panics
+///
+/// The strategy panics when generating if `count` exceeds 52.
+pub fn distinct_cards(count: usize) -> impl Strategy<Value = Vec<Card>> {
+    subsequence(all_cards(), count).prop_shuffle()
+}
+
+/// Two distinct hole cards.
+pub fn hole_cards() -> impl Strategy<Value = HoleCards> {
+    distinct_cards(2).prop_map(|cards| HoleCards::new(cards[0], cards[1]))
+}
+
+/// A board on any street: 0, 3, 4, or 5 distinct cards.
+pub fn board() -> impl Strategy<Value = Board> {
+    prop_oneof![Just(0_usize), Just(3), Just(4), Just(5)].prop_flat_map(board_of)
+}
+
+/// A board of exactly `board_cards` distinct cards.
+///
+/// # Panics
+///
+/// The strategy panics when generating if `board_cards` is not 0, 3, 4, or 5.
+pub fn board_of(board_cards: usize) -> impl Strategy<Value = Board> {
+    distinct_cards(board_cards)
+        .prop_map(|cards| Board::with_cards(cards).expect("0, 3, 4, or 5 board cards"))
+}
+
+/// Seven distinct cards, as evaluated at showdown.
+pub fn seven_cards() -> impl Strategy<Value = [Card; 7]> {
+    distinct_cards(7).prop_map(|cards| std::array::from_fn(|i| cards[i]))
+}
+
+/// Hole cards for `num_players` players and a board of `board_cards` cards, with no
+/// card dealt twice.
+///
+/// # Panics
+///
+/// The strategy panics when generating if `board_cards` is not 0, 3, 4, or 5, or if
+/// the deal needs more than 52 cards.
+pub fn deal(
+    num_players: usize,
+    board_cards: usize,
+) -> impl Strategy<Value = (Vec<HoleCards>, Board)> {
+    distinct_cards(2 * num_players + board_cards).prop_map(move |cards| {
+        let (holes, board) = cards.split_at(2 * num_players);
+        let players = holes
+            .chunks(2)
+            .map(|pair| HoleCards::new(pair[0], pair[1]))
+            .collect();
+        let board = Board::with_cards(board.to_vec()).expect("0, 3, 4, or 5 board cards");
+        (players, board)
+    })
+}
+
+/// A relabelling of the four suits, as used by `invariants::permute_suit`.
+pub fn suit_permutation() -> impl Strategy<Value = [Suit; 4]> {
+    Just(vec![
+        Suit::Clubs,
+        Suit::Diamonds,
+        Suit::Hearts,
+        Suit::Spades,
+    ])
+    .prop_shuffle()
+    .prop_map(|suits| std::array::from_fn(|i| suits[i]))
+}
+
+/// A preflop betting state partway through the round: 2 to 9 players with 1 to
+/// 10,000 chips, blinds posted, and up to 20 legal actions taken.
+pub fn betting_state() -> impl Strategy<Value = BettingState> {
+    (
+        vec(1..=10_000_u64, 2..=9),
+        1..=100_u64,
+        vec(any::<usize>(), 0..=20),
+    )
+        .prop_map(|(stacks, big_blind, choices)| {
+            let stacks = stacks.into_iter().map(Chips::new).collect();
+            replay_betting(stacks, Chips::new(big_blind), &choices)
+        })
+}
+
+/// Plays a preflop round from `stacks`, taking one legal action per entry of `choices`.
+///
+/// Seats 0 and 1 post the small and big blind and seat 2 (or 0 heads-up) acts first.
+/// Each choice picks among the acting player's `LegalActions::actions` modulo their
+/// number. Replaying stops early once nobody is left to act.
+///
+/// # Panics
+///
+/// Panics if `stacks` is empty.
+#[must_use]
+pub fn replay_betting(stacks: Vec<Chips>, big_blind: Chips, choices: &[usize]) -> BettingState {
+    let num_players = stacks.len();
+    let mut betting = BettingState::new(stacks);
+    betting.start_round(big_blind, true);
+    betting.post_blind(PlayerId::new(0), Chips::new(big_blind.value() / 2));
+    betting.post_blind(PlayerId::new(1), big_blind);
+    betting.set_first_to_act(PlayerId::from(2 % num_players));
+
+    for &choice in choices {
+        let Some(player) = betting.to_act() else {
+            break;
+        };
+        let actions = betting.legal_actions(player).actions();
+        let action = actions[choice % actions.len()];
+        betting
+            .apply_action(player, action)
+            .expect("listed actions are legal");
+    }
+    betting
+}
+
+impl Arbitrary for Card {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        card().boxed()
+    }
+}
+
+impl Arbitrary for HoleCards {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        hole_cards().boxed()
+    }
+}
+
+impl Arbitrary for Board {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        board().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use super::*;
+    use crate::core::domain::entities::card_set::CardSet;
+    use crate::core::domain::services::{CactusKevEvaluator, PerfectHashEvaluator};
+    use crate::core::ports::inbound::HandEvaluator;
+    use crate::test_support::invariants::{
+        check_chip_conservation, check_suit_symmetry, permute_suit,
+    };
+
+    static CACTUS_KEV: LazyLock<CactusKevEvaluator> = LazyLock::new(CactusKevEvaluator::new);
+    static PERFECT_HASH: LazyLock<PerfectHashEvaluator> = LazyLock::new(PerfectHashEvaluator::new);
+
+    proptest! {
+        #[test]
+        fn deals_never_repeat_a_card((players, board) in deal(4, 5)) {
+            let mut seen = CardSet::empty();
+            let cards = players.iter().flat_map(HoleCards::cards).chain(board.cards());
+            for &card in cards {
+                prop_assert!(seen.insert(card), "{card} dealt twice");
+            }
+        }
+
+        #[test]
+        fn evaluators_ignore_suit_labels(cards in seven_cards()) {
+            prop_assert_eq!(check_suit_symmetry(&*CACTUS_KEV, &cards), Ok(()));
+            prop_assert_eq!(check_suit_symmetry(&*PERFECT_HASH, &cards), Ok(()));
+        }
+
+        #[test]
+        fn evaluators_agree(cards in seven_cards(), permutation in suit_permutation()) {
+            let permuted = cards.map(|card| permute_suit(card, permutation));
+            prop_assert_eq!(
+                CACTUS_KEV.evaluate_7cards_fast(&cards),
+                PERFECT_HASH.evaluate_7cards_fast(&permuted)
+            );
+        }
+
+        #[test]
+        fn betting_conserves_chips(betting in betting_state()) {
+            prop_assert_eq!(check_chip_conservation(&betting), Ok(()));
+        }
+
+        #[test]
+        fn boards_are_on_a_street(board in any::<Board>()) {
+            prop_assert!(matches!(board.len(), 0 | 3 | 4 | 5));
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! JavaScript bindings for running the engine client-side, e.g. in web trainers.
+//!
+//! Built with the `wasm` feature for `wasm32-unknown-unknown`, typically through
+//! `wasm-pack build --features wasm`. Cards are passed as strings in the same notation
+//! the CLI accepts, and results come back as plain objects:
+//!
+//! ```text
+//! import { evaluate, equity, showdown } from "riverrun";
+//!
+//! evaluate("As Ks Qs Js Ts 2h 3d").description;        // "Royal Flush"
+//! equity("AsKs", "Qs Js 2h", 2, 20000).equity;          // ~0.6
+//! showdown(["AsKs", "QhQd"], "Qs Js 2h 7c 3d").winners; // [1]
+//! ```
+//!
+//! Failures, such as unparseable or repeated cards, are thrown as JavaScript `Error`s.
+
+use wasm_bindgen::prelude::*;
+
+use crate::core::domain::entities::board::Board;
+use crate::core::domain::entities::card::parse_cards;
+use crate::core::domain::entities::card_set::CardSet;
+use crate::core::domain::entities::hand::Hand;
+use crate::core::domain::entities::hole_cards::{HoleCards, validate_no_duplicates};
+use crate::core::domain::errors::CardError;
+use crate::core::domain::services::utils::card_combinations;
+use crate::core::domain::services::{CactusKevEvaluator, ShowdownSolver, SmartEquityCalculator};
+use crate::core::ports::inbound::{EquityCalculator, HandEvaluator, HandSolver, MAX_PLAYERS};
+
+/// The best five-card hand made from the cards passed to `evaluate`.
+#[wasm_bindgen(getter_with_clone)]
+pub struct Evaluation {
+    /// Hand category, e.g. `"Two Pair"`.
+    pub rank: String,
+    /// Full description, e.g. `"Two Pair, Aces and Kings, Queen kicker"`.
+    pub description: String,
+    /// The five cards that make the hand, e.g. `["As", "Ah", "Kd", "Kc", "Qs"]`.
+    #[wasm_bindgen(js_name = bestFive)]
+    pub best_five: Vec<String>,
+    /// Strength from 1 (royal flush) to 7462 (seven high); lower is stronger.
+    pub strength: u16,
+    /// Fraction of hand strengths this hand beats or ties, from 0 to 1.
+    pub percentile: f64,
+}
+
+/// Equity of a hand against random opponents, returned by `equity`.
+#[wasm_bindgen]
+pub struct Equity {
+    /// Share of the pot won on average, from 0 to 1, counting ties as split pots.
+    pub equity: f64,
+    /// Fraction of runouts won outright.
+    pub win: f64,
+    /// Fraction of runouts tied.
+    pub tie: f64,
+    /// Fraction of runouts lost.
+    pub loss: f64,
+    /// Runouts evaluated, enumerated or sampled.
+    pub samples: u32,
+}
+
+/// Winners of a showdown, returned by `showdown`.
+#[wasm_bindgen(getter_with_clone)]
+pub struct Showdown {
+    /// Indices of the winning hands; more than one for a split pot.
+    pub winners: Vec<u32>,
+    /// Description of each player's best hand, in the order the hands were given.
+    pub hands: Vec<String>,
+}
+
+/// Evaluates the best five-card hand among five to seven cards, e.g. `"As Ks Qs Js Ts"`.
+///
+/// # Errors
+///
+/// Throws if the cards do not parse, repeat, or number fewer than five or more than seven.
+#[wasm_bindgen]
+pub fn evaluate(cards: &str) -> Result<Evaluation, JsError> {
+    let cards = parse_cards(cards)?;
+    if cards.len() > 7 {
+        return Err(JsError::new("evaluate needs five to seven cards"));
+    }
+    let mut seen = CardSet::empty();
+    if let Some(card) = cards.iter().find(|&&card| !seen.insert(card)) {
+        return Err(CardError::DuplicateCard(*card).into());
+    }
+    let evaluator = CactusKevEvaluator::new();
+    let hand = card_combinations(&cards, 5)
+        .filter_map(|five| five.try_into().ok())
+        .map(|five| evaluator.evaluate_5cards(five))
+        .min_by_key(Hand::strength)
+        .ok_or_else(|| JsError::new("evaluate needs five to seven cards"))?;
+    Ok(Evaluation {
+        rank: hand.rank().name().to_string(),
+        description: hand.describe(),
+        best_five: hand.cards().iter().map(ToString::to_string).collect(),
+        strength: hand.strength(),
+        percentile: hand.percentile(),
+    })
+}
+
+/// Equity of `hand`, e.g. `"AsKs"`, on `board` against `opponents` random hands.
+///
+/// Small calculations are enumerated exactly; larger ones draw `samples` Monte Carlo
+/// samples, or the calculator's default when `samples` is omitted.
+///
+/// # Errors
+///
+/// Throws if the cards do not parse or repeat, or the board is not a flop, turn, river
+/// or empty.
+#[wasm_bindgen]
+pub fn equity(
+    hand: &str,
+    board: &str,
+    opponents: usize,
+    samples: Option<u32>,
+) -> Result<Equity, JsError> {
+    let hole_cards: HoleCards = hand.parse()?;
+    let board: Board = board.parse()?;
+    let calculator = SmartEquityCalculator::new(CactusKevEvaluator::new());
+    let calculator = match samples {
+        Some(samples) => calculator.with_samples(samples),
+        None => calculator,
+    };
+    let result = calculator.calculate(&hole_cards, &board, opponents)?;
+    Ok(Equity {
+        equity: result.equity(),
+        win: result.win_rate(),
+        tie: result.tie_rate(),
+        loss: result.lose_rate(),
+        samples: u32::try_from(result.samples()).unwrap_or(u32::MAX),
+    })
+}
+
+/// Winner(s) among `hands`, e.g. `["AsKs", "QhQd"]`, on a complete five-card `board`.
+///
+/// # Errors
+///
+/// Throws if the cards do not parse or repeat, the board is incomplete, or there are
+/// more than ten hands.
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)] // wasm-bindgen hands over JavaScript arrays by value
+pub fn showdown(hands: Vec<String>, board: &str) -> Result<Showdown, JsError> {
+    let players = hands
+        .iter()
+        .map(|hand| hand.parse())
+        .collect::<Result<Vec<HoleCards>, _>>()?;
+    let board: Board = board.parse()?;
+    if board.len() != 5 {
+        return Err(JsError::new("showdown needs a board of five cards"));
+    }
+    if players.len() > MAX_PLAYERS {
+        return Err(JsError::new("showdown supports at most ten hands"));
+    }
+    validate_no_duplicates(&players, &board)?;
+    let result = ShowdownSolver::new(CactusKevEvaluator::new()).solve_with_hands(&players, &board);
+    Ok(Showdown {
+        winners: result
+            .winner_indices()
+            .iter()
+            .filter_map(|&i| u32::try_from(i).ok())
+            .collect(),
+        hands: result.hands.iter().map(Hand::describe).collect(),
+    })
+}